@@ -0,0 +1,39 @@
+//! Optionally generate pads from a user-supplied SVD.
+//!
+//! With the `svd` feature, set `IMXRT_IOMUXC_SVD` to the path of an i.MX RT SVD
+//! file. The build script generates the `svd` module from the file's IOMUXC,
+//! IOMUXC_LPSR, and IOMUXC_SNVS peripherals, like `iomuxc.py` and `daisy.py`.
+//! Without the variable, the build script does nothing, and the package only
+//! has its committed pads. Without the feature, the build script doesn't
+//! include the generator.
+//!
+//! The build script keeps the generated module in `OUT_DIR`, keyed by a hash of
+//! the SVD, the generator, and the enabled features. It only regenerates the
+//! module when the key changes.
+
+use std::{env, path::PathBuf};
+
+#[cfg(feature = "svd")]
+#[path = "build/svd.rs"]
+mod svd;
+
+const SVD_VAR: &str = "IMXRT_IOMUXC_SVD";
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(imxrt_iomuxc_svd)");
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=build/svd.rs");
+    println!("cargo::rerun-if-env-changed={SVD_VAR}");
+
+    let Some(path) = env::var_os(SVD_VAR) else {
+        return;
+    };
+    let path = PathBuf::from(path);
+    #[cfg(feature = "svd")]
+    svd::generate_module(&path);
+    #[cfg(not(feature = "svd"))]
+    panic!(
+        "{SVD_VAR} is {}, but the svd feature is disabled",
+        path.display()
+    );
+}
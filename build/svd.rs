@@ -0,0 +1,756 @@
+//! The `svd` module generator.
+
+use super::SVD_VAR;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    fmt::Write as _,
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+// Pads that cannot drive an output.
+const INPUT_ONLY: &[&str] = &["WAKEUP", "POR_B", "ONOFF", "TEST_MODE"];
+
+/// Generate the `svd` module from the SVD at `path`
+pub fn generate_module(path: &Path) {
+    let chips = ["_IMXRT1010", "_IMXRT1060", "_IMXRT1170"];
+    if let Some(chip) = chips
+        .iter()
+        .find(|chip| env::var_os(format!("CARGO_FEATURE_{chip}")).is_some())
+    {
+        panic!(
+            "{SVD_VAR} conflicts with the {} feature",
+            chip[1..].to_lowercase()
+        );
+    }
+    println!("cargo::rerun-if-changed={}", path.display());
+
+    let svd = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("cannot read {SVD_VAR} {}: {err}", path.display()));
+
+    // Cargo reruns the build script when the SVD's timestamp changes, or when
+    // the variable names another copy of the same SVD. Keep the module if
+    // neither the SVD nor the generator changed since the last run.
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("svd.rs");
+    let stamp = out.with_extension("hash");
+    let key = cache_key(&svd);
+    if out.exists() && fs::read_to_string(&stamp).is_ok_and(|prev| prev == key) {
+        println!("cargo::rustc-cfg=imxrt_iomuxc_svd");
+        return;
+    }
+
+    let root = parse(&svd).unwrap_or_else(|err| panic!("cannot parse {}: {err}", path.display()));
+
+    let peripherals: Vec<&Element> = ["IOMUXC", "IOMUXC_LPSR", "IOMUXC_SNVS"]
+        .into_iter()
+        .filter_map(|name| {
+            root.find("peripherals")?
+                .children("peripheral")
+                .find(|peripheral| peripheral.text_of("name") == Some(name))
+        })
+        .collect();
+    assert!(
+        !peripherals.is_empty(),
+        "{} has no IOMUXC peripheral",
+        path.display()
+    );
+
+    let mut pads = BTreeMap::new();
+    let mut daisies = Vec::new();
+    let mut resets = Resets::default();
+    for peripheral in &peripherals {
+        extract(
+            peripheral,
+            root.text_of("resetValue"),
+            &mut pads,
+            &mut daisies,
+            &mut resets,
+        );
+    }
+    fs::write(out, generate(&pads, &daisies, &resets)).unwrap();
+    fs::write(stamp, key).unwrap();
+    println!("cargo::rustc-cfg=imxrt_iomuxc_svd");
+}
+
+/// Returns the cache key of the module generated from `svd`
+///
+/// The key covers the SVD, this generator, and the enabled features.
+fn cache_key(svd: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    svd.hash(&mut hasher);
+    include_str!("svd.rs").hash(&mut hasher);
+    let mut features: Vec<_> = env::vars_os()
+        .filter_map(|(var, _)| var.into_string().ok())
+        .filter(|var| var.starts_with("CARGO_FEATURE_"))
+        .collect();
+    features.sort();
+    features.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[derive(Default)]
+struct PadRegisters {
+    mux: Option<u32>,
+    pad: Option<u32>,
+    reset: (Option<u32>, Option<u32>),
+    gpio: Vec<Gpio>,
+    alternates: Vec<(u32, String)>,
+    instance: String,
+}
+
+struct Gpio {
+    alt: u32,
+    module: u32,
+    offset: u32,
+}
+
+struct SelectInput {
+    input: String,
+    pad: String,
+    address: u32,
+    value: u32,
+}
+
+impl SelectInput {
+    /// The selected pad, without the 1176 values' `SELECT_` prefix
+    fn pad_name(&self) -> &str {
+        self.pad.strip_prefix("SELECT_").unwrap_or(&self.pad)
+    }
+}
+
+/// Register reset values; the MUX and PAD registers, then the select input
+/// registers
+#[derive(Default)]
+struct Resets {
+    pads: Vec<(u32, u32)>,
+    inputs: Vec<(u32, u32)>,
+}
+
+fn extract(
+    peripheral: &Element,
+    device_reset: Option<&str>,
+    pads: &mut BTreeMap<String, PadRegisters>,
+    daisies: &mut Vec<SelectInput>,
+    resets: &mut Resets,
+) {
+    let base = number(peripheral.text_of("baseAddress").expect("baseAddress"));
+    // A register inherits the peripheral's, then the device's, reset value.
+    let default_reset = peripheral.text_of("resetValue").or(device_reset);
+    let instance = match peripheral.text_of("name").unwrap_or_default() {
+        "IOMUXC" => "IOMUXC",
+        name => name.trim_start_matches("IOMUXC_"),
+    };
+    let registers = peripheral.find("registers").expect("registers");
+    for register in registers.children("register") {
+        let name = register.text_of("name").expect("register name");
+        let address = base + number(register.text_of("addressOffset").expect("addressOffset"));
+        let reset = register
+            .text_of("resetValue")
+            .or(default_reset)
+            .map(|value| (address, number(value)));
+        let fields = || {
+            register
+                .find("fields")
+                .into_iter()
+                .flat_map(|fields| fields.children("field"))
+        };
+        let values = |field: &Element| -> Vec<(String, String, u32)> {
+            field
+                .find("enumeratedValues")
+                .into_iter()
+                .flat_map(|values| values.children("enumeratedValue"))
+                .map(|value| {
+                    (
+                        value.text_of("name").unwrap_or_default().to_string(),
+                        value.text_of("description").unwrap_or_default().to_string(),
+                        number(value.text_of("value").expect("enumerated value")),
+                    )
+                })
+                .collect()
+        };
+
+        if let Some(pad_name) = name.strip_prefix("SW_MUX_CTL_PAD_") {
+            resets.pads.extend(reset);
+            let registers = pads.entry(pad_name_of(pad_name)).or_default();
+            registers.mux = Some(address);
+            registers.reset.0 = reset.map(|(_, value)| value);
+            registers.instance = instance.to_string();
+            if let Some(mux_mode) = fields().find(|field| field.text_of("name") == Some("MUX_MODE"))
+            {
+                for (_, description, alt) in values(mux_mode) {
+                    if let Some(signal) = mux_port(&description) {
+                        registers.alternates.push((alt, signal.to_string()));
+                    }
+                    if let Some((module, offset)) = gpio_signal(&description) {
+                        registers.gpio.push(Gpio {
+                            alt,
+                            module,
+                            offset,
+                        });
+                    }
+                }
+            }
+        } else if let Some(pad_name) = name.strip_prefix("SW_PAD_CTL_PAD_") {
+            resets.pads.extend(reset);
+            let registers = pads.entry(pad_name_of(pad_name)).or_default();
+            registers.pad = Some(address);
+            registers.reset.1 = reset.map(|(_, value)| value);
+            registers.instance = instance.to_string();
+        } else if let Some(input) = name.strip_suffix("_SELECT_INPUT") {
+            resets.inputs.extend(reset);
+            for field in fields() {
+                for (pad, _, value) in values(field) {
+                    let pad = match pad.rfind("_ALT") {
+                        Some(alt) => &pad[..alt],
+                        None => &pad,
+                    };
+                    daisies.push(SelectInput {
+                        input: input.to_string(),
+                        pad: pad.to_string(),
+                        address,
+                        value,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// The pad named by a register, without the 1176 SNVS registers' `_DIG` suffix
+fn pad_name_of(register: &str) -> String {
+    register
+        .strip_suffix("_DIG")
+        .unwrap_or(register)
+        .to_string()
+}
+
+/// Find the signal name in an alternate's description, like
+/// `"ALT2 mux port: LPUART1_TX of instance: lpuart1"`.
+fn mux_port(description: &str) -> Option<&str> {
+    let (_, port) = description.split_once("mux port:")?;
+    port.split_whitespace().next()
+}
+
+/// Find the GPIO module and offset in an alternate's description.
+///
+/// Accepts the `GPIO1_IO03`, `GPIOMUX_IO03` (1010), and `GPIO_MUX1_IO3` (1170)
+/// forms.
+fn gpio_signal(description: &str) -> Option<(u32, u32)> {
+    fn digits(text: &str) -> Option<(u32, &str)> {
+        let end = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        Some((text[..end].parse().ok()?, &text[end..]))
+    }
+    for (start, _) in description.match_indices("GPIO") {
+        let rest = &description[start + "GPIO".len()..];
+        if let Some(rest) = rest.strip_prefix("MUX_IO") {
+            if let Some((offset, _)) = digits(rest) {
+                return Some((1, offset));
+            }
+        }
+        let rest = rest.strip_prefix("_MUX").unwrap_or(rest);
+        if let Some((module, rest)) = digits(rest) {
+            if let Some((offset, _)) = rest.strip_prefix("_IO").and_then(digits) {
+                return Some((module, offset));
+            }
+        }
+    }
+    None
+}
+
+fn generate(
+    pads: &BTreeMap<String, PadRegisters>,
+    daisies: &[SelectInput],
+    resets: &Resets,
+) -> String {
+    // Pads without both registers, like some SNVS pads, can't be a Pad type.
+    let pads: BTreeMap<&str, (u32, u32, &PadRegisters)> = pads
+        .iter()
+        .filter_map(|(name, registers)| {
+            Some((name.as_str(), (registers.mux?, registers.pad?, registers)))
+        })
+        .collect();
+
+    let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut fields = BTreeMap::new();
+    for (name, (_, _, registers)) in &pads {
+        let (group, field) = group_of(name, registers);
+        groups.entry(group).or_default().push(*name);
+        fields.insert(*name, field);
+    }
+
+    let mut out = String::from("// Generated by build.rs\n\n");
+    for (group, names) in &groups {
+        let module = group.to_lowercase();
+        writeln!(out, "/// Pads with the prefix {group}.").unwrap();
+        writeln!(out, "pub mod {module} {{").unwrap();
+        for name in names {
+            let (mux, pad, registers) = pads[*name];
+            writeln!(out, "const {name}_MUX_ADDR: u32 = {mux:#010x};").unwrap();
+            writeln!(out, "const {name}_PAD_ADDR: u32 = {pad:#010x};").unwrap();
+            writeln!(
+                out,
+                "pub type {name} = crate::Pad<{name}_MUX_ADDR, {name}_PAD_ADDR>;"
+            )
+            .unwrap();
+            // Input-only pads don't implement OutputCapable.
+            if !INPUT_ONLY.contains(name) {
+                writeln!(out, "impl crate::OutputCapable for {name} {{}}").unwrap();
+            }
+            for gpio in &registers.gpio {
+                writeln!(
+                    out,
+                    "impl crate::gpio::Pin<{}> for {name} {{ const ALT: u32 = {}; const OFFSET: u32 = {}; }}",
+                    gpio.module, gpio.alt, gpio.offset
+                )
+                .unwrap();
+            }
+            let alts: BTreeSet<u32> = registers
+                .alternates
+                .iter()
+                .map(|(alt, _)| *alt)
+                .chain(registers.gpio.iter().map(|gpio| gpio.alt))
+                .collect();
+            for alt in alts {
+                writeln!(out, "impl crate::AnyAltPin<{alt}> for {name} {{}}").unwrap();
+            }
+        }
+
+        writeln!(out, "/// All pads with prefix {group}.").unwrap();
+        writeln!(out, "pub struct Pads {{").unwrap();
+        for name in names {
+            writeln!(out, "pub {}: {name},", fields[name]).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "/// Erased pads with prefix {group}.").unwrap();
+        writeln!(
+            out,
+            "pub type ErasedPads = [crate::ErasedPad; {}];",
+            names.len()
+        )
+        .unwrap();
+        writeln!(out, "impl Pads {{").unwrap();
+        writeln!(out, "/// Take all pads from this group").unwrap();
+        writeln!(out, "///\n/// # Safety\n///").unwrap();
+        writeln!(
+            out,
+            "/// You may safely call this once to acquire all of the pads."
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "#[inline] pub const unsafe fn new() -> Self {{ Self {{"
+        )
+        .unwrap();
+        for name in names {
+            writeln!(out, "{}: {name}::new(),", fields[name]).unwrap();
+        }
+        writeln!(out, "}} }}").unwrap();
+        writeln!(out, "/// Erase all of the pads").unwrap();
+        writeln!(out, "#[inline] pub const fn erase(self) -> ErasedPads {{ [").unwrap();
+        for name in names {
+            writeln!(out, "self.{}.erase(),", fields[name]).unwrap();
+        }
+        writeln!(out, "] }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    writeln!(out, "/// All of the pads.").unwrap();
+    writeln!(out, "pub struct Pads {{").unwrap();
+    for group in groups.keys() {
+        let module = group.to_lowercase();
+        writeln!(out, "pub {module}: {module}::Pads,").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "impl Pads {{").unwrap();
+    writeln!(out, "/// Take all pads").unwrap();
+    writeln!(out, "///\n/// # Safety\n///").unwrap();
+    writeln!(
+        out,
+        "/// You may safely call this once to acquire all of the pads."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[inline] pub const unsafe fn new() -> Self {{ Self {{"
+    )
+    .unwrap();
+    for group in groups.keys() {
+        let module = group.to_lowercase();
+        writeln!(out, "{module}: {module}::Pads::new(),").unwrap();
+    }
+    writeln!(out, "}} }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "/// Daisy values for all select input registers.").unwrap();
+    writeln!(out, "pub mod daisy {{").unwrap();
+    writeln!(out, "use crate::Daisy;").unwrap();
+    for daisy in daisies {
+        writeln!(
+            out,
+            "#[allow(missing_docs)] pub const DAISY_{}_{}: Daisy = Daisy::new({:#010x} as *mut u32, {});",
+            daisy.input, daisy.pad, daisy.address, daisy.value
+        )
+        .unwrap();
+    }
+    writeln!(out, "/// All daisy values.").unwrap();
+    writeln!(out, "pub const ALL: &[Daisy] = &[").unwrap();
+    for daisy in daisies {
+        writeln!(out, "DAISY_{}_{},", daisy.input, daisy.pad).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    let location = |name: &str| {
+        let (mux, pad, _) = pads[name];
+        format!("crate::PadLocation {{ mux: {mux:#010x}, pad: {pad:#010x} }}")
+    };
+    writeln!(out, "/// Every alternate and select input.").unwrap();
+    writeln!(
+        out,
+        "pub const DATABASE: crate::signals::Database = crate::signals::Database {{"
+    )
+    .unwrap();
+    writeln!(out, "alternates: &[").unwrap();
+    for (name, (_, _, registers)) in &pads {
+        for (alt, signal) in &registers.alternates {
+            writeln!(
+                out,
+                "crate::signals::Alternate {{ pad: {}, alt: {alt}, signal: {signal:?} }},",
+                location(name)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "],").unwrap();
+    writeln!(out, "inputs: &[").unwrap();
+    for daisy in daisies
+        .iter()
+        .filter(|daisy| pads.contains_key(daisy.pad_name()))
+    {
+        writeln!(
+            out,
+            "crate::signals::SelectInput {{ input: {:?}, register: {:#010x}, pad: {}, value: {} }},",
+            daisy.input,
+            daisy.address,
+            location(daisy.pad_name()),
+            daisy.value
+        )
+        .unwrap();
+    }
+    writeln!(out, "],").unwrap();
+    writeln!(out, "}};").unwrap();
+
+    writeln!(
+        out,
+        "/// All pads, their reset values, and the daisy values that select them"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub const REGISTER_MAP: &[crate::register_map::PadRegisters] = &["
+    )
+    .unwrap();
+    for (name, (mux, pad, registers)) in &pads {
+        let reset = match registers.reset {
+            (Some(mux), Some(pad)) => format!(
+                "Some(crate::register_map::PadReset {{ mux: {mux:#010x}, pad: {pad:#010x} }})"
+            ),
+            _ => "None".to_string(),
+        };
+        writeln!(
+            out,
+            "crate::register_map::PadRegisters {{ name: {name:?}, mux: {mux:#010x}, pad: {pad:#010x}, reset: {reset}, daisies: &["
+        )
+        .unwrap();
+        for daisy in daisies.iter().filter(|daisy| daisy.pad_name() == *name) {
+            writeln!(
+                out,
+                "crate::register_map::DaisyEntry {{ reg: {:#010x}, value: {} }},",
+                daisy.address, daisy.value
+            )
+            .unwrap();
+        }
+        writeln!(out, "] }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(
+        out,
+        "/// The length of a [`register_map::save()`](crate::register_map::save) snapshot"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub const SNAPSHOT_LEN: usize = crate::register_map::snapshot_len(REGISTER_MAP, SNAPSHOT_GPRS);"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "/// The IOMUXC_GPR registers that a snapshot includes\n///\n/// The SVD doesn't say which GPRs select pad functions, so the list is empty."
+    )
+    .unwrap();
+    writeln!(out, "pub const SNAPSHOT_GPRS: &[u32] = &[];").unwrap();
+
+    writeln!(
+        out,
+        "/// The reset value of every MUX and PAD register, then every select input register."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub const RESET_VALUES: &[crate::register_map::ResetValue] = &["
+    )
+    .unwrap();
+    for (reg, value) in resets.pads.iter().chain(&resets.inputs) {
+        writeln!(
+            out,
+            "crate::register_map::ResetValue {{ reg: {reg:#010x}, value: {value:#010x} }},"
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out, "/// Write every register's reset value").unwrap();
+    writeln!(out, "///\n/// # Safety\n///").unwrap();
+    writeln!(
+        out,
+        "/// See [`register_map::reset()`](crate::register_map::reset)."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub unsafe fn reset() {{ crate::register_map::reset(RESET_VALUES) }}"
+    )
+    .unwrap();
+    out
+}
+
+/// Returns a pad's group, and its field name in the group's `Pads`.
+///
+/// Pads without a pad number, like the SNVS domain's `WAKEUP`, are grouped by
+/// their IOMUXC instance.
+fn group_of<'a>(name: &'a str, registers: &'a PadRegisters) -> (&'a str, String) {
+    match name.rsplit_once('_') {
+        Some((group, number))
+            if number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            (group, format!("p{number}"))
+        }
+        _ => (&registers.instance, name.to_lowercase()),
+    }
+}
+
+fn number(text: &str) -> u32 {
+    let text = text.trim();
+    let parsed = match text.get(..2) {
+        Some("0x" | "0X") => u32::from_str_radix(&text[2..], 16),
+        _ => text.parse(),
+    };
+    parsed.unwrap_or_else(|_| panic!("{text:?} is not a number"))
+}
+
+/// The elements of an XML document, without attributes.
+#[derive(Default)]
+struct Element {
+    name: String,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+    fn find(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+    fn text_of(&self, name: &str) -> Option<&str> {
+        self.find(name).map(|child| child.text.trim())
+    }
+}
+
+/// Parse just enough XML for an SVD.
+fn parse(xml: &str) -> Result<Element, String> {
+    let mut stack = vec![Element::default()];
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        stack.last_mut().unwrap().text.push_str(&unescape(text));
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment.find("-->").ok_or("unterminated comment")?;
+            rest = &comment[end + "-->".len()..];
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").ok_or("unterminated CDATA")?;
+            stack.last_mut().unwrap().text.push_str(&cdata[..end]);
+            rest = &cdata[end + "]]>".len()..];
+        } else {
+            let end = rest.find('>').ok_or("unterminated tag")?;
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            } else if let Some(name) = tag.strip_prefix('/') {
+                let element = stack.pop().ok_or("unbalanced tags")?;
+                if element.name != name.trim() {
+                    return Err(format!("expected </{}>, found </{name}>", element.name));
+                }
+                stack
+                    .last_mut()
+                    .ok_or("unbalanced tags")?
+                    .children
+                    .push(element);
+            } else {
+                let empty = tag.ends_with('/');
+                let tag = tag.trim_end_matches('/');
+                let name = tag.split_whitespace().next().ok_or("empty tag")?;
+                let element = Element {
+                    name: name.to_string(),
+                    ..Element::default()
+                };
+                if empty {
+                    stack.last_mut().unwrap().children.push(element);
+                } else {
+                    stack.push(element);
+                }
+            }
+        }
+    }
+    match stack.pop() {
+        Some(mut document) if stack.is_empty() => document
+            .children
+            .pop()
+            .ok_or_else(|| "no root element".to_string()),
+        _ => Err("unbalanced tags".to_string()),
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, generate, parse, BTreeMap, Resets};
+
+    const SVD: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.1">
+  <name>FIXTURE</name>
+  <resetValue>0x5</resetValue>
+  <peripherals>
+    <peripheral>
+      <name>IOMUXC</name>
+      <baseAddress>0x401F8000</baseAddress>
+      <registers>
+        <register>
+          <name>SW_MUX_CTL_PAD_GPIO_AD_B0_00</name>
+          <addressOffset>0xBC</addressOffset>
+          <fields>
+            <field>
+              <name>MUX_MODE</name>
+              <enumeratedValues>
+                <enumeratedValue>
+                  <name>ALT2</name>
+                  <description>Select mux mode: ALT2 mux port: LPUART1_TX of instance: lpuart1</description>
+                  <value>0x2</value>
+                </enumeratedValue>
+                <enumeratedValue>
+                  <name>ALT5</name>
+                  <description>Select mux mode: ALT5 mux port: GPIO1_IO00 of instance: gpio1</description>
+                  <value>0x5</value>
+                </enumeratedValue>
+              </enumeratedValues>
+            </field>
+          </fields>
+        </register>
+        <register>
+          <name>SW_PAD_CTL_PAD_GPIO_AD_B0_00</name>
+          <addressOffset>0x2AC</addressOffset>
+          <resetValue>0x10B0</resetValue>
+        </register>
+        <!-- A pad without a PAD register isn't a Pad type. -->
+        <register>
+          <name>SW_MUX_CTL_PAD_TEST_MODE</name>
+          <addressOffset>0x0</addressOffset>
+        </register>
+        <register>
+          <name>LPUART1_RX_SELECT_INPUT</name>
+          <addressOffset>0x600</addressOffset>
+          <resetValue>0x0</resetValue>
+          <fields>
+            <field>
+              <name>DAISY</name>
+              <enumeratedValues>
+                <enumeratedValue>
+                  <name>GPIO_AD_B0_00_ALT2</name>
+                  <value>0x1</value>
+                </enumeratedValue>
+              </enumeratedValues>
+            </field>
+          </fields>
+        </register>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>
+"#;
+
+    #[test]
+    fn parse_elements() {
+        let root = parse("<a><!-- <b/> --><b>1 &amp; 2</b><c/><d><![CDATA[<e>]]></d></a>").unwrap();
+        assert_eq!(root.name, "a");
+        assert_eq!(root.text_of("b"), Some("1 & 2"));
+        assert_eq!(root.text_of("c"), Some(""));
+        assert_eq!(root.text_of("d"), Some("<e>"));
+        assert!(parse("<a><b></a>").is_err());
+    }
+
+    #[test]
+    fn generate_fixture() {
+        let root = parse(SVD).unwrap();
+        let peripheral = root
+            .find("peripherals")
+            .and_then(|peripherals| peripherals.find("peripheral"))
+            .unwrap();
+        let mut pads = BTreeMap::new();
+        let mut daisies = Vec::new();
+        let mut resets = Resets::default();
+        extract(
+            peripheral,
+            root.text_of("resetValue"),
+            &mut pads,
+            &mut daisies,
+            &mut resets,
+        );
+
+        let pad = &pads["GPIO_AD_B0_00"];
+        assert_eq!((pad.mux, pad.pad), (Some(0x401F_80BC), Some(0x401F_82AC)));
+        assert_eq!(pad.reset, (Some(0x5), Some(0x10B0)));
+        assert_eq!(
+            pad.alternates,
+            [(2, "LPUART1_TX".to_string()), (5, "GPIO1_IO00".to_string())]
+        );
+        assert_eq!(resets.inputs, [(0x401F_8600, 0)]);
+
+        let out = generate(&pads, &daisies, &resets);
+        for line in [
+            "pub type GPIO_AD_B0_00 = crate::Pad<GPIO_AD_B0_00_MUX_ADDR, GPIO_AD_B0_00_PAD_ADDR>;",
+            "impl crate::gpio::Pin<1> for GPIO_AD_B0_00 { const ALT: u32 = 5; const OFFSET: u32 = 0; }",
+            "impl crate::AnyAltPin<2> for GPIO_AD_B0_00 {}",
+            "pub const DAISY_LPUART1_RX_GPIO_AD_B0_00: Daisy = Daisy::new(0x401f8600 as *mut u32, 1);",
+            "reset: Some(crate::register_map::PadReset { mux: 0x00000005, pad: 0x000010b0 })",
+            "crate::register_map::DaisyEntry { reg: 0x401f8600, value: 1 },",
+            "crate::register_map::ResetValue { reg: 0x401f82ac, value: 0x000010b0 },",
+        ] {
+            assert!(out.contains(line), "missing {line}");
+        }
+        assert!(!out.contains("TEST_MODE"));
+    }
+}
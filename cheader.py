@@ -0,0 +1,126 @@
+#!/usr/bin/env python3
+
+"""
+This script generates a C header of pad and signal tables for an i.MX RT chip.
+The header describes each pad's MUX and PAD register addresses, the
+alternate and daisy values of each pad's peripheral signals, and each pad's
+ADC inputs. It reads the
+chip's module in this package.
+
+Usage:
+
+    python3 cheader.py imxrt1060 > imxrt1060_iomuxc.h
+
+Like a cbindgen header, all values are #defines with a chip prefix.
+"""
+
+import pathlib
+import re
+import sys
+
+PERIPHERALS = {
+    "uart": "LPUART",
+    "spi": "LPSPI",
+    "i2c": "LPI2C",
+    "pwm": "FLEXPWM",
+    "sai": "SAI",
+    "usdhc": "USDHC",
+    "flexspi": "FLEXSPI",
+    "adc": "ADC",
+}
+
+INVOCATION = re.compile(r"^(\w+)!\s*[({]\s*(.*?)\s*[)}];?\s*$", re.M)
+ARGUMENT = re.compile(r"(\w+):\s*(\w+(?:\(\w+\))?)")
+DAISY = re.compile(r"pub const (\w+): Daisy =\s*Daisy::new\((0x[0-9a-fA-F_]+) as \*mut u32, (\d+)\);")
+REGISTER = re.compile(r"const (\w+)_(MUX|PAD)_ADDR: u32 = (0x[0-9a-fA-F_]+);")
+GPIO = re.compile(
+    r"impl crate::gpio::Pin<(\d+)> for (\w+) \{\s*const ALT: u32 = (\d+);\s*const OFFSET: u32 = (\d+);"
+)
+
+
+def hex32(value):
+    return f"0x{int(value.replace('_', ''), 16):08X}u"
+
+
+def signal_name(macro, args):
+    if macro == "uart":
+        return args["direction"]
+    if macro == "pwm":
+        return f"SM{args['submodule']}_{args['output']}"
+    if macro == "flexspi":
+        return f"{args['port']}_{args['signal']}"
+    return args["signal"]
+
+
+def daisy_name(daisy):
+    match = re.fullmatch(r"Some\((\w+)\)", daisy)
+    if match:
+        return match.group(1)
+    if daisy == "None":
+        return None
+    return daisy
+
+
+def cheader(chip):
+    root = pathlib.Path(__file__).parent / "src" / chip
+    prefix = chip.upper()
+
+    registers = {}
+    gpios = []
+    pads = (root / "pads.rs").read_text()
+    for pad, kind, addr in REGISTER.findall(pads):
+        registers.setdefault(pad, {})[kind] = addr
+    for module, pad, alt, offset in GPIO.findall(pads):
+        gpios.append((pad, module, alt, offset))
+
+    daisies = {}
+    signals = []
+    for path in sorted(root.glob("*.rs")):
+        if path.name in ("pads.rs", "mod.rs", "defaults.rs"):
+            continue
+        source = path.read_text()
+        for name, reg, value in DAISY.findall(source):
+            daisies[name] = (reg, value)
+        for macro, body in INVOCATION.findall(source):
+            if macro not in PERIPHERALS:
+                continue
+            args = dict(ARGUMENT.findall(body))
+            signals.append((macro, args))
+
+    guard = f"{prefix}_IOMUXC_H"
+    print("/* Generated by cheader.py */")
+    print()
+    print(f"#ifndef {guard}")
+    print(f"#define {guard}")
+    print()
+    print("/* Pad registers */")
+    for pad, regs in registers.items():
+        print(f"#define {prefix}_{pad}_MUX {hex32(regs['MUX'])}")
+        print(f"#define {prefix}_{pad}_PAD {hex32(regs['PAD'])}")
+    print()
+    print("/* GPIO signals */")
+    for pad, module, alt, offset in gpios:
+        name = f"{prefix}_{pad}_GPIO{module}_IO{int(offset):02}"
+        print(f"#define {name}_ALT {alt}u")
+    print()
+    print("/* Peripheral signals */")
+    for macro, args in signals:
+        peripheral = f"{PERIPHERALS[macro]}{args['module']}"
+        if macro == "adc":
+            print(f"#define {prefix}_{args['pad']}_{peripheral}_INPUT {args['input']}u")
+            continue
+        signal = signal_name(macro, args).upper()
+        name = f"{prefix}_{args['pad']}_{peripheral}_{signal}"
+        if "alt" in args:
+            print(f"#define {name}_ALT {args['alt']}u")
+        daisy = daisy_name(args.get("daisy", "None"))
+        if daisy is not None:
+            reg, value = daisies[daisy]
+            print(f"#define {name}_DAISY_REG {hex32(reg)}")
+            print(f"#define {name}_DAISY_VAL {value}u")
+    print()
+    print(f"#endif /* {guard} */")
+
+
+if __name__ == "__main__":
+    cheader(sys.argv[1])
@@ -5,21 +5,34 @@ Generate Daisy constants from an i.MX RT SVD file
 
 Example usage: to create SPI-related Daisy constants,
 
-    ./daisy.py path/to/imxrt.svd | grep LPSPI
+    ./daisy.py path/to/imxrt.svd LPSPI
 
-Copy and paste the constants into a Rust module.
+Each argument after the path selects the select inputs whose names start with
+it, like ENET or CSI. Without a selection, the script generates all of the
+chip's select inputs, including inputs of peripherals without pin traits.
+
+Copy and paste the constants into a Rust module. The path may also be a TOML
+pad description; see description.py for the format.
 """
 
 import xml.etree.ElementTree as ET
 
+import description
+
+
+def selected(name, prefixes):
+    return not prefixes or any(name.startswith(prefix) for prefix in prefixes)
+
 
-def daisy_constant(iomuxc):
+def daisy_constant(iomuxc, names, prefixes):
     base_address = int(iomuxc.find("./baseAddress").text, 16)
 
     for register in iomuxc.findall("./registers/register"):
         name = register.find("./name").text
         if "_SELECT_INPUT" in name:
             name = name.replace("_SELECT_INPUT", "")
+            if not selected(name, prefixes):
+                continue
             offset = int(register.find("./addressOffset").text, 16)
             address = base_address + offset
             for field in register.findall("./fields/field"):
@@ -30,20 +43,42 @@ def daisy_constant(iomuxc):
                     daisy = int(values.find("./value").text, 16)
                     constant = f"pub const DAISY_{name}_{pad}: Daisy = Daisy::new({address:#010x} as *mut u32, {daisy});"
                     print(constant)
+                    names.append(f"DAISY_{name}_{pad}")
+
+
+def describe_constant(path, names, prefixes):
+    for daisy in description.load(path)[1]:
+        if not selected(daisy.register, prefixes):
+            continue
+        for pad, value in daisy.inputs:
+            constant = f"pub const DAISY_{daisy.register}_{pad}: Daisy = Daisy::new({daisy.address:#010x} as *mut u32, {value});"
+            print(constant)
+            names.append(f"DAISY_{daisy.register}_{pad}")
+
 
+def search_iomuxces(path, prefixes):
+    names = []
+    if path.endswith(".toml"):
+        describe_constant(path, names, prefixes)
+    else:
+        tree = ET.parse(path)
+        root = tree.getroot()
+        iomuxc = root.find("./peripherals/peripheral[name='IOMUXC']")
+        iomuxc_lpsr = root.find("./peripherals/peripheral[name='IOMUXC_LPSR']")
 
-def search_iomuxces(path):
-    tree = ET.parse(path)
-    root = tree.getroot()
-    iomuxc = root.find("./peripherals/peripheral[name='IOMUXC']")
-    iomuxc_lpsr = root.find("./peripherals/peripheral[name='IOMUXC_LPSR']")
+        if iomuxc:
+            daisy_constant(iomuxc, names, prefixes)
+        if iomuxc_lpsr:
+            daisy_constant(iomuxc_lpsr, names, prefixes)
 
-    if iomuxc:
-        daisy_constant(iomuxc)
-    if iomuxc_lpsr:
-        daisy_constant(iomuxc_lpsr)
+    print()
+    print('#[cfg(feature = "self-check")]')
+    print("pub const ALL: &[Daisy] = &[")
+    for name in names:
+        print(f"    {name},")
+    print("];")
 
 if __name__ == "__main__":
     import sys
 
-    search_iomuxces(sys.argv[1])
+    search_iomuxces(sys.argv[1], sys.argv[2:])
@@ -0,0 +1,159 @@
+#!/usr/bin/env python3
+
+"""
+Load a TOML pad description.
+
+A pad description is an alternative to an SVD file. Use it to maintain pad
+data for a chip, or a chip derivative, that doesn't have a usable SVD. The
+iomuxc.py and daisy.py scripts accept a description wherever they accept an
+SVD; the file must end with ".toml".
+
+A description has a [[pad]] table for each pad, and a [[daisy]] table for each
+select input register. Addresses are absolute.
+
+    [[pad]]
+    name = "GPIO_AD_B0_00"
+    mux = 0x401F_80BC
+    pad = 0x401F_82AC
+    # Optional; true for pads that cannot drive an output. Default false.
+    input-only = false
+    # Optional; the pad's signals. iomuxc.py implements gpio::Pin for the
+    # GPIO signals, like "GPIO1_IO00".
+    alts = [
+        { alt = 2, signal = "LPUART1_TX" },
+        { alt = 5, signal = "GPIO1_IO00" },
+    ]
+    # Optional; the pad's ball or pin in each package. packages.py names
+    # the pads by these identifiers. Use a string for a BGA ball, and a
+    # number for a QFP pin, like LQFP80 = 12.
+    pins = { MAPBGA196 = "M14" }
+
+    [[daisy]]
+    # The register name, without "_SELECT_INPUT".
+    register = "LPUART1_RX"
+    address = 0x401F_8624
+    inputs = [
+        { pad = "GPIO_AD_B0_13", value = 1 },
+    ]
+
+A pad name usually ends with a two-digit pad number, and the prefix before the
+number is the pad's group. A pad without a number, like "WAKEUP", needs a
+group:
+
+    [[pad]]
+    name = "WAKEUP"
+    group = "SNVS"
+    mux = 0x400A_8000
+    pad = 0x400A_8018
+
+A pad must have both a MUX and a PAD register. Leave out pads that don't.
+
+Usage, to check a description:
+
+    python3 description.py path/to/pads.toml
+"""
+
+import tomllib
+
+
+class Pad:
+    """A pad's registers and signals."""
+
+    __slots__ = [
+        "name",
+        "mux",
+        "pad",
+        "input_only",
+        "alts",
+        "group",
+        "pins",
+    ]
+
+    def __init__(self, name, mux, pad, input_only, alts, group=None, pins=None):
+        self.name = name
+        self.mux = mux
+        self.pad = pad
+        self.input_only = input_only
+        self.alts = alts
+        self.group = group
+        self.pins = pins or {}
+
+
+class Daisy:
+    """A select input register, and the values that select its pads."""
+
+    __slots__ = [
+        "register",
+        "address",
+        "inputs",
+    ]
+
+    def __init__(self, register, address, inputs):
+        self.register = register
+        self.address = address
+        self.inputs = inputs
+
+
+def _require(table, key, kind, context):
+    if key not in table:
+        raise ValueError(f"{context} needs a '{key}'")
+    value = table[key]
+    if not isinstance(value, kind) or isinstance(value, bool) != (kind is bool):
+        raise ValueError(f"{context} '{key}' must be a {kind.__name__}")
+    return value
+
+
+def load(path):
+    """Returns the description's pads, by name, and its daisies."""
+    with open(path, "rb") as f:
+        description = tomllib.load(f)
+
+    pads = {}
+    for table in description.get("pad", []):
+        name = _require(table, "name", str, "a pad")
+        context = f"pad {name}"
+        if name in pads:
+            raise ValueError(f"{context} is described more than once")
+        group = table.get("group")
+        if not name[-2:].isdigit() and group is None:
+            raise ValueError(f"{context} needs a pad number, or a 'group'")
+        alts = []
+        for alt in table.get("alts", []):
+            value = _require(alt, "alt", int, context)
+            if not 0 <= value <= 0xF:
+                raise ValueError(f"{context} alt {value} doesn't fit the MUX_MODE field")
+            alts.append((value, _require(alt, "signal", str, context)))
+        pins = table.get("pins", {})
+        for package, pin in pins.items():
+            if isinstance(pin, bool) or not isinstance(pin, (str, int)):
+                raise ValueError(f"{context} {package} pin must be a string or a number")
+        pads[name] = Pad(
+            name,
+            _require(table, "mux", int, context),
+            _require(table, "pad", int, context),
+            table.get("input-only", False),
+            alts,
+            group,
+            pins,
+        )
+
+    daisies = []
+    for table in description.get("daisy", []):
+        register = _require(table, "register", str, "a daisy")
+        context = f"daisy {register}"
+        inputs = []
+        for select in table.get("inputs", []):
+            pad = _require(select, "pad", str, context)
+            if pad not in pads:
+                raise ValueError(f"{context} selects undescribed pad {pad}")
+            inputs.append((pad, _require(select, "value", int, context)))
+        daisies.append(Daisy(register, _require(table, "address", int, context), inputs))
+
+    return pads, daisies
+
+
+if __name__ == "__main__":
+    import sys
+
+    pads, daisies = load(sys.argv[1])
+    print(f"{len(pads)} pads, {len(daisies)} daisy registers")
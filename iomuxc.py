@@ -7,7 +7,13 @@ project.
 
 Usage:
 
-    python3 iomuxc.py path/to/imxrt.svd | rustfmt > path/to/output.rs
+    python3 iomuxc.py path/to/imxrt.svd imxrt1060 | rustfmt > path/to/output.rs
+
+The first argument may also be a TOML pad description; see description.py
+for the format.
+
+The second argument is the chip feature. Each pad group is gated on a
+per-group feature, like imxrt1060-gpio-ad-b0.
 """
 
 import re
@@ -15,6 +21,8 @@ import sys
 import xml.etree.ElementTree as ET
 from collections import defaultdict
 
+import description
+
 NEW_DOCSTRING = """
 /// Take all pads from this group
 ///
@@ -33,6 +41,35 @@ ERASE_DOCSTRING = """
 ///
 /// See `ErasedPads` for more information."""
 
+# Pads that cannot drive an output.
+INPUT_ONLY = {
+    "WAKEUP",
+    "POR_B",
+    "ONOFF",
+    "TEST_MODE",
+}
+
+GROUP_TRY_FROM_DOCSTRING = """
+/// Regroup erased pads
+///
+/// Fails, returning the erased pads, if the erased pads are not this
+/// group's pads in `erase()` order."""
+
+TRY_FROM_DOCSTRING = """
+/// Regroup all erased pads
+///
+/// Fails, returning the erased pads, if any group does not match."""
+
+GET_DOCSTRING = """
+/// Returns the erased pad at `location`
+///
+/// Returns `None` if none of the groups has the pad."""
+
+GET_MUT_DOCSTRING = """
+/// Returns the mutable erased pad at `location`
+///
+/// Returns `None` if none of the groups has the pad."""
+
 
 class GpioImpl:
     """A pad's GPIO implementation."""
@@ -49,7 +86,57 @@ class GpioImpl:
         self.offset = offset
 
 
+def parse_gpio_signal(desc):
+    """Returns the GPIO module and offset of a signal, or None."""
+    # This is the path taken on nearly all i.MX RT variants...
+    if gpio_match := re.search("GPIO\d_IO\d{2}", desc):
+        gpio_text = gpio_match.group(0)
+        [gpio_module, gpio_offset] = re.findall("\d+", gpio_text)
+        return int(gpio_module), int(gpio_offset)
+    # ...except for the 1010, which references "GPIO1_IOxx"
+    # as "GPIOMUX_IOxx".
+    elif gpio_match := re.search("GPIOMUX_IO\d{2}", desc):
+        gpio_text = gpio_match.group(0)
+        [gpio_offset] = re.findall("\d+", gpio_text)
+        return 1, int(gpio_offset)
+    # But wait! The 1176 SVD has a third form, "GPIO_MUXx_IOyz",
+    # which is mixed with the first form...
+    elif gpio_match := re.search("GPIO_MUX\d_IO\d", desc):
+        gpio_text = gpio_match.group(0)
+        [gpio_module, gpio_offset] = re.findall("\d+", gpio_text)
+        return int(gpio_module), int(gpio_offset)
+    # ...and the 1176's SNVS pads are on GPIO13, which isn't muxed.
+    elif gpio_match := re.search("GPIO13_IO\d{2}", desc):
+        gpio_text = gpio_match.group(0)
+        [gpio_module, gpio_offset] = re.findall("\d+", gpio_text)
+        return int(gpio_module), int(gpio_offset)
+    return None
+
+
+def describe_pads(path):
+    """Returns the pads of a TOML pad description."""
+    pads = {}
+    for name, pad in description.load(path)[0].items():
+        registers = {"MUX": pad.mux, "PAD": pad.pad, "INPUT_ONLY": pad.input_only}
+        if pad.group is not None:
+            registers["GROUP"] = pad.group
+        registers["ALTS"] = {alt for alt, _ in pad.alts}
+        for alt, signal in pad.alts:
+            if gpio_signal := parse_gpio_signal(signal):
+                registers.setdefault("GPIO", []).append(GpioImpl(alt, *gpio_signal))
+        pads[name] = registers
+    return pads
+
+
+def pad_name(name):
+    """Returns a pad's name, without the 1176 SNVS registers' _DIG suffix."""
+    return name.removesuffix("_DIG")
+
+
 def extract_pads(iomuxc):
+    # Pads without a pad number, like the SNVS domain's WAKEUP, are grouped by
+    # their IOMUXC instance.
+    instance = iomuxc.find("./name").text.replace("IOMUXC", "").strip("_")
     base_address = int(iomuxc.find("./baseAddress").text, 16)
 
     # Collect MUX and PAD absolute register addresses.
@@ -57,7 +144,7 @@ def extract_pads(iomuxc):
     for register in iomuxc.findall("./registers/register"):
         name = register.find("./name").text
         if "SW_MUX_CTL_PAD_" in name:
-            name = name.replace("SW_MUX_CTL_PAD_", "")
+            name = pad_name(name.replace("SW_MUX_CTL_PAD_", ""))
             offset = int(register.find("./addressOffset").text, 16)
             pads[name]["MUX"] = base_address + offset
 
@@ -67,71 +154,90 @@ def extract_pads(iomuxc):
                 continue
 
             for alt in mux_field.findall("./enumeratedValues/enumeratedValue"):
+                alt_value = int(alt.find("./value").text, 16)
+                pads[name].setdefault("ALTS", set()).add(alt_value)
                 desc = alt.find("./description").text
-
-                # This is the path taken on nearly all i.MX RT variants...
-                if gpio_match := re.search("GPIO\d_IO\d{2}", desc):
-                    gpio_text = gpio_match.group(0)
-                    [gpio_module, gpio_offset] = re.findall("\d+", gpio_text)
-                    gpio_module = int(gpio_module)
-                    gpio_offset = int(gpio_offset)
-                # ...except for the 1010, which references "GPIO1_IOxx"
-                # as "GPIOMUX_IOxx".
-                elif gpio_match := re.search("GPIOMUX_IO\d{2}", desc):
-                    gpio_text = gpio_match.group(0)
-                    [gpio_offset] = re.findall("\d+", gpio_text)
-                    gpio_module = 1
-                    gpio_offset = int(gpio_offset)
-                # But wait! The 1176 SVD has a third form, "GPIO_MUXx_IOyz",
-                # which is mixed with the first form...
-                elif gpio_match := re.search("GPIO_MUX\d_IO\d", desc):
-                    gpio_text = gpio_match.group(0)
-                    [gpio_module, gpio_offset] = re.findall("\d+", gpio_text)
-                    gpio_module = int(gpio_module)
-                    gpio_offset = int(gpio_offset)
-                else:
+                gpio_signal = parse_gpio_signal(desc)
+                if gpio_signal is None:
                     # There's no (expected) GPIO alt. This path is handled
                     # later during code generation.
                     continue
-                alt_value = int(alt.find("./value").text, 16)
                 gpio_impls = pads[name].get("GPIO", [])
-                gpio_impls.append(GpioImpl(alt_value, gpio_module, gpio_offset))
+                gpio_impls.append(GpioImpl(alt_value, *gpio_signal))
                 pads[name]["GPIO"] = gpio_impls
 
         elif "SW_PAD_CTL_PAD_" in name:
-            name = name.replace("SW_PAD_CTL_PAD_", "")
+            name = pad_name(name.replace("SW_PAD_CTL_PAD_", ""))
             offset = int(register.find("./addressOffset").text, 16)
             pads[name]["PAD"] = base_address + offset
 
-    # Sanity check.
     for name, registers in pads.items():
-        assert "PAD" in registers and "MUX" in registers
-
+        registers["INSTANCE"] = instance or "IOMUXC"
     return pads
 
 
-def iomuxc(path):
+def svd_pads(path):
     tree = ET.parse(path)
     root = tree.getroot()
     iomuxc = root.find("./peripherals/peripheral[name='IOMUXC']")
-    iomuxc_lpsr = root.find("./peripherals/peripheral[name='IOMUXC_LPSR']")
-
     pads = extract_pads(iomuxc)
-    if iomuxc_lpsr:
-        pads |= extract_pads(iomuxc_lpsr)
+    # The LPSR and SNVS instances have their own register layouts. Their
+    # addresses are absolute, like the IOMUXC's, and some of their pads have
+    # no MUX register.
+    for name in ("IOMUXC_LPSR", "IOMUXC_SNVS"):
+        instance = root.find(f"./peripherals/peripheral[name='{name}']")
+        if instance:
+            pads |= extract_pads(instance)
+    return pads
+
+
+def pad_group(name, registers):
+    """Returns a pad's group, and its field name in the group's Pads."""
+    numbered = re.fullmatch(r".+_\d{2}", name)
+    field = f"p{name[-len('01'):]}" if numbered else name.lower()
+    if "GROUP" in registers:
+        return registers["GROUP"], field
+    if numbered:
+        return name[: -len("_01")], field
+    return registers["INSTANCE"], field
+
+
+def iomuxc(path, chip):
+    if path.endswith(".toml"):
+        pads = describe_pads(path)
+    else:
+        pads = svd_pads(path)
+
+    # Pads without both registers can't be a Pad type.
+    incomplete = [
+        name for name, registers in pads.items()
+        if "MUX" not in registers or "PAD" not in registers
+    ]
+    for name in incomplete:
+        del pads[name]
 
     # Create pad groups.
     groups = defaultdict(list)
-    for name in pads.keys():
-        group = name[: -len("_01")]
+    fields = {}
+    for name, registers in pads.items():
+        group, fields[name] = pad_group(name, registers)
         groups[group].append(name)
 
+    def cfg(group):
+        feature = f"{chip}-{group.lower().replace('_', '-')}"
+        return f'#[cfg(feature = "{feature}")]'
+
     # Generate Rust modules
     print("// Generated by iomuxc.py")
     print("#![allow(non_camel_case_types)]")
     print()
+    for name in sorted(incomplete):
+        print(f"// {name} does not have both MUX and PAD registers.")
+    if incomplete:
+        print()
     for group, pad_names in groups.items():
         print(f"/// Pads with the prefix {group}.")
+        print(cfg(group))
         print(f"pub mod {group.lower()} {{")
         print()
 
@@ -147,6 +253,9 @@ def iomuxc(path):
             print(f"const {mux_reg_name}: u32 = {mux_addr};")
             print(f"const {pad_reg_name}: u32 = {pad_addr};")
             print(f"pub type {pad_name} = crate::Pad<{mux_reg_name}, {pad_reg_name}>;")
+            # Input-only pads don't implement OutputCapable.
+            if not registers.get("INPUT_ONLY", pad_name in INPUT_ONLY):
+                print(f"impl crate::OutputCapable for {pad_name} {{}}")
 
             # impl gpio::Pin
             for gpio_impl in registers.get("GPIO", []):
@@ -157,14 +266,15 @@ def iomuxc(path):
                 print("}")
             if registers.get("GPIO") is None:
                 print(f"// {pad_name} does not have any GPIO alternates.")
+            for alt in sorted(registers.get("ALTS", ())):
+                print(f"impl crate::AnyAltPin<{alt}> for {pad_name} {{}}")
             print()
 
         # Pads struct
         print(f"/// All pads with prefix {group}.")
         print("pub struct Pads {")
         for pad_name in pad_names:
-            pad_number = pad_name[-len("01") :]
-            print(f"pub p{pad_number}: {pad_name},")
+            print(f"pub {fields[pad_name]}: {pad_name},")
         print("}")
 
         # ErasedPads
@@ -179,25 +289,37 @@ def iomuxc(path):
         print(NEW_DOCSTRING)
         print("#[inline] pub const unsafe fn new() -> Self { Self {")
         for pad_name in pad_names:
-            pad_number = pad_name[-len("01") :]
-            print(f"p{pad_number}: {pad_name}::new(),")
+            print(f"{fields[pad_name]}: {pad_name}::new(),")
         print("} }")
 
         print(ERASE_DOCSTRING)
         print("#[inline] pub const fn erase(self) -> ErasedPads { [")
         for pad_name in sorted(pad_names):
-            pad_number = pad_name[-len("01") :]
-            print(f"self.p{pad_number}.erase(),")
+            print(f"self.{fields[pad_name]}.erase(),")
         print("] }")
         print("}")
 
+        print("impl ::core::convert::TryFrom<ErasedPads> for Pads {")
+        print("type Error = ErasedPads;")
+        print(GROUP_TRY_FROM_DOCSTRING)
+        print("fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {")
+        print("if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {")
+        print("// Safety: the erased pads represent ownership of this group.")
+        print("Ok(unsafe { Self::new() })")
+        print("} else { Err(erased) }")
+        print("} }")
+
         print("}")
         print()
 
     # Generate top-level Pads struct
     print("/// All of the pads.")
+    print("///")
+    print("/// The fields are public, so you can move groups out of `Pads`, and regroup")
+    print("/// them with a struct literal. To regroup erased pads, use `TryFrom`.")
     print("pub struct Pads {")
     for group in groups.keys():
+        print(cfg(group))
         print(f"pub {group.lower()}: {group.lower()}::Pads,")
     print("}")
     print()
@@ -206,11 +328,13 @@ def iomuxc(path):
     print(NEW_DOCSTRING)
     print("#[inline] pub const unsafe fn new() -> Self { Self {")
     for group in groups.keys():
+        print(cfg(group))
         print(f"{group.lower()}: {group.lower()}::Pads::new(),")
     print("} }")
     print(ERASE_DOCSTRING)
     print("#[inline] pub const fn erase(self) -> ErasedPads { ErasedPads {")
     for group in groups.keys():
+        print(cfg(group))
         print(f"{group.lower()}: self.{group.lower()}.erase(),")
     print("} }")
     print("}")
@@ -220,9 +344,47 @@ def iomuxc(path):
     print("/// All erased pads.")
     print("pub struct ErasedPads {")
     for group in groups.keys():
+        print(cfg(group))
         print(f"pub {group.lower()}: {group.lower()}::ErasedPads,")
     print("}")
+    print()
+
+    print("impl ::core::convert::TryFrom<ErasedPads> for Pads {")
+    print("type Error = ErasedPads;")
+    print(TRY_FROM_DOCSTRING)
+    print("fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {")
+    print("let expected = unsafe { Self::new() }.erase();")
+    print("let same: &[bool] = &[")
+    for group in groups.keys():
+        print(cfg(group))
+        print(f"crate::same_pads(&erased.{group.lower()}, &expected.{group.lower()}),")
+    print("];")
+    print("if same.iter().all(|same| *same) {")
+    print("// Safety: the erased pads represent ownership of all pads.")
+    print("Ok(unsafe { Self::new() })")
+    print("} else { Err(erased) }")
+    print("} }")
+    print()
+
+    print("impl ErasedPads {")
+    print(GET_DOCSTRING)
+    print("pub fn get(&self, location: crate::PadLocation) -> Option<&crate::ErasedPad> {")
+    print("crate::find_pad([")
+    for group in groups.keys():
+        print(cfg(group))
+        print(f"&self.{group.lower()},")
+    print("], location)")
+    print("}")
+    print(GET_MUT_DOCSTRING)
+    print("pub fn get_mut(&mut self, location: crate::PadLocation) -> Option<&mut crate::ErasedPad> {")
+    print("crate::find_pad_mut([")
+    for group in groups.keys():
+        print(cfg(group))
+        print(f"&mut self.{group.lower()},")
+    print("], location)")
+    print("}")
+    print("}")
 
 
 if __name__ == "__main__":
-    iomuxc(sys.argv[1])
+    iomuxc(sys.argv[1], sys.argv[2])
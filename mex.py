@@ -0,0 +1,155 @@
+#!/usr/bin/env python3
+
+"""
+This script generates pad setup functions from an MCUXpresso Config Tools
+pin configuration (.mex) file. Each function in the pins tool becomes a Rust
+function that prepares and configures its pads through imxrt-iomuxc.
+
+Usage:
+
+    python3 mex.py path/to/board.mex imxrt1060 | rustfmt > path/to/output.rs
+
+The second argument is the chip module. Run the script from a build script, or
+check in its output. The generated functions take the chip's `Pads`.
+"""
+
+import re
+import sys
+import xml.etree.ElementTree as ET
+
+# Peripherals with a prepare() function that's selected by the pad. The
+# value is the imxrt-iomuxc module.
+PREPARE = {
+    "LPUART": "lpuart",
+    "LPI2C": "lpi2c",
+    "LPSPI": "lpspi",
+    "FLEXPWM": "flexpwm",
+    "FLEXSPI": "flexspi",
+    "USDHC": "usdhc",
+}
+
+SLEW_RATE = {"Slow": "Slow", "Fast": "Fast"}
+SPEED = {"MHZ_50": "Low", "MHZ_100_01": "Medium", "MHZ_100": "Fast", "MHZ_200": "Max"}
+DRIVE_STRENGTH = {
+    "Disabled": "Disabled",
+    "R0": "R0",
+    "R0_2": "R0_2",
+    "R0_3": "R0_3",
+    "R0_4": "R0_4",
+    "R0_5": "R0_5",
+    "R0_6": "R0_6",
+    "R0_7": "R0_7",
+}
+ENABLED = {"Enable": "Enabled", "Disable": "Disabled"}
+PULL = {
+    "Pull_Down_100K_Ohm": "Pulldown100k",
+    "Pull_Up_47K_Ohm": "Pullup47k",
+    "Pull_Up_100K_Ohm": "Pullup100k",
+    "Pull_Up_22K_Ohm": "Pullup22k",
+}
+
+
+def strip_namespaces(root):
+    for element in root.iter():
+        element.tag = element.tag.split("}")[-1]
+
+
+def snake_case(name):
+    name = re.sub(r"(?<=[a-z0-9])(?=[A-Z])", "_", name)
+    return re.sub(r"_+", "_", name).lower()
+
+
+def pad_path(pin_signal):
+    group, number = pin_signal.rsplit("_", 1)
+    return f"pads.{group.lower()}.p{number}"
+
+
+def prepare(peripheral, pad):
+    """Returns the statement that prepares the pad for the peripheral, or None."""
+    match = re.fullmatch(r"([A-Z0-9]*?[A-Z])(\d*)", peripheral)
+    if match is None:
+        return None
+    name, number = match.groups()
+    if name in PREPARE:
+        return f"imxrt_iomuxc::{PREPARE[name]}::prepare(&mut {pad});"
+    if name == "GPIO" and number:
+        return f"imxrt_iomuxc::gpio::prepare::<_, {number}>(&mut {pad});"
+    if name == "ADC" and number:
+        return f"imxrt_iomuxc::adc::prepare::<_, {number}>(&mut {pad});"
+    if name == "SAI" and number:
+        return f"imxrt_iomuxc::sai::prepare::<imxrt_iomuxc::consts::Const<{number}>, _>(&mut {pad});"
+    return None
+
+
+def config(features):
+    """Returns the Config expression for the pin features, or None."""
+    setters = []
+    if "hysteresis_enable" in features:
+        setters.append(
+            f"set_hysteresis(imxrt_iomuxc::Hysteresis::{ENABLED[features['hysteresis_enable']]})"
+        )
+    if "pull_keeper_enable" in features or "pull_keeper_select" in features:
+        if features.get("pull_keeper_enable", "Enable") == "Disable":
+            pull_keeper = "None"
+        elif features.get("pull_keeper_select", "Keeper") == "Keeper":
+            pull_keeper = "Some(imxrt_iomuxc::PullKeeper::Keeper)"
+        else:
+            pull = PULL[features.get("pull_up_down_config", "Pull_Down_100K_Ohm")]
+            pull_keeper = f"Some(imxrt_iomuxc::PullKeeper::{pull})"
+        setters.append(f"set_pull_keeper({pull_keeper})")
+    if "open_drain" in features:
+        setters.append(
+            f"set_open_drain(imxrt_iomuxc::OpenDrain::{ENABLED[features['open_drain']]})"
+        )
+    if "speed" in features:
+        setters.append(f"set_speed(imxrt_iomuxc::Speed::{SPEED[features['speed']]})")
+    if "drive_strength" in features:
+        setters.append(
+            f"set_drive_strength(imxrt_iomuxc::DriveStrength::{DRIVE_STRENGTH[features['drive_strength']]})"
+        )
+    if "slew_rate" in features:
+        setters.append(
+            f"set_slew_rate(imxrt_iomuxc::SlewRate::{SLEW_RATE[features['slew_rate']]})"
+        )
+    if not setters:
+        return None
+    return "imxrt_iomuxc::Config::modify()." + ".".join(setters)
+
+
+def mex(path, chip):
+    tree = ET.parse(path)
+    root = tree.getroot()
+    strip_namespaces(root)
+
+    print("// Generated by mex.py")
+    print(f"use imxrt_iomuxc::{chip}::Pads;")
+    print()
+    for function in root.iter("function"):
+        name = function.get("name")
+        print(f"/// Applies the `{name}` pin configuration")
+        print(f"pub fn {snake_case(name)}(pads: &mut Pads) {{")
+        for pin in function.iter("pin"):
+            peripheral = pin.get("peripheral")
+            signal = pin.get("signal")
+            pad = pad_path(pin.get("pin_signal"))
+            features = {
+                feature.get("name"): feature.get("value")
+                for feature in pin.iter("pin_feature")
+            }
+            print(f"// {peripheral} {signal}")
+            statement = prepare(peripheral, pad)
+            if statement is None:
+                print(f"// {peripheral} does not have a prepare() function.")
+            else:
+                print(statement)
+            if features.get("software_input_on") == "Enable":
+                print(f"imxrt_iomuxc::set_sion(&mut {pad});")
+            cfg = config(features)
+            if cfg is not None:
+                print(f"imxrt_iomuxc::configure(&mut {pad}, {cfg});")
+        print("}")
+        print()
+
+
+if __name__ == "__main__":
+    mex(sys.argv[1], sys.argv[2])
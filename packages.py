@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+"""
+This script generates package pin aliases from a TOML pad description (see
+description.py). Each package becomes a module of type aliases that name the
+chip's pads by ball, like K14, or by pin number, like P72.
+
+Usage:
+
+    python3 packages.py path/to/pads.toml imxrt1060 | rustfmt > path/to/packages.rs
+
+The second argument is the chip feature. Each alias is gated on its pad's
+block feature. Include the output in the chip's module.
+"""
+
+import sys
+from collections import defaultdict
+
+import description
+from iomuxc import pad_group
+
+
+def pin_name(pin):
+    if isinstance(pin, int):
+        return f"P{pin}"
+    return pin.upper()
+
+
+def packages(path, chip):
+    pads, _ = description.load(path)
+    aliases = defaultdict(dict)
+    for name, pad in pads.items():
+        for package, pin in pad.pins.items():
+            pin = pin_name(pin)
+            if pin in aliases[package]:
+                raise ValueError(
+                    f"{package} {pin} names both {aliases[package][pin]} and {name}"
+                )
+            aliases[package][pin] = name
+
+    def group_of(name):
+        pad = pads[name]
+        registers = {"INSTANCE": "IOMUXC"}
+        if pad.group is not None:
+            registers["GROUP"] = pad.group
+        return pad_group(name, registers)[0]
+
+    print("// Generated by packages.py")
+    print()
+    for package, pins in sorted(aliases.items()):
+        print(f"/// Pads of the {package} package, named by ball or pin.")
+        print(f"pub mod {package.lower()} {{")
+        for pin, name in sorted(pins.items()):
+            group = group_of(name)
+            feature = f"{chip}-{group.lower().replace('_', '-')}"
+            print(f"/// {package} {pin}")
+            print(f'#[cfg(feature = "{feature}")]')
+            print(f"pub type {pin} = super::{group.lower()}::{name};")
+        print("}")
+        print()
+
+
+if __name__ == "__main__":
+    packages(sys.argv[1], sys.argv[2])
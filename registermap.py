@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+"""
+This script generates a chip's REGISTER_MAP, a const table of the chip's pad
+registers. It reads the chip's module in this package.
+
+Usage:
+
+    python3 registermap.py imxrt1060 | rustfmt > src/imxrt1060/register_map.rs
+"""
+
+import pathlib
+import re
+import sys
+
+from cheader import DAISY, REGISTER
+
+DOCSTRING = """
+/// All pads, and their registers
+///
+/// Each entry lists the daisy values that select the pad. The table doesn't
+/// know the pads' reset values."""
+
+
+def pad_for_daisy(name, pads):
+    """Returns the pad that a daisy constant selects."""
+    matches = [pad for pad in pads if name.endswith("_" + pad)]
+    if not matches:
+        # Some daisy names abbreviate the pad name's prefix.
+        matches = [pad for pad in pads if name.endswith(pad[len("GPIO") :])]
+    return max(matches, key=len)
+
+
+def registermap(chip):
+    root = pathlib.Path(__file__).parent / "src" / chip
+
+    registers = {}
+    for pad, kind, addr in REGISTER.findall((root / "pads.rs").read_text()):
+        registers.setdefault(pad, {})[kind] = addr
+
+    daisies = {pad: [] for pad in registers}
+    for path in sorted(root.glob("*.rs")):
+        for name, reg, value in DAISY.findall(path.read_text()):
+            daisies[pad_for_daisy(name, registers)].append((reg, value))
+
+    print("// Generated by registermap.py")
+    print("use crate::register_map::{DaisyEntry, PadRegisters};")
+    print(DOCSTRING)
+    print("pub const REGISTER_MAP: &[PadRegisters] = &[")
+    for pad, regs in registers.items():
+        print("PadRegisters {")
+        print(f'name: "{pad}",')
+        print(f"mux: {regs['MUX']},")
+        print(f"pad: {regs['PAD']},")
+        print("reset: None,")
+        print("daisies: &[")
+        for reg, value in daisies[pad]:
+            print(f"DaisyEntry {{ reg: {reg}, value: {value} }},")
+        print("],")
+        print("},")
+    print("];")
+
+
+if __name__ == "__main__":
+    registermap(sys.argv[1])
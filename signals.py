@@ -0,0 +1,122 @@
+#!/usr/bin/env python3
+
+"""
+This script generates a chip's signal database from an i.MX RT SVD file, or
+from a TOML pad description (see description.py). The database has every
+alternate of every pad, and every select input value, even for peripherals
+that don't have pin traits.
+
+Usage:
+
+    python3 signals.py path/to/imxrt1062.svd | rustfmt > src/imxrt1060/signals.rs
+
+Include the output as a chip module, and re-export its DATABASE.
+"""
+
+import re
+import sys
+import xml.etree.ElementTree as ET
+
+import description
+
+MUX_PORT = re.compile(r"mux port:\s*(\S+)")
+
+
+def svd_database(path):
+    """Returns the pad locations, alternates, and select inputs of an SVD."""
+    root = ET.parse(path).getroot()
+    locations = {}
+    alternates = []
+    inputs = []
+    for name in ("IOMUXC", "IOMUXC_LPSR", "IOMUXC_SNVS"):
+        iomuxc = root.find(f"./peripherals/peripheral[name='{name}']")
+        if iomuxc is None:
+            continue
+        base_address = int(iomuxc.find("./baseAddress").text, 16)
+        for register in iomuxc.findall("./registers/register"):
+            name = register.find("./name").text
+            address = base_address + int(register.find("./addressOffset").text, 16)
+            values = register.findall("./fields/field/enumeratedValues/enumeratedValue")
+            # The 1176's SNVS pad registers end in _DIG; the pads don't.
+            if "SW_MUX_CTL_PAD_" in name:
+                pad = name.replace("SW_MUX_CTL_PAD_", "").removesuffix("_DIG")
+                locations.setdefault(pad, {})["MUX"] = address
+                mux_field = register.find("./fields/field[name='MUX_MODE']")
+                if mux_field is None:
+                    continue
+                for alt in mux_field.findall("./enumeratedValues/enumeratedValue"):
+                    if match := MUX_PORT.search(alt.find("./description").text):
+                        value = int(alt.find("./value").text, 16)
+                        alternates.append((pad, value, match.group(1)))
+            elif "SW_PAD_CTL_PAD_" in name:
+                pad = name.replace("SW_PAD_CTL_PAD_", "").removesuffix("_DIG")
+                locations.setdefault(pad, {})["PAD"] = address
+            elif "_SELECT_INPUT" in name:
+                input = name.replace("_SELECT_INPUT", "")
+                for value in values:
+                    pad = value.find("./name").text
+                    if "_ALT" in pad:
+                        pad = pad[:-5]  # _ALTx
+                    # The 1176's values start with SELECT_.
+                    pad = pad.removeprefix("SELECT_")
+                    daisy = int(value.find("./value").text, 16)
+                    inputs.append((input, address, pad, daisy))
+    # Pads without both registers have no PadLocation.
+    locations = {
+        pad: registers
+        for pad, registers in locations.items()
+        if "MUX" in registers and "PAD" in registers
+    }
+    alternates = [alt for alt in alternates if alt[0] in locations]
+    return locations, alternates, inputs
+
+
+def describe_database(path):
+    """Returns the pad locations, alternates, and select inputs of a description."""
+    pads, daisies = description.load(path)
+    locations = {name: {"MUX": pad.mux, "PAD": pad.pad} for name, pad in pads.items()}
+    alternates = [
+        (name, alt, signal) for name, pad in pads.items() for alt, signal in pad.alts
+    ]
+    inputs = [
+        (daisy.register, daisy.address, pad, value)
+        for daisy in daisies
+        for pad, value in daisy.inputs
+    ]
+    return locations, alternates, inputs
+
+
+def signals(path):
+    if path.endswith(".toml"):
+        locations, alternates, inputs = describe_database(path)
+    else:
+        locations, alternates, inputs = svd_database(path)
+
+    def location(pad):
+        registers = locations[pad]
+        return f"crate::PadLocation {{ mux: {registers['MUX']:#010x}, pad: {registers['PAD']:#010x} }}"
+
+    print("// Generated by signals.py")
+    print()
+    print("use crate::signals::{Alternate, Database, SelectInput};")
+    print()
+    print("/// Every alternate and select input of the chip.")
+    print("pub const DATABASE: Database = Database {")
+    print("alternates: &[")
+    for pad, alt, signal in alternates:
+        print(f'Alternate {{ pad: {location(pad)}, alt: {alt}, signal: "{signal}" }},')
+    print("],")
+    print("inputs: &[")
+    for input, address, pad, value in inputs:
+        # Some select inputs name signals, not pads.
+        if pad not in locations:
+            continue
+        print(
+            f'SelectInput {{ input: "{input}", register: {address:#010x}, pad: {location(pad)}, value: {value} }},'
+        )
+    print("],")
+    print("};")
+
+
+if __name__ == "__main__":
+    signals(sys.argv[1])
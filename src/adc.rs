@@ -7,29 +7,110 @@ pub const ADC2: u8 = 2;
 ///
 /// Some pads may be used in both `ADC1` and `ADC2`, so implementations
 /// indicate their compatibility by supplying a constant `N`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an input for ADC{N}",
+    label = "this pad is not connected to ADC{N}",
+    note = "the `adc::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin<const N: u8>: super::Iomuxc {
     /// The input pin identifier
     ///
     /// Starts at `0`, and increments up.
     const INPUT: u32;
+    /// The ADC input channel; `8` for `ADC2_IN8`
+    ///
+    /// Write this to the channel select field (`ADCH`) of a conversion
+    /// control register.
+    const CHANNEL: u8 = Self::INPUT as u8;
+    /// The ADC instance number; `2` for `ADC2`
+    const MODULE: u8 = N;
+}
+
+impl<P: Pin<N>, const N: u8> Pin<N> for &mut P {
+    const INPUT: u32 = P::INPUT;
 }
 
+/// The pad configuration for an ADC input
+///
+/// Disables the pull/keeper and the input hysteresis. Other fields keep their
+/// values.
+pub(crate) const CONFIG: super::Config = super::Config::modify()
+    .set_pull_keeper(None)
+    .set_hysteresis(super::Hysteresis::Disabled);
+
 /// Prepare an ADC pin
 ///
 /// Due to a requirement in the ADC module, `prepare` will disable the pull/keeper
-/// on the pin. The configuration change will not affect any other settings.
+/// and the hysteresis on the pin. The configuration change will not affect any
+/// other settings.
 pub fn prepare<P: Pin<N>, const N: u8>(pin: &mut P) {
     // See the note in the ADC section of the reference manual
     // (using iMXRT1060, rev 2). ADC input signals connect to
     // GPIO, and we need to disable the keeper to prevent signal
-    // jumps.
-    super::configure(pin, super::Config::modify().set_pull_keeper(None));
+    // jumps. The hysteresis comparator also loads the analog input.
+    super::configure(pin, CONFIG);
     // Not putting the ADC into the GPIO alternate. Reference
     // manuals indicate that the alt (mode) doesn't matter. We're
     // expecting that the GPIO input path is implicit, regardless
     // of the alt.
 }
 
+/// A pad that connects to both `ADC1` and `ADC2`
+///
+/// Both ADCs can sample the pad. Every pad that implements `Pin<1>` and
+/// `Pin<2>` implements `SharedPin`.
+pub trait SharedPin: Pin<ADC1> + Pin<ADC2> {}
+
+impl<P: Pin<ADC1> + Pin<ADC2>> SharedPin for P {}
+
+/// An `ADC1` input and an `ADC2` input that sample together
+///
+/// In synchronous mode, the ADC external trigger control (ADC_ETC) starts
+/// both ADCs with a single trigger. The type checks that `adc1` is an `ADC1`
+/// input, and `adc2` is an `ADC2` input. The ADC inputs are single ended;
+/// there are no differential pairs.
+///
+/// ```
+/// use imxrt_iomuxc::adc::SyncPair;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b1::{GPIO_AD_B1_02, GPIO_AD_B1_03};
+///
+/// type Pair = SyncPair<GPIO_AD_B1_02, GPIO_AD_B1_03>;
+/// assert_eq!(Pair::CHANNELS, (7, 8));
+/// ```
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::adc::SyncPair;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// // GPIO_AD_B0_13 is not an ADC2 input.
+/// type Pair = SyncPair<GPIO_AD_B0_12, GPIO_AD_B0_13>;
+/// let _ = Pair::CHANNELS;
+/// ```
+#[derive(Debug)]
+pub struct SyncPair<A, B> {
+    /// The `ADC1` input
+    pub adc1: A,
+    /// The `ADC2` input
+    pub adc2: B,
+}
+
+impl<A: Pin<ADC1>, B: Pin<ADC2>> SyncPair<A, B> {
+    /// The `ADC1` and `ADC2` input channels
+    pub const CHANNELS: (u8, u8) = (A::CHANNEL, B::CHANNEL);
+
+    /// Pair the ADC inputs
+    pub const fn new(adc1: A, adc2: B) -> Self {
+        Self { adc1, adc2 }
+    }
+
+    /// Prepare both ADC inputs
+    pub fn prepare(&mut self) {
+        prepare(&mut self.adc1);
+        prepare(&mut self.adc2);
+    }
+}
+
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! adc {
     (module: $module:expr, pad: $pad:ty, input: $input:expr) => {
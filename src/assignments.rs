@@ -0,0 +1,271 @@
+//! Pin assignment manifests
+//!
+//! A manifest assigns signals to pads, one per line:
+//!
+//! ```text
+//! # Console
+//! LPUART1_TX = GPIO_AD_B0_12
+//! LPUART1_RX = GPIO_AD_B0_13
+//! ```
+//!
+//! Blank lines, and text after a `#`, are ignored. [`check()`] rejects a
+//! manifest that assigns two signals to one pad, that assigns a signal to a
+//! pad without that alternate, or that routes two pads to one select input.
+//! Call it from a build script to catch the conflicts before flashing:
+//!
+//! ```no_run
+//! // build.rs
+//! use imxrt_iomuxc::{assignments, imxrt1060};
+//!
+//! let manifest = std::fs::read_to_string("pins.txt").unwrap();
+//! if let Err(err) = assignments::check(&manifest, imxrt1060::REGISTER_MAP, &imxrt1060::DATABASE) {
+//!     panic!("pins.txt: {err}");
+//! }
+//! println!("cargo::rerun-if-changed=pins.txt");
+//! ```
+//!
+//! The `map` names the pads, and the signal [`Database`] describes their
+//! alternates and select inputs. A signal uses the select input of the same
+//! name, like `LPUART1_RX`.
+
+use crate::{register_map::PadRegisters, signals::Database, PadLocation};
+use core::fmt;
+
+/// A manifest error
+///
+/// Lines are numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<'a> {
+    /// The line isn't a `SIGNAL = PAD` assignment
+    Syntax {
+        /// The line number
+        line: usize,
+    },
+    /// The map doesn't have the pad
+    UnknownPad {
+        /// The line number
+        line: usize,
+        /// The pad name
+        pad: &'a str,
+    },
+    /// The pad doesn't have an alternate for the signal
+    NoAlternate {
+        /// The line number
+        line: usize,
+        /// The signal name
+        signal: &'a str,
+        /// The pad name
+        pad: &'a str,
+    },
+    /// Two signals claim the same pad
+    SharedPad {
+        /// The line number
+        line: usize,
+        /// The pad name
+        pad: &'a str,
+        /// The line that first claimed the pad
+        first: usize,
+    },
+    /// Two pads feed the same select input
+    SharedInput {
+        /// The line number
+        line: usize,
+        /// The select input name
+        input: &'static str,
+        /// The line that first routed a pad to the input
+        first: usize,
+    },
+}
+
+impl fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Syntax { line } => write!(f, "line {line}: expected 'SIGNAL = PAD'"),
+            Error::UnknownPad { line, pad } => write!(f, "line {line}: unknown pad {pad}"),
+            Error::NoAlternate { line, signal, pad } => {
+                write!(f, "line {line}: {pad} can't carry {signal}")
+            }
+            Error::SharedPad { line, pad, first } => {
+                write!(f, "line {line}: {pad} is already assigned on line {first}")
+            }
+            Error::SharedInput { line, input, first } => write!(
+                f,
+                "line {line}: the {input} select input already has a pad on line {first}"
+            ),
+        }
+    }
+}
+
+/// One parsed assignment
+struct Assignment<'a> {
+    line: usize,
+    signal: &'a str,
+    pad: &'a str,
+}
+
+/// Returns the manifest's assignments, in order
+fn parse(manifest: &str) -> impl Iterator<Item = Result<Assignment<'_>, Error<'_>>> {
+    manifest.lines().enumerate().filter_map(|(idx, text)| {
+        let line = idx + 1;
+        let text = text.split('#').next().unwrap_or_default().trim();
+        if text.is_empty() {
+            return None;
+        }
+        let assignment = text
+            .split_once('=')
+            .map(|(signal, pad)| (signal.trim(), pad.trim()))
+            .filter(|(signal, pad)| !signal.is_empty() && !pad.is_empty())
+            .map(|(signal, pad)| Assignment { line, signal, pad })
+            .ok_or(Error::Syntax { line });
+        Some(assignment)
+    })
+}
+
+/// Check a manifest
+///
+/// Returns the first error, by line. Checking doesn't allocate, so you may also
+/// call it on the target.
+pub fn check<'a>(
+    manifest: &'a str,
+    map: &[PadRegisters],
+    database: &Database,
+) -> Result<(), Error<'a>> {
+    let location = |pad: &str| {
+        map.iter()
+            .find(|registers| registers.name == pad)
+            .map(|registers| PadLocation {
+                mux: registers.mux,
+                pad: registers.pad,
+            })
+    };
+    for (idx, assignment) in parse(manifest).enumerate() {
+        let Assignment { line, signal, pad } = assignment?;
+        let here = location(pad).ok_or(Error::UnknownPad { line, pad })?;
+        if database.find_alternate(here, signal).is_none() {
+            return Err(Error::NoAlternate { line, signal, pad });
+        }
+        let input = database.find_input(here, signal);
+        // Earlier lines are already checked, so they parse.
+        for earlier in parse(manifest).take(idx).flatten() {
+            if earlier.pad == pad {
+                return Err(Error::SharedPad {
+                    line,
+                    pad,
+                    first: earlier.line,
+                });
+            }
+            let earlier_input =
+                location(earlier.pad).and_then(|there| database.find_input(there, earlier.signal));
+            if let (Some(input), Some(earlier_input)) = (input, earlier_input) {
+                if input.register == earlier_input.register {
+                    return Err(Error::SharedInput {
+                        line,
+                        input: input.input,
+                        first: earlier.line,
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Error};
+    use crate::register_map::PadRegisters;
+    use crate::signals::{Alternate, Database, SelectInput};
+    use crate::PadLocation;
+
+    const A: PadLocation = PadLocation { mux: 4, pad: 8 };
+    const B: PadLocation = PadLocation { mux: 12, pad: 16 };
+
+    const MAP: &[PadRegisters] = &[
+        PadRegisters {
+            name: "PAD_A",
+            mux: 4,
+            pad: 8,
+            reset: None,
+            daisies: &[],
+        },
+        PadRegisters {
+            name: "PAD_B",
+            mux: 12,
+            pad: 16,
+            reset: None,
+            daisies: &[],
+        },
+    ];
+
+    const DATABASE: Database = Database {
+        alternates: &[
+            Alternate {
+                pad: A,
+                alt: 2,
+                signal: "LPUART1_RX",
+            },
+            Alternate {
+                pad: A,
+                alt: 2,
+                signal: "LPUART1_TX",
+            },
+            Alternate {
+                pad: B,
+                alt: 3,
+                signal: "LPUART1_RX",
+            },
+        ],
+        inputs: &[
+            SelectInput {
+                input: "LPUART1_RX",
+                register: 0x100,
+                pad: A,
+                value: 0,
+            },
+            SelectInput {
+                input: "LPUART1_RX",
+                register: 0x100,
+                pad: B,
+                value: 1,
+            },
+        ],
+    };
+
+    #[test]
+    fn conflicts() {
+        let check = |manifest| check(manifest, MAP, &DATABASE);
+        assert_eq!(check("# Console\n\nLPUART1_RX = PAD_B # RX\n"), Ok(()));
+        assert_eq!(check("LPUART1_RX PAD_B"), Err(Error::Syntax { line: 1 }));
+        assert_eq!(
+            check("LPUART1_RX = PAD_C"),
+            Err(Error::UnknownPad {
+                line: 1,
+                pad: "PAD_C"
+            })
+        );
+        assert_eq!(
+            check("LPUART1_TX = PAD_B"),
+            Err(Error::NoAlternate {
+                line: 1,
+                signal: "LPUART1_TX",
+                pad: "PAD_B"
+            })
+        );
+        assert_eq!(
+            check("LPUART1_TX = PAD_A\nLPUART1_RX = PAD_A"),
+            Err(Error::SharedPad {
+                line: 2,
+                pad: "PAD_A",
+                first: 1
+            })
+        );
+        assert_eq!(
+            check("LPUART1_RX = PAD_A\n\nLPUART1_RX = PAD_B"),
+            Err(Error::SharedInput {
+                line: 3,
+                input: "LPUART1_RX",
+                first: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Board pin definitions
+
+use crate::ErasedPad;
+
+/// The MUX and PAD register addresses of a pad
+///
+/// Unlike a pad, a `PadLocation` is `Copy` and `Sync`, so you may put it in
+/// a `const` table. Board modules use these tables to select pads by pin number
+/// at run time.
+///
+/// Locations compare, order, and hash by their MUX address, then their PAD
+/// address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "remote", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PadLocation {
+    /// The MUX register address
+    pub mux: u32,
+    /// The PAD register address
+    pub pad: u32,
+}
+
+impl PadLocation {
+    /// Returns the pad's stable numeric ID
+    ///
+    /// The ID is the MUX register address. The hardware fixes the address, so
+    /// a chip's pads have unique IDs that don't change across firmware or
+    /// package versions. Persist the ID to refer to a pad, and compare it with
+    /// the IDs of a chip's `REGISTER_MAP` to find the pad again.
+    #[inline(always)]
+    pub const fn id(&self) -> u32 {
+        self.mux
+    }
+
+    /// Create an erased pad at this location
+    ///
+    /// # Safety
+    ///
+    /// This creates a handle to the pad. The same rules as [`Pad::new()`](crate::Pad::new)
+    /// apply.
+    #[inline(always)]
+    pub const unsafe fn erase(self) -> ErasedPad {
+        ErasedPad::new(self.mux as *mut u32, self.pad as *mut u32)
+    }
+}
+
+/// Define a struct of board pins
+///
+/// Each field names a board pin, and selects its pad from the chip's `Pads`.
+/// The macro generates
+///
+/// - `from_pads()`, which takes the pins from the chip's pads. Pads that are not
+///   board pins are dropped.
+/// - `take()`, which takes the pins without the chip's pads.
+/// - `into_pads()`, which erases the pins, returning them in field order.
+/// - `LEN`, the number of pins.
+///
+/// Use `define_board_pins!` to describe your own board. The built-in `boards`
+/// modules use it for their `Pins`.
+///
+/// # Example
+///
+/// ```
+/// use imxrt_iomuxc::{define_board_pins, imxrt1060};
+///
+/// define_board_pins! {
+///     /// My board's pins
+///     pub struct Pins(imxrt1060::Pads) {
+///         /// The LED
+///         pub led: imxrt1060::gpio_b0::GPIO_B0_03 = gpio_b0.p03,
+///         /// The button
+///         pub button: imxrt1060::gpio_ad_b0::GPIO_AD_B0_02 = gpio_ad_b0.p02,
+///     }
+/// }
+///
+/// let pins = Pins::from_pads(unsafe { imxrt1060::Pads::new() });
+/// let erased = pins.into_pads();
+/// assert_eq!(erased.len(), Pins::LEN);
+/// ```
+#[macro_export]
+macro_rules! define_board_pins {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($pads:ty) {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $ty:ty = $group:ident . $pad:ident
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $name {
+            /// The number of pins
+            pub const LEN: usize = [$(stringify!($field)),*].len();
+
+            /// Take the pins from the chip's pads
+            ///
+            /// Pads that are not pins are dropped.
+            pub fn from_pads(pads: $pads) -> Self {
+                Self {
+                    $($field: pads.$group.$pad,)*
+                }
+            }
+
+            /// Take the pins
+            ///
+            /// This takes all of the chip's pads, and drops the pads that are
+            /// not pins.
+            ///
+            /// # Safety
+            ///
+            /// This creates the chip's pads. The same rules as the pads' `new()`
+            /// apply.
+            pub unsafe fn take() -> Self {
+                Self::from_pads(<$pads>::new())
+            }
+
+            /// Erase the pins, returning them in field order
+            pub fn into_pads(self) -> [$crate::ErasedPad; Self::LEN] {
+                [$(self.$field.erase()),*]
+            }
+        }
+
+        $($crate::__record_board_pin!($ty, stringify!($field));)*
+    };
+}
+
+/// Record a board pin in the pin map
+#[cfg(feature = "pin-map")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __record_board_pin {
+    ($pad:ty, $signal:expr) => {
+        $crate::record_pin!($pad, $signal);
+    };
+}
+
+/// Without the `pin-map` feature, board pins are not recorded
+#[cfg(not(feature = "pin-map"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __record_board_pin {
+    ($pad:ty, $signal:expr) => {};
+}
+
+/// Fail to compile if a pin assignment uses a pad twice
+///
+/// List the pad types of the application's whole pin assignment. The check
+/// runs during const evaluation, so a duplicate is a compile error, even if
+/// the pads are never created.
+///
+/// ```
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// imxrt_iomuxc::assert_unique_pads!(GPIO_AD_B0_12, GPIO_AD_B0_13);
+/// ```
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// // error: the pin assignment uses GPIO_AD_B0_12 more than once
+/// imxrt_iomuxc::assert_unique_pads!(GPIO_AD_B0_12, GPIO_AD_B0_13, GPIO_AD_B0_12);
+/// ```
+///
+/// For an assignment that's data, like a board's `PINS` table, pass the
+/// [`PadLocation`]s after `locations:`.
+///
+/// ```
+/// # #[cfg(feature = "teensy40")] {
+/// imxrt_iomuxc::assert_unique_pads!(locations: imxrt_iomuxc::boards::teensy40::PINS);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_unique_pads {
+    (locations: $locations:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            $crate::__private::first_duplicate(&$locations).is_none(),
+            "the pin assignment uses a pad more than once"
+        );
+    };
+    ($($pad:ty),+ $(,)?) => {
+        const _: () = {
+            const MESSAGES: &[&str] = &[$(::core::concat!(
+                "the pin assignment uses ",
+                ::core::stringify!($pad),
+                " more than once"
+            )),+];
+            if let ::core::option::Option::Some(idx) =
+                $crate::__private::first_duplicate(&[$(<$pad>::LOCATION),+])
+            {
+                ::core::panic!("{}", MESSAGES[idx]);
+            }
+        };
+    };
+}
+
+/// Returns the index of the first location that repeats an earlier location
+///
+/// Locations with the same MUX register are the same pad.
+#[doc(hidden)]
+pub const fn first_duplicate(locations: &[PadLocation]) -> Option<usize> {
+    let mut idx = 1;
+    while idx < locations.len() {
+        let mut earlier = 0;
+        while earlier < idx {
+            if locations[earlier].mux == locations[idx].mux {
+                return Some(idx);
+            }
+            earlier += 1;
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// A board's role pads
+///
+/// Implement `Board` to name the pads that a board uses for common roles, like
+/// the console UART or the LED. Firmware that's generic over a `Board` can be
+/// compiled for another board, or for custom hardware, by changing one type.
+/// The built-in `boards` modules implement `Board`.
+///
+/// ```
+/// use imxrt_iomuxc::{alternate_raw, lpuart, Board};
+///
+/// fn start<B: Board>() {
+///     let roles = unsafe { B::take() };
+///     let mut console = lpuart::Pins::new(roles.console_tx, roles.console_rx);
+///     console.prepare();
+///     let mut led = roles.led;
+///     alternate_raw(&mut led, B::LED_ALT);
+///     // Set GPIO LED_GPIO's LED_OFFSET bit to turn on the LED...
+/// }
+/// ```
+pub trait Board {
+    /// The console's transmit pad
+    type ConsoleTx: crate::lpuart::Pin<Direction = crate::lpuart::Tx>;
+    /// The console's receive pad, on the same LPUART as the transmit pad
+    type ConsoleRx: crate::lpuart::Pin<
+        Direction = crate::lpuart::Rx,
+        Module = <Self::ConsoleTx as crate::lpuart::Pin>::Module,
+    >;
+    /// The I2C clock pad
+    type I2cScl: crate::lpi2c::Pin<Signal = crate::lpi2c::Scl>;
+    /// The I2C data pad, on the same LPI2C as the clock pad
+    type I2cSda: crate::lpi2c::Pin<
+        Signal = crate::lpi2c::Sda,
+        Module = <Self::I2cScl as crate::lpi2c::Pin>::Module,
+    >;
+    /// The LED pad
+    type Led: crate::OutputCapable;
+
+    /// The LED's GPIO instance; `2` for `GPIO2`
+    const LED_GPIO: u8;
+    /// The LED's GPIO alternate
+    const LED_ALT: u32;
+    /// The LED's GPIO offset; `3` for `GPIO2_IO03`
+    const LED_OFFSET: u32;
+
+    /// Take the role pads
+    ///
+    /// # Safety
+    ///
+    /// This creates the role pads. The same rules as [`Pad::new()`](crate::Pad::new)
+    /// apply.
+    unsafe fn take() -> BoardRoles<Self>;
+}
+
+/// A board's role pads, from [`Board::take()`]
+pub struct BoardRoles<B: Board + ?Sized> {
+    /// The console's transmit pad
+    pub console_tx: B::ConsoleTx,
+    /// The console's receive pad
+    pub console_rx: B::ConsoleRx,
+    /// The I2C clock pad
+    pub i2c_scl: B::I2cScl,
+    /// The I2C data pad
+    pub i2c_sda: B::I2cSda,
+    /// The LED pad
+    pub led: B::Led,
+}
+
+/// A board's SD card pads
+///
+/// Only boards with an SD card slot implement `SdCard`.
+pub trait SdCard: Board {
+    /// The command pad
+    type SdCmd: crate::usdhc::Pin<Signal = crate::usdhc::Cmd>;
+    /// The clock pad
+    type SdClk: crate::usdhc::Pin<
+        Signal = crate::usdhc::Clk,
+        Module = <Self::SdCmd as crate::usdhc::Pin>::Module,
+    >;
+    /// The data 0 pad
+    type SdData0: crate::usdhc::Pin<
+        Signal = crate::usdhc::Data0,
+        Module = <Self::SdCmd as crate::usdhc::Pin>::Module,
+    >;
+    /// The data 1 pad
+    type SdData1: crate::usdhc::Pin<
+        Signal = crate::usdhc::Data1,
+        Module = <Self::SdCmd as crate::usdhc::Pin>::Module,
+    >;
+    /// The data 2 pad
+    type SdData2: crate::usdhc::Pin<
+        Signal = crate::usdhc::Data2,
+        Module = <Self::SdCmd as crate::usdhc::Pin>::Module,
+    >;
+    /// The data 3 pad
+    type SdData3: crate::usdhc::Pin<
+        Signal = crate::usdhc::Data3,
+        Module = <Self::SdCmd as crate::usdhc::Pin>::Module,
+    >;
+
+    /// Take the SD card pads
+    ///
+    /// # Safety
+    ///
+    /// This creates the SD card pads. The same rules as [`Pad::new()`](crate::Pad::new)
+    /// apply.
+    unsafe fn take_sd_card() -> SdCardRoles<Self>;
+}
+
+/// A board's SD card pads, from [`SdCard::take_sd_card()`]
+pub struct SdCardRoles<B: SdCard + ?Sized> {
+    /// The command pad
+    pub cmd: B::SdCmd,
+    /// The clock pad
+    pub clk: B::SdClk,
+    /// The data pads, 0 through 3
+    pub data: (B::SdData0, B::SdData1, B::SdData2, B::SdData3),
+}
+
+/// Implement [`Board`] for a built-in board
+#[allow(unused)]
+macro_rules! board_roles {
+    (
+        $board:ty,
+        console: ($tx:ty, $rx:ty),
+        i2c: ($scl:ty, $sda:ty),
+        led: ($led:ty, gpio: $gpio:literal) $(,)?
+    ) => {
+        impl crate::Board for $board {
+            type ConsoleTx = $tx;
+            type ConsoleRx = $rx;
+            type I2cScl = $scl;
+            type I2cSda = $sda;
+            type Led = $led;
+
+            const LED_GPIO: u8 = $gpio;
+            const LED_ALT: u32 = <$led as crate::gpio::Pin<$gpio>>::ALT;
+            const LED_OFFSET: u32 = <$led as crate::gpio::Pin<$gpio>>::OFFSET;
+
+            unsafe fn take() -> crate::BoardRoles<Self> {
+                crate::BoardRoles {
+                    console_tx: <$tx>::new(),
+                    console_rx: <$rx>::new(),
+                    i2c_scl: <$scl>::new(),
+                    i2c_sda: <$sda>::new(),
+                    led: <$led>::new(),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,49 @@
+//! MIMXRT1010-EVK pins
+//!
+//! The aliases name the i.MX RT 1011 pads by the EVK's labels, following the
+//! MCUXpresso SDK board files. They cover the user LED, the user button, and
+//! the debug console UART, LPUART1, which the EVK's debug probe bridges to
+//! USB.
+//!
+//! The aliases don't cover the Arduino header. Its pins share pads with the
+//! EVK's audio codec and serial flash, depending on the board's jumpers, so
+//! there is no `PINS` table, and no [`Board`](crate::Board) implementation.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::imxrt1010evk, imxrt1010::Pads, lpuart};
+//!
+//! let pins = imxrt1010evk::Pins::from_pads(unsafe { Pads::new() });
+//! let mut console = lpuart::Pins::new(pins.console_tx, pins.console_rx);
+//! console.prepare();
+//! ```
+
+use crate::imxrt1010 as pads;
+
+/// The user LED; GPIO1_IO11
+pub type UserLed = pads::gpio::GPIO_11;
+/// The user button; GPIO2_IO05
+pub type UserButton = pads::gpio_sd::GPIO_SD_05;
+/// The debug console TX; LPUART1 TX
+pub type ConsoleTx = pads::gpio::GPIO_10;
+/// The debug console RX; LPUART1 RX
+pub type ConsoleRx = pads::gpio::GPIO_09;
+
+crate::define_board_pins! {
+    /// MIMXRT1010-EVK pins
+    pub struct Pins(pads::Pads) {
+        /// The user LED
+        pub user_led: UserLed = gpio.p11,
+        /// The user button
+        pub user_button: UserButton = gpio_sd.p05,
+        /// The debug console TX
+        pub console_tx: ConsoleTx = gpio.p10,
+        /// The debug console RX
+        pub console_rx: ConsoleRx = gpio.p09,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
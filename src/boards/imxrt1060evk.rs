@@ -0,0 +1,159 @@
+//! MIMXRT1060-EVK pins
+//!
+//! The aliases name the i.MX RT 1062 pads by the EVK's Arduino header
+//! labels. Some header pins share a pad:
+//!
+//! - `A4` and `A5` are `D14` and `D15`.
+//! - `UserLed` is `D4`.
+//!
+//! `Pins` has one field for each pad. The EVK's user button connects to
+//! the `WAKEUP` pad, which is not one of the 1060 pads, so there is no
+//! alias for the button.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::imxrt1060evk, imxrt1060::Pads, lpuart};
+//!
+//! let pins = imxrt1060evk::Pins::from_pads(unsafe { Pads::new() });
+//! let mut uart = lpuart::Pins::new(pins.d1, pins.d0);
+//! uart.prepare();
+//! ```
+
+use crate::imxrt1060 as pads;
+use crate::{ErasedPad, PadLocation};
+
+/// Arduino header D0
+pub type D0 = pads::gpio_ad_b1::GPIO_AD_B1_07;
+/// Arduino header D1
+pub type D1 = pads::gpio_ad_b1::GPIO_AD_B1_06;
+/// Arduino header D2
+pub type D2 = pads::gpio_ad_b0::GPIO_AD_B0_11;
+/// Arduino header D3
+pub type D3 = pads::gpio_ad_b1::GPIO_AD_B1_08;
+/// Arduino header D4
+pub type D4 = pads::gpio_ad_b0::GPIO_AD_B0_09;
+/// Arduino header D5
+pub type D5 = pads::gpio_ad_b0::GPIO_AD_B0_10;
+/// Arduino header D6
+pub type D6 = pads::gpio_ad_b1::GPIO_AD_B1_02;
+/// Arduino header D7
+pub type D7 = pads::gpio_ad_b1::GPIO_AD_B1_03;
+/// Arduino header D8
+pub type D8 = pads::gpio_ad_b0::GPIO_AD_B0_03;
+/// Arduino header D9
+pub type D9 = pads::gpio_ad_b0::GPIO_AD_B0_02;
+/// Arduino header D10
+pub type D10 = pads::gpio_sd_b0::GPIO_SD_B0_01;
+/// Arduino header D11
+pub type D11 = pads::gpio_sd_b0::GPIO_SD_B0_02;
+/// Arduino header D12
+pub type D12 = pads::gpio_sd_b0::GPIO_SD_B0_03;
+/// Arduino header D13
+pub type D13 = pads::gpio_sd_b0::GPIO_SD_B0_00;
+/// Arduino header D14
+pub type D14 = pads::gpio_ad_b1::GPIO_AD_B1_01;
+/// Arduino header D15
+pub type D15 = pads::gpio_ad_b1::GPIO_AD_B1_00;
+/// Arduino header A0
+pub type A0 = pads::gpio_ad_b1::GPIO_AD_B1_10;
+/// Arduino header A1
+pub type A1 = pads::gpio_ad_b1::GPIO_AD_B1_11;
+/// Arduino header A2
+pub type A2 = pads::gpio_ad_b1::GPIO_AD_B1_04;
+/// Arduino header A3
+pub type A3 = pads::gpio_ad_b1::GPIO_AD_B1_05;
+/// Arduino header A4
+pub type A4 = D14;
+/// Arduino header A5
+pub type A5 = D15;
+
+/// The user LED
+pub type UserLed = D4;
+
+/// Pin locations, indexed by pin number
+pub const PINS: [PadLocation; 16] = [
+    D0::LOCATION,
+    D1::LOCATION,
+    D2::LOCATION,
+    D3::LOCATION,
+    D4::LOCATION,
+    D5::LOCATION,
+    D6::LOCATION,
+    D7::LOCATION,
+    D8::LOCATION,
+    D9::LOCATION,
+    D10::LOCATION,
+    D11::LOCATION,
+    D12::LOCATION,
+    D13::LOCATION,
+    D14::LOCATION,
+    D15::LOCATION,
+];
+
+/// Returns the erased pad for the numeric pin, or `None` if there is no such pin
+///
+/// # Safety
+///
+/// This creates a handle to the pad, which may alias another handle. The same
+/// rules as [`Pad::new()`](crate::Pad::new) apply.
+pub unsafe fn erased_pin(number: usize) -> Option<ErasedPad> {
+    PINS.get(number).map(|location| location.erase())
+}
+
+crate::define_board_pins! {
+    /// MIMXRT1060-EVK Arduino header pins
+    pub struct Pins(pads::Pads) {
+        /// Arduino header D0
+        pub d0: D0 = gpio_ad_b1.p07,
+        /// Arduino header D1
+        pub d1: D1 = gpio_ad_b1.p06,
+        /// Arduino header D2
+        pub d2: D2 = gpio_ad_b0.p11,
+        /// Arduino header D3
+        pub d3: D3 = gpio_ad_b1.p08,
+        /// Arduino header D4
+        pub d4: D4 = gpio_ad_b0.p09,
+        /// Arduino header D5
+        pub d5: D5 = gpio_ad_b0.p10,
+        /// Arduino header D6
+        pub d6: D6 = gpio_ad_b1.p02,
+        /// Arduino header D7
+        pub d7: D7 = gpio_ad_b1.p03,
+        /// Arduino header D8
+        pub d8: D8 = gpio_ad_b0.p03,
+        /// Arduino header D9
+        pub d9: D9 = gpio_ad_b0.p02,
+        /// Arduino header D10
+        pub d10: D10 = gpio_sd_b0.p01,
+        /// Arduino header D11
+        pub d11: D11 = gpio_sd_b0.p02,
+        /// Arduino header D12
+        pub d12: D12 = gpio_sd_b0.p03,
+        /// Arduino header D13
+        pub d13: D13 = gpio_sd_b0.p00,
+        /// Arduino header D14
+        pub d14: D14 = gpio_ad_b1.p01,
+        /// Arduino header D15
+        pub d15: D15 = gpio_ad_b1.p00,
+        /// Arduino header A0
+        pub a0: A0 = gpio_ad_b1.p10,
+        /// Arduino header A1
+        pub a1: A1 = gpio_ad_b1.p11,
+        /// Arduino header A2
+        pub a2: A2 = gpio_ad_b1.p04,
+        /// Arduino header A3
+        pub a3: A3 = gpio_ad_b1.p05,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
+
+/// The MIMXRT1060-EVK, as a [`Board`](crate::Board)
+///
+/// The console pads are the debug UART, LPUART1; the I2C pads are D15 and D14, LPI2C1.
+pub enum Imxrt1060Evk {}
+
+board_roles!(Imxrt1060Evk, console: (pads::gpio_ad_b0::GPIO_AD_B0_12, pads::gpio_ad_b0::GPIO_AD_B0_13), i2c: (D15, D14), led: (UserLed, gpio: 1));
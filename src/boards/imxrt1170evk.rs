@@ -0,0 +1,95 @@
+//! MIMXRT1170-EVK pins
+//!
+//! The aliases name the i.MX RT 1176 pads by the EVK's connector labels,
+//! following the MCUXpresso SDK board files. They cover the Arduino header's
+//! UART, SPI, and I2C pins, the user LED, and the MIPI display and camera
+//! control pins.
+//!
+//! `DisplayBacklight` is the same pad as `D11`. The EVK's user button connects
+//! to the `WAKEUP` pad, which is not one of the 1170 pads, so there is no alias
+//! for the button. There are no aliases for the M.2 connector's control pins.
+//! The aliases do not cover every header pin, so there is no `PINS` table.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::imxrt1170evk, imxrt1170::Pads, lpi2c};
+//!
+//! let pins = imxrt1170evk::Pins::from_pads(unsafe { Pads::new() });
+//! let mut i2c = lpi2c::Pins::new(pins.d15, pins.d14);
+//! i2c.prepare();
+//! ```
+
+use crate::imxrt1170 as pads;
+
+/// Arduino header D0; LPUART2 RX
+pub type D0 = pads::gpio_disp_b2::GPIO_DISP_B2_11;
+/// Arduino header D1; LPUART2 TX
+pub type D1 = pads::gpio_disp_b2::GPIO_DISP_B2_10;
+/// Arduino header D10; LPSPI1 PCS0
+pub type D10 = pads::gpio_ad::GPIO_AD_29;
+/// Arduino header D11; LPSPI1 SDO
+pub type D11 = pads::gpio_ad::GPIO_AD_30;
+/// Arduino header D12; LPSPI1 SDI
+pub type D12 = pads::gpio_ad::GPIO_AD_31;
+/// Arduino header D13; LPSPI1 SCK
+pub type D13 = pads::gpio_ad::GPIO_AD_28;
+/// Arduino header D14; LPI2C5 SDA
+pub type D14 = pads::gpio_lpsr::GPIO_LPSR_04;
+/// Arduino header D15; LPI2C5 SCL
+pub type D15 = pads::gpio_lpsr::GPIO_LPSR_05;
+/// The user LED
+pub type UserLed = pads::gpio_ad::GPIO_AD_04;
+/// The MIPI display's reset
+pub type DisplayReset = pads::gpio_ad::GPIO_AD_02;
+/// The MIPI display's power enable
+pub type DisplayPower = pads::gpio_disp_b2::GPIO_DISP_B2_15;
+/// The camera's power down
+pub type CameraPowerDown = pads::gpio_ad::GPIO_AD_26;
+/// The camera's reset
+pub type CameraReset = pads::gpio_disp_b2::GPIO_DISP_B2_14;
+/// The MIPI display's backlight
+pub type DisplayBacklight = D11;
+
+crate::define_board_pins! {
+    /// MIMXRT1170-EVK pins
+    pub struct Pins(pads::Pads) {
+        /// Arduino header D0; LPUART2 RX
+        pub d0: D0 = gpio_disp_b2.p11,
+        /// Arduino header D1; LPUART2 TX
+        pub d1: D1 = gpio_disp_b2.p10,
+        /// Arduino header D10; LPSPI1 PCS0
+        pub d10: D10 = gpio_ad.p29,
+        /// Arduino header D11; LPSPI1 SDO
+        pub d11: D11 = gpio_ad.p30,
+        /// Arduino header D12; LPSPI1 SDI
+        pub d12: D12 = gpio_ad.p31,
+        /// Arduino header D13; LPSPI1 SCK
+        pub d13: D13 = gpio_ad.p28,
+        /// Arduino header D14; LPI2C5 SDA
+        pub d14: D14 = gpio_lpsr.p04,
+        /// Arduino header D15; LPI2C5 SCL
+        pub d15: D15 = gpio_lpsr.p05,
+        /// The user LED
+        pub user_led: UserLed = gpio_ad.p04,
+        /// The MIPI display's reset
+        pub display_reset: DisplayReset = gpio_ad.p02,
+        /// The MIPI display's power enable
+        pub display_power: DisplayPower = gpio_disp_b2.p15,
+        /// The camera's power down
+        pub camera_power_down: CameraPowerDown = gpio_ad.p26,
+        /// The camera's reset
+        pub camera_reset: CameraReset = gpio_disp_b2.p14,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
+
+/// The MIMXRT1170-EVK, as a [`Board`](crate::Board)
+///
+/// The console pads are the debug UART, LPUART1; the I2C pads are D15 and D14, LPI2C5.
+pub enum Imxrt1170Evk {}
+
+board_roles!(Imxrt1170Evk, console: (pads::gpio_ad::GPIO_AD_24, pads::gpio_ad::GPIO_AD_25), i2c: (D15, D14), led: (UserLed, gpio: 3));
@@ -0,0 +1,171 @@
+//! Adafruit Metro M7 pins
+//!
+//! The aliases name the i.MX RT 1011 pads by the Metro M7's silkscreen
+//! labels. The names match the CircuitPython `board` module. `D13` is also
+//! the board's red LED.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::metro_m7, imxrt1010::Pads, lpi2c};
+//!
+//! let pins = metro_m7::Pins::from_pads(unsafe { Pads::new() });
+//! let mut i2c = lpi2c::Pins::new(pins.scl, pins.sda);
+//! i2c.prepare();
+//! ```
+
+use crate::imxrt1010 as pads;
+use crate::{ErasedPad, PadLocation};
+
+/// Metro M7 D0; the LPUART1 RX
+pub type D0 = pads::gpio::GPIO_09;
+/// Metro M7 D1; the LPUART1 TX
+pub type D1 = pads::gpio::GPIO_10;
+/// Metro M7 D2
+pub type D2 = pads::gpio::GPIO_13;
+/// Metro M7 D3
+pub type D3 = pads::gpio::GPIO_12;
+/// Metro M7 D4
+pub type D4 = pads::gpio_sd::GPIO_SD_00;
+/// Metro M7 D5
+pub type D5 = pads::gpio_sd::GPIO_SD_01;
+/// Metro M7 D6
+pub type D6 = pads::gpio_sd::GPIO_SD_02;
+/// Metro M7 D7
+pub type D7 = pads::gpio::GPIO_11;
+/// Metro M7 D8
+pub type D8 = pads::gpio::GPIO_08;
+/// Metro M7 D9
+pub type D9 = pads::gpio::GPIO_07;
+/// Metro M7 D10
+pub type D10 = pads::gpio::GPIO_06;
+/// Metro M7 D11
+pub type D11 = pads::gpio::GPIO_05;
+/// Metro M7 D12
+pub type D12 = pads::gpio::GPIO_04;
+/// Metro M7 D13
+pub type D13 = pads::gpio::GPIO_03;
+/// Metro M7 A0
+pub type A0 = pads::gpio_ad::GPIO_AD_02;
+/// Metro M7 A1
+pub type A1 = pads::gpio_ad::GPIO_AD_01;
+/// Metro M7 A2
+pub type A2 = pads::gpio_ad::GPIO_AD_00;
+/// Metro M7 A3
+pub type A3 = pads::gpio_ad::GPIO_AD_05;
+/// Metro M7 A4
+pub type A4 = pads::gpio_ad::GPIO_AD_10;
+/// Metro M7 A5
+pub type A5 = pads::gpio_ad::GPIO_AD_08;
+/// Metro M7 SCL
+pub type Scl = pads::gpio::GPIO_02;
+/// Metro M7 SDA
+pub type Sda = pads::gpio::GPIO_01;
+/// Metro M7 SCK
+pub type Sck = pads::gpio_ad::GPIO_AD_06;
+/// Metro M7 MOSI
+pub type Mosi = pads::gpio_ad::GPIO_AD_04;
+/// Metro M7 MISO
+pub type Miso = pads::gpio_ad::GPIO_AD_03;
+/// Metro M7 NEOPIXEL; the on-board RGB LED
+pub type Neopixel = pads::gpio::GPIO_00;
+
+/// The Metro M7 red LED
+pub type Led = D13;
+
+/// Pin locations, indexed by pin number
+pub const PINS: [PadLocation; 14] = [
+    D0::LOCATION,
+    D1::LOCATION,
+    D2::LOCATION,
+    D3::LOCATION,
+    D4::LOCATION,
+    D5::LOCATION,
+    D6::LOCATION,
+    D7::LOCATION,
+    D8::LOCATION,
+    D9::LOCATION,
+    D10::LOCATION,
+    D11::LOCATION,
+    D12::LOCATION,
+    D13::LOCATION,
+];
+
+/// Returns the erased pad for the numeric pin, or `None` if there is no such pin
+///
+/// # Safety
+///
+/// This creates a handle to the pad, which may alias another handle. The same
+/// rules as [`Pad::new()`](crate::Pad::new) apply.
+pub unsafe fn erased_pin(number: usize) -> Option<ErasedPad> {
+    PINS.get(number).map(|location| location.erase())
+}
+
+crate::define_board_pins! {
+    /// Adafruit Metro M7 pins
+    pub struct Pins(pads::Pads) {
+        /// D0
+        pub d0: D0 = gpio.p09,
+        /// D1
+        pub d1: D1 = gpio.p10,
+        /// D2
+        pub d2: D2 = gpio.p13,
+        /// D3
+        pub d3: D3 = gpio.p12,
+        /// D4
+        pub d4: D4 = gpio_sd.p00,
+        /// D5
+        pub d5: D5 = gpio_sd.p01,
+        /// D6
+        pub d6: D6 = gpio_sd.p02,
+        /// D7
+        pub d7: D7 = gpio.p11,
+        /// D8
+        pub d8: D8 = gpio.p08,
+        /// D9
+        pub d9: D9 = gpio.p07,
+        /// D10
+        pub d10: D10 = gpio.p06,
+        /// D11
+        pub d11: D11 = gpio.p05,
+        /// D12
+        pub d12: D12 = gpio.p04,
+        /// D13
+        pub d13: D13 = gpio.p03,
+        /// A0
+        pub a0: A0 = gpio_ad.p02,
+        /// A1
+        pub a1: A1 = gpio_ad.p01,
+        /// A2
+        pub a2: A2 = gpio_ad.p00,
+        /// A3
+        pub a3: A3 = gpio_ad.p05,
+        /// A4
+        pub a4: A4 = gpio_ad.p10,
+        /// A5
+        pub a5: A5 = gpio_ad.p08,
+        /// SCL
+        pub scl: Scl = gpio.p02,
+        /// SDA
+        pub sda: Sda = gpio.p01,
+        /// SCK
+        pub sck: Sck = gpio_ad.p06,
+        /// MOSI
+        pub mosi: Mosi = gpio_ad.p04,
+        /// MISO
+        pub miso: Miso = gpio_ad.p03,
+        /// NEOPIXEL
+        pub neopixel: Neopixel = gpio.p00,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
+
+/// The Metro M7, as a [`Board`](crate::Board)
+///
+/// The console pads are D1 and D0, LPUART1; the I2C pads are SCL and SDA, LPI2C1.
+pub enum MetroM7 {}
+
+board_roles!(MetroM7, console: (D1, D0), i2c: (Scl, Sda), led: (Led, gpio: 1));
@@ -0,0 +1,28 @@
+//! Pad aliases for development boards
+//!
+//! Each board module names its pads by the board's pin labels. The aliases
+//! are the chip's pad types, so they work with all pin traits. Use a board's
+//! `Pins::from_pads()` to take the board pins from the chip's pads, or
+//! `BoardPads::take()` to take only the board pins.
+
+#[cfg(feature = "imxrt1010evk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imxrt1010evk")))]
+pub mod imxrt1010evk;
+#[cfg(feature = "imxrt1060evk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imxrt1060evk")))]
+pub mod imxrt1060evk;
+#[cfg(feature = "imxrt1170evk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imxrt1170evk")))]
+pub mod imxrt1170evk;
+#[cfg(feature = "metro-m7")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metro-m7")))]
+pub mod metro_m7;
+#[cfg(feature = "teensy40")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teensy40")))]
+pub mod teensy40;
+#[cfg(feature = "teensy41")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teensy41")))]
+pub mod teensy41;
+#[cfg(feature = "teensy-micromod")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teensy-micromod")))]
+pub mod teensy_micromod;
@@ -0,0 +1,172 @@
+//! Teensy 4.0 pins
+//!
+//! The aliases name the i.MX RT 1062 pads by their Teensy 4.0 pin numbers.
+//! `P13` is also the board's LED.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::teensy40, imxrt1060::Pads, lpuart};
+//!
+//! let pins = teensy40::Pins::from_pads(unsafe { Pads::new() });
+//! let mut uart = lpuart::Pins::new(pins.p1, pins.p0);
+//! uart.prepare();
+//! ```
+
+use crate::imxrt1060 as pads;
+use crate::{ErasedPad, PadLocation};
+
+/// Teensy 4.0 pin 0
+pub type P0 = pads::gpio_ad_b0::GPIO_AD_B0_03;
+/// Teensy 4.0 pin 1
+pub type P1 = pads::gpio_ad_b0::GPIO_AD_B0_02;
+/// Teensy 4.0 pin 2
+pub type P2 = pads::gpio_emc::GPIO_EMC_04;
+/// Teensy 4.0 pin 3
+pub type P3 = pads::gpio_emc::GPIO_EMC_05;
+/// Teensy 4.0 pin 4
+pub type P4 = pads::gpio_emc::GPIO_EMC_06;
+/// Teensy 4.0 pin 5
+pub type P5 = pads::gpio_emc::GPIO_EMC_08;
+/// Teensy 4.0 pin 6
+pub type P6 = pads::gpio_b0::GPIO_B0_10;
+/// Teensy 4.0 pin 7
+pub type P7 = pads::gpio_b1::GPIO_B1_01;
+/// Teensy 4.0 pin 8
+pub type P8 = pads::gpio_b1::GPIO_B1_00;
+/// Teensy 4.0 pin 9
+pub type P9 = pads::gpio_b0::GPIO_B0_11;
+/// Teensy 4.0 pin 10
+pub type P10 = pads::gpio_b0::GPIO_B0_00;
+/// Teensy 4.0 pin 11
+pub type P11 = pads::gpio_b0::GPIO_B0_02;
+/// Teensy 4.0 pin 12
+pub type P12 = pads::gpio_b0::GPIO_B0_01;
+/// Teensy 4.0 pin 13
+pub type P13 = pads::gpio_b0::GPIO_B0_03;
+/// Teensy 4.0 pin 14
+pub type P14 = pads::gpio_ad_b1::GPIO_AD_B1_02;
+/// Teensy 4.0 pin 15
+pub type P15 = pads::gpio_ad_b1::GPIO_AD_B1_03;
+/// Teensy 4.0 pin 16
+pub type P16 = pads::gpio_ad_b1::GPIO_AD_B1_07;
+/// Teensy 4.0 pin 17
+pub type P17 = pads::gpio_ad_b1::GPIO_AD_B1_06;
+/// Teensy 4.0 pin 18
+pub type P18 = pads::gpio_ad_b1::GPIO_AD_B1_01;
+/// Teensy 4.0 pin 19
+pub type P19 = pads::gpio_ad_b1::GPIO_AD_B1_00;
+/// Teensy 4.0 pin 20
+pub type P20 = pads::gpio_ad_b1::GPIO_AD_B1_10;
+/// Teensy 4.0 pin 21
+pub type P21 = pads::gpio_ad_b1::GPIO_AD_B1_11;
+/// Teensy 4.0 pin 22
+pub type P22 = pads::gpio_ad_b1::GPIO_AD_B1_08;
+/// Teensy 4.0 pin 23
+pub type P23 = pads::gpio_ad_b1::GPIO_AD_B1_09;
+
+/// The Teensy 4.0 LED
+pub type Led = P13;
+
+/// Pin locations, indexed by pin number
+pub const PINS: [PadLocation; 24] = [
+    P0::LOCATION,
+    P1::LOCATION,
+    P2::LOCATION,
+    P3::LOCATION,
+    P4::LOCATION,
+    P5::LOCATION,
+    P6::LOCATION,
+    P7::LOCATION,
+    P8::LOCATION,
+    P9::LOCATION,
+    P10::LOCATION,
+    P11::LOCATION,
+    P12::LOCATION,
+    P13::LOCATION,
+    P14::LOCATION,
+    P15::LOCATION,
+    P16::LOCATION,
+    P17::LOCATION,
+    P18::LOCATION,
+    P19::LOCATION,
+    P20::LOCATION,
+    P21::LOCATION,
+    P22::LOCATION,
+    P23::LOCATION,
+];
+
+/// Returns the erased pad for the numeric pin, or `None` if there is no such pin
+///
+/// # Safety
+///
+/// This creates a handle to the pad, which may alias another handle. The same
+/// rules as [`Pad::new()`](crate::Pad::new) apply.
+pub unsafe fn erased_pin(number: usize) -> Option<ErasedPad> {
+    PINS.get(number).map(|location| location.erase())
+}
+
+crate::define_board_pins! {
+    /// Teensy 4.0 pins
+    pub struct Pins(pads::Pads) {
+        /// Pin 0
+        pub p0: P0 = gpio_ad_b0.p03,
+        /// Pin 1
+        pub p1: P1 = gpio_ad_b0.p02,
+        /// Pin 2
+        pub p2: P2 = gpio_emc.p04,
+        /// Pin 3
+        pub p3: P3 = gpio_emc.p05,
+        /// Pin 4
+        pub p4: P4 = gpio_emc.p06,
+        /// Pin 5
+        pub p5: P5 = gpio_emc.p08,
+        /// Pin 6
+        pub p6: P6 = gpio_b0.p10,
+        /// Pin 7
+        pub p7: P7 = gpio_b1.p01,
+        /// Pin 8
+        pub p8: P8 = gpio_b1.p00,
+        /// Pin 9
+        pub p9: P9 = gpio_b0.p11,
+        /// Pin 10
+        pub p10: P10 = gpio_b0.p00,
+        /// Pin 11
+        pub p11: P11 = gpio_b0.p02,
+        /// Pin 12
+        pub p12: P12 = gpio_b0.p01,
+        /// Pin 13
+        pub p13: P13 = gpio_b0.p03,
+        /// Pin 14
+        pub p14: P14 = gpio_ad_b1.p02,
+        /// Pin 15
+        pub p15: P15 = gpio_ad_b1.p03,
+        /// Pin 16
+        pub p16: P16 = gpio_ad_b1.p07,
+        /// Pin 17
+        pub p17: P17 = gpio_ad_b1.p06,
+        /// Pin 18
+        pub p18: P18 = gpio_ad_b1.p01,
+        /// Pin 19
+        pub p19: P19 = gpio_ad_b1.p00,
+        /// Pin 20
+        pub p20: P20 = gpio_ad_b1.p10,
+        /// Pin 21
+        pub p21: P21 = gpio_ad_b1.p11,
+        /// Pin 22
+        pub p22: P22 = gpio_ad_b1.p08,
+        /// Pin 23
+        pub p23: P23 = gpio_ad_b1.p09,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
+
+/// The Teensy 4.0, as a [`Board`](crate::Board)
+///
+/// The console pads are pins 1 and 0, LPUART6; the I2C pads are pins 19 and 18, LPI2C1.
+pub enum Teensy40 {}
+
+board_roles!(Teensy40, console: (P1, P0), i2c: (P19, P18), led: (Led, gpio: 2));
@@ -0,0 +1,349 @@
+//! Teensy 4.1 pins
+//!
+//! The aliases name the i.MX RT 1062 pads by their Teensy 4.1 pin numbers.
+//! `P13` is also the board's LED. Pins 24 through 41 are on the board's
+//! edges, pins 42 through 47 are the SD card pads, and pins 48 through 54
+//! are the bottom-side memory expansion pads.
+//!
+//! The Teensy 4.1 numbers some pads differently than the Teensy 4.0. Use
+//! this module, not `teensy40`, for a Teensy 4.1.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::teensy41, imxrt1060::Pads, lpuart};
+//!
+//! let pins = teensy41::Pins::from_pads(unsafe { Pads::new() });
+//! let mut uart = lpuart::Pins::new(pins.p1, pins.p0);
+//! uart.prepare();
+//! ```
+
+use crate::imxrt1060 as pads;
+use crate::{ErasedPad, PadLocation};
+
+/// Teensy 4.1 pin 0
+pub type P0 = pads::gpio_ad_b0::GPIO_AD_B0_03;
+/// Teensy 4.1 pin 1
+pub type P1 = pads::gpio_ad_b0::GPIO_AD_B0_02;
+/// Teensy 4.1 pin 2
+pub type P2 = pads::gpio_emc::GPIO_EMC_04;
+/// Teensy 4.1 pin 3
+pub type P3 = pads::gpio_emc::GPIO_EMC_05;
+/// Teensy 4.1 pin 4
+pub type P4 = pads::gpio_emc::GPIO_EMC_06;
+/// Teensy 4.1 pin 5
+pub type P5 = pads::gpio_emc::GPIO_EMC_08;
+/// Teensy 4.1 pin 6
+pub type P6 = pads::gpio_b0::GPIO_B0_10;
+/// Teensy 4.1 pin 7
+pub type P7 = pads::gpio_b1::GPIO_B1_01;
+/// Teensy 4.1 pin 8
+pub type P8 = pads::gpio_b1::GPIO_B1_00;
+/// Teensy 4.1 pin 9
+pub type P9 = pads::gpio_b0::GPIO_B0_11;
+/// Teensy 4.1 pin 10
+pub type P10 = pads::gpio_b0::GPIO_B0_00;
+/// Teensy 4.1 pin 11
+pub type P11 = pads::gpio_b0::GPIO_B0_02;
+/// Teensy 4.1 pin 12
+pub type P12 = pads::gpio_b0::GPIO_B0_01;
+/// Teensy 4.1 pin 13
+pub type P13 = pads::gpio_b0::GPIO_B0_03;
+/// Teensy 4.1 pin 14
+pub type P14 = pads::gpio_ad_b1::GPIO_AD_B1_02;
+/// Teensy 4.1 pin 15
+pub type P15 = pads::gpio_ad_b1::GPIO_AD_B1_03;
+/// Teensy 4.1 pin 16
+pub type P16 = pads::gpio_ad_b1::GPIO_AD_B1_07;
+/// Teensy 4.1 pin 17
+pub type P17 = pads::gpio_ad_b1::GPIO_AD_B1_06;
+/// Teensy 4.1 pin 18
+pub type P18 = pads::gpio_ad_b1::GPIO_AD_B1_01;
+/// Teensy 4.1 pin 19
+pub type P19 = pads::gpio_ad_b1::GPIO_AD_B1_00;
+/// Teensy 4.1 pin 20
+pub type P20 = pads::gpio_ad_b1::GPIO_AD_B1_10;
+/// Teensy 4.1 pin 21
+pub type P21 = pads::gpio_ad_b1::GPIO_AD_B1_11;
+/// Teensy 4.1 pin 22
+pub type P22 = pads::gpio_ad_b1::GPIO_AD_B1_08;
+/// Teensy 4.1 pin 23
+pub type P23 = pads::gpio_ad_b1::GPIO_AD_B1_09;
+/// Teensy 4.1 pin 24
+pub type P24 = pads::gpio_ad_b0::GPIO_AD_B0_12;
+/// Teensy 4.1 pin 25
+pub type P25 = pads::gpio_ad_b0::GPIO_AD_B0_13;
+/// Teensy 4.1 pin 26
+pub type P26 = pads::gpio_ad_b1::GPIO_AD_B1_14;
+/// Teensy 4.1 pin 27
+pub type P27 = pads::gpio_ad_b1::GPIO_AD_B1_15;
+/// Teensy 4.1 pin 28
+pub type P28 = pads::gpio_emc::GPIO_EMC_32;
+/// Teensy 4.1 pin 29
+pub type P29 = pads::gpio_emc::GPIO_EMC_31;
+/// Teensy 4.1 pin 30
+pub type P30 = pads::gpio_emc::GPIO_EMC_37;
+/// Teensy 4.1 pin 31
+pub type P31 = pads::gpio_emc::GPIO_EMC_36;
+/// Teensy 4.1 pin 32
+pub type P32 = pads::gpio_b0::GPIO_B0_12;
+/// Teensy 4.1 pin 33
+pub type P33 = pads::gpio_emc::GPIO_EMC_07;
+/// Teensy 4.1 pin 34
+pub type P34 = pads::gpio_b1::GPIO_B1_13;
+/// Teensy 4.1 pin 35
+pub type P35 = pads::gpio_b1::GPIO_B1_12;
+/// Teensy 4.1 pin 36
+pub type P36 = pads::gpio_b1::GPIO_B1_02;
+/// Teensy 4.1 pin 37
+pub type P37 = pads::gpio_b1::GPIO_B1_03;
+/// Teensy 4.1 pin 38
+pub type P38 = pads::gpio_ad_b1::GPIO_AD_B1_12;
+/// Teensy 4.1 pin 39
+pub type P39 = pads::gpio_ad_b1::GPIO_AD_B1_13;
+/// Teensy 4.1 pin 40
+pub type P40 = pads::gpio_ad_b1::GPIO_AD_B1_04;
+/// Teensy 4.1 pin 41
+pub type P41 = pads::gpio_ad_b1::GPIO_AD_B1_05;
+/// Teensy 4.1 pin 42
+pub type P42 = pads::gpio_sd_b0::GPIO_SD_B0_03;
+/// Teensy 4.1 pin 43
+pub type P43 = pads::gpio_sd_b0::GPIO_SD_B0_02;
+/// Teensy 4.1 pin 44
+pub type P44 = pads::gpio_sd_b0::GPIO_SD_B0_01;
+/// Teensy 4.1 pin 45
+pub type P45 = pads::gpio_sd_b0::GPIO_SD_B0_00;
+/// Teensy 4.1 pin 46
+pub type P46 = pads::gpio_sd_b0::GPIO_SD_B0_05;
+/// Teensy 4.1 pin 47
+pub type P47 = pads::gpio_sd_b0::GPIO_SD_B0_04;
+/// Teensy 4.1 pin 48
+pub type P48 = pads::gpio_emc::GPIO_EMC_24;
+/// Teensy 4.1 pin 49
+pub type P49 = pads::gpio_emc::GPIO_EMC_27;
+/// Teensy 4.1 pin 50
+pub type P50 = pads::gpio_emc::GPIO_EMC_28;
+/// Teensy 4.1 pin 51
+pub type P51 = pads::gpio_emc::GPIO_EMC_22;
+/// Teensy 4.1 pin 52
+pub type P52 = pads::gpio_emc::GPIO_EMC_26;
+/// Teensy 4.1 pin 53
+pub type P53 = pads::gpio_emc::GPIO_EMC_25;
+/// Teensy 4.1 pin 54
+pub type P54 = pads::gpio_emc::GPIO_EMC_29;
+
+/// The Teensy 4.1 LED
+pub type Led = P13;
+
+/// Pin locations, indexed by pin number
+pub const PINS: [PadLocation; 55] = [
+    P0::LOCATION,
+    P1::LOCATION,
+    P2::LOCATION,
+    P3::LOCATION,
+    P4::LOCATION,
+    P5::LOCATION,
+    P6::LOCATION,
+    P7::LOCATION,
+    P8::LOCATION,
+    P9::LOCATION,
+    P10::LOCATION,
+    P11::LOCATION,
+    P12::LOCATION,
+    P13::LOCATION,
+    P14::LOCATION,
+    P15::LOCATION,
+    P16::LOCATION,
+    P17::LOCATION,
+    P18::LOCATION,
+    P19::LOCATION,
+    P20::LOCATION,
+    P21::LOCATION,
+    P22::LOCATION,
+    P23::LOCATION,
+    P24::LOCATION,
+    P25::LOCATION,
+    P26::LOCATION,
+    P27::LOCATION,
+    P28::LOCATION,
+    P29::LOCATION,
+    P30::LOCATION,
+    P31::LOCATION,
+    P32::LOCATION,
+    P33::LOCATION,
+    P34::LOCATION,
+    P35::LOCATION,
+    P36::LOCATION,
+    P37::LOCATION,
+    P38::LOCATION,
+    P39::LOCATION,
+    P40::LOCATION,
+    P41::LOCATION,
+    P42::LOCATION,
+    P43::LOCATION,
+    P44::LOCATION,
+    P45::LOCATION,
+    P46::LOCATION,
+    P47::LOCATION,
+    P48::LOCATION,
+    P49::LOCATION,
+    P50::LOCATION,
+    P51::LOCATION,
+    P52::LOCATION,
+    P53::LOCATION,
+    P54::LOCATION,
+];
+
+/// Returns the erased pad for the numeric pin, or `None` if there is no such pin
+///
+/// # Safety
+///
+/// This creates a handle to the pad, which may alias another handle. The same
+/// rules as [`Pad::new()`](crate::Pad::new) apply.
+pub unsafe fn erased_pin(number: usize) -> Option<ErasedPad> {
+    PINS.get(number).map(|location| location.erase())
+}
+
+crate::define_board_pins! {
+    /// Teensy 4.1 pins
+    pub struct Pins(pads::Pads) {
+        /// Pin 0
+        pub p0: P0 = gpio_ad_b0.p03,
+        /// Pin 1
+        pub p1: P1 = gpio_ad_b0.p02,
+        /// Pin 2
+        pub p2: P2 = gpio_emc.p04,
+        /// Pin 3
+        pub p3: P3 = gpio_emc.p05,
+        /// Pin 4
+        pub p4: P4 = gpio_emc.p06,
+        /// Pin 5
+        pub p5: P5 = gpio_emc.p08,
+        /// Pin 6
+        pub p6: P6 = gpio_b0.p10,
+        /// Pin 7
+        pub p7: P7 = gpio_b1.p01,
+        /// Pin 8
+        pub p8: P8 = gpio_b1.p00,
+        /// Pin 9
+        pub p9: P9 = gpio_b0.p11,
+        /// Pin 10
+        pub p10: P10 = gpio_b0.p00,
+        /// Pin 11
+        pub p11: P11 = gpio_b0.p02,
+        /// Pin 12
+        pub p12: P12 = gpio_b0.p01,
+        /// Pin 13
+        pub p13: P13 = gpio_b0.p03,
+        /// Pin 14
+        pub p14: P14 = gpio_ad_b1.p02,
+        /// Pin 15
+        pub p15: P15 = gpio_ad_b1.p03,
+        /// Pin 16
+        pub p16: P16 = gpio_ad_b1.p07,
+        /// Pin 17
+        pub p17: P17 = gpio_ad_b1.p06,
+        /// Pin 18
+        pub p18: P18 = gpio_ad_b1.p01,
+        /// Pin 19
+        pub p19: P19 = gpio_ad_b1.p00,
+        /// Pin 20
+        pub p20: P20 = gpio_ad_b1.p10,
+        /// Pin 21
+        pub p21: P21 = gpio_ad_b1.p11,
+        /// Pin 22
+        pub p22: P22 = gpio_ad_b1.p08,
+        /// Pin 23
+        pub p23: P23 = gpio_ad_b1.p09,
+        /// Pin 24
+        pub p24: P24 = gpio_ad_b0.p12,
+        /// Pin 25
+        pub p25: P25 = gpio_ad_b0.p13,
+        /// Pin 26
+        pub p26: P26 = gpio_ad_b1.p14,
+        /// Pin 27
+        pub p27: P27 = gpio_ad_b1.p15,
+        /// Pin 28
+        pub p28: P28 = gpio_emc.p32,
+        /// Pin 29
+        pub p29: P29 = gpio_emc.p31,
+        /// Pin 30
+        pub p30: P30 = gpio_emc.p37,
+        /// Pin 31
+        pub p31: P31 = gpio_emc.p36,
+        /// Pin 32
+        pub p32: P32 = gpio_b0.p12,
+        /// Pin 33
+        pub p33: P33 = gpio_emc.p07,
+        /// Pin 34
+        pub p34: P34 = gpio_b1.p13,
+        /// Pin 35
+        pub p35: P35 = gpio_b1.p12,
+        /// Pin 36
+        pub p36: P36 = gpio_b1.p02,
+        /// Pin 37
+        pub p37: P37 = gpio_b1.p03,
+        /// Pin 38
+        pub p38: P38 = gpio_ad_b1.p12,
+        /// Pin 39
+        pub p39: P39 = gpio_ad_b1.p13,
+        /// Pin 40
+        pub p40: P40 = gpio_ad_b1.p04,
+        /// Pin 41
+        pub p41: P41 = gpio_ad_b1.p05,
+        /// Pin 42
+        pub p42: P42 = gpio_sd_b0.p03,
+        /// Pin 43
+        pub p43: P43 = gpio_sd_b0.p02,
+        /// Pin 44
+        pub p44: P44 = gpio_sd_b0.p01,
+        /// Pin 45
+        pub p45: P45 = gpio_sd_b0.p00,
+        /// Pin 46
+        pub p46: P46 = gpio_sd_b0.p05,
+        /// Pin 47
+        pub p47: P47 = gpio_sd_b0.p04,
+        /// Pin 48
+        pub p48: P48 = gpio_emc.p24,
+        /// Pin 49
+        pub p49: P49 = gpio_emc.p27,
+        /// Pin 50
+        pub p50: P50 = gpio_emc.p28,
+        /// Pin 51
+        pub p51: P51 = gpio_emc.p22,
+        /// Pin 52
+        pub p52: P52 = gpio_emc.p26,
+        /// Pin 53
+        pub p53: P53 = gpio_emc.p25,
+        /// Pin 54
+        pub p54: P54 = gpio_emc.p29,
+    }
+}
+
+/// The pads that the board breaks out
+///
+/// Use `BoardPads` instead of the chip's pads, so that you cannot configure
+/// pads that are not routed on the board.
+pub type BoardPads = Pins;
+
+/// The Teensy 4.1, as a [`Board`](crate::Board)
+///
+/// The console pads are pins 1 and 0, LPUART6; the I2C pads are pins 19 and 18, LPI2C1.
+pub enum Teensy41 {}
+
+board_roles!(Teensy41, console: (P1, P0), i2c: (P19, P18), led: (Led, gpio: 2));
+
+impl crate::SdCard for Teensy41 {
+    type SdCmd = P45;
+    type SdClk = P44;
+    type SdData0 = P43;
+    type SdData1 = P42;
+    type SdData2 = P47;
+    type SdData3 = P46;
+
+    unsafe fn take_sd_card() -> crate::SdCardRoles<Self> {
+        crate::SdCardRoles {
+            cmd: P45::new(),
+            clk: P44::new(),
+            data: (P43::new(), P42::new(), P47::new(), P46::new()),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Teensy MicroMod pins
+//!
+//! The aliases name the i.MX RT 1062 pads by their MicroMod edge connector
+//! signals. Each alias notes the signal's Teensy pin number. `SpiSck` is also
+//! the board's LED.
+//!
+//! There are no aliases for the general purpose `G0` through `G11` signals.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{boards::teensy_micromod, imxrt1060::Pads, lpspi};
+//!
+//! let pins = teensy_micromod::Pins::from_pads(unsafe { Pads::new() });
+//! let mut spi = lpspi::Pins::new(pins.spi_copi, pins.spi_cipo, pins.spi_sck, pins.spi_cs);
+//! spi.prepare();
+//! ```
+
+use crate::imxrt1060 as pads;
+
+/// MicroMod UART_TX1; Teensy pin 1
+pub type Tx1 = pads::gpio_ad_b0::GPIO_AD_B0_02;
+/// MicroMod UART_RX1; Teensy pin 0
+pub type Rx1 = pads::gpio_ad_b0::GPIO_AD_B0_03;
+/// MicroMod I2C_SDA; Teensy pin 18
+pub type Sda = pads::gpio_ad_b1::GPIO_AD_B1_01;
+/// MicroMod I2C_SCL; Teensy pin 19
+pub type Scl = pads::gpio_ad_b1::GPIO_AD_B1_00;
+/// MicroMod SPI_COPI; Teensy pin 11
+pub type SpiCopi = pads::gpio_b0::GPIO_B0_02;
+/// MicroMod SPI_CIPO; Teensy pin 12
+pub type SpiCipo = pads::gpio_b0::GPIO_B0_01;
+/// MicroMod SPI_SCK; Teensy pin 13
+pub type SpiSck = pads::gpio_b0::GPIO_B0_03;
+/// MicroMod SPI_CS; Teensy pin 10
+pub type SpiCs = pads::gpio_b0::GPIO_B0_00;
+/// MicroMod A0; Teensy pin 14
+pub type A0 = pads::gpio_ad_b1::GPIO_AD_B1_02;
+/// MicroMod A1; Teensy pin 15
+pub type A1 = pads::gpio_ad_b1::GPIO_AD_B1_03;
+/// MicroMod D0; Teensy pin 4
+pub type D0 = pads::gpio_emc::GPIO_EMC_06;
+/// MicroMod D1; Teensy pin 5
+pub type D1 = pads::gpio_emc::GPIO_EMC_08;
+/// MicroMod PWM0; Teensy pin 2
+pub type Pwm0 = pads::gpio_emc::GPIO_EMC_04;
+/// MicroMod PWM1; Teensy pin 3
+pub type Pwm1 = pads::gpio_emc::GPIO_EMC_05;
+
+/// The Teensy MicroMod LED
+pub type Led = SpiSck;
+
+crate::define_board_pins! {
+    /// Teensy MicroMod pins
+    pub struct Pins(pads::Pads) {
+        /// UART_TX1
+        pub tx1: Tx1 = gpio_ad_b0.p02,
+        /// UART_RX1
+        pub rx1: Rx1 = gpio_ad_b0.p03,
+        /// I2C_SDA
+        pub sda: Sda = gpio_ad_b1.p01,
+        /// I2C_SCL
+        pub scl: Scl = gpio_ad_b1.p00,
+        /// SPI_COPI
+        pub spi_copi: SpiCopi = gpio_b0.p02,
+        /// SPI_CIPO
+        pub spi_cipo: SpiCipo = gpio_b0.p01,
+        /// SPI_SCK
+        pub spi_sck: SpiSck = gpio_b0.p03,
+        /// SPI_CS
+        pub spi_cs: SpiCs = gpio_b0.p00,
+        /// A0
+        pub a0: A0 = gpio_ad_b1.p02,
+        /// A1
+        pub a1: A1 = gpio_ad_b1.p03,
+        /// D0
+        pub d0: D0 = gpio_emc.p06,
+        /// D1
+        pub d1: D1 = gpio_emc.p08,
+        /// PWM0
+        pub pwm0: Pwm0 = gpio_emc.p04,
+        /// PWM1
+        pub pwm1: Pwm1 = gpio_emc.p05,
+    }
+}
+
+/// The Teensy MicroMod, as a [`Board`](crate::Board)
+///
+/// The console pads are UART_TX1 and UART_RX1, LPUART6; the I2C pads are I2C_SCL and I2C_SDA, LPI2C1.
+pub enum TeensyMicroMod {}
+
+board_roles!(TeensyMicroMod, console: (Tx1, Rx1), i2c: (Scl, Sda), led: (Led, gpio: 2));
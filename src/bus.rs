@@ -0,0 +1,195 @@
+//! Parallel bus pad configuration
+//!
+//! A parallel interface, like the SEMC, LCDIF, or CSI, has one pad for each
+//! data bit. A [`Bus`] holds a tuple of those pads, ordered by bit. The tuple's
+//! length is the bus width, and [`Bus::new()`] checks at compile time that the
+//! pads are bits 0 through `WIDTH - 1`, in order, of one module. Then,
+//! [`Bus::prepare()`] prepares the whole bus.
+//!
+//! A data pad implements [`Pin`]. The pin's `Interface` is a type tag that
+//! names the interface, so that a bus can't mix, say, CSI and LCDIF data pads.
+//! No chip module implements `Pin` yet, so define the data pads with
+//! [`define_pad!`](crate::define_pad):
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{bus::{self, Bus}, consts::Const, define_pad};
+//!
+//! /// The camera interface.
+//! pub enum Csi {}
+//!
+//! macro_rules! data_pad {
+//!     ($name:ident, $mux:literal, $bit:literal) => {
+//!         define_pad! { pub struct $name { mux: $mux, pad: $mux + 0x1F0 } }
+//!         impl bus::Pin for $name {
+//!             const ALT: u32 = 4;
+//!             const DAISY: Option<imxrt_iomuxc::Daisy> = None;
+//!             const BIT: u32 = $bit;
+//!             type Interface = Csi;
+//!             type Module = Const<1>;
+//!         }
+//!     };
+//! }
+//! data_pad!(D0, 0x1000, 0);
+//! data_pad!(D1, 0x1004, 1);
+//! data_pad!(D2, 0x1008, 2);
+//! data_pad!(D3, 0x100C, 3);
+//!
+//! let mut bus: Bus<_, 4> = unsafe { Bus::new((D0::new(), D1::new(), D2::new(), D3::new())) };
+//! bus.prepare();
+//! assert_eq!(Bus::<(D0, D1, D2, D3), 4>::MODULE, 1);
+//! ```
+//!
+//! The pads must be in bit order:
+//!
+//! ```compile_fail
+//! # use imxrt_iomuxc::{bus::{self, Bus}, consts::Const, define_pad};
+//! # pub enum Csi {}
+//! # macro_rules! data_pad {
+//! #     ($name:ident, $mux:literal, $bit:literal) => {
+//! #         define_pad! { pub struct $name { mux: $mux, pad: $mux + 0x1F0 } }
+//! #         impl bus::Pin for $name {
+//! #             const ALT: u32 = 4;
+//! #             const DAISY: Option<imxrt_iomuxc::Daisy> = None;
+//! #             const BIT: u32 = $bit;
+//! #             type Interface = Csi;
+//! #             type Module = Const<1>;
+//! #         }
+//! #     };
+//! # }
+//! # data_pad!(D0, 0x1000, 0);
+//! # data_pad!(D1, 0x1004, 1);
+//! // D1 and D0 are swapped.
+//! let bus: Bus<_, 2> = unsafe { Bus::new((D1::new(), D0::new())) };
+//! ```
+//!
+//! A bus has at most 32 data pads.
+
+use crate::consts::Unsigned;
+
+/// A data pin of a parallel interface
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a parallel bus data pin",
+    note = "the `bus::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the data pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// The data bit; `3` for `CSI_DATA03`
+    const BIT: u32;
+    /// A type tag for the interface
+    type Interface;
+    /// The interface module; `Const<1>` for `SEMC1`
+    type Module: Unsigned;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    const BIT: u32 = P::BIT;
+    type Interface = P::Interface;
+    type Module = P::Module;
+}
+
+/// Prepare a data pin
+///
+/// # Safety
+///
+/// `prepare()` inherits all the unsafety that comes from the `IOMUX` supertrait.
+/// In particular, we cannot be sure that the implementation's pointers are correct.
+/// It may also write a daisy configuration that's incorrect.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, false, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// A tuple of `WIDTH` data pins
+///
+/// Implemented for tuples of one through 32 [`Pin`]s that share an interface
+/// and a module.
+pub trait Data<const WIDTH: usize>: private::Sealed {
+    /// The pins' interface
+    type Interface;
+    /// The pins' module
+    type Module: Unsigned;
+    /// The data bit of each pin, in tuple order
+    const BITS: [u32; WIDTH];
+    /// Prepare every pin
+    fn prepare(&mut self);
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! data {
+    ($first:ident 0 $($pin:ident $idx:tt)*; $width:expr) => {
+        impl<$first $(, $pin)*> private::Sealed for ($first, $($pin,)*) {}
+
+        impl<$first: Pin $(, $pin: Pin<Interface = $first::Interface, Module = $first::Module>)*>
+            Data<{ $width }> for ($first, $($pin,)*)
+        {
+            type Interface = $first::Interface;
+            type Module = $first::Module;
+            const BITS: [u32; $width] = [$first::BIT $(, $pin::BIT)*];
+            fn prepare(&mut self) {
+                prepare(&mut self.0);
+                $(prepare(&mut self.$idx);)*
+            }
+        }
+    };
+}
+
+macro_rules! data_tuples {
+    ([$($pin:ident $idx:tt)+] $last:tt) => {
+        data!($($pin $idx)+; $last + 1);
+    };
+    ([$($pin:ident $idx:tt)+] $last:tt $next:ident $nidx:tt $($rest:tt)*) => {
+        data!($($pin $idx)+; $last + 1);
+        data_tuples!([$($pin $idx)+ $next $nidx] $nidx $($rest)*);
+    };
+}
+
+data_tuples!([P0 0] 0
+    P1 1 P2 2 P3 3 P4 4 P5 5 P6 6 P7 7
+    P8 8 P9 9 P10 10 P11 11 P12 12 P13 13 P14 14 P15 15
+    P16 16 P17 17 P18 18 P19 19 P20 20 P21 21 P22 22 P23 23
+    P24 24 P25 25 P26 26 P27 27 P28 28 P29 29 P30 30 P31 31
+);
+
+/// The data pins of a `WIDTH`-bit parallel bus
+///
+/// `T` is a tuple of [`Pin`]s, one for each bit, starting at bit 0.
+#[derive(Debug)]
+pub struct Bus<T, const WIDTH: usize> {
+    /// The data pins, in bit order
+    pub data: T,
+}
+
+impl<T: Data<WIDTH>, const WIDTH: usize> Bus<T, WIDTH> {
+    /// The bus' module number
+    pub const MODULE: u8 = <T::Module as Unsigned>::USIZE as u8;
+
+    /// Group the data pins
+    pub const fn new(data: T) -> Self {
+        const {
+            let mut bit = 0;
+            while bit < WIDTH {
+                assert!(
+                    T::BITS[bit] == bit as u32,
+                    "the bus pins must be data bits 0 through WIDTH - 1, in order"
+                );
+                bit += 1;
+            }
+        };
+        Self { data }
+    }
+
+    /// Prepare all of the data pins
+    pub fn prepare(&mut self) {
+        self.data.prepare();
+    }
+}
@@ -0,0 +1,165 @@
+//! Invariant checks for pad definitions
+//!
+//! These routines walk pad and daisy definitions, and assert that the
+//! definitions make sense for the hardware. They're useful as a smoke test
+//! when adding new chip families, and as a canary in downstream CI.
+//!
+//! Each chip module exposes a `self_check()` function that runs these checks
+//! over all of its pads and daisy values. You may also call these functions
+//! on your own pad definitions.
+//!
+//! Pin implementations check their alternate values when they're defined;
+//! an alternate that exceeds the MUX_MODE field will not compile.
+
+use crate::{Daisy, ErasedPad};
+use core::ops::Range;
+
+/// The width of the SELECT_INPUT field in a daisy register.
+const DAISY_MASK: u32 = 0b111;
+
+/// An invariant that does not hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// More than one pad uses the register at this address.
+    DuplicateRegister(u32),
+    /// The pad register at this address is outside of the IOMUXC.
+    RegisterOutOfRange(u32),
+    /// The daisy register at this address is outside of the IOMUXC.
+    DaisyOutOfRange(u32),
+    /// The daisy value does not fit in the register's input select field.
+    DaisyValueOutOfRange {
+        /// The daisy register address.
+        reg: u32,
+        /// The invalid daisy value.
+        value: u32,
+    },
+    /// More than one daisy constant describes this register and value.
+    DuplicateDaisy {
+        /// The daisy register address.
+        reg: u32,
+        /// The duplicated daisy value.
+        value: u32,
+    },
+}
+
+fn in_range(addr: u32, iomuxc: &[Range<u32>]) -> bool {
+    iomuxc.iter().any(|range| range.contains(&addr))
+}
+
+/// Check the pads' registers
+///
+/// All pads, across all `groups`, must use unique registers. All registers must
+/// be within one of the `iomuxc` address ranges.
+pub fn pads(groups: &[&[ErasedPad]], iomuxc: &[Range<u32>]) -> Result<(), Violation> {
+    let registers = || {
+        groups
+            .iter()
+            .flat_map(|group| group.iter())
+            .map(ErasedPad::location)
+            .flat_map(|location| [location.mux, location.pad])
+    };
+    for (idx, reg) in registers().enumerate() {
+        if !in_range(reg, iomuxc) {
+            return Err(Violation::RegisterOutOfRange(reg));
+        }
+        if registers().skip(idx + 1).any(|other| other == reg) {
+            return Err(Violation::DuplicateRegister(reg));
+        }
+    }
+    Ok(())
+}
+
+/// Check the daisy values
+///
+/// All daisy registers must be within one of the `iomuxc` address ranges,
+/// and all values must fit within the input select field. Each register and
+/// value pair must be unique across all `groups`.
+pub fn daisies(groups: &[&[Daisy]], iomuxc: &[Range<u32>]) -> Result<(), Violation> {
+    let daisies = || groups.iter().flat_map(|group| group.iter());
+    for (idx, daisy) in daisies().enumerate() {
+        let reg = daisy.reg as u32;
+        let value = daisy.value;
+        if !in_range(reg, iomuxc) {
+            return Err(Violation::DaisyOutOfRange(reg));
+        }
+        if value & !DAISY_MASK != 0 {
+            return Err(Violation::DaisyValueOutOfRange { reg, value });
+        }
+        if daisies().skip(idx + 1).any(|other| other == daisy) {
+            return Err(Violation::DuplicateDaisy { reg, value });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pad;
+
+    const IOMUXC: &[Range<u32>] = &[0x100..0x180, 0x180..0x200];
+
+    #[test]
+    fn pads_ok() {
+        let group = [
+            unsafe { Pad::<0x100, 0x180>::new() }.erase(),
+            unsafe { Pad::<0x104, 0x184>::new() }.erase(),
+        ];
+        assert_eq!(pads(&[&group], IOMUXC), Ok(()));
+    }
+
+    #[test]
+    fn pads_duplicate_across_groups() {
+        let left = [unsafe { Pad::<0x100, 0x180>::new() }.erase()];
+        let right = [unsafe { Pad::<0x104, 0x180>::new() }.erase()];
+        assert_eq!(
+            pads(&[&left, &right], IOMUXC),
+            Err(Violation::DuplicateRegister(0x180))
+        );
+    }
+
+    #[test]
+    fn pads_out_of_range() {
+        let group = [unsafe { Pad::<0x100, 0x200>::new() }.erase()];
+        assert_eq!(
+            pads(&[&group], IOMUXC),
+            Err(Violation::RegisterOutOfRange(0x200))
+        );
+    }
+
+    #[test]
+    fn daisies_ok() {
+        let group = [
+            Daisy::new(0x1F0 as *mut u32, 0),
+            Daisy::new(0x1F0 as *mut u32, 1),
+        ];
+        assert_eq!(daisies(&[&group], IOMUXC), Ok(()));
+    }
+
+    #[test]
+    fn daisies_invalid() {
+        let group = [Daisy::new(0x0F0 as *mut u32, 0)];
+        assert_eq!(
+            daisies(&[&group], IOMUXC),
+            Err(Violation::DaisyOutOfRange(0x0F0))
+        );
+
+        let group = [Daisy::new(0x1F0 as *mut u32, 8)];
+        assert_eq!(
+            daisies(&[&group], IOMUXC),
+            Err(Violation::DaisyValueOutOfRange {
+                reg: 0x1F0,
+                value: 8
+            })
+        );
+
+        let group = [Daisy::new(0x1F0 as *mut u32, 1)];
+        assert_eq!(
+            daisies(&[&group, &group], IOMUXC),
+            Err(Violation::DuplicateDaisy {
+                reg: 0x1F0,
+                value: 1
+            })
+        );
+    }
+}
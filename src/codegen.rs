@@ -0,0 +1,142 @@
+//! Generate board pin structs in a build script
+//!
+//! A board crate that routes only some of a chip's pads can generate its own
+//! pin struct, instead of writing a [`define_board_pins!`] invocation by hand.
+//! List the pins by pad name. [`board_pins()`] checks each name against the
+//! chip's register map, and returns the Rust source for the struct.
+//!
+//! ```no_run
+//! // build.rs
+//! use imxrt_iomuxc::{codegen, imxrt1060};
+//!
+//! let pins = [
+//!     codegen::Pin { field: "led", pad: "GPIO_B0_03", doc: "The LED" },
+//!     codegen::Pin { field: "button", pad: "GPIO_AD_B0_02", doc: "The button" },
+//! ];
+//! let source = codegen::board_pins("imxrt1060", "Pins", imxrt1060::REGISTER_MAP, &pins)
+//!     .unwrap_or_else(|err| panic!("{err}"));
+//! let out = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+//! std::fs::write(out.join("pins.rs"), source).unwrap();
+//! ```
+//!
+//! Then, include the source in the board crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/pins.rs"));
+//! ```
+//!
+//! The struct has the same methods as any other [`define_board_pins!`] struct.
+//! The build script needs this package, with the `std` feature and the chip
+//! feature, as a build dependency.
+//!
+//! [`define_board_pins!`]: crate::define_board_pins
+
+use crate::register_map::PadRegisters;
+use core::fmt::{self, Write};
+use std::string::String;
+
+/// A board pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pin<'a> {
+    /// The struct field, like `led`
+    pub field: &'a str,
+    /// The pad, like `GPIO_B0_03`
+    pub pad: &'a str,
+    /// The field's documentation
+    pub doc: &'a str,
+}
+
+/// The register map doesn't have the pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownPad<'a>(pub &'a str);
+
+impl fmt::Display for UnknownPad<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown pad {}", self.0)
+    }
+}
+
+/// Returns the `Pads` group and field of a pad, like `("gpio_b0", "p03")`
+fn field_of(pad: &str) -> Option<(String, String)> {
+    let (group, number) = pad.rsplit_once('_')?;
+    if number.is_empty() || !number.bytes().all(|digit| digit.is_ascii_digit()) {
+        return None;
+    }
+    Some((group.to_ascii_lowercase(), std::format!("p{number}")))
+}
+
+/// Generate a board pin struct
+///
+/// `chip` is the chip module, like `imxrt1060`, and `map` is the chip's
+/// `REGISTER_MAP`. `name` names the struct. Fails if `map` doesn't have one of
+/// the pads.
+pub fn board_pins<'a>(
+    chip: &str,
+    name: &str,
+    map: &[PadRegisters],
+    pins: &[Pin<'a>],
+) -> Result<String, UnknownPad<'a>> {
+    let mut source = String::new();
+    // Writing to a String never fails.
+    let _ = writeln!(source, "imxrt_iomuxc::define_board_pins! {{");
+    let _ = writeln!(
+        source,
+        "    pub struct {name}(imxrt_iomuxc::{chip}::Pads) {{"
+    );
+    for pin in pins {
+        let (group, field) = field_of(pin.pad)
+            .filter(|_| map.iter().any(|registers| registers.name == pin.pad))
+            .ok_or(UnknownPad(pin.pad))?;
+        let _ = writeln!(source, "        /// {}", pin.doc);
+        let _ = writeln!(
+            source,
+            "        pub {}: imxrt_iomuxc::{chip}::{group}::{} = {group}.{field},",
+            pin.field, pin.pad
+        );
+    }
+    let _ = writeln!(source, "    }}");
+    let _ = writeln!(source, "}}");
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{board_pins, Pin, UnknownPad};
+    use crate::register_map::PadRegisters;
+
+    const MAP: &[PadRegisters] = &[PadRegisters {
+        name: "GPIO_B0_03",
+        mux: 4,
+        pad: 8,
+        reset: None,
+        daisies: &[],
+    }];
+
+    #[test]
+    fn generate_pins() {
+        let led = Pin {
+            field: "led",
+            pad: "GPIO_B0_03",
+            doc: "The LED",
+        };
+        assert_eq!(
+            board_pins("imxrt1060", "Pins", MAP, &[led]).unwrap(),
+            concat!(
+                "imxrt_iomuxc::define_board_pins! {\n",
+                "    pub struct Pins(imxrt_iomuxc::imxrt1060::Pads) {\n",
+                "        /// The LED\n",
+                "        pub led: imxrt_iomuxc::imxrt1060::gpio_b0::GPIO_B0_03 = gpio_b0.p03,\n",
+                "    }\n",
+                "}\n",
+            )
+        );
+        let button = Pin {
+            pad: "GPIO_AD_B0_02",
+            ..led
+        };
+        assert_eq!(
+            board_pins("imxrt1060", "Pins", MAP, &[led, button]),
+            Err(UnknownPad("GPIO_AD_B0_02"))
+        );
+    }
+}
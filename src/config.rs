@@ -1,6 +1,6 @@
 //! Pad configuration
 
-use crate::Iomuxc;
+use crate::{ErasedPad, Iomuxc};
 use core::ptr;
 
 /// Applies the configuration `config` for the supplied pad
@@ -9,6 +9,10 @@ use core::ptr;
 /// resistors, and other configurations. See [`Config`](struct.Config.html)
 /// for possible configurations.
 ///
+/// When `config` sets every field, either from [`Config::zero()`] or by setting
+/// each field of a [`Config::modify()`], `configure` writes the pad register
+/// without reading it. Reserved bits are written as zero.
+///
 /// # Example
 ///
 /// ```no_run
@@ -26,19 +30,190 @@ use core::ptr;
 #[inline(always)]
 pub fn configure<I: Iomuxc>(pad: &mut I, config: Config) {
     // Safety: same justification as set_sion.
-    unsafe {
-        let cfg = ptr::read_volatile(pad.pad());
-        let cfg = (cfg & !config.mask) | config.value;
-        ptr::write_volatile(pad.pad(), cfg);
+    unsafe { configure_register(pad.pad(), config) }
+}
+
+/// Applies `config` to the pad inside a critical section
+///
+/// Like [`configure()`], but `critical_section` runs the register
+/// read-modify-write. `critical_section` must call its argument once, with
+/// interrupts disabled, or with a lock held. Use this when thread and
+/// interrupt code both configure the same pad, and the code shares the
+/// pad through unsafe code.
+///
+/// The package doesn't depend on a critical section implementation. Supply
+/// yours:
+///
+/// ```no_run
+/// use imxrt_iomuxc::{configure_cs, Config, PullKeeper};
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_03;
+/// # mod critical_section { pub fn with<R>(f: impl FnOnce(()) -> R) -> R { f(()) } }
+///
+/// let mut pad = unsafe { GPIO_AD_B0_03::new() };
+/// const PULLUP: Config = Config::modify().set_pull_keeper(Some(PullKeeper::Pullup22k));
+///
+/// configure_cs(&mut pad, PULLUP, |f| critical_section::with(|_| f()));
+/// ```
+pub fn configure_cs<I: Iomuxc>(
+    pad: &mut I,
+    config: Config,
+    critical_section: impl FnOnce(&mut dyn FnMut()),
+) {
+    let pad = pad.pad();
+    // Safety: same justification as set_sion. The critical section makes
+    // the read-modify-write atomic.
+    critical_section(&mut || unsafe { configure_register(pad, config) });
+}
+
+/// Applies `config` to the pad register `pad`
+///
+/// This function is not generic, so all pads share one copy of the
+/// configuration code.
+///
+/// # Safety
+///
+/// `pad` must be a valid pad register. The caller must ensure that the
+/// read-modify-write operation does not race.
+pub(crate) unsafe fn configure_register(pad: *mut u32, config: Config) {
+    crate::lock::check_pad(pad);
+    #[cfg(feature = "xip-guard")]
+    crate::xip::check_pad(pad);
+    match config.mask {
+        // Nothing to change.
+        0 => {}
+        // Every field changes; skip the read.
+        mask if mask & FIELDS_MASK == FIELDS_MASK => {
+            ptr::write_volatile(pad, config.value);
+            #[cfg(any(feature = "log", feature = "defmt"))]
+            crate::trace::write(pad, config.value);
+        }
+        mask => {
+            let cfg = ptr::read_volatile(pad);
+            let cfg = (cfg & !mask) | config.value;
+            ptr::write_volatile(pad, cfg);
+            #[cfg(any(feature = "log", feature = "defmt"))]
+            crate::trace::write(pad, cfg);
+        }
+    }
+}
+
+/// Applies `config` to the pad only if the pad register doesn't already match
+///
+/// Unlike [`configure()`], this function always reads the pad register. If the
+/// configured fields already have their values, the function skips the write,
+/// and returns `false`. Otherwise, it writes the register, and returns `true`.
+///
+/// Use this when you re-apply a pin configuration that's typically unchanged,
+/// like on every wake from a low-power state.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{configure_if_changed, Config, SlewRate};
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_03;
+///
+/// let mut pad = unsafe { GPIO_AD_B0_03::new() };
+/// const CONFIG: Config = Config::modify().set_slew_rate(SlewRate::Slow);
+///
+/// if configure_if_changed(&mut pad, CONFIG) {
+///     // The pad was not configured as expected...
+/// }
+/// ```
+pub fn configure_if_changed<I: Iomuxc>(pad: &mut I, config: Config) -> bool {
+    // Safety: same justification as set_sion.
+    unsafe { configure_register_if_changed(pad.pad(), config) }
+}
+
+/// The non-generic implementation of [`configure_if_changed()`].
+///
+/// # Safety
+///
+/// See [`configure_register()`].
+unsafe fn configure_register_if_changed(pad: *mut u32, config: Config) -> bool {
+    crate::lock::check_pad(pad);
+    #[cfg(feature = "xip-guard")]
+    crate::xip::check_pad(pad);
+    let current = ptr::read_volatile(pad);
+    let next = if config.mask & FIELDS_MASK == FIELDS_MASK {
+        config.value
+    } else {
+        (current & !config.mask) | config.value
+    };
+    let changed = current != next;
+    if changed {
+        ptr::write_volatile(pad, next);
+        #[cfg(any(feature = "log", feature = "defmt"))]
+        crate::trace::write(pad, next);
+    }
+    changed
+}
+
+/// Applies each configuration to its erased pad
+///
+/// `table` is a sequence of `(pad, config)` pairs. All pads share one
+/// [`configure()`] implementation, so describe a board's pin configuration
+/// as a table, and review it against the schematic. To configure parallel
+/// slices of pads and configurations, `zip()` them.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::{configure_all, Config, PullKeeper, SlewRate};
+/// use imxrt_iomuxc::imxrt1060::Pads;
+///
+/// const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+/// const LED: Config = Config::zero().set_slew_rate(SlewRate::Fast);
+///
+/// let mut pads = unsafe { Pads::new() }.erase();
+/// configure_all([
+///     (&mut pads.gpio_ad_b0[12], UART),
+///     (&mut pads.gpio_b0[3], LED),
+/// ]);
+///
+/// let uart = &mut pads.gpio_ad_b0[12..14];
+/// configure_all(uart.iter_mut().zip([UART, UART]));
+/// ```
+pub fn configure_all<'a>(table: impl IntoIterator<Item = (&'a mut ErasedPad, Config)>) {
+    for (pad, config) in table {
+        configure(pad, config);
     }
 }
 
+/// Applies configurations to a list of pads
+///
+/// Each entry is a `(pad, config)` pair. `configure!` expands each entry into
+/// a call to [`configure()`](crate::configure()). A configuration created with
+/// [`Config::zero()`] is written without first reading the register, and a
+/// configuration that modifies nothing is skipped.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::{Config, PullKeeper, SlewRate};
+/// use imxrt_iomuxc::imxrt1060::Pads;
+///
+/// const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+/// const LED: Config = Config::zero().set_slew_rate(SlewRate::Fast);
+///
+/// let mut pads = unsafe { Pads::new() };
+/// imxrt_iomuxc::configure! {
+///     (pads.gpio_ad_b0.p12, UART),
+///     (pads.gpio_ad_b0.p13, UART),
+///     (pads.gpio_b0.p03, LED),
+/// }
+/// ```
+#[macro_export]
+macro_rules! configure {
+    ($(($pad:expr, $config:expr)),* $(,)?) => {{
+        $($crate::configure(&mut $pad, $config);)*
+    }};
+}
+
 const HYSTERESIS_SHIFT: u32 = 16;
 const HYSTERESIS_MASK: u32 = 1 << HYSTERESIS_SHIFT;
 
 /// The hysteresis (HYS) bit controls whether a pin acts as a Schmitt trigger,
 /// which is a comparator remembering its last input state (hysteresis).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum Hysteresis {
     Enabled = 1 << HYSTERESIS_SHIFT,
@@ -95,6 +270,7 @@ const PULL_KEEPER_MASK: u32 = PULLKEEP_MASK | PULLUPDOWN_MASK | PULL_KEEP_SELECT
 
 /// The pull up, pull down, or keeper configuration.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum PullKeeper {
     /// 100KOhm pull **down**
@@ -126,6 +302,7 @@ const OPENDRAIN_MASK: u32 = 1 << OPENDRAIN_SHIFT;
 /// the pad and an external component is bi-directional. If disabled, then
 /// the output driver drives logic 1 and logic 0.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum OpenDrain {
     Enabled = 1 << OPENDRAIN_SHIFT,
@@ -149,6 +326,7 @@ const SPEED_MASK: u32 = 0b11 << SPEED_SHIFT;
 /// See Operating Frequency table in the GPIO block guide in the reference
 /// manual for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum Speed {
     Low = 0b00 << SPEED_SHIFT,
@@ -166,6 +344,7 @@ const DRIVE_STRENGTH_MASK: u32 = 0b111 << DRIVE_STRENGTH_SHIFT;
 /// output and its load. To achieve maximal transferred power, the impedance of the driver has to
 /// match the load impedance.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum DriveStrength {
     Disabled = 0b000 << DRIVE_STRENGTH_SHIFT,
@@ -191,12 +370,21 @@ const SLEW_RATE_MASK: u32 = 1 << SLEW_RATE_SHIFT;
 /// Since rapidly changing states consume more power and generate spikes,
 /// it should be enabled only when necessary.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[repr(u32)]
 pub enum SlewRate {
     Fast = 1 << SLEW_RATE_SHIFT,
     Slow = 0 << SLEW_RATE_SHIFT,
 }
 
+/// All of the configuration fields.
+const FIELDS_MASK: u32 = HYSTERESIS_MASK
+    | PULL_KEEPER_MASK
+    | OPENDRAIN_MASK
+    | SPEED_MASK
+    | DRIVE_STRENGTH_MASK
+    | SLEW_RATE_MASK;
+
 /// A configuration capable of compile-time, `const` configuration:
 ///
 /// ```
@@ -209,9 +397,11 @@ pub enum SlewRate {
 ///
 /// Use [`configure()`](fn.configure.html) to set configurations to pads.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "remote", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub struct Config {
-    value: u32,
-    mask: u32,
+    pub(crate) value: u32,
+    pub(crate) mask: u32,
 }
 
 impl Config {
@@ -312,6 +502,21 @@ impl Config {
         self.mask & Self::ZERO_BIT != 0
     }
 
+    /// Returns the configuration's pad register value
+    ///
+    /// Fields that the configuration does not set are zero. Use [`mask()`](Self::mask)
+    /// to find the fields that the configuration sets.
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns the mask of pad register bits that the configuration sets
+    ///
+    /// A [`zero()`](Self::zero) configuration sets every bit.
+    pub const fn mask(&self) -> u32 {
+        self.mask
+    }
+
     /// Set the hysteresis bit
     pub const fn set_hysteresis(mut self, hys: Hysteresis) -> Self {
         self.value = (self.value & !HYSTERESIS_MASK) | (hys as u32);
@@ -327,7 +532,7 @@ impl Config {
             None => 0u32,
             Some(pk) => pk as u32,
         };
-        self.value = (self.value & !PULL_KEEPER_MASK) | (pk as u32);
+        self.value = (self.value & !PULL_KEEPER_MASK) | pk;
         self.mask |= PULL_KEEPER_MASK;
         self
     }
@@ -359,6 +564,167 @@ impl Config {
         self.mask |= SLEW_RATE_MASK;
         self
     }
+
+    /// Combine two configurations, preferring the fields of `other`
+    ///
+    /// The result sets every field that either configuration sets. When both
+    /// set a field, `other`'s value wins. If `other` is a [`zero()`](Self::zero)
+    /// configuration, the result is `other`. If `self` is a `zero()`
+    /// configuration, so is the result.
+    ///
+    /// Use `merge()` to apply per-pin overrides to a shared base configuration
+    /// in `const` items. The `|` operator does the same.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, DriveStrength, SlewRate, Speed};
+    ///
+    /// const BASE: Config = Config::zero()
+    ///     .set_speed(Speed::Fast)
+    ///     .set_drive_strength(DriveStrength::R0_4);
+    /// const FAST_EDGES: Config = BASE.merge(Config::modify().set_slew_rate(SlewRate::Fast));
+    ///
+    /// assert_eq!(FAST_EDGES, BASE.set_slew_rate(SlewRate::Fast));
+    /// assert_eq!(FAST_EDGES, BASE | Config::modify().set_slew_rate(SlewRate::Fast));
+    /// ```
+    pub const fn merge(self, other: Config) -> Self {
+        Config {
+            value: (self.value & !other.mask) | other.value,
+            mask: self.mask | other.mask,
+        }
+    }
+}
+
+impl core::ops::BitOr for Config {
+    type Output = Config;
+    /// See [`Config::merge()`]
+    fn bitor(self, other: Config) -> Config {
+        self.merge(other)
+    }
+}
+
+impl core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, other: Config) {
+        *self = self.merge(other);
+    }
+}
+
+/// The fields of a configuration
+///
+/// A field is `None` if the configuration doesn't set it.
+#[cfg(any(feature = "export", feature = "text"))]
+pub(crate) struct Fields {
+    pub(crate) hysteresis: Option<Hysteresis>,
+    pub(crate) pull_keeper: Option<Option<PullKeeper>>,
+    pub(crate) open_drain: Option<OpenDrain>,
+    pub(crate) speed: Option<Speed>,
+    pub(crate) drive_strength: Option<DriveStrength>,
+    pub(crate) slew_rate: Option<SlewRate>,
+}
+
+/// Decode the fields that `config` sets
+#[cfg(any(feature = "export", feature = "text"))]
+pub(crate) fn fields(config: Config) -> Fields {
+    let Config { value, mask } = config;
+    let hysteresis = match value & HYSTERESIS_MASK {
+        0 => Hysteresis::Disabled,
+        _ => Hysteresis::Enabled,
+    };
+    let pull_keeper = if value & PULLKEEP_MASK == 0 {
+        None
+    } else if value & PULL_KEEP_SELECT_MASK == 0 {
+        Some(PullKeeper::Keeper)
+    } else {
+        Some(match (value & PULLUPDOWN_MASK) >> PULLUPDOWN_SHIFT {
+            0b00 => PullKeeper::Pulldown100k,
+            0b01 => PullKeeper::Pullup47k,
+            0b10 => PullKeeper::Pullup100k,
+            _ => PullKeeper::Pullup22k,
+        })
+    };
+    let open_drain = match value & OPENDRAIN_MASK {
+        0 => OpenDrain::Disabled,
+        _ => OpenDrain::Enabled,
+    };
+    let speed = match (value & SPEED_MASK) >> SPEED_SHIFT {
+        0b00 => Speed::Low,
+        0b01 => Speed::Medium,
+        0b10 => Speed::Fast,
+        _ => Speed::Max,
+    };
+    let drive_strength = match (value & DRIVE_STRENGTH_MASK) >> DRIVE_STRENGTH_SHIFT {
+        0b000 => DriveStrength::Disabled,
+        0b001 => DriveStrength::R0,
+        0b010 => DriveStrength::R0_2,
+        0b011 => DriveStrength::R0_3,
+        0b100 => DriveStrength::R0_4,
+        0b101 => DriveStrength::R0_5,
+        0b110 => DriveStrength::R0_6,
+        _ => DriveStrength::R0_7,
+    };
+    let slew_rate = match value & SLEW_RATE_MASK {
+        0 => SlewRate::Slow,
+        _ => SlewRate::Fast,
+    };
+    let set = |field_mask: u32| mask & field_mask != 0;
+    Fields {
+        hysteresis: set(HYSTERESIS_MASK).then_some(hysteresis),
+        pull_keeper: set(PULL_KEEPER_MASK).then_some(pull_keeper),
+        open_drain: set(OPENDRAIN_MASK).then_some(open_drain),
+        speed: set(SPEED_MASK).then_some(speed),
+        drive_strength: set(DRIVE_STRENGTH_MASK).then_some(drive_strength),
+        slew_rate: set(SLEW_RATE_MASK).then_some(slew_rate),
+    }
+}
+
+/// Returns a configuration that sets every field to its value in the pad
+/// register `value`
+#[cfg(any(feature = "export", feature = "text"))]
+pub(crate) const fn from_register(value: u32) -> Config {
+    Config {
+        value: value & FIELDS_MASK,
+        mask: FIELDS_MASK,
+    }
+}
+
+/// Write the `Config::zero()` expression that reproduces the pad register `value`
+///
+/// Bits outside of the configuration fields are not written.
+#[cfg(feature = "export")]
+pub(crate) fn write_expr(value: u32, out: &mut dyn core::fmt::Write) -> core::fmt::Result {
+    let Fields {
+        hysteresis: Some(hysteresis),
+        pull_keeper: Some(pull_keeper),
+        open_drain: Some(open_drain),
+        speed: Some(speed),
+        drive_strength: Some(drive_strength),
+        slew_rate: Some(slew_rate),
+    } = fields(from_register(value))
+    else {
+        unreachable!("every field is set");
+    };
+
+    write!(out, "imxrt_iomuxc::Config::zero()")?;
+    write!(
+        out,
+        ".set_hysteresis(imxrt_iomuxc::Hysteresis::{hysteresis:?})"
+    )?;
+    match pull_keeper {
+        None => write!(out, ".set_pull_keeper(None)")?,
+        Some(pk) => write!(
+            out,
+            ".set_pull_keeper(Some(imxrt_iomuxc::PullKeeper::{pk:?}))"
+        )?,
+    }
+    write!(
+        out,
+        ".set_open_drain(imxrt_iomuxc::OpenDrain::{open_drain:?})"
+    )?;
+    write!(out, ".set_speed(imxrt_iomuxc::Speed::{speed:?})")?;
+    write!(
+        out,
+        ".set_drive_strength(imxrt_iomuxc::DriveStrength::{drive_strength:?})"
+    )?;
+    write!(out, ".set_slew_rate(imxrt_iomuxc::SlewRate::{slew_rate:?})")
 }
 
 #[cfg(test)]
@@ -423,6 +789,62 @@ mod tests {
         assert_eq!(pad.0, 0);
     }
 
+    #[test]
+    fn modify_set_all_skips_read() {
+        // A reserved bit that a read-modify-write would keep.
+        let mut pad = Pad(PAD_BITMASK | 1 << 1);
+        const CONFIG: Config = Config::modify()
+            .set_hysteresis(Hysteresis::Disabled)
+            .set_pull_keeper(None)
+            .set_open_drain(OpenDrain::Disabled)
+            .set_speed(Speed::Low)
+            .set_drive_strength(DriveStrength::Disabled)
+            .set_slew_rate(SlewRate::Fast);
+
+        configure(&mut pad, CONFIG);
+
+        assert_eq!(pad.0, SlewRate::Fast as u32);
+    }
+
+    #[test]
+    fn configure_if_changed_skips_matching() {
+        let mut pad = Pad(PAD_BITMASK);
+        assert!(!configure_if_changed(
+            &mut pad,
+            Config::modify().set_slew_rate(SlewRate::Fast)
+        ));
+        assert_eq!(pad.0, PAD_BITMASK);
+
+        assert!(configure_if_changed(
+            &mut pad,
+            Config::modify().set_slew_rate(SlewRate::Slow)
+        ));
+        assert_eq!(pad.0, PAD_BITMASK & !1);
+
+        assert!(configure_if_changed(&mut pad, Config::zero()));
+        assert_eq!(pad.0, 0);
+        assert!(!configure_if_changed(&mut pad, Config::zero()));
+    }
+
+    #[test]
+    fn modify_nothing() {
+        let mut pad = Pad(PAD_BITMASK);
+        configure(&mut pad, Config::modify());
+        assert_eq!(pad.0, PAD_BITMASK);
+    }
+
+    #[test]
+    fn configure_macro() {
+        let mut first = PAD_ALL_HIGH;
+        let mut second = Pad(0);
+        crate::configure! {
+            (first, Config::zero()),
+            (second, Config::modify().set_slew_rate(SlewRate::Fast)),
+        }
+        assert_eq!(first.0, 0);
+        assert_eq!(second.0, 1);
+    }
+
     #[test]
     fn pull_keeper_none() {
         let mut pad = Pad(0);
@@ -430,6 +852,64 @@ mod tests {
         assert_eq!(pad.0, 0);
     }
 
+    #[test]
+    fn value_and_mask() {
+        let config = Config::modify().set_speed(Speed::Fast);
+        assert_eq!(config.value(), Speed::Fast as u32);
+        assert_eq!(config.mask(), SPEED_MASK);
+        assert_eq!(Config::zero().mask(), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn merge_prefers_other() {
+        let base = Config::zero()
+            .set_speed(Speed::Fast)
+            .set_slew_rate(SlewRate::Fast);
+        let merged = base | Config::modify().set_slew_rate(SlewRate::Slow);
+        assert_eq!(merged, base.set_slew_rate(SlewRate::Slow));
+        assert!(merged.is_zero());
+
+        let modify = Config::modify().set_speed(Speed::Max);
+        assert_eq!(modify.merge(Config::zero()), Config::zero());
+        assert_eq!(modify.merge(Config::modify()), modify);
+
+        let mut config = Config::modify();
+        config |= modify;
+        assert_eq!(config, modify);
+    }
+
+    #[test]
+    fn configure_all_pairs() {
+        let mut registers = [[0u32; 2]; 2];
+        let [first, second] = &mut registers;
+        let mut pads = [
+            unsafe { ErasedPad::new(&mut first[0], &mut first[1]) },
+            unsafe { ErasedPad::new(&mut second[0], &mut second[1]) },
+        ];
+        configure_all(pads.iter_mut().zip([
+            Config::modify().set_slew_rate(SlewRate::Fast),
+            Config::modify().set_open_drain(OpenDrain::Enabled),
+        ]));
+        assert_eq!(registers[0][1], SlewRate::Fast as u32);
+        assert_eq!(registers[1][1], OpenDrain::Enabled as u32);
+    }
+
+    #[test]
+    fn configure_in_critical_section() {
+        let mut pad = Pad(0);
+        let mut entered = 0;
+        configure_cs(
+            &mut pad,
+            Config::modify().set_slew_rate(SlewRate::Fast),
+            |f| {
+                entered += 1;
+                f();
+            },
+        );
+        assert_eq!(entered, 1);
+        assert_eq!(pad.0, SlewRate::Fast as u32);
+    }
+
     #[test]
     fn pull_keeper_keeper() {
         let mut pad = Pad(0);
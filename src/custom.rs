@@ -0,0 +1,85 @@
+//! Pads for unsupported parts.
+
+/// Define a pad for a part that this package doesn't support
+///
+/// `define_pad!` defines a pad type in your crate. The type implements
+/// [`Iomuxc`](crate::Iomuxc), so [`configure()`](fn@crate::configure),
+/// [`alternate()`](crate::alternate), and the other pad functions accept it.
+/// Since the type is local to your crate, you may implement the peripheral
+/// pin traits for it. Then, the peripheral `prepare()` functions accept the
+/// pad, too. Use [`Daisy::new_unchecked()`](crate::Daisy::new_unchecked) for
+/// your pins' daisy values.
+///
+/// The macro defines two methods on the pad:
+///
+/// - `pub const unsafe fn new() -> Self` creates a handle to the pad. The
+///   same safety rules as [`Pad::new()`](crate::Pad::new) apply.
+/// - `pub fn erase(self) -> ErasedPad` erases the pad's type.
+///
+/// The MUX and PAD register addresses must be correct for your part. The
+/// package can't check them.
+///
+/// # Example
+///
+/// ```
+/// use imxrt_iomuxc::{define_pad, lpuart, consts::Const, Daisy};
+///
+/// define_pad! {
+///     /// The pad for LPUART7_TX.
+///     pub struct UART7_TX { mux: 0x400E_8010, pad: 0x400E_8254 }
+/// }
+///
+/// impl lpuart::Pin for UART7_TX {
+///     const ALT: u32 = 2;
+///     const DAISY: Option<Daisy> = Some(unsafe { Daisy::new_unchecked(0x400E_8630 as *mut u32, 1) });
+///     type Direction = lpuart::Tx;
+///     type Module = Const<7>;
+/// }
+///
+/// let pad = unsafe { UART7_TX::new() };
+/// assert_eq!(pad.erase().location().mux, 0x400E_8010);
+/// ```
+#[macro_export]
+macro_rules! define_pad {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident { mux: $mux:expr, pad: $pad:expr $(,)? }) => {
+        $(#[$attr])*
+        $vis struct $name {
+            _private: (),
+        }
+
+        impl $name {
+            /// Create a handle to the pad
+            ///
+            /// # Safety
+            ///
+            /// The same rules as `imxrt_iomuxc::Pad::new()` apply.
+            #[inline(always)]
+            #[allow(dead_code)]
+            pub const unsafe fn new() -> Self {
+                Self { _private: () }
+            }
+
+            /// Erase the pad's type
+            #[inline(always)]
+            #[allow(dead_code)]
+            pub fn erase(self) -> $crate::ErasedPad {
+                // Safety: the pad handle is consumed, so the erased pad
+                // takes over its registers.
+                unsafe { $crate::ErasedPad::new($mux as *mut u32, $pad as *mut u32) }
+            }
+        }
+
+        impl $crate::__private::Sealed for $name {}
+
+        unsafe impl $crate::Iomuxc for $name {
+            #[inline(always)]
+            fn mux(&mut self) -> *mut u32 {
+                $mux as *mut u32
+            }
+            #[inline(always)]
+            fn pad(&mut self) -> *mut u32 {
+                $pad as *mut u32
+            }
+        }
+    };
+}
@@ -0,0 +1,275 @@
+//! Digital input and output adapters for GPIO pads
+//!
+//! An [`Output`] or [`Input`] pairs a GPIO pad with its GPIO register block.
+//! The adapters have the same methods as the `embedded-hal` digital pin traits,
+//! so simple programs can blink an LED without a HAL. With the `embedded-hal`
+//! feature, the adapters also implement those traits: `Output` implements
+//! `OutputPin` and `StatefulOutputPin`, and `Input` implements `InputPin`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use imxrt_iomuxc::digital::Output;
+//! use imxrt_iomuxc::imxrt1060::gpio_b0::GPIO_B0_03;
+//!
+//! // The GPIO2 register block. If you're using imxrt-ral, use the
+//! // GPIO2 instance's pointer.
+//! const GPIO2: *const () = 0x401B_C000 as *const ();
+//!
+//! let pad = unsafe { GPIO_B0_03::new() };
+//! let mut led = unsafe { Output::<_, 2>::new(pad, GPIO2) };
+//! led.set_high();
+//! led.toggle();
+//! ```
+
+use crate::{
+    gpio::{self, Pin},
+    OutputCapable,
+};
+use core::ptr;
+
+/// Data register offset.
+const DR: usize = 0x00;
+/// Direction register offset.
+const GDIR: usize = 0x04;
+/// Pad status register offset.
+const PSR: usize = 0x08;
+/// Data set register offset.
+const DR_SET: usize = 0x84;
+/// Data clear register offset.
+const DR_CLEAR: usize = 0x88;
+/// Data toggle register offset.
+const DR_TOGGLE: usize = 0x8C;
+
+/// A GPIO pad and its register block.
+#[derive(Debug)]
+struct Gpio<P> {
+    pin: P,
+    regs: *mut u32,
+    mask: u32,
+}
+
+impl<P> Gpio<P> {
+    /// # Safety
+    ///
+    /// `regs` must be the pin's GPIO register block.
+    unsafe fn new<T, const N: u8>(mut pin: P, regs: *const T, output: bool) -> Self
+    where
+        P: Pin<N>,
+    {
+        gpio::prepare(&mut pin);
+        let gpio = Self {
+            pin,
+            regs: regs as *mut u32,
+            mask: 1 << P::OFFSET,
+        };
+        let gdir = gpio.read(GDIR);
+        if output {
+            gpio.write(GDIR, gdir | gpio.mask);
+        } else {
+            gpio.write(GDIR, gdir & !gpio.mask);
+        }
+        gpio
+    }
+    fn read(&self, offset: usize) -> u32 {
+        // Safety: the caller of new() ensured that this is a GPIO register block.
+        unsafe { ptr::read_volatile(self.regs.byte_add(offset)) }
+    }
+    fn write(&self, offset: usize, value: u32) {
+        // Safety: the caller of new() ensured that this is a GPIO register block.
+        unsafe { ptr::write_volatile(self.regs.byte_add(offset), value) }
+    }
+    fn is_set(&self, offset: usize) -> bool {
+        self.read(offset) & self.mask != 0
+    }
+}
+
+/// A GPIO output
+///
+/// `N` is the GPIO module; a `2` for `GPIO2`. The pad must be
+/// [`OutputCapable`].
+#[derive(Debug)]
+pub struct Output<P, const N: u8> {
+    gpio: Gpio<P>,
+}
+
+impl<P: Pin<N> + OutputCapable, const N: u8> Output<P, N> {
+    /// Prepare the pin, and configure it as an output
+    ///
+    /// # Safety
+    ///
+    /// `regs` must point to the `GPIO{N}` register block. The caller must ensure
+    /// that the direction register's read-modify-write does not race.
+    pub unsafe fn new<T>(pin: P, regs: *const T) -> Self {
+        Self {
+            gpio: Gpio::new(pin, regs, true),
+        }
+    }
+    /// Drive the output high
+    pub fn set_high(&mut self) {
+        self.gpio.write(DR_SET, self.gpio.mask);
+    }
+    /// Drive the output low
+    pub fn set_low(&mut self) {
+        self.gpio.write(DR_CLEAR, self.gpio.mask);
+    }
+    /// Toggle the output
+    pub fn toggle(&mut self) {
+        self.gpio.write(DR_TOGGLE, self.gpio.mask);
+    }
+    /// Returns `true` if the output is set high
+    pub fn is_set_high(&self) -> bool {
+        self.gpio.is_set(DR)
+    }
+    /// Returns `true` if the output is set low
+    pub fn is_set_low(&self) -> bool {
+        !self.is_set_high()
+    }
+    /// Release the pin
+    ///
+    /// The pad remains a GPIO output.
+    pub fn release(self) -> P {
+        self.gpio.pin
+    }
+}
+
+/// A GPIO input
+///
+/// `N` is the GPIO module; a `2` for `GPIO2`.
+#[derive(Debug)]
+pub struct Input<P, const N: u8> {
+    gpio: Gpio<P>,
+}
+
+impl<P: Pin<N>, const N: u8> Input<P, N> {
+    /// Prepare the pin, and configure it as an input
+    ///
+    /// # Safety
+    ///
+    /// `regs` must point to the `GPIO{N}` register block. The caller must ensure
+    /// that the direction register's read-modify-write does not race.
+    pub unsafe fn new<T>(pin: P, regs: *const T) -> Self {
+        Self {
+            gpio: Gpio::new(pin, regs, false),
+        }
+    }
+    /// Returns `true` if the input is high
+    pub fn is_high(&self) -> bool {
+        self.gpio.is_set(PSR)
+    }
+    /// Returns `true` if the input is low
+    pub fn is_low(&self) -> bool {
+        !self.is_high()
+    }
+    /// Release the pin
+    pub fn release(self) -> P {
+        self.gpio.pin
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+mod hal {
+    use super::{Input, Output, Pin};
+    use crate::OutputCapable;
+    use core::convert::Infallible;
+    use embedded_hal::digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin};
+
+    impl<P: Pin<N> + OutputCapable, const N: u8> ErrorType for Output<P, N> {
+        type Error = Infallible;
+    }
+
+    impl<P: Pin<N> + OutputCapable, const N: u8> OutputPin for Output<P, N> {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            Output::set_low(self);
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            Output::set_high(self);
+            Ok(())
+        }
+    }
+
+    impl<P: Pin<N> + OutputCapable, const N: u8> StatefulOutputPin for Output<P, N> {
+        fn is_set_high(&mut self) -> Result<bool, Infallible> {
+            Ok(Output::is_set_high(self))
+        }
+        fn is_set_low(&mut self) -> Result<bool, Infallible> {
+            Ok(Output::is_set_low(self))
+        }
+        fn toggle(&mut self) -> Result<(), Infallible> {
+            Output::toggle(self);
+            Ok(())
+        }
+    }
+
+    impl<P: Pin<N>, const N: u8> ErrorType for Input<P, N> {
+        type Error = Infallible;
+    }
+
+    impl<P: Pin<N>, const N: u8> InputPin for Input<P, N> {
+        fn is_high(&mut self) -> Result<bool, Infallible> {
+            Ok(Input::is_high(self))
+        }
+        fn is_low(&mut self) -> Result<bool, Infallible> {
+            Ok(Input::is_low(self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NoPin;
+
+    impl gpio::Pin<1> for NoPin<1> {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 3;
+    }
+
+    impl OutputCapable for NoPin<1> {}
+
+    #[test]
+    fn output() {
+        let mut regs = [0u32; 36];
+        let mut output = unsafe { Output::<_, 1>::new(NoPin::<1>::new(), regs.as_mut_ptr()) };
+        output.set_high();
+        output.toggle();
+        output.set_low();
+        let pin = output.release();
+        assert_eq!(regs[GDIR / 4], 1 << 3);
+        assert_eq!(regs[DR_SET / 4], 1 << 3);
+        assert_eq!(regs[DR_TOGGLE / 4], 1 << 3);
+        assert_eq!(regs[DR_CLEAR / 4], 1 << 3);
+        let _ = pin;
+    }
+
+    #[test]
+    fn input() {
+        let mut regs = [0u32; 36];
+        regs[GDIR / 4] = u32::MAX;
+        regs[PSR / 4] = 1 << 3;
+        let input = unsafe { Input::<_, 1>::new(NoPin::<1>::new(), regs.as_mut_ptr()) };
+        assert!(input.is_high());
+        let _ = input.release();
+        assert_eq!(regs[GDIR / 4], !(1 << 3));
+    }
+
+    #[cfg(feature = "embedded-hal")]
+    #[test]
+    fn embedded_hal() {
+        use embedded_hal::digital::{InputPin, OutputPin, StatefulOutputPin};
+
+        let mut regs = [0u32; 36];
+        let mut output = unsafe { Output::<_, 1>::new(NoPin::<1>::new(), regs.as_mut_ptr()) };
+        OutputPin::set_high(&mut output).unwrap();
+        StatefulOutputPin::toggle(&mut output).unwrap();
+        assert!(StatefulOutputPin::is_set_low(&mut output).unwrap());
+        let _ = output.release();
+        assert_eq!(regs[DR_SET / 4], 1 << 3);
+        assert_eq!(regs[DR_TOGGLE / 4], 1 << 3);
+
+        regs[PSR / 4] = 1 << 3;
+        let mut input = unsafe { Input::<_, 1>::new(NoPin::<1>::new(), regs.as_mut_ptr()) };
+        assert!(InputPin::is_high(&mut input).unwrap());
+    }
+}
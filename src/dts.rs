@@ -0,0 +1,154 @@
+//! Pinctrl device tree fragments
+//!
+//! Describe each pad's alternate, daisy, and configuration with an [`Assignment`].
+//! Then, use [`fragment()`] to write a pinctrl node for a Linux or Zephyr device
+//! tree. The node uses the `fsl,pins` property of the i.MX pinctrl binding. Each
+//! entry is the six cell tuple
+//!
+//! ```text
+//! <mux_reg conf_reg input_reg mux_mode input_val pad_config>
+//! ```
+//!
+//! where the registers are offsets from the IOMUXC base address.
+//!
+//! # Example
+//!
+//! ```
+//! use imxrt_iomuxc::{dts, lpuart, Config, PullKeeper};
+//! use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+//!
+//! const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+//!
+//! let mut tx = unsafe { GPIO_AD_B0_12::new() };
+//! let mut rx = unsafe { GPIO_AD_B0_13::new() };
+//! let assignments = [
+//!     dts::Assignment::new(
+//!         "GPIO_AD_B0_12 LPUART1_TX",
+//!         &mut tx,
+//!         <GPIO_AD_B0_12 as lpuart::Pin>::ALT,
+//!         <GPIO_AD_B0_12 as lpuart::Pin>::DAISY,
+//!         UART,
+//!     ),
+//!     dts::Assignment::new(
+//!         "GPIO_AD_B0_13 LPUART1_RX",
+//!         &mut rx,
+//!         <GPIO_AD_B0_13 as lpuart::Pin>::ALT,
+//!         <GPIO_AD_B0_13 as lpuart::Pin>::DAISY,
+//!         UART,
+//!     ),
+//! ];
+//! let node = dts::fragment("pinctrl_lpuart1", "lpuart1grp", 0x401F_8000, &assignments);
+//! assert!(node.starts_with("pinctrl_lpuart1: lpuart1grp {"));
+//! ```
+
+use crate::{Config, Daisy, Iomuxc};
+use core::fmt::Write;
+use std::string::String;
+
+/// The binding's SION flag, set in the pad configuration cell.
+const SION_FLAG: u32 = 1 << 30;
+
+/// A pad's pinctrl settings
+///
+/// Unspecified configuration fields are written as zero, like a
+/// [`Config::zero()`] configuration.
+#[derive(Debug, Clone, Copy)]
+pub struct Assignment<'a> {
+    name: &'a str,
+    mux: u32,
+    pad: u32,
+    alt: u32,
+    sion: bool,
+    daisy: Option<Daisy>,
+    config: Config,
+}
+
+impl<'a> Assignment<'a> {
+    /// Describe the settings for `pad`
+    ///
+    /// `name` is the entry's comment. `alt` and `daisy` are typically the
+    /// `ALT` and `DAISY` constants of the pad's pin implementation.
+    pub fn new<I: Iomuxc>(
+        name: &'a str,
+        pad: &mut I,
+        alt: u32,
+        daisy: Option<Daisy>,
+        config: Config,
+    ) -> Self {
+        Self {
+            name,
+            mux: pad.mux() as u32,
+            pad: pad.pad() as u32,
+            alt,
+            sion: false,
+            daisy,
+            config,
+        }
+    }
+
+    /// Set the pad's SION bit
+    pub const fn set_sion(mut self) -> Self {
+        self.sion = true;
+        self
+    }
+}
+
+/// Write a pinctrl node named `node`, labeled `label`, for the assignments
+///
+/// `base` is the IOMUXC base address.
+pub fn fragment(label: &str, node: &str, base: u32, assignments: &[Assignment]) -> String {
+    let mut dts = String::new();
+    // Writing to a String never fails.
+    let _ = writeln!(dts, "{label}: {node} {{");
+    let _ = writeln!(dts, "\tfsl,pins = <");
+    for assignment in assignments {
+        let (input_reg, input_val) = match assignment.daisy {
+            Some(daisy) => (daisy.reg as u32 - base, daisy.value),
+            None => (0, 0),
+        };
+        let mut config = assignment.config.value;
+        if assignment.sion {
+            config |= SION_FLAG;
+        }
+        let _ = writeln!(
+            dts,
+            "\t\t{:#06x} {:#06x} {:#06x} {:#x} {:#x} {:#010x}\t/* {} */",
+            assignment.mux - base,
+            assignment.pad - base,
+            input_reg,
+            assignment.alt,
+            input_val,
+            config,
+            assignment.name,
+        );
+    }
+    let _ = writeln!(dts, "\t>;");
+    let _ = writeln!(dts, "}};");
+    dts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pad, SlewRate};
+
+    #[test]
+    fn fragment_entries() {
+        let mut pad = unsafe { Pad::<0x1000_00BC, 0x1000_02AC>::new() };
+        let daisy = Daisy::new(0x1000_053C as *mut u32, 1);
+        let config = Config::zero().set_slew_rate(SlewRate::Fast);
+        let assignments = [
+            Assignment::new("first", &mut pad, 2, Some(daisy), config),
+            Assignment::new("second", &mut pad, 5, None, Config::zero()).set_sion(),
+        ];
+        assert_eq!(
+            fragment("pinctrl_test", "testgrp", 0x1000_0000, &assignments),
+            "pinctrl_test: testgrp {\n\
+             \tfsl,pins = <\n\
+             \t\t0x00bc 0x02ac 0x053c 0x2 0x1 0x00000001\t/* first */\n\
+             \t\t0x00bc 0x02ac 0x0000 0x5 0x0 0x40000000\t/* second */\n\
+             \t>;\n\
+             };\n"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! embassy `Peripheral` implementations
+//!
+//! An embassy HAL accepts a pad by value, by `&mut`, or as a
+//! `PeripheralRef`, the same way that it accepts its other peripherals. A
+//! `PeripheralRef` only lends out a shared reference, so the HAL prepares the
+//! pad through a `clone_unchecked()` copy, like it does its other peripherals'
+//! registers.
+
+use crate::{ErasedPad, Pad};
+use embassy_hal_internal::Peripheral;
+
+impl<const MUX: u32, const PAD: u32> Peripheral for Pad<MUX, PAD> {
+    type P = Self;
+
+    unsafe fn clone_unchecked(&self) -> Self::P {
+        // Safety: the caller ensures that only one copy is in use.
+        unsafe { Self::new() }
+    }
+}
+
+impl Peripheral for ErasedPad {
+    type P = Self;
+
+    unsafe fn clone_unchecked(&self) -> Self::P {
+        // Safety: the pointers came from a valid pad, and the caller ensures
+        // that only one copy is in use.
+        unsafe { Self::new(self.mux.as_ptr(), self.pad.as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{alternate, Alternate, ErasedPad, Iomuxc, Pad};
+    use embassy_hal_internal::{Peripheral, PeripheralRef};
+
+    /// Prepare a pad like an embassy HAL would
+    fn prepare<'a, P>(pad: impl Peripheral<P = P> + 'a) -> PeripheralRef<'a, P>
+    where
+        P: Iomuxc + Peripheral<P = P>,
+    {
+        let pad = pad.into_ref();
+        alternate(&mut unsafe { P::clone_unchecked(&pad) }, Alternate::<3>);
+        pad
+    }
+
+    #[test]
+    fn into_ref() {
+        let mut registers = [0u32; 2];
+        let [mux, pad] = registers.each_mut().map(|reg| reg as *mut u32);
+        let mut erased = unsafe { ErasedPad::new(mux, pad) };
+
+        let borrowed = prepare(&mut erased);
+        assert_eq!(borrowed.location().mux, mux as u32);
+        assert_eq!(unsafe { mux.replace(0) }, 3);
+
+        let _owned = prepare(erased);
+        assert_eq!(unsafe { mux.read() }, 3);
+
+        let typed = unsafe { Pad::<0x401F_80EC, 0x401F_82DC>::new() }.into_ref();
+        assert_eq!(
+            unsafe { Peripheral::clone_unchecked(&*typed) }.mux() as u32,
+            0x401F_80EC
+        );
+    }
+}
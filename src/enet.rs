@@ -0,0 +1,420 @@
+//! ENET pad configuration
+//!
+//! The 1060 module implements [`Pin`] for the ENET RMII and management pads
+//! on GPIO_B1. The package doesn't describe the other ENET pads, the ENET2
+//! pads, or the IEEE 1588 event pads yet. Implement [`Pin`] for the pads that
+//! your board routes to them, using the alternates and select inputs from the
+//! reference manual's muxing tables. Generate the select input values with
+//! `daisy.py`, like `daisy.py path/to/imxrt.svd ENET`.
+//!
+//! ```
+//! use imxrt_iomuxc::{consts::Const, define_pad, enet};
+//!
+//! define_pad! {
+//!     /// A pad that carries ENET_1588_EVENT0_OUT in ALT 4.
+//!     pub struct EVENT0 { mux: 0x1000, pad: 0x2000 }
+//! }
+//!
+//! impl enet::Pin for EVENT0 {
+//!     const ALT: u32 = 4;
+//!     const DAISY: Option<imxrt_iomuxc::Daisy> = None;
+//!     type Signal = enet::Event0Out;
+//!     type Module = Const<1>;
+//! }
+//!
+//! fn trigger_output<P: enet::Pin<Signal = enet::Event0Out>>(pin: &mut P) {
+//!     enet::prepare(pin);
+//! }
+//! ```
+
+/// An ENET signal
+pub trait Signal: private::Sealed {
+    /// `true` if the signal's pad needs its SION bit
+    ///
+    /// The ENET receives the RMII reference clock through the pad's input
+    /// path, even when the ENET drives the clock.
+    const SION: bool = false;
+}
+
+/// A tag that indicates the management clock; `ENET_MDC`
+pub enum Mdc {}
+/// A tag that indicates the management data; `ENET_MDIO`
+pub enum Mdio {}
+/// A tag that indicates the RMII reference clock; `ENET_REF_CLK`
+pub enum RefClk {}
+/// A tag that indicates the transmit enable; `ENET_TX_EN`
+pub enum TxEn {}
+/// A tag that indicates transmit data 0; `ENET_TX_DATA0`
+pub enum TxData0 {}
+/// A tag that indicates transmit data 1; `ENET_TX_DATA1`
+pub enum TxData1 {}
+/// A tag that indicates the receive enable, or carrier sense; `ENET_RX_EN`
+pub enum RxEn {}
+/// A tag that indicates receive data 0; `ENET_RX_DATA0`
+pub enum RxData0 {}
+/// A tag that indicates receive data 1; `ENET_RX_DATA1`
+pub enum RxData1 {}
+/// A tag that indicates the receive error; `ENET_RX_ER`
+pub enum RxEr {}
+/// A tag that indicates the IEEE 1588 timer channel 0 input; `ENET_1588_EVENT0_IN`
+pub enum Event0In {}
+/// A tag that indicates the IEEE 1588 timer channel 0 output; `ENET_1588_EVENT0_OUT`
+pub enum Event0Out {}
+/// A tag that indicates the IEEE 1588 timer channel 1 input; `ENET_1588_EVENT1_IN`
+pub enum Event1In {}
+/// A tag that indicates the IEEE 1588 timer channel 1 output; `ENET_1588_EVENT1_OUT`
+pub enum Event1Out {}
+/// A tag that indicates the IEEE 1588 timer channel 2 input; `ENET_1588_EVENT2_IN`
+pub enum Event2In {}
+/// A tag that indicates the IEEE 1588 timer channel 2 output; `ENET_1588_EVENT2_OUT`
+pub enum Event2Out {}
+/// A tag that indicates the IEEE 1588 timer channel 3 input; `ENET_1588_EVENT3_IN`
+pub enum Event3In {}
+/// A tag that indicates the IEEE 1588 timer channel 3 output; `ENET_1588_EVENT3_OUT`
+pub enum Event3Out {}
+
+impl Signal for Mdc {}
+impl Signal for Mdio {}
+impl Signal for RefClk {
+    const SION: bool = true;
+}
+impl Signal for TxEn {}
+impl Signal for TxData0 {}
+impl Signal for TxData1 {}
+impl Signal for RxEn {}
+impl Signal for RxData0 {}
+impl Signal for RxData1 {}
+impl Signal for RxEr {}
+impl Signal for Event0In {}
+impl Signal for Event0Out {}
+impl Signal for Event1In {}
+impl Signal for Event1Out {}
+impl Signal for Event2In {}
+impl Signal for Event2Out {}
+impl Signal for Event3In {}
+impl Signal for Event3Out {}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Mdc {}
+    impl Sealed for super::Mdio {}
+    impl Sealed for super::RefClk {}
+    impl Sealed for super::TxEn {}
+    impl Sealed for super::TxData0 {}
+    impl Sealed for super::TxData1 {}
+    impl Sealed for super::RxEn {}
+    impl Sealed for super::RxData0 {}
+    impl Sealed for super::RxData1 {}
+    impl Sealed for super::RxEr {}
+    impl Sealed for super::Event0In {}
+    impl Sealed for super::Event0Out {}
+    impl Sealed for super::Event1In {}
+    impl Sealed for super::Event1Out {}
+    impl Sealed for super::Event2In {}
+    impl Sealed for super::Event2Out {}
+    impl Sealed for super::Event3In {}
+    impl Sealed for super::Event3Out {}
+}
+
+/// An ENET pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an ENET pin",
+    label = "this pad does not support any ENET signal",
+    note = "the `enet::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// Daisy register
+    const DAISY: Option<super::Daisy>;
+    /// ENET signal
+    type Signal: Signal;
+    /// ENET module; `Const<1>` for `ENET`, and `Const<2>` for `ENET2`
+    type Module: super::consts::Unsigned;
+    /// The module instance number; `2` for `Module = Const<2>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Signal = P::Signal;
+    type Module = P::Module;
+}
+
+/// Prepare an ENET pin
+///
+/// If you do not call `prepare()` on your ENET pin, it might not work as an
+/// ENET pin. `prepare()` sets the SION bit of a [`RefClk`] pin, and clears it
+/// for all other pins.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, <P::Signal as Signal>::SION, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare an ENET pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe {
+        super::prepare_mux(
+            pin.mux(),
+            P::ALT,
+            <P::Signal as Signal>::SION,
+            daisy.resolve(P::DAISY),
+        )
+    };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the ENET input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare an ENET pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// The RMII and management pins of one ENET
+///
+/// [`prepare()`](Self::prepare) prepares all the pins. On a 1060, use
+/// [`prepare_rmii()`] to also configure the reference clock.
+#[derive(Debug)]
+pub struct Rmii<MDC, MDIO, CLK, TXEN, TXD0, TXD1, RXEN, RXD0, RXD1, RXER> {
+    /// Management clock pin
+    pub mdc: MDC,
+    /// Management data pin
+    pub mdio: MDIO,
+    /// Reference clock pin
+    pub ref_clk: CLK,
+    /// Transmit enable pin
+    pub tx_en: TXEN,
+    /// Transmit data 0 pin
+    pub tx_data0: TXD0,
+    /// Transmit data 1 pin
+    pub tx_data1: TXD1,
+    /// Receive enable, or carrier sense, pin
+    pub rx_en: RXEN,
+    /// Receive data 0 pin
+    pub rx_data0: RXD0,
+    /// Receive data 1 pin
+    pub rx_data1: RXD1,
+    /// Receive error pin
+    pub rx_er: RXER,
+}
+
+impl<MDC, MDIO, CLK, TXEN, TXD0, TXD1, RXEN, RXD0, RXD1, RXER>
+    Rmii<MDC, MDIO, CLK, TXEN, TXD0, TXD1, RXEN, RXD0, RXD1, RXER>
+where
+    MDC: Pin<Signal = Mdc>,
+    MDIO: Pin<Signal = Mdio, Module = MDC::Module>,
+    CLK: Pin<Signal = RefClk, Module = MDC::Module>,
+    TXEN: Pin<Signal = TxEn, Module = MDC::Module>,
+    TXD0: Pin<Signal = TxData0, Module = MDC::Module>,
+    TXD1: Pin<Signal = TxData1, Module = MDC::Module>,
+    RXEN: Pin<Signal = RxEn, Module = MDC::Module>,
+    RXD0: Pin<Signal = RxData0, Module = MDC::Module>,
+    RXD1: Pin<Signal = RxData1, Module = MDC::Module>,
+    RXER: Pin<Signal = RxEr, Module = MDC::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = MDC::MODULE;
+
+    /// Prepare all of the RMII pins
+    ///
+    /// This muxes the pads, and writes their daisies. It doesn't configure
+    /// the reference clock; see [`prepare_rmii()`].
+    pub fn prepare(&mut self) {
+        prepare(&mut self.mdc);
+        prepare(&mut self.mdio);
+        prepare(&mut self.ref_clk);
+        prepare(&mut self.tx_en);
+        prepare(&mut self.tx_data0);
+        prepare(&mut self.tx_data1);
+        prepare(&mut self.rx_en);
+        prepare(&mut self.rx_data0);
+        prepare(&mut self.rx_data1);
+        prepare(&mut self.rx_er);
+    }
+}
+
+/// The device that provides the 50MHz RMII reference clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefClock {
+    /// The PHY, or an external oscillator, drives the reference clock pad
+    Phy,
+    /// The ENET PLL drives the reference clock out of the pad, to the PHY
+    Mcu,
+}
+
+/// Prepare the RMII pins, and configure the reference clock
+///
+/// Prepares all of the `pins`, then sets the ENET's GPR clock source and TX
+/// clock direction for `clock`. The reference clock pad's SION bit is set, so
+/// the ENET also receives the clock that it drives.
+///
+/// When the MCU provides the clock, enable the ENET PLL's 50MHz output
+/// before using the ENET.
+///
+/// ```no_run
+/// use imxrt_iomuxc::enet::{self, RefClock, Rmii};
+/// use imxrt_iomuxc::imxrt1060::{gpio_b1::*, gpr::Gpr};
+///
+/// let mut pins = unsafe {
+///     Rmii {
+///         mdc: GPIO_B1_14::new(),
+///         mdio: GPIO_B1_15::new(),
+///         ref_clk: GPIO_B1_10::new(),
+///         tx_en: GPIO_B1_09::new(),
+///         tx_data0: GPIO_B1_07::new(),
+///         tx_data1: GPIO_B1_08::new(),
+///         rx_en: GPIO_B1_06::new(),
+///         rx_data0: GPIO_B1_04::new(),
+///         rx_data1: GPIO_B1_05::new(),
+///         rx_er: GPIO_B1_11::new(),
+///     }
+/// };
+/// let mut gpr = unsafe { Gpr::new() };
+/// enet::prepare_rmii(&mut pins, &mut gpr, RefClock::Mcu);
+/// ```
+#[cfg(feature = "_imxrt1060")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imxrt1060")))]
+pub fn prepare_rmii<MDC, MDIO, CLK, TXEN, TXD0, TXD1, RXEN, RXD0, RXD1, RXER>(
+    pins: &mut Rmii<MDC, MDIO, CLK, TXEN, TXD0, TXD1, RXEN, RXD0, RXD1, RXER>,
+    gpr: &mut crate::imxrt1060::gpr::Gpr,
+    clock: RefClock,
+) where
+    MDC: Pin<Signal = Mdc>,
+    MDIO: Pin<Signal = Mdio, Module = MDC::Module>,
+    CLK: Pin<Signal = RefClk, Module = MDC::Module>,
+    TXEN: Pin<Signal = TxEn, Module = MDC::Module>,
+    TXD0: Pin<Signal = TxData0, Module = MDC::Module>,
+    TXD1: Pin<Signal = TxData1, Module = MDC::Module>,
+    RXEN: Pin<Signal = RxEn, Module = MDC::Module>,
+    RXD0: Pin<Signal = RxData0, Module = MDC::Module>,
+    RXD1: Pin<Signal = RxData1, Module = MDC::Module>,
+    RXER: Pin<Signal = RxEr, Module = MDC::Module>,
+{
+    use crate::imxrt1060::gpr::{Direction, EnetClockSource};
+
+    pins.prepare();
+    let (source, direction) = match clock {
+        RefClock::Phy => (EnetClockSource::Pad, Direction::Input),
+        RefClock::Mcu => (EnetClockSource::Pll, Direction::Output),
+    };
+    gpr.set_enet_clock_source(MDC::MODULE, source);
+    gpr.set_enet_tx_clock_direction(MDC::MODULE, direction);
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! enet {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Signal = $signal;
+            type Module = crate::consts::Const<$module>;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prepare, Pin, RefClk, Signal, TxEn};
+    use crate::{consts::Const, Daisy, ErasedPad, Iomuxc, SION_BIT};
+    use core::marker::PhantomData;
+
+    /// An ENET pin in ALT 3, backed by test registers
+    struct TestPin<S>(ErasedPad, PhantomData<S>);
+
+    impl<S> TestPin<S> {
+        fn new(registers: &mut [u32; 2]) -> Self {
+            let [mux, pad] = registers;
+            Self(unsafe { ErasedPad::new(mux, pad) }, PhantomData)
+        }
+    }
+
+    impl<S> crate::private::Sealed for TestPin<S> {}
+    unsafe impl<S> Iomuxc for TestPin<S> {
+        fn mux(&mut self) -> *mut u32 {
+            self.0.mux()
+        }
+        fn pad(&mut self) -> *mut u32 {
+            self.0.pad()
+        }
+    }
+    impl<S: Signal> Pin for TestPin<S> {
+        const ALT: u32 = 3;
+        const DAISY: Option<Daisy> = None;
+        type Signal = S;
+        type Module = Const<1>;
+    }
+
+    #[test]
+    fn sion_only_for_ref_clk() {
+        let mut registers = [[0u32; 2]; 2];
+        let [clk, tx_en] = &mut registers;
+        tx_en[0] = SION_BIT;
+        prepare(&mut TestPin::<RefClk>::new(clk));
+        prepare(&mut TestPin::<TxEn>::new(tx_en));
+        assert_eq!(registers[0][0], SION_BIT | 3);
+        assert_eq!(registers[1][0], 3);
+    }
+
+    #[cfg(feature = "_imxrt1060")]
+    #[test]
+    fn prepare_rmii_pins_and_clock() {
+        use super::{
+            prepare_rmii, Mdc, Mdio, RefClock, Rmii, RxData0, RxData1, RxEn, RxEr, TxData0, TxData1,
+        };
+        use crate::imxrt1060::gpr::Gpr;
+
+        let mut registers = [[SION_BIT; 2]; 10];
+        let [mdc, mdio, clk, tx_en, txd0, txd1, rx_en, rxd0, rxd1, rx_er] = &mut registers;
+        let mut pins = Rmii {
+            mdc: TestPin::<Mdc>::new(mdc),
+            mdio: TestPin::<Mdio>::new(mdio),
+            ref_clk: TestPin::<RefClk>::new(clk),
+            tx_en: TestPin::<TxEn>::new(tx_en),
+            tx_data0: TestPin::<TxData0>::new(txd0),
+            tx_data1: TestPin::<TxData1>::new(txd1),
+            rx_en: TestPin::<RxEn>::new(rx_en),
+            rx_data0: TestPin::<RxData0>::new(rxd0),
+            rx_data1: TestPin::<RxData1>::new(rxd1),
+            rx_er: TestPin::<RxEr>::new(rx_er),
+        };
+        let mut gprs = [0u32; 30];
+        prepare_rmii(
+            &mut pins,
+            &mut Gpr::from_registers(&mut gprs),
+            RefClock::Mcu,
+        );
+
+        let muxes: [u32; 10] = core::array::from_fn(|idx| registers[idx][0]);
+        assert_eq!(muxes, [3, 3, SION_BIT | 3, 3, 3, 3, 3, 3, 3, 3]);
+        // ENET1_TX_CLK_DIR is set, and ENET1_CLK_SEL selects the PLL.
+        assert_eq!(gprs[1], 1 << 17);
+
+        prepare_rmii(
+            &mut pins,
+            &mut Gpr::from_registers(&mut gprs),
+            RefClock::Phy,
+        );
+        assert_eq!(gprs[1], 1 << 13);
+    }
+}
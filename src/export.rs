@@ -0,0 +1,122 @@
+//! Export the current pad state as Rust source
+//!
+//! [`write_rust()`] reads the MUX and PAD registers of every pad in a chip's
+//! `REGISTER_MAP`, and writes a function that reproduces the pads' alternate,
+//! SION, and configuration with this crate's API. Use it to capture the pinmux
+//! left behind by a bootloader, a debugger script, or C startup code.
+//!
+//! The generated function takes the chip's `Pads`:
+//!
+//! ```text
+//! pub fn restore(pads: &mut Pads) {
+//!     imxrt_iomuxc::alternate(&mut pads.gpio_ad_b0.p12, imxrt_iomuxc::Alternate::<2>);
+//!     imxrt_iomuxc::clear_sion(&mut pads.gpio_ad_b0.p12);
+//!     imxrt_iomuxc::configure(&mut pads.gpio_ad_b0.p12, imxrt_iomuxc::Config::zero()...);
+//!     // ...
+//! }
+//! ```
+//!
+//! The configuration is decoded with the [`Config`](crate::Config) field layout.
+//! The export does not describe daisy registers.
+
+use crate::register_map::PadRegisters;
+use core::fmt::{self, Write};
+
+/// Write a `restore` function that reproduces the state of the pads in `map`
+///
+/// # Safety
+///
+/// Reads every MUX and PAD register described by `map`. The addresses must be
+/// valid for reads. When `map` is a chip's `REGISTER_MAP`, the caller must be
+/// running on that chip.
+pub unsafe fn write_rust<W: Write>(out: &mut W, map: &[PadRegisters]) -> fmt::Result {
+    writeln!(out, "pub fn restore(pads: &mut Pads) {{")?;
+    for pad in map {
+        let mux = core::ptr::read_volatile(pad.mux as *const u32);
+        let config = core::ptr::read_volatile(pad.pad as *const u32);
+        write_pad(out, pad.name, mux, config)?;
+    }
+    writeln!(out, "}}")
+}
+
+/// Write the statements that reproduce one pad's `mux` and `pad` register values.
+fn write_pad(out: &mut dyn Write, name: &str, mux: u32, pad: u32) -> fmt::Result {
+    let (group, number) = name.rsplit_once('_').unwrap_or(("", name));
+    let field = Field(group, number);
+
+    writeln!(
+        out,
+        "    imxrt_iomuxc::alternate(&mut {field}, imxrt_iomuxc::Alternate::<{}>);",
+        mux & crate::ALT_MASK
+    )?;
+    if mux & crate::SION_BIT != 0 {
+        writeln!(out, "    imxrt_iomuxc::set_sion(&mut {field});")?;
+    } else {
+        writeln!(out, "    imxrt_iomuxc::clear_sion(&mut {field});")?;
+    }
+    write!(out, "    imxrt_iomuxc::configure(&mut {field}, ")?;
+    crate::config::write_expr(pad, out)?;
+    writeln!(out, ");")
+}
+
+/// A pad's field in a chip's `Pads`, like `pads.gpio_ad_b0.p12`.
+struct Field<'a>(&'a str, &'a str);
+
+impl fmt::Display for Field<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("pads.")?;
+        for c in self.0.chars() {
+            write!(f, "{}", c.to_ascii_lowercase())?;
+        }
+        write!(f, ".p{}", self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_pad;
+
+    struct Buffer([u8; 1024], usize);
+
+    impl core::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn pad_statements() {
+        let mut buffer = Buffer([0; 1024], 0);
+        write_pad(&mut buffer, "GPIO_AD_B0_12", 0x12, 0x0001_B0B0).unwrap();
+        let text = core::str::from_utf8(&buffer.0[..buffer.1]).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(
+            lines.next(),
+            Some("    imxrt_iomuxc::alternate(&mut pads.gpio_ad_b0.p12, imxrt_iomuxc::Alternate::<2>);")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("    imxrt_iomuxc::set_sion(&mut pads.gpio_ad_b0.p12);")
+        );
+        assert_eq!(
+            lines.next(),
+            Some(concat!(
+                "    imxrt_iomuxc::configure(&mut pads.gpio_ad_b0.p12, ",
+                "imxrt_iomuxc::Config::zero()",
+                ".set_hysteresis(imxrt_iomuxc::Hysteresis::Enabled)",
+                ".set_pull_keeper(Some(imxrt_iomuxc::PullKeeper::Pullup100k))",
+                ".set_open_drain(imxrt_iomuxc::OpenDrain::Disabled)",
+                ".set_speed(imxrt_iomuxc::Speed::Fast)",
+                ".set_drive_strength(imxrt_iomuxc::DriveStrength::R0_6)",
+                ".set_slew_rate(imxrt_iomuxc::SlewRate::Slow));",
+            ))
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
@@ -0,0 +1,135 @@
+//! FlexCAN pad configuration
+
+/// Tag that indicates the TX signal
+pub enum Tx {}
+/// Tag that indicates the RX signal
+pub enum Rx {}
+
+/// A FlexCAN signal; one of `TX` or `RX`
+pub trait Signal: private::Sealed {}
+
+impl Signal for Tx {}
+impl Signal for Rx {}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Tx {}
+    impl Sealed for super::Rx {}
+}
+
+/// A FlexCAN pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a FlexCAN pin",
+    label = "this pad does not support any FlexCAN signal",
+    note = "the `flexcan::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// Daisy register
+    const DAISY: Option<super::Daisy>;
+    /// FlexCAN signal
+    type Signal: Signal;
+    /// FlexCAN module; `Const<3>` for `CAN3`
+    type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Signal = P::Signal;
+    type Module = P::Module;
+}
+
+/// Prepare a FlexCAN pin
+///
+/// If you do not call `prepare()` on your FlexCAN pin, it might not work as a
+/// FlexCAN pin.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a FlexCAN pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, daisy.resolve(P::DAISY)) };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the FlexCAN input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a FlexCAN pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// A pair of FlexCAN pins from the same module
+#[derive(Debug)]
+pub struct Pins<T, R> {
+    /// Transfer pin
+    pub tx: T,
+    /// Receive pin
+    pub rx: R,
+}
+
+impl<T, R> Pins<T, R>
+where
+    T: Pin<Signal = Tx>,
+    R: Pin<Signal = Rx, Module = T::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = T::MODULE;
+
+    /// Group the FlexCAN pins
+    pub const fn new(tx: T, rx: R) -> Self {
+        Self { tx, rx }
+    }
+
+    /// Prepare all of the FlexCAN pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.tx);
+        prepare(&mut self.rx);
+    }
+}
+
+impl<T, R> From<(T, R)> for Pins<T, R>
+where
+    T: Pin<Signal = Tx>,
+    R: Pin<Signal = Rx, Module = T::Module>,
+{
+    fn from((tx, rx): (T, R)) -> Self {
+        Self::new(tx, rx)
+    }
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! can {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Signal = $signal;
+            type Module = crate::consts::Const<$module>;
+        }
+    };
+}
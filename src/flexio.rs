@@ -0,0 +1,63 @@
+//! FlexIO pad configuration
+//!
+//! A FlexIO pin is bidirectional. The FlexIO shifters and timers select the
+//! pin by its index, so the index is part of the pin's type.
+//!
+//! ```
+//! use imxrt_iomuxc::{define_pad, flexio};
+//!
+//! define_pad! {
+//!     /// A pad that routes FLEXIO1_IO05.
+//!     pub struct FLEXIO_PAD { mux: 0x401F_8010, pad: 0x401F_8254 }
+//! }
+//!
+//! impl flexio::Pin<1> for FLEXIO_PAD {
+//!     const ALT: u32 = 4;
+//!     const OFFSET: u32 = 5;
+//! }
+//!
+//! fn shifter_pin<P: flexio::Pin<1>>(_: &P) -> u32 {
+//!     P::OFFSET
+//! }
+//!
+//! let pad = unsafe { FLEXIO_PAD::new() };
+//! assert_eq!(shifter_pin(&pad), 5);
+//! ```
+
+/// A FlexIO pin
+///
+/// The constant `N` is the associated FlexIO module
+/// (a `2` for `FLEXIO2`).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a FLEXIO{N} pin",
+    label = "this pad does not have a FLEXIO{N} alternate",
+    note = "the `flexio::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin<const N: u8>: super::Iomuxc {
+    /// The alternate value for this pad
+    const ALT: u32;
+    /// The FlexIO pin index; `5` for `FLEXIO2_IO05`
+    const OFFSET: u32;
+}
+
+impl<P: Pin<N>, const N: u8> Pin<N> for &mut P {
+    const ALT: u32 = P::ALT;
+    const OFFSET: u32 = P::OFFSET;
+}
+
+/// Prepare a pad to be used as a FlexIO pin
+pub fn prepare<P: Pin<N>, const N: u8>(pin: &mut P) {
+    super::alternate_raw(pin, P::ALT);
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! flexio {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, offset: $offset:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
+        impl Pin<$module> for $pad {
+            const ALT: u32 = $alt;
+            const OFFSET: u32 = $offset;
+        }
+    };
+}
@@ -1,31 +1,56 @@
 //! PWM pad configuration
 
-/// A PWM output identified; one of `A` or `B`
+/// A PWM output identified; one of `A`, `B`, or `X`
 pub trait Output: private::Sealed {}
 /// PWM output A
 pub enum A {}
 /// PWM output B
 pub enum B {}
+/// PWM output X
+///
+/// Use the X output as an extra PWM output, or as an input capture pin.
+pub enum X {}
 
 impl Output for A {}
 impl Output for B {}
+impl Output for X {}
 
 mod private {
     pub trait Sealed {}
     impl Sealed for super::A {}
     impl Sealed for super::B {}
+    impl Sealed for super::X {}
 }
 
 /// A PWM pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an FlexPWM pin",
+    label = "this pad does not support any FlexPWM signal",
+    note = "the `flexpwm::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin: super::Iomuxc {
     /// The alternate mode for the PWM pin
     const ALT: u32;
     /// The output identifier
     type Output: Output;
-    /// The PWM module; `U2` is `PWM2`
+    /// The PWM module; `Const<2>` is `PWM2`
     type Module: super::consts::Unsigned;
-    /// The PWM submodule; `U3` for `PWM2_SM3`
+    /// The PWM submodule; `Const<3>` for `PWM2_SM3`
     type Submodule: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+    /// The submodule number; `3` for `Submodule = Const<3>`
+    const SUBMODULE: u8 = <Self::Submodule as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    type Output = P::Output;
+    type Module = P::Module;
+    type Submodule = P::Submodule;
 }
 
 /// Prepare a PWM pin
@@ -34,17 +59,27 @@ pub trait Pin: super::Iomuxc {
 ///
 /// `prepare()` inherits all the unsafety of the `IOMUX` supertrait.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
+    super::alternate_raw(pin, P::ALT);
+}
+
+/// Prepare a PWM pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Output, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! pwm {
-    (module: $module:ty, submodule: $submodule:ty, alt: $alt:expr, pad: $pad:ty, output: $output:ty) => {
+    (module: $module:literal, submodule: $submodule:literal, alt: $alt:expr, pad: $pad:ty, output: $output:ty) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
         impl Pin for $pad {
             const ALT: u32 = $alt;
             type Output = $output;
-            type Module = $module;
-            type Submodule = $submodule;
+            type Module = crate::consts::Const<$module>;
+            type Submodule = crate::consts::Const<$submodule>;
         }
     };
 }
@@ -0,0 +1,285 @@
+//! FlexSPI pad configuration
+//!
+//! A FlexSPI controller has two ports, `A` and `B`. Each port has a clock,
+//! chip selects, a DQS strobe, and four data signals. In octal mode, the
+//! controller combines both ports' data signals: port A provides `DATA0`
+//! through `DATA3`, and port B provides `DATA4` through `DATA7`.
+//!
+//! # Examples
+//!
+//! Require a complete quad-SPI pin set on port A, all from the same module.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::flexspi::{self, Pin, Sclk, Ss0, Data0, Data1, Data2, Data3, A};
+//!
+//! fn flexspi_quad<C, S, D0, D1, D2, D3>(
+//!     sclk: &mut C,
+//!     ss0: &mut S,
+//!     data: (&mut D0, &mut D1, &mut D2, &mut D3),
+//! ) where
+//!     C: Pin<Port = A, Signal = Sclk>,
+//!     S: Pin<Port = A, Signal = Ss0, Module = C::Module>,
+//!     D0: Pin<Port = A, Signal = Data0, Module = C::Module>,
+//!     D1: Pin<Port = A, Signal = Data1, Module = C::Module>,
+//!     D2: Pin<Port = A, Signal = Data2, Module = C::Module>,
+//!     D3: Pin<Port = A, Signal = Data3, Module = C::Module>,
+//! {
+//!     flexspi::prepare(sclk);
+//!     flexspi::prepare(ss0);
+//!     flexspi::prepare(data.0);
+//!     flexspi::prepare(data.1);
+//!     flexspi::prepare(data.2);
+//!     flexspi::prepare(data.3);
+//! }
+//!
+//! # use imxrt_iomuxc::imxrt1060::gpio_ad_b1::*;
+//! let mut sclk = unsafe { GPIO_AD_B1_14::new() };
+//! let mut ss0 = unsafe { GPIO_AD_B1_15::new() };
+//! let mut d0 = unsafe { GPIO_AD_B1_13::new() };
+//! let mut d1 = unsafe { GPIO_AD_B1_12::new() };
+//! let mut d2 = unsafe { GPIO_AD_B1_11::new() };
+//! let mut d3 = unsafe { GPIO_AD_B1_10::new() };
+//! flexspi_quad(&mut sclk, &mut ss0, (&mut d0, &mut d1, &mut d2, &mut d3));
+//! ```
+//!
+//! For an octal pin set, additionally require port `B` data pins, and use
+//! [`OctalData::INDEX`] to order the eight data signals.
+
+/// A FlexSPI port; one of `A` or `B`
+pub trait Port: private::Sealed {}
+/// FlexSPI port A
+pub enum A {}
+/// FlexSPI port B
+///
+/// In octal mode, port B carries data signals four through seven.
+pub enum B {}
+
+impl Port for A {}
+impl Port for B {}
+
+/// A FlexSPI signal
+pub trait Signal: private::Sealed {
+    /// `true` if the signal's pad needs its SION bit
+    ///
+    /// The controller samples its read strobe through the clock or DQS
+    /// pad's input path.
+    const SION: bool = false;
+}
+
+/// A tag that indicates a FlexSPI clock pad
+pub enum Sclk {}
+/// A tag that indicates a FlexSPI chip select 0 pad
+pub enum Ss0 {}
+/// A tag that indicates a FlexSPI chip select 1 pad
+///
+/// This is the second chip select on the port.
+pub enum Ss1 {}
+/// A tag that indicates a FlexSPI data strobe pad
+pub enum Dqs {}
+/// A tag that indicates a FlexSPI data 0 pad
+pub enum Data0 {}
+/// A tag that indicates a FlexSPI data 1 pad
+pub enum Data1 {}
+/// A tag that indicates a FlexSPI data 2 pad
+pub enum Data2 {}
+/// A tag that indicates a FlexSPI data 3 pad
+pub enum Data3 {}
+
+impl Signal for Sclk {
+    const SION: bool = true;
+}
+impl Signal for Ss0 {}
+impl Signal for Ss1 {}
+impl Signal for Dqs {
+    const SION: bool = true;
+}
+impl Signal for Data0 {}
+impl Signal for Data1 {}
+impl Signal for Data2 {}
+impl Signal for Data3 {}
+
+/// A FlexSPI data signal
+pub trait DataSignal: Signal {
+    /// Data pin index within the port; the `2` in `DATA02`
+    const INDEX: u8;
+}
+
+impl DataSignal for Data0 {
+    const INDEX: u8 = 0;
+}
+impl DataSignal for Data1 {
+    const INDEX: u8 = 1;
+}
+impl DataSignal for Data2 {
+    const INDEX: u8 = 2;
+}
+impl DataSignal for Data3 {
+    const INDEX: u8 = 3;
+}
+
+/// Describes a data signal's position in an octal bus
+///
+/// Port `A` data signals are octal data zero through three. Port `B`
+/// data signals are octal data four through seven.
+pub trait OctalData: private::Sealed {
+    /// The octal data index; `5` for port `B` `DATA01`
+    const INDEX: u8;
+}
+
+impl<D: DataSignal> OctalData for (A, D) {
+    const INDEX: u8 = D::INDEX;
+}
+impl<D: DataSignal> OctalData for (B, D) {
+    const INDEX: u8 = D::INDEX + 4;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::A {}
+    impl Sealed for super::B {}
+    impl Sealed for super::Sclk {}
+    impl Sealed for super::Ss0 {}
+    impl Sealed for super::Ss1 {}
+    impl Sealed for super::Dqs {}
+    impl Sealed for super::Data0 {}
+    impl Sealed for super::Data1 {}
+    impl Sealed for super::Data2 {}
+    impl Sealed for super::Data3 {}
+    impl<P: super::Port, D: super::DataSignal> Sealed for (P, D) {}
+}
+
+/// A FlexSPI pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a FlexSPI pin",
+    label = "this pad does not support any FlexSPI signal",
+    note = "the `flexspi::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin: super::Iomuxc {
+    /// Alternate value for this pin
+    const ALT: u32;
+    /// The daisy register which will select the pad
+    const DAISY: Option<super::Daisy>;
+    /// FlexSPI signal
+    type Signal: Signal;
+    /// FlexSPI port
+    type Port: Port;
+    /// FlexSPI module; `Const<2>` for `FLEXSPI2`
+    type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Signal = P::Signal;
+    type Port = P::Port;
+    type Module = P::Module;
+}
+
+/// Prepare a FlexSPI pin
+///
+/// If you do not call `prepare()` on your FlexSPI pin, it might not work as a FlexSPI
+/// pin. `prepare()` sets the SION bit of a [`Sclk`] or [`Dqs`] pin, and clears it
+/// for all other pins.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, <P::Signal as Signal>::SION, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a FlexSPI pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe {
+        super::prepare_mux(
+            pin.mux(),
+            P::ALT,
+            <P::Signal as Signal>::SION,
+            daisy.resolve(P::DAISY),
+        )
+    };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the FlexSPI input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a FlexSPI pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! flexspi {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, port: $port:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
+        impl Pin for $pad {
+            const ALT: u32 = $alt;
+            const DAISY: Option<Daisy> = $daisy;
+            type Signal = $signal;
+            type Port = $port;
+            type Module = crate::consts::Const<$module>;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prepare, Data0, Dqs, Pin, Sclk, Signal, Ss0, A};
+    use crate::{consts::Const, Daisy, ErasedPad, Iomuxc, SION_BIT};
+    use core::marker::PhantomData;
+
+    /// A FlexSPI pin in ALT 1, backed by test registers
+    struct TestPin<S>(ErasedPad, PhantomData<S>);
+
+    impl<S> TestPin<S> {
+        fn new(registers: &mut [u32; 2]) -> Self {
+            let [mux, pad] = registers;
+            Self(unsafe { ErasedPad::new(mux, pad) }, PhantomData)
+        }
+    }
+
+    impl<S> crate::private::Sealed for TestPin<S> {}
+    unsafe impl<S> Iomuxc for TestPin<S> {
+        fn mux(&mut self) -> *mut u32 {
+            self.0.mux()
+        }
+        fn pad(&mut self) -> *mut u32 {
+            self.0.pad()
+        }
+    }
+    impl<S: Signal> Pin for TestPin<S> {
+        const ALT: u32 = 1;
+        const DAISY: Option<Daisy> = None;
+        type Signal = S;
+        type Port = A;
+        type Module = Const<1>;
+    }
+
+    #[test]
+    fn sion_only_for_sclk_and_dqs() {
+        let mut registers = [[SION_BIT; 2]; 4];
+        let [sclk, dqs, ss0, data0] = &mut registers;
+        prepare(&mut TestPin::<Sclk>::new(sclk));
+        prepare(&mut TestPin::<Dqs>::new(dqs));
+        prepare(&mut TestPin::<Ss0>::new(ss0));
+        prepare(&mut TestPin::<Data0>::new(data0));
+        let muxes: [u32; 4] = core::array::from_fn(|idx| registers[idx][0]);
+        assert_eq!(muxes, [SION_BIT | 1, SION_BIT | 1, 1, 1]);
+    }
+}
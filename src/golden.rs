@@ -0,0 +1,217 @@
+//! Golden tests of pin data
+//!
+//! A signal [`Database`] lists the reference manual's alternates and select
+//! inputs. [`table()`] turns the database into golden rows: for each pad and
+//! signal, the alternate and daisy value that a `prepare()` must write.
+//! [`compare()`] checks a [`PreparePlan`] against a row. Since the plan records
+//! the same settings as the peripheral `prepare()` functions, without touching
+//! any register, the check runs on the host.
+//!
+//! ```
+//! use imxrt_iomuxc::{golden, signals::{Alternate, Database}, PreparePlan};
+//! use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_12;
+//!
+//! const DATABASE: Database = Database {
+//!     alternates: &[Alternate {
+//!         pad: GPIO_AD_B0_12::LOCATION,
+//!         alt: 2,
+//!         signal: "LPUART1_TX",
+//!     }],
+//!     inputs: &[],
+//! };
+//!
+//! let mut tx = unsafe { GPIO_AD_B0_12::new() };
+//! let mut plan = PreparePlan::<1>::new();
+//! plan.lpuart(&mut tx);
+//! assert_eq!(
+//!     golden::compare(&DATABASE, GPIO_AD_B0_12::LOCATION, "LPUART1_TX", &plan),
+//!     Ok(())
+//! );
+//! ```
+//!
+//! A row's daisy is the select input with the signal's name. Signals whose
+//! select input register has another name don't have a golden daisy.
+
+use crate::{register_map::DaisyEntry, signals::Database, PadLocation, PreparePlan};
+
+/// The expected alternate and daisy of a pad's signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Golden {
+    /// The pad
+    pub pad: PadLocation,
+    /// The signal name from the reference manual, like `"LPUART2_TX"`
+    pub signal: &'static str,
+    /// The MUX_MODE value that selects the signal
+    pub alt: u32,
+    /// The daisy value that routes the pad to the signal's input
+    pub daisy: Option<DaisyEntry>,
+}
+
+/// A plan that doesn't match its golden row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mismatch {
+    /// The database doesn't have the signal on the pad
+    UnknownSignal,
+    /// The plan doesn't write the pad's MUX register
+    NotPlanned,
+    /// The plan selects the wrong alternate
+    Alternate {
+        /// The golden alternate
+        expected: u32,
+        /// The planned alternate
+        actual: u32,
+    },
+    /// The plan doesn't write the golden daisy value
+    Daisy {
+        /// The golden daisy value
+        expected: DaisyEntry,
+        /// The value planned for the daisy register, if any
+        actual: Option<u32>,
+    },
+}
+
+/// Returns the golden rows of every alternate in `database`
+pub fn table(database: &Database) -> impl Iterator<Item = Golden> + '_ {
+    database.alternates.iter().map(move |alt| Golden {
+        pad: alt.pad,
+        signal: alt.signal,
+        alt: alt.alt,
+        daisy: database
+            .find_input(alt.pad, alt.signal)
+            .map(|input| DaisyEntry {
+                reg: input.register,
+                value: input.value,
+            }),
+    })
+}
+
+/// Returns the golden row of the pad's signal
+pub fn expected(database: &Database, pad: PadLocation, signal: &str) -> Option<Golden> {
+    table(database).find(|row| row.pad == pad && row.signal == signal)
+}
+
+/// Check that `plan` prepares the pad's signal like the golden row
+///
+/// `plan` may prepare other pads, too.
+pub fn compare<const N: usize>(
+    database: &Database,
+    pad: PadLocation,
+    signal: &str,
+    plan: &PreparePlan<'_, N>,
+) -> Result<(), Mismatch> {
+    let row = expected(database, pad, signal).ok_or(Mismatch::UnknownSignal)?;
+    let alt = plan.planned_alt(pad.mux).ok_or(Mismatch::NotPlanned)?;
+    if alt != row.alt {
+        return Err(Mismatch::Alternate {
+            expected: row.alt,
+            actual: alt,
+        });
+    }
+    if let Some(daisy) = row.daisy {
+        let actual = plan.planned_daisy(daisy.reg);
+        if actual != Some(daisy.value) {
+            return Err(Mismatch::Daisy {
+                expected: daisy,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, table, Mismatch};
+    use crate::register_map::DaisyEntry;
+    use crate::signals::{Alternate, Database, SelectInput};
+    use crate::{consts::Const, Daisy, Iomuxc, PadLocation, PreparePlan};
+
+    const MUX: u32 = 0x401F_80EC;
+    const PAD: PadLocation = PadLocation {
+        mux: MUX,
+        pad: 0x401F_82DC,
+    };
+    const DAISY: u32 = 0x401F_852C;
+
+    const DATABASE: Database = Database {
+        alternates: &[
+            Alternate {
+                pad: PAD,
+                alt: 2,
+                signal: "LPUART1_RX",
+            },
+            Alternate {
+                pad: PAD,
+                alt: 3,
+                signal: "LPUART2_RX",
+            },
+        ],
+        inputs: &[SelectInput {
+            input: "LPUART2_RX",
+            register: DAISY,
+            pad: PAD,
+            value: 1,
+        }],
+    };
+
+    /// A pad that's never written.
+    struct Pad;
+
+    impl crate::private::Sealed for Pad {}
+
+    unsafe impl Iomuxc for Pad {
+        fn mux(&mut self) -> *mut u32 {
+            MUX as *mut u32
+        }
+        fn pad(&mut self) -> *mut u32 {
+            PAD.pad as *mut u32
+        }
+    }
+
+    impl crate::lpuart::Pin for Pad {
+        const ALT: u32 = 3;
+        const DAISY: Option<Daisy> = Some(Daisy::new(DAISY as *mut u32, 2));
+        type Direction = crate::lpuart::Rx;
+        type Module = Const<2>;
+    }
+
+    #[test]
+    fn golden_rows() {
+        assert!(table(&DATABASE).map(|row| row.daisy).eq([
+            None,
+            Some(DaisyEntry {
+                reg: DAISY,
+                value: 1
+            })
+        ]));
+
+        let mut pad = Pad;
+        let mut plan = PreparePlan::<1>::new();
+        assert_eq!(
+            compare(&DATABASE, PAD, "LPUART2_RX", &plan),
+            Err(Mismatch::NotPlanned)
+        );
+        plan.lpuart(&mut pad);
+        assert_eq!(
+            compare(&DATABASE, PAD, "LPUART1_RX", &plan),
+            Err(Mismatch::Alternate {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            compare(&DATABASE, PAD, "LPUART2_RX", &plan),
+            Err(Mismatch::Daisy {
+                expected: DaisyEntry {
+                    reg: DAISY,
+                    value: 1
+                },
+                actual: Some(2)
+            })
+        );
+        assert_eq!(
+            compare(&DATABASE, PAD, "LPUART3_RX", &plan),
+            Err(Mismatch::UnknownSignal)
+        );
+    }
+}
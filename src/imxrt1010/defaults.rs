@@ -0,0 +1,111 @@
+//! Default pins for peripheral instances
+//!
+//! Each macro takes the chip's [`Pads`](super::Pads), and an instance number. It moves
+//! that instance's default pads out of `Pads`, returning them in a tuple. The defaults
+//! match the MIMXRT1010-EVK board and the SDK examples.
+//!
+//! An instance without a default pin set fails to compile.
+//!
+//! Each default pin set also has a bundle type, like [`Lpuart1`]. Convert the
+//! macro's tuple into the bundle to name, store, and prepare the pins together.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use imxrt_iomuxc::imxrt1010::{defaults, Pads};
+//!
+//! let pads = unsafe { Pads::new() };
+//! let (scl, sda) = defaults::lpi2c!(pads, 1);
+//!
+//! let mut lpuart1 = defaults::Lpuart1::from(defaults::lpuart!(pads, 1));
+//! lpuart1.prepare();
+//! ```
+
+#[cfg(feature = "imxrt1010-gpio")]
+use super::gpio::*;
+#[cfg(feature = "imxrt1010-gpio-ad")]
+use super::gpio_ad::*;
+
+/// The default LPUART1 pins
+#[cfg(feature = "imxrt1010-gpio")]
+pub type Lpuart1 = crate::lpuart::Pins<GPIO_10, GPIO_09>;
+/// The default LPI2C1 pins
+#[cfg(feature = "imxrt1010-gpio")]
+pub type Lpi2c1 = crate::lpi2c::Pins<GPIO_02, GPIO_01>;
+/// The default LPSPI1 pins
+#[cfg(feature = "imxrt1010-gpio-ad")]
+pub type Lpspi1 = crate::lpspi::Pins<GPIO_AD_04, GPIO_AD_03, GPIO_AD_06, GPIO_AD_05>;
+
+/// The boot ROM's serial downloader pins
+///
+/// The serial downloader receives on LPUART1, using the [`Lpuart1`] pads, and on
+/// USB OTG1, which has dedicated pads.
+#[cfg(feature = "imxrt1010-gpio")]
+pub type SerialDownloader = Lpuart1;
+
+/// Prepare the serial downloader pins, before entering the ROM
+///
+/// Selects the LPUART1 alternate on the pads, and writes any daisy registers,
+/// so that the ROM's serial downloader can reach them. Use this in a bootloader
+/// or in manufacturing firmware that reused the pads before it enters the
+/// serial downloader. The pad configurations don't change.
+///
+/// ```no_run
+/// use imxrt_iomuxc::imxrt1010::{defaults, Pads};
+///
+/// let pads = unsafe { Pads::new() };
+/// let mut sdp = defaults::SerialDownloader::from(defaults::lpuart!(pads, 1));
+/// defaults::prepare_serial_downloader(&mut sdp);
+/// // Enter the ROM...
+/// ```
+#[cfg(feature = "imxrt1010-gpio")]
+pub fn prepare_serial_downloader(pins: &mut SerialDownloader) {
+    pins.prepare();
+}
+
+/// Take the default LPUART pins; `(tx, rx)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1010_lpuart_defaults {
+    ($pads:expr, 1) => {
+        ($pads.gpio.p10, $pads.gpio.p09)
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPUART", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1010_lpuart_defaults as lpuart;
+
+/// Take the default LPI2C pins; `(scl, sda)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1010_lpi2c_defaults {
+    ($pads:expr, 1) => {
+        ($pads.gpio.p02, $pads.gpio.p01)
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPI2C", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1010_lpi2c_defaults as lpi2c;
+
+/// Take the default LPSPI pins; `(sdo, sdi, sck, pcs0)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1010_lpspi_defaults {
+    ($pads:expr, 1) => {
+        (
+            $pads.gpio_ad.p04,
+            $pads.gpio_ad.p03,
+            $pads.gpio_ad.p06,
+            $pads.gpio_ad.p05,
+        )
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPSPI", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1010_lpspi_defaults as lpspi;
@@ -1,8 +1,14 @@
 //! I2C pin implementations
 
-use super::pads::{gpio::*, gpio_ad::*, gpio_sd::*};
+#![cfg_attr(not(feature = "imxrt1010"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1010-gpio")]
+use super::pads::gpio::*;
+#[cfg(feature = "imxrt1010-gpio-ad")]
+use super::pads::gpio_ad::*;
+#[cfg(feature = "imxrt1010-gpio-sd")]
+use super::pads::gpio_sd::*;
 use crate::{
-    consts::*,
     lpi2c::{Pin, Scl, Sda},
     Daisy,
 };
@@ -12,34 +18,50 @@ use crate::{
 //
 
 // SCL
-i2c!(module: U1, alt: 0, pad: GPIO_AD_14,    signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_AD_14);
-i2c!(module: U1, alt: 1, pad: GPIO_SD_06,    signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_SD_06);
-i2c!(module: U1, alt: 1, pad: GPIO_12,       signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_12);
-i2c!(module: U1, alt: 3, pad: GPIO_02,       signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_02);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 1, alt: 0, pad: GPIO_AD_14,    signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_AD_14);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+i2c!(module: 1, alt: 1, pad: GPIO_SD_06,    signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_SD_06);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 1, alt: 1, pad: GPIO_12,       signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_12);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 1, alt: 3, pad: GPIO_02,       signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_02);
 
 // SDA
-i2c!(module: U1, alt: 0, pad: GPIO_AD_13,    signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_AD_13);
-i2c!(module: U1, alt: 1, pad: GPIO_SD_05,    signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_SD_05);
-i2c!(module: U1, alt: 1, pad: GPIO_11,       signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_11);
-i2c!(module: U1, alt: 3, pad: GPIO_01,       signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_01);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 1, alt: 0, pad: GPIO_AD_13,    signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_AD_13);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+i2c!(module: 1, alt: 1, pad: GPIO_SD_05,    signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_SD_05);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 1, alt: 1, pad: GPIO_11,       signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_11);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 1, alt: 3, pad: GPIO_01,       signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_01);
 
 //
 // I2C2
 //
 
 // SCL
-i2c!(module: U2, alt: 0, pad: GPIO_AD_08,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_AD_08);
-i2c!(module: U2, alt: 1, pad: GPIO_SD_08,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_SD_08);
-i2c!(module: U2, alt: 3, pad: GPIO_AD_02,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_AD_02);
-i2c!(module: U2, alt: 3, pad: GPIO_10,       signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_10);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 2, alt: 0, pad: GPIO_AD_08,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_AD_08);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+i2c!(module: 2, alt: 1, pad: GPIO_SD_08,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_SD_08);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 2, alt: 3, pad: GPIO_AD_02,    signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_AD_02);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 2, alt: 3, pad: GPIO_10,       signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_10);
 
 // SDA
-i2c!(module: U2, alt: 0, pad: GPIO_AD_07,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_AD_07);
-i2c!(module: U2, alt: 1, pad: GPIO_SD_07,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_SD_07);
-i2c!(module: U2, alt: 3, pad: GPIO_AD_01,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_AD_01);
-i2c!(module: U2, alt: 3, pad: GPIO_09,       signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_09);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 2, alt: 0, pad: GPIO_AD_07,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_AD_07);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+i2c!(module: 2, alt: 1, pad: GPIO_SD_07,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_SD_07);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+i2c!(module: 2, alt: 3, pad: GPIO_AD_01,    signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_AD_01);
+#[cfg(feature = "imxrt1010-gpio")]
+i2c!(module: 2, alt: 3, pad: GPIO_09,       signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_09);
 
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
 
     use super::Daisy;
@@ -61,5 +83,27 @@ mod daisy {
     pub const DAISY_LPI2C2_SDA_GPIO_AD_01: Daisy = Daisy::new(0x401f81cc as *mut u32, 1);
     pub const DAISY_LPI2C2_SDA_GPIO_SD_07: Daisy = Daisy::new(0x401f81cc as *mut u32, 2);
     pub const DAISY_LPI2C2_SDA_GPIO_09: Daisy = Daisy::new(0x401f81cc as *mut u32, 3);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPI2C1_HREQ_GPIO_AD_06,
+        DAISY_LPI2C1_HREQ_GPIO_10,
+        DAISY_LPI2C1_SCL_GPIO_AD_14,
+        DAISY_LPI2C1_SCL_GPIO_SD_06,
+        DAISY_LPI2C1_SCL_GPIO_12,
+        DAISY_LPI2C1_SCL_GPIO_02,
+        DAISY_LPI2C1_SDA_GPIO_AD_13,
+        DAISY_LPI2C1_SDA_GPIO_SD_05,
+        DAISY_LPI2C1_SDA_GPIO_11,
+        DAISY_LPI2C1_SDA_GPIO_01,
+        DAISY_LPI2C2_SCL_GPIO_AD_08,
+        DAISY_LPI2C2_SCL_GPIO_AD_02,
+        DAISY_LPI2C2_SCL_GPIO_SD_08,
+        DAISY_LPI2C2_SCL_GPIO_10,
+        DAISY_LPI2C2_SDA_GPIO_AD_07,
+        DAISY_LPI2C2_SDA_GPIO_AD_01,
+        DAISY_LPI2C2_SDA_GPIO_SD_07,
+        DAISY_LPI2C2_SDA_GPIO_09,
+    ];
 }
 use daisy::*;
@@ -1,8 +1,12 @@
 //! SPI pin implementations
 
-use super::pads::{gpio_ad::*, gpio_sd::*};
+#![cfg_attr(not(feature = "imxrt1010"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1010-gpio-ad")]
+use super::pads::gpio_ad::*;
+#[cfg(feature = "imxrt1010-gpio-sd")]
+use super::pads::gpio_sd::*;
 use crate::{
-    consts::*,
     lpspi::{Pcs0, Pin, Sck, Sdi, Sdo},
     Daisy,
 };
@@ -12,42 +16,58 @@ use crate::{
 //
 
 // PCS0
-spi!(module: U1, alt: 0, pad: GPIO_AD_05, signal: Pcs0, daisy: DAISY_LPSPI1_PCS_0_GPIO_AD_05);
-spi!(module: U1, alt: 2, pad: GPIO_SD_07, signal: Pcs0, daisy: DAISY_LPSPI1_PCS_0_GPIO_SD_07);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_05, signal: Pcs0, daisy: DAISY_LPSPI1_PCS_0_GPIO_AD_05);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 1, alt: 2, pad: GPIO_SD_07, signal: Pcs0, daisy: DAISY_LPSPI1_PCS_0_GPIO_SD_07);
 
 // SCK
-spi!(module: U1, alt: 0, pad: GPIO_AD_06, signal: Sck, daisy: DAISY_LPSPI1_SCK_GPIO_AD_06);
-spi!(module: U1, alt: 2, pad: GPIO_SD_08, signal: Sck, daisy: DAISY_LPSPI1_SCK_GPIO_SD_08);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_06, signal: Sck, daisy: DAISY_LPSPI1_SCK_GPIO_AD_06);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 1, alt: 2, pad: GPIO_SD_08, signal: Sck, daisy: DAISY_LPSPI1_SCK_GPIO_SD_08);
 
 // SDI
-spi!(module: U1, alt: 0, pad: GPIO_AD_03, signal: Sdi, daisy: DAISY_LPSPI1_SDI_GPIO_AD_03);
-spi!(module: U1, alt: 2, pad: GPIO_SD_05, signal: Sdi, daisy: DAISY_LPSPI1_SDI_GPIO_SD_05);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_03, signal: Sdi, daisy: DAISY_LPSPI1_SDI_GPIO_AD_03);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 1, alt: 2, pad: GPIO_SD_05, signal: Sdi, daisy: DAISY_LPSPI1_SDI_GPIO_SD_05);
 
 // SDO
-spi!(module: U1, alt: 0, pad: GPIO_AD_04, signal: Sdo, daisy: DAISY_LPSPI1_SDO_GPIO_AD_04);
-spi!(module: U1, alt: 2, pad: GPIO_SD_06, signal: Sdo, daisy: DAISY_LPSPI1_SDO_GPIO_SD_06);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_04, signal: Sdo, daisy: DAISY_LPSPI1_SDO_GPIO_AD_04);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 1, alt: 2, pad: GPIO_SD_06, signal: Sdo, daisy: DAISY_LPSPI1_SDO_GPIO_SD_06);
 
 //
 // SPI2
 //
 
 // PCS0
-spi!(module: U2, alt: 0, pad: GPIO_AD_11, signal: Pcs0, daisy: DAISY_LPSPI2_PCS_0_GPIO_AD_11);
-spi!(module: U2, alt: 1, pad: GPIO_SD_12, signal: Pcs0, daisy: DAISY_LPSPI2_PCS_0_GPIO_SD_12);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 2, alt: 0, pad: GPIO_AD_11, signal: Pcs0, daisy: DAISY_LPSPI2_PCS_0_GPIO_AD_11);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 2, alt: 1, pad: GPIO_SD_12, signal: Pcs0, daisy: DAISY_LPSPI2_PCS_0_GPIO_SD_12);
 
 // SCK
-spi!(module: U2, alt: 0, pad: GPIO_AD_12, signal: Sck, daisy: DAISY_LPSPI2_SCK_GPIO_AD_12);
-spi!(module: U2, alt: 1, pad: GPIO_SD_11, signal: Sck, daisy: DAISY_LPSPI2_SCK_GPIO_SD_11);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 2, alt: 0, pad: GPIO_AD_12, signal: Sck, daisy: DAISY_LPSPI2_SCK_GPIO_AD_12);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 2, alt: 1, pad: GPIO_SD_11, signal: Sck, daisy: DAISY_LPSPI2_SCK_GPIO_SD_11);
 
 // SDI
-spi!(module: U2, alt: 0, pad: GPIO_AD_09, signal: Sdi, daisy: DAISY_LPSPI2_SDI_GPIO_AD_09);
-spi!(module: U2, alt: 1, pad: GPIO_SD_09, signal: Sdi, daisy: DAISY_LPSPI2_SDI_GPIO_SD_09);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 2, alt: 0, pad: GPIO_AD_09, signal: Sdi, daisy: DAISY_LPSPI2_SDI_GPIO_AD_09);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 2, alt: 1, pad: GPIO_SD_09, signal: Sdi, daisy: DAISY_LPSPI2_SDI_GPIO_SD_09);
 
 // SDO
-spi!(module: U2, alt: 0, pad: GPIO_AD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_AD_10);
-spi!(module: U2, alt: 1, pad: GPIO_SD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_SD_10);
+#[cfg(feature = "imxrt1010-gpio-ad")]
+spi!(module: 2, alt: 0, pad: GPIO_AD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_AD_10);
+#[cfg(feature = "imxrt1010-gpio-sd")]
+spi!(module: 2, alt: 1, pad: GPIO_SD_10, signal: Sdo, daisy: DAISY_LPSPI2_SDO_GPIO_SD_10);
 
-mod daisy {
+pub mod daisy {
     use super::Daisy;
 
     pub const DAISY_LPSPI1_PCS_0_GPIO_AD_05: Daisy = Daisy::new(0x401f81d0 as *mut u32, 0);
@@ -66,6 +86,26 @@ mod daisy {
     pub const DAISY_LPSPI2_SDI_GPIO_SD_09: Daisy = Daisy::new(0x401f81e8 as *mut u32, 1);
     pub const DAISY_LPSPI2_SDO_GPIO_AD_10: Daisy = Daisy::new(0x401f81ec as *mut u32, 0);
     pub const DAISY_LPSPI2_SDO_GPIO_SD_10: Daisy = Daisy::new(0x401f81ec as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPSPI1_PCS_0_GPIO_AD_05,
+        DAISY_LPSPI1_PCS_0_GPIO_SD_07,
+        DAISY_LPSPI1_SCK_GPIO_AD_06,
+        DAISY_LPSPI1_SCK_GPIO_SD_08,
+        DAISY_LPSPI1_SDI_GPIO_AD_03,
+        DAISY_LPSPI1_SDI_GPIO_SD_05,
+        DAISY_LPSPI1_SDO_GPIO_AD_04,
+        DAISY_LPSPI1_SDO_GPIO_SD_06,
+        DAISY_LPSPI2_PCS_0_GPIO_AD_11,
+        DAISY_LPSPI2_PCS_0_GPIO_SD_12,
+        DAISY_LPSPI2_SCK_GPIO_AD_12,
+        DAISY_LPSPI2_SCK_GPIO_SD_11,
+        DAISY_LPSPI2_SDI_GPIO_AD_09,
+        DAISY_LPSPI2_SDI_GPIO_SD_09,
+        DAISY_LPSPI2_SDO_GPIO_AD_10,
+        DAISY_LPSPI2_SDO_GPIO_SD_10,
+    ];
 }
 
 use daisy::*;
@@ -1,8 +1,14 @@
 //! UART pin implementations
 
-use super::pads::{gpio::*, gpio_ad::*, gpio_sd::*};
+#![cfg_attr(not(feature = "imxrt1010"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1010-gpio")]
+use super::pads::gpio::*;
+#[cfg(feature = "imxrt1010-gpio-ad")]
+use super::pads::gpio_ad::*;
+#[cfg(feature = "imxrt1010-gpio-sd")]
+use super::pads::gpio_sd::*;
 use crate::{
-    consts::*,
     lpuart::{Pin, Rx, Tx},
     Daisy,
 };
@@ -10,39 +16,57 @@ use crate::{
 //
 // UART1
 //
-uart!(module: U1, alt: 0, pad: GPIO_09,      direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_09));
-uart!(module: U1, alt: 2, pad: GPIO_SD_11,   direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_SD_11));
-uart!(module: U1, alt: 0, pad: GPIO_10,      direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_10));
-uart!(module: U1, alt: 2, pad: GPIO_SD_12,   direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_SD_12));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 1, alt: 0, pad: GPIO_09,      direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_09));
+#[cfg(feature = "imxrt1010-gpio-sd")]
+uart!(module: 1, alt: 2, pad: GPIO_SD_11,   direction: Rx, daisy: Some(DAISY_LPUART1_RXD_GPIO_SD_11));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 1, alt: 0, pad: GPIO_10,      direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_10));
+#[cfg(feature = "imxrt1010-gpio-sd")]
+uart!(module: 1, alt: 2, pad: GPIO_SD_12,   direction: Tx, daisy: Some(DAISY_LPUART1_TXD_GPIO_SD_12));
 
 //
 // UART2
 //
-uart!(module: U2, alt: 0, pad: GPIO_13,      direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_13));
-uart!(module: U2, alt: 2, pad: GPIO_SD_09,   direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_SD_09));
-uart!(module: U2, alt: 0, pad: GPIO_AD_00,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_AD_00));
-uart!(module: U2, alt: 2, pad: GPIO_SD_10,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_SD_10));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 2, alt: 0, pad: GPIO_13,      direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_13));
+#[cfg(feature = "imxrt1010-gpio-sd")]
+uart!(module: 2, alt: 2, pad: GPIO_SD_09,   direction: Rx, daisy: Some(DAISY_LPUART2_RXD_GPIO_SD_09));
+#[cfg(feature = "imxrt1010-gpio-ad")]
+uart!(module: 2, alt: 0, pad: GPIO_AD_00,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_AD_00));
+#[cfg(feature = "imxrt1010-gpio-sd")]
+uart!(module: 2, alt: 2, pad: GPIO_SD_10,   direction: Tx, daisy: Some(DAISY_LPUART2_TXD_GPIO_SD_10));
 
 //
 // UART3
 //
-uart!(module: U3, alt: 0, pad: GPIO_11,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_11));
-uart!(module: U3, alt: 1, pad: GPIO_AD_07,   direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_AD_07));
-uart!(module: U3, alt: 3, pad: GPIO_07,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_07));
-uart!(module: U3, alt: 0, pad: GPIO_12,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_12));
-uart!(module: U3, alt: 1, pad: GPIO_AD_08,   direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_AD_08));
-uart!(module: U3, alt: 3, pad: GPIO_08,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_08));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 3, alt: 0, pad: GPIO_11,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_11));
+#[cfg(feature = "imxrt1010-gpio-ad")]
+uart!(module: 3, alt: 1, pad: GPIO_AD_07,   direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_AD_07));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 3, alt: 3, pad: GPIO_07,      direction: Rx, daisy: Some(DAISY_LPUART3_RXD_GPIO_07));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 3, alt: 0, pad: GPIO_12,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_12));
+#[cfg(feature = "imxrt1010-gpio-ad")]
+uart!(module: 3, alt: 1, pad: GPIO_AD_08,   direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_AD_08));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 3, alt: 3, pad: GPIO_08,      direction: Tx, daisy: Some(DAISY_LPUART3_TXD_GPIO_08));
 
 //
 // UART4
 //
-uart!(module: U4, alt: 0, pad: GPIO_AD_01,   direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_AD_01));
-uart!(module: U4, alt: 3, pad: GPIO_05,      direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_05));
-uart!(module: U4, alt: 0, pad: GPIO_AD_02,   direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_AD_02));
-uart!(module: U4, alt: 3, pad: GPIO_06,      direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_06));
+#[cfg(feature = "imxrt1010-gpio-ad")]
+uart!(module: 4, alt: 0, pad: GPIO_AD_01,   direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_AD_01));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 4, alt: 3, pad: GPIO_05,      direction: Rx, daisy: Some(DAISY_LPUART4_RXD_GPIO_05));
+#[cfg(feature = "imxrt1010-gpio-ad")]
+uart!(module: 4, alt: 0, pad: GPIO_AD_02,   direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_AD_02));
+#[cfg(feature = "imxrt1010-gpio")]
+uart!(module: 4, alt: 3, pad: GPIO_06,      direction: Tx, daisy: Some(DAISY_LPUART4_TXD_GPIO_06));
 
 /// Auto-generated Daisy constants
-mod daisy {
+pub mod daisy {
     use super::Daisy;
 
     pub const DAISY_LPUART1_RXD_GPIO_SD_11: Daisy = Daisy::new(0x401f81f0 as *mut u32, 0);
@@ -63,5 +87,27 @@ mod daisy {
     pub const DAISY_LPUART4_RXD_GPIO_05: Daisy = Daisy::new(0x401f8208 as *mut u32, 1);
     pub const DAISY_LPUART4_TXD_GPIO_AD_02: Daisy = Daisy::new(0x401f820c as *mut u32, 0);
     pub const DAISY_LPUART4_TXD_GPIO_06: Daisy = Daisy::new(0x401f820c as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPUART1_RXD_GPIO_SD_11,
+        DAISY_LPUART1_RXD_GPIO_09,
+        DAISY_LPUART1_TXD_GPIO_SD_12,
+        DAISY_LPUART1_TXD_GPIO_10,
+        DAISY_LPUART2_RXD_GPIO_SD_09,
+        DAISY_LPUART2_RXD_GPIO_13,
+        DAISY_LPUART2_TXD_GPIO_AD_00,
+        DAISY_LPUART2_TXD_GPIO_SD_10,
+        DAISY_LPUART3_RXD_GPIO_AD_07,
+        DAISY_LPUART3_RXD_GPIO_11,
+        DAISY_LPUART3_RXD_GPIO_07,
+        DAISY_LPUART3_TXD_GPIO_AD_08,
+        DAISY_LPUART3_TXD_GPIO_12,
+        DAISY_LPUART3_TXD_GPIO_08,
+        DAISY_LPUART4_RXD_GPIO_AD_01,
+        DAISY_LPUART4_RXD_GPIO_05,
+        DAISY_LPUART4_TXD_GPIO_AD_02,
+        DAISY_LPUART4_TXD_GPIO_06,
+    ];
 }
 use daisy::*;
@@ -8,11 +8,116 @@ mod lpi2c;
 mod lpspi;
 mod lpuart;
 
+pub mod defaults;
 mod pads;
 pub use pads::*;
+mod register_map;
+pub use register_map::REGISTER_MAP;
+mod signals;
+pub use signals::DATABASE;
+/// The length of a [`register_map::save()`](crate::register_map::save) snapshot
+pub const SNAPSHOT_LEN: usize = crate::register_map::snapshot_len(REGISTER_MAP, SNAPSHOT_GPRS);
+/// The IOMUXC_GPR registers that a [`register_map::save()`](crate::register_map::save)
+/// snapshot includes
+///
+/// This module doesn't describe the 1010's GPRs, so the list is empty.
+pub const SNAPSHOT_GPRS: &[u32] = &[];
 
+/// The FLEXSPI port A pads that the boot ROM reads the boot flash from
+///
+/// GPIO_SD_06 through GPIO_SD_12. See the [`xip`](crate::xip) module to guard them.
+pub const BOOT_FLASH: &[crate::PadLocation] = &[
+    // GPIO_SD_06
+    crate::PadLocation {
+        mux: 0x401F_806C,
+        pad: 0x401F_811C,
+    },
+    // GPIO_SD_07
+    crate::PadLocation {
+        mux: 0x401F_8068,
+        pad: 0x401F_8118,
+    },
+    // GPIO_SD_08
+    crate::PadLocation {
+        mux: 0x401F_8064,
+        pad: 0x401F_8114,
+    },
+    // GPIO_SD_09
+    crate::PadLocation {
+        mux: 0x401F_8060,
+        pad: 0x401F_8110,
+    },
+    // GPIO_SD_10
+    crate::PadLocation {
+        mux: 0x401F_805C,
+        pad: 0x401F_810C,
+    },
+    // GPIO_SD_11
+    crate::PadLocation {
+        mux: 0x401F_8058,
+        pad: 0x401F_8108,
+    },
+    // GPIO_SD_12
+    crate::PadLocation {
+        mux: 0x401F_8054,
+        pad: 0x401F_8104,
+    },
+];
+
+/// The FLEXSPI memory-mapped address range
+pub const FLEXSPI_MEMORY: core::ops::Range<u32> = 0x6000_0000..0x7000_0000;
+
+/// The chip family, in the upper half of the USB_ANALOG DIGPROG register
+///
+/// The 1010 reports `0x006D`. The lower half is the silicon revision.
+pub const DIGPROG_FAMILY: u32 = 0x006D;
+
+/// In debug builds, panic unless the chip is an i.MX RT 1010
+///
+/// Reads the USB_ANALOG DIGPROG register, and compares its chip family to
+/// [`DIGPROG_FAMILY`]. Call this early, before you prepare any pins, to catch a
+/// 1010 build that's running on another chip, with other pads. Release builds
+/// don't read the register.
+///
+/// # Safety
+///
+/// Reads a processor register. Only call this on an i.MX RT chip.
+pub unsafe fn debug_check_chip() {
+    crate::debug_check_chip(crate::DIGPROG, "1010", DIGPROG_FAMILY)
+}
+
+/// Route a pad to a peripheral input
+///
+/// Writes the daisy value that selects `pad` for `input`, using the chip's
+/// [`REGISTER_MAP`]. Fails, without writing, if the pad can't feed the input.
+/// See [`register_map::select_input()`](crate::register_map::select_input).
+pub fn select_input(
+    input: crate::register_map::InputSignal,
+    pad: &crate::ErasedPad,
+) -> Result<(), crate::register_map::RoutingError> {
+    // Safety: the map describes this chip, and the erased pad proves that
+    // we own the pad. The daisy register is shared, like it is for the
+    // peripheral prepare() functions.
+    unsafe { crate::register_map::select_input(REGISTER_MAP, input, pad) }
+}
+
+/// Daisy values for the chip's select inputs
+///
+/// Each peripheral module has a constant for every value of the peripheral's
+/// select input registers. The pin implementations use these values, so you
+/// can audit, or perform, a pin's input selection yourself.
+///
+/// To wire a peripheral that has no pin traits, generate its constants with
+/// `daisy.py`, like `daisy.py path/to/imxrt.svd ENET`.
+pub mod daisy {
+    pub use super::lpi2c::daisy as lpi2c;
+    pub use super::lpspi::daisy as lpspi;
+    pub use super::lpuart::daisy as lpuart;
+}
+
+#[cfg(feature = "imxrt1010-gpio-sd")]
 mod ccm {
-    pub use crate::ccm::{Function, Observable, Pin};
+    pub use crate::ccm::{Observable, Pin};
 
     impl Pin for super::pads::gpio_sd::GPIO_SD_02 {
         const ALT: u32 = 3;
@@ -23,3 +128,23 @@ mod ccm {
         type Function = Observable<2>;
     }
 }
+
+/// Check the invariants of all pad and daisy definitions
+///
+/// See the [`check`](crate::check) module for more information.
+#[cfg(feature = "self-check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-check")))]
+pub fn self_check() -> Result<(), crate::check::Violation> {
+    const IOMUXC: core::ops::Range<u32> = 0x401F_8000..0x401F_C000;
+    const IOMUXC_SNVS: core::ops::Range<u32> = 0x400A_8000..0x400A_C000;
+    // Safety: the erased pads never access their registers.
+    let pads = unsafe { Pads::new() }.erase();
+    crate::check::pads(
+        &[&pads.gpio_ad, &pads.gpio_sd, &pads.gpio, &pads.snvs],
+        &[IOMUXC, IOMUXC_SNVS],
+    )?;
+    crate::check::daisies(
+        &[lpi2c::daisy::ALL, lpspi::daisy::ALL, lpuart::daisy::ALL],
+        &[IOMUXC],
+    )
+}
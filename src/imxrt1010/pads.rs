@@ -1,143 +1,278 @@
 // Generated by iomuxc.py
 #![allow(non_camel_case_types)]
 
+// ONOFF does not have both MUX and PAD registers.
+// POR_B does not have both MUX and PAD registers.
+// TEST_MODE does not have both MUX and PAD registers.
+
 /// Pads with the prefix GPIO_AD.
+#[cfg(feature = "imxrt1010-gpio-ad")]
 pub mod gpio_ad {
 
     const GPIO_AD_14_MUX_ADDR: u32 = 0x401f8010;
     const GPIO_AD_14_PAD_ADDR: u32 = 0x401f80c0;
     pub type GPIO_AD_14 = crate::Pad<GPIO_AD_14_MUX_ADDR, GPIO_AD_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_14 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_14 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 28;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_14 {}
 
     const GPIO_AD_13_MUX_ADDR: u32 = 0x401f8014;
     const GPIO_AD_13_PAD_ADDR: u32 = 0x401f80c4;
     pub type GPIO_AD_13 = crate::Pad<GPIO_AD_13_MUX_ADDR, GPIO_AD_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_13 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_13 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 27;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_13 {}
 
     const GPIO_AD_12_MUX_ADDR: u32 = 0x401f8018;
     const GPIO_AD_12_PAD_ADDR: u32 = 0x401f80c8;
     pub type GPIO_AD_12 = crate::Pad<GPIO_AD_12_MUX_ADDR, GPIO_AD_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_12 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_12 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 26;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_12 {}
 
     const GPIO_AD_11_MUX_ADDR: u32 = 0x401f801c;
     const GPIO_AD_11_PAD_ADDR: u32 = 0x401f80cc;
     pub type GPIO_AD_11 = crate::Pad<GPIO_AD_11_MUX_ADDR, GPIO_AD_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_11 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 25;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_11 {}
 
     const GPIO_AD_10_MUX_ADDR: u32 = 0x401f8020;
     const GPIO_AD_10_PAD_ADDR: u32 = 0x401f80d0;
     pub type GPIO_AD_10 = crate::Pad<GPIO_AD_10_MUX_ADDR, GPIO_AD_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_10 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 24;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_10 {}
 
     const GPIO_AD_09_MUX_ADDR: u32 = 0x401f8024;
     const GPIO_AD_09_PAD_ADDR: u32 = 0x401f80d4;
     pub type GPIO_AD_09 = crate::Pad<GPIO_AD_09_MUX_ADDR, GPIO_AD_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_09 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 23;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_09 {}
 
     const GPIO_AD_08_MUX_ADDR: u32 = 0x401f8028;
     const GPIO_AD_08_PAD_ADDR: u32 = 0x401f80d8;
     pub type GPIO_AD_08 = crate::Pad<GPIO_AD_08_MUX_ADDR, GPIO_AD_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_08 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 22;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_08 {}
 
     const GPIO_AD_07_MUX_ADDR: u32 = 0x401f802c;
     const GPIO_AD_07_PAD_ADDR: u32 = 0x401f80dc;
     pub type GPIO_AD_07 = crate::Pad<GPIO_AD_07_MUX_ADDR, GPIO_AD_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_07 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 21;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_07 {}
 
     const GPIO_AD_06_MUX_ADDR: u32 = 0x401f8030;
     const GPIO_AD_06_PAD_ADDR: u32 = 0x401f80e0;
     pub type GPIO_AD_06 = crate::Pad<GPIO_AD_06_MUX_ADDR, GPIO_AD_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_06 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 20;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_06 {}
 
     const GPIO_AD_05_MUX_ADDR: u32 = 0x401f8034;
     const GPIO_AD_05_PAD_ADDR: u32 = 0x401f80e4;
     pub type GPIO_AD_05 = crate::Pad<GPIO_AD_05_MUX_ADDR, GPIO_AD_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_05 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 19;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_05 {}
 
     const GPIO_AD_04_MUX_ADDR: u32 = 0x401f8038;
     const GPIO_AD_04_PAD_ADDR: u32 = 0x401f80e8;
     pub type GPIO_AD_04 = crate::Pad<GPIO_AD_04_MUX_ADDR, GPIO_AD_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_04 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 18;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_04 {}
 
     const GPIO_AD_03_MUX_ADDR: u32 = 0x401f803c;
     const GPIO_AD_03_PAD_ADDR: u32 = 0x401f80ec;
     pub type GPIO_AD_03 = crate::Pad<GPIO_AD_03_MUX_ADDR, GPIO_AD_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_03 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 17;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_03 {}
 
     const GPIO_AD_02_MUX_ADDR: u32 = 0x401f8040;
     const GPIO_AD_02_PAD_ADDR: u32 = 0x401f80f0;
     pub type GPIO_AD_02 = crate::Pad<GPIO_AD_02_MUX_ADDR, GPIO_AD_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_02 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 16;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_02 {}
 
     const GPIO_AD_01_MUX_ADDR: u32 = 0x401f8044;
     const GPIO_AD_01_PAD_ADDR: u32 = 0x401f80f4;
     pub type GPIO_AD_01 = crate::Pad<GPIO_AD_01_MUX_ADDR, GPIO_AD_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_01 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 15;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_01 {}
 
     const GPIO_AD_00_MUX_ADDR: u32 = 0x401f8048;
     const GPIO_AD_00_PAD_ADDR: u32 = 0x401f80f8;
     pub type GPIO_AD_00 = crate::Pad<GPIO_AD_00_MUX_ADDR, GPIO_AD_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_00 {}
 
     impl crate::gpio::Pin<1> for GPIO_AD_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 14;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<7> for GPIO_AD_00 {}
 
     /// All pads with prefix GPIO_AD.
     pub struct Pads {
@@ -218,141 +353,259 @@ pub mod gpio_ad {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_SD.
+#[cfg(feature = "imxrt1010-gpio-sd")]
 pub mod gpio_sd {
 
     const GPIO_SD_14_MUX_ADDR: u32 = 0x401f804c;
     const GPIO_SD_14_PAD_ADDR: u32 = 0x401f80fc;
     pub type GPIO_SD_14 = crate::Pad<GPIO_SD_14_MUX_ADDR, GPIO_SD_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_14 {}
     // GPIO_SD_14 does not have any GPIO alternates.
+    impl crate::AnyAltPin<0> for GPIO_SD_14 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_14 {}
 
     const GPIO_SD_13_MUX_ADDR: u32 = 0x401f8050;
     const GPIO_SD_13_PAD_ADDR: u32 = 0x401f8100;
     pub type GPIO_SD_13 = crate::Pad<GPIO_SD_13_MUX_ADDR, GPIO_SD_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_13 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_13 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 13;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_13 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_13 {}
 
     const GPIO_SD_12_MUX_ADDR: u32 = 0x401f8054;
     const GPIO_SD_12_PAD_ADDR: u32 = 0x401f8104;
     pub type GPIO_SD_12 = crate::Pad<GPIO_SD_12_MUX_ADDR, GPIO_SD_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_12 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_12 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 12;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_12 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_12 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_12 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_12 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_12 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_12 {}
 
     const GPIO_SD_11_MUX_ADDR: u32 = 0x401f8058;
     const GPIO_SD_11_PAD_ADDR: u32 = 0x401f8108;
     pub type GPIO_SD_11 = crate::Pad<GPIO_SD_11_MUX_ADDR, GPIO_SD_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_11 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 11;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_11 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_11 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_11 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_11 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_11 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_11 {}
 
     const GPIO_SD_10_MUX_ADDR: u32 = 0x401f805c;
     const GPIO_SD_10_PAD_ADDR: u32 = 0x401f810c;
     pub type GPIO_SD_10 = crate::Pad<GPIO_SD_10_MUX_ADDR, GPIO_SD_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_10 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 10;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_10 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_10 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_10 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_10 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_10 {}
 
     const GPIO_SD_09_MUX_ADDR: u32 = 0x401f8060;
     const GPIO_SD_09_PAD_ADDR: u32 = 0x401f8110;
     pub type GPIO_SD_09 = crate::Pad<GPIO_SD_09_MUX_ADDR, GPIO_SD_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_09 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 9;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_09 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_09 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_09 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_09 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_09 {}
 
     const GPIO_SD_08_MUX_ADDR: u32 = 0x401f8064;
     const GPIO_SD_08_PAD_ADDR: u32 = 0x401f8114;
     pub type GPIO_SD_08 = crate::Pad<GPIO_SD_08_MUX_ADDR, GPIO_SD_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_08 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 8;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_08 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_08 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_08 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_08 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_08 {}
 
     const GPIO_SD_07_MUX_ADDR: u32 = 0x401f8068;
     const GPIO_SD_07_PAD_ADDR: u32 = 0x401f8118;
     pub type GPIO_SD_07 = crate::Pad<GPIO_SD_07_MUX_ADDR, GPIO_SD_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_07 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 7;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_07 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_07 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_07 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_07 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_07 {}
 
     const GPIO_SD_06_MUX_ADDR: u32 = 0x401f806c;
     const GPIO_SD_06_PAD_ADDR: u32 = 0x401f811c;
     pub type GPIO_SD_06 = crate::Pad<GPIO_SD_06_MUX_ADDR, GPIO_SD_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_06 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 6;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_06 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_06 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_06 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_06 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_06 {}
 
     const GPIO_SD_05_MUX_ADDR: u32 = 0x401f8070;
     const GPIO_SD_05_PAD_ADDR: u32 = 0x401f8120;
     pub type GPIO_SD_05 = crate::Pad<GPIO_SD_05_MUX_ADDR, GPIO_SD_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_05 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 5;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_05 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_05 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_05 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_05 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_05 {}
 
     const GPIO_SD_04_MUX_ADDR: u32 = 0x401f8074;
     const GPIO_SD_04_PAD_ADDR: u32 = 0x401f8124;
     pub type GPIO_SD_04 = crate::Pad<GPIO_SD_04_MUX_ADDR, GPIO_SD_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_04 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 4;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_04 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_04 {}
 
     const GPIO_SD_03_MUX_ADDR: u32 = 0x401f8078;
     const GPIO_SD_03_PAD_ADDR: u32 = 0x401f8128;
     pub type GPIO_SD_03 = crate::Pad<GPIO_SD_03_MUX_ADDR, GPIO_SD_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_03 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_03 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_03 {}
 
     const GPIO_SD_02_MUX_ADDR: u32 = 0x401f807c;
     const GPIO_SD_02_PAD_ADDR: u32 = 0x401f812c;
     pub type GPIO_SD_02 = crate::Pad<GPIO_SD_02_MUX_ADDR, GPIO_SD_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_02 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_02 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_02 {}
 
     const GPIO_SD_01_MUX_ADDR: u32 = 0x401f8080;
     const GPIO_SD_01_PAD_ADDR: u32 = 0x401f8130;
     pub type GPIO_SD_01 = crate::Pad<GPIO_SD_01_MUX_ADDR, GPIO_SD_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_01 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_01 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_01 {}
 
     const GPIO_SD_00_MUX_ADDR: u32 = 0x401f8084;
     const GPIO_SD_00_PAD_ADDR: u32 = 0x401f8134;
     pub type GPIO_SD_00 = crate::Pad<GPIO_SD_00_MUX_ADDR, GPIO_SD_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_00 {}
 
     impl crate::gpio::Pin<2> for GPIO_SD_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_00 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_00 {}
 
     /// All pads with prefix GPIO_SD.
     pub struct Pads {
@@ -433,136 +686,259 @@ pub mod gpio_sd {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO.
+#[cfg(feature = "imxrt1010-gpio")]
 pub mod gpio {
 
     const GPIO_13_MUX_ADDR: u32 = 0x401f8088;
     const GPIO_13_PAD_ADDR: u32 = 0x401f8138;
     pub type GPIO_13 = crate::Pad<GPIO_13_MUX_ADDR, GPIO_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_13 {}
 
     impl crate::gpio::Pin<1> for GPIO_13 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 13;
     }
+    impl crate::AnyAltPin<0> for GPIO_13 {}
+    impl crate::AnyAltPin<1> for GPIO_13 {}
+    impl crate::AnyAltPin<2> for GPIO_13 {}
+    impl crate::AnyAltPin<3> for GPIO_13 {}
+    impl crate::AnyAltPin<4> for GPIO_13 {}
+    impl crate::AnyAltPin<5> for GPIO_13 {}
+    impl crate::AnyAltPin<6> for GPIO_13 {}
+    impl crate::AnyAltPin<7> for GPIO_13 {}
 
     const GPIO_12_MUX_ADDR: u32 = 0x401f808c;
     const GPIO_12_PAD_ADDR: u32 = 0x401f813c;
     pub type GPIO_12 = crate::Pad<GPIO_12_MUX_ADDR, GPIO_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_12 {}
 
     impl crate::gpio::Pin<1> for GPIO_12 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 12;
     }
+    impl crate::AnyAltPin<0> for GPIO_12 {}
+    impl crate::AnyAltPin<1> for GPIO_12 {}
+    impl crate::AnyAltPin<2> for GPIO_12 {}
+    impl crate::AnyAltPin<3> for GPIO_12 {}
+    impl crate::AnyAltPin<4> for GPIO_12 {}
+    impl crate::AnyAltPin<5> for GPIO_12 {}
+    impl crate::AnyAltPin<6> for GPIO_12 {}
+    impl crate::AnyAltPin<7> for GPIO_12 {}
 
     const GPIO_11_MUX_ADDR: u32 = 0x401f8090;
     const GPIO_11_PAD_ADDR: u32 = 0x401f8140;
     pub type GPIO_11 = crate::Pad<GPIO_11_MUX_ADDR, GPIO_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_11 {}
 
     impl crate::gpio::Pin<1> for GPIO_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 11;
     }
+    impl crate::AnyAltPin<0> for GPIO_11 {}
+    impl crate::AnyAltPin<1> for GPIO_11 {}
+    impl crate::AnyAltPin<2> for GPIO_11 {}
+    impl crate::AnyAltPin<3> for GPIO_11 {}
+    impl crate::AnyAltPin<4> for GPIO_11 {}
+    impl crate::AnyAltPin<5> for GPIO_11 {}
+    impl crate::AnyAltPin<6> for GPIO_11 {}
+    impl crate::AnyAltPin<7> for GPIO_11 {}
 
     const GPIO_10_MUX_ADDR: u32 = 0x401f8094;
     const GPIO_10_PAD_ADDR: u32 = 0x401f8144;
     pub type GPIO_10 = crate::Pad<GPIO_10_MUX_ADDR, GPIO_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_10 {}
 
     impl crate::gpio::Pin<1> for GPIO_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 10;
     }
+    impl crate::AnyAltPin<0> for GPIO_10 {}
+    impl crate::AnyAltPin<1> for GPIO_10 {}
+    impl crate::AnyAltPin<2> for GPIO_10 {}
+    impl crate::AnyAltPin<3> for GPIO_10 {}
+    impl crate::AnyAltPin<4> for GPIO_10 {}
+    impl crate::AnyAltPin<5> for GPIO_10 {}
+    impl crate::AnyAltPin<6> for GPIO_10 {}
 
     const GPIO_09_MUX_ADDR: u32 = 0x401f8098;
     const GPIO_09_PAD_ADDR: u32 = 0x401f8148;
     pub type GPIO_09 = crate::Pad<GPIO_09_MUX_ADDR, GPIO_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_09 {}
 
     impl crate::gpio::Pin<1> for GPIO_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 9;
     }
+    impl crate::AnyAltPin<0> for GPIO_09 {}
+    impl crate::AnyAltPin<1> for GPIO_09 {}
+    impl crate::AnyAltPin<2> for GPIO_09 {}
+    impl crate::AnyAltPin<3> for GPIO_09 {}
+    impl crate::AnyAltPin<4> for GPIO_09 {}
+    impl crate::AnyAltPin<5> for GPIO_09 {}
+    impl crate::AnyAltPin<6> for GPIO_09 {}
 
     const GPIO_08_MUX_ADDR: u32 = 0x401f809c;
     const GPIO_08_PAD_ADDR: u32 = 0x401f814c;
     pub type GPIO_08 = crate::Pad<GPIO_08_MUX_ADDR, GPIO_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_08 {}
 
     impl crate::gpio::Pin<1> for GPIO_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 8;
     }
+    impl crate::AnyAltPin<0> for GPIO_08 {}
+    impl crate::AnyAltPin<1> for GPIO_08 {}
+    impl crate::AnyAltPin<2> for GPIO_08 {}
+    impl crate::AnyAltPin<3> for GPIO_08 {}
+    impl crate::AnyAltPin<4> for GPIO_08 {}
+    impl crate::AnyAltPin<5> for GPIO_08 {}
+    impl crate::AnyAltPin<6> for GPIO_08 {}
 
     const GPIO_07_MUX_ADDR: u32 = 0x401f80a0;
     const GPIO_07_PAD_ADDR: u32 = 0x401f8150;
     pub type GPIO_07 = crate::Pad<GPIO_07_MUX_ADDR, GPIO_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_07 {}
 
     impl crate::gpio::Pin<1> for GPIO_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 7;
     }
+    impl crate::AnyAltPin<0> for GPIO_07 {}
+    impl crate::AnyAltPin<1> for GPIO_07 {}
+    impl crate::AnyAltPin<2> for GPIO_07 {}
+    impl crate::AnyAltPin<3> for GPIO_07 {}
+    impl crate::AnyAltPin<4> for GPIO_07 {}
+    impl crate::AnyAltPin<5> for GPIO_07 {}
+    impl crate::AnyAltPin<6> for GPIO_07 {}
 
     const GPIO_06_MUX_ADDR: u32 = 0x401f80a4;
     const GPIO_06_PAD_ADDR: u32 = 0x401f8154;
     pub type GPIO_06 = crate::Pad<GPIO_06_MUX_ADDR, GPIO_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_06 {}
 
     impl crate::gpio::Pin<1> for GPIO_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 6;
     }
+    impl crate::AnyAltPin<0> for GPIO_06 {}
+    impl crate::AnyAltPin<1> for GPIO_06 {}
+    impl crate::AnyAltPin<2> for GPIO_06 {}
+    impl crate::AnyAltPin<3> for GPIO_06 {}
+    impl crate::AnyAltPin<4> for GPIO_06 {}
+    impl crate::AnyAltPin<5> for GPIO_06 {}
 
     const GPIO_05_MUX_ADDR: u32 = 0x401f80a8;
     const GPIO_05_PAD_ADDR: u32 = 0x401f8158;
     pub type GPIO_05 = crate::Pad<GPIO_05_MUX_ADDR, GPIO_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_05 {}
 
     impl crate::gpio::Pin<1> for GPIO_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 5;
     }
+    impl crate::AnyAltPin<0> for GPIO_05 {}
+    impl crate::AnyAltPin<1> for GPIO_05 {}
+    impl crate::AnyAltPin<2> for GPIO_05 {}
+    impl crate::AnyAltPin<3> for GPIO_05 {}
+    impl crate::AnyAltPin<4> for GPIO_05 {}
+    impl crate::AnyAltPin<5> for GPIO_05 {}
 
     const GPIO_04_MUX_ADDR: u32 = 0x401f80ac;
     const GPIO_04_PAD_ADDR: u32 = 0x401f815c;
     pub type GPIO_04 = crate::Pad<GPIO_04_MUX_ADDR, GPIO_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_04 {}
 
     impl crate::gpio::Pin<1> for GPIO_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 4;
     }
+    impl crate::AnyAltPin<0> for GPIO_04 {}
+    impl crate::AnyAltPin<1> for GPIO_04 {}
+    impl crate::AnyAltPin<2> for GPIO_04 {}
+    impl crate::AnyAltPin<4> for GPIO_04 {}
+    impl crate::AnyAltPin<5> for GPIO_04 {}
 
     const GPIO_03_MUX_ADDR: u32 = 0x401f80b0;
     const GPIO_03_PAD_ADDR: u32 = 0x401f8160;
     pub type GPIO_03 = crate::Pad<GPIO_03_MUX_ADDR, GPIO_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_03 {}
 
     impl crate::gpio::Pin<1> for GPIO_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_03 {}
+    impl crate::AnyAltPin<1> for GPIO_03 {}
+    impl crate::AnyAltPin<2> for GPIO_03 {}
+    impl crate::AnyAltPin<4> for GPIO_03 {}
+    impl crate::AnyAltPin<5> for GPIO_03 {}
 
     const GPIO_02_MUX_ADDR: u32 = 0x401f80b4;
     const GPIO_02_PAD_ADDR: u32 = 0x401f8164;
     pub type GPIO_02 = crate::Pad<GPIO_02_MUX_ADDR, GPIO_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_02 {}
 
     impl crate::gpio::Pin<1> for GPIO_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_02 {}
+    impl crate::AnyAltPin<1> for GPIO_02 {}
+    impl crate::AnyAltPin<2> for GPIO_02 {}
+    impl crate::AnyAltPin<3> for GPIO_02 {}
+    impl crate::AnyAltPin<4> for GPIO_02 {}
+    impl crate::AnyAltPin<5> for GPIO_02 {}
 
     const GPIO_01_MUX_ADDR: u32 = 0x401f80b8;
     const GPIO_01_PAD_ADDR: u32 = 0x401f8168;
     pub type GPIO_01 = crate::Pad<GPIO_01_MUX_ADDR, GPIO_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_01 {}
 
     impl crate::gpio::Pin<1> for GPIO_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_01 {}
+    impl crate::AnyAltPin<1> for GPIO_01 {}
+    impl crate::AnyAltPin<2> for GPIO_01 {}
+    impl crate::AnyAltPin<3> for GPIO_01 {}
+    impl crate::AnyAltPin<4> for GPIO_01 {}
+    impl crate::AnyAltPin<5> for GPIO_01 {}
 
     const GPIO_00_MUX_ADDR: u32 = 0x401f80bc;
     const GPIO_00_PAD_ADDR: u32 = 0x401f816c;
     pub type GPIO_00 = crate::Pad<GPIO_00_MUX_ADDR, GPIO_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_00 {}
 
     impl crate::gpio::Pin<1> for GPIO_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_00 {}
+    impl crate::AnyAltPin<1> for GPIO_00 {}
+    impl crate::AnyAltPin<2> for GPIO_00 {}
+    impl crate::AnyAltPin<3> for GPIO_00 {}
+    impl crate::AnyAltPin<4> for GPIO_00 {}
+    impl crate::AnyAltPin<5> for GPIO_00 {}
 
     /// All pads with prefix GPIO.
     pub struct Pads {
@@ -640,13 +1016,106 @@ pub mod gpio {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
+}
+
+/// Pads with the prefix SNVS.
+#[cfg(feature = "imxrt1010-snvs")]
+pub mod snvs {
+
+    const PMIC_ON_REQ_MUX_ADDR: u32 = 0x400a8000;
+    const PMIC_ON_REQ_PAD_ADDR: u32 = 0x400a8010;
+    pub type PMIC_ON_REQ = crate::Pad<PMIC_ON_REQ_MUX_ADDR, PMIC_ON_REQ_PAD_ADDR>;
+    impl crate::OutputCapable for PMIC_ON_REQ {}
+
+    impl crate::gpio::Pin<5> for PMIC_ON_REQ {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 0;
+    }
+    impl crate::AnyAltPin<0> for PMIC_ON_REQ {}
+    impl crate::AnyAltPin<5> for PMIC_ON_REQ {}
+
+    /// All pads with prefix SNVS.
+    pub struct Pads {
+        pub pmic_on_req: PMIC_ON_REQ,
+    }
+    /// Erased pads with prefix SNVS.
+    ///
+    /// Use [`Pads::erase()`] to get an `ErasedPads` instance.
+    pub type ErasedPads = [crate::ErasedPad; 1];
+    impl Pads {
+        /// Take all pads from this group
+        ///
+        /// # Safety
+        ///
+        /// You may safely call this once to acquire all of the pads.
+        /// Subsequent calls may return pads that are mutably aliased
+        /// elsewhere. Consider calling new() at the start of your program.
+        #[inline]
+        pub const unsafe fn new() -> Self {
+            Self {
+                pmic_on_req: PMIC_ON_REQ::new(),
+            }
+        }
+
+        /// Erase all of the pads
+        ///
+        /// The return type is an array, where the index indicates the
+        /// pad offset from the start of the group. For example, AD_B0_03
+        /// would be referenced as erased_pads\[3\].
+        ///
+        /// See `ErasedPads` for more information.
+        #[inline]
+        pub const fn erase(self) -> ErasedPads {
+            [self.pmic_on_req.erase()]
+        }
+    }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// All of the pads.
+///
+/// The fields are public, so you can move groups out of `Pads`, and regroup
+/// them with a struct literal. To regroup erased pads, use `TryFrom`.
 pub struct Pads {
+    #[cfg(feature = "imxrt1010-gpio-ad")]
     pub gpio_ad: gpio_ad::Pads,
+    #[cfg(feature = "imxrt1010-gpio-sd")]
     pub gpio_sd: gpio_sd::Pads,
+    #[cfg(feature = "imxrt1010-gpio")]
     pub gpio: gpio::Pads,
+    #[cfg(feature = "imxrt1010-snvs")]
+    pub snvs: snvs::Pads,
 }
 
 impl Pads {
@@ -660,9 +1129,14 @@ impl Pads {
     #[inline]
     pub const unsafe fn new() -> Self {
         Self {
+            #[cfg(feature = "imxrt1010-gpio-ad")]
             gpio_ad: gpio_ad::Pads::new(),
+            #[cfg(feature = "imxrt1010-gpio-sd")]
             gpio_sd: gpio_sd::Pads::new(),
+            #[cfg(feature = "imxrt1010-gpio")]
             gpio: gpio::Pads::new(),
+            #[cfg(feature = "imxrt1010-snvs")]
+            snvs: snvs::Pads::new(),
         }
     }
 
@@ -676,16 +1150,93 @@ impl Pads {
     #[inline]
     pub const fn erase(self) -> ErasedPads {
         ErasedPads {
+            #[cfg(feature = "imxrt1010-gpio-ad")]
             gpio_ad: self.gpio_ad.erase(),
+            #[cfg(feature = "imxrt1010-gpio-sd")]
             gpio_sd: self.gpio_sd.erase(),
+            #[cfg(feature = "imxrt1010-gpio")]
             gpio: self.gpio.erase(),
+            #[cfg(feature = "imxrt1010-snvs")]
+            snvs: self.snvs.erase(),
         }
     }
 }
 
 /// All erased pads.
 pub struct ErasedPads {
+    #[cfg(feature = "imxrt1010-gpio-ad")]
     pub gpio_ad: gpio_ad::ErasedPads,
+    #[cfg(feature = "imxrt1010-gpio-sd")]
     pub gpio_sd: gpio_sd::ErasedPads,
+    #[cfg(feature = "imxrt1010-gpio")]
     pub gpio: gpio::ErasedPads,
+    #[cfg(feature = "imxrt1010-snvs")]
+    pub snvs: snvs::ErasedPads,
+}
+
+impl ::core::convert::TryFrom<ErasedPads> for Pads {
+    type Error = ErasedPads;
+
+    /// Regroup all erased pads
+    ///
+    /// Fails, returning the erased pads, if any group does not match.
+    fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+        let expected = unsafe { Self::new() }.erase();
+        let same: &[bool] = &[
+            #[cfg(feature = "imxrt1010-gpio-ad")]
+            crate::same_pads(&erased.gpio_ad, &expected.gpio_ad),
+            #[cfg(feature = "imxrt1010-gpio-sd")]
+            crate::same_pads(&erased.gpio_sd, &expected.gpio_sd),
+            #[cfg(feature = "imxrt1010-gpio")]
+            crate::same_pads(&erased.gpio, &expected.gpio),
+            #[cfg(feature = "imxrt1010-snvs")]
+            crate::same_pads(&erased.snvs, &expected.snvs),
+        ];
+        if same.iter().all(|same| *same) {
+            // Safety: the erased pads represent ownership of all pads.
+            Ok(unsafe { Self::new() })
+        } else {
+            Err(erased)
+        }
+    }
+}
+
+impl ErasedPads {
+    /// Returns the erased pad at `location`
+    ///
+    /// Returns `None` if none of the groups has the pad.
+    pub fn get(&self, location: crate::PadLocation) -> Option<&crate::ErasedPad> {
+        crate::find_pad(
+            [
+                #[cfg(feature = "imxrt1010-gpio-ad")]
+                &self.gpio_ad,
+                #[cfg(feature = "imxrt1010-gpio-sd")]
+                &self.gpio_sd,
+                #[cfg(feature = "imxrt1010-gpio")]
+                &self.gpio,
+                #[cfg(feature = "imxrt1010-snvs")]
+                &self.snvs,
+            ],
+            location,
+        )
+    }
+
+    /// Returns the mutable erased pad at `location`
+    ///
+    /// Returns `None` if none of the groups has the pad.
+    pub fn get_mut(&mut self, location: crate::PadLocation) -> Option<&mut crate::ErasedPad> {
+        crate::find_pad_mut(
+            [
+                #[cfg(feature = "imxrt1010-gpio-ad")]
+                &mut self.gpio_ad,
+                #[cfg(feature = "imxrt1010-gpio-sd")]
+                &mut self.gpio_sd,
+                #[cfg(feature = "imxrt1010-gpio")]
+                &mut self.gpio,
+                #[cfg(feature = "imxrt1010-snvs")]
+                &mut self.snvs,
+            ],
+            location,
+        )
+    }
 }
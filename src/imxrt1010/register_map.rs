@@ -0,0 +1,532 @@
+// Generated by registermap.py
+use crate::register_map::{DaisyEntry, PadRegisters};
+
+/// All pads, and their registers
+///
+/// Each entry lists the daisy values that select the pad. The table doesn't
+/// know the pads' reset values.
+pub const REGISTER_MAP: &[PadRegisters] = &[
+    PadRegisters {
+        name: "GPIO_AD_14",
+        mux: 0x401f8010,
+        pad: 0x401f80c0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81c0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_13",
+        mux: 0x401f8014,
+        pad: 0x401f80c4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81c4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_12",
+        mux: 0x401f8018,
+        pad: 0x401f80c8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81e4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_11",
+        mux: 0x401f801c,
+        pad: 0x401f80cc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81e0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_10",
+        mux: 0x401f8020,
+        pad: 0x401f80d0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81ec,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_09",
+        mux: 0x401f8024,
+        pad: 0x401f80d4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81e8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_08",
+        mux: 0x401f8028,
+        pad: 0x401f80d8,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8204,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_07",
+        mux: 0x401f802c,
+        pad: 0x401f80dc,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81cc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8200,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_06",
+        mux: 0x401f8030,
+        pad: 0x401f80e0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81bc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f81d4,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_05",
+        mux: 0x401f8034,
+        pad: 0x401f80e4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81d0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_04",
+        mux: 0x401f8038,
+        pad: 0x401f80e8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81dc,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_03",
+        mux: 0x401f803c,
+        pad: 0x401f80ec,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81d8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_02",
+        mux: 0x401f8040,
+        pad: 0x401f80f0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f820c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_01",
+        mux: 0x401f8044,
+        pad: 0x401f80f4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81cc,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8208,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_00",
+        mux: 0x401f8048,
+        pad: 0x401f80f8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81fc,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_14",
+        mux: 0x401f804c,
+        pad: 0x401f80fc,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_13",
+        mux: 0x401f8050,
+        pad: 0x401f8100,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_12",
+        mux: 0x401f8054,
+        pad: 0x401f8104,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81e0,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81f4,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_11",
+        mux: 0x401f8058,
+        pad: 0x401f8108,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81e4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81f0,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_10",
+        mux: 0x401f805c,
+        pad: 0x401f810c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81ec,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81fc,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_09",
+        mux: 0x401f8060,
+        pad: 0x401f8110,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81e8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81f8,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_08",
+        mux: 0x401f8064,
+        pad: 0x401f8114,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c8,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f81d4,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_07",
+        mux: 0x401f8068,
+        pad: 0x401f8118,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81cc,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f81d0,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_06",
+        mux: 0x401f806c,
+        pad: 0x401f811c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c0,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81dc,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_05",
+        mux: 0x401f8070,
+        pad: 0x401f8120,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81d8,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_04",
+        mux: 0x401f8074,
+        pad: 0x401f8124,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_03",
+        mux: 0x401f8078,
+        pad: 0x401f8128,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_02",
+        mux: 0x401f807c,
+        pad: 0x401f812c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_01",
+        mux: 0x401f8080,
+        pad: 0x401f8130,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_00",
+        mux: 0x401f8084,
+        pad: 0x401f8134,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_13",
+        mux: 0x401f8088,
+        pad: 0x401f8138,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81f8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_12",
+        mux: 0x401f808c,
+        pad: 0x401f813c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c0,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8204,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_11",
+        mux: 0x401f8090,
+        pad: 0x401f8140,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81c4,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8200,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_10",
+        mux: 0x401f8094,
+        pad: 0x401f8144,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81bc,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f81c8,
+                value: 3,
+            },
+            DaisyEntry {
+                reg: 0x401f81f4,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_09",
+        mux: 0x401f8098,
+        pad: 0x401f8148,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f81cc,
+                value: 3,
+            },
+            DaisyEntry {
+                reg: 0x401f81f0,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_08",
+        mux: 0x401f809c,
+        pad: 0x401f814c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8204,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_07",
+        mux: 0x401f80a0,
+        pad: 0x401f8150,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8200,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_06",
+        mux: 0x401f80a4,
+        pad: 0x401f8154,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f820c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_05",
+        mux: 0x401f80a8,
+        pad: 0x401f8158,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8208,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_04",
+        mux: 0x401f80ac,
+        pad: 0x401f815c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_03",
+        mux: 0x401f80b0,
+        pad: 0x401f8160,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_02",
+        mux: 0x401f80b4,
+        pad: 0x401f8164,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81c0,
+            value: 3,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_01",
+        mux: 0x401f80b8,
+        pad: 0x401f8168,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f81c4,
+            value: 3,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_00",
+        mux: 0x401f80bc,
+        pad: 0x401f816c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "PMIC_ON_REQ",
+        mux: 0x400a8000,
+        pad: 0x400a8010,
+        reset: None,
+        daisies: &[],
+    },
+];
@@ -0,0 +1,3271 @@
+// Generated by signals.py
+
+use crate::signals::{Alternate, Database, SelectInput};
+
+/// Every alternate and select input of the chip.
+pub const DATABASE: Database = Database {
+    alternates: &[
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 0,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 1,
+            signal: "LPUART3_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 2,
+            signal: "KPP_COL00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 3,
+            signal: "LPUART4_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 6,
+            signal: "REF_CLK_24M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            alt: 7,
+            signal: "XBAR1_INOUT02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 0,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 1,
+            signal: "LPUART3_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 2,
+            signal: "KPP_ROW00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 3,
+            signal: "LPUART4_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 6,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            alt: 7,
+            signal: "JTAG_TMS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 0,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM0_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 2,
+            signal: "KPP_COL01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 3,
+            signal: "PIT_TRIGGER01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 6,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            alt: 7,
+            signal: "JTAG_TCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 0,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM1_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 2,
+            signal: "KPP_ROW01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 3,
+            signal: "PIT_TRIGGER02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 6,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            alt: 7,
+            signal: "JTAG_MOD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 0,
+            signal: "LPSPI2_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM2_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 2,
+            signal: "KPP_COL02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 3,
+            signal: "PIT_TRIGGER03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 6,
+            signal: "OTG1_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            alt: 7,
+            signal: "JTAG_TDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 0,
+            signal: "LPSPI2_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM3_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 2,
+            signal: "KPP_ROW02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 3,
+            signal: "ARM_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 6,
+            signal: "REF_32K_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            alt: 7,
+            signal: "JTAG_TDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 0,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 1,
+            signal: "LPUART3_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 2,
+            signal: "ARM_CM7_TXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 3,
+            signal: "LPUART2_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 4,
+            signal: "GPT2_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 6,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            alt: 7,
+            signal: "JTAG_TRSTB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 0,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 1,
+            signal: "LPUART3_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 2,
+            signal: "ARM_CM7_RXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 3,
+            signal: "LPUART2_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 4,
+            signal: "GPT2_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 6,
+            signal: "OCOTP_FUSE_LATCHED",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            alt: 7,
+            signal: "XBAR1_INOUT03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 0,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 1,
+            signal: "PIT_TRIGGER00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 3,
+            signal: "KPP_COL01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 4,
+            signal: "GPT2_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            alt: 6,
+            signal: "LPI2C1_HREQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 0,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 1,
+            signal: "PIT_TRIGGER01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 3,
+            signal: "KPP_ROW01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 4,
+            signal: "GPT2_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 0,
+            signal: "LPSPI1_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 1,
+            signal: "PIT_TRIGGER02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 3,
+            signal: "KPP_COL02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 4,
+            signal: "GPT2_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            alt: 6,
+            signal: "SNVS_HP_VIO_5_CTL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 0,
+            signal: "LPSPI1_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 1,
+            signal: "PIT_TRIGGER03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 3,
+            signal: "KPP_ROW02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 4,
+            signal: "GPT2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 6,
+            signal: "SNVS_HP_VIO_5_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            alt: 7,
+            signal: "JTAG_DE_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 0,
+            signal: "LPUART4_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 1,
+            signal: "LPSPI1_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 2,
+            signal: "WDOG2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 3,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 4,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 0,
+            signal: "LPUART4_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 1,
+            signal: "LPSPI2_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 2,
+            signal: "WDOG1_ANY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 3,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 4,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 6,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 0,
+            signal: "LPUART2_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 1,
+            signal: "LPSPI1_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 2,
+            signal: "KPP_COL03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 3,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 6,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f80fc,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f80fc,
+            },
+            alt: 1,
+            signal: "FLEXSPI_B_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 1,
+            signal: "SAI3_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 2,
+            signal: "ARM_CM7_TXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 3,
+            signal: "CCM_PMIC_RDY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 5,
+            signal: "GPIO2_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8100,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 1,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 2,
+            signal: "LPUART1_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 5,
+            signal: "GPIO2_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            alt: 6,
+            signal: "WDOG2_RST_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 1,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 2,
+            signal: "LPUART1_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 5,
+            signal: "GPIO2_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            alt: 6,
+            signal: "WDOG1_RST_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            alt: 1,
+            signal: "LPSPI2_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            alt: 2,
+            signal: "LPUART2_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            alt: 1,
+            signal: "LPSPI2_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            alt: 2,
+            signal: "LPUART2_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            alt: 5,
+            signal: "GPIO2_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            alt: 1,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            alt: 2,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            alt: 5,
+            signal: "GPIO2_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            alt: 1,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            alt: 5,
+            signal: "GPIO2_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            alt: 1,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            alt: 2,
+            signal: "LPSPI1_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            alt: 0,
+            signal: "FLEXSPI_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            alt: 1,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            alt: 2,
+            signal: "LPSPI1_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            alt: 5,
+            signal: "GPIO2_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 1,
+            signal: "SAI3_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 3,
+            signal: "CCM_WAIT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 5,
+            signal: "GPIO2_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_MODE00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 1,
+            signal: "SAI3_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 3,
+            signal: "CCM_REF_EN_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 5,
+            signal: "GPIO2_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_MODE01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 1,
+            signal: "SAI3_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 3,
+            signal: "CCM_CLKO1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 1,
+            signal: "SAI3_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 3,
+            signal: "CCM_CLKO2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 5,
+            signal: "GPIO2_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 1,
+            signal: "SAI3_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 2,
+            signal: "ARM_CM7_RXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 3,
+            signal: "CCM_STOP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 5,
+            signal: "GPIO2_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 0,
+            signal: "LPUART2_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 1,
+            signal: "LPSPI2_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 2,
+            signal: "KPP_ROW03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 3,
+            signal: "OTG1_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 6,
+            signal: "SPDIF_LOCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 0,
+            signal: "LPUART3_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 1,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 2,
+            signal: "KPP_COL00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 3,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 6,
+            signal: "SPDIF_EXT_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 0,
+            signal: "LPUART3_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 1,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 2,
+            signal: "KPP_ROW00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 3,
+            signal: "FLEXSPI_B_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 6,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            alt: 7,
+            signal: "ARM_CM7_TRACE03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 0,
+            signal: "LPUART1_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 1,
+            signal: "LPI2C1_HREQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 2,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 3,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            alt: 6,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 0,
+            signal: "LPUART1_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 1,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 2,
+            signal: "FLEXSPI_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 3,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            alt: 6,
+            signal: "SPDIF_SR_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 0,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 1,
+            signal: "GPT1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 3,
+            signal: "LPUART3_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            alt: 6,
+            signal: "LPUART1_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 0,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 1,
+            signal: "GPT1_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 3,
+            signal: "LPUART3_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 4,
+            signal: "SPDIF_LOCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            alt: 6,
+            signal: "LPUART1_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 0,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 1,
+            signal: "GPT1_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 3,
+            signal: "LPUART4_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 4,
+            signal: "SPDIF_EXT_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 0,
+            signal: "SAI1_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 1,
+            signal: "GPT1_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 3,
+            signal: "LPUART4_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 4,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            alt: 0,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            alt: 1,
+            signal: "GPT1_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            alt: 4,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            alt: 0,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            alt: 1,
+            signal: "GPT1_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            alt: 4,
+            signal: "SPDIF_SR_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 0,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 1,
+            signal: "WDOG2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 3,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 4,
+            signal: "KPP_COL03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 0,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 1,
+            signal: "WDOG1_ANY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 3,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 4,
+            signal: "KPP_ROW03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 0,
+            signal: "FLEXSPI_B_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 1,
+            signal: "SAI3_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 2,
+            signal: "LPSPI2_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 3,
+            signal: "LPSPI1_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 4,
+            signal: "PIT_TRIGGER00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            alt: 5,
+            signal: "GPIOMUX_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8000,
+                pad: 0x400a8010,
+            },
+            alt: 0,
+            signal: "SNVS_LP_PMIC_ON_REQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8000,
+                pad: 0x400a8010,
+            },
+            alt: 5,
+            signal: "GPIO5_IO00",
+        },
+    ],
+    inputs: &[
+        SelectInput {
+            input: "USB_OTG_ID",
+            register: 0x401f8170,
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG_ID",
+            register: 0x401f8170,
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_0",
+            register: 0x401f8174,
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f812c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_0",
+            register: 0x401f8174,
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_1",
+            register: 0x401f8178,
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8124,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_1",
+            register: 0x401f8178,
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_2",
+            register: 0x401f817c,
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_2",
+            register: 0x401f817c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_3",
+            register: 0x401f8180,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_3",
+            register: 0x401f8180,
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_0",
+            register: 0x401f8184,
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8130,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_0",
+            register: 0x401f8184,
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_1",
+            register: 0x401f8188,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8128,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_1",
+            register: 0x401f8188,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f8160,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_2",
+            register: 0x401f818c,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_2",
+            register: 0x401f818c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_3",
+            register: 0x401f8190,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_3",
+            register: 0x401f8190,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI_DQS_FA",
+            register: 0x401f8194,
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f80fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI_DQS_FA",
+            register: 0x401f8194,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI_DQS_FB",
+            register: 0x401f8198,
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f80fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI_DQS_FB",
+            register: 0x401f8198,
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f816c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_0",
+            register: 0x401f819c,
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_0",
+            register: 0x401f819c,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_1",
+            register: 0x401f81a0,
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_1",
+            register: 0x401f81a0,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_2",
+            register: 0x401f81a4,
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_2",
+            register: 0x401f81a4,
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_3",
+            register: 0x401f81a8,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_3",
+            register: 0x401f81a8,
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_0",
+            register: 0x401f81ac,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_0",
+            register: 0x401f81ac,
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_1",
+            register: 0x401f81b0,
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_1",
+            register: 0x401f81b0,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_2",
+            register: 0x401f81b4,
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_2",
+            register: 0x401f81b4,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_3",
+            register: 0x401f81b8,
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_3",
+            register: 0x401f81b8,
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_HREQ",
+            register: 0x401f81bc,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_HREQ",
+            register: 0x401f81bc,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f81c0,
+            pad: crate::PadLocation {
+                mux: 0x401f8010,
+                pad: 0x401f80c0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f81c0,
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f81c0,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f81c0,
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f8164,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f81c4,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f81c4,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f81c4,
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f81c4,
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f8168,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f81c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f81c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f81c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f81c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f81cc,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f81cc,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f81cc,
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f81cc,
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "LPSPI1_PCS_0",
+            register: 0x401f81d0,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f80e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_PCS_0",
+            register: 0x401f81d0,
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8118,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SCK",
+            register: 0x401f81d4,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f80e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SCK",
+            register: 0x401f81d4,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8114,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SDI",
+            register: 0x401f81d8,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f80ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SDI",
+            register: 0x401f81d8,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8120,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SDO",
+            register: 0x401f81dc,
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f80e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SDO",
+            register: 0x401f81dc,
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f811c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_PCS_0",
+            register: 0x401f81e0,
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f80cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_PCS_0",
+            register: 0x401f81e0,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SCK",
+            register: 0x401f81e4,
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f80c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SCK",
+            register: 0x401f81e4,
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SDI",
+            register: 0x401f81e8,
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f80d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SDI",
+            register: 0x401f81e8,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SDO",
+            register: 0x401f81ec,
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f80d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SDO",
+            register: 0x401f81ec,
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART1_RXD",
+            register: 0x401f81f0,
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8108,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART1_RXD",
+            register: 0x401f81f0,
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8148,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART1_TXD",
+            register: 0x401f81f4,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8104,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART1_TXD",
+            register: 0x401f81f4,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART2_RXD",
+            register: 0x401f81f8,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8110,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART2_RXD",
+            register: 0x401f81f8,
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8138,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART2_TXD",
+            register: 0x401f81fc,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART2_TXD",
+            register: 0x401f81fc,
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f810c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_RXD",
+            register: 0x401f8200,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART3_RXD",
+            register: 0x401f8200,
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8140,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_RXD",
+            register: 0x401f8200,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8150,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART3_TXD",
+            register: 0x401f8204,
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f80d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART3_TXD",
+            register: 0x401f8204,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_TXD",
+            register: 0x401f8204,
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f814c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART4_RXD",
+            register: 0x401f8208,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART4_RXD",
+            register: 0x401f8208,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8158,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART4_TXD",
+            register: 0x401f820c,
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f80f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART4_TXD",
+            register: 0x401f820c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "NMI_GLUE_NMI",
+            register: 0x401f8210,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f80c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "NMI_GLUE_NMI",
+            register: 0x401f8210,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f80f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SPDIF_IN1",
+            register: 0x401f8214,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8144,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SPDIF_IN1",
+            register: 0x401f8214,
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f815c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SPDIF_TX_CLK2",
+            register: 0x401f8218,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SPDIF_TX_CLK2",
+            register: 0x401f8218,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8154,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USB_OTG_OC",
+            register: 0x401f821c,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f80f4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG_OC",
+            register: 0x401f821c,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f813c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XEV_GLUE_RXEV",
+            register: 0x401f8220,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f80dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XEV_GLUE_RXEV",
+            register: 0x401f8220,
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8134,
+            },
+            value: 1,
+        },
+    ],
+};
@@ -5,45 +5,126 @@
 //! information available in Table 10-1: Muxing Options, in the IOMUXC
 //! section of the reference manual.
 
-use super::{gpio_ad_b0::*, gpio_ad_b1::*};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::gpio_ad_b1::*;
 use crate::adc::Pin;
 
 //
 // Adc1
 //
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_11, input: 0);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
 adc!(module: 1, pad: GPIO_AD_B0_12, input: 1);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
 adc!(module: 1, pad: GPIO_AD_B0_13, input: 2);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
 adc!(module: 1, pad: GPIO_AD_B0_14, input: 3);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
 adc!(module: 1, pad: GPIO_AD_B0_15, input: 4);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_00, input: 5);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_01, input: 6);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_02, input: 7);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_03, input: 8);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_04, input: 9);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_05, input: 10);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_06, input: 11);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_07, input: 12);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_08, input: 13);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_09, input: 14);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 1, pad: GPIO_AD_B1_10, input: 15);
 
 //
 // Adc2
 //
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_11, input: 0);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_12, input: 1);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_13, input: 2);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_14, input: 3);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_15, input: 4);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_00, input: 5);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_01, input: 6);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_02, input: 7);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_03, input: 8);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_04, input: 9);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_05, input: 10);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_06, input: 11);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_07, input: 12);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_08, input: 13);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_09, input: 14);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
 adc!(module: 2, pad: GPIO_AD_B1_10, input: 15);
+
+//
+// Analog
+//
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+impl crate::Analog for GPIO_AD_B0_12 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+impl crate::Analog for GPIO_AD_B0_13 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+impl crate::Analog for GPIO_AD_B0_14 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+impl crate::Analog for GPIO_AD_B0_15 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_00 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_01 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_02 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_03 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_04 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_05 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_06 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_07 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_08 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_09 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_10 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_11 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_12 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_13 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_14 {}
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+impl crate::Analog for GPIO_AD_B1_15 {}
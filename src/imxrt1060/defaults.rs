@@ -0,0 +1,119 @@
+//! Default pins for peripheral instances
+//!
+//! Each macro takes the chip's [`Pads`](super::Pads), and an instance number. It moves
+//! that instance's default pads out of `Pads`, returning them in a tuple. The defaults
+//! match the MIMXRT1060-EVK board and the SDK examples.
+//!
+//! An instance without a default pin set fails to compile.
+//!
+//! Each default pin set also has a bundle type, like [`Lpuart1`]. Convert the
+//! macro's tuple into the bundle to name, store, and prepare the pins together.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use imxrt_iomuxc::imxrt1060::{defaults, Pads};
+//!
+//! let pads = unsafe { Pads::new() };
+//! let (scl, sda) = defaults::lpi2c!(pads, 1);
+//!
+//! let mut lpuart1 = defaults::Lpuart1::from(defaults::lpuart!(pads, 1));
+//! lpuart1.prepare();
+//! ```
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::gpio_sd_b0::*;
+
+/// The default LPUART1 pins
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pub type Lpuart1 = crate::lpuart::Pins<GPIO_AD_B0_12, GPIO_AD_B0_13>;
+/// The default LPUART3 pins
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+pub type Lpuart3 = crate::lpuart::Pins<GPIO_AD_B1_06, GPIO_AD_B1_07>;
+/// The default LPI2C1 pins
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+pub type Lpi2c1 = crate::lpi2c::Pins<GPIO_AD_B1_00, GPIO_AD_B1_01>;
+/// The default LPSPI1 pins
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pub type Lpspi1 = crate::lpspi::Pins<GPIO_SD_B0_02, GPIO_SD_B0_03, GPIO_SD_B0_00, GPIO_SD_B0_01>;
+
+/// The boot ROM's serial downloader pins
+///
+/// The serial downloader receives on LPUART1, using the [`Lpuart1`] pads, and on
+/// USB OTG1, which has dedicated pads.
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pub type SerialDownloader = Lpuart1;
+
+/// Prepare the serial downloader pins, before entering the ROM
+///
+/// Selects the LPUART1 alternate on the pads, and writes any daisy registers,
+/// so that the ROM's serial downloader can reach them. Use this in a bootloader
+/// or in manufacturing firmware that reused the pads before it enters the
+/// serial downloader. The pad configurations don't change.
+///
+/// ```no_run
+/// use imxrt_iomuxc::imxrt1060::{defaults, Pads};
+///
+/// let pads = unsafe { Pads::new() };
+/// let mut sdp = defaults::SerialDownloader::from(defaults::lpuart!(pads, 1));
+/// defaults::prepare_serial_downloader(&mut sdp);
+/// // Enter the ROM...
+/// ```
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pub fn prepare_serial_downloader(pins: &mut SerialDownloader) {
+    pins.prepare();
+}
+
+/// Take the default LPUART pins; `(tx, rx)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1060_lpuart_defaults {
+    ($pads:expr, 1) => {
+        ($pads.gpio_ad_b0.p12, $pads.gpio_ad_b0.p13)
+    };
+    ($pads:expr, 3) => {
+        ($pads.gpio_ad_b1.p06, $pads.gpio_ad_b1.p07)
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPUART", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1060_lpuart_defaults as lpuart;
+
+/// Take the default LPI2C pins; `(scl, sda)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1060_lpi2c_defaults {
+    ($pads:expr, 1) => {
+        ($pads.gpio_ad_b1.p00, $pads.gpio_ad_b1.p01)
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPI2C", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1060_lpi2c_defaults as lpi2c;
+
+/// Take the default LPSPI pins; `(sdo, sdi, sck, pcs0)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imxrt1060_lpspi_defaults {
+    ($pads:expr, 1) => {
+        (
+            $pads.gpio_sd_b0.p02,
+            $pads.gpio_sd_b0.p03,
+            $pads.gpio_sd_b0.p00,
+            $pads.gpio_sd_b0.p01,
+        )
+    };
+    ($pads:expr, $n:literal) => {
+        compile_error!(concat!("No default pins for LPSPI", stringify!($n)))
+    };
+}
+#[doc(inline)]
+pub use __imxrt1060_lpspi_defaults as lpspi;
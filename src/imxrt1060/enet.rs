@@ -0,0 +1,60 @@
+//! ENET pin implementations
+//!
+//! ENET's RMII and management pads are on GPIO_B1.
+
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-b1")]
+use super::pads::gpio_b1::*;
+use crate::{
+    enet::{Mdc, Mdio, Pin, RefClk, RxData0, RxData1, RxEn, RxEr, TxData0, TxData1, TxEn},
+    Daisy,
+};
+
+//
+// ENET
+//
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 0, pad: GPIO_B1_14, signal: Mdc,     daisy: None);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 0, pad: GPIO_B1_15, signal: Mdio,    daisy: Some(DAISY_ENET_MDIO_GPIO_B1_15));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 6, pad: GPIO_B1_10, signal: RefClk,  daisy: Some(DAISY_ENET_IPG_CLK_RMII_GPIO_B1_10));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_09, signal: TxEn,    daisy: None);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_07, signal: TxData0, daisy: None);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_08, signal: TxData1, daisy: None);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_06, signal: RxEn,    daisy: Some(DAISY_ENET_RXEN_GPIO_B1_06));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_04, signal: RxData0, daisy: Some(DAISY_ENET0_RXDATA_GPIO_B1_04));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_05, signal: RxData1, daisy: Some(DAISY_ENET1_RXDATA_GPIO_B1_05));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+enet!(module: 1, alt: 3, pad: GPIO_B1_11, signal: RxEr,    daisy: Some(DAISY_ENET_RXERR_GPIO_B1_11));
+
+pub mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_ENET_IPG_CLK_RMII_GPIO_B1_10: Daisy = Daisy::new(0x401f842c as *mut u32, 1);
+    pub const DAISY_ENET_MDIO_GPIO_B1_15: Daisy = Daisy::new(0x401f8430 as *mut u32, 2);
+    pub const DAISY_ENET0_RXDATA_GPIO_B1_04: Daisy = Daisy::new(0x401f8434 as *mut u32, 1);
+    pub const DAISY_ENET1_RXDATA_GPIO_B1_05: Daisy = Daisy::new(0x401f8438 as *mut u32, 1);
+    pub const DAISY_ENET_RXEN_GPIO_B1_06: Daisy = Daisy::new(0x401f843c as *mut u32, 1);
+    pub const DAISY_ENET_RXERR_GPIO_B1_11: Daisy = Daisy::new(0x401f8440 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_ENET_IPG_CLK_RMII_GPIO_B1_10,
+        DAISY_ENET_MDIO_GPIO_B1_15,
+        DAISY_ENET0_RXDATA_GPIO_B1_04,
+        DAISY_ENET1_RXDATA_GPIO_B1_05,
+        DAISY_ENET_RXEN_GPIO_B1_06,
+        DAISY_ENET_RXERR_GPIO_B1_11,
+    ];
+}
+
+use daisy::*;
@@ -1,20 +1,57 @@
 //! PWM implementation
 
-use super::pads::{gpio_ad_b0::*, gpio_b0::*, gpio_b1::*, gpio_emc::*, gpio_sd_b0::*};
-use crate::{
-    consts::*,
-    flexpwm::{Pin, A, B},
-};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
 
-pwm!(module: U1, submodule: U0, alt: 1, pad: GPIO_SD_B0_00, output: A);
-pwm!(module: U1, submodule: U0, alt: 1, pad: GPIO_SD_B0_01, output: B);
-pwm!(module: U1, submodule: U3, alt: 1, pad: GPIO_AD_B0_10, output: A);
-pwm!(module: U1, submodule: U3, alt: 1, pad: GPIO_AD_B0_11, output: B);
-pwm!(module: U2, submodule: U2, alt: 2, pad: GPIO_B0_10, output: A);
-pwm!(module: U2, submodule: U2, alt: 2, pad: GPIO_B0_11, output: B);
-pwm!(module: U1, submodule: U3, alt: 6, pad: GPIO_B1_01, output: B);
-pwm!(module: U1, submodule: U3, alt: 6, pad: GPIO_B1_00, output: A);
-pwm!(module: U4, submodule: U2, alt: 1, pad: GPIO_EMC_04, output: A);
-pwm!(module: U4, submodule: U2, alt: 1, pad: GPIO_EMC_05, output: B);
-pwm!(module: U2, submodule: U0, alt: 1, pad: GPIO_EMC_06, output: A);
-pwm!(module: U2, submodule: U1, alt: 1, pad: GPIO_EMC_08, output: A);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::pads::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-b0")]
+use super::pads::gpio_b0::*;
+#[cfg(feature = "imxrt1060-gpio-b1")]
+use super::pads::gpio_b1::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::pads::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::pads::gpio_sd_b0::*;
+use crate::flexpwm::{Pin, A, B, X};
+
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 0, alt: 1, pad: GPIO_SD_B0_00, output: A);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 0, alt: 1, pad: GPIO_SD_B0_01, output: B);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 1, alt: 1, pad: GPIO_SD_B0_02, output: A);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 1, alt: 1, pad: GPIO_SD_B0_03, output: B);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 2, alt: 1, pad: GPIO_SD_B0_04, output: A);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+pwm!(module: 1, submodule: 2, alt: 1, pad: GPIO_SD_B0_05, output: B);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 3, alt: 1, pad: GPIO_AD_B0_10, output: A);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 3, alt: 1, pad: GPIO_AD_B0_11, output: B);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+pwm!(module: 2, submodule: 2, alt: 2, pad: GPIO_B0_10, output: A);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+pwm!(module: 2, submodule: 2, alt: 2, pad: GPIO_B0_11, output: B);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+pwm!(module: 1, submodule: 3, alt: 6, pad: GPIO_B1_01, output: B);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+pwm!(module: 1, submodule: 3, alt: 6, pad: GPIO_B1_00, output: A);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+pwm!(module: 4, submodule: 2, alt: 1, pad: GPIO_EMC_04, output: A);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+pwm!(module: 4, submodule: 2, alt: 1, pad: GPIO_EMC_05, output: B);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+pwm!(module: 2, submodule: 0, alt: 1, pad: GPIO_EMC_06, output: A);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+pwm!(module: 2, submodule: 1, alt: 1, pad: GPIO_EMC_08, output: A);
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 0, alt: 4, pad: GPIO_AD_B0_02, output: X);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 1, alt: 4, pad: GPIO_AD_B0_03, output: X);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 2, alt: 4, pad: GPIO_AD_B0_12, output: X);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+pwm!(module: 1, submodule: 3, alt: 4, pad: GPIO_AD_B0_13, output: X);
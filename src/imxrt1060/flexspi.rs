@@ -0,0 +1,258 @@
+//! FlexSPI pin implementations
+//!
+//! FLEXSPI is available on the GPIO_AD_B1, GPIO_SD_B0, and GPIO_SD_B1 pads.
+//! FLEXSPI2 ports A and B are available on the GPIO_EMC pads.
+
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::pads::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::pads::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::pads::gpio_sd_b0::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+use super::pads::gpio_sd_b1::*;
+use crate::{
+    flexspi::{Data0, Data1, Data2, Data3, Dqs, Pin, Sclk, Ss0, Ss1, A, B},
+    Daisy,
+};
+
+//
+// FLEXSPI, port A
+//
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_14, port: A, signal: Sclk,  daisy: Some(DAISY_FLEXSPIA_SCK_GPIO_AD_B1_14));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_07, port: A, signal: Sclk,  daisy: Some(DAISY_FLEXSPIA_SCK_GPIO_SD_B1_07));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_15, port: A, signal: Ss0,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_06, port: A, signal: Ss0,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_08, port: A, signal: Ss1,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_09, port: A, signal: Dqs,   daisy: Some(DAISY_FLEXSPIA_DQS_GPIO_AD_B1_09));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_05, port: A, signal: Dqs,   daisy: Some(DAISY_FLEXSPIA_DQS_GPIO_SD_B1_05));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_13, port: A, signal: Data0, daisy: Some(DAISY_FLEXSPIA_DATA0_GPIO_AD_B1_13));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_08, port: A, signal: Data0, daisy: Some(DAISY_FLEXSPIA_DATA0_GPIO_SD_B1_08));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_12, port: A, signal: Data1, daisy: Some(DAISY_FLEXSPIA_DATA1_GPIO_AD_B1_12));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_09, port: A, signal: Data1, daisy: Some(DAISY_FLEXSPIA_DATA1_GPIO_SD_B1_09));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_11, port: A, signal: Data2, daisy: Some(DAISY_FLEXSPIA_DATA2_GPIO_AD_B1_11));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_10, port: A, signal: Data2, daisy: Some(DAISY_FLEXSPIA_DATA2_GPIO_SD_B1_10));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_10, port: A, signal: Data3, daisy: Some(DAISY_FLEXSPIA_DATA3_GPIO_AD_B1_10));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_11, port: A, signal: Data3, daisy: Some(DAISY_FLEXSPIA_DATA3_GPIO_SD_B1_11));
+
+//
+// FLEXSPI, port B
+//
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_04, port: B, signal: Sclk,  daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+flexspi!(module: 1, alt: 4, pad: GPIO_SD_B0_04, port: B, signal: Ss0,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+flexspi!(module: 1, alt: 6, pad: GPIO_SD_B0_01, port: B, signal: Ss1,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+flexspi!(module: 1, alt: 4, pad: GPIO_SD_B0_05, port: B, signal: Dqs,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_07, port: B, signal: Data0, daisy: Some(DAISY_FLEXSPIB_DATA0_GPIO_AD_B1_07));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_03, port: B, signal: Data0, daisy: Some(DAISY_FLEXSPIB_DATA0_GPIO_SD_B1_03));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_06, port: B, signal: Data1, daisy: Some(DAISY_FLEXSPIB_DATA1_GPIO_AD_B1_06));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_02, port: B, signal: Data1, daisy: Some(DAISY_FLEXSPIB_DATA1_GPIO_SD_B1_02));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_05, port: B, signal: Data2, daisy: Some(DAISY_FLEXSPIB_DATA2_GPIO_AD_B1_05));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_01, port: B, signal: Data2, daisy: Some(DAISY_FLEXSPIB_DATA2_GPIO_SD_B1_01));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+flexspi!(module: 1, alt: 0, pad: GPIO_AD_B1_04, port: B, signal: Data3, daisy: Some(DAISY_FLEXSPIB_DATA3_GPIO_AD_B1_04));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+flexspi!(module: 1, alt: 1, pad: GPIO_SD_B1_00, port: B, signal: Data3, daisy: Some(DAISY_FLEXSPIB_DATA3_GPIO_SD_B1_00));
+
+//
+// FLEXSPI2, port A
+//
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_25,   port: A, signal: Sclk,  daisy: Some(DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_EMC_25));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_24,   port: A, signal: Ss0,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_22,   port: A, signal: Ss1,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_23,   port: A, signal: Dqs,   daisy: Some(DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_EMC_23));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_26,   port: A, signal: Data0, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_EMC_26));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_27,   port: A, signal: Data1, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_EMC_27));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_28,   port: A, signal: Data2, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_EMC_28));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_29,   port: A, signal: Data3, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_EMC_29));
+
+//
+// FLEXSPI2, port B
+//
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_12,   port: B, signal: Sclk,  daisy: Some(DAISY_FLEXSPI2_IPP_IND_SCK_FB_GPIO_EMC_12));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_10,   port: B, signal: Ss0,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_09,   port: B, signal: Ss1,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_11,   port: B, signal: Dqs,   daisy: None);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_13,   port: B, signal: Data0, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT0_GPIO_EMC_13));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_14,   port: B, signal: Data1, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT1_GPIO_EMC_14));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_15,   port: B, signal: Data2, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT2_GPIO_EMC_15));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+flexspi!(module: 2, alt: 8, pad: GPIO_EMC_16,   port: B, signal: Data3, daisy: Some(DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT3_GPIO_EMC_16));
+
+/// Auto-generated Daisy constants
+pub mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_FLEXSPIA_DQS_GPIO_SD_B1_05: Daisy = Daisy::new(0x401f84a4 as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_DQS_GPIO_AD_B1_09: Daisy = Daisy::new(0x401f84a4 as *mut u32, 1);
+    pub const DAISY_FLEXSPIA_DATA0_GPIO_SD_B1_08: Daisy = Daisy::new(0x401f84a8 as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_DATA0_GPIO_AD_B1_13: Daisy = Daisy::new(0x401f84a8 as *mut u32, 1);
+    pub const DAISY_FLEXSPIA_DATA1_GPIO_SD_B1_09: Daisy = Daisy::new(0x401f84ac as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_DATA1_GPIO_AD_B1_12: Daisy = Daisy::new(0x401f84ac as *mut u32, 1);
+    pub const DAISY_FLEXSPIA_DATA2_GPIO_SD_B1_10: Daisy = Daisy::new(0x401f84b0 as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_DATA2_GPIO_AD_B1_11: Daisy = Daisy::new(0x401f84b0 as *mut u32, 1);
+    pub const DAISY_FLEXSPIA_DATA3_GPIO_SD_B1_11: Daisy = Daisy::new(0x401f84b4 as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_DATA3_GPIO_AD_B1_10: Daisy = Daisy::new(0x401f84b4 as *mut u32, 1);
+    pub const DAISY_FLEXSPIB_DATA0_GPIO_SD_B1_03: Daisy = Daisy::new(0x401f84b8 as *mut u32, 0);
+    pub const DAISY_FLEXSPIB_DATA0_GPIO_AD_B1_07: Daisy = Daisy::new(0x401f84b8 as *mut u32, 1);
+    pub const DAISY_FLEXSPIB_DATA1_GPIO_SD_B1_02: Daisy = Daisy::new(0x401f84bc as *mut u32, 0);
+    pub const DAISY_FLEXSPIB_DATA1_GPIO_AD_B1_06: Daisy = Daisy::new(0x401f84bc as *mut u32, 1);
+    pub const DAISY_FLEXSPIB_DATA2_GPIO_SD_B1_01: Daisy = Daisy::new(0x401f84c0 as *mut u32, 0);
+    pub const DAISY_FLEXSPIB_DATA2_GPIO_AD_B1_05: Daisy = Daisy::new(0x401f84c0 as *mut u32, 1);
+    pub const DAISY_FLEXSPIB_DATA3_GPIO_SD_B1_00: Daisy = Daisy::new(0x401f84c4 as *mut u32, 0);
+    pub const DAISY_FLEXSPIB_DATA3_GPIO_AD_B1_04: Daisy = Daisy::new(0x401f84c4 as *mut u32, 1);
+    pub const DAISY_FLEXSPIA_SCK_GPIO_SD_B1_07: Daisy = Daisy::new(0x401f84c8 as *mut u32, 0);
+    pub const DAISY_FLEXSPIA_SCK_GPIO_AD_B1_14: Daisy = Daisy::new(0x401f84c8 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_SPI_B1_00: Daisy =
+        Daisy::new(0x401f872c as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_EMC_23: Daisy =
+        Daisy::new(0x401f872c as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_SPI_B0_09: Daisy =
+        Daisy::new(0x401f872c as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_SPI_B1_04: Daisy =
+        Daisy::new(0x401f8730 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_EMC_26: Daisy =
+        Daisy::new(0x401f8730 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_SPI_B0_02: Daisy =
+        Daisy::new(0x401f8730 as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_SPI_B1_03: Daisy =
+        Daisy::new(0x401f8734 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_EMC_27: Daisy =
+        Daisy::new(0x401f8734 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_SPI_B0_12: Daisy =
+        Daisy::new(0x401f8734 as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_SPI_B1_02: Daisy =
+        Daisy::new(0x401f8738 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_EMC_28: Daisy =
+        Daisy::new(0x401f8738 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_SPI_B0_06: Daisy =
+        Daisy::new(0x401f8738 as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_SPI_B1_01: Daisy =
+        Daisy::new(0x401f873c as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_EMC_29: Daisy =
+        Daisy::new(0x401f873c as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_SPI_B0_10: Daisy =
+        Daisy::new(0x401f873c as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT0_GPIO_EMC_13: Daisy =
+        Daisy::new(0x401f8740 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT0_GPIO_SPI_B0_11: Daisy =
+        Daisy::new(0x401f8740 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT1_GPIO_EMC_14: Daisy =
+        Daisy::new(0x401f8744 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT1_GPIO_SPI_B0_07: Daisy =
+        Daisy::new(0x401f8744 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT2_GPIO_EMC_15: Daisy =
+        Daisy::new(0x401f8748 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT2_GPIO_SPI_B0_03: Daisy =
+        Daisy::new(0x401f8748 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT3_GPIO_EMC_16: Daisy =
+        Daisy::new(0x401f874c as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT3_GPIO_SPI_B0_04: Daisy =
+        Daisy::new(0x401f874c as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_SPI_B1_05: Daisy =
+        Daisy::new(0x401f8750 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_EMC_25: Daisy =
+        Daisy::new(0x401f8750 as *mut u32, 1);
+    pub const DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_SPI_B0_08: Daisy =
+        Daisy::new(0x401f8750 as *mut u32, 2);
+    pub const DAISY_FLEXSPI2_IPP_IND_SCK_FB_GPIO_EMC_12: Daisy =
+        Daisy::new(0x401f8754 as *mut u32, 0);
+    pub const DAISY_FLEXSPI2_IPP_IND_SCK_FB_GPIO_SPI_B0_01: Daisy =
+        Daisy::new(0x401f8754 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_FLEXSPIA_DQS_GPIO_SD_B1_05,
+        DAISY_FLEXSPIA_DQS_GPIO_AD_B1_09,
+        DAISY_FLEXSPIA_DATA0_GPIO_SD_B1_08,
+        DAISY_FLEXSPIA_DATA0_GPIO_AD_B1_13,
+        DAISY_FLEXSPIA_DATA1_GPIO_SD_B1_09,
+        DAISY_FLEXSPIA_DATA1_GPIO_AD_B1_12,
+        DAISY_FLEXSPIA_DATA2_GPIO_SD_B1_10,
+        DAISY_FLEXSPIA_DATA2_GPIO_AD_B1_11,
+        DAISY_FLEXSPIA_DATA3_GPIO_SD_B1_11,
+        DAISY_FLEXSPIA_DATA3_GPIO_AD_B1_10,
+        DAISY_FLEXSPIB_DATA0_GPIO_SD_B1_03,
+        DAISY_FLEXSPIB_DATA0_GPIO_AD_B1_07,
+        DAISY_FLEXSPIB_DATA1_GPIO_SD_B1_02,
+        DAISY_FLEXSPIB_DATA1_GPIO_AD_B1_06,
+        DAISY_FLEXSPIB_DATA2_GPIO_SD_B1_01,
+        DAISY_FLEXSPIB_DATA2_GPIO_AD_B1_05,
+        DAISY_FLEXSPIB_DATA3_GPIO_SD_B1_00,
+        DAISY_FLEXSPIB_DATA3_GPIO_AD_B1_04,
+        DAISY_FLEXSPIA_SCK_GPIO_SD_B1_07,
+        DAISY_FLEXSPIA_SCK_GPIO_AD_B1_14,
+        DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_SPI_B1_00,
+        DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_EMC_23,
+        DAISY_FLEXSPI2_IPP_IND_DQS_FA_GPIO_SPI_B0_09,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_SPI_B1_04,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_EMC_26,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT0_GPIO_SPI_B0_02,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_SPI_B1_03,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_EMC_27,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT1_GPIO_SPI_B0_12,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_SPI_B1_02,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_EMC_28,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT2_GPIO_SPI_B0_06,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_SPI_B1_01,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_EMC_29,
+        DAISY_FLEXSPI2_IPP_IND_IO_FA_BIT3_GPIO_SPI_B0_10,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT0_GPIO_EMC_13,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT0_GPIO_SPI_B0_11,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT1_GPIO_EMC_14,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT1_GPIO_SPI_B0_07,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT2_GPIO_EMC_15,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT2_GPIO_SPI_B0_03,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT3_GPIO_EMC_16,
+        DAISY_FLEXSPI2_IPP_IND_IO_FB_BIT3_GPIO_SPI_B0_04,
+        DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_SPI_B1_05,
+        DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_EMC_25,
+        DAISY_FLEXSPI2_IPP_IND_SCK_FA_GPIO_SPI_B0_08,
+        DAISY_FLEXSPI2_IPP_IND_SCK_FB_GPIO_EMC_12,
+        DAISY_FLEXSPI2_IPP_IND_SCK_FB_GPIO_SPI_B0_01,
+    ];
+}
+
+use daisy::*;
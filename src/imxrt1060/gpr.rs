@@ -0,0 +1,208 @@
+//! IOMUXC_GPR fields that affect pin muxing
+//!
+//! The general purpose registers select some pad functions outside of the
+//! pads' MUX registers: the direction of the SAI MCLK pads, the ENET
+//! reference clock, and which GPIO module controls the GPIO pads. [`Gpr`]
+//! changes those fields without touching the rest of the GPRs.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::imxrt1060::gpr::{Direction, EnetClockSource, Gpr};
+//! use imxrt_iomuxc::imxrt1060::gpio_b0::GPIO_B0_03;
+//!
+//! let mut gpr = unsafe { Gpr::new() };
+//! // Drive the 50MHz RMII reference clock out of the ENET_REF_CLK pad.
+//! gpr.set_enet_clock_source(1, EnetClockSource::Pll);
+//! gpr.set_enet_tx_clock_direction(1, Direction::Output);
+//! // Control GPIO2_IO03 with the fast GPIO7.
+//! let led = unsafe { GPIO_B0_03::new() };
+//! gpr.set_fast_gpio(&led, true);
+//! ```
+
+use crate::gpio;
+
+/// The IOMUXC_GPR register block
+const BASE_ADDR: u32 = 0x400A_C000;
+const BASE: *mut u32 = BASE_ADDR as *mut u32;
+
+/// GPR1, with the SAI MCLK and ENET clock fields
+const GPR1: usize = 1;
+/// GPR26, the first fast GPIO select register; GPR26 through GPR29 select
+/// GPIO6 through GPIO9 for GPIO1 through GPIO4
+const GPR26: usize = 26;
+
+/// The addresses of GPR1 and GPR26 through GPR29, for a register snapshot
+pub(crate) const SNAPSHOT: &[u32] = &[
+    gpr_addr(GPR1),
+    gpr_addr(GPR26),
+    gpr_addr(GPR26 + 1),
+    gpr_addr(GPR26 + 2),
+    gpr_addr(GPR26 + 3),
+];
+
+const fn gpr_addr(gpr: usize) -> u32 {
+    BASE_ADDR + 4 * gpr as u32
+}
+
+/// The direction of a clock pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The pad is a clock input
+    Input,
+    /// The peripheral drives the clock out of the pad
+    Output,
+}
+
+/// The source of an ENET transmit reference clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnetClockSource {
+    /// The ENET PLL; `ENETn_CLK_SEL = 0`
+    ///
+    /// Set the TX clock direction to [`Output`](Direction::Output) to drive
+    /// the clock to the PHY.
+    Pll,
+    /// The `ENET_TX_CLK` pad; `ENETn_CLK_SEL = 1`
+    ///
+    /// An external oscillator clocks both the PHY and the ENET.
+    Pad,
+}
+
+/// The IOMUXC general purpose registers
+///
+/// The GPRs hold fields for many peripherals. `Gpr` only changes the fields
+/// that its methods name.
+#[derive(Debug)]
+pub struct Gpr {
+    base: *mut u32,
+}
+
+impl Gpr {
+    /// Acquire the general purpose registers
+    ///
+    /// # Safety
+    ///
+    /// The returned object modifies the GPRs without synchronization. Make
+    /// sure that there's only one `Gpr`, and nothing else modifies the GPRs
+    /// while it's changing a field.
+    pub const unsafe fn new() -> Self {
+        Self { base: BASE }
+    }
+
+    /// Use `registers` as the GPR block
+    #[cfg(test)]
+    pub(crate) fn from_registers(registers: &mut [u32; 30]) -> Self {
+        Self {
+            base: registers.as_mut_ptr(),
+        }
+    }
+
+    /// Set, and clear, bits in a GPR
+    fn modify(&mut self, index: usize, clear: u32, set: u32) {
+        // Safety: the base is the GPR block, and index is a GPR of the block.
+        // We have exclusive access to the GPRs.
+        unsafe {
+            let reg = self.base.add(index);
+            reg.write_volatile((reg.read_volatile() & !clear) | set);
+        }
+    }
+
+    /// Set the direction of a SAI's MCLK pad; `module` is `1` for `SAI1`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `module` isn't `1`, `2`, or `3`.
+    pub fn set_sai_mclk_direction(&mut self, module: u8, direction: Direction) {
+        assert!((1..=3).contains(&module), "SAI{module} doesn't exist");
+        // SAI1_MCLK_DIR is bit 19, followed by SAI2 and SAI3.
+        let bit = 1 << (18 + module as u32);
+        self.modify(GPR1, bit, set_if(direction == Direction::Output, bit));
+    }
+
+    /// Set the direction of an ENET's TX reference clock pad; `module` is `1`
+    /// for `ENET`, and `2` for `ENET2`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `module` isn't `1` or `2`.
+    pub fn set_enet_tx_clock_direction(&mut self, module: u8, direction: Direction) {
+        assert!((1..=2).contains(&module), "ENET{module} doesn't exist");
+        // ENET1_TX_CLK_DIR is bit 17, and ENET2_TX_CLK_DIR is bit 18.
+        let bit = 1 << (16 + module as u32);
+        self.modify(GPR1, bit, set_if(direction == Direction::Output, bit));
+    }
+
+    /// Select the source of an ENET's TX reference clock; `module` is `1` for
+    /// `ENET`, and `2` for `ENET2`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `module` isn't `1` or `2`.
+    pub fn set_enet_clock_source(&mut self, module: u8, source: EnetClockSource) {
+        assert!((1..=2).contains(&module), "ENET{module} doesn't exist");
+        // ENET1_CLK_SEL is bit 13, and ENET2_CLK_SEL is bit 14.
+        let bit = 1 << (12 + module as u32);
+        self.modify(GPR1, bit, set_if(source == EnetClockSource::Pad, bit));
+    }
+
+    /// Select the fast GPIO module for a GPIO pin
+    ///
+    /// When `fast` is `true`, GPIO6 controls a GPIO1 pin, GPIO7 controls a GPIO2
+    /// pin, and so on through GPIO9 and GPIO4. The fast modules connect to
+    /// the CPU's tightly coupled bus. Otherwise, the pin's own GPIO module
+    /// controls it. Only GPIO1 through GPIO4 pins have a fast module.
+    pub fn set_fast_gpio<P: gpio::Pin<N>, const N: u8>(&mut self, _: &P, fast: bool) {
+        const {
+            assert!(
+                1 <= N && N <= 4,
+                "only GPIO1 through GPIO4 have fast modules"
+            )
+        };
+        let bit = 1 << P::OFFSET;
+        self.modify(GPR26 + (N as usize - 1), bit, set_if(fast, bit));
+    }
+}
+
+/// Returns `bit` if `cond` is `true`, or zero
+const fn set_if(cond: bool, bit: u32) -> u32 {
+    if cond {
+        bit
+    } else {
+        0
+    }
+}
+
+#[cfg(all(test, feature = "imxrt1060-gpio-b0"))]
+mod tests {
+    use super::{Direction, EnetClockSource, Gpr, GPR1, GPR26, SNAPSHOT};
+    use crate::imxrt1060::gpio_b0::GPIO_B0_03;
+
+    #[test]
+    fn fields() {
+        let mut registers = [0u32; 30];
+        registers[GPR1] = 1;
+        let mut gpr = Gpr {
+            base: registers.as_mut_ptr(),
+        };
+        gpr.set_sai_mclk_direction(2, Direction::Output);
+        gpr.set_enet_tx_clock_direction(1, Direction::Output);
+        gpr.set_enet_clock_source(1, EnetClockSource::Pad);
+        gpr.set_enet_clock_source(1, EnetClockSource::Pll);
+        let led = unsafe { GPIO_B0_03::new() };
+        gpr.set_fast_gpio(&led, true);
+        assert_eq!(registers[GPR1], 1 | 1 << 20 | 1 << 17);
+        assert_eq!(registers[GPR26 + 1], 1 << 3);
+    }
+
+    #[test]
+    fn snapshot_addresses() {
+        assert_eq!(
+            SNAPSHOT,
+            [
+                0x400A_C004,
+                0x400A_C068,
+                0x400A_C06C,
+                0x400A_C070,
+                0x400A_C074
+            ]
+        );
+    }
+}
@@ -1,8 +1,20 @@
 //! I2C pin implementations
 
-use super::pads::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_sd_b0::*};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::pads::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::pads::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-b0")]
+use super::pads::gpio_b0::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::pads::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::pads::gpio_sd_b0::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+use super::pads::gpio_sd_b1::*;
 use crate::{
-    consts::*,
     lpi2c::{Pin, Scl, Sda},
     Daisy,
 };
@@ -10,31 +22,57 @@ use crate::{
 //
 // I2C1
 //
-i2c!(module: U1, alt: 3, pad: GPIO_AD_B1_00, signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_AD_B1_00);
-i2c!(module: U1, alt: 3, pad: GPIO_AD_B1_01, signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_AD_B1_01);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+i2c!(module: 1, alt: 3, pad: GPIO_AD_B1_00, signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_AD_B1_00);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+i2c!(module: 1, alt: 3, pad: GPIO_AD_B1_01, signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_AD_B1_01);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+i2c!(module: 1, alt: 2, pad: GPIO_SD_B1_04, signal: Scl, daisy: DAISY_LPI2C1_SCL_GPIO_SD_B1_04);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+i2c!(module: 1, alt: 2, pad: GPIO_SD_B1_05, signal: Sda, daisy: DAISY_LPI2C1_SDA_GPIO_SD_B1_05);
 
 //
 // I2C2
 //
-
-// TODO
+#[cfg(feature = "imxrt1060-gpio-b0")]
+i2c!(module: 2, alt: 2, pad: GPIO_B0_04, signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_B0_04);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+i2c!(module: 2, alt: 2, pad: GPIO_B0_05, signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_B0_05);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+i2c!(module: 2, alt: 3, pad: GPIO_SD_B1_11, signal: Scl, daisy: DAISY_LPI2C2_SCL_GPIO_SD_B1_11);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+i2c!(module: 2, alt: 3, pad: GPIO_SD_B1_10, signal: Sda, daisy: DAISY_LPI2C2_SDA_GPIO_SD_B1_10);
 
 //
 // I2C3
 //
-i2c!(module: U3, alt: 1, pad: GPIO_AD_B1_07, signal: Scl, daisy: DAISY_LPI2C3_SCL_GPIO_AD_B1_07);
-i2c!(module: U3, alt: 1, pad: GPIO_AD_B1_06, signal: Sda, daisy: DAISY_LPI2C3_SDA_GPIO_AD_B1_06);
-i2c!(module: U3, alt: 2, pad: GPIO_SD_B0_00, signal: Scl, daisy: DAISY_LPI2C3_SCL_GPIO_SD_B0_00);
-i2c!(module: U3, alt: 2, pad: GPIO_SD_B0_01, signal: Sda, daisy: DAISY_LPI2C3_SDA_GPIO_SD_B0_01);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+i2c!(module: 3, alt: 1, pad: GPIO_AD_B1_07, signal: Scl, daisy: DAISY_LPI2C3_SCL_GPIO_AD_B1_07);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+i2c!(module: 3, alt: 1, pad: GPIO_AD_B1_06, signal: Sda, daisy: DAISY_LPI2C3_SDA_GPIO_AD_B1_06);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+i2c!(module: 3, alt: 2, pad: GPIO_SD_B0_00, signal: Scl, daisy: DAISY_LPI2C3_SCL_GPIO_SD_B0_00);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+i2c!(module: 3, alt: 2, pad: GPIO_SD_B0_01, signal: Sda, daisy: DAISY_LPI2C3_SDA_GPIO_SD_B0_01);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+i2c!(module: 3, alt: 2, pad: GPIO_EMC_22, signal: Scl, daisy: DAISY_LPI2C3_SCL_GPIO_EMC_22);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+i2c!(module: 3, alt: 2, pad: GPIO_EMC_21, signal: Sda, daisy: DAISY_LPI2C3_SDA_GPIO_EMC_21);
 
 //
 // I2C4
 //
-i2c!(module: U4, alt: 0, pad: GPIO_AD_B0_12, signal: Scl, daisy: DAISY_LPI2C4_SCL_GPIO_AD_B0_12);
-i2c!(module: U4, alt: 0, pad: GPIO_AD_B0_13, signal: Sda, daisy: DAISY_LPI2C4_SDA_GPIO_AD_B0_13);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+i2c!(module: 4, alt: 0, pad: GPIO_AD_B0_12, signal: Scl, daisy: DAISY_LPI2C4_SCL_GPIO_AD_B0_12);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+i2c!(module: 4, alt: 0, pad: GPIO_AD_B0_13, signal: Sda, daisy: DAISY_LPI2C4_SDA_GPIO_AD_B0_13);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+i2c!(module: 4, alt: 2, pad: GPIO_EMC_12, signal: Scl, daisy: DAISY_LPI2C4_SCL_GPIO_EMC_12);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+i2c!(module: 4, alt: 2, pad: GPIO_EMC_11, signal: Sda, daisy: DAISY_LPI2C4_SDA_GPIO_EMC_11);
 
 /// Auto-generated Daisy constants
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
 
     use super::Daisy;
@@ -57,6 +95,28 @@ mod daisy {
     pub const DAISY_LPI2C4_SCL_GPIO_AD_B0_12: Daisy = Daisy::new(0x401f84e4 as *mut u32, 1);
     pub const DAISY_LPI2C4_SDA_GPIO_EMC_11: Daisy = Daisy::new(0x401f84e8 as *mut u32, 0);
     pub const DAISY_LPI2C4_SDA_GPIO_AD_B0_13: Daisy = Daisy::new(0x401f84e8 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPI2C1_SCL_GPIO_SD_B1_04,
+        DAISY_LPI2C1_SCL_GPIO_AD_B1_00,
+        DAISY_LPI2C1_SDA_GPIO_SD_B1_05,
+        DAISY_LPI2C1_SDA_GPIO_AD_B1_01,
+        DAISY_LPI2C2_SCL_GPIO_SD_B1_11,
+        DAISY_LPI2C2_SCL_GPIO_B0_04,
+        DAISY_LPI2C2_SDA_GPIO_SD_B1_10,
+        DAISY_LPI2C2_SDA_GPIO_B0_05,
+        DAISY_LPI2C3_SCL_GPIO_EMC_22,
+        DAISY_LPI2C3_SCL_GPIO_SD_B0_00,
+        DAISY_LPI2C3_SCL_GPIO_AD_B1_07,
+        DAISY_LPI2C3_SDA_GPIO_EMC_21,
+        DAISY_LPI2C3_SDA_GPIO_SD_B0_01,
+        DAISY_LPI2C3_SDA_GPIO_AD_B1_06,
+        DAISY_LPI2C4_SCL_GPIO_EMC_12,
+        DAISY_LPI2C4_SCL_GPIO_AD_B0_12,
+        DAISY_LPI2C4_SDA_GPIO_EMC_11,
+        DAISY_LPI2C4_SDA_GPIO_AD_B0_13,
+    ];
 }
 
 use daisy::*;
@@ -1,10 +1,22 @@
 //! SPI pin implementation
 
-use super::pads::{
-    gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_b1::*, gpio_emc::*, gpio_sd_b0::*, gpio_sd_b1::*,
-};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::pads::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::pads::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-b0")]
+use super::pads::gpio_b0::*;
+#[cfg(feature = "imxrt1060-gpio-b1")]
+use super::pads::gpio_b1::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::pads::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::pads::gpio_sd_b0::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+use super::pads::gpio_sd_b1::*;
 use crate::{
-    consts::*,
     lpspi::{Pcs0, Pin, Sck, Sdi, Sdo},
     Daisy,
 };
@@ -12,53 +24,85 @@ use crate::{
 //
 // SPI1
 //
-spi!(module: U1, alt: 3, pad: GPIO_EMC_30,   signal: Pcs0, daisy: DAISY_LPSPI1_PCS0_GPIO_EMC_30);
-spi!(module: U1, alt: 4, pad: GPIO_SD_B0_01, signal: Pcs0, daisy: DAISY_LPSPI1_PCS0_GPIO_SD_B0_01);
-spi!(module: U1, alt: 3, pad: GPIO_EMC_27,   signal: Sck,  daisy: DAISY_LPSPI1_SCK_GPIO_EMC_27);
-spi!(module: U1, alt: 4, pad: GPIO_SD_B0_00, signal: Sck,  daisy: DAISY_LPSPI1_SCK_GPIO_SD_B0_00);
-spi!(module: U1, alt: 3, pad: GPIO_EMC_29,   signal: Sdi,  daisy: DAISY_LPSPI1_SDI_GPIO_EMC_29);
-spi!(module: U1, alt: 4, pad: GPIO_SD_B0_03, signal: Sdi,  daisy: DAISY_LPSPI1_SDI_GPIO_SD_B0_03);
-spi!(module: U1, alt: 3, pad: GPIO_EMC_28,   signal: Sdo,  daisy: DAISY_LPSPI1_SDO_GPIO_EMC_28);
-spi!(module: U1, alt: 4, pad: GPIO_SD_B0_02, signal: Sdo,  daisy: DAISY_LPSPI1_SDO_GPIO_SD_B0_02);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 1, alt: 3, pad: GPIO_EMC_30,   signal: Pcs0, daisy: DAISY_LPSPI1_PCS0_GPIO_EMC_30);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+spi!(module: 1, alt: 4, pad: GPIO_SD_B0_01, signal: Pcs0, daisy: DAISY_LPSPI1_PCS0_GPIO_SD_B0_01);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 1, alt: 3, pad: GPIO_EMC_27,   signal: Sck,  daisy: DAISY_LPSPI1_SCK_GPIO_EMC_27);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+spi!(module: 1, alt: 4, pad: GPIO_SD_B0_00, signal: Sck,  daisy: DAISY_LPSPI1_SCK_GPIO_SD_B0_00);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 1, alt: 3, pad: GPIO_EMC_29,   signal: Sdi,  daisy: DAISY_LPSPI1_SDI_GPIO_EMC_29);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+spi!(module: 1, alt: 4, pad: GPIO_SD_B0_03, signal: Sdi,  daisy: DAISY_LPSPI1_SDI_GPIO_SD_B0_03);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 1, alt: 3, pad: GPIO_EMC_28,   signal: Sdo,  daisy: DAISY_LPSPI1_SDO_GPIO_EMC_28);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+spi!(module: 1, alt: 4, pad: GPIO_SD_B0_02, signal: Sdo,  daisy: DAISY_LPSPI1_SDO_GPIO_SD_B0_02);
 
 //
 // SPI2
 //
-spi!(module: U2, alt: 2, pad: GPIO_EMC_00,   signal: Sck,  daisy: DAISY_LPSPI2_SCK_GPIO_EMC_00);
-spi!(module: U2, alt: 4, pad: GPIO_SD_B1_07, signal: Sck,  daisy: DAISY_LPSPI2_SCK_GPIO_SD_B1_07);
-spi!(module: U2, alt: 2, pad: GPIO_EMC_02,   signal: Sdo,  daisy: DAISY_LPSPI2_SDO_GPIO_EMC_02);
-spi!(module: U2, alt: 4, pad: GPIO_SD_B1_08, signal: Sdo,  daisy: DAISY_LPSPI2_SDO_GPIO_SD_B1_08);
-spi!(module: U2, alt: 2, pad: GPIO_EMC_03,   signal: Sdi,  daisy: DAISY_LPSPI2_SDI_GPIO_EMC_03);
-spi!(module: U2, alt: 4, pad: GPIO_SD_B1_09, signal: Sdi,  daisy: DAISY_LPSPI2_SDI_GPIO_SD_B1_09);
-spi!(module: U2, alt: 2, pad: GPIO_EMC_01,   signal: Pcs0, daisy: DAISY_LPSPI2_PCS0_GPIO_EMC_01);
-spi!(module: U2, alt: 4, pad: GPIO_SD_B1_06, signal: Pcs0, daisy: DAISY_LPSPI2_PCS0_GPIO_SD_B1_06);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 2, alt: 2, pad: GPIO_EMC_00,   signal: Sck,  daisy: DAISY_LPSPI2_SCK_GPIO_EMC_00);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+spi!(module: 2, alt: 4, pad: GPIO_SD_B1_07, signal: Sck,  daisy: DAISY_LPSPI2_SCK_GPIO_SD_B1_07);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 2, alt: 2, pad: GPIO_EMC_02,   signal: Sdo,  daisy: DAISY_LPSPI2_SDO_GPIO_EMC_02);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+spi!(module: 2, alt: 4, pad: GPIO_SD_B1_08, signal: Sdo,  daisy: DAISY_LPSPI2_SDO_GPIO_SD_B1_08);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 2, alt: 2, pad: GPIO_EMC_03,   signal: Sdi,  daisy: DAISY_LPSPI2_SDI_GPIO_EMC_03);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+spi!(module: 2, alt: 4, pad: GPIO_SD_B1_09, signal: Sdi,  daisy: DAISY_LPSPI2_SDI_GPIO_SD_B1_09);
+#[cfg(feature = "imxrt1060-gpio-emc")]
+spi!(module: 2, alt: 2, pad: GPIO_EMC_01,   signal: Pcs0, daisy: DAISY_LPSPI2_PCS0_GPIO_EMC_01);
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+spi!(module: 2, alt: 4, pad: GPIO_SD_B1_06, signal: Pcs0, daisy: DAISY_LPSPI2_PCS0_GPIO_SD_B1_06);
 
 //
 // SPI3
 //
-spi!(module: U3, alt: 2, pad: GPIO_AD_B1_15, signal: Sck,  daisy: DAISY_LPSPI3_SCK_GPIO_AD_B1_15);
-spi!(module: U3, alt: 7, pad: GPIO_AD_B0_00, signal: Sck,  daisy: DAISY_LPSPI3_SCK_GPIO_AD_B0_00);
-spi!(module: U3, alt: 2, pad: GPIO_AD_B1_14, signal: Sdo,  daisy: DAISY_LPSPI3_SDO_GPIO_AD_B1_14);
-spi!(module: U3, alt: 7, pad: GPIO_AD_B0_01, signal: Sdo,  daisy: DAISY_LPSPI3_SDO_GPIO_AD_B0_01);
-spi!(module: U3, alt: 2, pad: GPIO_AD_B1_13, signal: Sdi,  daisy: DAISY_LPSPI3_SDI_GPIO_AD_B1_13);
-spi!(module: U3, alt: 7, pad: GPIO_AD_B0_02, signal: Sdi,  daisy: DAISY_LPSPI3_SDI_GPIO_AD_B0_02);
-spi!(module: U3, alt: 2, pad: GPIO_AD_B1_12, signal: Pcs0, daisy: DAISY_LPSPI3_PCS0_GPIO_AD_B1_12);
-spi!(module: U3, alt: 7, pad: GPIO_AD_B0_03, signal: Pcs0, daisy: DAISY_LPSPI3_PCS0_GPIO_AD_B0_03);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+spi!(module: 3, alt: 2, pad: GPIO_AD_B1_15, signal: Sck,  daisy: DAISY_LPSPI3_SCK_GPIO_AD_B1_15);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+spi!(module: 3, alt: 7, pad: GPIO_AD_B0_00, signal: Sck,  daisy: DAISY_LPSPI3_SCK_GPIO_AD_B0_00);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+spi!(module: 3, alt: 2, pad: GPIO_AD_B1_14, signal: Sdo,  daisy: DAISY_LPSPI3_SDO_GPIO_AD_B1_14);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+spi!(module: 3, alt: 7, pad: GPIO_AD_B0_01, signal: Sdo,  daisy: DAISY_LPSPI3_SDO_GPIO_AD_B0_01);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+spi!(module: 3, alt: 2, pad: GPIO_AD_B1_13, signal: Sdi,  daisy: DAISY_LPSPI3_SDI_GPIO_AD_B1_13);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+spi!(module: 3, alt: 7, pad: GPIO_AD_B0_02, signal: Sdi,  daisy: DAISY_LPSPI3_SDI_GPIO_AD_B0_02);
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+spi!(module: 3, alt: 2, pad: GPIO_AD_B1_12, signal: Pcs0, daisy: DAISY_LPSPI3_PCS0_GPIO_AD_B1_12);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+spi!(module: 3, alt: 7, pad: GPIO_AD_B0_03, signal: Pcs0, daisy: DAISY_LPSPI3_PCS0_GPIO_AD_B0_03);
 
 //
 // SPI4
 //
-spi!(module: U4, alt: 1, pad: GPIO_B1_07, signal: Sck,  daisy: DAISY_LPSPI4_SCK_GPIO_B1_07);
-spi!(module: U4, alt: 3, pad: GPIO_B0_03, signal: Sck,  daisy: DAISY_LPSPI4_SCK_GPIO_B0_03);
-spi!(module: U4, alt: 1, pad: GPIO_B1_06, signal: Sdo,  daisy: DAISY_LPSPI4_SDO_GPIO_B1_06);
-spi!(module: U4, alt: 3, pad: GPIO_B0_02, signal: Sdo,  daisy: DAISY_LPSPI4_SDO_GPIO_B0_02);
-spi!(module: U4, alt: 1, pad: GPIO_B1_05, signal: Sdi,  daisy: DAISY_LPSPI4_SDI_GPIO_B1_05);
-spi!(module: U4, alt: 3, pad: GPIO_B0_01, signal: Sdi,  daisy: DAISY_LPSPI4_SDI_GPIO_B0_01);
-spi!(module: U4, alt: 1, pad: GPIO_B1_04, signal: Pcs0, daisy: DAISY_LPSPI4_PCS0_GPIO_B1_04);
-spi!(module: U4, alt: 3, pad: GPIO_B0_00, signal: Pcs0, daisy: DAISY_LPSPI4_PCS0_GPIO_B0_00);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+spi!(module: 4, alt: 1, pad: GPIO_B1_07, signal: Sck,  daisy: DAISY_LPSPI4_SCK_GPIO_B1_07);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+spi!(module: 4, alt: 3, pad: GPIO_B0_03, signal: Sck,  daisy: DAISY_LPSPI4_SCK_GPIO_B0_03);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+spi!(module: 4, alt: 1, pad: GPIO_B1_06, signal: Sdo,  daisy: DAISY_LPSPI4_SDO_GPIO_B1_06);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+spi!(module: 4, alt: 3, pad: GPIO_B0_02, signal: Sdo,  daisy: DAISY_LPSPI4_SDO_GPIO_B0_02);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+spi!(module: 4, alt: 1, pad: GPIO_B1_05, signal: Sdi,  daisy: DAISY_LPSPI4_SDI_GPIO_B1_05);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+spi!(module: 4, alt: 3, pad: GPIO_B0_01, signal: Sdi,  daisy: DAISY_LPSPI4_SDI_GPIO_B0_01);
+#[cfg(feature = "imxrt1060-gpio-b1")]
+spi!(module: 4, alt: 1, pad: GPIO_B1_04, signal: Pcs0, daisy: DAISY_LPSPI4_PCS0_GPIO_B1_04);
+#[cfg(feature = "imxrt1060-gpio-b0")]
+spi!(module: 4, alt: 3, pad: GPIO_B0_00, signal: Pcs0, daisy: DAISY_LPSPI4_PCS0_GPIO_B0_00);
 
 /// Auto-generated DAISY values
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
 
     use super::Daisy;
@@ -95,6 +139,42 @@ mod daisy {
     pub const DAISY_LPSPI4_SDI_GPIO_B1_05: Daisy = Daisy::new(0x401f8524 as *mut u32, 1);
     pub const DAISY_LPSPI4_SDO_GPIO_B0_02: Daisy = Daisy::new(0x401f8528 as *mut u32, 0);
     pub const DAISY_LPSPI4_SDO_GPIO_B1_06: Daisy = Daisy::new(0x401f8528 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPSPI1_PCS0_GPIO_SD_B0_01,
+        DAISY_LPSPI1_PCS0_GPIO_EMC_30,
+        DAISY_LPSPI1_SCK_GPIO_EMC_27,
+        DAISY_LPSPI1_SCK_GPIO_SD_B0_00,
+        DAISY_LPSPI1_SDI_GPIO_EMC_29,
+        DAISY_LPSPI1_SDI_GPIO_SD_B0_03,
+        DAISY_LPSPI1_SDO_GPIO_EMC_28,
+        DAISY_LPSPI1_SDO_GPIO_SD_B0_02,
+        DAISY_LPSPI2_PCS0_GPIO_SD_B1_06,
+        DAISY_LPSPI2_PCS0_GPIO_EMC_01,
+        DAISY_LPSPI2_SCK_GPIO_SD_B1_07,
+        DAISY_LPSPI2_SCK_GPIO_EMC_00,
+        DAISY_LPSPI2_SDI_GPIO_SD_B1_09,
+        DAISY_LPSPI2_SDI_GPIO_EMC_03,
+        DAISY_LPSPI2_SDO_GPIO_SD_B1_08,
+        DAISY_LPSPI2_SDO_GPIO_EMC_02,
+        DAISY_LPSPI3_PCS0_GPIO_AD_B0_03,
+        DAISY_LPSPI3_PCS0_GPIO_AD_B1_12,
+        DAISY_LPSPI3_SCK_GPIO_AD_B0_00,
+        DAISY_LPSPI3_SCK_GPIO_AD_B1_15,
+        DAISY_LPSPI3_SDI_GPIO_AD_B0_02,
+        DAISY_LPSPI3_SDI_GPIO_AD_B1_13,
+        DAISY_LPSPI3_SDO_GPIO_AD_B0_01,
+        DAISY_LPSPI3_SDO_GPIO_AD_B1_14,
+        DAISY_LPSPI4_PCS0_GPIO_B0_00,
+        DAISY_LPSPI4_PCS0_GPIO_B1_04,
+        DAISY_LPSPI4_SCK_GPIO_B0_03,
+        DAISY_LPSPI4_SCK_GPIO_B1_07,
+        DAISY_LPSPI4_SDI_GPIO_B0_01,
+        DAISY_LPSPI4_SDI_GPIO_B1_05,
+        DAISY_LPSPI4_SDO_GPIO_B0_02,
+        DAISY_LPSPI4_SDO_GPIO_B1_06,
+    ];
 }
 
 use daisy::*;
@@ -1,62 +1,134 @@
 //! UART pin implementations
 
-use super::pads::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_b1::*, gpio_emc::*};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::pads::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::pads::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-b0")]
+use super::pads::gpio_b0::*;
+#[cfg(feature = "imxrt1060-gpio-b1")]
+use super::pads::gpio_b1::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::pads::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+use super::pads::gpio_sd_b0::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+use super::pads::gpio_sd_b1::*;
 use crate::{
-    consts::*,
-    lpuart::{Pin, Rx, Tx},
+    lpuart::{Pin, Rts, Rx, Tx},
     Daisy,
 };
 
 //
 // UART1
 //
-uart!(module: U1, alt: 2, pad: GPIO_AD_B0_13, direction: Rx, daisy: None);
-uart!(module: U1, alt: 2, pad: GPIO_AD_B0_12, direction: Tx, daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+uart!(module: 1, alt: 2, pad: GPIO_AD_B0_13, direction: Rx, daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+uart!(module: 1, alt: 2, pad: GPIO_AD_B0_12, direction: Tx, daisy: None);
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+uart!(module: 1, alt: 2, pad: GPIO_AD_B0_15, direction: Rts, daisy: None);
 
 //
 // UART2
 //
-uart!(module: U2, alt: 2, pad: GPIO_AD_B1_03, direction: Rx, daisy: Some(DAISY_LPUART2_RX_GPIO_AD_B1_03));
-uart!(module: U2, alt: 2, pad: GPIO_AD_B1_02, direction: Tx, daisy: Some(DAISY_LPUART2_TX_GPIO_AD_B1_02));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 2, alt: 2, pad: GPIO_AD_B1_03, direction: Rx, daisy: Some(DAISY_LPUART2_RX_GPIO_AD_B1_03));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 2, alt: 2, pad: GPIO_AD_B1_02, direction: Tx, daisy: Some(DAISY_LPUART2_TX_GPIO_AD_B1_02));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 2, alt: 2, pad: GPIO_SD_B1_10, direction: Rx, daisy: Some(DAISY_LPUART2_RX_GPIO_SD_B1_10));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 2, alt: 2, pad: GPIO_SD_B1_11, direction: Tx, daisy: Some(DAISY_LPUART2_TX_GPIO_SD_B1_11));
 
 //
 // UART3
 //
-uart!(module: U3, alt: 2, pad: GPIO_AD_B1_07, direction: Rx, daisy: Some(DAISY_LPUART3_RX_GPIO_AD_B1_07));
-uart!(module: U3, alt: 2, pad: GPIO_AD_B1_06, direction: Tx, daisy: Some(DAISY_LPUART3_TX_GPIO_AD_B1_06));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 3, alt: 2, pad: GPIO_AD_B1_07, direction: Rx, daisy: Some(DAISY_LPUART3_RX_GPIO_AD_B1_07));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 3, alt: 2, pad: GPIO_AD_B1_06, direction: Tx, daisy: Some(DAISY_LPUART3_TX_GPIO_AD_B1_06));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 3, alt: 2, pad: GPIO_EMC_14, direction: Rx, daisy: Some(DAISY_LPUART3_RX_GPIO_EMC_14));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 3, alt: 2, pad: GPIO_EMC_13, direction: Tx, daisy: Some(DAISY_LPUART3_TX_GPIO_EMC_13));
+#[cfg(feature = "imxrt1060-gpio-b0")]
+uart!(module: 3, alt: 3, pad: GPIO_B0_09, direction: Rx, daisy: Some(DAISY_LPUART3_RX_GPIO_B0_09));
+#[cfg(feature = "imxrt1060-gpio-b0")]
+uart!(module: 3, alt: 3, pad: GPIO_B0_08, direction: Tx, daisy: Some(DAISY_LPUART3_TX_GPIO_B0_08));
 
 //
 // UART4
 //
-uart!(module: U4, alt: 2, pad: GPIO_B1_01, direction: Rx, daisy: Some(DAISY_LPUART4_RX_GPIO_B1_01));
-uart!(module: U4, alt: 2, pad: GPIO_B1_00, direction: Tx, daisy: Some(DAISY_LPUART4_TX_GPIO_B1_00));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+uart!(module: 4, alt: 2, pad: GPIO_B1_01, direction: Rx, daisy: Some(DAISY_LPUART4_RX_GPIO_B1_01));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+uart!(module: 4, alt: 2, pad: GPIO_B1_00, direction: Tx, daisy: Some(DAISY_LPUART4_TX_GPIO_B1_00));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 4, alt: 4, pad: GPIO_SD_B1_01, direction: Rx, daisy: Some(DAISY_LPUART4_RX_GPIO_SD_B1_01));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 4, alt: 4, pad: GPIO_SD_B1_00, direction: Tx, daisy: Some(DAISY_LPUART4_TX_GPIO_SD_B1_00));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 4, alt: 2, pad: GPIO_EMC_20, direction: Rx, daisy: Some(DAISY_LPUART4_RX_GPIO_EMC_20));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 4, alt: 2, pad: GPIO_EMC_19, direction: Tx, daisy: Some(DAISY_LPUART4_TX_GPIO_EMC_19));
 
 //
 // UART5
 //
-
-// TODO
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 5, alt: 2, pad: GPIO_EMC_24, direction: Rx, daisy: Some(DAISY_LPUART5_RX_GPIO_EMC_24));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 5, alt: 2, pad: GPIO_EMC_23, direction: Tx, daisy: Some(DAISY_LPUART5_TX_GPIO_EMC_23));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+uart!(module: 5, alt: 1, pad: GPIO_B1_13, direction: Rx, daisy: Some(DAISY_LPUART5_RX_GPIO_B1_13));
+#[cfg(feature = "imxrt1060-gpio-b1")]
+uart!(module: 5, alt: 1, pad: GPIO_B1_12, direction: Tx, daisy: Some(DAISY_LPUART5_TX_GPIO_B1_12));
 
 //
 // UART6
 //
-uart!(module: U6, alt: 2, pad: GPIO_AD_B0_03, direction: Rx, daisy: Some(DAISY_LPUART6_RX_GPIO_AD_B0_03));
-uart!(module: U6, alt: 2, pad: GPIO_AD_B0_02, direction: Tx, daisy: Some(DAISY_LPUART6_TX_GPIO_AD_B0_02));
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+uart!(module: 6, alt: 2, pad: GPIO_AD_B0_03, direction: Rx, daisy: Some(DAISY_LPUART6_RX_GPIO_AD_B0_03));
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+uart!(module: 6, alt: 2, pad: GPIO_AD_B0_02, direction: Tx, daisy: Some(DAISY_LPUART6_TX_GPIO_AD_B0_02));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 6, alt: 2, pad: GPIO_EMC_26, direction: Rx, daisy: Some(DAISY_LPUART6_RX_GPIO_EMC_26));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 6, alt: 2, pad: GPIO_EMC_25, direction: Tx, daisy: Some(DAISY_LPUART6_TX_GPIO_EMC_25));
 
 //
 // UART7
 //
-uart!(module: U7, alt: 2, pad: GPIO_EMC_32, direction: Rx, daisy: Some(DAISY_LPUART7_RX_GPIO_EMC_32));
-uart!(module: U7, alt: 2, pad: GPIO_EMC_31, direction: Tx, daisy: Some(DAISY_LPUART7_TX_GPIO_EMC_31));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 7, alt: 2, pad: GPIO_EMC_32, direction: Rx, daisy: Some(DAISY_LPUART7_RX_GPIO_EMC_32));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 7, alt: 2, pad: GPIO_EMC_31, direction: Tx, daisy: Some(DAISY_LPUART7_TX_GPIO_EMC_31));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 7, alt: 2, pad: GPIO_SD_B1_09, direction: Rx, daisy: Some(DAISY_LPUART7_RX_GPIO_SD_B1_09));
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+uart!(module: 7, alt: 2, pad: GPIO_SD_B1_08, direction: Tx, daisy: Some(DAISY_LPUART7_TX_GPIO_SD_B1_08));
 
 //
 // UART8
 //
-uart!(module: U8, alt: 2, pad: GPIO_AD_B1_11, direction: Rx, daisy: Some(DAISY_LPUART8_RX_GPIO_AD_B1_11));
-uart!(module: U8, alt: 2, pad: GPIO_AD_B1_10, direction: Tx, daisy: Some(DAISY_LPUART8_TX_GPIO_AD_B1_10));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 8, alt: 2, pad: GPIO_AD_B1_11, direction: Rx, daisy: Some(DAISY_LPUART8_RX_GPIO_AD_B1_11));
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+uart!(module: 8, alt: 2, pad: GPIO_AD_B1_10, direction: Tx, daisy: Some(DAISY_LPUART8_TX_GPIO_AD_B1_10));
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+uart!(module: 8, alt: 2, pad: GPIO_SD_B0_05, direction: Rx, daisy: Some(DAISY_LPUART8_RX_GPIO_SD_B0_05));
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+uart!(module: 8, alt: 2, pad: GPIO_SD_B0_04, direction: Tx, daisy: Some(DAISY_LPUART8_TX_GPIO_SD_B0_04));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 8, alt: 2, pad: GPIO_EMC_39, direction: Rx, daisy: Some(DAISY_LPUART8_RX_GPIO_EMC_39));
+#[cfg(feature = "imxrt1060-gpio-emc")]
+uart!(module: 8, alt: 2, pad: GPIO_EMC_38, direction: Tx, daisy: Some(DAISY_LPUART8_TX_GPIO_EMC_38));
 
 /// Auto-generated Daisy constants
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
     use super::Daisy;
 
@@ -96,6 +168,46 @@ mod daisy {
     pub const DAISY_LPUART8_TX_GPIO_SD_B0_04: Daisy = Daisy::new(0x401f8564 as *mut u32, 0);
     pub const DAISY_LPUART8_TX_GPIO_AD_B1_10: Daisy = Daisy::new(0x401f8564 as *mut u32, 1);
     pub const DAISY_LPUART8_TX_GPIO_EMC_38: Daisy = Daisy::new(0x401f8564 as *mut u32, 2);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPUART2_RX_GPIO_SD_B1_10,
+        DAISY_LPUART2_RX_GPIO_AD_B1_03,
+        DAISY_LPUART2_TX_GPIO_SD_B1_11,
+        DAISY_LPUART2_TX_GPIO_AD_B1_02,
+        DAISY_LPUART3_CT_GPIOS_B_EMC_15,
+        DAISY_LPUART3_CT_GPIOS_B_AD_B1_04,
+        DAISY_LPUART3_RX_GPIO_AD_B1_07,
+        DAISY_LPUART3_RX_GPIO_EMC_14,
+        DAISY_LPUART3_RX_GPIO_B0_09,
+        DAISY_LPUART3_TX_GPIO_AD_B1_06,
+        DAISY_LPUART3_TX_GPIO_EMC_13,
+        DAISY_LPUART3_TX_GPIO_B0_08,
+        DAISY_LPUART4_RX_GPIO_SD_B1_01,
+        DAISY_LPUART4_RX_GPIO_EMC_20,
+        DAISY_LPUART4_RX_GPIO_B1_01,
+        DAISY_LPUART4_TX_GPIO_SD_B1_00,
+        DAISY_LPUART4_TX_GPIO_EMC_19,
+        DAISY_LPUART4_TX_GPIO_B1_00,
+        DAISY_LPUART5_RX_GPIO_EMC_24,
+        DAISY_LPUART5_RX_GPIO_B1_13,
+        DAISY_LPUART5_TX_GPIO_EMC_23,
+        DAISY_LPUART5_TX_GPIO_B1_12,
+        DAISY_LPUART6_RX_GPIO_EMC_26,
+        DAISY_LPUART6_RX_GPIO_AD_B0_03,
+        DAISY_LPUART6_TX_GPIO_EMC_25,
+        DAISY_LPUART6_TX_GPIO_AD_B0_02,
+        DAISY_LPUART7_RX_GPIO_SD_B1_09,
+        DAISY_LPUART7_RX_GPIO_EMC_32,
+        DAISY_LPUART7_TX_GPIO_SD_B1_08,
+        DAISY_LPUART7_TX_GPIO_EMC_31,
+        DAISY_LPUART8_RX_GPIO_SD_B0_05,
+        DAISY_LPUART8_RX_GPIO_AD_B1_11,
+        DAISY_LPUART8_RX_GPIO_EMC_39,
+        DAISY_LPUART8_TX_GPIO_SD_B0_04,
+        DAISY_LPUART8_TX_GPIO_AD_B1_10,
+        DAISY_LPUART8_TX_GPIO_EMC_38,
+    ];
 }
 
 use daisy::*;
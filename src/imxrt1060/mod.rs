@@ -4,12 +4,193 @@
 //! functions are tagged with `imxrt-iomuxc` traits.
 
 mod adc;
+mod enet;
 mod flexpwm;
+mod flexspi;
 mod lpi2c;
 mod lpspi;
 mod lpuart;
 mod sai;
 mod usdhc;
 
+pub mod defaults;
+pub mod gpr;
 mod pads;
 pub use pads::*;
+mod register_map;
+pub use register_map::REGISTER_MAP;
+mod signals;
+pub use signals::DATABASE;
+pub mod supply;
+/// The length of a [`register_map::save()`](crate::register_map::save) snapshot
+pub const SNAPSHOT_LEN: usize = crate::register_map::snapshot_len(REGISTER_MAP, SNAPSHOT_GPRS);
+/// The IOMUXC_GPR registers that a [`register_map::save()`](crate::register_map::save)
+/// snapshot includes
+///
+/// GPR1, with the SAI MCLK and ENET clock pad fields, and GPR26 through GPR29,
+/// which select the fast GPIOs.
+pub const SNAPSHOT_GPRS: &[u32] = gpr::SNAPSHOT;
+
+/// The FLEXSPI port A pads that the boot ROM reads the boot flash from
+///
+/// GPIO_SD_B1_05 through GPIO_SD_B1_11. See the [`xip`](crate::xip) module to guard them.
+pub const BOOT_FLASH: &[crate::PadLocation] = &[
+    // GPIO_SD_B1_05
+    crate::PadLocation {
+        mux: 0x401F_81E8,
+        pad: 0x401F_83D8,
+    },
+    // GPIO_SD_B1_06
+    crate::PadLocation {
+        mux: 0x401F_81EC,
+        pad: 0x401F_83DC,
+    },
+    // GPIO_SD_B1_07
+    crate::PadLocation {
+        mux: 0x401F_81F0,
+        pad: 0x401F_83E0,
+    },
+    // GPIO_SD_B1_08
+    crate::PadLocation {
+        mux: 0x401F_81F4,
+        pad: 0x401F_83E4,
+    },
+    // GPIO_SD_B1_09
+    crate::PadLocation {
+        mux: 0x401F_81F8,
+        pad: 0x401F_83E8,
+    },
+    // GPIO_SD_B1_10
+    crate::PadLocation {
+        mux: 0x401F_81FC,
+        pad: 0x401F_83EC,
+    },
+    // GPIO_SD_B1_11
+    crate::PadLocation {
+        mux: 0x401F_8200,
+        pad: 0x401F_83F0,
+    },
+];
+
+/// The FLEXSPI memory-mapped address range
+pub const FLEXSPI_MEMORY: core::ops::Range<u32> = 0x6000_0000..0x7000_0000;
+
+/// The chip family, in the upper half of the USB_ANALOG DIGPROG register
+///
+/// The 1060 and 1064 report `0x006C`, and the 1050 reports `0x006A`. The lower half is the silicon revision.
+pub const DIGPROG_FAMILY: u32 = 0x006C;
+
+/// In debug builds, panic unless the chip is an i.MX RT 1060
+///
+/// Reads the USB_ANALOG DIGPROG register, and compares its chip family to
+/// [`DIGPROG_FAMILY`]. Call this early, before you prepare any pins, to catch a
+/// 1060 build that's running on another chip, with other pads. Release builds
+/// don't read the register.
+///
+/// # Safety
+///
+/// Reads a processor register. Only call this on an i.MX RT chip.
+pub unsafe fn debug_check_chip() {
+    crate::debug_check_chip(crate::DIGPROG, "1060", DIGPROG_FAMILY)
+}
+
+/// Route a pad to a peripheral input
+///
+/// Writes the daisy value that selects `pad` for `input`, using the chip's
+/// [`REGISTER_MAP`]. Fails, without writing, if the pad can't feed the input.
+/// See [`register_map::select_input()`](crate::register_map::select_input).
+pub fn select_input(
+    input: crate::register_map::InputSignal,
+    pad: &crate::ErasedPad,
+) -> Result<(), crate::register_map::RoutingError> {
+    // Safety: the map describes this chip, and the erased pad proves that
+    // we own the pad. The daisy register is shared, like it is for the
+    // peripheral prepare() functions.
+    unsafe { crate::register_map::select_input(REGISTER_MAP, input, pad) }
+}
+
+/// Daisy values for the chip's select inputs
+///
+/// Each peripheral module has a constant for every value of the peripheral's
+/// select input registers. The pin implementations use these values, so you
+/// can audit, or perform, a pin's input selection yourself. The `enet` module
+/// only has the values that select the GPIO_B1 RMII pads.
+///
+/// To wire a peripheral that has no pin traits, generate its constants with
+/// `daisy.py`, like `daisy.py path/to/imxrt.svd CSI`.
+pub mod daisy {
+    pub use super::enet::daisy as enet;
+    pub use super::flexspi::daisy as flexspi;
+    pub use super::lpi2c::daisy as lpi2c;
+    pub use super::lpspi::daisy as lpspi;
+    pub use super::lpuart::daisy as lpuart;
+    pub use super::sai::daisy as sai;
+    pub use super::usdhc::daisy as usdhc;
+}
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+mod ccm {
+    pub use crate::ccm::{Pin, RefClk24M, RefClk32K};
+
+    impl Pin for super::pads::gpio_ad_b0::GPIO_AD_B0_00 {
+        const ALT: u32 = 2;
+        type Function = RefClk32K;
+    }
+    impl Pin for super::pads::gpio_ad_b0::GPIO_AD_B0_01 {
+        const ALT: u32 = 2;
+        type Function = RefClk24M;
+    }
+    impl Pin for super::pads::gpio_ad_b0::GPIO_AD_B0_03 {
+        const ALT: u32 = 6;
+        type Function = RefClk24M;
+    }
+}
+
+/// Check the invariants of all pad and daisy definitions
+///
+/// See the [`check`](crate::check) module for more information.
+#[cfg(feature = "self-check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-check")))]
+pub fn self_check() -> Result<(), crate::check::Violation> {
+    const IOMUXC: core::ops::Range<u32> = 0x401F_8000..0x401F_C000;
+    const IOMUXC_SNVS: core::ops::Range<u32> = 0x400A_8000..0x400A_C000;
+    // Safety: the erased pads never access their registers.
+    let pads = unsafe { Pads::new() }.erase();
+    crate::check::pads(
+        &[
+            #[cfg(feature = "imxrt1060-gpio-emc")]
+            &pads.gpio_emc,
+            #[cfg(feature = "imxrt1060-gpio-ad-b0")]
+            &pads.gpio_ad_b0,
+            #[cfg(feature = "imxrt1060-gpio-ad-b1")]
+            &pads.gpio_ad_b1,
+            #[cfg(feature = "imxrt1060-gpio-b0")]
+            &pads.gpio_b0,
+            #[cfg(feature = "imxrt1060-gpio-b1")]
+            &pads.gpio_b1,
+            #[cfg(feature = "imxrt1060-gpio-sd-b0")]
+            &pads.gpio_sd_b0,
+            #[cfg(feature = "imxrt1060-gpio-sd-b1")]
+            &pads.gpio_sd_b1,
+            #[cfg(feature = "imxrt1060-gpio-spi-b0")]
+            &pads.gpio_spi_b0,
+            #[cfg(feature = "imxrt1060-gpio-spi-b1")]
+            &pads.gpio_spi_b1,
+            #[cfg(feature = "imxrt1060-snvs")]
+            &pads.snvs,
+        ],
+        &[IOMUXC, IOMUXC_SNVS],
+    )?;
+    crate::check::daisies(
+        &[
+            enet::daisy::ALL,
+            flexspi::daisy::ALL,
+            lpi2c::daisy::ALL,
+            lpspi::daisy::ALL,
+            lpuart::daisy::ALL,
+            sai::daisy::ALL,
+            usdhc::daisy::ALL,
+        ],
+        &[IOMUXC],
+    )
+}
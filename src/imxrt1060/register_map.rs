@@ -0,0 +1,1763 @@
+// Generated by registermap.py
+use crate::register_map::{DaisyEntry, PadRegisters};
+
+/// All pads, and their registers
+///
+/// Each entry lists the daisy values that select the pad. The table doesn't
+/// know the pads' reset values.
+pub const REGISTER_MAP: &[PadRegisters] = &[
+    PadRegisters {
+        name: "GPIO_EMC_00",
+        mux: 0x401f8014,
+        pad: 0x401f8204,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8500,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_01",
+        mux: 0x401f8018,
+        pad: 0x401f8208,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84fc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_02",
+        mux: 0x401f801c,
+        pad: 0x401f820c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8508,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_03",
+        mux: 0x401f8020,
+        pad: 0x401f8210,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8504,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_04",
+        mux: 0x401f8024,
+        pad: 0x401f8214,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_05",
+        mux: 0x401f8028,
+        pad: 0x401f8218,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85c4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_06",
+        mux: 0x401f802c,
+        pad: 0x401f821c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85c0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_07",
+        mux: 0x401f8030,
+        pad: 0x401f8220,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_08",
+        mux: 0x401f8034,
+        pad: 0x401f8224,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_09",
+        mux: 0x401f8038,
+        pad: 0x401f8228,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85bc,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_10",
+        mux: 0x401f803c,
+        pad: 0x401f822c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_11",
+        mux: 0x401f8040,
+        pad: 0x401f8230,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84e8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_12",
+        mux: 0x401f8044,
+        pad: 0x401f8234,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8754,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f84e4,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_13",
+        mux: 0x401f8048,
+        pad: 0x401f8238,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8740,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f853c,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_14",
+        mux: 0x401f804c,
+        pad: 0x401f823c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8744,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8538,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_15",
+        mux: 0x401f8050,
+        pad: 0x401f8240,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8748,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8534,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_16",
+        mux: 0x401f8054,
+        pad: 0x401f8244,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f874c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_17",
+        mux: 0x401f8058,
+        pad: 0x401f8248,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_18",
+        mux: 0x401f805c,
+        pad: 0x401f824c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_19",
+        mux: 0x401f8060,
+        pad: 0x401f8250,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8544,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_20",
+        mux: 0x401f8064,
+        pad: 0x401f8254,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8540,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_21",
+        mux: 0x401f8068,
+        pad: 0x401f8258,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84e0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_22",
+        mux: 0x401f806c,
+        pad: 0x401f825c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84dc,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_23",
+        mux: 0x401f8070,
+        pad: 0x401f8260,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f872c,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f854c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_24",
+        mux: 0x401f8074,
+        pad: 0x401f8264,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8548,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_25",
+        mux: 0x401f8078,
+        pad: 0x401f8268,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8750,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8554,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_26",
+        mux: 0x401f807c,
+        pad: 0x401f826c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8730,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8550,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_27",
+        mux: 0x401f8080,
+        pad: 0x401f8270,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8734,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84f0,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_28",
+        mux: 0x401f8084,
+        pad: 0x401f8274,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8738,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84f8,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_29",
+        mux: 0x401f8088,
+        pad: 0x401f8278,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f873c,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84f4,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_30",
+        mux: 0x401f808c,
+        pad: 0x401f827c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84ec,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_31",
+        mux: 0x401f8090,
+        pad: 0x401f8280,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f855c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_32",
+        mux: 0x401f8094,
+        pad: 0x401f8284,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8558,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_33",
+        mux: 0x401f8098,
+        pad: 0x401f8288,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8778,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_34",
+        mux: 0x401f809c,
+        pad: 0x401f828c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f877c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_35",
+        mux: 0x401f80a0,
+        pad: 0x401f8290,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8774,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_36",
+        mux: 0x401f80a4,
+        pad: 0x401f8294,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_37",
+        mux: 0x401f80a8,
+        pad: 0x401f8298,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8770,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_38",
+        mux: 0x401f80ac,
+        pad: 0x401f829c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8564,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8780,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_39",
+        mux: 0x401f80b0,
+        pad: 0x401f82a0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8560,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8784,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_40",
+        mux: 0x401f80b4,
+        pad: 0x401f82a4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_41",
+        mux: 0x401f80b8,
+        pad: 0x401f82a8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_00",
+        mux: 0x401f80bc,
+        pad: 0x401f82ac,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8510,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_01",
+        mux: 0x401f80c0,
+        pad: 0x401f82b0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8518,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_02",
+        mux: 0x401f80c4,
+        pad: 0x401f82b4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8514,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8554,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_03",
+        mux: 0x401f80c8,
+        pad: 0x401f82b8,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f850c,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8550,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_04",
+        mux: 0x401f80cc,
+        pad: 0x401f82bc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85c4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_05",
+        mux: 0x401f80d0,
+        pad: 0x401f82c0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85c0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_06",
+        mux: 0x401f80d4,
+        pad: 0x401f82c4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_07",
+        mux: 0x401f80d8,
+        pad: 0x401f82c8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85bc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_08",
+        mux: 0x401f80dc,
+        pad: 0x401f82cc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_09",
+        mux: 0x401f80e0,
+        pad: 0x401f82d0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_10",
+        mux: 0x401f80e4,
+        pad: 0x401f82d4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85b0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_11",
+        mux: 0x401f80e8,
+        pad: 0x401f82d8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_12",
+        mux: 0x401f80ec,
+        pad: 0x401f82dc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84e4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_13",
+        mux: 0x401f80f0,
+        pad: 0x401f82e0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84e8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_14",
+        mux: 0x401f80f4,
+        pad: 0x401f82e4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B0_15",
+        mux: 0x401f80f8,
+        pad: 0x401f82e8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_00",
+        mux: 0x401f80fc,
+        pad: 0x401f82ec,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84cc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_01",
+        mux: 0x401f8100,
+        pad: 0x401f82f0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84d0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_02",
+        mux: 0x401f8104,
+        pad: 0x401f82f4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8530,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_03",
+        mux: 0x401f8108,
+        pad: 0x401f82f8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f852c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_04",
+        mux: 0x401f810c,
+        pad: 0x401f82fc,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84c4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8534,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_05",
+        mux: 0x401f8110,
+        pad: 0x401f8300,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84c0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_06",
+        mux: 0x401f8114,
+        pad: 0x401f8304,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84bc,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84e0,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f853c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_07",
+        mux: 0x401f8118,
+        pad: 0x401f8308,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84dc,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8538,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_08",
+        mux: 0x401f811c,
+        pad: 0x401f830c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_09",
+        mux: 0x401f8120,
+        pad: 0x401f8310,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84a4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f858c,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_10",
+        mux: 0x401f8124,
+        pad: 0x401f8314,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8564,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f85a4,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_11",
+        mux: 0x401f8128,
+        pad: 0x401f8318,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b0,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8560,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8590,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_12",
+        mux: 0x401f812c,
+        pad: 0x401f831c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84ac,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f850c,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8594,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_13",
+        mux: 0x401f8130,
+        pad: 0x401f8320,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84a8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8514,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_14",
+        mux: 0x401f8134,
+        pad: 0x401f8324,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84c8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8518,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f85a8,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_B1_15",
+        mux: 0x401f8138,
+        pad: 0x401f8328,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8510,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f85ac,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_B0_00",
+        mux: 0x401f813c,
+        pad: 0x401f832c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f851c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_01",
+        mux: 0x401f8140,
+        pad: 0x401f8330,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8524,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_02",
+        mux: 0x401f8144,
+        pad: 0x401f8334,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8528,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_03",
+        mux: 0x401f8148,
+        pad: 0x401f8338,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8520,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_04",
+        mux: 0x401f814c,
+        pad: 0x401f833c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84d4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_05",
+        mux: 0x401f8150,
+        pad: 0x401f8340,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84d8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_06",
+        mux: 0x401f8154,
+        pad: 0x401f8344,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_B0_07",
+        mux: 0x401f8158,
+        pad: 0x401f8348,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_B0_08",
+        mux: 0x401f815c,
+        pad: 0x401f834c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f853c,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_09",
+        mux: 0x401f8160,
+        pad: 0x401f8350,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8538,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_10",
+        mux: 0x401f8164,
+        pad: 0x401f8354,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8598,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_11",
+        mux: 0x401f8168,
+        pad: 0x401f8358,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f859c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_12",
+        mux: 0x401f816c,
+        pad: 0x401f835c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85a0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_13",
+        mux: 0x401f8170,
+        pad: 0x401f8360,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f858c,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_14",
+        mux: 0x401f8174,
+        pad: 0x401f8364,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85a4,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B0_15",
+        mux: 0x401f8178,
+        pad: 0x401f8368,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8590,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_00",
+        mux: 0x401f817c,
+        pad: 0x401f836c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8544,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x401f8594,
+                value: 2,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_B1_01",
+        mux: 0x401f8180,
+        pad: 0x401f8370,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8540,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_02",
+        mux: 0x401f8184,
+        pad: 0x401f8374,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85a8,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_03",
+        mux: 0x401f8188,
+        pad: 0x401f8378,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f85ac,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_04",
+        mux: 0x401f818c,
+        pad: 0x401f837c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8434,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f851c,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_B1_05",
+        mux: 0x401f8190,
+        pad: 0x401f8380,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f8438,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8524,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_B1_06",
+        mux: 0x401f8194,
+        pad: 0x401f8384,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f843c,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f8528,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_B1_07",
+        mux: 0x401f8198,
+        pad: 0x401f8388,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8520,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_08",
+        mux: 0x401f819c,
+        pad: 0x401f838c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_B1_09",
+        mux: 0x401f81a0,
+        pad: 0x401f8390,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_B1_10",
+        mux: 0x401f81a4,
+        pad: 0x401f8394,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f842c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_11",
+        mux: 0x401f81a8,
+        pad: 0x401f8398,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8440,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_12",
+        mux: 0x401f81ac,
+        pad: 0x401f839c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f854c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_13",
+        mux: 0x401f81b0,
+        pad: 0x401f83a0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8548,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_B1_14",
+        mux: 0x401f81b4,
+        pad: 0x401f83a4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_B1_15",
+        mux: 0x401f81b8,
+        pad: 0x401f83a8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8430,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_00",
+        mux: 0x401f81bc,
+        pad: 0x401f83ac,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84dc,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84f0,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_01",
+        mux: 0x401f81c0,
+        pad: 0x401f83b0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84e0,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x401f84ec,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_02",
+        mux: 0x401f81c4,
+        pad: 0x401f83b4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84f8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_03",
+        mux: 0x401f81c8,
+        pad: 0x401f83b8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f84f4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_04",
+        mux: 0x401f81cc,
+        pad: 0x401f83bc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8564,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B0_05",
+        mux: 0x401f81d0,
+        pad: 0x401f83c0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8560,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_00",
+        mux: 0x401f81d4,
+        pad: 0x401f83c4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84c4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8544,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8598,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8778,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_01",
+        mux: 0x401f81d8,
+        pad: 0x401f83c8,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84c0,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8540,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f859c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_02",
+        mux: 0x401f81dc,
+        pad: 0x401f83cc,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84bc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f85a0,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8784,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_03",
+        mux: 0x401f81e0,
+        pad: 0x401f83d0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f858c,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8780,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_04",
+        mux: 0x401f81e4,
+        pad: 0x401f83d4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84cc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f85a4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8770,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_05",
+        mux: 0x401f81e8,
+        pad: 0x401f83d8,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84a4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f84d0,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8590,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f877c,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_06",
+        mux: 0x401f81ec,
+        pad: 0x401f83dc,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84fc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8594,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8774,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_07",
+        mux: 0x401f81f0,
+        pad: 0x401f83e0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84c8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8500,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_08",
+        mux: 0x401f81f4,
+        pad: 0x401f83e4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84a8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8508,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f855c,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f85a8,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_09",
+        mux: 0x401f81f8,
+        pad: 0x401f83e8,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84ac,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8504,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8558,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f85ac,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_10",
+        mux: 0x401f81fc,
+        pad: 0x401f83ec,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b0,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f84d8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f852c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_11",
+        mux: 0x401f8200,
+        pad: 0x401f83f0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x401f84b4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f84d4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x401f8530,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_00",
+        mux: 0x401f865c,
+        pad: 0x401f86b4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_01",
+        mux: 0x401f8660,
+        pad: 0x401f86b8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8754,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_02",
+        mux: 0x401f8664,
+        pad: 0x401f86bc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8730,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_03",
+        mux: 0x401f8668,
+        pad: 0x401f86c0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8748,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_04",
+        mux: 0x401f866c,
+        pad: 0x401f86c4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f874c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_05",
+        mux: 0x401f8670,
+        pad: 0x401f86c8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_06",
+        mux: 0x401f8674,
+        pad: 0x401f86cc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8738,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_07",
+        mux: 0x401f8678,
+        pad: 0x401f86d0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8744,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_08",
+        mux: 0x401f867c,
+        pad: 0x401f86d4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8750,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_09",
+        mux: 0x401f8680,
+        pad: 0x401f86d8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f872c,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_10",
+        mux: 0x401f8684,
+        pad: 0x401f86dc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f873c,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_11",
+        mux: 0x401f8688,
+        pad: 0x401f86e0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8740,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_12",
+        mux: 0x401f868c,
+        pad: 0x401f86e4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8734,
+            value: 2,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B0_13",
+        mux: 0x401f8690,
+        pad: 0x401f86e8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_00",
+        mux: 0x401f8694,
+        pad: 0x401f86ec,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f872c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_01",
+        mux: 0x401f8698,
+        pad: 0x401f86f0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f873c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_02",
+        mux: 0x401f869c,
+        pad: 0x401f86f4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8738,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_03",
+        mux: 0x401f86a0,
+        pad: 0x401f86f8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8734,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_04",
+        mux: 0x401f86a4,
+        pad: 0x401f86fc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8730,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_05",
+        mux: 0x401f86a8,
+        pad: 0x401f8700,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401f8750,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_06",
+        mux: 0x401f86ac,
+        pad: 0x401f8704,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SPI_B1_07",
+        mux: 0x401f86b0,
+        pad: 0x401f8708,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "WAKEUP",
+        mux: 0x400a8000,
+        pad: 0x400a8018,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "PMIC_ON_REQ",
+        mux: 0x400a8004,
+        pad: 0x400a801c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "PMIC_STBY_REQ",
+        mux: 0x400a8008,
+        pad: 0x400a8020,
+        reset: None,
+        daisies: &[],
+    },
+];
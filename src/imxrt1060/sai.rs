@@ -1,6 +1,19 @@
 //! SAI / I2S pin implementation
 
-use super::{gpio_ad_b0::*, gpio_ad_b1::*, gpio_b0::*, gpio_b1::*, gpio_emc::*, gpio_sd_b1::*};
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+use super::gpio_ad_b0::*;
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+use super::gpio_ad_b1::*;
+#[cfg(feature = "imxrt1060-gpio-b0")]
+use super::gpio_b0::*;
+#[cfg(feature = "imxrt1060-gpio-b1")]
+use super::gpio_b1::*;
+#[cfg(feature = "imxrt1060-gpio-emc")]
+use super::gpio_emc::*;
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+use super::gpio_sd_b1::*;
 use crate::{consts::*, sai::*, Daisy};
 
 /// SAI1 multiplexed TX / RX pin
@@ -18,26 +31,26 @@ pub enum TxData3RxData1 {}
 
 impl Signal for TxData1RxData3 {}
 impl TxDataSignal for TxData1RxData3 {
-    type Index = U1;
+    type Index = Const<1>;
 }
 impl RxDataSignal for TxData1RxData3 {
-    type Index = U3;
+    type Index = Const<3>;
 }
 
 impl Signal for TxData2RxData2 {}
 impl TxDataSignal for TxData2RxData2 {
-    type Index = U2;
+    type Index = Const<2>;
 }
 impl RxDataSignal for TxData2RxData2 {
-    type Index = U2;
+    type Index = Const<2>;
 }
 
 impl Signal for TxData3RxData1 {}
 impl TxDataSignal for TxData3RxData1 {
-    type Index = U3;
+    type Index = Const<3>;
 }
 impl RxDataSignal for TxData3RxData1 {
-    type Index = U1;
+    type Index = Const<1>;
 }
 
 impl private::Sealed for TxData1RxData3 {}
@@ -48,94 +61,149 @@ impl private::Sealed for TxData3RxData1 {}
 // SAI1
 //
 
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_08, signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_SD_B1_08) }
-sai! { module: U1, alt: 3, pad: GPIO_B1_02,    signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_B1_02) }
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_14, signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_AD_B1_14) }
-
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_15, signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_AD_B1_15) }
-sai! { module: U1, alt: 3, pad: GPIO_B1_03,    signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_B1_03) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_09, signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_SD_B1_09) }
-
-sai! { module: U1, alt: 3, pad: GPIO_B0_13,    signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_B0_13) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_03, signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_SD_B1_03) }
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_09, signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_AD_B1_09) }
-
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_11, signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_AD_B1_11) }
-sai! { module: U1, alt: 3, pad: GPIO_B0_15,    signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_B0_15) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_05, signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_SD_B1_05) }
-
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_10, signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_AD_B1_10) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_04, signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_SD_B1_04) }
-sai! { module: U1, alt: 3, pad: GPIO_B0_14,    signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_B0_14) }
-
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_13, signal: TxData, daisy: None }
-sai! { module: U1, alt: 3, pad: GPIO_B1_01,    signal: TxData, daisy: None }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_07, signal: TxData, daisy: None }
-
-sai! { module: U1, alt: 3, pad: GPIO_B1_00,    signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_B1_00) }
-sai! { module: U1, alt: 3, pad: GPIO_AD_B1_12, signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_AD_B1_12) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_06, signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_SD_B1_06) }
-
-sai! { module: U1, alt: 3, pad: GPIO_B0_12,    signal: TxData1RxData3, daisy: Some(DAISY_SAI1_RX_DATA3_GPIO_B0_12) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_02, signal: TxData1RxData3, daisy: Some(DAISY_SAI1_RX_DATA3_GPIO_SD_B1_02) }
-
-sai! { module: U1, alt: 3, pad: GPIO_B0_11,    signal: TxData2RxData2, daisy: Some(DAISY_SAI1_RX_DATA2_GPIO_B0_11) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_01, signal: TxData2RxData2, daisy: Some(DAISY_SAI1_RX_DATA2_GPIO_SD_B1_01) }
-
-sai! { module: U1, alt: 3, pad: GPIO_B0_10,    signal: TxData3RxData1, daisy: Some(DAISY_SAI1_RX_DATA1_GPIO_B0_10) }
-sai! { module: U1, alt: 3, pad: GPIO_SD_B1_00, signal: TxData3RxData1, daisy: Some(DAISY_SAI1_RX_DATA1_GPIO_SD_B1_00) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_08, signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_SD_B1_08) }
+#[cfg(feature = "imxrt1060-gpio-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_B1_02,    signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_B1_02) }
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_14, signal: TxBclk, daisy: Some(DAISY_SAI1_TX_BCLK_GPIO_AD_B1_14) }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_15, signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_AD_B1_15) }
+#[cfg(feature = "imxrt1060-gpio-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_B1_03,    signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_B1_03) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_09, signal: TxSync, daisy: Some(DAISY_SAI1_TX_SYNC_GPIO_SD_B1_09) }
+
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_13,    signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_B0_13) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_03, signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_SD_B1_03) }
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_09, signal: Mclk, daisy: Some(DAISY_SAI1_MCLK2_GPIO_AD_B1_09) }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_11, signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_AD_B1_11) }
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_15,    signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_B0_15) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_05, signal: RxBclk, daisy: Some(DAISY_SAI1_RX_BCLK_GPIO_SD_B1_05) }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_10, signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_AD_B1_10) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_04, signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_SD_B1_04) }
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_14,    signal: RxSync, daisy: Some(DAISY_SAI1_RX_SYNC_GPIO_B0_14) }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_13, signal: TxData, daisy: None }
+#[cfg(feature = "imxrt1060-gpio-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_B1_01,    signal: TxData, daisy: None }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_07, signal: TxData, daisy: None }
+
+#[cfg(feature = "imxrt1060-gpio-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_B1_00,    signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_B1_00) }
+#[cfg(feature = "imxrt1060-gpio-ad-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_AD_B1_12, signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_AD_B1_12) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_06, signal: RxData, daisy: Some(DAISY_SAI1_RX_DATA0_GPIO_SD_B1_06) }
+
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_12,    signal: TxData1RxData3, daisy: Some(DAISY_SAI1_RX_DATA3_GPIO_B0_12) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_02, signal: TxData1RxData3, daisy: Some(DAISY_SAI1_RX_DATA3_GPIO_SD_B1_02) }
+
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_11,    signal: TxData2RxData2, daisy: Some(DAISY_SAI1_RX_DATA2_GPIO_B0_11) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_01, signal: TxData2RxData2, daisy: Some(DAISY_SAI1_RX_DATA2_GPIO_SD_B1_01) }
+
+#[cfg(feature = "imxrt1060-gpio-b0")]
+sai! { module: 1, alt: 3, pad: GPIO_B0_10,    signal: TxData3RxData1, daisy: Some(DAISY_SAI1_RX_DATA1_GPIO_B0_10) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 1, alt: 3, pad: GPIO_SD_B1_00, signal: TxData3RxData1, daisy: Some(DAISY_SAI1_RX_DATA1_GPIO_SD_B1_00) }
 
 //
 // SAI2
 //
 
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_05, signal: TxBclk, daisy: Some(DAISY_SAI2_TX_BCLK_GPIO_AD_B0_05) }
-sai! { module: U2, alt: 2, pad: GPIO_EMC_06,   signal: TxBclk, daisy: Some(DAISY_SAI2_TX_BCLK_GPIO_EMC_06) }
-
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_04, signal: TxSync, daisy: Some(DAISY_SAI2_TX_SYNC_GPIO_AD_B0_04) }
-sai! { module: U2, alt: 2, pad: GPIO_EMC_05,   signal: TxSync, daisy: Some(DAISY_SAI2_TX_SYNC_GPIO_EMC_05) }
-
-sai! { module: U2, alt: 2, pad: GPIO_EMC_10,   signal: RxBclk, daisy: Some(DAISY_SAI2_RX_BCLK_GPIO_EMC_10) }
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_06, signal: RxBclk, daisy: Some(DAISY_SAI2_RX_BCLK_GPIO_AD_B0_06) }
-
-sai! { module: U2, alt: 2, pad: GPIO_EMC_09,   signal: RxSync, daisy: Some(DAISY_SAI2_RX_SYNC_GPIO_EMC_09) }
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_07, signal: RxSync, daisy: Some(DAISY_SAI2_RX_SYNC_GPIO_AD_B0_07) }
-
-sai! { module: U2, alt: 2, pad: GPIO_EMC_07,   signal: Mclk, daisy: Some(DAISY_SAI2_MCLK2_GPIO_EMC_07) }
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_10, signal: Mclk, daisy: Some(DAISY_SAI2_MCLK2_GPIO_AD_B0_10) }
-
-sai! { module: U2, alt: 2, pad: GPIO_EMC_04,   signal: TxData, daisy: None }
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_09, signal: TxData, daisy: None }
-
-sai! { module: U2, alt: 3, pad: GPIO_AD_B0_08, signal: RxData, daisy: Some(DAISY_SAI2_RX_DATA0_GPIO_AD_B0_08) }
-sai! { module: U2, alt: 2, pad: GPIO_EMC_08,   signal: RxData, daisy: Some(DAISY_SAI2_RX_DATA0_GPIO_EMC_08) }
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_05, signal: TxBclk, daisy: Some(DAISY_SAI2_TX_BCLK_GPIO_AD_B0_05) }
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_06,   signal: TxBclk, daisy: Some(DAISY_SAI2_TX_BCLK_GPIO_EMC_06) }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_04, signal: TxSync, daisy: Some(DAISY_SAI2_TX_SYNC_GPIO_AD_B0_04) }
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_05,   signal: TxSync, daisy: Some(DAISY_SAI2_TX_SYNC_GPIO_EMC_05) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_10,   signal: RxBclk, daisy: Some(DAISY_SAI2_RX_BCLK_GPIO_EMC_10) }
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_06, signal: RxBclk, daisy: Some(DAISY_SAI2_RX_BCLK_GPIO_AD_B0_06) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_09,   signal: RxSync, daisy: Some(DAISY_SAI2_RX_SYNC_GPIO_EMC_09) }
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_07, signal: RxSync, daisy: Some(DAISY_SAI2_RX_SYNC_GPIO_AD_B0_07) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_07,   signal: Mclk, daisy: Some(DAISY_SAI2_MCLK2_GPIO_EMC_07) }
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_10, signal: Mclk, daisy: Some(DAISY_SAI2_MCLK2_GPIO_AD_B0_10) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_04,   signal: TxData, daisy: None }
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_09, signal: TxData, daisy: None }
+
+#[cfg(feature = "imxrt1060-gpio-ad-b0")]
+sai! { module: 2, alt: 3, pad: GPIO_AD_B0_08, signal: RxData, daisy: Some(DAISY_SAI2_RX_DATA0_GPIO_AD_B0_08) }
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 2, alt: 2, pad: GPIO_EMC_08,   signal: RxData, daisy: Some(DAISY_SAI2_RX_DATA0_GPIO_EMC_08) }
 
 //
 // SAI3
 //
 
-sai! { module: U3, alt: 3, pad: GPIO_EMC_38,   signal: TxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_EMC_38) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_03, signal: TxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_SD_B1_03) }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_39,   signal: TxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_EMC_39) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_02, signal: TxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_SD_B1_02) }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_35,   signal: RxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_EMC_35) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_06, signal: RxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_SD_B1_06) }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_34,   signal: RxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_EMC_34) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_05, signal: RxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_SD_B1_05) }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_37,   signal: Mclk, daisy: Some(DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_EMC_37) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_04, signal: Mclk, daisy: Some(DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_SD_B1_04) }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_36,   signal: TxData, daisy: None }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_01, signal: TxData, daisy: None }
-
-sai! { module: U3, alt: 3, pad: GPIO_EMC_33,   signal: RxData, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_EMC_33) }
-sai! { module: U3, alt: 8, pad: GPIO_SD_B1_00, signal: RxData, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_SD_B1_00) }
-
-mod daisy {
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_38,   signal: TxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_EMC_38) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_03, signal: TxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_SD_B1_03) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_39,   signal: TxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_EMC_39) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_02, signal: TxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_SD_B1_02) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_35,   signal: RxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_EMC_35) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_06, signal: RxBclk, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_SD_B1_06) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_34,   signal: RxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_EMC_34) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_05, signal: RxSync, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_SD_B1_05) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_37,   signal: Mclk, daisy: Some(DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_EMC_37) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_04, signal: Mclk, daisy: Some(DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_SD_B1_04) }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_36,   signal: TxData, daisy: None }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_01, signal: TxData, daisy: None }
+
+#[cfg(feature = "imxrt1060-gpio-emc")]
+sai! { module: 3, alt: 3, pad: GPIO_EMC_33,   signal: RxData, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_EMC_33) }
+#[cfg(feature = "imxrt1060-gpio-sd-b1")]
+sai! { module: 3, alt: 8, pad: GPIO_SD_B1_00, signal: RxData, daisy: Some(DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_SD_B1_00) }
+
+pub mod daisy {
     use super::Daisy;
 
     pub const DAISY_SAI1_MCLK2_GPIO_SD_B1_03: Daisy = Daisy::new(0x401f858c as *mut u32, 0);
@@ -198,6 +266,58 @@ mod daisy {
         Daisy::new(0x401f8784 as *mut u32, 0);
     pub const DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_SD_B1_02: Daisy =
         Daisy::new(0x401f8784 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_SAI1_MCLK2_GPIO_SD_B1_03,
+        DAISY_SAI1_MCLK2_GPIO_AD_B1_09,
+        DAISY_SAI1_MCLK2_GPIO_B0_13,
+        DAISY_SAI1_RX_BCLK_GPIO_SD_B1_05,
+        DAISY_SAI1_RX_BCLK_GPIO_AD_B1_11,
+        DAISY_SAI1_RX_BCLK_GPIO_B0_15,
+        DAISY_SAI1_RX_DATA0_GPIO_SD_B1_06,
+        DAISY_SAI1_RX_DATA0_GPIO_AD_B1_12,
+        DAISY_SAI1_RX_DATA0_GPIO_B1_00,
+        DAISY_SAI1_RX_DATA1_GPIO_SD_B1_00,
+        DAISY_SAI1_RX_DATA1_GPIO_B0_10,
+        DAISY_SAI1_RX_DATA2_GPIO_SD_B1_01,
+        DAISY_SAI1_RX_DATA2_GPIO_B0_11,
+        DAISY_SAI1_RX_DATA3_GPIO_SD_B1_02,
+        DAISY_SAI1_RX_DATA3_GPIO_B0_12,
+        DAISY_SAI1_RX_SYNC_GPIO_SD_B1_04,
+        DAISY_SAI1_RX_SYNC_GPIO_AD_B1_10,
+        DAISY_SAI1_RX_SYNC_GPIO_B0_14,
+        DAISY_SAI1_TX_BCLK_GPIO_SD_B1_08,
+        DAISY_SAI1_TX_BCLK_GPIO_AD_B1_14,
+        DAISY_SAI1_TX_BCLK_GPIO_B1_02,
+        DAISY_SAI1_TX_SYNC_GPIO_SD_B1_09,
+        DAISY_SAI1_TX_SYNC_GPIO_AD_B1_15,
+        DAISY_SAI1_TX_SYNC_GPIO_B1_03,
+        DAISY_SAI2_MCLK2_GPIO_EMC_07,
+        DAISY_SAI2_MCLK2_GPIO_AD_B0_10,
+        DAISY_SAI2_RX_BCLK_GPIO_EMC_10,
+        DAISY_SAI2_RX_BCLK_GPIO_AD_B0_06,
+        DAISY_SAI2_RX_DATA0_GPIO_EMC_08,
+        DAISY_SAI2_RX_DATA0_GPIO_AD_B0_08,
+        DAISY_SAI2_RX_SYNC_GPIO_EMC_09,
+        DAISY_SAI2_RX_SYNC_GPIO_AD_B0_07,
+        DAISY_SAI2_TX_BCLK_GPIO_EMC_06,
+        DAISY_SAI2_TX_BCLK_GPIO_AD_B0_05,
+        DAISY_SAI2_TX_SYNC_GPIO_EMC_05,
+        DAISY_SAI2_TX_SYNC_GPIO_AD_B0_04,
+        DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_EMC_37,
+        DAISY_SAI3_IPG_CLK_SAI_MCLK_2_GPIO_SD_B1_04,
+        DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_EMC_35,
+        DAISY_SAI3_IPP_IND_SAI_RXBCLK_GPIO_SD_B1_06,
+        DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_EMC_33,
+        DAISY_SAI3_IPP_IND_SAI_RXDATA_0_GPIO_SD_B1_00,
+        DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_EMC_34,
+        DAISY_SAI3_IPP_IND_SAI_RXSYNC_GPIO_SD_B1_05,
+        DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_EMC_38,
+        DAISY_SAI3_IPP_IND_SAI_TXBCLK_GPIO_SD_B1_03,
+        DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_EMC_39,
+        DAISY_SAI3_IPP_IND_SAI_TXSYNC_GPIO_SD_B1_02,
+    ];
 }
 
 use daisy::*;
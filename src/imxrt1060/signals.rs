@@ -0,0 +1,11973 @@
+// Generated by signals.py
+
+use crate::signals::{Alternate, Database, SelectInput};
+
+/// Every alternate and select input of the chip.
+pub const DATABASE: Database = Database {
+    alternates: &[
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 0,
+            signal: "SEMC_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 2,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 3,
+            signal: "XBAR1_XBAR_IN02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            alt: 5,
+            signal: "GPIO4_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 0,
+            signal: "SEMC_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 2,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 3,
+            signal: "XBAR1_IN03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            alt: 5,
+            signal: "GPIO4_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 2,
+            signal: "LPSPI2_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 0,
+            signal: "SEMC_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 2,
+            signal: "LPSPI2_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            alt: 5,
+            signal: "GPIO4_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 0,
+            signal: "SEMC_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 2,
+            signal: "SAI2_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            alt: 5,
+            signal: "GPIO4_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 0,
+            signal: "SEMC_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 2,
+            signal: "SAI2_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            alt: 5,
+            signal: "GPIO4_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 2,
+            signal: "SAI2_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 0,
+            signal: "SEMC_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 2,
+            signal: "SAI2_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            alt: 5,
+            signal: "GPIO4_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 0,
+            signal: "SEMC_DM00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 2,
+            signal: "SAI2_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            alt: 5,
+            signal: "GPIO4_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 2,
+            signal: "SAI2_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 3,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 5,
+            signal: "GPIO4_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 2,
+            signal: "SAI2_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 3,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 2,
+            signal: "LPI2C4_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 3,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 5,
+            signal: "GPIO4_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 1,
+            signal: "XBAR1_IN24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 2,
+            signal: "LPI2C4_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 3,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 5,
+            signal: "GPIO4_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 1,
+            signal: "XBAR1_IN25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 2,
+            signal: "LPUART3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 3,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 5,
+            signal: "GPIO4_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 2,
+            signal: "LPUART3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 3,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 4,
+            signal: "LPSPI2_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 1,
+            signal: "XBAR1_IN20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 2,
+            signal: "LPUART3_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 3,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 4,
+            signal: "QTIMER3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 5,
+            signal: "GPIO4_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 1,
+            signal: "XBAR1_IN21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 2,
+            signal: "LPUART3_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 3,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 4,
+            signal: "QTIMER3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 5,
+            signal: "GPIO4_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 2,
+            signal: "LPUART4_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 3,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 4,
+            signal: "QTIMER3_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            alt: 5,
+            signal: "GPIO4_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 2,
+            signal: "LPUART4_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 3,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 4,
+            signal: "QTIMER3_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            alt: 6,
+            signal: "SNVS_VIO_5_CTL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 2,
+            signal: "LPUART4_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 3,
+            signal: "ENET_RDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 4,
+            signal: "QTIMER2_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 5,
+            signal: "GPIO4_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            alt: 6,
+            signal: "SNVS_VIO_5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 2,
+            signal: "LPUART4_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 3,
+            signal: "ENET_RDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 4,
+            signal: "QTIMER2_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            alt: 5,
+            signal: "GPIO4_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 0,
+            signal: "SEMC_BA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 2,
+            signal: "LPI2C3_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 3,
+            signal: "ENET_TDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 4,
+            signal: "QTIMER2_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            alt: 5,
+            signal: "GPIO4_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 0,
+            signal: "SEMC_BA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 2,
+            signal: "LPI2C3_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 3,
+            signal: "ENET_TDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 4,
+            signal: "QTIMER2_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 2,
+            signal: "LPUART5_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 3,
+            signal: "ENET_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 4,
+            signal: "GPT1_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 5,
+            signal: "GPIO4_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 0,
+            signal: "SEMC_CAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 2,
+            signal: "LPUART5_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 3,
+            signal: "ENET_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 4,
+            signal: "GPT1_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 5,
+            signal: "GPIO4_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 0,
+            signal: "SEMC_RAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 2,
+            signal: "LPUART6_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 3,
+            signal: "ENET_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 4,
+            signal: "ENET_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 5,
+            signal: "GPIO4_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 0,
+            signal: "SEMC_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 2,
+            signal: "LPUART6_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 3,
+            signal: "ENET_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 0,
+            signal: "SEMC_CKE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 2,
+            signal: "LPUART5_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 3,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 5,
+            signal: "GPIO4_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 0,
+            signal: "SEMC_WE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 2,
+            signal: "LPUART5_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 3,
+            signal: "LPSPI1_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 5,
+            signal: "GPIO4_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 0,
+            signal: "SEMC_CS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 2,
+            signal: "LPUART6_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 3,
+            signal: "LPSPI1_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 4,
+            signal: "FLEXIO1_FLEXIO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 5,
+            signal: "GPIO4_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            alt: 8,
+            signal: "FLEXSPI2_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 2,
+            signal: "LPUART6_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 3,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 4,
+            signal: "CSI_DATA23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 5,
+            signal: "GPIO4_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 0,
+            signal: "SEMC_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 2,
+            signal: "LPUART7_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 3,
+            signal: "LPSPI1_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 4,
+            signal: "CSI_DATA22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 5,
+            signal: "GPIO4_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 0,
+            signal: "SEMC_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 2,
+            signal: "LPUART7_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 3,
+            signal: "CCM_PMIC_RDY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 4,
+            signal: "CSI_DATA21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 5,
+            signal: "GPIO3_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            alt: 8,
+            signal: "ENET2_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 0,
+            signal: "SEMC_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 2,
+            signal: "USDHC1_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 3,
+            signal: "SAI3_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 4,
+            signal: "CSI_DATA20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 5,
+            signal: "GPIO3_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 8,
+            signal: "ENET2_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            alt: 9,
+            signal: "ENET2_REF_CLK2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 2,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 3,
+            signal: "SAI3_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 4,
+            signal: "CSI_DATA19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 5,
+            signal: "GPIO3_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            alt: 8,
+            signal: "ENET2_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 0,
+            signal: "SEMC_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 3,
+            signal: "SAI3_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 4,
+            signal: "CSI_DATA18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 5,
+            signal: "GPIO3_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 6,
+            signal: "USDHC1_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 0,
+            signal: "SEMC_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 1,
+            signal: "XBAR1_IN22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 3,
+            signal: "SAI3_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 4,
+            signal: "CSI_DATA17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 5,
+            signal: "GPIO3_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 6,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            alt: 9,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 0,
+            signal: "SEMC_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 1,
+            signal: "XBAR1_IN23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 3,
+            signal: "SAI3_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 4,
+            signal: "CSI_DATA16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 5,
+            signal: "GPIO3_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 6,
+            signal: "USDHC2_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 8,
+            signal: "ENET2_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            alt: 9,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 0,
+            signal: "SEMC_DM01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 2,
+            signal: "LPUART8_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 3,
+            signal: "SAI3_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 4,
+            signal: "CSI_FIELD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 5,
+            signal: "GPIO3_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 6,
+            signal: "USDHC2_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            alt: 8,
+            signal: "ENET2_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 0,
+            signal: "SEMC_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 2,
+            signal: "LPUART8_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 3,
+            signal: "SAI3_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 4,
+            signal: "WDOG1_WDOG_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 6,
+            signal: "USDHC2_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 8,
+            signal: "ENET2_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            alt: 9,
+            signal: "SEMC_DQS4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 0,
+            signal: "SEMC_RDY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 1,
+            signal: "GPT2_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 3,
+            signal: "USB_OTG2_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 4,
+            signal: "ENET_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 5,
+            signal: "GPIO3_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 6,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            alt: 9,
+            signal: "SEMC_CLK5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 0,
+            signal: "SEMC_CSX00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 1,
+            signal: "GPT2_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 3,
+            signal: "USB_OTG2_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 4,
+            signal: "ENET_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 5,
+            signal: "GPIO3_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            alt: 6,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 0,
+            signal: "FLEXPWM2_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 2,
+            signal: "REF_CLK_32K",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 3,
+            signal: "USB_OTG2_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 4,
+            signal: "LPI2C1_SCLS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 5,
+            signal: "GPIO1_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 6,
+            signal: "USDHC1_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            alt: 7,
+            signal: "LPSPI3_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 0,
+            signal: "FLEXPWM2_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 2,
+            signal: "REF_CLK_24M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 3,
+            signal: "USB_OTG1_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 4,
+            signal: "LPI2C1_SDAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 5,
+            signal: "GPIO1_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 6,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            alt: 7,
+            signal: "LPSPI3_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 0,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 2,
+            signal: "LPUART6_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 3,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMX00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 5,
+            signal: "GPIO1_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 6,
+            signal: "LPI2C1_HREQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            alt: 7,
+            signal: "LPSPI3_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 0,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 2,
+            signal: "LPUART6_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 3,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMX01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 5,
+            signal: "GPIO1_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 6,
+            signal: "REF_CLK_24M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            alt: 7,
+            signal: "LPSPI3_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 0,
+            signal: "SRC_BOOT_MODE00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 1,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 2,
+            signal: "ENET_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 3,
+            signal: "SAI2_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 4,
+            signal: "CSI_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 5,
+            signal: "GPIO1_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 6,
+            signal: "PIT_TRIGGER00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            alt: 7,
+            signal: "LPSPI3_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 0,
+            signal: "SRC_BOOT_MODE01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 1,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 2,
+            signal: "ENET_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 3,
+            signal: "SAI2_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 4,
+            signal: "CSI_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 5,
+            signal: "GPIO1_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            alt: 7,
+            signal: "LPSPI3_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 0,
+            signal: "JTAG_TMS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 1,
+            signal: "GPT2_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 2,
+            signal: "ENET_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 3,
+            signal: "SAI2_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 4,
+            signal: "CSI_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 5,
+            signal: "GPIO1_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            alt: 7,
+            signal: "LPSPI3_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 0,
+            signal: "JTAG_TCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 1,
+            signal: "GPT2_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 2,
+            signal: "ENET_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 3,
+            signal: "SAI2_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 4,
+            signal: "CSI_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 5,
+            signal: "GPIO1_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            alt: 7,
+            signal: "ENET_1588_EVENT3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 0,
+            signal: "JTAG_MOD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 1,
+            signal: "GPT2_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 2,
+            signal: "ENET_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 3,
+            signal: "SAI2_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 4,
+            signal: "CSI_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 5,
+            signal: "GPIO1_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 6,
+            signal: "XBAR1_IN20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            alt: 7,
+            signal: "ENET_1588_EVENT3_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 0,
+            signal: "JTAG_TDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 2,
+            signal: "ENET_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 3,
+            signal: "SAI2_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 4,
+            signal: "CSI_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 5,
+            signal: "GPIO1_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 6,
+            signal: "XBAR1_IN21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 7,
+            signal: "GPT2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            alt: 9,
+            signal: "SEMC_DQS4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 0,
+            signal: "JTAG_TDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 2,
+            signal: "ENET_CRS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 3,
+            signal: "SAI2_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 4,
+            signal: "CSI_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 5,
+            signal: "GPIO1_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 6,
+            signal: "XBAR1_IN22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 7,
+            signal: "ENET_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 8,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            alt: 9,
+            signal: "ARM_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 0,
+            signal: "JTAG_TRSTB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 2,
+            signal: "ENET_COL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 3,
+            signal: "WDOG1_WDOG_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 4,
+            signal: "CSI_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 5,
+            signal: "GPIO1_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 6,
+            signal: "XBAR1_IN23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 7,
+            signal: "ENET_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 8,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            alt: 9,
+            signal: "SEMC_CLK6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 0,
+            signal: "LPI2C4_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 1,
+            signal: "CCM_PMIC_READY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 2,
+            signal: "LPUART1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 3,
+            signal: "WDOG2_WDOG_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMX02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 5,
+            signal: "GPIO1_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            alt: 7,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 0,
+            signal: "LPI2C4_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 1,
+            signal: "GPT1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 2,
+            signal: "LPUART1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 3,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWMX03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 5,
+            signal: "GPIO1_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT1_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            alt: 7,
+            signal: "REF_CLK_24M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 0,
+            signal: "USB_OTG2_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 1,
+            signal: "XBAR1_IN24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 2,
+            signal: "LPUART1_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 4,
+            signal: "CSI_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 5,
+            signal: "GPIO1_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 6,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            alt: 8,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 0,
+            signal: "USB_OTG2_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 1,
+            signal: "XBAR1_IN25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 2,
+            signal: "LPUART1_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 4,
+            signal: "CSI_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 5,
+            signal: "GPIO1_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 6,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 7,
+            signal: "WDOG1_WDOG_RST_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            alt: 8,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 0,
+            signal: "USB_OTG2_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 2,
+            signal: "LPUART2_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 3,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 4,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 5,
+            signal: "GPIO1_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 6,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 7,
+            signal: "KPP_ROW07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 0,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 2,
+            signal: "LPUART2_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 3,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 4,
+            signal: "CCM_PMIC_READY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 5,
+            signal: "GPIO1_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 6,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 7,
+            signal: "KPP_COL07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 0,
+            signal: "USB_OTG1_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 2,
+            signal: "LPUART2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 3,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 4,
+            signal: "ENET_1588_EVENT2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 5,
+            signal: "GPIO1_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 6,
+            signal: "USDHC1_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 7,
+            signal: "KPP_ROW06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 8,
+            signal: "GPT2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 0,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 2,
+            signal: "LPUART2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 3,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 4,
+            signal: "ENET_1588_EVENT2_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 5,
+            signal: "GPIO1_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 6,
+            signal: "USDHC2_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 7,
+            signal: "KPP_COL06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 8,
+            signal: "GPT2_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 0,
+            signal: "FLEXSPIB_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 1,
+            signal: "ENET_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 2,
+            signal: "LPUART3_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 3,
+            signal: "SPDIF_SR_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 4,
+            signal: "CSI_PIXCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 5,
+            signal: "GPIO1_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 7,
+            signal: "KPP_ROW05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 8,
+            signal: "GPT2_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 0,
+            signal: "FLEXSPIB_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 1,
+            signal: "ENET_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 2,
+            signal: "LPUART3_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 3,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 4,
+            signal: "CSI_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 5,
+            signal: "GPIO1_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 7,
+            signal: "KPP_COL05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 8,
+            signal: "GPT2_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 0,
+            signal: "FLEXSPIB_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 1,
+            signal: "LPI2C3_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 2,
+            signal: "LPUART3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 3,
+            signal: "SPDIF_LOCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 4,
+            signal: "CSI_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 5,
+            signal: "GPIO1_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 7,
+            signal: "KPP_ROW04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 8,
+            signal: "GPT2_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 0,
+            signal: "FLEXSPIB_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 1,
+            signal: "LPI2C3_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 2,
+            signal: "LPUART3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 3,
+            signal: "SPDIF_EXT_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 4,
+            signal: "CSI_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 5,
+            signal: "GPIO1_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 7,
+            signal: "KPP_COL04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 8,
+            signal: "GPT2_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 3,
+            signal: "CCM_PMIC_READY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 4,
+            signal: "CSI_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 5,
+            signal: "GPIO1_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 6,
+            signal: "USDHC2_CMD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 7,
+            signal: "KPP_ROW03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 3,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 4,
+            signal: "CSI_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 5,
+            signal: "GPIO1_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 6,
+            signal: "USDHC2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 7,
+            signal: "KPP_COL03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 1,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 2,
+            signal: "LPUART8_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 3,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 4,
+            signal: "CSI_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 5,
+            signal: "GPIO1_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 6,
+            signal: "USDHC2_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 7,
+            signal: "KPP_ROW02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 1,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 2,
+            signal: "LPUART8_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 3,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 4,
+            signal: "CSI_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 5,
+            signal: "GPIO1_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 6,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 7,
+            signal: "KPP_COL02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT1_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 1,
+            signal: "ACMP_OUT00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 2,
+            signal: "LPSPI3_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 3,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 4,
+            signal: "CSI_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 5,
+            signal: "GPIO1_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 7,
+            signal: "KPP_ROW01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 1,
+            signal: "ACMP_OUT01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 2,
+            signal: "LPSPI3_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 4,
+            signal: "CSI_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 5,
+            signal: "GPIO1_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 7,
+            signal: "KPP_COL01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT2_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 1,
+            signal: "ACMP_OUT02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 2,
+            signal: "LPSPI3_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 3,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 4,
+            signal: "CSI_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 5,
+            signal: "GPIO1_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 7,
+            signal: "KPP_ROW00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 0,
+            signal: "FLEXSPIA_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 1,
+            signal: "ACMP_OUT03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 2,
+            signal: "LPSPI3_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 3,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 4,
+            signal: "CSI_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 5,
+            signal: "GPIO1_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 6,
+            signal: "USDHC2_DATA7",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 7,
+            signal: "KPP_COL00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT3_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 0,
+            signal: "LCD_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 1,
+            signal: "QTIMER1_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 2,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 3,
+            signal: "LPSPI4_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 6,
+            signal: "SEMC_CSX01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            alt: 8,
+            signal: "ENET2_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 0,
+            signal: "LCD_ENABLE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 1,
+            signal: "QTIMER1_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 2,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 3,
+            signal: "LPSPI4_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 5,
+            signal: "GPIO2_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 6,
+            signal: "SEMC_CSX02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            alt: 8,
+            signal: "ENET2_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 0,
+            signal: "LCD_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 1,
+            signal: "QTIMER1_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 3,
+            signal: "LPSPI4_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 5,
+            signal: "GPIO2_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 6,
+            signal: "SEMC_CSX03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 0,
+            signal: "LCD_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 1,
+            signal: "QTIMER2_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 3,
+            signal: "LPSPI4_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 5,
+            signal: "GPIO2_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 6,
+            signal: "WDOG2_RESET_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            alt: 8,
+            signal: "ENET2_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 0,
+            signal: "LCD_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 1,
+            signal: "QTIMER2_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 2,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 3,
+            signal: "ARM_TRACE0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 0,
+            signal: "LCD_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 1,
+            signal: "QTIMER2_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 2,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 3,
+            signal: "ARM_TRACE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 5,
+            signal: "GPIO2_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 0,
+            signal: "LCD_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 3,
+            signal: "ARM_TRACE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 5,
+            signal: "GPIO2_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            alt: 8,
+            signal: "ENET2_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 0,
+            signal: "LCD_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 3,
+            signal: "ARM_TRACE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 5,
+            signal: "GPIO2_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            alt: 8,
+            signal: "ENET2_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 0,
+            signal: "LCD_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 3,
+            signal: "LPUART3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 0,
+            signal: "LCD_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 1,
+            signal: "QTIMER4_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 3,
+            signal: "LPUART3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 5,
+            signal: "GPIO2_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 0,
+            signal: "LCD_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 1,
+            signal: "QTIMER4_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 5,
+            signal: "GPIO2_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            alt: 8,
+            signal: "ENET2_CRS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 0,
+            signal: "LCD_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 1,
+            signal: "QTIMER4_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 5,
+            signal: "GPIO2_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            alt: 8,
+            signal: "ENET2_COL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 0,
+            signal: "LCD_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 2,
+            signal: "ARM_TRACE_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 0,
+            signal: "LCD_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 2,
+            signal: "ARM_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 3,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 5,
+            signal: "GPIO2_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 0,
+            signal: "LCD_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 2,
+            signal: "ARM_TXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 3,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 5,
+            signal: "GPIO2_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            alt: 8,
+            signal: "ENET2_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 0,
+            signal: "LCD_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 2,
+            signal: "ARM_RXEV",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 3,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 5,
+            signal: "GPIO2_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 6,
+            signal: "SRC_BOOT_CFG11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 8,
+            signal: "ENET2_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            alt: 9,
+            signal: "ENET2_REF_CLK2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 0,
+            signal: "LCD_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 2,
+            signal: "LPUART4_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 3,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 6,
+            signal: "FLEXPWM1_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 8,
+            signal: "ENET2_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 0,
+            signal: "LCD_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 2,
+            signal: "LPUART4_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 5,
+            signal: "GPIO2_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 6,
+            signal: "FLEXPWM1_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 0,
+            signal: "LCD_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 2,
+            signal: "LPSPI4_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 3,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 5,
+            signal: "GPIO2_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 6,
+            signal: "FLEXPWM2_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 0,
+            signal: "LCD_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 2,
+            signal: "LPSPI4_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 3,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 5,
+            signal: "GPIO2_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 6,
+            signal: "FLEXPWM2_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 8,
+            signal: "ENET2_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 0,
+            signal: "LCD_DATA16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 1,
+            signal: "LPSPI4_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 2,
+            signal: "CSI_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 3,
+            signal: "ENET_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 8,
+            signal: "GPT1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 0,
+            signal: "LCD_DATA17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 1,
+            signal: "LPSPI4_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 2,
+            signal: "CSI_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 3,
+            signal: "ENET_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 5,
+            signal: "GPIO2_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 8,
+            signal: "GPT1_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 0,
+            signal: "LCD_DATA18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 1,
+            signal: "LPSPI4_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 2,
+            signal: "CSI_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 3,
+            signal: "ENET_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 5,
+            signal: "GPIO2_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 8,
+            signal: "GPT1_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 0,
+            signal: "LCD_DATA19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 1,
+            signal: "LPSPI4_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 2,
+            signal: "CSI_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 3,
+            signal: "ENET_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 5,
+            signal: "GPIO2_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 8,
+            signal: "GPT1_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 0,
+            signal: "LCD_DATA20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 1,
+            signal: "QTIMER1_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 2,
+            signal: "CSI_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 3,
+            signal: "ENET_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 6,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 8,
+            signal: "GPT1_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f819c,
+                pad: 0x401f838c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 0,
+            signal: "LCD_DATA21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 1,
+            signal: "QTIMER2_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 2,
+            signal: "CSI_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 3,
+            signal: "ENET_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 5,
+            signal: "GPIO2_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 6,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 8,
+            signal: "GPT1_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 0,
+            signal: "LCD_DATA22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 1,
+            signal: "QTIMER3_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 2,
+            signal: "CSI_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 3,
+            signal: "ENET_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 5,
+            signal: "GPIO2_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 6,
+            signal: "ENET_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 0,
+            signal: "LCD_DATA23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 1,
+            signal: "QTIMER4_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 2,
+            signal: "CSI_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 3,
+            signal: "ENET_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 5,
+            signal: "GPIO2_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 6,
+            signal: "LPSPI4_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 1,
+            signal: "LPUART5_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 2,
+            signal: "CSI_PIXCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 5,
+            signal: "GPIO2_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 6,
+            signal: "USDHC1_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 0,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 1,
+            signal: "LPUART5_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 2,
+            signal: "CSI_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 5,
+            signal: "GPIO2_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 6,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 8,
+            signal: "SEMC_DQS4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 0,
+            signal: "ENET_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 2,
+            signal: "CSI_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 3,
+            signal: "XBAR1_IN02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 5,
+            signal: "GPIO2_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 6,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 0,
+            signal: "ENET_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 2,
+            signal: "CSI_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 3,
+            signal: "XBAR1_IN03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 4,
+            signal: "FLEXIO2_FLEXIO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 5,
+            signal: "GPIO2_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 6,
+            signal: "USDHC1_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 8,
+            signal: "ENET2_TDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            alt: 9,
+            signal: "FLEXIO3_FLEXIO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 0,
+            signal: "USDHC1_CMD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 2,
+            signal: "LPI2C3_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 4,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 5,
+            signal: "GPIO3_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 6,
+            signal: "FLEXSPIA_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 8,
+            signal: "ENET2_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            alt: 9,
+            signal: "SEMC_DQS4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 0,
+            signal: "USDHC1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 2,
+            signal: "LPI2C3_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 4,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 6,
+            signal: "FLEXSPIB_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 8,
+            signal: "ENET2_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            alt: 9,
+            signal: "ENET2_REF_CLK2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 2,
+            signal: "LPUART8_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 4,
+            signal: "LPSPI1_SDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 5,
+            signal: "GPIO3_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 8,
+            signal: "ENET2_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            alt: 9,
+            signal: "SEMC_CLK5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 2,
+            signal: "LPUART8_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 4,
+            signal: "LPSPI1_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 5,
+            signal: "GPIO3_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            alt: 9,
+            signal: "SEMC_CLK6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 2,
+            signal: "LPUART8_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 4,
+            signal: "FLEXSPIB_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 5,
+            signal: "GPIO3_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 6,
+            signal: "CCM_CLKO1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            alt: 8,
+            signal: "ENET2_RDATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWMB02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 2,
+            signal: "LPUART8_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 3,
+            signal: "XBAR1_INOUT09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 4,
+            signal: "FLEXSPIB_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 6,
+            signal: "CCM_CLKO2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            alt: 8,
+            signal: "ENET2_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 1,
+            signal: "FLEXSPIB_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 4,
+            signal: "LPUART4_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 5,
+            signal: "GPIO3_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            alt: 8,
+            signal: "SAI3_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 1,
+            signal: "FLEXSPIB_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 2,
+            signal: "FLEXPWM1_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 4,
+            signal: "LPUART4_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 5,
+            signal: "GPIO3_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            alt: 8,
+            signal: "SAI3_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 1,
+            signal: "FLEXSPIB_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 4,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 5,
+            signal: "GPIO3_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 6,
+            signal: "CCM_WAIT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            alt: 8,
+            signal: "SAI3_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 1,
+            signal: "FLEXSPIB_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 2,
+            signal: "FLEXPWM2_PWMB03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 3,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 4,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 6,
+            signal: "CCM_PMIC_READY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            alt: 8,
+            signal: "SAI3_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 0,
+            signal: "USDHC2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 1,
+            signal: "FLEXSPIB_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 2,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 3,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 4,
+            signal: "FLEXSPIA_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 5,
+            signal: "GPIO3_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 6,
+            signal: "CCM_STOP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            alt: 8,
+            signal: "SAI3_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 0,
+            signal: "USDHC2_CMD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 2,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 3,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 4,
+            signal: "FLEXSPIB_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 5,
+            signal: "GPIO3_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            alt: 8,
+            signal: "SAI3_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 0,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 2,
+            signal: "LPUART7_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 3,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 4,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 5,
+            signal: "GPIO3_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            alt: 8,
+            signal: "SAI3_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 0,
+            signal: "SEMC_CSX01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 2,
+            signal: "LPUART7_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 3,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 4,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 2,
+            signal: "LPUART7_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 3,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 4,
+            signal: "LPSPI2_SD0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 5,
+            signal: "GPIO3_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            alt: 6,
+            signal: "SEMC_CSX02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 2,
+            signal: "LPUART7_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 3,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 4,
+            signal: "LPSPI2_SDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            alt: 5,
+            signal: "GPIO3_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 2,
+            signal: "LPUART2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 3,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 4,
+            signal: "LPSPI2_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            alt: 5,
+            signal: "GPIO3_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA7",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 1,
+            signal: "FLEXSPIA_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 2,
+            signal: "LPUART2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 3,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 4,
+            signal: "LPSPI2_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            alt: 5,
+            signal: "GPIO3_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8660,
+                pad: 0x401f86b8,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8664,
+                pad: 0x401f86bc,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8668,
+                pad: 0x401f86c0,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f866c,
+                pad: 0x401f86c4,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8670,
+                pad: 0x401f86c8,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8674,
+                pad: 0x401f86cc,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8678,
+                pad: 0x401f86d0,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f867c,
+                pad: 0x401f86d4,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8680,
+                pad: 0x401f86d8,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8684,
+                pad: 0x401f86dc,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8688,
+                pad: 0x401f86e0,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f868c,
+                pad: 0x401f86e4,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8694,
+                pad: 0x401f86ec,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f8698,
+                pad: 0x401f86f0,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f869c,
+                pad: 0x401f86f4,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f86a0,
+                pad: 0x401f86f8,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f86a4,
+                pad: 0x401f86fc,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f86a8,
+                pad: 0x401f8700,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x401f86ac,
+                pad: 0x401f8704,
+            },
+            alt: 0,
+            signal: "FLEXSPI2_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8000,
+                pad: 0x400a8018,
+            },
+            alt: 5,
+            signal: "GPIO5_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8000,
+                pad: 0x400a8018,
+            },
+            alt: 7,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8004,
+                pad: 0x400a801c,
+            },
+            alt: 0,
+            signal: "SNVS_LP_PMIC_ON_REQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8004,
+                pad: 0x400a801c,
+            },
+            alt: 5,
+            signal: "GPIO5_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8008,
+                pad: 0x400a8020,
+            },
+            alt: 0,
+            signal: "CCM_PMIC_VSTBY_REQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400a8008,
+                pad: 0x400a8020,
+            },
+            alt: 5,
+            signal: "GPIO5_IO02",
+        },
+    ],
+    inputs: &[
+        SelectInput {
+            input: "ANATOP_USB_OTG1_ID",
+            register: 0x401f83f4,
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ANATOP_USB_OTG1_ID",
+            register: 0x401f83f4,
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ANATOP_USB_OTG2_ID",
+            register: 0x401f83f8,
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ANATOP_USB_OTG2_ID",
+            register: 0x401f83f8,
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CCM_PMIC_READY",
+            register: 0x401f83fc,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CCM_PMIC_READY",
+            register: 0x401f83fc,
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CCM_PMIC_READY",
+            register: 0x401f83fc,
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "CCM_PMIC_READY",
+            register: 0x401f83fc,
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "CCM_PMIC_READY",
+            register: 0x401f83fc,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            value: 4,
+        },
+        SelectInput {
+            input: "CSI_DATA02",
+            register: 0x401f8400,
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA02",
+            register: 0x401f8400,
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA03",
+            register: 0x401f8404,
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA03",
+            register: 0x401f8404,
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA04",
+            register: 0x401f8408,
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA04",
+            register: 0x401f8408,
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA05",
+            register: 0x401f840c,
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA05",
+            register: 0x401f840c,
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA06",
+            register: 0x401f8410,
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA06",
+            register: 0x401f8410,
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA07",
+            register: 0x401f8414,
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA07",
+            register: 0x401f8414,
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA08",
+            register: 0x401f8418,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA08",
+            register: 0x401f8418,
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_DATA09",
+            register: 0x401f841c,
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_DATA09",
+            register: 0x401f841c,
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_HSYNC",
+            register: 0x401f8420,
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_HSYNC",
+            register: 0x401f8420,
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_HSYNC",
+            register: 0x401f8420,
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "CSI_PIXCLK",
+            register: 0x401f8424,
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_PIXCLK",
+            register: 0x401f8424,
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_VSYNC",
+            register: 0x401f8428,
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CSI_VSYNC",
+            register: 0x401f8428,
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CSI_VSYNC",
+            register: 0x401f8428,
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_IPG_CLK_RMII",
+            register: 0x401f842c,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_IPG_CLK_RMII",
+            register: 0x401f842c,
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MDIO",
+            register: 0x401f8430,
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MDIO",
+            register: 0x401f8430,
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MDIO",
+            register: 0x401f8430,
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET0_RXDATA",
+            register: 0x401f8434,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET0_RXDATA",
+            register: 0x401f8434,
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET1_RXDATA",
+            register: 0x401f8438,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET1_RXDATA",
+            register: 0x401f8438,
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_RXEN",
+            register: 0x401f843c,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_RXEN",
+            register: 0x401f843c,
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_RXERR",
+            register: 0x401f8440,
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_RXERR",
+            register: 0x401f8440,
+            pad: crate::PadLocation {
+                mux: 0x401f81a8,
+                pad: 0x401f8398,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET0_TIMER",
+            register: 0x401f8444,
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET0_TIMER",
+            register: 0x401f8444,
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET0_TIMER",
+            register: 0x401f8444,
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_TXCLK",
+            register: 0x401f8448,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_TXCLK",
+            register: 0x401f8448,
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x401f844c,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x401f844c,
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x401f844c,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x401f844c,
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x401f8450,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x401f8450,
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x401f8450,
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x401f8450,
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA3",
+            register: 0x401f8454,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA3",
+            register: 0x401f8454,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA3",
+            register: 0x401f8454,
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA3",
+            register: 0x401f8454,
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA3",
+            register: 0x401f8454,
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            value: 4,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA0",
+            register: 0x401f8458,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA0",
+            register: 0x401f8458,
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA1",
+            register: 0x401f845c,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA1",
+            register: 0x401f845c,
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA2",
+            register: 0x401f8460,
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA2",
+            register: 0x401f8460,
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB3",
+            register: 0x401f8464,
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB3",
+            register: 0x401f8464,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB3",
+            register: 0x401f8464,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB3",
+            register: 0x401f8464,
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB3",
+            register: 0x401f8464,
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            value: 4,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB0",
+            register: 0x401f8468,
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB0",
+            register: 0x401f8468,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB1",
+            register: 0x401f846c,
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB1",
+            register: 0x401f846c,
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB2",
+            register: 0x401f8470,
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB2",
+            register: 0x401f8470,
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA3",
+            register: 0x401f8474,
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA3",
+            register: 0x401f8474,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA3",
+            register: 0x401f8474,
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA3",
+            register: 0x401f8474,
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA3",
+            register: 0x401f8474,
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            value: 4,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA0",
+            register: 0x401f8478,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA0",
+            register: 0x401f8478,
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA1",
+            register: 0x401f847c,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA1",
+            register: 0x401f847c,
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA2",
+            register: 0x401f8480,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA2",
+            register: 0x401f8480,
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB3",
+            register: 0x401f8484,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB3",
+            register: 0x401f8484,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB3",
+            register: 0x401f8484,
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB3",
+            register: 0x401f8484,
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB0",
+            register: 0x401f8488,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB0",
+            register: 0x401f8488,
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB1",
+            register: 0x401f848c,
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB1",
+            register: 0x401f848c,
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB2",
+            register: 0x401f8490,
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB2",
+            register: 0x401f8490,
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA0",
+            register: 0x401f8494,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA0",
+            register: 0x401f8494,
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA1",
+            register: 0x401f8498,
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA1",
+            register: 0x401f8498,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA2",
+            register: 0x401f849c,
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA2",
+            register: 0x401f849c,
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA3",
+            register: 0x401f84a0,
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM4_PWMA3",
+            register: 0x401f84a0,
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DQS",
+            register: 0x401f84a4,
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DQS",
+            register: 0x401f84a4,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA0",
+            register: 0x401f84a8,
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA0",
+            register: 0x401f84a8,
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA1",
+            register: 0x401f84ac,
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA1",
+            register: 0x401f84ac,
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA2",
+            register: 0x401f84b0,
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA2",
+            register: 0x401f84b0,
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA3",
+            register: 0x401f84b4,
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_DATA3",
+            register: 0x401f84b4,
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA0",
+            register: 0x401f84b8,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA0",
+            register: 0x401f84b8,
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA1",
+            register: 0x401f84bc,
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA1",
+            register: 0x401f84bc,
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA2",
+            register: 0x401f84c0,
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA2",
+            register: 0x401f84c0,
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA3",
+            register: 0x401f84c4,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIB_DATA3",
+            register: 0x401f84c4,
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPIA_SCK",
+            register: 0x401f84c8,
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPIA_SCK",
+            register: 0x401f84c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f84cc,
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_SCL",
+            register: 0x401f84cc,
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f84d0,
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_SDA",
+            register: 0x401f84d0,
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f84d4,
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_SCL",
+            register: 0x401f84d4,
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f84d8,
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_SDA",
+            register: 0x401f84d8,
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C3_SCL",
+            register: 0x401f84dc,
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C3_SCL",
+            register: 0x401f84dc,
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C3_SCL",
+            register: 0x401f84dc,
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C3_SDA",
+            register: 0x401f84e0,
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C3_SDA",
+            register: 0x401f84e0,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C3_SDA",
+            register: 0x401f84e0,
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C4_SCL",
+            register: 0x401f84e4,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C4_SCL",
+            register: 0x401f84e4,
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C4_SDA",
+            register: 0x401f84e8,
+            pad: crate::PadLocation {
+                mux: 0x401f8040,
+                pad: 0x401f8230,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C4_SDA",
+            register: 0x401f84e8,
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_PCS0",
+            register: 0x401f84ec,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_PCS0",
+            register: 0x401f84ec,
+            pad: crate::PadLocation {
+                mux: 0x401f808c,
+                pad: 0x401f827c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SCK",
+            register: 0x401f84f0,
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SCK",
+            register: 0x401f84f0,
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SDI",
+            register: 0x401f84f4,
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SDI",
+            register: 0x401f84f4,
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_SDO",
+            register: 0x401f84f8,
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_SDO",
+            register: 0x401f84f8,
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_PCS0",
+            register: 0x401f84fc,
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_PCS0",
+            register: 0x401f84fc,
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SCK",
+            register: 0x401f8500,
+            pad: crate::PadLocation {
+                mux: 0x401f81f0,
+                pad: 0x401f83e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SCK",
+            register: 0x401f8500,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SDI",
+            register: 0x401f8504,
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SDI",
+            register: 0x401f8504,
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_SDO",
+            register: 0x401f8508,
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_SDO",
+            register: 0x401f8508,
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_PCS0",
+            register: 0x401f850c,
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_PCS0",
+            register: 0x401f850c,
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_SCK",
+            register: 0x401f8510,
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_SCK",
+            register: 0x401f8510,
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_SDI",
+            register: 0x401f8514,
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_SDI",
+            register: 0x401f8514,
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_SDO",
+            register: 0x401f8518,
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_SDO",
+            register: 0x401f8518,
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_PCS0",
+            register: 0x401f851c,
+            pad: crate::PadLocation {
+                mux: 0x401f813c,
+                pad: 0x401f832c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_PCS0",
+            register: 0x401f851c,
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_SCK",
+            register: 0x401f8520,
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_SCK",
+            register: 0x401f8520,
+            pad: crate::PadLocation {
+                mux: 0x401f8198,
+                pad: 0x401f8388,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_SDI",
+            register: 0x401f8524,
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_SDI",
+            register: 0x401f8524,
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_SDO",
+            register: 0x401f8528,
+            pad: crate::PadLocation {
+                mux: 0x401f8144,
+                pad: 0x401f8334,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_SDO",
+            register: 0x401f8528,
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART2_RX",
+            register: 0x401f852c,
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART2_RX",
+            register: 0x401f852c,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART2_TX",
+            register: 0x401f8530,
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART2_TX",
+            register: 0x401f8530,
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_CTS_B",
+            register: 0x401f8534,
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART3_CTS_B",
+            register: 0x401f8534,
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_RX",
+            register: 0x401f8538,
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART3_RX",
+            register: 0x401f8538,
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_RX",
+            register: 0x401f8538,
+            pad: crate::PadLocation {
+                mux: 0x401f8160,
+                pad: 0x401f8350,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART3_TX",
+            register: 0x401f853c,
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART3_TX",
+            register: 0x401f853c,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART3_TX",
+            register: 0x401f853c,
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART4_RX",
+            register: 0x401f8540,
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART4_RX",
+            register: 0x401f8540,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART4_RX",
+            register: 0x401f8540,
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART4_TX",
+            register: 0x401f8544,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART4_TX",
+            register: 0x401f8544,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART4_TX",
+            register: 0x401f8544,
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART5_RX",
+            register: 0x401f8548,
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART5_RX",
+            register: 0x401f8548,
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART5_TX",
+            register: 0x401f854c,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART5_TX",
+            register: 0x401f854c,
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART6_RX",
+            register: 0x401f8550,
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART6_RX",
+            register: 0x401f8550,
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART6_TX",
+            register: 0x401f8554,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART6_TX",
+            register: 0x401f8554,
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART7_RX",
+            register: 0x401f8558,
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART7_RX",
+            register: 0x401f8558,
+            pad: crate::PadLocation {
+                mux: 0x401f8094,
+                pad: 0x401f8284,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART7_TX",
+            register: 0x401f855c,
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART7_TX",
+            register: 0x401f855c,
+            pad: crate::PadLocation {
+                mux: 0x401f8090,
+                pad: 0x401f8280,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART8_RX",
+            register: 0x401f8560,
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART8_RX",
+            register: 0x401f8560,
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART8_RX",
+            register: 0x401f8560,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART8_TX",
+            register: 0x401f8564,
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART8_TX",
+            register: 0x401f8564,
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART8_TX",
+            register: 0x401f8564,
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "NMI",
+            register: 0x401f8568,
+            pad: crate::PadLocation {
+                mux: 0x401f80ec,
+                pad: 0x401f82dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "NMI",
+            register: 0x401f8568,
+            pad: crate::PadLocation {
+                mux: 0x400a8000,
+                pad: 0x400a8018,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER0",
+            register: 0x401f856c,
+            pad: crate::PadLocation {
+                mux: 0x401f8060,
+                pad: 0x401f8250,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER0",
+            register: 0x401f856c,
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER1",
+            register: 0x401f8570,
+            pad: crate::PadLocation {
+                mux: 0x401f8064,
+                pad: 0x401f8254,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER1",
+            register: 0x401f8570,
+            pad: crate::PadLocation {
+                mux: 0x401f814c,
+                pad: 0x401f833c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER2",
+            register: 0x401f8574,
+            pad: crate::PadLocation {
+                mux: 0x401f8068,
+                pad: 0x401f8258,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER2",
+            register: 0x401f8574,
+            pad: crate::PadLocation {
+                mux: 0x401f8150,
+                pad: 0x401f8340,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER3",
+            register: 0x401f8578,
+            pad: crate::PadLocation {
+                mux: 0x401f806c,
+                pad: 0x401f825c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TIMER3",
+            register: 0x401f8578,
+            pad: crate::PadLocation {
+                mux: 0x401f81a0,
+                pad: 0x401f8390,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER0",
+            register: 0x401f857c,
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER0",
+            register: 0x401f857c,
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER0",
+            register: 0x401f857c,
+            pad: crate::PadLocation {
+                mux: 0x401f8154,
+                pad: 0x401f8344,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER1",
+            register: 0x401f8580,
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER1",
+            register: 0x401f8580,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER1",
+            register: 0x401f8580,
+            pad: crate::PadLocation {
+                mux: 0x401f8158,
+                pad: 0x401f8348,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER2",
+            register: 0x401f8584,
+            pad: crate::PadLocation {
+                mux: 0x401f8058,
+                pad: 0x401f8248,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER2",
+            register: 0x401f8584,
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER2",
+            register: 0x401f8584,
+            pad: crate::PadLocation {
+                mux: 0x401f815c,
+                pad: 0x401f834c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER3",
+            register: 0x401f8588,
+            pad: crate::PadLocation {
+                mux: 0x401f805c,
+                pad: 0x401f824c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER3",
+            register: 0x401f8588,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TIMER3",
+            register: 0x401f8588,
+            pad: crate::PadLocation {
+                mux: 0x401f81a4,
+                pad: 0x401f8394,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_MCLK2",
+            register: 0x401f858c,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_MCLK2",
+            register: 0x401f858c,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_MCLK2",
+            register: 0x401f858c,
+            pad: crate::PadLocation {
+                mux: 0x401f8170,
+                pad: 0x401f8360,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_RX_BCLK",
+            register: 0x401f8590,
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_BCLK",
+            register: 0x401f8590,
+            pad: crate::PadLocation {
+                mux: 0x401f8128,
+                pad: 0x401f8318,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_BCLK",
+            register: 0x401f8590,
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA0",
+            register: 0x401f8594,
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA0",
+            register: 0x401f8594,
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA0",
+            register: 0x401f8594,
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA1",
+            register: 0x401f8598,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA1",
+            register: 0x401f8598,
+            pad: crate::PadLocation {
+                mux: 0x401f8164,
+                pad: 0x401f8354,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA2",
+            register: 0x401f859c,
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA2",
+            register: 0x401f859c,
+            pad: crate::PadLocation {
+                mux: 0x401f8168,
+                pad: 0x401f8358,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA3",
+            register: 0x401f85a0,
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_DATA3",
+            register: 0x401f85a0,
+            pad: crate::PadLocation {
+                mux: 0x401f816c,
+                pad: 0x401f835c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_SYNC",
+            register: 0x401f85a4,
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_RX_SYNC",
+            register: 0x401f85a4,
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_RX_SYNC",
+            register: 0x401f85a4,
+            pad: crate::PadLocation {
+                mux: 0x401f8174,
+                pad: 0x401f8364,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_TX_BCLK",
+            register: 0x401f85a8,
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_TX_BCLK",
+            register: 0x401f85a8,
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_TX_BCLK",
+            register: 0x401f85a8,
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI1_TX_SYNC",
+            register: 0x401f85ac,
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_TX_SYNC",
+            register: 0x401f85ac,
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_TX_SYNC",
+            register: 0x401f85ac,
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SAI2_MCLK2",
+            register: 0x401f85b0,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_MCLK2",
+            register: 0x401f85b0,
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI2_RX_BCLK",
+            register: 0x401f85b4,
+            pad: crate::PadLocation {
+                mux: 0x401f803c,
+                pad: 0x401f822c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_RX_BCLK",
+            register: 0x401f85b4,
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI2_RX_DATA0",
+            register: 0x401f85b8,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_RX_DATA0",
+            register: 0x401f85b8,
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI2_RX_SYNC",
+            register: 0x401f85bc,
+            pad: crate::PadLocation {
+                mux: 0x401f8038,
+                pad: 0x401f8228,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_RX_SYNC",
+            register: 0x401f85bc,
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI2_TX_BCLK",
+            register: 0x401f85c0,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_TX_BCLK",
+            register: 0x401f85c0,
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI2_TX_SYNC",
+            register: 0x401f85c4,
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI2_TX_SYNC",
+            register: 0x401f85c4,
+            pad: crate::PadLocation {
+                mux: 0x401f80cc,
+                pad: 0x401f82bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SPDIF_IN",
+            register: 0x401f85c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SPDIF_IN",
+            register: 0x401f85c8,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USB_OTG2_OC",
+            register: 0x401f85cc,
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG2_OC",
+            register: 0x401f85cc,
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USB_OTG1_OC",
+            register: 0x401f85d0,
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG1_OC",
+            register: 0x401f85d0,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC1_CD_B",
+            register: 0x401f85d4,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC1_CD_B",
+            register: 0x401f85d4,
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC1_CD_B",
+            register: 0x401f85d4,
+            pad: crate::PadLocation {
+                mux: 0x401f81ac,
+                pad: 0x401f839c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "USDHC1_WP",
+            register: 0x401f85d8,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC1_WP",
+            register: 0x401f85d8,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC1_WP",
+            register: 0x401f85d8,
+            pad: crate::PadLocation {
+                mux: 0x401f80fc,
+                pad: 0x401f82ec,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "USDHC1_WP",
+            register: 0x401f85d8,
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "USDHC2_CLK",
+            register: 0x401f85dc,
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_CLK",
+            register: 0x401f85dc,
+            pad: crate::PadLocation {
+                mux: 0x401f8120,
+                pad: 0x401f8310,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_CD_B",
+            register: 0x401f85e0,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_CD_B",
+            register: 0x401f85e0,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_CMD",
+            register: 0x401f85e4,
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_CMD",
+            register: 0x401f85e4,
+            pad: crate::PadLocation {
+                mux: 0x401f811c,
+                pad: 0x401f830c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA0",
+            register: 0x401f85e8,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA0",
+            register: 0x401f85e8,
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA1",
+            register: 0x401f85ec,
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA1",
+            register: 0x401f85ec,
+            pad: crate::PadLocation {
+                mux: 0x401f8110,
+                pad: 0x401f8300,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA2",
+            register: 0x401f85f0,
+            pad: crate::PadLocation {
+                mux: 0x401f81d8,
+                pad: 0x401f83c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA2",
+            register: 0x401f85f0,
+            pad: crate::PadLocation {
+                mux: 0x401f8114,
+                pad: 0x401f8304,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA3",
+            register: 0x401f85f4,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA3",
+            register: 0x401f85f4,
+            pad: crate::PadLocation {
+                mux: 0x401f8118,
+                pad: 0x401f8308,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA4",
+            register: 0x401f85f8,
+            pad: crate::PadLocation {
+                mux: 0x401f81f4,
+                pad: 0x401f83e4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA4",
+            register: 0x401f85f8,
+            pad: crate::PadLocation {
+                mux: 0x401f812c,
+                pad: 0x401f831c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA5",
+            register: 0x401f85fc,
+            pad: crate::PadLocation {
+                mux: 0x401f81f8,
+                pad: 0x401f83e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA5",
+            register: 0x401f85fc,
+            pad: crate::PadLocation {
+                mux: 0x401f8130,
+                pad: 0x401f8320,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA6",
+            register: 0x401f8600,
+            pad: crate::PadLocation {
+                mux: 0x401f81fc,
+                pad: 0x401f83ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA6",
+            register: 0x401f8600,
+            pad: crate::PadLocation {
+                mux: 0x401f8134,
+                pad: 0x401f8324,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_DATA7",
+            register: 0x401f8604,
+            pad: crate::PadLocation {
+                mux: 0x401f8200,
+                pad: 0x401f83f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_DATA7",
+            register: 0x401f8604,
+            pad: crate::PadLocation {
+                mux: 0x401f8138,
+                pad: 0x401f8328,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_WP",
+            register: 0x401f8608,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_WP",
+            register: 0x401f8608,
+            pad: crate::PadLocation {
+                mux: 0x401f8124,
+                pad: 0x401f8314,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN02",
+            register: 0x401f860c,
+            pad: crate::PadLocation {
+                mux: 0x401f8014,
+                pad: 0x401f8204,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN02",
+            register: 0x401f860c,
+            pad: crate::PadLocation {
+                mux: 0x401f81b4,
+                pad: 0x401f83a4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN03",
+            register: 0x401f8610,
+            pad: crate::PadLocation {
+                mux: 0x401f8018,
+                pad: 0x401f8208,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN03",
+            register: 0x401f8610,
+            pad: crate::PadLocation {
+                mux: 0x401f81b8,
+                pad: 0x401f83a8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN04",
+            register: 0x401f8614,
+            pad: crate::PadLocation {
+                mux: 0x401f801c,
+                pad: 0x401f820c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN04",
+            register: 0x401f8614,
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN05",
+            register: 0x401f8618,
+            pad: crate::PadLocation {
+                mux: 0x401f8020,
+                pad: 0x401f8210,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN05",
+            register: 0x401f8618,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN06",
+            register: 0x401f861c,
+            pad: crate::PadLocation {
+                mux: 0x401f8024,
+                pad: 0x401f8214,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN06",
+            register: 0x401f861c,
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN07",
+            register: 0x401f8620,
+            pad: crate::PadLocation {
+                mux: 0x401f8028,
+                pad: 0x401f8218,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN07",
+            register: 0x401f8620,
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN08",
+            register: 0x401f8624,
+            pad: crate::PadLocation {
+                mux: 0x401f802c,
+                pad: 0x401f821c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN08",
+            register: 0x401f8624,
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN09",
+            register: 0x401f8628,
+            pad: crate::PadLocation {
+                mux: 0x401f8030,
+                pad: 0x401f8220,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN09",
+            register: 0x401f8628,
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN17",
+            register: 0x401f862c,
+            pad: crate::PadLocation {
+                mux: 0x401f8034,
+                pad: 0x401f8224,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN17",
+            register: 0x401f862c,
+            pad: crate::PadLocation {
+                mux: 0x401f80c8,
+                pad: 0x401f82b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN17",
+            register: 0x401f862c,
+            pad: crate::PadLocation {
+                mux: 0x401f80d0,
+                pad: 0x401f82c0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "XBAR1_IN17",
+            register: 0x401f862c,
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "XBAR1_IN18",
+            register: 0x401f8630,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN18",
+            register: 0x401f8630,
+            pad: crate::PadLocation {
+                mux: 0x401f80d4,
+                pad: 0x401f82c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN20",
+            register: 0x401f8634,
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN20",
+            register: 0x401f8634,
+            pad: crate::PadLocation {
+                mux: 0x401f80dc,
+                pad: 0x401f82cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN22",
+            register: 0x401f8638,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN22",
+            register: 0x401f8638,
+            pad: crate::PadLocation {
+                mux: 0x401f80e4,
+                pad: 0x401f82d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN23",
+            register: 0x401f863c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN23",
+            register: 0x401f863c,
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN24",
+            register: 0x401f8640,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN24",
+            register: 0x401f8640,
+            pad: crate::PadLocation {
+                mux: 0x401f80f4,
+                pad: 0x401f82e4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN14",
+            register: 0x401f8644,
+            pad: crate::PadLocation {
+                mux: 0x401f80bc,
+                pad: 0x401f82ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN14",
+            register: 0x401f8644,
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN15",
+            register: 0x401f8648,
+            pad: crate::PadLocation {
+                mux: 0x401f80c0,
+                pad: 0x401f82b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN15",
+            register: 0x401f8648,
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN16",
+            register: 0x401f864c,
+            pad: crate::PadLocation {
+                mux: 0x401f80c4,
+                pad: 0x401f82b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN16",
+            register: 0x401f864c,
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN25",
+            register: 0x401f8650,
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN25",
+            register: 0x401f8650,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN19",
+            register: 0x401f8654,
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN19",
+            register: 0x401f8654,
+            pad: crate::PadLocation {
+                mux: 0x401f80d8,
+                pad: 0x401f82c8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN21",
+            register: 0x401f8658,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN21",
+            register: 0x401f8658,
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPG_CLK_RMII",
+            register: 0x401f870c,
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPG_CLK_RMII",
+            register: 0x401f870c,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPG_CLK_RMII",
+            register: 0x401f870c,
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_MDIO",
+            register: 0x401f8710,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_MDIO",
+            register: 0x401f8710,
+            pad: crate::PadLocation {
+                mux: 0x401f8140,
+                pad: 0x401f8330,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_0",
+            register: 0x401f8714,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_0",
+            register: 0x401f8714,
+            pad: crate::PadLocation {
+                mux: 0x401f81c8,
+                pad: 0x401f83b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_0",
+            register: 0x401f8714,
+            pad: crate::PadLocation {
+                mux: 0x401f8180,
+                pad: 0x401f8370,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_1",
+            register: 0x401f8718,
+            pad: crate::PadLocation {
+                mux: 0x401f80a4,
+                pad: 0x401f8294,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_1",
+            register: 0x401f8718,
+            pad: crate::PadLocation {
+                mux: 0x401f81cc,
+                pad: 0x401f83bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXDATA_1",
+            register: 0x401f8718,
+            pad: crate::PadLocation {
+                mux: 0x401f8184,
+                pad: 0x401f8374,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXEN",
+            register: 0x401f871c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXEN",
+            register: 0x401f871c,
+            pad: crate::PadLocation {
+                mux: 0x401f81d0,
+                pad: 0x401f83c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXEN",
+            register: 0x401f871c,
+            pad: crate::PadLocation {
+                mux: 0x401f8188,
+                pad: 0x401f8378,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXERR",
+            register: 0x401f8720,
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXERR",
+            register: 0x401f8720,
+            pad: crate::PadLocation {
+                mux: 0x401f81c4,
+                pad: 0x401f83b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_RXERR",
+            register: 0x401f8720,
+            pad: crate::PadLocation {
+                mux: 0x401f817c,
+                pad: 0x401f836c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_TIMER_0",
+            register: 0x401f8724,
+            pad: crate::PadLocation {
+                mux: 0x401f8100,
+                pad: 0x401f82f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_TIMER_0",
+            register: 0x401f8724,
+            pad: crate::PadLocation {
+                mux: 0x401f8148,
+                pad: 0x401f8338,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_TXCLK",
+            register: 0x401f8728,
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_TXCLK",
+            register: 0x401f8728,
+            pad: crate::PadLocation {
+                mux: 0x401f81c0,
+                pad: 0x401f83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET2_IPP_IND_MAC0_TXCLK",
+            register: 0x401f8728,
+            pad: crate::PadLocation {
+                mux: 0x401f8178,
+                pad: 0x401f8368,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_DQS_FA",
+            register: 0x401f872c,
+            pad: crate::PadLocation {
+                mux: 0x401f8694,
+                pad: 0x401f86ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_DQS_FA",
+            register: 0x401f872c,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_DQS_FA",
+            register: 0x401f872c,
+            pad: crate::PadLocation {
+                mux: 0x401f8680,
+                pad: 0x401f86d8,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT0",
+            register: 0x401f8730,
+            pad: crate::PadLocation {
+                mux: 0x401f86a4,
+                pad: 0x401f86fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT0",
+            register: 0x401f8730,
+            pad: crate::PadLocation {
+                mux: 0x401f807c,
+                pad: 0x401f826c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT0",
+            register: 0x401f8730,
+            pad: crate::PadLocation {
+                mux: 0x401f8664,
+                pad: 0x401f86bc,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT1",
+            register: 0x401f8734,
+            pad: crate::PadLocation {
+                mux: 0x401f86a0,
+                pad: 0x401f86f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT1",
+            register: 0x401f8734,
+            pad: crate::PadLocation {
+                mux: 0x401f8080,
+                pad: 0x401f8270,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT1",
+            register: 0x401f8734,
+            pad: crate::PadLocation {
+                mux: 0x401f868c,
+                pad: 0x401f86e4,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT2",
+            register: 0x401f8738,
+            pad: crate::PadLocation {
+                mux: 0x401f869c,
+                pad: 0x401f86f4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT2",
+            register: 0x401f8738,
+            pad: crate::PadLocation {
+                mux: 0x401f8084,
+                pad: 0x401f8274,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT2",
+            register: 0x401f8738,
+            pad: crate::PadLocation {
+                mux: 0x401f8674,
+                pad: 0x401f86cc,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT3",
+            register: 0x401f873c,
+            pad: crate::PadLocation {
+                mux: 0x401f8698,
+                pad: 0x401f86f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT3",
+            register: 0x401f873c,
+            pad: crate::PadLocation {
+                mux: 0x401f8088,
+                pad: 0x401f8278,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FA_BIT3",
+            register: 0x401f873c,
+            pad: crate::PadLocation {
+                mux: 0x401f8684,
+                pad: 0x401f86dc,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT0",
+            register: 0x401f8740,
+            pad: crate::PadLocation {
+                mux: 0x401f8048,
+                pad: 0x401f8238,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT0",
+            register: 0x401f8740,
+            pad: crate::PadLocation {
+                mux: 0x401f8688,
+                pad: 0x401f86e0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT1",
+            register: 0x401f8744,
+            pad: crate::PadLocation {
+                mux: 0x401f804c,
+                pad: 0x401f823c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT1",
+            register: 0x401f8744,
+            pad: crate::PadLocation {
+                mux: 0x401f8678,
+                pad: 0x401f86d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT2",
+            register: 0x401f8748,
+            pad: crate::PadLocation {
+                mux: 0x401f8050,
+                pad: 0x401f8240,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT2",
+            register: 0x401f8748,
+            pad: crate::PadLocation {
+                mux: 0x401f8668,
+                pad: 0x401f86c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT3",
+            register: 0x401f874c,
+            pad: crate::PadLocation {
+                mux: 0x401f8054,
+                pad: 0x401f8244,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_IO_FB_BIT3",
+            register: 0x401f874c,
+            pad: crate::PadLocation {
+                mux: 0x401f866c,
+                pad: 0x401f86c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_SCK_FA",
+            register: 0x401f8750,
+            pad: crate::PadLocation {
+                mux: 0x401f86a8,
+                pad: 0x401f8700,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_SCK_FA",
+            register: 0x401f8750,
+            pad: crate::PadLocation {
+                mux: 0x401f8078,
+                pad: 0x401f8268,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_SCK_FA",
+            register: 0x401f8750,
+            pad: crate::PadLocation {
+                mux: 0x401f867c,
+                pad: 0x401f86d4,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_SCK_FB",
+            register: 0x401f8754,
+            pad: crate::PadLocation {
+                mux: 0x401f8044,
+                pad: 0x401f8234,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_IPP_IND_SCK_FB",
+            register: 0x401f8754,
+            pad: crate::PadLocation {
+                mux: 0x401f8660,
+                pad: 0x401f86b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CAPIN1",
+            register: 0x401f8758,
+            pad: crate::PadLocation {
+                mux: 0x401f8074,
+                pad: 0x401f8264,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CAPIN1",
+            register: 0x401f8758,
+            pad: crate::PadLocation {
+                mux: 0x401f8190,
+                pad: 0x401f8380,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CAPIN2",
+            register: 0x401f875c,
+            pad: crate::PadLocation {
+                mux: 0x401f8070,
+                pad: 0x401f8260,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CAPIN2",
+            register: 0x401f875c,
+            pad: crate::PadLocation {
+                mux: 0x401f8194,
+                pad: 0x401f8384,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CLKIN",
+            register: 0x401f8760,
+            pad: crate::PadLocation {
+                mux: 0x401f80f0,
+                pad: 0x401f82e0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT1_IPP_IND_CLKIN",
+            register: 0x401f8760,
+            pad: crate::PadLocation {
+                mux: 0x401f818c,
+                pad: 0x401f837c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CAPIN1",
+            register: 0x401f8764,
+            pad: crate::PadLocation {
+                mux: 0x401f80b8,
+                pad: 0x401f82a8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CAPIN1",
+            register: 0x401f8764,
+            pad: crate::PadLocation {
+                mux: 0x401f8108,
+                pad: 0x401f82f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CAPIN2",
+            register: 0x401f8768,
+            pad: crate::PadLocation {
+                mux: 0x401f80b4,
+                pad: 0x401f82a4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CAPIN2",
+            register: 0x401f8768,
+            pad: crate::PadLocation {
+                mux: 0x401f810c,
+                pad: 0x401f82fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CLKIN",
+            register: 0x401f876c,
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT2_IPP_IND_CLKIN",
+            register: 0x401f876c,
+            pad: crate::PadLocation {
+                mux: 0x401f8104,
+                pad: 0x401f82f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPG_CLK_SAI_MCLK_2",
+            register: 0x401f8770,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPG_CLK_SAI_MCLK_2",
+            register: 0x401f8770,
+            pad: crate::PadLocation {
+                mux: 0x401f81e4,
+                pad: 0x401f83d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXBCLK",
+            register: 0x401f8774,
+            pad: crate::PadLocation {
+                mux: 0x401f80a0,
+                pad: 0x401f8290,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXBCLK",
+            register: 0x401f8774,
+            pad: crate::PadLocation {
+                mux: 0x401f81ec,
+                pad: 0x401f83dc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXDATA_0",
+            register: 0x401f8778,
+            pad: crate::PadLocation {
+                mux: 0x401f8098,
+                pad: 0x401f8288,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXDATA_0",
+            register: 0x401f8778,
+            pad: crate::PadLocation {
+                mux: 0x401f81d4,
+                pad: 0x401f83c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXSYNC",
+            register: 0x401f877c,
+            pad: crate::PadLocation {
+                mux: 0x401f809c,
+                pad: 0x401f828c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_RXSYNC",
+            register: 0x401f877c,
+            pad: crate::PadLocation {
+                mux: 0x401f81e8,
+                pad: 0x401f83d8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_TXBCLK",
+            register: 0x401f8780,
+            pad: crate::PadLocation {
+                mux: 0x401f80ac,
+                pad: 0x401f829c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_TXBCLK",
+            register: 0x401f8780,
+            pad: crate::PadLocation {
+                mux: 0x401f81e0,
+                pad: 0x401f83d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_TXSYNC",
+            register: 0x401f8784,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI3_IPP_IND_SAI_TXSYNC",
+            register: 0x401f8784,
+            pad: crate::PadLocation {
+                mux: 0x401f81dc,
+                pad: 0x401f83cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SEMC_I_IPP_IND_DQS4",
+            register: 0x401f8788,
+            pad: crate::PadLocation {
+                mux: 0x401f81bc,
+                pad: 0x401f83ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SEMC_I_IPP_IND_DQS4",
+            register: 0x401f8788,
+            pad: crate::PadLocation {
+                mux: 0x401f80b0,
+                pad: 0x401f82a0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SEMC_I_IPP_IND_DQS4",
+            register: 0x401f8788,
+            pad: crate::PadLocation {
+                mux: 0x401f80e0,
+                pad: 0x401f82d0,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "SEMC_I_IPP_IND_DQS4",
+            register: 0x401f8788,
+            pad: crate::PadLocation {
+                mux: 0x401f81b0,
+                pad: 0x401f83a0,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "CANFD_IPP_IND_CANRX",
+            register: 0x401f878c,
+            pad: crate::PadLocation {
+                mux: 0x401f80a8,
+                pad: 0x401f8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CANFD_IPP_IND_CANRX",
+            register: 0x401f878c,
+            pad: crate::PadLocation {
+                mux: 0x401f80f8,
+                pad: 0x401f82e8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CANFD_IPP_IND_CANRX",
+            register: 0x401f878c,
+            pad: crate::PadLocation {
+                mux: 0x401f80e8,
+                pad: 0x401f82d8,
+            },
+            value: 2,
+        },
+    ],
+};
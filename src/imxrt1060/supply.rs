@@ -0,0 +1,96 @@
+//! Pad supply groups
+//!
+//! Each 1060 pad group is powered by one NVCC supply rail. A rail may be
+//! 3.3V or 1.8V, depending on the board, and the pads' drive settings
+//! behave differently at each voltage. Use [`Supply`] to find the rail that
+//! powers a pad, then compare it with your board's rail voltages.
+//!
+//! ```
+//! use imxrt_iomuxc::imxrt1060::{gpio_sd_b0::GPIO_SD_B0_00, supply::Supply};
+//!
+//! const CMD: Option<Supply> = Supply::of_location(GPIO_SD_B0_00::LOCATION);
+//! assert_eq!(CMD, Some(Supply::Sd0));
+//! ```
+
+use crate::{Iomuxc, PadLocation};
+
+/// An NVCC supply rail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Supply {
+    /// `NVCC_EMC`; the `GPIO_EMC` pads
+    Emc,
+    /// `NVCC_GPIO`; the `GPIO_AD_B0`, `GPIO_AD_B1`, `GPIO_B0`, and `GPIO_B1` pads
+    Gpio,
+    /// `NVCC_SD0`; the `GPIO_SD_B0` pads
+    Sd0,
+    /// `NVCC_SD1`; the `GPIO_SD_B1` pads
+    Sd1,
+    /// `VDD_SNVS_IN`; the `SNVS` pads
+    Snvs,
+}
+
+/// The first and last MUX register of each group, and the group's supply
+const GROUPS: &[(u32, u32, Supply)] = &[
+    // GPIO_EMC_00 through GPIO_EMC_41
+    (0x401F_8014, 0x401F_80B8, Supply::Emc),
+    // GPIO_AD_B0_00 through GPIO_B1_15
+    (0x401F_80BC, 0x401F_81B8, Supply::Gpio),
+    // GPIO_SD_B0_00 through GPIO_SD_B0_05
+    (0x401F_81BC, 0x401F_81D0, Supply::Sd0),
+    // GPIO_SD_B1_00 through GPIO_SD_B1_11
+    (0x401F_81D4, 0x401F_8200, Supply::Sd1),
+    // WAKEUP through PMIC_STBY_REQ
+    (0x400A_8000, 0x400A_8008, Supply::Snvs),
+];
+
+impl Supply {
+    /// Returns the supply rail of the pad at `location`
+    ///
+    /// Returns `None` if `location` isn't a 1060 pad, or if it's one of the
+    /// 1064's `GPIO_SPI_B0` and `GPIO_SPI_B1` pads, which connect the
+    /// in-package flash.
+    pub const fn of_location(location: PadLocation) -> Option<Self> {
+        let mut idx = 0;
+        while idx < GROUPS.len() {
+            let (first, last, supply) = GROUPS[idx];
+            if first <= location.mux && location.mux <= last {
+                return Some(supply);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    /// Returns the supply rail of the pad
+    ///
+    /// See [`of_location()`](Self::of_location).
+    pub fn of<I: Iomuxc>(pad: &mut I) -> Option<Self> {
+        Self::of_location(PadLocation {
+            mux: pad.mux() as u32,
+            pad: pad.pad() as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Supply;
+    use crate::PadLocation;
+
+    #[test]
+    fn pad_groups() {
+        let supply = |mux| Supply::of_location(PadLocation { mux, pad: 0 });
+        // GPIO_EMC_41, GPIO_AD_B0_00, GPIO_B1_15
+        assert_eq!(supply(0x401F_80B8), Some(Supply::Emc));
+        assert_eq!(supply(0x401F_80BC), Some(Supply::Gpio));
+        assert_eq!(supply(0x401F_81B8), Some(Supply::Gpio));
+        // GPIO_SD_B0_05, GPIO_SD_B1_00
+        assert_eq!(supply(0x401F_81D0), Some(Supply::Sd0));
+        assert_eq!(supply(0x401F_81D4), Some(Supply::Sd1));
+        // WAKEUP, PMIC_STBY_REQ
+        assert_eq!(supply(0x400A_8000), Some(Supply::Snvs));
+        assert_eq!(supply(0x400A_8008), Some(Supply::Snvs));
+        // GPIO_SPI_B0_00
+        assert_eq!(supply(0x401F_865C), None);
+    }
+}
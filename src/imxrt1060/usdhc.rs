@@ -1,19 +1,31 @@
 //! uSDHC pin implementations
 
+#![cfg_attr(not(feature = "imxrt1060"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
 use super::gpio_sd_b0::*;
-use crate::{config::PullKeeper::*, consts::*, usdhc::*, Daisy};
+use crate::{config::PullKeeper::*, usdhc::*, Daisy};
 
 //
 // uSDHC1
 //
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_00, signal: Cmd, keeper: Some(Pullup100k), daisy: None);
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_01, signal: Clk, keeper: Some(Keeper), daisy: None);
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_02, signal: Data0, keeper: Some(Pullup100k), daisy: None);
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_03, signal: Data1, keeper: Some(Pullup100k), daisy: None);
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_04, signal: Data2, keeper: Some(Pullup100k), daisy: None);
-usdhc!(module: U1, alt: 0, pad: GPIO_SD_B0_05, signal: Data3, keeper: Some(Pulldown100k), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_00, signal: Cmd, keeper: Some(Pullup100k), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_01, signal: Clk, keeper: Some(Keeper), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_02, signal: Data0, keeper: Some(Pullup100k), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_03, signal: Data1, keeper: Some(Pullup100k), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_04, signal: Data2, keeper: Some(Pullup100k), daisy: None);
+#[cfg(feature = "imxrt1060-gpio-sd-b0")]
+usdhc!(module: 1, alt: 0, pad: GPIO_SD_B0_05, signal: Data3, keeper: Some(Pulldown100k), daisy: None);
 
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
     use super::Daisy;
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[];
 }
@@ -0,0 +1,244 @@
+//! Pad ownership for the 1170's two cores
+//!
+//! When the CM7 and CM4 run separate images, both images can reach every pad.
+//! If both images configure the same pad, the last write wins. Describe which core
+//! owns each pad group with one const [`Assignment`], shared by both images. Then,
+//! [`split_for_cores()`](super::Pads::split_for_cores) divides the chip's pads into
+//! two disjoint [`CorePads`]. Each image keeps its own core's pads, and drops the
+//! other's.
+//!
+//! The core is part of the `CorePads` type, so a function can require pads that
+//! belong to one core.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::imxrt1170::{cores::{Assignment, Cm4, CorePads, Group}, Pads};
+//!
+//! /// Shared by both images.
+//! const ASSIGNMENT: Assignment = Assignment::new().assign_cm4(Group::GpioLpsr);
+//!
+//! fn cm4_main(pads: CorePads<Cm4>) {
+//!     let lpsr = pads.gpio_lpsr.unwrap();
+//!     // ...
+//! }
+//!
+//! let (_cm7, cm4) = unsafe { Pads::new() }.split_for_cores(&ASSIGNMENT);
+//! cm4_main(cm4);
+//! ```
+
+use super::pads::*;
+use core::marker::PhantomData;
+
+/// A core of the 1170
+pub trait Core: private::Sealed {
+    /// The core's identifier
+    const ID: CoreId;
+}
+
+/// The Cortex-M7 core
+#[derive(Debug)]
+pub enum Cm7 {}
+
+/// The Cortex-M4 core
+#[derive(Debug)]
+pub enum Cm4 {}
+
+impl Core for Cm7 {
+    const ID: CoreId = CoreId::Cm7;
+}
+
+impl Core for Cm4 {
+    const ID: CoreId = CoreId::Cm4;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Cm7 {}
+    impl Sealed for super::Cm4 {}
+}
+
+/// Identifies a core at run time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreId {
+    /// The Cortex-M7 core
+    Cm7,
+    /// The Cortex-M4 core
+    Cm4,
+}
+
+/// A pad group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Group {
+    /// The `GPIO_EMC_B1` pads
+    GpioEmcB1,
+    /// The `GPIO_EMC_B2` pads
+    GpioEmcB2,
+    /// The `GPIO_AD` pads
+    GpioAd,
+    /// The `GPIO_SD_B1` pads
+    GpioSdB1,
+    /// The `GPIO_SD_B2` pads
+    GpioSdB2,
+    /// The `GPIO_DISP_B1` pads
+    GpioDispB1,
+    /// The `GPIO_DISP_B2` pads
+    GpioDispB2,
+    /// The `GPIO_LPSR` pads
+    GpioLpsr,
+    /// The `SNVS` pads
+    Snvs,
+    /// The `GPIO_SNVS` pads
+    GpioSnvs,
+}
+
+/// The pad groups that each core owns
+///
+/// By default, the CM7 owns every group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    /// One bit per `Group`; set if the CM4 owns the group.
+    cm4: u16,
+}
+
+impl Default for Assignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Assignment {
+    /// An assignment that gives every group to the CM7
+    pub const fn new() -> Self {
+        Self { cm4: 0 }
+    }
+
+    /// Give `group` to the CM4
+    pub const fn assign_cm4(mut self, group: Group) -> Self {
+        self.cm4 |= 1 << group as u16;
+        self
+    }
+
+    /// Give `group` to the CM7
+    pub const fn assign_cm7(mut self, group: Group) -> Self {
+        self.cm4 &= !(1 << group as u16);
+        self
+    }
+
+    /// Returns the core that owns `group`
+    pub const fn owner(&self, group: Group) -> CoreId {
+        if self.cm4 & (1 << group as u16) != 0 {
+            CoreId::Cm4
+        } else {
+            CoreId::Cm7
+        }
+    }
+}
+
+/// The pad groups owned by core `C`
+///
+/// A group is `Some` if `C` owns the group, and `None` if the other core owns it.
+pub struct CorePads<C: Core> {
+    #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+    pub gpio_emc_b1: Option<gpio_emc_b1::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+    pub gpio_emc_b2: Option<gpio_emc_b2::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-ad")]
+    pub gpio_ad: Option<gpio_ad::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+    pub gpio_sd_b1: Option<gpio_sd_b1::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+    pub gpio_sd_b2: Option<gpio_sd_b2::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+    pub gpio_disp_b1: Option<gpio_disp_b1::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+    pub gpio_disp_b2: Option<gpio_disp_b2::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-lpsr")]
+    pub gpio_lpsr: Option<gpio_lpsr::Pads>,
+    #[cfg(feature = "imxrt1170-snvs")]
+    pub snvs: Option<snvs::Pads>,
+    #[cfg(feature = "imxrt1170-gpio-snvs")]
+    pub gpio_snvs: Option<gpio_snvs::Pads>,
+    _core: PhantomData<C>,
+}
+
+impl Pads {
+    /// Split the pads into the CM7's pads and the CM4's pads
+    ///
+    /// Each group goes to the core that owns it in `assignment`.
+    pub fn split_for_cores(self, assignment: &Assignment) -> (CorePads<Cm7>, CorePads<Cm4>) {
+        fn split<T>(pads: T, owner: CoreId) -> (Option<T>, Option<T>) {
+            match owner {
+                CoreId::Cm7 => (Some(pads), None),
+                CoreId::Cm4 => (None, Some(pads)),
+            }
+        }
+        #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+        let gpio_emc_b1 = split(self.gpio_emc_b1, assignment.owner(Group::GpioEmcB1));
+        #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+        let gpio_emc_b2 = split(self.gpio_emc_b2, assignment.owner(Group::GpioEmcB2));
+        #[cfg(feature = "imxrt1170-gpio-ad")]
+        let gpio_ad = split(self.gpio_ad, assignment.owner(Group::GpioAd));
+        #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+        let gpio_sd_b1 = split(self.gpio_sd_b1, assignment.owner(Group::GpioSdB1));
+        #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+        let gpio_sd_b2 = split(self.gpio_sd_b2, assignment.owner(Group::GpioSdB2));
+        #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+        let gpio_disp_b1 = split(self.gpio_disp_b1, assignment.owner(Group::GpioDispB1));
+        #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+        let gpio_disp_b2 = split(self.gpio_disp_b2, assignment.owner(Group::GpioDispB2));
+        #[cfg(feature = "imxrt1170-gpio-lpsr")]
+        let gpio_lpsr = split(self.gpio_lpsr, assignment.owner(Group::GpioLpsr));
+        #[cfg(feature = "imxrt1170-snvs")]
+        let snvs = split(self.snvs, assignment.owner(Group::Snvs));
+        #[cfg(feature = "imxrt1170-gpio-snvs")]
+        let gpio_snvs = split(self.gpio_snvs, assignment.owner(Group::GpioSnvs));
+        (
+            CorePads {
+                #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+                gpio_emc_b1: gpio_emc_b1.0,
+                #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+                gpio_emc_b2: gpio_emc_b2.0,
+                #[cfg(feature = "imxrt1170-gpio-ad")]
+                gpio_ad: gpio_ad.0,
+                #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+                gpio_sd_b1: gpio_sd_b1.0,
+                #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+                gpio_sd_b2: gpio_sd_b2.0,
+                #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+                gpio_disp_b1: gpio_disp_b1.0,
+                #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+                gpio_disp_b2: gpio_disp_b2.0,
+                #[cfg(feature = "imxrt1170-gpio-lpsr")]
+                gpio_lpsr: gpio_lpsr.0,
+                #[cfg(feature = "imxrt1170-snvs")]
+                snvs: snvs.0,
+                #[cfg(feature = "imxrt1170-gpio-snvs")]
+                gpio_snvs: gpio_snvs.0,
+                _core: PhantomData,
+            },
+            CorePads {
+                #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+                gpio_emc_b1: gpio_emc_b1.1,
+                #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+                gpio_emc_b2: gpio_emc_b2.1,
+                #[cfg(feature = "imxrt1170-gpio-ad")]
+                gpio_ad: gpio_ad.1,
+                #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+                gpio_sd_b1: gpio_sd_b1.1,
+                #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+                gpio_sd_b2: gpio_sd_b2.1,
+                #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+                gpio_disp_b1: gpio_disp_b1.1,
+                #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+                gpio_disp_b2: gpio_disp_b2.1,
+                #[cfg(feature = "imxrt1170-gpio-lpsr")]
+                gpio_lpsr: gpio_lpsr.1,
+                #[cfg(feature = "imxrt1170-snvs")]
+                snvs: snvs.1,
+                #[cfg(feature = "imxrt1170-gpio-snvs")]
+                gpio_snvs: gpio_snvs.1,
+                _core: PhantomData,
+            },
+        )
+    }
+}
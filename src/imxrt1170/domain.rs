@@ -0,0 +1,130 @@
+//! Resource domain checks for dual-core systems
+//!
+//! On the 1170, the XRDC2 assigns each peripheral to resource domains. The
+//! IOMUXC, IOMUXC_LPSR, and IOMUXC_SNVS are separate XRDC2 peripherals, so a core might
+//! have access to one, but not the others. Accessing a register block that's
+//! not assigned to the core's domain results in a bus fault.
+//!
+//! Describe your core's assignments with a [`Domain`]. Then, use
+//! [`check()`] or [`try_configure()`] to report a [`DomainViolation`] before
+//! touching the pad.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{Config, SlewRate};
+//! use imxrt_iomuxc::imxrt1170::{domain::{self, Domain, Instance}, gpio_lpsr::GPIO_LPSR_04};
+//!
+//! // This core's XRDC2 setup only allows access to the IOMUXC_LPSR.
+//! const CM4: Domain = Domain::new().allow(Instance::IomuxcLpsr);
+//!
+//! let mut pad = unsafe { GPIO_LPSR_04::new() };
+//! domain::try_configure(&CM4, &mut pad, Config::modify().set_slew_rate(SlewRate::Fast)).unwrap();
+//! ```
+
+use crate::{Config, Iomuxc};
+use core::ops::Range;
+
+/// IOMUXC register addresses.
+const IOMUXC: Range<u32> = 0x400E_8000..0x400E_C000;
+/// IOMUXC_LPSR register addresses.
+const IOMUXC_LPSR: Range<u32> = 0x40C0_8000..0x40C0_C000;
+/// IOMUXC_SNVS register addresses.
+const IOMUXC_SNVS: Range<u32> = 0x40C9_4000..0x40C9_8000;
+
+/// An IOMUXC register block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instance {
+    /// The IOMUXC
+    Iomuxc,
+    /// The IOMUXC_LPSR; used by the `GPIO_LPSR` pads
+    IomuxcLpsr,
+    /// The IOMUXC_SNVS; used by the `SNVS` and `GPIO_SNVS` pads
+    IomuxcSnvs,
+}
+
+impl Instance {
+    /// Returns the register block that contains the pad
+    ///
+    /// Returns `None` if the pad's registers are not in an IOMUXC.
+    pub fn of<I: Iomuxc>(pad: &mut I) -> Option<Self> {
+        let mux = pad.mux() as u32;
+        if IOMUXC.contains(&mux) {
+            Some(Instance::Iomuxc)
+        } else if IOMUXC_LPSR.contains(&mux) {
+            Some(Instance::IomuxcLpsr)
+        } else if IOMUXC_SNVS.contains(&mux) {
+            Some(Instance::IomuxcSnvs)
+        } else {
+            None
+        }
+    }
+}
+
+/// The IOMUXC register blocks that a resource domain may access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Domain {
+    iomuxc: bool,
+    iomuxc_lpsr: bool,
+    iomuxc_snvs: bool,
+}
+
+impl Domain {
+    /// A domain without access to any IOMUXC register block
+    pub const fn new() -> Self {
+        Self {
+            iomuxc: false,
+            iomuxc_lpsr: false,
+            iomuxc_snvs: false,
+        }
+    }
+    /// Allow access to the `instance`
+    pub const fn allow(mut self, instance: Instance) -> Self {
+        match instance {
+            Instance::Iomuxc => self.iomuxc = true,
+            Instance::IomuxcLpsr => self.iomuxc_lpsr = true,
+            Instance::IomuxcSnvs => self.iomuxc_snvs = true,
+        }
+        self
+    }
+    /// Returns `true` if the domain may access the `instance`
+    pub const fn allows(&self, instance: Instance) -> bool {
+        match instance {
+            Instance::Iomuxc => self.iomuxc,
+            Instance::IomuxcLpsr => self.iomuxc_lpsr,
+            Instance::IomuxcSnvs => self.iomuxc_snvs,
+        }
+    }
+}
+
+impl Default for Domain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The domain may not access the pad's register block
+///
+/// The value is the register block that holds the pad, or `None` if the
+/// pad's registers are not in an IOMUXC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainViolation(pub Option<Instance>);
+
+/// Check that the domain may access the pad's registers
+pub fn check<I: Iomuxc>(domain: &Domain, pad: &mut I) -> Result<(), DomainViolation> {
+    match Instance::of(pad) {
+        Some(instance) if domain.allows(instance) => Ok(()),
+        instance => Err(DomainViolation(instance)),
+    }
+}
+
+/// Apply the configuration if the domain may access the pad's registers
+///
+/// Otherwise, leave the pad untouched, and return the violation.
+pub fn try_configure<I: Iomuxc>(
+    domain: &Domain,
+    pad: &mut I,
+    config: Config,
+) -> Result<(), DomainViolation> {
+    check(domain, pad)?;
+    crate::configure(pad, config);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! FlexCAN pin implementations
+
+#![cfg_attr(not(feature = "imxrt1170"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+use super::pads::gpio_lpsr::*;
+use crate::{
+    flexcan::{Pin, Rx, Tx},
+    Daisy,
+};
+
+//
+// CAN3
+//
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 0, pad: GPIO_LPSR_00, signal: Tx, daisy: None);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 0, pad: GPIO_LPSR_01, signal: Rx, daisy: Some(DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_01));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 6, pad: GPIO_LPSR_06, signal: Tx, daisy: None);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 6, pad: GPIO_LPSR_07, signal: Rx, daisy: Some(DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_07));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 1, pad: GPIO_LPSR_08, signal: Tx, daisy: None);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+can!(module: 3, alt: 1, pad: GPIO_LPSR_09, signal: Rx, daisy: Some(DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_09));
+
+pub mod daisy {
+    #![allow(unused)]
+    use super::Daisy;
+
+    pub const DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_01: Daisy =
+        Daisy::new(0x40c08080 as *mut u32, 0);
+    pub const DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_07: Daisy =
+        Daisy::new(0x40c08080 as *mut u32, 1);
+    pub const DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_09: Daisy =
+        Daisy::new(0x40c08080 as *mut u32, 2);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_01,
+        DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_07,
+        DAISY_CAN3_IPP_IND_CANRX_SELECT_GPIO_LPSR_09,
+    ];
+}
+
+use daisy::*;
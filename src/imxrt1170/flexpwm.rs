@@ -1,12 +1,16 @@
 //! PWM implementation.
 
+#![cfg_attr(not(feature = "imxrt1170"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1170-gpio-ad")]
 use super::pads::gpio_ad::*;
-use crate::{
-    consts::*,
-    flexpwm::{Pin, A, B},
-};
+use crate::flexpwm::{Pin, A, B};
 
-pwm!(module: U1, submodule: U2, alt: 4, pad: GPIO_AD_04, output: A);
-pwm!(module: U1, submodule: U2, alt: 4, pad: GPIO_AD_05, output: B);
-pwm!(module: U2, submodule: U2, alt: 4, pad: GPIO_AD_28, output: A);
-pwm!(module: U2, submodule: U2, alt: 4, pad: GPIO_AD_29, output: B);
+#[cfg(feature = "imxrt1170-gpio-ad")]
+pwm!(module: 1, submodule: 2, alt: 4, pad: GPIO_AD_04, output: A);
+#[cfg(feature = "imxrt1170-gpio-ad")]
+pwm!(module: 1, submodule: 2, alt: 4, pad: GPIO_AD_05, output: B);
+#[cfg(feature = "imxrt1170-gpio-ad")]
+pwm!(module: 2, submodule: 2, alt: 4, pad: GPIO_AD_28, output: A);
+#[cfg(feature = "imxrt1170-gpio-ad")]
+pwm!(module: 2, submodule: 2, alt: 4, pad: GPIO_AD_29, output: B);
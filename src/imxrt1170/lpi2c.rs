@@ -1,8 +1,10 @@
 //! I2C pin implementations
 
+#![cfg_attr(not(feature = "imxrt1170"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
 use super::pads::gpio_lpsr::*;
 use crate::{
-    consts::*,
     lpi2c::{Pin, Scl, Sda},
     Daisy,
 };
@@ -10,10 +12,29 @@ use crate::{
 //
 // I2C5
 //
-i2c!(module: U5, alt: 0, pad: GPIO_LPSR_05, signal: Scl, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_05);
-i2c!(module: U5, alt: 0, pad: GPIO_LPSR_04, signal: Sda, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_04);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 5, alt: 0, pad: GPIO_LPSR_05, signal: Scl, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_05);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 5, alt: 0, pad: GPIO_LPSR_04, signal: Sda, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_04);
+
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 5, alt: 6, pad: GPIO_LPSR_09, signal: Scl, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_09);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 5, alt: 6, pad: GPIO_LPSR_08, signal: Sda, daisy: DAISY_LPI2C5_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_08);
+
+//
+// I2C6
+//
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 6, alt: 0, pad: GPIO_LPSR_07, signal: Scl, daisy: DAISY_LPI2C6_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_07);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 6, alt: 0, pad: GPIO_LPSR_06, signal: Sda, daisy: DAISY_LPI2C6_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_06);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 6, alt: 2, pad: GPIO_LPSR_11, signal: Scl, daisy: DAISY_LPI2C6_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_11);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+i2c!(module: 6, alt: 2, pad: GPIO_LPSR_10, signal: Sda, daisy: DAISY_LPI2C6_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_10);
 
-mod daisy {
+pub mod daisy {
     #![allow(unused)]
     use super::Daisy;
 
@@ -65,6 +86,34 @@ mod daisy {
         Daisy::new(0x40c08090 as *mut u32, 0);
     pub const DAISY_LPI2C6_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_10: Daisy =
         Daisy::new(0x40c08090 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPI2C1_LPI2C_SCL_SELECT_GPIO_AD_08,
+        DAISY_LPI2C1_LPI2C_SCL_SELECT_GPIO_AD_32,
+        DAISY_LPI2C1_LPI2C_SDA_SELECT_GPIO_AD_09,
+        DAISY_LPI2C1_LPI2C_SDA_SELECT_GPIO_AD_33,
+        DAISY_LPI2C2_LPI2C_SCL_SELECT_GPIO_EMC_B2_00,
+        DAISY_LPI2C2_LPI2C_SCL_SELECT_GPIO_AD_18,
+        DAISY_LPI2C2_LPI2C_SDA_SELECT_GPIO_EMC_B2_01,
+        DAISY_LPI2C2_LPI2C_SDA_SELECT_GPIO_AD_19,
+        DAISY_LPI2C3_LPI2C_SCL_SELECT_GPIO_DISP_B1_02,
+        DAISY_LPI2C3_LPI2C_SCL_SELECT_GPIO_DISP_B2_10,
+        DAISY_LPI2C3_LPI2C_SDA_SELECT_GPIO_DISP_B1_03,
+        DAISY_LPI2C3_LPI2C_SDA_SELECT_GPIO_DISP_B2_11,
+        DAISY_LPI2C4_LPI2C_SCL_SELECT_GPIO_AD_24,
+        DAISY_LPI2C4_LPI2C_SCL_SELECT_GPIO_DISP_B2_12,
+        DAISY_LPI2C4_LPI2C_SDA_SELECT_GPIO_AD_25,
+        DAISY_LPI2C4_LPI2C_SDA_SELECT_GPIO_DISP_B2_13,
+        DAISY_LPI2C5_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_05,
+        DAISY_LPI2C5_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_09,
+        DAISY_LPI2C5_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_04,
+        DAISY_LPI2C5_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_08,
+        DAISY_LPI2C6_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_07,
+        DAISY_LPI2C6_IPP_IND_LPI2C_SCL_SELECT_GPIO_LPSR_11,
+        DAISY_LPI2C6_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_06,
+        DAISY_LPI2C6_IPP_IND_LPI2C_SDA_SELECT_GPIO_LPSR_10,
+    ];
 }
 
 use daisy::*;
@@ -1,6 +1,10 @@
+#![cfg_attr(not(feature = "imxrt1170"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1170-gpio-ad")]
 use super::pads::gpio_ad::*;
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+use super::pads::gpio_lpsr::*;
 use crate::{
-    consts::*,
     lpspi::{Pcs0, Pin, Sck, Sdi, Sdo},
     Daisy,
 };
@@ -10,18 +14,70 @@ use crate::{
 //
 
 // PCS0
-spi!(module: U1, alt: 0, pad: GPIO_AD_29, signal: Pcs0, daisy: DAISY_LPSPI1_LPSPI_PCS_0_SELECT_GPIO_AD_29);
+#[cfg(feature = "imxrt1170-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_29, signal: Pcs0, daisy: DAISY_LPSPI1_LPSPI_PCS_0_SELECT_GPIO_AD_29);
+
+// SCK
+#[cfg(feature = "imxrt1170-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_28, signal: Sck, daisy: DAISY_LPSPI1_LPSPI_SCK_SELECT_GPIO_AD_28);
+
+// SDI
+#[cfg(feature = "imxrt1170-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_31, signal: Sdi, daisy: DAISY_LPSPI1_LPSPI_SDI_SELECT_GPIO_AD_31);
+
+// SDO
+#[cfg(feature = "imxrt1170-gpio-ad")]
+spi!(module: 1, alt: 0, pad: GPIO_AD_30, signal: Sdo, daisy: DAISY_LPSPI1_LPSPI_SDO_SELECT_GPIO_AD_30);
+
+//
+// SPI5
+//
+
+// PCS0
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 1, pad: GPIO_LPSR_03, signal: Pcs0, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_PCS_0_SELECT_GPIO_LPSR_03);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 8, pad: GPIO_LPSR_13, signal: Pcs0, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_PCS_0_SELECT_GPIO_LPSR_13);
 
 // SCK
-spi!(module: U1, alt: 0, pad: GPIO_AD_28, signal: Sck, daisy: DAISY_LPSPI1_LPSPI_SCK_SELECT_GPIO_AD_28);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 1, pad: GPIO_LPSR_02, signal: Sck, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_SCK_SELECT_GPIO_LPSR_02);
+// GPIO_LPSR_12 is also SPI5 SCK, at alt 8. It implements the SPI6 SDI instead.
 
 // SDI
-spi!(module: U1, alt: 0, pad: GPIO_AD_31, signal: Sdi, daisy: DAISY_LPSPI1_LPSPI_SDI_SELECT_GPIO_AD_31);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 1, pad: GPIO_LPSR_05, signal: Sdi, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_SDI_SELECT_GPIO_LPSR_05);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 8, pad: GPIO_LPSR_15, signal: Sdi, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_SDI_SELECT_GPIO_LPSR_15);
 
 // SDO
-spi!(module: U1, alt: 0, pad: GPIO_AD_30, signal: Sdo, daisy: DAISY_LPSPI1_LPSPI_SDO_SELECT_GPIO_AD_30);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 1, pad: GPIO_LPSR_04, signal: Sdo, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_SDO_SELECT_GPIO_LPSR_04);
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 5, alt: 8, pad: GPIO_LPSR_14, signal: Sdo, daisy: DAISY_LPSPI5_IPP_IND_LPSPI_SDO_SELECT_GPIO_LPSR_14);
 
-mod daisy {
+//
+// SPI6
+//
+// Each SPI6 signal has one pad, so SPI6 has no select inputs.
+
+// PCS0
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 6, alt: 4, pad: GPIO_LPSR_09, signal: Pcs0, daisy: None);
+
+// SCK
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 6, alt: 4, pad: GPIO_LPSR_10, signal: Sck, daisy: None);
+
+// SDI
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 6, alt: 4, pad: GPIO_LPSR_12, signal: Sdi, daisy: None);
+
+// SDO
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+spi!(module: 6, alt: 4, pad: GPIO_LPSR_11, signal: Sdo, daisy: None);
+
+pub mod daisy {
     #![allow(unused)]
     use super::Daisy;
 
@@ -121,6 +177,58 @@ mod daisy {
         Daisy::new(0x40c080a0 as *mut u32, 0);
     pub const DAISY_LPSPI5_IPP_IND_LPSPI_SDO_SELECT_GPIO_LPSR_14: Daisy =
         Daisy::new(0x40c080a0 as *mut u32, 1);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPSPI1_LPSPI_PCS_0_SELECT_GPIO_EMC_B2_01,
+        DAISY_LPSPI1_LPSPI_PCS_0_SELECT_GPIO_AD_29,
+        DAISY_LPSPI1_LPSPI_SCK_SELECT_GPIO_EMC_B2_00,
+        DAISY_LPSPI1_LPSPI_SCK_SELECT_GPIO_AD_28,
+        DAISY_LPSPI1_LPSPI_SDI_SELECT_GPIO_EMC_B2_03,
+        DAISY_LPSPI1_LPSPI_SDI_SELECT_GPIO_AD_31,
+        DAISY_LPSPI1_LPSPI_SDO_SELECT_GPIO_EMC_B2_02,
+        DAISY_LPSPI1_LPSPI_SDO_SELECT_GPIO_AD_30,
+        DAISY_LPSPI2_LPSPI_PCS_0_SELECT_GPIO_AD_25,
+        DAISY_LPSPI2_LPSPI_PCS_0_SELECT_GPIO_SD_B2_08,
+        DAISY_LPSPI2_LPSPI_PCS_1_SELECT_GPIO_AD_21,
+        DAISY_LPSPI2_LPSPI_PCS_1_SELECT_GPIO_SD_B2_11,
+        DAISY_LPSPI2_LPSPI_SCK_SELECT_GPIO_AD_24,
+        DAISY_LPSPI2_LPSPI_SCK_SELECT_GPIO_SD_B2_07,
+        DAISY_LPSPI2_LPSPI_SDI_SELECT_GPIO_AD_27,
+        DAISY_LPSPI2_LPSPI_SDI_SELECT_GPIO_SD_B2_10,
+        DAISY_LPSPI2_LPSPI_SDO_SELECT_GPIO_AD_26,
+        DAISY_LPSPI2_LPSPI_SDO_SELECT_GPIO_SD_B2_09,
+        DAISY_LPSPI3_LPSPI_PCS_0_SELECT_GPIO_EMC_B2_05,
+        DAISY_LPSPI3_LPSPI_PCS_0_SELECT_GPIO_DISP_B1_07,
+        DAISY_LPSPI3_LPSPI_PCS_1_SELECT_GPIO_EMC_B2_08,
+        DAISY_LPSPI3_LPSPI_PCS_1_SELECT_GPIO_DISP_B1_08,
+        DAISY_LPSPI3_LPSPI_PCS_2_SELECT_GPIO_EMC_B2_09,
+        DAISY_LPSPI3_LPSPI_PCS_2_SELECT_GPIO_DISP_B1_09,
+        DAISY_LPSPI3_LPSPI_PCS_3_SELECT_GPIO_EMC_B2_10,
+        DAISY_LPSPI3_LPSPI_PCS_3_SELECT_GPIO_DISP_B1_10,
+        DAISY_LPSPI3_LPSPI_SCK_SELECT_GPIO_EMC_B2_04,
+        DAISY_LPSPI3_LPSPI_SCK_SELECT_GPIO_DISP_B1_04,
+        DAISY_LPSPI3_LPSPI_SDI_SELECT_GPIO_EMC_B2_07,
+        DAISY_LPSPI3_LPSPI_SDI_SELECT_GPIO_DISP_B1_05,
+        DAISY_LPSPI3_LPSPI_SDO_SELECT_GPIO_EMC_B2_06,
+        DAISY_LPSPI3_LPSPI_SDO_SELECT_GPIO_DISP_B1_06,
+        DAISY_LPSPI4_LPSPI_PCS_0_SELECT_GPIO_SD_B2_01,
+        DAISY_LPSPI4_LPSPI_PCS_0_SELECT_GPIO_DISP_B2_15,
+        DAISY_LPSPI4_LPSPI_SCK_SELECT_GPIO_SD_B2_00,
+        DAISY_LPSPI4_LPSPI_SCK_SELECT_GPIO_DISP_B2_12,
+        DAISY_LPSPI4_LPSPI_SDI_SELECT_GPIO_SD_B2_03,
+        DAISY_LPSPI4_LPSPI_SDI_SELECT_GPIO_DISP_B2_13,
+        DAISY_LPSPI4_LPSPI_SDO_SELECT_GPIO_SD_B2_02,
+        DAISY_LPSPI4_LPSPI_SDO_SELECT_GPIO_DISP_B2_14,
+        DAISY_LPSPI5_IPP_IND_LPSPI_PCS_0_SELECT_GPIO_LPSR_03,
+        DAISY_LPSPI5_IPP_IND_LPSPI_PCS_0_SELECT_GPIO_LPSR_13,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SCK_SELECT_GPIO_LPSR_02,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SCK_SELECT_GPIO_LPSR_12,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SDI_SELECT_GPIO_LPSR_05,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SDI_SELECT_GPIO_LPSR_15,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SDO_SELECT_GPIO_LPSR_04,
+        DAISY_LPSPI5_IPP_IND_LPSPI_SDO_SELECT_GPIO_LPSR_14,
+    ];
 }
 
 use daisy::*;
@@ -1,6 +1,10 @@
+#![cfg_attr(not(feature = "imxrt1170"), allow(unused_imports, dead_code))]
+
+#[cfg(feature = "imxrt1170-gpio-ad")]
 use super::pads::gpio_ad::*;
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+use super::pads::gpio_lpsr::*;
 use crate::{
-    consts::*,
     lpuart::{Pin, Rx, Tx},
     Daisy,
 };
@@ -8,10 +12,44 @@ use crate::{
 //
 // UART1
 //
-uart!(module: U1, alt: 0, pad: GPIO_AD_24, direction: Tx, daisy: Some(DAISY_LPUART1_LPUART_TXD_SELECT_GPIO_AD_24));
-uart!(module: U1, alt: 0, pad: GPIO_AD_25, direction: Rx, daisy: Some(DAISY_LPUART1_LPUART_RXD_SELECT_GPIO_AD_25));
+#[cfg(feature = "imxrt1170-gpio-ad")]
+uart!(module: 1, alt: 0, pad: GPIO_AD_24, direction: Tx, daisy: Some(DAISY_LPUART1_LPUART_TXD_SELECT_GPIO_AD_24));
+#[cfg(feature = "imxrt1170-gpio-ad")]
+uart!(module: 1, alt: 0, pad: GPIO_AD_25, direction: Rx, daisy: Some(DAISY_LPUART1_LPUART_RXD_SELECT_GPIO_AD_25));
+
+//
+// UART11
+//
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 11, alt: 6, pad: GPIO_LPSR_04, direction: Tx, daisy: Some(DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_04));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 11, alt: 6, pad: GPIO_LPSR_05, direction: Rx, daisy: Some(DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_05));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 11, alt: 0, pad: GPIO_LPSR_08, direction: Tx, daisy: Some(DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_08));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 11, alt: 0, pad: GPIO_LPSR_09, direction: Rx, daisy: Some(DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_09));
+// GPIO_LPSR_11 is also UART11 RTS, at alt 1. It implements the UART12 RX
+// instead.
 
-mod daisy {
+//
+// UART12
+//
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 6, pad: GPIO_LPSR_00, direction: Tx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_00));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 6, pad: GPIO_LPSR_01, direction: Rx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_01));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 3, pad: GPIO_LPSR_06, direction: Tx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_06));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 3, pad: GPIO_LPSR_07, direction: Rx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_07));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 8, pad: GPIO_LPSR_10, direction: Tx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_10));
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+uart!(module: 12, alt: 8, pad: GPIO_LPSR_11, direction: Rx, daisy: Some(DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_11));
+// GPIO_LPSR_04 is also UART12 RTS, at alt 3. It implements the UART11 TX
+// instead.
+
+pub mod daisy {
     #![allow(unused)]
     use super::Daisy;
 
@@ -51,6 +89,58 @@ mod daisy {
         Daisy::new(0x400e8638 as *mut u32, 0);
     pub const DAISY_LPUART8_LPUART_TXD_SELECT_GPIO_DISP_B2_08: Daisy =
         Daisy::new(0x400e8638 as *mut u32, 1);
+    pub const DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_05: Daisy =
+        Daisy::new(0x40c080a4 as *mut u32, 0);
+    pub const DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_09: Daisy =
+        Daisy::new(0x40c080a4 as *mut u32, 1);
+    pub const DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_04: Daisy =
+        Daisy::new(0x40c080a8 as *mut u32, 0);
+    pub const DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_08: Daisy =
+        Daisy::new(0x40c080a8 as *mut u32, 1);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_01: Daisy =
+        Daisy::new(0x40c080ac as *mut u32, 0);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_07: Daisy =
+        Daisy::new(0x40c080ac as *mut u32, 1);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_11: Daisy =
+        Daisy::new(0x40c080ac as *mut u32, 2);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_00: Daisy =
+        Daisy::new(0x40c080b0 as *mut u32, 0);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_06: Daisy =
+        Daisy::new(0x40c080b0 as *mut u32, 1);
+    pub const DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_10: Daisy =
+        Daisy::new(0x40c080b0 as *mut u32, 2);
+
+    #[cfg(feature = "self-check")]
+    pub const ALL: &[Daisy] = &[
+        DAISY_LPUART1_LPUART_RXD_SELECT_GPIO_AD_25,
+        DAISY_LPUART1_LPUART_RXD_SELECT_GPIO_DISP_B1_03,
+        DAISY_LPUART1_LPUART_RXD_SELECT_GPIO_DISP_B2_09,
+        DAISY_LPUART1_LPUART_TXD_SELECT_GPIO_AD_24,
+        DAISY_LPUART1_LPUART_TXD_SELECT_GPIO_DISP_B1_02,
+        DAISY_LPUART1_LPUART_TXD_SELECT_GPIO_DISP_B2_08,
+        DAISY_LPUART10_LPUART_RXD_SELECT_GPIO_AD_16,
+        DAISY_LPUART10_LPUART_RXD_SELECT_GPIO_AD_33,
+        DAISY_LPUART10_LPUART_TXD_SELECT_GPIO_AD_15,
+        DAISY_LPUART10_LPUART_TXD_SELECT_GPIO_AD_32,
+        DAISY_LPUART7_LPUART_RXD_SELECT_GPIO_AD_01,
+        DAISY_LPUART7_LPUART_RXD_SELECT_GPIO_DISP_B2_07,
+        DAISY_LPUART7_LPUART_TXD_SELECT_GPIO_AD_00,
+        DAISY_LPUART7_LPUART_TXD_SELECT_GPIO_DISP_B2_06,
+        DAISY_LPUART8_LPUART_RXD_SELECT_GPIO_AD_03,
+        DAISY_LPUART8_LPUART_RXD_SELECT_GPIO_DISP_B2_09,
+        DAISY_LPUART8_LPUART_TXD_SELECT_GPIO_AD_02,
+        DAISY_LPUART8_LPUART_TXD_SELECT_GPIO_DISP_B2_08,
+        DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_05,
+        DAISY_LPUART11_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_09,
+        DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_04,
+        DAISY_LPUART11_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_08,
+        DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_01,
+        DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_07,
+        DAISY_LPUART12_IPP_IND_LPUART_RXD_SELECT_GPIO_LPSR_11,
+        DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_00,
+        DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_06,
+        DAISY_LPUART12_IPP_IND_LPUART_TXD_SELECT_GPIO_LPSR_10,
+    ];
 }
 
 use daisy::*;
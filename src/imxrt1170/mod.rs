@@ -3,16 +3,125 @@
 //! The module exports all of the i.MX RT 1170 processor's pads. Pads that can support
 //! peripheral functions are tagged with `imxrt-iomuxc` traits.
 
+pub mod cores;
+pub mod defaults;
+pub mod domain;
 mod pads;
+pub mod power;
 pub use pads::*;
+mod register_map;
+pub use register_map::REGISTER_MAP;
+mod signals;
+pub use signals::DATABASE;
+/// The length of a [`register_map::save()`](crate::register_map::save) snapshot
+pub const SNAPSHOT_LEN: usize = crate::register_map::snapshot_len(REGISTER_MAP, SNAPSHOT_GPRS);
+/// The IOMUXC_GPR registers that a [`register_map::save()`](crate::register_map::save)
+/// snapshot includes
+///
+/// This module doesn't describe the 1170's GPRs, so the list is empty.
+pub const SNAPSHOT_GPRS: &[u32] = &[];
 
+/// The FLEXSPI1 port A pads that the boot ROM reads the boot flash from
+///
+/// GPIO_SD_B2_05 through GPIO_SD_B2_11. See the [`xip`](crate::xip) module to guard them.
+pub const BOOT_FLASH: &[crate::PadLocation] = &[
+    // GPIO_SD_B2_05
+    crate::PadLocation {
+        mux: 0x400E_81C8,
+        pad: 0x400E_840C,
+    },
+    // GPIO_SD_B2_06
+    crate::PadLocation {
+        mux: 0x400E_81CC,
+        pad: 0x400E_8410,
+    },
+    // GPIO_SD_B2_07
+    crate::PadLocation {
+        mux: 0x400E_81D0,
+        pad: 0x400E_8414,
+    },
+    // GPIO_SD_B2_08
+    crate::PadLocation {
+        mux: 0x400E_81D4,
+        pad: 0x400E_8418,
+    },
+    // GPIO_SD_B2_09
+    crate::PadLocation {
+        mux: 0x400E_81D8,
+        pad: 0x400E_841C,
+    },
+    // GPIO_SD_B2_10
+    crate::PadLocation {
+        mux: 0x400E_81DC,
+        pad: 0x400E_8420,
+    },
+    // GPIO_SD_B2_11
+    crate::PadLocation {
+        mux: 0x400E_81E0,
+        pad: 0x400E_8424,
+    },
+];
+
+/// The FLEXSPI1 memory-mapped address range
+pub const FLEXSPI_MEMORY: core::ops::Range<u32> = 0x3000_0000..0x4000_0000;
+
+/// Route a pad to a peripheral input
+///
+/// Writes the daisy value that selects `pad` for `input`, using the chip's
+/// [`REGISTER_MAP`]. Fails, without writing, if the pad can't feed the input.
+/// See [`register_map::select_input()`](crate::register_map::select_input).
+pub fn select_input(
+    input: crate::register_map::InputSignal,
+    pad: &crate::ErasedPad,
+) -> Result<(), crate::register_map::RoutingError> {
+    // Safety: the map describes this chip, and the erased pad proves that
+    // we own the pad. The daisy register is shared, like it is for the
+    // peripheral prepare() functions.
+    unsafe { crate::register_map::select_input(REGISTER_MAP, input, pad) }
+}
+
+/// Daisy values for the chip's select inputs
+///
+/// Each peripheral module has a constant for every value of the peripheral's
+/// select input registers. The pin implementations use these values, so you
+/// can audit, or perform, a pin's input selection yourself.
+///
+/// To wire a peripheral that has no pin traits, generate its constants with
+/// `daisy.py`, like `daisy.py path/to/imxrt.svd ENET`.
+pub mod daisy {
+    pub use super::flexcan::daisy as flexcan;
+    pub use super::lpi2c::daisy as lpi2c;
+    pub use super::lpspi::daisy as lpspi;
+    pub use super::lpuart::daisy as lpuart;
+}
+
+/// The pads of the low-power, state retention (LPSR) domain
+///
+/// These `GPIO_LPSR` pads have registers in the IOMUXC_LPSR block, not in the
+/// IOMUXC. Otherwise, they're like any other pad: [`configure()`](fn@crate::configure),
+/// [`alternate()`](crate::alternate), and the peripheral `prepare()` functions
+/// accept them. Use [`domain::Instance`] to find a pad's register block.
+///
+/// Take the LPSR pads from the chip's [`Pads`]:
+///
+/// ```no_run
+/// use imxrt_iomuxc::imxrt1170::{LpsrPads, Pads};
+///
+/// let pads = unsafe { Pads::new() };
+/// let lpsr: LpsrPads = pads.gpio_lpsr;
+/// ```
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
+pub type LpsrPads = gpio_lpsr::Pads;
+
+mod flexcan;
 mod flexpwm;
 mod lpi2c;
 mod lpspi;
 mod lpuart;
 
+#[cfg(feature = "imxrt1170-gpio-emc-b1")]
 mod ccm {
-    pub use crate::ccm::{Function, Observable, Pin};
+    pub use crate::ccm::{Observable, Pin};
 
     impl Pin for super::pads::gpio_emc_b1::GPIO_EMC_B1_40 {
         const ALT: u32 = 9;
@@ -23,3 +132,55 @@ mod ccm {
         type Function = Observable<2>;
     }
 }
+
+/// Check the invariants of all pad and daisy definitions
+///
+/// See the [`check`](crate::check) module for more information.
+#[cfg(feature = "self-check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-check")))]
+pub fn self_check() -> Result<(), crate::check::Violation> {
+    const IOMUXC: &[core::ops::Range<u32>] = &[
+        // IOMUXC
+        0x400E_8000..0x400E_C000,
+        // IOMUXC_LPSR
+        0x40C0_8000..0x40C0_C000,
+        // IOMUXC_SNVS
+        0x40C9_4000..0x40C9_8000,
+    ];
+    // Safety: the erased pads never access their registers.
+    let pads = unsafe { Pads::new() }.erase();
+    crate::check::pads(
+        &[
+            #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+            &pads.gpio_emc_b1,
+            #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+            &pads.gpio_emc_b2,
+            #[cfg(feature = "imxrt1170-gpio-ad")]
+            &pads.gpio_ad,
+            #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+            &pads.gpio_sd_b1,
+            #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+            &pads.gpio_sd_b2,
+            #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+            &pads.gpio_disp_b1,
+            #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+            &pads.gpio_disp_b2,
+            #[cfg(feature = "imxrt1170-gpio-lpsr")]
+            &pads.gpio_lpsr,
+            #[cfg(feature = "imxrt1170-snvs")]
+            &pads.snvs,
+            #[cfg(feature = "imxrt1170-gpio-snvs")]
+            &pads.gpio_snvs,
+        ],
+        IOMUXC,
+    )?;
+    crate::check::daisies(
+        &[
+            flexcan::daisy::ALL,
+            lpi2c::daisy::ALL,
+            lpspi::daisy::ALL,
+            lpuart::daisy::ALL,
+        ],
+        IOMUXC,
+    )
+}
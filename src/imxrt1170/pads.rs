@@ -1,12 +1,18 @@
 // Generated by iomuxc.py
 #![allow(non_camel_case_types)]
 
+// ONOFF does not have both MUX and PAD registers.
+// POR_B does not have both MUX and PAD registers.
+// TEST_MODE does not have both MUX and PAD registers.
+
 /// Pads with the prefix GPIO_EMC_B1.
+#[cfg(feature = "imxrt1170-gpio-emc-b1")]
 pub mod gpio_emc_b1 {
 
     const GPIO_EMC_B1_00_MUX_ADDR: u32 = 0x400e8010;
     const GPIO_EMC_B1_00_PAD_ADDR: u32 = 0x400e8254;
     pub type GPIO_EMC_B1_00 = crate::Pad<GPIO_EMC_B1_00_MUX_ADDR, GPIO_EMC_B1_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_00 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_00 {
         const ALT: u32 = 5;
@@ -17,10 +23,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_00 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_00 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_00 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_00 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_00 {}
 
     const GPIO_EMC_B1_01_MUX_ADDR: u32 = 0x400e8014;
     const GPIO_EMC_B1_01_PAD_ADDR: u32 = 0x400e8258;
     pub type GPIO_EMC_B1_01 = crate::Pad<GPIO_EMC_B1_01_MUX_ADDR, GPIO_EMC_B1_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_01 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_01 {
         const ALT: u32 = 5;
@@ -31,10 +43,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_01 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_01 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_01 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_01 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_01 {}
 
     const GPIO_EMC_B1_02_MUX_ADDR: u32 = 0x400e8018;
     const GPIO_EMC_B1_02_PAD_ADDR: u32 = 0x400e825c;
     pub type GPIO_EMC_B1_02 = crate::Pad<GPIO_EMC_B1_02_MUX_ADDR, GPIO_EMC_B1_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_02 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_02 {
         const ALT: u32 = 5;
@@ -45,10 +63,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_02 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_02 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_02 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_02 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_02 {}
 
     const GPIO_EMC_B1_03_MUX_ADDR: u32 = 0x400e801c;
     const GPIO_EMC_B1_03_PAD_ADDR: u32 = 0x400e8260;
     pub type GPIO_EMC_B1_03 = crate::Pad<GPIO_EMC_B1_03_MUX_ADDR, GPIO_EMC_B1_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_03 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_03 {
         const ALT: u32 = 5;
@@ -59,10 +83,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_03 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_03 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_03 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_03 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_03 {}
 
     const GPIO_EMC_B1_04_MUX_ADDR: u32 = 0x400e8020;
     const GPIO_EMC_B1_04_PAD_ADDR: u32 = 0x400e8264;
     pub type GPIO_EMC_B1_04 = crate::Pad<GPIO_EMC_B1_04_MUX_ADDR, GPIO_EMC_B1_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_04 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_04 {
         const ALT: u32 = 5;
@@ -73,10 +103,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 4;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_04 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_04 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_04 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_04 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_04 {}
 
     const GPIO_EMC_B1_05_MUX_ADDR: u32 = 0x400e8024;
     const GPIO_EMC_B1_05_PAD_ADDR: u32 = 0x400e8268;
     pub type GPIO_EMC_B1_05 = crate::Pad<GPIO_EMC_B1_05_MUX_ADDR, GPIO_EMC_B1_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_05 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_05 {
         const ALT: u32 = 5;
@@ -87,10 +123,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 5;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_05 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_05 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_05 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_05 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_05 {}
 
     const GPIO_EMC_B1_06_MUX_ADDR: u32 = 0x400e8028;
     const GPIO_EMC_B1_06_PAD_ADDR: u32 = 0x400e826c;
     pub type GPIO_EMC_B1_06 = crate::Pad<GPIO_EMC_B1_06_MUX_ADDR, GPIO_EMC_B1_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_06 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_06 {
         const ALT: u32 = 5;
@@ -101,10 +143,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 6;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_06 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_06 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_06 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_06 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_06 {}
 
     const GPIO_EMC_B1_07_MUX_ADDR: u32 = 0x400e802c;
     const GPIO_EMC_B1_07_PAD_ADDR: u32 = 0x400e8270;
     pub type GPIO_EMC_B1_07 = crate::Pad<GPIO_EMC_B1_07_MUX_ADDR, GPIO_EMC_B1_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_07 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_07 {
         const ALT: u32 = 5;
@@ -115,10 +163,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 7;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_07 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_07 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_07 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_07 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_07 {}
 
     const GPIO_EMC_B1_08_MUX_ADDR: u32 = 0x400e8030;
     const GPIO_EMC_B1_08_PAD_ADDR: u32 = 0x400e8274;
     pub type GPIO_EMC_B1_08 = crate::Pad<GPIO_EMC_B1_08_MUX_ADDR, GPIO_EMC_B1_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_08 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_08 {
         const ALT: u32 = 5;
@@ -129,10 +183,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 8;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_08 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_08 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_08 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_08 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_08 {}
 
     const GPIO_EMC_B1_09_MUX_ADDR: u32 = 0x400e8034;
     const GPIO_EMC_B1_09_PAD_ADDR: u32 = 0x400e8278;
     pub type GPIO_EMC_B1_09 = crate::Pad<GPIO_EMC_B1_09_MUX_ADDR, GPIO_EMC_B1_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_09 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_09 {
         const ALT: u32 = 5;
@@ -143,10 +203,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 9;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_09 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_09 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_09 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_09 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_09 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_09 {}
 
     const GPIO_EMC_B1_10_MUX_ADDR: u32 = 0x400e8038;
     const GPIO_EMC_B1_10_PAD_ADDR: u32 = 0x400e827c;
     pub type GPIO_EMC_B1_10 = crate::Pad<GPIO_EMC_B1_10_MUX_ADDR, GPIO_EMC_B1_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_10 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_10 {
         const ALT: u32 = 5;
@@ -157,10 +224,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 10;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_10 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_10 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_10 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_10 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_10 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_10 {}
 
     const GPIO_EMC_B1_11_MUX_ADDR: u32 = 0x400e803c;
     const GPIO_EMC_B1_11_PAD_ADDR: u32 = 0x400e8280;
     pub type GPIO_EMC_B1_11 = crate::Pad<GPIO_EMC_B1_11_MUX_ADDR, GPIO_EMC_B1_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_11 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_11 {
         const ALT: u32 = 5;
@@ -171,10 +245,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 11;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_11 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_11 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_11 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_11 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_11 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_11 {}
 
     const GPIO_EMC_B1_12_MUX_ADDR: u32 = 0x400e8040;
     const GPIO_EMC_B1_12_PAD_ADDR: u32 = 0x400e8284;
     pub type GPIO_EMC_B1_12 = crate::Pad<GPIO_EMC_B1_12_MUX_ADDR, GPIO_EMC_B1_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_12 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_12 {
         const ALT: u32 = 5;
@@ -185,10 +266,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 12;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_12 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_12 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_12 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_12 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_12 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_12 {}
 
     const GPIO_EMC_B1_13_MUX_ADDR: u32 = 0x400e8044;
     const GPIO_EMC_B1_13_PAD_ADDR: u32 = 0x400e8288;
     pub type GPIO_EMC_B1_13 = crate::Pad<GPIO_EMC_B1_13_MUX_ADDR, GPIO_EMC_B1_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_13 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_13 {
         const ALT: u32 = 5;
@@ -199,10 +287,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 13;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_13 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_13 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_13 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_13 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_13 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_13 {}
 
     const GPIO_EMC_B1_14_MUX_ADDR: u32 = 0x400e8048;
     const GPIO_EMC_B1_14_PAD_ADDR: u32 = 0x400e828c;
     pub type GPIO_EMC_B1_14 = crate::Pad<GPIO_EMC_B1_14_MUX_ADDR, GPIO_EMC_B1_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_14 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_14 {
         const ALT: u32 = 5;
@@ -213,10 +308,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 14;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_14 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_14 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_14 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_14 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_14 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_14 {}
 
     const GPIO_EMC_B1_15_MUX_ADDR: u32 = 0x400e804c;
     const GPIO_EMC_B1_15_PAD_ADDR: u32 = 0x400e8290;
     pub type GPIO_EMC_B1_15 = crate::Pad<GPIO_EMC_B1_15_MUX_ADDR, GPIO_EMC_B1_15_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_15 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_15 {
         const ALT: u32 = 5;
@@ -227,10 +329,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 15;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_15 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_15 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_15 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_15 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_15 {}
 
     const GPIO_EMC_B1_16_MUX_ADDR: u32 = 0x400e8050;
     const GPIO_EMC_B1_16_PAD_ADDR: u32 = 0x400e8294;
     pub type GPIO_EMC_B1_16 = crate::Pad<GPIO_EMC_B1_16_MUX_ADDR, GPIO_EMC_B1_16_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_16 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_16 {
         const ALT: u32 = 5;
@@ -241,10 +349,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 16;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_16 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_16 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_16 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_16 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_16 {}
 
     const GPIO_EMC_B1_17_MUX_ADDR: u32 = 0x400e8054;
     const GPIO_EMC_B1_17_PAD_ADDR: u32 = 0x400e8298;
     pub type GPIO_EMC_B1_17 = crate::Pad<GPIO_EMC_B1_17_MUX_ADDR, GPIO_EMC_B1_17_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_17 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_17 {
         const ALT: u32 = 5;
@@ -255,10 +369,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 17;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_17 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_17 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_17 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_17 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_17 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_17 {}
 
     const GPIO_EMC_B1_18_MUX_ADDR: u32 = 0x400e8058;
     const GPIO_EMC_B1_18_PAD_ADDR: u32 = 0x400e829c;
     pub type GPIO_EMC_B1_18 = crate::Pad<GPIO_EMC_B1_18_MUX_ADDR, GPIO_EMC_B1_18_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_18 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_18 {
         const ALT: u32 = 5;
@@ -269,10 +390,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 18;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_18 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_18 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_18 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_18 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_18 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_18 {}
 
     const GPIO_EMC_B1_19_MUX_ADDR: u32 = 0x400e805c;
     const GPIO_EMC_B1_19_PAD_ADDR: u32 = 0x400e82a0;
     pub type GPIO_EMC_B1_19 = crate::Pad<GPIO_EMC_B1_19_MUX_ADDR, GPIO_EMC_B1_19_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_19 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_19 {
         const ALT: u32 = 5;
@@ -283,10 +411,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 19;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_19 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_19 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_19 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_19 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_19 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_19 {}
 
     const GPIO_EMC_B1_20_MUX_ADDR: u32 = 0x400e8060;
     const GPIO_EMC_B1_20_PAD_ADDR: u32 = 0x400e82a4;
     pub type GPIO_EMC_B1_20 = crate::Pad<GPIO_EMC_B1_20_MUX_ADDR, GPIO_EMC_B1_20_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_20 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_20 {
         const ALT: u32 = 5;
@@ -297,10 +432,17 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 20;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_20 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_20 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_20 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_20 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_20 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_20 {}
 
     const GPIO_EMC_B1_21_MUX_ADDR: u32 = 0x400e8064;
     const GPIO_EMC_B1_21_PAD_ADDR: u32 = 0x400e82a8;
     pub type GPIO_EMC_B1_21 = crate::Pad<GPIO_EMC_B1_21_MUX_ADDR, GPIO_EMC_B1_21_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_21 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_21 {
         const ALT: u32 = 5;
@@ -311,10 +453,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 21;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_21 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_21 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_21 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_21 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_21 {}
 
     const GPIO_EMC_B1_22_MUX_ADDR: u32 = 0x400e8068;
     const GPIO_EMC_B1_22_PAD_ADDR: u32 = 0x400e82ac;
     pub type GPIO_EMC_B1_22 = crate::Pad<GPIO_EMC_B1_22_MUX_ADDR, GPIO_EMC_B1_22_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_22 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_22 {
         const ALT: u32 = 5;
@@ -325,10 +473,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 22;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_22 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_22 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_22 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_22 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_22 {}
 
     const GPIO_EMC_B1_23_MUX_ADDR: u32 = 0x400e806c;
     const GPIO_EMC_B1_23_PAD_ADDR: u32 = 0x400e82b0;
     pub type GPIO_EMC_B1_23 = crate::Pad<GPIO_EMC_B1_23_MUX_ADDR, GPIO_EMC_B1_23_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_23 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_23 {
         const ALT: u32 = 5;
@@ -339,10 +493,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 23;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_23 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_23 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_23 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_23 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_23 {}
 
     const GPIO_EMC_B1_24_MUX_ADDR: u32 = 0x400e8070;
     const GPIO_EMC_B1_24_PAD_ADDR: u32 = 0x400e82b4;
     pub type GPIO_EMC_B1_24 = crate::Pad<GPIO_EMC_B1_24_MUX_ADDR, GPIO_EMC_B1_24_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_24 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_24 {
         const ALT: u32 = 5;
@@ -353,10 +513,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 24;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_24 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_24 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_24 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_24 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_24 {}
 
     const GPIO_EMC_B1_25_MUX_ADDR: u32 = 0x400e8074;
     const GPIO_EMC_B1_25_PAD_ADDR: u32 = 0x400e82b8;
     pub type GPIO_EMC_B1_25 = crate::Pad<GPIO_EMC_B1_25_MUX_ADDR, GPIO_EMC_B1_25_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_25 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_25 {
         const ALT: u32 = 5;
@@ -367,10 +533,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 25;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_25 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_25 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_25 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_25 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_25 {}
 
     const GPIO_EMC_B1_26_MUX_ADDR: u32 = 0x400e8078;
     const GPIO_EMC_B1_26_PAD_ADDR: u32 = 0x400e82bc;
     pub type GPIO_EMC_B1_26 = crate::Pad<GPIO_EMC_B1_26_MUX_ADDR, GPIO_EMC_B1_26_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_26 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_26 {
         const ALT: u32 = 5;
@@ -381,10 +553,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 26;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_26 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_26 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_26 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_26 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_26 {}
 
     const GPIO_EMC_B1_27_MUX_ADDR: u32 = 0x400e807c;
     const GPIO_EMC_B1_27_PAD_ADDR: u32 = 0x400e82c0;
     pub type GPIO_EMC_B1_27 = crate::Pad<GPIO_EMC_B1_27_MUX_ADDR, GPIO_EMC_B1_27_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_27 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_27 {
         const ALT: u32 = 5;
@@ -395,10 +573,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 27;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_27 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_27 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_27 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_27 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_27 {}
 
     const GPIO_EMC_B1_28_MUX_ADDR: u32 = 0x400e8080;
     const GPIO_EMC_B1_28_PAD_ADDR: u32 = 0x400e82c4;
     pub type GPIO_EMC_B1_28 = crate::Pad<GPIO_EMC_B1_28_MUX_ADDR, GPIO_EMC_B1_28_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_28 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_28 {
         const ALT: u32 = 5;
@@ -409,10 +593,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 28;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_28 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_28 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_28 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_28 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_28 {}
 
     const GPIO_EMC_B1_29_MUX_ADDR: u32 = 0x400e8084;
     const GPIO_EMC_B1_29_PAD_ADDR: u32 = 0x400e82c8;
     pub type GPIO_EMC_B1_29 = crate::Pad<GPIO_EMC_B1_29_MUX_ADDR, GPIO_EMC_B1_29_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_29 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_29 {
         const ALT: u32 = 5;
@@ -423,10 +613,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 29;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_29 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_29 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_29 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_29 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_29 {}
 
     const GPIO_EMC_B1_30_MUX_ADDR: u32 = 0x400e8088;
     const GPIO_EMC_B1_30_PAD_ADDR: u32 = 0x400e82cc;
     pub type GPIO_EMC_B1_30 = crate::Pad<GPIO_EMC_B1_30_MUX_ADDR, GPIO_EMC_B1_30_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_30 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_30 {
         const ALT: u32 = 5;
@@ -437,10 +633,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 30;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_30 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_30 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_30 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_30 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_30 {}
 
     const GPIO_EMC_B1_31_MUX_ADDR: u32 = 0x400e808c;
     const GPIO_EMC_B1_31_PAD_ADDR: u32 = 0x400e82d0;
     pub type GPIO_EMC_B1_31 = crate::Pad<GPIO_EMC_B1_31_MUX_ADDR, GPIO_EMC_B1_31_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_31 {}
 
     impl crate::gpio::Pin<1> for GPIO_EMC_B1_31 {
         const ALT: u32 = 5;
@@ -451,10 +653,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 31;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_31 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_31 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_31 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B1_31 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_31 {}
 
     const GPIO_EMC_B1_32_MUX_ADDR: u32 = 0x400e8090;
     const GPIO_EMC_B1_32_PAD_ADDR: u32 = 0x400e82d4;
     pub type GPIO_EMC_B1_32 = crate::Pad<GPIO_EMC_B1_32_MUX_ADDR, GPIO_EMC_B1_32_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_32 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_32 {
         const ALT: u32 = 5;
@@ -465,10 +673,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_32 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_32 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_32 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_32 {}
 
     const GPIO_EMC_B1_33_MUX_ADDR: u32 = 0x400e8094;
     const GPIO_EMC_B1_33_PAD_ADDR: u32 = 0x400e82d8;
     pub type GPIO_EMC_B1_33 = crate::Pad<GPIO_EMC_B1_33_MUX_ADDR, GPIO_EMC_B1_33_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_33 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_33 {
         const ALT: u32 = 5;
@@ -479,10 +692,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_33 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_33 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_33 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_33 {}
 
     const GPIO_EMC_B1_34_MUX_ADDR: u32 = 0x400e8098;
     const GPIO_EMC_B1_34_PAD_ADDR: u32 = 0x400e82dc;
     pub type GPIO_EMC_B1_34 = crate::Pad<GPIO_EMC_B1_34_MUX_ADDR, GPIO_EMC_B1_34_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_34 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_34 {
         const ALT: u32 = 5;
@@ -493,10 +711,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_34 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_34 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_34 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_34 {}
 
     const GPIO_EMC_B1_35_MUX_ADDR: u32 = 0x400e809c;
     const GPIO_EMC_B1_35_PAD_ADDR: u32 = 0x400e82e0;
     pub type GPIO_EMC_B1_35 = crate::Pad<GPIO_EMC_B1_35_MUX_ADDR, GPIO_EMC_B1_35_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_35 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_35 {
         const ALT: u32 = 5;
@@ -507,10 +730,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_35 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_35 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_35 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_35 {}
 
     const GPIO_EMC_B1_36_MUX_ADDR: u32 = 0x400e80a0;
     const GPIO_EMC_B1_36_PAD_ADDR: u32 = 0x400e82e4;
     pub type GPIO_EMC_B1_36 = crate::Pad<GPIO_EMC_B1_36_MUX_ADDR, GPIO_EMC_B1_36_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_36 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_36 {
         const ALT: u32 = 5;
@@ -521,10 +749,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 4;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_36 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_36 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_36 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_36 {}
 
     const GPIO_EMC_B1_37_MUX_ADDR: u32 = 0x400e80a4;
     const GPIO_EMC_B1_37_PAD_ADDR: u32 = 0x400e82e8;
     pub type GPIO_EMC_B1_37 = crate::Pad<GPIO_EMC_B1_37_MUX_ADDR, GPIO_EMC_B1_37_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_37 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_37 {
         const ALT: u32 = 5;
@@ -535,10 +768,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 5;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_37 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_37 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_37 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_37 {}
 
     const GPIO_EMC_B1_38_MUX_ADDR: u32 = 0x400e80a8;
     const GPIO_EMC_B1_38_PAD_ADDR: u32 = 0x400e82ec;
     pub type GPIO_EMC_B1_38 = crate::Pad<GPIO_EMC_B1_38_MUX_ADDR, GPIO_EMC_B1_38_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_38 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_38 {
         const ALT: u32 = 5;
@@ -549,10 +787,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 6;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_38 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_38 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_38 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_38 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_38 {}
 
     const GPIO_EMC_B1_39_MUX_ADDR: u32 = 0x400e80ac;
     const GPIO_EMC_B1_39_PAD_ADDR: u32 = 0x400e82f0;
     pub type GPIO_EMC_B1_39 = crate::Pad<GPIO_EMC_B1_39_MUX_ADDR, GPIO_EMC_B1_39_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_39 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_39 {
         const ALT: u32 = 5;
@@ -563,10 +807,16 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 7;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_39 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_39 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_39 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_39 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_39 {}
 
     const GPIO_EMC_B1_40_MUX_ADDR: u32 = 0x400e80b0;
     const GPIO_EMC_B1_40_PAD_ADDR: u32 = 0x400e82f4;
     pub type GPIO_EMC_B1_40 = crate::Pad<GPIO_EMC_B1_40_MUX_ADDR, GPIO_EMC_B1_40_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_40 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_40 {
         const ALT: u32 = 5;
@@ -577,10 +827,19 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 8;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B1_40 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_40 {}
 
     const GPIO_EMC_B1_41_MUX_ADDR: u32 = 0x400e80b4;
     const GPIO_EMC_B1_41_PAD_ADDR: u32 = 0x400e82f8;
     pub type GPIO_EMC_B1_41 = crate::Pad<GPIO_EMC_B1_41_MUX_ADDR, GPIO_EMC_B1_41_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B1_41 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B1_41 {
         const ALT: u32 = 5;
@@ -591,6 +850,15 @@ pub mod gpio_emc_b1 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 9;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B1_41 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B1_41 {}
 
     /// All pads with prefix GPIO_EMC_B1.
     pub struct Pads {
@@ -752,14 +1020,32 @@ pub mod gpio_emc_b1 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_EMC_B2.
+#[cfg(feature = "imxrt1170-gpio-emc-b2")]
 pub mod gpio_emc_b2 {
 
     const GPIO_EMC_B2_00_MUX_ADDR: u32 = 0x400e80b8;
     const GPIO_EMC_B2_00_PAD_ADDR: u32 = 0x400e82fc;
     pub type GPIO_EMC_B2_00 = crate::Pad<GPIO_EMC_B2_00_MUX_ADDR, GPIO_EMC_B2_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_00 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_00 {
         const ALT: u32 = 5;
@@ -770,10 +1056,23 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 10;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_00 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_00 {}
 
     const GPIO_EMC_B2_01_MUX_ADDR: u32 = 0x400e80bc;
     const GPIO_EMC_B2_01_PAD_ADDR: u32 = 0x400e8300;
     pub type GPIO_EMC_B2_01 = crate::Pad<GPIO_EMC_B2_01_MUX_ADDR, GPIO_EMC_B2_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_01 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_01 {
         const ALT: u32 = 5;
@@ -784,10 +1083,23 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 11;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_01 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_01 {}
 
     const GPIO_EMC_B2_02_MUX_ADDR: u32 = 0x400e80c0;
     const GPIO_EMC_B2_02_PAD_ADDR: u32 = 0x400e8304;
     pub type GPIO_EMC_B2_02 = crate::Pad<GPIO_EMC_B2_02_MUX_ADDR, GPIO_EMC_B2_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_02 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_02 {
         const ALT: u32 = 5;
@@ -798,10 +1110,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 12;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_02 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_02 {}
 
     const GPIO_EMC_B2_03_MUX_ADDR: u32 = 0x400e80c4;
     const GPIO_EMC_B2_03_PAD_ADDR: u32 = 0x400e8308;
     pub type GPIO_EMC_B2_03 = crate::Pad<GPIO_EMC_B2_03_MUX_ADDR, GPIO_EMC_B2_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_03 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_03 {
         const ALT: u32 = 5;
@@ -812,10 +1135,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 13;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_03 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_03 {}
 
     const GPIO_EMC_B2_04_MUX_ADDR: u32 = 0x400e80c8;
     const GPIO_EMC_B2_04_PAD_ADDR: u32 = 0x400e830c;
     pub type GPIO_EMC_B2_04 = crate::Pad<GPIO_EMC_B2_04_MUX_ADDR, GPIO_EMC_B2_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_04 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_04 {
         const ALT: u32 = 5;
@@ -826,10 +1160,22 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 14;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_04 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_04 {}
 
     const GPIO_EMC_B2_05_MUX_ADDR: u32 = 0x400e80cc;
     const GPIO_EMC_B2_05_PAD_ADDR: u32 = 0x400e8310;
     pub type GPIO_EMC_B2_05 = crate::Pad<GPIO_EMC_B2_05_MUX_ADDR, GPIO_EMC_B2_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_05 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_05 {
         const ALT: u32 = 5;
@@ -840,10 +1186,23 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 15;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_05 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_05 {}
 
     const GPIO_EMC_B2_06_MUX_ADDR: u32 = 0x400e80d0;
     const GPIO_EMC_B2_06_PAD_ADDR: u32 = 0x400e8314;
     pub type GPIO_EMC_B2_06 = crate::Pad<GPIO_EMC_B2_06_MUX_ADDR, GPIO_EMC_B2_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_06 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_06 {
         const ALT: u32 = 5;
@@ -854,10 +1213,23 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 16;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_06 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_06 {}
 
     const GPIO_EMC_B2_07_MUX_ADDR: u32 = 0x400e80d4;
     const GPIO_EMC_B2_07_PAD_ADDR: u32 = 0x400e8318;
     pub type GPIO_EMC_B2_07 = crate::Pad<GPIO_EMC_B2_07_MUX_ADDR, GPIO_EMC_B2_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_07 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_07 {
         const ALT: u32 = 5;
@@ -868,10 +1240,23 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 17;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_07 {}
+    impl crate::AnyAltPin<11> for GPIO_EMC_B2_07 {}
 
     const GPIO_EMC_B2_08_MUX_ADDR: u32 = 0x400e80d8;
     const GPIO_EMC_B2_08_PAD_ADDR: u32 = 0x400e831c;
     pub type GPIO_EMC_B2_08 = crate::Pad<GPIO_EMC_B2_08_MUX_ADDR, GPIO_EMC_B2_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_08 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_08 {
         const ALT: u32 = 5;
@@ -882,10 +1267,22 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 18;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_08 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_08 {}
 
     const GPIO_EMC_B2_09_MUX_ADDR: u32 = 0x400e80dc;
     const GPIO_EMC_B2_09_PAD_ADDR: u32 = 0x400e8320;
     pub type GPIO_EMC_B2_09 = crate::Pad<GPIO_EMC_B2_09_MUX_ADDR, GPIO_EMC_B2_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_09 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_09 {
         const ALT: u32 = 5;
@@ -896,10 +1293,22 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 19;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_09 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_09 {}
 
     const GPIO_EMC_B2_10_MUX_ADDR: u32 = 0x400e80e0;
     const GPIO_EMC_B2_10_PAD_ADDR: u32 = 0x400e8324;
     pub type GPIO_EMC_B2_10 = crate::Pad<GPIO_EMC_B2_10_MUX_ADDR, GPIO_EMC_B2_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_10 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_10 {
         const ALT: u32 = 5;
@@ -910,10 +1319,22 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 20;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_10 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_10 {}
 
     const GPIO_EMC_B2_11_MUX_ADDR: u32 = 0x400e80e4;
     const GPIO_EMC_B2_11_PAD_ADDR: u32 = 0x400e8328;
     pub type GPIO_EMC_B2_11 = crate::Pad<GPIO_EMC_B2_11_MUX_ADDR, GPIO_EMC_B2_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_11 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_11 {
         const ALT: u32 = 5;
@@ -924,10 +1345,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 21;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_11 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_11 {}
 
     const GPIO_EMC_B2_12_MUX_ADDR: u32 = 0x400e80e8;
     const GPIO_EMC_B2_12_PAD_ADDR: u32 = 0x400e832c;
     pub type GPIO_EMC_B2_12 = crate::Pad<GPIO_EMC_B2_12_MUX_ADDR, GPIO_EMC_B2_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_12 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_12 {
         const ALT: u32 = 5;
@@ -938,10 +1370,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 22;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_12 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_12 {}
 
     const GPIO_EMC_B2_13_MUX_ADDR: u32 = 0x400e80ec;
     const GPIO_EMC_B2_13_PAD_ADDR: u32 = 0x400e8330;
     pub type GPIO_EMC_B2_13 = crate::Pad<GPIO_EMC_B2_13_MUX_ADDR, GPIO_EMC_B2_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_13 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_13 {
         const ALT: u32 = 5;
@@ -952,10 +1395,20 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 23;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_13 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_13 {}
 
     const GPIO_EMC_B2_14_MUX_ADDR: u32 = 0x400e80f0;
     const GPIO_EMC_B2_14_PAD_ADDR: u32 = 0x400e8334;
     pub type GPIO_EMC_B2_14 = crate::Pad<GPIO_EMC_B2_14_MUX_ADDR, GPIO_EMC_B2_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_14 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_14 {
         const ALT: u32 = 5;
@@ -966,10 +1419,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 24;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<7> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_14 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_14 {}
 
     const GPIO_EMC_B2_15_MUX_ADDR: u32 = 0x400e80f4;
     const GPIO_EMC_B2_15_PAD_ADDR: u32 = 0x400e8338;
     pub type GPIO_EMC_B2_15 = crate::Pad<GPIO_EMC_B2_15_MUX_ADDR, GPIO_EMC_B2_15_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_15 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_15 {
         const ALT: u32 = 5;
@@ -980,10 +1444,20 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 25;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_15 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_15 {}
 
     const GPIO_EMC_B2_16_MUX_ADDR: u32 = 0x400e80f8;
     const GPIO_EMC_B2_16_PAD_ADDR: u32 = 0x400e833c;
     pub type GPIO_EMC_B2_16 = crate::Pad<GPIO_EMC_B2_16_MUX_ADDR, GPIO_EMC_B2_16_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_16 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_16 {
         const ALT: u32 = 5;
@@ -994,10 +1468,20 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 26;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_16 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_16 {}
 
     const GPIO_EMC_B2_17_MUX_ADDR: u32 = 0x400e80fc;
     const GPIO_EMC_B2_17_PAD_ADDR: u32 = 0x400e8340;
     pub type GPIO_EMC_B2_17 = crate::Pad<GPIO_EMC_B2_17_MUX_ADDR, GPIO_EMC_B2_17_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_17 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_17 {
         const ALT: u32 = 5;
@@ -1008,10 +1492,20 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 27;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_17 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_17 {}
 
     const GPIO_EMC_B2_18_MUX_ADDR: u32 = 0x400e8100;
     const GPIO_EMC_B2_18_PAD_ADDR: u32 = 0x400e8344;
     pub type GPIO_EMC_B2_18 = crate::Pad<GPIO_EMC_B2_18_MUX_ADDR, GPIO_EMC_B2_18_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_18 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_18 {
         const ALT: u32 = 5;
@@ -1022,10 +1516,21 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 28;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<6> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_18 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_18 {}
 
     const GPIO_EMC_B2_19_MUX_ADDR: u32 = 0x400e8104;
     const GPIO_EMC_B2_19_PAD_ADDR: u32 = 0x400e8348;
     pub type GPIO_EMC_B2_19 = crate::Pad<GPIO_EMC_B2_19_MUX_ADDR, GPIO_EMC_B2_19_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_19 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_19 {
         const ALT: u32 = 5;
@@ -1036,10 +1541,20 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 29;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_19 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_19 {}
 
     const GPIO_EMC_B2_20_MUX_ADDR: u32 = 0x400e8108;
     const GPIO_EMC_B2_20_PAD_ADDR: u32 = 0x400e834c;
     pub type GPIO_EMC_B2_20 = crate::Pad<GPIO_EMC_B2_20_MUX_ADDR, GPIO_EMC_B2_20_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_EMC_B2_20 {}
 
     impl crate::gpio::Pin<2> for GPIO_EMC_B2_20 {
         const ALT: u32 = 5;
@@ -1050,6 +1565,15 @@ pub mod gpio_emc_b2 {
         const ALT: u32 = 10;
         const OFFSET: u32 = 30;
     }
+    impl crate::AnyAltPin<0> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<1> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<2> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<3> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<4> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<5> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<8> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<9> for GPIO_EMC_B2_20 {}
+    impl crate::AnyAltPin<10> for GPIO_EMC_B2_20 {}
 
     /// All pads with prefix GPIO_EMC_B2.
     pub struct Pads {
@@ -1148,14 +1672,32 @@ pub mod gpio_emc_b2 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_AD.
+#[cfg(feature = "imxrt1170-gpio-ad")]
 pub mod gpio_ad {
 
     const GPIO_AD_00_MUX_ADDR: u32 = 0x400e810c;
     const GPIO_AD_00_PAD_ADDR: u32 = 0x400e8350;
     pub type GPIO_AD_00 = crate::Pad<GPIO_AD_00_MUX_ADDR, GPIO_AD_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_00 {}
 
     impl crate::gpio::Pin<2> for GPIO_AD_00 {
         const ALT: u32 = 5;
@@ -1166,10 +1708,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 31;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_00 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_00 {}
 
     const GPIO_AD_01_MUX_ADDR: u32 = 0x400e8110;
     const GPIO_AD_01_PAD_ADDR: u32 = 0x400e8354;
     pub type GPIO_AD_01 = crate::Pad<GPIO_AD_01_MUX_ADDR, GPIO_AD_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_01 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_01 {
         const ALT: u32 = 5;
@@ -1180,10 +1733,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_01 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_01 {}
 
     const GPIO_AD_02_MUX_ADDR: u32 = 0x400e8114;
     const GPIO_AD_02_PAD_ADDR: u32 = 0x400e8358;
     pub type GPIO_AD_02 = crate::Pad<GPIO_AD_02_MUX_ADDR, GPIO_AD_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_02 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_02 {
         const ALT: u32 = 5;
@@ -1194,10 +1758,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_02 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_02 {}
 
     const GPIO_AD_03_MUX_ADDR: u32 = 0x400e8118;
     const GPIO_AD_03_PAD_ADDR: u32 = 0x400e835c;
     pub type GPIO_AD_03 = crate::Pad<GPIO_AD_03_MUX_ADDR, GPIO_AD_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_03 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_03 {
         const ALT: u32 = 5;
@@ -1208,10 +1783,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_03 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_03 {}
 
     const GPIO_AD_04_MUX_ADDR: u32 = 0x400e811c;
     const GPIO_AD_04_PAD_ADDR: u32 = 0x400e8360;
     pub type GPIO_AD_04 = crate::Pad<GPIO_AD_04_MUX_ADDR, GPIO_AD_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_04 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_04 {
         const ALT: u32 = 5;
@@ -1222,10 +1808,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_04 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_04 {}
 
     const GPIO_AD_05_MUX_ADDR: u32 = 0x400e8120;
     const GPIO_AD_05_PAD_ADDR: u32 = 0x400e8364;
     pub type GPIO_AD_05 = crate::Pad<GPIO_AD_05_MUX_ADDR, GPIO_AD_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_05 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_05 {
         const ALT: u32 = 5;
@@ -1236,10 +1833,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 4;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_05 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_05 {}
 
     const GPIO_AD_06_MUX_ADDR: u32 = 0x400e8124;
     const GPIO_AD_06_PAD_ADDR: u32 = 0x400e8368;
     pub type GPIO_AD_06 = crate::Pad<GPIO_AD_06_MUX_ADDR, GPIO_AD_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_06 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_06 {
         const ALT: u32 = 5;
@@ -1250,10 +1858,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 5;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_06 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_06 {}
 
     const GPIO_AD_07_MUX_ADDR: u32 = 0x400e8128;
     const GPIO_AD_07_PAD_ADDR: u32 = 0x400e836c;
     pub type GPIO_AD_07 = crate::Pad<GPIO_AD_07_MUX_ADDR, GPIO_AD_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_07 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_07 {
         const ALT: u32 = 5;
@@ -1264,10 +1884,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 6;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_07 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_07 {}
 
     const GPIO_AD_08_MUX_ADDR: u32 = 0x400e812c;
     const GPIO_AD_08_PAD_ADDR: u32 = 0x400e8370;
     pub type GPIO_AD_08 = crate::Pad<GPIO_AD_08_MUX_ADDR, GPIO_AD_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_08 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_08 {
         const ALT: u32 = 5;
@@ -1278,10 +1910,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 7;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_08 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_08 {}
 
     const GPIO_AD_09_MUX_ADDR: u32 = 0x400e8130;
     const GPIO_AD_09_PAD_ADDR: u32 = 0x400e8374;
     pub type GPIO_AD_09 = crate::Pad<GPIO_AD_09_MUX_ADDR, GPIO_AD_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_09 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_09 {
         const ALT: u32 = 5;
@@ -1292,10 +1935,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 8;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_09 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_09 {}
 
     const GPIO_AD_10_MUX_ADDR: u32 = 0x400e8134;
     const GPIO_AD_10_PAD_ADDR: u32 = 0x400e8378;
     pub type GPIO_AD_10 = crate::Pad<GPIO_AD_10_MUX_ADDR, GPIO_AD_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_10 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_10 {
         const ALT: u32 = 5;
@@ -1306,10 +1960,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 9;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_10 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_10 {}
 
     const GPIO_AD_11_MUX_ADDR: u32 = 0x400e8138;
     const GPIO_AD_11_PAD_ADDR: u32 = 0x400e837c;
     pub type GPIO_AD_11 = crate::Pad<GPIO_AD_11_MUX_ADDR, GPIO_AD_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_11 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_11 {
         const ALT: u32 = 5;
@@ -1320,10 +1985,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 10;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_11 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_11 {}
 
     const GPIO_AD_12_MUX_ADDR: u32 = 0x400e813c;
     const GPIO_AD_12_PAD_ADDR: u32 = 0x400e8380;
     pub type GPIO_AD_12 = crate::Pad<GPIO_AD_12_MUX_ADDR, GPIO_AD_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_12 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_12 {
         const ALT: u32 = 5;
@@ -1334,10 +2010,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 11;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_12 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_12 {}
 
     const GPIO_AD_13_MUX_ADDR: u32 = 0x400e8140;
     const GPIO_AD_13_PAD_ADDR: u32 = 0x400e8384;
     pub type GPIO_AD_13 = crate::Pad<GPIO_AD_13_MUX_ADDR, GPIO_AD_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_13 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_13 {
         const ALT: u32 = 5;
@@ -1348,10 +2036,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 12;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_13 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_13 {}
 
     const GPIO_AD_14_MUX_ADDR: u32 = 0x400e8144;
     const GPIO_AD_14_PAD_ADDR: u32 = 0x400e8388;
     pub type GPIO_AD_14 = crate::Pad<GPIO_AD_14_MUX_ADDR, GPIO_AD_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_14 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_14 {
         const ALT: u32 = 5;
@@ -1362,10 +2062,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 13;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_14 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_14 {}
 
     const GPIO_AD_15_MUX_ADDR: u32 = 0x400e8148;
     const GPIO_AD_15_PAD_ADDR: u32 = 0x400e838c;
     pub type GPIO_AD_15 = crate::Pad<GPIO_AD_15_MUX_ADDR, GPIO_AD_15_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_15 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_15 {
         const ALT: u32 = 5;
@@ -1376,10 +2088,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 14;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_15 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_15 {}
 
     const GPIO_AD_16_MUX_ADDR: u32 = 0x400e814c;
     const GPIO_AD_16_PAD_ADDR: u32 = 0x400e8390;
     pub type GPIO_AD_16 = crate::Pad<GPIO_AD_16_MUX_ADDR, GPIO_AD_16_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_16 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_16 {
         const ALT: u32 = 5;
@@ -1390,10 +2113,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 15;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_16 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_16 {}
 
     const GPIO_AD_17_MUX_ADDR: u32 = 0x400e8150;
     const GPIO_AD_17_PAD_ADDR: u32 = 0x400e8394;
     pub type GPIO_AD_17 = crate::Pad<GPIO_AD_17_MUX_ADDR, GPIO_AD_17_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_17 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_17 {
         const ALT: u32 = 5;
@@ -1404,10 +2139,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 16;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_17 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_17 {}
 
     const GPIO_AD_18_MUX_ADDR: u32 = 0x400e8154;
     const GPIO_AD_18_PAD_ADDR: u32 = 0x400e8398;
     pub type GPIO_AD_18 = crate::Pad<GPIO_AD_18_MUX_ADDR, GPIO_AD_18_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_18 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_18 {
         const ALT: u32 = 5;
@@ -1418,10 +2165,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 17;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_18 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_18 {}
 
     const GPIO_AD_19_MUX_ADDR: u32 = 0x400e8158;
     const GPIO_AD_19_PAD_ADDR: u32 = 0x400e839c;
     pub type GPIO_AD_19 = crate::Pad<GPIO_AD_19_MUX_ADDR, GPIO_AD_19_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_19 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_19 {
         const ALT: u32 = 5;
@@ -1432,10 +2191,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 18;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_19 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_19 {}
 
     const GPIO_AD_20_MUX_ADDR: u32 = 0x400e815c;
     const GPIO_AD_20_PAD_ADDR: u32 = 0x400e83a0;
     pub type GPIO_AD_20 = crate::Pad<GPIO_AD_20_MUX_ADDR, GPIO_AD_20_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_20 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_20 {
         const ALT: u32 = 5;
@@ -1446,10 +2217,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 19;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_20 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_20 {}
 
     const GPIO_AD_21_MUX_ADDR: u32 = 0x400e8160;
     const GPIO_AD_21_PAD_ADDR: u32 = 0x400e83a4;
     pub type GPIO_AD_21 = crate::Pad<GPIO_AD_21_MUX_ADDR, GPIO_AD_21_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_21 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_21 {
         const ALT: u32 = 5;
@@ -1460,10 +2243,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 20;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_21 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_21 {}
 
     const GPIO_AD_22_MUX_ADDR: u32 = 0x400e8164;
     const GPIO_AD_22_PAD_ADDR: u32 = 0x400e83a8;
     pub type GPIO_AD_22 = crate::Pad<GPIO_AD_22_MUX_ADDR, GPIO_AD_22_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_22 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_22 {
         const ALT: u32 = 5;
@@ -1474,10 +2268,20 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 21;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_22 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_22 {}
 
     const GPIO_AD_23_MUX_ADDR: u32 = 0x400e8168;
     const GPIO_AD_23_PAD_ADDR: u32 = 0x400e83ac;
     pub type GPIO_AD_23 = crate::Pad<GPIO_AD_23_MUX_ADDR, GPIO_AD_23_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_23 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_23 {
         const ALT: u32 = 5;
@@ -1488,10 +2292,20 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 22;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_23 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_23 {}
 
     const GPIO_AD_24_MUX_ADDR: u32 = 0x400e816c;
     const GPIO_AD_24_PAD_ADDR: u32 = 0x400e83b0;
     pub type GPIO_AD_24 = crate::Pad<GPIO_AD_24_MUX_ADDR, GPIO_AD_24_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_24 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_24 {
         const ALT: u32 = 5;
@@ -1502,10 +2316,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 23;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_24 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_24 {}
 
     const GPIO_AD_25_MUX_ADDR: u32 = 0x400e8170;
     const GPIO_AD_25_PAD_ADDR: u32 = 0x400e83b4;
     pub type GPIO_AD_25 = crate::Pad<GPIO_AD_25_MUX_ADDR, GPIO_AD_25_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_25 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_25 {
         const ALT: u32 = 5;
@@ -1516,10 +2341,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 24;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_25 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_25 {}
 
     const GPIO_AD_26_MUX_ADDR: u32 = 0x400e8174;
     const GPIO_AD_26_PAD_ADDR: u32 = 0x400e83b8;
     pub type GPIO_AD_26 = crate::Pad<GPIO_AD_26_MUX_ADDR, GPIO_AD_26_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_26 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_26 {
         const ALT: u32 = 5;
@@ -1530,10 +2366,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 25;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_26 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_26 {}
 
     const GPIO_AD_27_MUX_ADDR: u32 = 0x400e8178;
     const GPIO_AD_27_PAD_ADDR: u32 = 0x400e83bc;
     pub type GPIO_AD_27 = crate::Pad<GPIO_AD_27_MUX_ADDR, GPIO_AD_27_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_27 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_27 {
         const ALT: u32 = 5;
@@ -1544,10 +2392,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 26;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_27 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_27 {}
 
     const GPIO_AD_28_MUX_ADDR: u32 = 0x400e817c;
     const GPIO_AD_28_PAD_ADDR: u32 = 0x400e83c0;
     pub type GPIO_AD_28 = crate::Pad<GPIO_AD_28_MUX_ADDR, GPIO_AD_28_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_28 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_28 {
         const ALT: u32 = 5;
@@ -1558,10 +2418,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 27;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_28 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_28 {}
 
     const GPIO_AD_29_MUX_ADDR: u32 = 0x400e8180;
     const GPIO_AD_29_PAD_ADDR: u32 = 0x400e83c4;
     pub type GPIO_AD_29 = crate::Pad<GPIO_AD_29_MUX_ADDR, GPIO_AD_29_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_29 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_29 {
         const ALT: u32 = 5;
@@ -1572,10 +2444,22 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 28;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_29 {}
+    impl crate::AnyAltPin<11> for GPIO_AD_29 {}
 
     const GPIO_AD_30_MUX_ADDR: u32 = 0x400e8184;
     const GPIO_AD_30_PAD_ADDR: u32 = 0x400e83c8;
     pub type GPIO_AD_30 = crate::Pad<GPIO_AD_30_MUX_ADDR, GPIO_AD_30_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_30 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_30 {
         const ALT: u32 = 5;
@@ -1586,10 +2470,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 29;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_30 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_30 {}
 
     const GPIO_AD_31_MUX_ADDR: u32 = 0x400e8188;
     const GPIO_AD_31_PAD_ADDR: u32 = 0x400e83cc;
     pub type GPIO_AD_31 = crate::Pad<GPIO_AD_31_MUX_ADDR, GPIO_AD_31_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_31 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_31 {
         const ALT: u32 = 5;
@@ -1600,10 +2495,21 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 30;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_31 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_31 {}
 
     const GPIO_AD_32_MUX_ADDR: u32 = 0x400e818c;
     const GPIO_AD_32_PAD_ADDR: u32 = 0x400e83d0;
     pub type GPIO_AD_32 = crate::Pad<GPIO_AD_32_MUX_ADDR, GPIO_AD_32_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_32 {}
 
     impl crate::gpio::Pin<3> for GPIO_AD_32 {
         const ALT: u32 = 5;
@@ -1614,33 +2520,76 @@ pub mod gpio_ad {
         const ALT: u32 = 10;
         const OFFSET: u32 = 31;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_32 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_32 {}
 
     const GPIO_AD_33_MUX_ADDR: u32 = 0x400e8190;
     const GPIO_AD_33_PAD_ADDR: u32 = 0x400e83d4;
     pub type GPIO_AD_33 = crate::Pad<GPIO_AD_33_MUX_ADDR, GPIO_AD_33_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_33 {}
 
     impl crate::gpio::Pin<4> for GPIO_AD_33 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_33 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_33 {}
 
     const GPIO_AD_34_MUX_ADDR: u32 = 0x400e8194;
     const GPIO_AD_34_PAD_ADDR: u32 = 0x400e83d8;
     pub type GPIO_AD_34 = crate::Pad<GPIO_AD_34_MUX_ADDR, GPIO_AD_34_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_34 {}
 
     impl crate::gpio::Pin<4> for GPIO_AD_34 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_34 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_34 {}
 
     const GPIO_AD_35_MUX_ADDR: u32 = 0x400e8198;
     const GPIO_AD_35_PAD_ADDR: u32 = 0x400e83dc;
     pub type GPIO_AD_35 = crate::Pad<GPIO_AD_35_MUX_ADDR, GPIO_AD_35_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_AD_35 {}
 
     impl crate::gpio::Pin<4> for GPIO_AD_35 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<1> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<2> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<3> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<4> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<5> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<6> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<8> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<9> for GPIO_AD_35 {}
+    impl crate::AnyAltPin<10> for GPIO_AD_35 {}
 
     /// All pads with prefix GPIO_AD.
     pub struct Pads {
@@ -1784,64 +2733,133 @@ pub mod gpio_ad {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_SD_B1.
+#[cfg(feature = "imxrt1170-gpio-sd-b1")]
 pub mod gpio_sd_b1 {
 
     const GPIO_SD_B1_00_MUX_ADDR: u32 = 0x400e819c;
     const GPIO_SD_B1_00_PAD_ADDR: u32 = 0x400e83e0;
     pub type GPIO_SD_B1_00 = crate::Pad<GPIO_SD_B1_00_MUX_ADDR, GPIO_SD_B1_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_00 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_00 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_00 {}
 
     const GPIO_SD_B1_01_MUX_ADDR: u32 = 0x400e81a0;
     const GPIO_SD_B1_01_PAD_ADDR: u32 = 0x400e83e4;
     pub type GPIO_SD_B1_01 = crate::Pad<GPIO_SD_B1_01_MUX_ADDR, GPIO_SD_B1_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_01 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_01 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_01 {}
 
     const GPIO_SD_B1_02_MUX_ADDR: u32 = 0x400e81a4;
     const GPIO_SD_B1_02_PAD_ADDR: u32 = 0x400e83e8;
     pub type GPIO_SD_B1_02 = crate::Pad<GPIO_SD_B1_02_MUX_ADDR, GPIO_SD_B1_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_02 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<9> for GPIO_SD_B1_02 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_02 {}
 
     const GPIO_SD_B1_03_MUX_ADDR: u32 = 0x400e81a8;
     const GPIO_SD_B1_03_PAD_ADDR: u32 = 0x400e83ec;
     pub type GPIO_SD_B1_03 = crate::Pad<GPIO_SD_B1_03_MUX_ADDR, GPIO_SD_B1_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_03 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<9> for GPIO_SD_B1_03 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_03 {}
 
     const GPIO_SD_B1_04_MUX_ADDR: u32 = 0x400e81ac;
     const GPIO_SD_B1_04_PAD_ADDR: u32 = 0x400e83f0;
     pub type GPIO_SD_B1_04 = crate::Pad<GPIO_SD_B1_04_MUX_ADDR, GPIO_SD_B1_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_04 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<9> for GPIO_SD_B1_04 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_04 {}
 
     const GPIO_SD_B1_05_MUX_ADDR: u32 = 0x400e81b0;
     const GPIO_SD_B1_05_PAD_ADDR: u32 = 0x400e83f4;
     pub type GPIO_SD_B1_05 = crate::Pad<GPIO_SD_B1_05_MUX_ADDR, GPIO_SD_B1_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B1_05 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B1_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<9> for GPIO_SD_B1_05 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B1_05 {}
 
     /// All pads with prefix GPIO_SD_B1.
     pub struct Pads {
@@ -1895,118 +2913,238 @@ pub mod gpio_sd_b1 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_SD_B2.
+#[cfg(feature = "imxrt1170-gpio-sd-b2")]
 pub mod gpio_sd_b2 {
 
     const GPIO_SD_B2_00_MUX_ADDR: u32 = 0x400e81b4;
     const GPIO_SD_B2_00_PAD_ADDR: u32 = 0x400e83f8;
     pub type GPIO_SD_B2_00 = crate::Pad<GPIO_SD_B2_00_MUX_ADDR, GPIO_SD_B2_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_00 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_00 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_00 {}
 
     const GPIO_SD_B2_01_MUX_ADDR: u32 = 0x400e81b8;
     const GPIO_SD_B2_01_PAD_ADDR: u32 = 0x400e83fc;
     pub type GPIO_SD_B2_01 = crate::Pad<GPIO_SD_B2_01_MUX_ADDR, GPIO_SD_B2_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_01 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_01 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_01 {}
 
     const GPIO_SD_B2_02_MUX_ADDR: u32 = 0x400e81bc;
     const GPIO_SD_B2_02_PAD_ADDR: u32 = 0x400e8400;
     pub type GPIO_SD_B2_02 = crate::Pad<GPIO_SD_B2_02_MUX_ADDR, GPIO_SD_B2_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_02 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_02 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_02 {}
 
     const GPIO_SD_B2_03_MUX_ADDR: u32 = 0x400e81c0;
     const GPIO_SD_B2_03_PAD_ADDR: u32 = 0x400e8404;
     pub type GPIO_SD_B2_03 = crate::Pad<GPIO_SD_B2_03_MUX_ADDR, GPIO_SD_B2_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_03 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_03 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_03 {}
 
     const GPIO_SD_B2_04_MUX_ADDR: u32 = 0x400e81c4;
     const GPIO_SD_B2_04_PAD_ADDR: u32 = 0x400e8408;
     pub type GPIO_SD_B2_04 = crate::Pad<GPIO_SD_B2_04_MUX_ADDR, GPIO_SD_B2_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_04 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_04 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_04 {}
 
     const GPIO_SD_B2_05_MUX_ADDR: u32 = 0x400e81c8;
     const GPIO_SD_B2_05_PAD_ADDR: u32 = 0x400e840c;
     pub type GPIO_SD_B2_05 = crate::Pad<GPIO_SD_B2_05_MUX_ADDR, GPIO_SD_B2_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_05 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_05 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_05 {}
 
     const GPIO_SD_B2_06_MUX_ADDR: u32 = 0x400e81cc;
     const GPIO_SD_B2_06_PAD_ADDR: u32 = 0x400e8410;
     pub type GPIO_SD_B2_06 = crate::Pad<GPIO_SD_B2_06_MUX_ADDR, GPIO_SD_B2_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_06 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_06 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_06 {}
 
     const GPIO_SD_B2_07_MUX_ADDR: u32 = 0x400e81d0;
     const GPIO_SD_B2_07_PAD_ADDR: u32 = 0x400e8414;
     pub type GPIO_SD_B2_07 = crate::Pad<GPIO_SD_B2_07_MUX_ADDR, GPIO_SD_B2_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_07 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<8> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<9> for GPIO_SD_B2_07 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_07 {}
 
     const GPIO_SD_B2_08_MUX_ADDR: u32 = 0x400e81d4;
     const GPIO_SD_B2_08_PAD_ADDR: u32 = 0x400e8418;
     pub type GPIO_SD_B2_08 = crate::Pad<GPIO_SD_B2_08_MUX_ADDR, GPIO_SD_B2_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_08 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B2_08 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_08 {}
 
     const GPIO_SD_B2_09_MUX_ADDR: u32 = 0x400e81d8;
     const GPIO_SD_B2_09_PAD_ADDR: u32 = 0x400e841c;
     pub type GPIO_SD_B2_09 = crate::Pad<GPIO_SD_B2_09_MUX_ADDR, GPIO_SD_B2_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_09 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B2_09 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_09 {}
 
     const GPIO_SD_B2_10_MUX_ADDR: u32 = 0x400e81dc;
     const GPIO_SD_B2_10_PAD_ADDR: u32 = 0x400e8420;
     pub type GPIO_SD_B2_10 = crate::Pad<GPIO_SD_B2_10_MUX_ADDR, GPIO_SD_B2_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_10 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B2_10 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_10 {}
 
     const GPIO_SD_B2_11_MUX_ADDR: u32 = 0x400e81e0;
     const GPIO_SD_B2_11_PAD_ADDR: u32 = 0x400e8424;
     pub type GPIO_SD_B2_11 = crate::Pad<GPIO_SD_B2_11_MUX_ADDR, GPIO_SD_B2_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SD_B2_11 {}
 
     impl crate::gpio::Pin<4> for GPIO_SD_B2_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<1> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<2> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<3> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<4> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<5> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<6> for GPIO_SD_B2_11 {}
+    impl crate::AnyAltPin<10> for GPIO_SD_B2_11 {}
 
     /// All pads with prefix GPIO_SD_B2.
     pub struct Pads {
@@ -2078,118 +3216,259 @@ pub mod gpio_sd_b2 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_DISP_B1.
+#[cfg(feature = "imxrt1170-gpio-disp-b1")]
 pub mod gpio_disp_b1 {
 
     const GPIO_DISP_B1_00_MUX_ADDR: u32 = 0x400e81e4;
     const GPIO_DISP_B1_00_PAD_ADDR: u32 = 0x400e8428;
     pub type GPIO_DISP_B1_00 = crate::Pad<GPIO_DISP_B1_00_MUX_ADDR, GPIO_DISP_B1_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_00 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_00 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_00 {}
 
     const GPIO_DISP_B1_01_MUX_ADDR: u32 = 0x400e81e8;
     const GPIO_DISP_B1_01_PAD_ADDR: u32 = 0x400e842c;
     pub type GPIO_DISP_B1_01 = crate::Pad<GPIO_DISP_B1_01_MUX_ADDR, GPIO_DISP_B1_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_01 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_01 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_01 {}
 
     const GPIO_DISP_B1_02_MUX_ADDR: u32 = 0x400e81ec;
     const GPIO_DISP_B1_02_PAD_ADDR: u32 = 0x400e8430;
     pub type GPIO_DISP_B1_02 = crate::Pad<GPIO_DISP_B1_02_MUX_ADDR, GPIO_DISP_B1_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_02 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_02 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_02 {}
 
     const GPIO_DISP_B1_03_MUX_ADDR: u32 = 0x400e81f0;
     const GPIO_DISP_B1_03_PAD_ADDR: u32 = 0x400e8434;
     pub type GPIO_DISP_B1_03 = crate::Pad<GPIO_DISP_B1_03_MUX_ADDR, GPIO_DISP_B1_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_03 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_03 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_03 {}
 
     const GPIO_DISP_B1_04_MUX_ADDR: u32 = 0x400e81f4;
     const GPIO_DISP_B1_04_PAD_ADDR: u32 = 0x400e8438;
     pub type GPIO_DISP_B1_04 = crate::Pad<GPIO_DISP_B1_04_MUX_ADDR, GPIO_DISP_B1_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_04 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_04 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_04 {}
 
     const GPIO_DISP_B1_05_MUX_ADDR: u32 = 0x400e81f8;
     const GPIO_DISP_B1_05_PAD_ADDR: u32 = 0x400e843c;
     pub type GPIO_DISP_B1_05 = crate::Pad<GPIO_DISP_B1_05_MUX_ADDR, GPIO_DISP_B1_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_05 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_05 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_05 {}
 
     const GPIO_DISP_B1_06_MUX_ADDR: u32 = 0x400e81fc;
     const GPIO_DISP_B1_06_PAD_ADDR: u32 = 0x400e8440;
     pub type GPIO_DISP_B1_06 = crate::Pad<GPIO_DISP_B1_06_MUX_ADDR, GPIO_DISP_B1_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_06 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_06 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_06 {}
 
     const GPIO_DISP_B1_07_MUX_ADDR: u32 = 0x400e8200;
     const GPIO_DISP_B1_07_PAD_ADDR: u32 = 0x400e8444;
     pub type GPIO_DISP_B1_07 = crate::Pad<GPIO_DISP_B1_07_MUX_ADDR, GPIO_DISP_B1_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_07 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_07 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_07 {}
 
     const GPIO_DISP_B1_08_MUX_ADDR: u32 = 0x400e8204;
     const GPIO_DISP_B1_08_PAD_ADDR: u32 = 0x400e8448;
     pub type GPIO_DISP_B1_08 = crate::Pad<GPIO_DISP_B1_08_MUX_ADDR, GPIO_DISP_B1_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_08 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 2;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_08 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_08 {}
 
     const GPIO_DISP_B1_09_MUX_ADDR: u32 = 0x400e8208;
     const GPIO_DISP_B1_09_PAD_ADDR: u32 = 0x400e844c;
     pub type GPIO_DISP_B1_09 = crate::Pad<GPIO_DISP_B1_09_MUX_ADDR, GPIO_DISP_B1_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_09 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_09 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_09 {}
 
     const GPIO_DISP_B1_10_MUX_ADDR: u32 = 0x400e820c;
     const GPIO_DISP_B1_10_PAD_ADDR: u32 = 0x400e8450;
     pub type GPIO_DISP_B1_10 = crate::Pad<GPIO_DISP_B1_10_MUX_ADDR, GPIO_DISP_B1_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_10 {}
 
     impl crate::gpio::Pin<4> for GPIO_DISP_B1_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 3;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_10 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_10 {}
 
     const GPIO_DISP_B1_11_MUX_ADDR: u32 = 0x400e8210;
     const GPIO_DISP_B1_11_PAD_ADDR: u32 = 0x400e8454;
     pub type GPIO_DISP_B1_11 = crate::Pad<GPIO_DISP_B1_11_MUX_ADDR, GPIO_DISP_B1_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B1_11 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B1_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B1_11 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B1_11 {}
 
     /// All pads with prefix GPIO_DISP_B1.
     pub struct Pads {
@@ -2261,154 +3540,336 @@ pub mod gpio_disp_b1 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_DISP_B2.
+#[cfg(feature = "imxrt1170-gpio-disp-b2")]
 pub mod gpio_disp_b2 {
 
     const GPIO_DISP_B2_00_MUX_ADDR: u32 = 0x400e8214;
     const GPIO_DISP_B2_00_PAD_ADDR: u32 = 0x400e8458;
     pub type GPIO_DISP_B2_00 = crate::Pad<GPIO_DISP_B2_00_MUX_ADDR, GPIO_DISP_B2_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_00 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_00 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_00 {}
 
     const GPIO_DISP_B2_01_MUX_ADDR: u32 = 0x400e8218;
     const GPIO_DISP_B2_01_PAD_ADDR: u32 = 0x400e845c;
     pub type GPIO_DISP_B2_01 = crate::Pad<GPIO_DISP_B2_01_MUX_ADDR, GPIO_DISP_B2_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_01 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_01 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_01 {}
 
     const GPIO_DISP_B2_02_MUX_ADDR: u32 = 0x400e821c;
     const GPIO_DISP_B2_02_PAD_ADDR: u32 = 0x400e8460;
     pub type GPIO_DISP_B2_02 = crate::Pad<GPIO_DISP_B2_02_MUX_ADDR, GPIO_DISP_B2_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_02 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_02 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_02 {}
 
     const GPIO_DISP_B2_03_MUX_ADDR: u32 = 0x400e8220;
     const GPIO_DISP_B2_03_PAD_ADDR: u32 = 0x400e8464;
     pub type GPIO_DISP_B2_03 = crate::Pad<GPIO_DISP_B2_03_MUX_ADDR, GPIO_DISP_B2_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_03 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_03 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_03 {}
 
     const GPIO_DISP_B2_04_MUX_ADDR: u32 = 0x400e8224;
     const GPIO_DISP_B2_04_PAD_ADDR: u32 = 0x400e8468;
     pub type GPIO_DISP_B2_04 = crate::Pad<GPIO_DISP_B2_04_MUX_ADDR, GPIO_DISP_B2_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_04 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_04 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_04 {}
 
     const GPIO_DISP_B2_05_MUX_ADDR: u32 = 0x400e8228;
     const GPIO_DISP_B2_05_PAD_ADDR: u32 = 0x400e846c;
     pub type GPIO_DISP_B2_05 = crate::Pad<GPIO_DISP_B2_05_MUX_ADDR, GPIO_DISP_B2_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_05 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_05 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_05 {}
 
     const GPIO_DISP_B2_06_MUX_ADDR: u32 = 0x400e822c;
     const GPIO_DISP_B2_06_PAD_ADDR: u32 = 0x400e8470;
     pub type GPIO_DISP_B2_06 = crate::Pad<GPIO_DISP_B2_06_MUX_ADDR, GPIO_DISP_B2_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_06 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_06 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_06 {}
 
     const GPIO_DISP_B2_07_MUX_ADDR: u32 = 0x400e8230;
     const GPIO_DISP_B2_07_PAD_ADDR: u32 = 0x400e8474;
     pub type GPIO_DISP_B2_07 = crate::Pad<GPIO_DISP_B2_07_MUX_ADDR, GPIO_DISP_B2_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_07 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_07 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_07 {}
 
     const GPIO_DISP_B2_08_MUX_ADDR: u32 = 0x400e8234;
     const GPIO_DISP_B2_08_PAD_ADDR: u32 = 0x400e8478;
     pub type GPIO_DISP_B2_08 = crate::Pad<GPIO_DISP_B2_08_MUX_ADDR, GPIO_DISP_B2_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_08 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_08 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_08 {}
 
     const GPIO_DISP_B2_09_MUX_ADDR: u32 = 0x400e8238;
     const GPIO_DISP_B2_09_PAD_ADDR: u32 = 0x400e847c;
     pub type GPIO_DISP_B2_09 = crate::Pad<GPIO_DISP_B2_09_MUX_ADDR, GPIO_DISP_B2_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_09 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_09 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_09 {}
 
     const GPIO_DISP_B2_10_MUX_ADDR: u32 = 0x400e823c;
     const GPIO_DISP_B2_10_PAD_ADDR: u32 = 0x400e8480;
     pub type GPIO_DISP_B2_10 = crate::Pad<GPIO_DISP_B2_10_MUX_ADDR, GPIO_DISP_B2_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_10 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_10 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_10 {}
 
     const GPIO_DISP_B2_11_MUX_ADDR: u32 = 0x400e8240;
     const GPIO_DISP_B2_11_PAD_ADDR: u32 = 0x400e8484;
     pub type GPIO_DISP_B2_11 = crate::Pad<GPIO_DISP_B2_11_MUX_ADDR, GPIO_DISP_B2_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_11 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_11 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_11 {}
 
     const GPIO_DISP_B2_12_MUX_ADDR: u32 = 0x400e8244;
     const GPIO_DISP_B2_12_PAD_ADDR: u32 = 0x400e8488;
     pub type GPIO_DISP_B2_12 = crate::Pad<GPIO_DISP_B2_12_MUX_ADDR, GPIO_DISP_B2_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_12 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_12 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_12 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_12 {}
 
     const GPIO_DISP_B2_13_MUX_ADDR: u32 = 0x400e8248;
     const GPIO_DISP_B2_13_PAD_ADDR: u32 = 0x400e848c;
     pub type GPIO_DISP_B2_13 = crate::Pad<GPIO_DISP_B2_13_MUX_ADDR, GPIO_DISP_B2_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_13 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_13 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_13 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_13 {}
 
     const GPIO_DISP_B2_14_MUX_ADDR: u32 = 0x400e824c;
     const GPIO_DISP_B2_14_PAD_ADDR: u32 = 0x400e8490;
     pub type GPIO_DISP_B2_14 = crate::Pad<GPIO_DISP_B2_14_MUX_ADDR, GPIO_DISP_B2_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_14 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_14 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_14 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_14 {}
 
     const GPIO_DISP_B2_15_MUX_ADDR: u32 = 0x400e8250;
     const GPIO_DISP_B2_15_PAD_ADDR: u32 = 0x400e8494;
     pub type GPIO_DISP_B2_15 = crate::Pad<GPIO_DISP_B2_15_MUX_ADDR, GPIO_DISP_B2_15_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_DISP_B2_15 {}
 
     impl crate::gpio::Pin<5> for GPIO_DISP_B2_15 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<1> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<2> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<3> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<4> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<5> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<6> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<8> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<9> for GPIO_DISP_B2_15 {}
+    impl crate::AnyAltPin<10> for GPIO_DISP_B2_15 {}
 
     /// All pads with prefix GPIO_DISP_B2.
     pub struct Pads {
@@ -2492,154 +3953,316 @@ pub mod gpio_disp_b2 {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// Pads with the prefix GPIO_LPSR.
+#[cfg(feature = "imxrt1170-gpio-lpsr")]
 pub mod gpio_lpsr {
 
     const GPIO_LPSR_00_MUX_ADDR: u32 = 0x40c08000;
     const GPIO_LPSR_00_PAD_ADDR: u32 = 0x40c08040;
     pub type GPIO_LPSR_00 = crate::Pad<GPIO_LPSR_00_MUX_ADDR, GPIO_LPSR_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_00 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_00 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_00 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_00 {}
 
     const GPIO_LPSR_01_MUX_ADDR: u32 = 0x40c08004;
     const GPIO_LPSR_01_PAD_ADDR: u32 = 0x40c08044;
     pub type GPIO_LPSR_01 = crate::Pad<GPIO_LPSR_01_MUX_ADDR, GPIO_LPSR_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_01 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_01 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_01 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_01 {}
 
     const GPIO_LPSR_02_MUX_ADDR: u32 = 0x40c08008;
     const GPIO_LPSR_02_PAD_ADDR: u32 = 0x40c08048;
     pub type GPIO_LPSR_02 = crate::Pad<GPIO_LPSR_02_MUX_ADDR, GPIO_LPSR_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_02 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_02 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_02 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_02 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_02 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_02 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_02 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_02 {}
 
     const GPIO_LPSR_03_MUX_ADDR: u32 = 0x40c0800c;
     const GPIO_LPSR_03_PAD_ADDR: u32 = 0x40c0804c;
     pub type GPIO_LPSR_03 = crate::Pad<GPIO_LPSR_03_MUX_ADDR, GPIO_LPSR_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_03 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_03 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_03 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_03 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_03 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_03 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_03 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_03 {}
 
     const GPIO_LPSR_04_MUX_ADDR: u32 = 0x40c08010;
     const GPIO_LPSR_04_PAD_ADDR: u32 = 0x40c08050;
     pub type GPIO_LPSR_04 = crate::Pad<GPIO_LPSR_04_MUX_ADDR, GPIO_LPSR_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_04 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_04 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_04 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_04 {}
 
     const GPIO_LPSR_05_MUX_ADDR: u32 = 0x40c08014;
     const GPIO_LPSR_05_PAD_ADDR: u32 = 0x40c08054;
     pub type GPIO_LPSR_05 = crate::Pad<GPIO_LPSR_05_MUX_ADDR, GPIO_LPSR_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_05 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_05 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_05 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_05 {}
 
     const GPIO_LPSR_06_MUX_ADDR: u32 = 0x40c08018;
     const GPIO_LPSR_06_PAD_ADDR: u32 = 0x40c08058;
     pub type GPIO_LPSR_06 = crate::Pad<GPIO_LPSR_06_MUX_ADDR, GPIO_LPSR_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_06 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_06 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_06 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_06 {}
 
     const GPIO_LPSR_07_MUX_ADDR: u32 = 0x40c0801c;
     const GPIO_LPSR_07_PAD_ADDR: u32 = 0x40c0805c;
     pub type GPIO_LPSR_07 = crate::Pad<GPIO_LPSR_07_MUX_ADDR, GPIO_LPSR_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_07 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_07 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_07 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_07 {}
 
     const GPIO_LPSR_08_MUX_ADDR: u32 = 0x40c08020;
     const GPIO_LPSR_08_PAD_ADDR: u32 = 0x40c08060;
     pub type GPIO_LPSR_08 = crate::Pad<GPIO_LPSR_08_MUX_ADDR, GPIO_LPSR_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_08 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_08 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_08 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_08 {}
 
     const GPIO_LPSR_09_MUX_ADDR: u32 = 0x40c08024;
     const GPIO_LPSR_09_PAD_ADDR: u32 = 0x40c08064;
     pub type GPIO_LPSR_09 = crate::Pad<GPIO_LPSR_09_MUX_ADDR, GPIO_LPSR_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_09 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_09 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 0;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_09 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_09 {}
 
     const GPIO_LPSR_10_MUX_ADDR: u32 = 0x40c08028;
     const GPIO_LPSR_10_PAD_ADDR: u32 = 0x40c08068;
     pub type GPIO_LPSR_10 = crate::Pad<GPIO_LPSR_10_MUX_ADDR, GPIO_LPSR_10_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_10 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_10 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_10 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_10 {}
 
     const GPIO_LPSR_11_MUX_ADDR: u32 = 0x40c0802c;
     const GPIO_LPSR_11_PAD_ADDR: u32 = 0x40c0806c;
     pub type GPIO_LPSR_11 = crate::Pad<GPIO_LPSR_11_MUX_ADDR, GPIO_LPSR_11_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_11 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_11 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_11 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_11 {}
 
     const GPIO_LPSR_12_MUX_ADDR: u32 = 0x40c08030;
     const GPIO_LPSR_12_PAD_ADDR: u32 = 0x40c08070;
     pub type GPIO_LPSR_12 = crate::Pad<GPIO_LPSR_12_MUX_ADDR, GPIO_LPSR_12_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_12 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_12 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<3> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<4> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<6> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_12 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_12 {}
 
     const GPIO_LPSR_13_MUX_ADDR: u32 = 0x40c08034;
     const GPIO_LPSR_13_PAD_ADDR: u32 = 0x40c08074;
     pub type GPIO_LPSR_13 = crate::Pad<GPIO_LPSR_13_MUX_ADDR, GPIO_LPSR_13_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_13 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_13 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_13 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_13 {}
 
     const GPIO_LPSR_14_MUX_ADDR: u32 = 0x40c08038;
     const GPIO_LPSR_14_PAD_ADDR: u32 = 0x40c08078;
     pub type GPIO_LPSR_14 = crate::Pad<GPIO_LPSR_14_MUX_ADDR, GPIO_LPSR_14_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_14 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_14 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_14 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_14 {}
 
     const GPIO_LPSR_15_MUX_ADDR: u32 = 0x40c0803c;
     const GPIO_LPSR_15_PAD_ADDR: u32 = 0x40c0807c;
     pub type GPIO_LPSR_15 = crate::Pad<GPIO_LPSR_15_MUX_ADDR, GPIO_LPSR_15_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_LPSR_15 {}
 
     impl crate::gpio::Pin<6> for GPIO_LPSR_15 {
         const ALT: u32 = 5;
         const OFFSET: u32 = 1;
     }
+    impl crate::AnyAltPin<0> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<1> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<2> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<5> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<7> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<8> for GPIO_LPSR_15 {}
+    impl crate::AnyAltPin<10> for GPIO_LPSR_15 {}
 
     /// All pads with prefix GPIO_LPSR.
     pub struct Pads {
@@ -2723,18 +4346,355 @@ pub mod gpio_lpsr {
             ]
         }
     }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
+}
+
+/// Pads with the prefix SNVS.
+#[cfg(feature = "imxrt1170-snvs")]
+pub mod snvs {
+
+    const WAKEUP_MUX_ADDR: u32 = 0x40c94000;
+    const WAKEUP_PAD_ADDR: u32 = 0x40c94040;
+    pub type WAKEUP = crate::Pad<WAKEUP_MUX_ADDR, WAKEUP_PAD_ADDR>;
+
+    impl crate::gpio::Pin<13> for WAKEUP {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 0;
+    }
+    impl crate::AnyAltPin<5> for WAKEUP {}
+    impl crate::AnyAltPin<7> for WAKEUP {}
+
+    const PMIC_ON_REQ_MUX_ADDR: u32 = 0x40c94004;
+    const PMIC_ON_REQ_PAD_ADDR: u32 = 0x40c94044;
+    pub type PMIC_ON_REQ = crate::Pad<PMIC_ON_REQ_MUX_ADDR, PMIC_ON_REQ_PAD_ADDR>;
+    impl crate::OutputCapable for PMIC_ON_REQ {}
+
+    impl crate::gpio::Pin<13> for PMIC_ON_REQ {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 1;
+    }
+    impl crate::AnyAltPin<0> for PMIC_ON_REQ {}
+    impl crate::AnyAltPin<5> for PMIC_ON_REQ {}
+
+    const PMIC_STBY_REQ_MUX_ADDR: u32 = 0x40c94008;
+    const PMIC_STBY_REQ_PAD_ADDR: u32 = 0x40c94048;
+    pub type PMIC_STBY_REQ = crate::Pad<PMIC_STBY_REQ_MUX_ADDR, PMIC_STBY_REQ_PAD_ADDR>;
+    impl crate::OutputCapable for PMIC_STBY_REQ {}
+
+    impl crate::gpio::Pin<13> for PMIC_STBY_REQ {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 2;
+    }
+    impl crate::AnyAltPin<0> for PMIC_STBY_REQ {}
+    impl crate::AnyAltPin<5> for PMIC_STBY_REQ {}
+
+    /// All pads with prefix SNVS.
+    pub struct Pads {
+        pub wakeup: WAKEUP,
+        pub pmic_on_req: PMIC_ON_REQ,
+        pub pmic_stby_req: PMIC_STBY_REQ,
+    }
+    /// Erased pads with prefix SNVS.
+    ///
+    /// Use [`Pads::erase()`] to get an `ErasedPads` instance.
+    pub type ErasedPads = [crate::ErasedPad; 3];
+    impl Pads {
+        /// Take all pads from this group
+        ///
+        /// # Safety
+        ///
+        /// You may safely call this once to acquire all of the pads.
+        /// Subsequent calls may return pads that are mutably aliased
+        /// elsewhere. Consider calling new() at the start of your program.
+        #[inline]
+        pub const unsafe fn new() -> Self {
+            Self {
+                wakeup: WAKEUP::new(),
+                pmic_on_req: PMIC_ON_REQ::new(),
+                pmic_stby_req: PMIC_STBY_REQ::new(),
+            }
+        }
+
+        /// Erase all of the pads
+        ///
+        /// The return type is an array, where the index indicates the
+        /// pad offset from the start of the group. For example, AD_B0_03
+        /// would be referenced as erased_pads\[3\].
+        ///
+        /// See `ErasedPads` for more information.
+        #[inline]
+        pub const fn erase(self) -> ErasedPads {
+            [
+                self.pmic_on_req.erase(),
+                self.pmic_stby_req.erase(),
+                self.wakeup.erase(),
+            ]
+        }
+    }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
+}
+
+/// Pads with the prefix GPIO_SNVS.
+#[cfg(feature = "imxrt1170-gpio-snvs")]
+pub mod gpio_snvs {
+
+    const GPIO_SNVS_00_MUX_ADDR: u32 = 0x40c9400c;
+    const GPIO_SNVS_00_PAD_ADDR: u32 = 0x40c9404c;
+    pub type GPIO_SNVS_00 = crate::Pad<GPIO_SNVS_00_MUX_ADDR, GPIO_SNVS_00_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_00 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_00 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 3;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_00 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_00 {}
+
+    const GPIO_SNVS_01_MUX_ADDR: u32 = 0x40c94010;
+    const GPIO_SNVS_01_PAD_ADDR: u32 = 0x40c94050;
+    pub type GPIO_SNVS_01 = crate::Pad<GPIO_SNVS_01_MUX_ADDR, GPIO_SNVS_01_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_01 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_01 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 4;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_01 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_01 {}
+
+    const GPIO_SNVS_02_MUX_ADDR: u32 = 0x40c94014;
+    const GPIO_SNVS_02_PAD_ADDR: u32 = 0x40c94054;
+    pub type GPIO_SNVS_02 = crate::Pad<GPIO_SNVS_02_MUX_ADDR, GPIO_SNVS_02_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_02 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_02 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 5;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_02 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_02 {}
+
+    const GPIO_SNVS_03_MUX_ADDR: u32 = 0x40c94018;
+    const GPIO_SNVS_03_PAD_ADDR: u32 = 0x40c94058;
+    pub type GPIO_SNVS_03 = crate::Pad<GPIO_SNVS_03_MUX_ADDR, GPIO_SNVS_03_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_03 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_03 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 6;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_03 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_03 {}
+
+    const GPIO_SNVS_04_MUX_ADDR: u32 = 0x40c9401c;
+    const GPIO_SNVS_04_PAD_ADDR: u32 = 0x40c9405c;
+    pub type GPIO_SNVS_04 = crate::Pad<GPIO_SNVS_04_MUX_ADDR, GPIO_SNVS_04_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_04 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_04 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 7;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_04 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_04 {}
+
+    const GPIO_SNVS_05_MUX_ADDR: u32 = 0x40c94020;
+    const GPIO_SNVS_05_PAD_ADDR: u32 = 0x40c94060;
+    pub type GPIO_SNVS_05 = crate::Pad<GPIO_SNVS_05_MUX_ADDR, GPIO_SNVS_05_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_05 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_05 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 8;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_05 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_05 {}
+
+    const GPIO_SNVS_06_MUX_ADDR: u32 = 0x40c94024;
+    const GPIO_SNVS_06_PAD_ADDR: u32 = 0x40c94064;
+    pub type GPIO_SNVS_06 = crate::Pad<GPIO_SNVS_06_MUX_ADDR, GPIO_SNVS_06_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_06 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_06 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 9;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_06 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_06 {}
+
+    const GPIO_SNVS_07_MUX_ADDR: u32 = 0x40c94028;
+    const GPIO_SNVS_07_PAD_ADDR: u32 = 0x40c94068;
+    pub type GPIO_SNVS_07 = crate::Pad<GPIO_SNVS_07_MUX_ADDR, GPIO_SNVS_07_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_07 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_07 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 10;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_07 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_07 {}
+
+    const GPIO_SNVS_08_MUX_ADDR: u32 = 0x40c9402c;
+    const GPIO_SNVS_08_PAD_ADDR: u32 = 0x40c9406c;
+    pub type GPIO_SNVS_08 = crate::Pad<GPIO_SNVS_08_MUX_ADDR, GPIO_SNVS_08_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_08 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_08 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 11;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_08 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_08 {}
+
+    const GPIO_SNVS_09_MUX_ADDR: u32 = 0x40c94030;
+    const GPIO_SNVS_09_PAD_ADDR: u32 = 0x40c94070;
+    pub type GPIO_SNVS_09 = crate::Pad<GPIO_SNVS_09_MUX_ADDR, GPIO_SNVS_09_PAD_ADDR>;
+    impl crate::OutputCapable for GPIO_SNVS_09 {}
+
+    impl crate::gpio::Pin<13> for GPIO_SNVS_09 {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 12;
+    }
+    impl crate::AnyAltPin<0> for GPIO_SNVS_09 {}
+    impl crate::AnyAltPin<5> for GPIO_SNVS_09 {}
+
+    /// All pads with prefix GPIO_SNVS.
+    pub struct Pads {
+        pub p00: GPIO_SNVS_00,
+        pub p01: GPIO_SNVS_01,
+        pub p02: GPIO_SNVS_02,
+        pub p03: GPIO_SNVS_03,
+        pub p04: GPIO_SNVS_04,
+        pub p05: GPIO_SNVS_05,
+        pub p06: GPIO_SNVS_06,
+        pub p07: GPIO_SNVS_07,
+        pub p08: GPIO_SNVS_08,
+        pub p09: GPIO_SNVS_09,
+    }
+    /// Erased pads with prefix GPIO_SNVS.
+    ///
+    /// Use [`Pads::erase()`] to get an `ErasedPads` instance.
+    pub type ErasedPads = [crate::ErasedPad; 10];
+    impl Pads {
+        /// Take all pads from this group
+        ///
+        /// # Safety
+        ///
+        /// You may safely call this once to acquire all of the pads.
+        /// Subsequent calls may return pads that are mutably aliased
+        /// elsewhere. Consider calling new() at the start of your program.
+        #[inline]
+        pub const unsafe fn new() -> Self {
+            Self {
+                p00: GPIO_SNVS_00::new(),
+                p01: GPIO_SNVS_01::new(),
+                p02: GPIO_SNVS_02::new(),
+                p03: GPIO_SNVS_03::new(),
+                p04: GPIO_SNVS_04::new(),
+                p05: GPIO_SNVS_05::new(),
+                p06: GPIO_SNVS_06::new(),
+                p07: GPIO_SNVS_07::new(),
+                p08: GPIO_SNVS_08::new(),
+                p09: GPIO_SNVS_09::new(),
+            }
+        }
+
+        /// Erase all of the pads
+        ///
+        /// The return type is an array, where the index indicates the
+        /// pad offset from the start of the group. For example, AD_B0_03
+        /// would be referenced as erased_pads\[3\].
+        ///
+        /// See `ErasedPads` for more information.
+        #[inline]
+        pub const fn erase(self) -> ErasedPads {
+            [
+                self.p00.erase(),
+                self.p01.erase(),
+                self.p02.erase(),
+                self.p03.erase(),
+                self.p04.erase(),
+                self.p05.erase(),
+                self.p06.erase(),
+                self.p07.erase(),
+                self.p08.erase(),
+                self.p09.erase(),
+            ]
+        }
+    }
+    impl ::core::convert::TryFrom<ErasedPads> for Pads {
+        type Error = ErasedPads;
+
+        /// Regroup erased pads
+        ///
+        /// Fails, returning the erased pads, if the erased pads are not this
+        /// group's pads in `erase()` order.
+        fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+            if crate::same_pads(&erased, &unsafe { Self::new() }.erase()) {
+                // Safety: the erased pads represent ownership of this group.
+                Ok(unsafe { Self::new() })
+            } else {
+                Err(erased)
+            }
+        }
+    }
 }
 
 /// All of the pads.
+///
+/// The fields are public, so you can move groups out of `Pads`, and regroup
+/// them with a struct literal. To regroup erased pads, use `TryFrom`.
 pub struct Pads {
+    #[cfg(feature = "imxrt1170-gpio-emc-b1")]
     pub gpio_emc_b1: gpio_emc_b1::Pads,
+    #[cfg(feature = "imxrt1170-gpio-emc-b2")]
     pub gpio_emc_b2: gpio_emc_b2::Pads,
+    #[cfg(feature = "imxrt1170-gpio-ad")]
     pub gpio_ad: gpio_ad::Pads,
+    #[cfg(feature = "imxrt1170-gpio-sd-b1")]
     pub gpio_sd_b1: gpio_sd_b1::Pads,
+    #[cfg(feature = "imxrt1170-gpio-sd-b2")]
     pub gpio_sd_b2: gpio_sd_b2::Pads,
+    #[cfg(feature = "imxrt1170-gpio-disp-b1")]
     pub gpio_disp_b1: gpio_disp_b1::Pads,
+    #[cfg(feature = "imxrt1170-gpio-disp-b2")]
     pub gpio_disp_b2: gpio_disp_b2::Pads,
+    #[cfg(feature = "imxrt1170-gpio-lpsr")]
     pub gpio_lpsr: gpio_lpsr::Pads,
+    #[cfg(feature = "imxrt1170-snvs")]
+    pub snvs: snvs::Pads,
+    #[cfg(feature = "imxrt1170-gpio-snvs")]
+    pub gpio_snvs: gpio_snvs::Pads,
 }
 
 impl Pads {
@@ -2748,14 +4708,26 @@ impl Pads {
     #[inline]
     pub const unsafe fn new() -> Self {
         Self {
+            #[cfg(feature = "imxrt1170-gpio-emc-b1")]
             gpio_emc_b1: gpio_emc_b1::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-emc-b2")]
             gpio_emc_b2: gpio_emc_b2::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-ad")]
             gpio_ad: gpio_ad::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-sd-b1")]
             gpio_sd_b1: gpio_sd_b1::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-sd-b2")]
             gpio_sd_b2: gpio_sd_b2::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-disp-b1")]
             gpio_disp_b1: gpio_disp_b1::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-disp-b2")]
             gpio_disp_b2: gpio_disp_b2::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-lpsr")]
             gpio_lpsr: gpio_lpsr::Pads::new(),
+            #[cfg(feature = "imxrt1170-snvs")]
+            snvs: snvs::Pads::new(),
+            #[cfg(feature = "imxrt1170-gpio-snvs")]
+            gpio_snvs: gpio_snvs::Pads::new(),
         }
     }
 
@@ -2769,26 +4741,153 @@ impl Pads {
     #[inline]
     pub const fn erase(self) -> ErasedPads {
         ErasedPads {
+            #[cfg(feature = "imxrt1170-gpio-emc-b1")]
             gpio_emc_b1: self.gpio_emc_b1.erase(),
+            #[cfg(feature = "imxrt1170-gpio-emc-b2")]
             gpio_emc_b2: self.gpio_emc_b2.erase(),
+            #[cfg(feature = "imxrt1170-gpio-ad")]
             gpio_ad: self.gpio_ad.erase(),
+            #[cfg(feature = "imxrt1170-gpio-sd-b1")]
             gpio_sd_b1: self.gpio_sd_b1.erase(),
+            #[cfg(feature = "imxrt1170-gpio-sd-b2")]
             gpio_sd_b2: self.gpio_sd_b2.erase(),
+            #[cfg(feature = "imxrt1170-gpio-disp-b1")]
             gpio_disp_b1: self.gpio_disp_b1.erase(),
+            #[cfg(feature = "imxrt1170-gpio-disp-b2")]
             gpio_disp_b2: self.gpio_disp_b2.erase(),
+            #[cfg(feature = "imxrt1170-gpio-lpsr")]
             gpio_lpsr: self.gpio_lpsr.erase(),
+            #[cfg(feature = "imxrt1170-snvs")]
+            snvs: self.snvs.erase(),
+            #[cfg(feature = "imxrt1170-gpio-snvs")]
+            gpio_snvs: self.gpio_snvs.erase(),
         }
     }
 }
 
 /// All erased pads.
 pub struct ErasedPads {
+    #[cfg(feature = "imxrt1170-gpio-emc-b1")]
     pub gpio_emc_b1: gpio_emc_b1::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-emc-b2")]
     pub gpio_emc_b2: gpio_emc_b2::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-ad")]
     pub gpio_ad: gpio_ad::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-sd-b1")]
     pub gpio_sd_b1: gpio_sd_b1::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-sd-b2")]
     pub gpio_sd_b2: gpio_sd_b2::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-disp-b1")]
     pub gpio_disp_b1: gpio_disp_b1::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-disp-b2")]
     pub gpio_disp_b2: gpio_disp_b2::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-lpsr")]
     pub gpio_lpsr: gpio_lpsr::ErasedPads,
+    #[cfg(feature = "imxrt1170-snvs")]
+    pub snvs: snvs::ErasedPads,
+    #[cfg(feature = "imxrt1170-gpio-snvs")]
+    pub gpio_snvs: gpio_snvs::ErasedPads,
+}
+
+impl ::core::convert::TryFrom<ErasedPads> for Pads {
+    type Error = ErasedPads;
+
+    /// Regroup all erased pads
+    ///
+    /// Fails, returning the erased pads, if any group does not match.
+    fn try_from(erased: ErasedPads) -> Result<Self, Self::Error> {
+        let expected = unsafe { Self::new() }.erase();
+        let same: &[bool] = &[
+            #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+            crate::same_pads(&erased.gpio_emc_b1, &expected.gpio_emc_b1),
+            #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+            crate::same_pads(&erased.gpio_emc_b2, &expected.gpio_emc_b2),
+            #[cfg(feature = "imxrt1170-gpio-ad")]
+            crate::same_pads(&erased.gpio_ad, &expected.gpio_ad),
+            #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+            crate::same_pads(&erased.gpio_sd_b1, &expected.gpio_sd_b1),
+            #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+            crate::same_pads(&erased.gpio_sd_b2, &expected.gpio_sd_b2),
+            #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+            crate::same_pads(&erased.gpio_disp_b1, &expected.gpio_disp_b1),
+            #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+            crate::same_pads(&erased.gpio_disp_b2, &expected.gpio_disp_b2),
+            #[cfg(feature = "imxrt1170-gpio-lpsr")]
+            crate::same_pads(&erased.gpio_lpsr, &expected.gpio_lpsr),
+            #[cfg(feature = "imxrt1170-snvs")]
+            crate::same_pads(&erased.snvs, &expected.snvs),
+            #[cfg(feature = "imxrt1170-gpio-snvs")]
+            crate::same_pads(&erased.gpio_snvs, &expected.gpio_snvs),
+        ];
+        if same.iter().all(|same| *same) {
+            // Safety: the erased pads represent ownership of all pads.
+            Ok(unsafe { Self::new() })
+        } else {
+            Err(erased)
+        }
+    }
+}
+
+impl ErasedPads {
+    /// Returns the erased pad at `location`
+    ///
+    /// Returns `None` if none of the groups has the pad.
+    pub fn get(&self, location: crate::PadLocation) -> Option<&crate::ErasedPad> {
+        crate::find_pad(
+            [
+                #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+                &self.gpio_emc_b1,
+                #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+                &self.gpio_emc_b2,
+                #[cfg(feature = "imxrt1170-gpio-ad")]
+                &self.gpio_ad,
+                #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+                &self.gpio_sd_b1,
+                #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+                &self.gpio_sd_b2,
+                #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+                &self.gpio_disp_b1,
+                #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+                &self.gpio_disp_b2,
+                #[cfg(feature = "imxrt1170-gpio-lpsr")]
+                &self.gpio_lpsr,
+                #[cfg(feature = "imxrt1170-snvs")]
+                &self.snvs,
+                #[cfg(feature = "imxrt1170-gpio-snvs")]
+                &self.gpio_snvs,
+            ],
+            location,
+        )
+    }
+
+    /// Returns the mutable erased pad at `location`
+    ///
+    /// Returns `None` if none of the groups has the pad.
+    pub fn get_mut(&mut self, location: crate::PadLocation) -> Option<&mut crate::ErasedPad> {
+        crate::find_pad_mut(
+            [
+                #[cfg(feature = "imxrt1170-gpio-emc-b1")]
+                &mut self.gpio_emc_b1,
+                #[cfg(feature = "imxrt1170-gpio-emc-b2")]
+                &mut self.gpio_emc_b2,
+                #[cfg(feature = "imxrt1170-gpio-ad")]
+                &mut self.gpio_ad,
+                #[cfg(feature = "imxrt1170-gpio-sd-b1")]
+                &mut self.gpio_sd_b1,
+                #[cfg(feature = "imxrt1170-gpio-sd-b2")]
+                &mut self.gpio_sd_b2,
+                #[cfg(feature = "imxrt1170-gpio-disp-b1")]
+                &mut self.gpio_disp_b1,
+                #[cfg(feature = "imxrt1170-gpio-disp-b2")]
+                &mut self.gpio_disp_b2,
+                #[cfg(feature = "imxrt1170-gpio-lpsr")]
+                &mut self.gpio_lpsr,
+                #[cfg(feature = "imxrt1170-snvs")]
+                &mut self.snvs,
+                #[cfg(feature = "imxrt1170-gpio-snvs")]
+                &mut self.gpio_snvs,
+            ],
+            location,
+        )
+    }
 }
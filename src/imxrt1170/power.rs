@@ -0,0 +1,130 @@
+//! Power domain checks for low-power systems
+//!
+//! On the 1170, the IOMUXC is in the WAKEUPMIX power domain, and the IOMUXC_LPSR
+//! is in the LPSRMIX power domain. The IOMUXC_SNVS is in the SNVS power
+//! domain. When a domain is powered off, accessing its
+//! register block results in a bus fault.
+//!
+//! Describe the domains that are on with [`Powered`]. Then, use [`check()`] or
+//! [`try_configure()`] to report an [`Unpowered`] error before touching the pad.
+//! This module doesn't read the power controller; your firmware tracks the
+//! domains' states.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{Config, SlewRate};
+//! use imxrt_iomuxc::imxrt1170::{gpio_ad::GPIO_AD_04, power::{self, PowerDomain, Powered}};
+//!
+//! // Only the LPSR domain is on while the system is suspended.
+//! const SUSPENDED: Powered = Powered::new().on(PowerDomain::Lpsr);
+//!
+//! let mut pad = unsafe { GPIO_AD_04::new() };
+//! let config = Config::modify().set_slew_rate(SlewRate::Fast);
+//! assert!(power::try_configure(&SUSPENDED, &mut pad, config).is_err());
+//! ```
+
+use super::domain::Instance;
+use crate::{Config, Iomuxc};
+
+/// A power domain that contains an IOMUXC register block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerDomain {
+    /// The WAKEUPMIX; contains the IOMUXC
+    Wakeup,
+    /// The LPSRMIX; contains the IOMUXC_LPSR, used by the `GPIO_LPSR` pads
+    Lpsr,
+    /// The SNVS domain; contains the IOMUXC_SNVS, used by the `SNVS` and
+    /// `GPIO_SNVS` pads
+    Snvs,
+}
+
+impl PowerDomain {
+    /// Returns the power domain that contains the pad's registers
+    ///
+    /// Returns `None` if the pad's registers are not in an IOMUXC.
+    pub fn of<I: Iomuxc>(pad: &mut I) -> Option<Self> {
+        Instance::of(pad).map(|instance| match instance {
+            Instance::Iomuxc => PowerDomain::Wakeup,
+            Instance::IomuxcLpsr => PowerDomain::Lpsr,
+            Instance::IomuxcSnvs => PowerDomain::Snvs,
+        })
+    }
+}
+
+/// The power domains that are on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Powered {
+    wakeup: bool,
+    lpsr: bool,
+    snvs: bool,
+}
+
+impl Powered {
+    /// No domains are on
+    pub const fn new() -> Self {
+        Self {
+            wakeup: false,
+            lpsr: false,
+            snvs: false,
+        }
+    }
+    /// Mark the `domain` as on
+    pub const fn on(mut self, domain: PowerDomain) -> Self {
+        match domain {
+            PowerDomain::Wakeup => self.wakeup = true,
+            PowerDomain::Lpsr => self.lpsr = true,
+            PowerDomain::Snvs => self.snvs = true,
+        }
+        self
+    }
+    /// Mark the `domain` as off
+    pub const fn off(mut self, domain: PowerDomain) -> Self {
+        match domain {
+            PowerDomain::Wakeup => self.wakeup = false,
+            PowerDomain::Lpsr => self.lpsr = false,
+            PowerDomain::Snvs => self.snvs = false,
+        }
+        self
+    }
+    /// Returns `true` if the `domain` is on
+    pub const fn is_on(&self, domain: PowerDomain) -> bool {
+        match domain {
+            PowerDomain::Wakeup => self.wakeup,
+            PowerDomain::Lpsr => self.lpsr,
+            PowerDomain::Snvs => self.snvs,
+        }
+    }
+}
+
+impl Default for Powered {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The pad's power domain is off
+///
+/// The value is the power domain that holds the pad's registers, or `None` if
+/// the pad's registers are not in an IOMUXC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unpowered(pub Option<PowerDomain>);
+
+/// Check that the pad's power domain is on
+pub fn check<I: Iomuxc>(powered: &Powered, pad: &mut I) -> Result<(), Unpowered> {
+    match PowerDomain::of(pad) {
+        Some(domain) if powered.is_on(domain) => Ok(()),
+        domain => Err(Unpowered(domain)),
+    }
+}
+
+/// Apply the configuration if the pad's power domain is on
+///
+/// Otherwise, leave the pad untouched, and return the error.
+pub fn try_configure<I: Iomuxc>(
+    powered: &Powered,
+    pad: &mut I,
+    config: Config,
+) -> Result<(), Unpowered> {
+    check(powered, pad)?;
+    crate::configure(pad, config);
+    Ok(())
+}
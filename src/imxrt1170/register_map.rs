@@ -0,0 +1,1541 @@
+// Generated by registermap.py
+use crate::register_map::{DaisyEntry, PadRegisters};
+
+/// All pads, and their registers
+///
+/// Each entry lists the daisy values that select the pad. The table doesn't
+/// know the pads' reset values.
+pub const REGISTER_MAP: &[PadRegisters] = &[
+    PadRegisters {
+        name: "GPIO_EMC_B1_00",
+        mux: 0x400e8010,
+        pad: 0x400e8254,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_01",
+        mux: 0x400e8014,
+        pad: 0x400e8258,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_02",
+        mux: 0x400e8018,
+        pad: 0x400e825c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_03",
+        mux: 0x400e801c,
+        pad: 0x400e8260,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_04",
+        mux: 0x400e8020,
+        pad: 0x400e8264,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_05",
+        mux: 0x400e8024,
+        pad: 0x400e8268,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_06",
+        mux: 0x400e8028,
+        pad: 0x400e826c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_07",
+        mux: 0x400e802c,
+        pad: 0x400e8270,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_08",
+        mux: 0x400e8030,
+        pad: 0x400e8274,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_09",
+        mux: 0x400e8034,
+        pad: 0x400e8278,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_10",
+        mux: 0x400e8038,
+        pad: 0x400e827c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_11",
+        mux: 0x400e803c,
+        pad: 0x400e8280,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_12",
+        mux: 0x400e8040,
+        pad: 0x400e8284,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_13",
+        mux: 0x400e8044,
+        pad: 0x400e8288,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_14",
+        mux: 0x400e8048,
+        pad: 0x400e828c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_15",
+        mux: 0x400e804c,
+        pad: 0x400e8290,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_16",
+        mux: 0x400e8050,
+        pad: 0x400e8294,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_17",
+        mux: 0x400e8054,
+        pad: 0x400e8298,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_18",
+        mux: 0x400e8058,
+        pad: 0x400e829c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_19",
+        mux: 0x400e805c,
+        pad: 0x400e82a0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_20",
+        mux: 0x400e8060,
+        pad: 0x400e82a4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_21",
+        mux: 0x400e8064,
+        pad: 0x400e82a8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_22",
+        mux: 0x400e8068,
+        pad: 0x400e82ac,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_23",
+        mux: 0x400e806c,
+        pad: 0x400e82b0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_24",
+        mux: 0x400e8070,
+        pad: 0x400e82b4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_25",
+        mux: 0x400e8074,
+        pad: 0x400e82b8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_26",
+        mux: 0x400e8078,
+        pad: 0x400e82bc,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_27",
+        mux: 0x400e807c,
+        pad: 0x400e82c0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_28",
+        mux: 0x400e8080,
+        pad: 0x400e82c4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_29",
+        mux: 0x400e8084,
+        pad: 0x400e82c8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_30",
+        mux: 0x400e8088,
+        pad: 0x400e82cc,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_31",
+        mux: 0x400e808c,
+        pad: 0x400e82d0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_32",
+        mux: 0x400e8090,
+        pad: 0x400e82d4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_33",
+        mux: 0x400e8094,
+        pad: 0x400e82d8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_34",
+        mux: 0x400e8098,
+        pad: 0x400e82dc,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_35",
+        mux: 0x400e809c,
+        pad: 0x400e82e0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_36",
+        mux: 0x400e80a0,
+        pad: 0x400e82e4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_37",
+        mux: 0x400e80a4,
+        pad: 0x400e82e8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_38",
+        mux: 0x400e80a8,
+        pad: 0x400e82ec,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_39",
+        mux: 0x400e80ac,
+        pad: 0x400e82f0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_40",
+        mux: 0x400e80b0,
+        pad: 0x400e82f4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B1_41",
+        mux: 0x400e80b4,
+        pad: 0x400e82f8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_00",
+        mux: 0x400e80b8,
+        pad: 0x400e82fc,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85b4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e85d0,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_01",
+        mux: 0x400e80bc,
+        pad: 0x400e8300,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85b8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e85cc,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_02",
+        mux: 0x400e80c0,
+        pad: 0x400e8304,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85d8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_03",
+        mux: 0x400e80c4,
+        pad: 0x400e8308,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85d4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_04",
+        mux: 0x400e80c8,
+        pad: 0x400e830c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8600,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_05",
+        mux: 0x400e80cc,
+        pad: 0x400e8310,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_06",
+        mux: 0x400e80d0,
+        pad: 0x400e8314,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8608,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_07",
+        mux: 0x400e80d4,
+        pad: 0x400e8318,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8604,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_08",
+        mux: 0x400e80d8,
+        pad: 0x400e831c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f4,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_09",
+        mux: 0x400e80dc,
+        pad: 0x400e8320,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_10",
+        mux: 0x400e80e0,
+        pad: 0x400e8324,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85fc,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_11",
+        mux: 0x400e80e4,
+        pad: 0x400e8328,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_12",
+        mux: 0x400e80e8,
+        pad: 0x400e832c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_13",
+        mux: 0x400e80ec,
+        pad: 0x400e8330,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_14",
+        mux: 0x400e80f0,
+        pad: 0x400e8334,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_15",
+        mux: 0x400e80f4,
+        pad: 0x400e8338,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_16",
+        mux: 0x400e80f8,
+        pad: 0x400e833c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_17",
+        mux: 0x400e80fc,
+        pad: 0x400e8340,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_18",
+        mux: 0x400e8100,
+        pad: 0x400e8344,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_19",
+        mux: 0x400e8104,
+        pad: 0x400e8348,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_EMC_B2_20",
+        mux: 0x400e8108,
+        pad: 0x400e834c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_00",
+        mux: 0x400e810c,
+        pad: 0x400e8350,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8630,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_01",
+        mux: 0x400e8110,
+        pad: 0x400e8354,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e862c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_02",
+        mux: 0x400e8114,
+        pad: 0x400e8358,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8638,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_03",
+        mux: 0x400e8118,
+        pad: 0x400e835c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8634,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_04",
+        mux: 0x400e811c,
+        pad: 0x400e8360,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_05",
+        mux: 0x400e8120,
+        pad: 0x400e8364,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_06",
+        mux: 0x400e8124,
+        pad: 0x400e8368,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_07",
+        mux: 0x400e8128,
+        pad: 0x400e836c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_08",
+        mux: 0x400e812c,
+        pad: 0x400e8370,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85ac,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_09",
+        mux: 0x400e8130,
+        pad: 0x400e8374,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85b0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_10",
+        mux: 0x400e8134,
+        pad: 0x400e8378,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_11",
+        mux: 0x400e8138,
+        pad: 0x400e837c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_12",
+        mux: 0x400e813c,
+        pad: 0x400e8380,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_13",
+        mux: 0x400e8140,
+        pad: 0x400e8384,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_14",
+        mux: 0x400e8144,
+        pad: 0x400e8388,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_15",
+        mux: 0x400e8148,
+        pad: 0x400e838c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8628,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_16",
+        mux: 0x400e814c,
+        pad: 0x400e8390,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8624,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_17",
+        mux: 0x400e8150,
+        pad: 0x400e8394,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_18",
+        mux: 0x400e8154,
+        pad: 0x400e8398,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85b4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_19",
+        mux: 0x400e8158,
+        pad: 0x400e839c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85b8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_20",
+        mux: 0x400e815c,
+        pad: 0x400e83a0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_21",
+        mux: 0x400e8160,
+        pad: 0x400e83a4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85e0,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_22",
+        mux: 0x400e8164,
+        pad: 0x400e83a8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_23",
+        mux: 0x400e8168,
+        pad: 0x400e83ac,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_24",
+        mux: 0x400e816c,
+        pad: 0x400e83b0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85c4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e85e4,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e8620,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_25",
+        mux: 0x400e8170,
+        pad: 0x400e83b4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85c8,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e85dc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e861c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_26",
+        mux: 0x400e8174,
+        pad: 0x400e83b8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85ec,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_27",
+        mux: 0x400e8178,
+        pad: 0x400e83bc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85e8,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_28",
+        mux: 0x400e817c,
+        pad: 0x400e83c0,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85d0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_29",
+        mux: 0x400e8180,
+        pad: 0x400e83c4,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85cc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_30",
+        mux: 0x400e8184,
+        pad: 0x400e83c8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85d8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_31",
+        mux: 0x400e8188,
+        pad: 0x400e83cc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85d4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_AD_32",
+        mux: 0x400e818c,
+        pad: 0x400e83d0,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85ac,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x400e8628,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_33",
+        mux: 0x400e8190,
+        pad: 0x400e83d4,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85b0,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x400e8624,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_AD_34",
+        mux: 0x400e8194,
+        pad: 0x400e83d8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_AD_35",
+        mux: 0x400e8198,
+        pad: 0x400e83dc,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_00",
+        mux: 0x400e819c,
+        pad: 0x400e83e0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_01",
+        mux: 0x400e81a0,
+        pad: 0x400e83e4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_02",
+        mux: 0x400e81a4,
+        pad: 0x400e83e8,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_03",
+        mux: 0x400e81a8,
+        pad: 0x400e83ec,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_04",
+        mux: 0x400e81ac,
+        pad: 0x400e83f0,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B1_05",
+        mux: 0x400e81b0,
+        pad: 0x400e83f4,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_00",
+        mux: 0x400e81b4,
+        pad: 0x400e83f8,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8610,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_01",
+        mux: 0x400e81b8,
+        pad: 0x400e83fc,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e860c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_02",
+        mux: 0x400e81bc,
+        pad: 0x400e8400,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8618,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_03",
+        mux: 0x400e81c0,
+        pad: 0x400e8404,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8614,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_04",
+        mux: 0x400e81c4,
+        pad: 0x400e8408,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_05",
+        mux: 0x400e81c8,
+        pad: 0x400e840c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_06",
+        mux: 0x400e81cc,
+        pad: 0x400e8410,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_07",
+        mux: 0x400e81d0,
+        pad: 0x400e8414,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85e4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_08",
+        mux: 0x400e81d4,
+        pad: 0x400e8418,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85dc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_09",
+        mux: 0x400e81d8,
+        pad: 0x400e841c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85ec,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_10",
+        mux: 0x400e81dc,
+        pad: 0x400e8420,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85e8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_SD_B2_11",
+        mux: 0x400e81e0,
+        pad: 0x400e8424,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85e0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_00",
+        mux: 0x400e81e4,
+        pad: 0x400e8428,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_01",
+        mux: 0x400e81e8,
+        pad: 0x400e842c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_02",
+        mux: 0x400e81ec,
+        pad: 0x400e8430,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85bc,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e8620,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_03",
+        mux: 0x400e81f0,
+        pad: 0x400e8434,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85c0,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x400e861c,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_04",
+        mux: 0x400e81f4,
+        pad: 0x400e8438,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8600,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_05",
+        mux: 0x400e81f8,
+        pad: 0x400e843c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8604,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_06",
+        mux: 0x400e81fc,
+        pad: 0x400e8440,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8608,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_07",
+        mux: 0x400e8200,
+        pad: 0x400e8444,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_08",
+        mux: 0x400e8204,
+        pad: 0x400e8448,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f4,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_09",
+        mux: 0x400e8208,
+        pad: 0x400e844c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85f8,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_10",
+        mux: 0x400e820c,
+        pad: 0x400e8450,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85fc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B1_11",
+        mux: 0x400e8210,
+        pad: 0x400e8454,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_00",
+        mux: 0x400e8214,
+        pad: 0x400e8458,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_01",
+        mux: 0x400e8218,
+        pad: 0x400e845c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_02",
+        mux: 0x400e821c,
+        pad: 0x400e8460,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_03",
+        mux: 0x400e8220,
+        pad: 0x400e8464,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_04",
+        mux: 0x400e8224,
+        pad: 0x400e8468,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_05",
+        mux: 0x400e8228,
+        pad: 0x400e846c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_06",
+        mux: 0x400e822c,
+        pad: 0x400e8470,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8630,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_07",
+        mux: 0x400e8230,
+        pad: 0x400e8474,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e862c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_08",
+        mux: 0x400e8234,
+        pad: 0x400e8478,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e8620,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x400e8638,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_09",
+        mux: 0x400e8238,
+        pad: 0x400e847c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e861c,
+                value: 2,
+            },
+            DaisyEntry {
+                reg: 0x400e8634,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_10",
+        mux: 0x400e823c,
+        pad: 0x400e8480,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85bc,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_11",
+        mux: 0x400e8240,
+        pad: 0x400e8484,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e85c0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_12",
+        mux: 0x400e8244,
+        pad: 0x400e8488,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85c4,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x400e8610,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_13",
+        mux: 0x400e8248,
+        pad: 0x400e848c,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x400e85c8,
+                value: 1,
+            },
+            DaisyEntry {
+                reg: 0x400e8614,
+                value: 1,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_14",
+        mux: 0x400e824c,
+        pad: 0x400e8490,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e8618,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_DISP_B2_15",
+        mux: 0x400e8250,
+        pad: 0x400e8494,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x400e860c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_00",
+        mux: 0x40c08000,
+        pad: 0x40c08040,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_01",
+        mux: 0x40c08004,
+        pad: 0x40c08044,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_02",
+        mux: 0x40c08008,
+        pad: 0x40c08048,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08098,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_03",
+        mux: 0x40c0800c,
+        pad: 0x40c0804c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08094,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_04",
+        mux: 0x40c08010,
+        pad: 0x40c08050,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x40c08088,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x40c080a0,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_05",
+        mux: 0x40c08014,
+        pad: 0x40c08054,
+        reset: None,
+        daisies: &[
+            DaisyEntry {
+                reg: 0x40c08084,
+                value: 0,
+            },
+            DaisyEntry {
+                reg: 0x40c0809c,
+                value: 0,
+            },
+        ],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_06",
+        mux: 0x40c08018,
+        pad: 0x40c08058,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08090,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_07",
+        mux: 0x40c0801c,
+        pad: 0x40c0805c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c0808c,
+            value: 0,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_08",
+        mux: 0x40c08020,
+        pad: 0x40c08060,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08088,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_09",
+        mux: 0x40c08024,
+        pad: 0x40c08064,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08084,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_10",
+        mux: 0x40c08028,
+        pad: 0x40c08068,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08090,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_11",
+        mux: 0x40c0802c,
+        pad: 0x40c0806c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c0808c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_12",
+        mux: 0x40c08030,
+        pad: 0x40c08070,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08098,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_13",
+        mux: 0x40c08034,
+        pad: 0x40c08074,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c08094,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_14",
+        mux: 0x40c08038,
+        pad: 0x40c08078,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c080a0,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "GPIO_LPSR_15",
+        mux: 0x40c0803c,
+        pad: 0x40c0807c,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x40c0809c,
+            value: 1,
+        }],
+    },
+    PadRegisters {
+        name: "WAKEUP",
+        mux: 0x40c94000,
+        pad: 0x40c94040,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "PMIC_ON_REQ",
+        mux: 0x40c94004,
+        pad: 0x40c94044,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "PMIC_STBY_REQ",
+        mux: 0x40c94008,
+        pad: 0x40c94048,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_00",
+        mux: 0x40c9400c,
+        pad: 0x40c9404c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_01",
+        mux: 0x40c94010,
+        pad: 0x40c94050,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_02",
+        mux: 0x40c94014,
+        pad: 0x40c94054,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_03",
+        mux: 0x40c94018,
+        pad: 0x40c94058,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_04",
+        mux: 0x40c9401c,
+        pad: 0x40c9405c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_05",
+        mux: 0x40c94020,
+        pad: 0x40c94060,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_06",
+        mux: 0x40c94024,
+        pad: 0x40c94064,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_07",
+        mux: 0x40c94028,
+        pad: 0x40c94068,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_08",
+        mux: 0x40c9402c,
+        pad: 0x40c9406c,
+        reset: None,
+        daisies: &[],
+    },
+    PadRegisters {
+        name: "GPIO_SNVS_09",
+        mux: 0x40c94030,
+        pad: 0x40c94070,
+        reset: None,
+        daisies: &[],
+    },
+];
@@ -0,0 +1,14298 @@
+// Generated by signals.py
+
+use crate::signals::{Alternate, Database, SelectInput};
+
+/// Every alternate and select input of the chip.
+pub const DATABASE: Database = Database {
+    alternates: &[
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8010,
+                pad: 0x400e8254,
+            },
+            alt: 0,
+            signal: "SEMC_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8010,
+                pad: 0x400e8254,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8010,
+                pad: 0x400e8254,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8010,
+                pad: 0x400e8254,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8010,
+                pad: 0x400e8254,
+            },
+            alt: 10,
+            signal: "GPIO7_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8014,
+                pad: 0x400e8258,
+            },
+            alt: 0,
+            signal: "SEMC_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8014,
+                pad: 0x400e8258,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8014,
+                pad: 0x400e8258,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8014,
+                pad: 0x400e8258,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8014,
+                pad: 0x400e8258,
+            },
+            alt: 10,
+            signal: "GPIO7_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8018,
+                pad: 0x400e825c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8018,
+                pad: 0x400e825c,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8018,
+                pad: 0x400e825c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8018,
+                pad: 0x400e825c,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8018,
+                pad: 0x400e825c,
+            },
+            alt: 10,
+            signal: "GPIO7_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e801c,
+                pad: 0x400e8260,
+            },
+            alt: 0,
+            signal: "SEMC_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e801c,
+                pad: 0x400e8260,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e801c,
+                pad: 0x400e8260,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e801c,
+                pad: 0x400e8260,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e801c,
+                pad: 0x400e8260,
+            },
+            alt: 10,
+            signal: "GPIO7_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8020,
+                pad: 0x400e8264,
+            },
+            alt: 0,
+            signal: "SEMC_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8020,
+                pad: 0x400e8264,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8020,
+                pad: 0x400e8264,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8020,
+                pad: 0x400e8264,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8020,
+                pad: 0x400e8264,
+            },
+            alt: 10,
+            signal: "GPIO7_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8024,
+                pad: 0x400e8268,
+            },
+            alt: 0,
+            signal: "SEMC_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8024,
+                pad: 0x400e8268,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8024,
+                pad: 0x400e8268,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8024,
+                pad: 0x400e8268,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8024,
+                pad: 0x400e8268,
+            },
+            alt: 10,
+            signal: "GPIO7_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            alt: 10,
+            signal: "GPIO7_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            alt: 0,
+            signal: "SEMC_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            alt: 10,
+            signal: "GPIO7_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            alt: 0,
+            signal: "SEMC_DM00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            alt: 10,
+            signal: "GPIO7_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 2,
+            signal: "GPT5_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            alt: 10,
+            signal: "GPIO7_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 2,
+            signal: "GPT5_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            alt: 10,
+            signal: "GPIO7_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 2,
+            signal: "GPT5_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            alt: 10,
+            signal: "GPIO7_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 2,
+            signal: "GPT5_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8040,
+                pad: 0x400e8284,
+            },
+            alt: 10,
+            signal: "GPIO7_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 2,
+            signal: "GPT5_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8044,
+                pad: 0x400e8288,
+            },
+            alt: 10,
+            signal: "GPIO7_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 2,
+            signal: "GPT5_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8048,
+                pad: 0x400e828c,
+            },
+            alt: 10,
+            signal: "GPIO7_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e804c,
+                pad: 0x400e8290,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e804c,
+                pad: 0x400e8290,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e804c,
+                pad: 0x400e8290,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e804c,
+                pad: 0x400e8290,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e804c,
+                pad: 0x400e8290,
+            },
+            alt: 10,
+            signal: "GPIO7_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8050,
+                pad: 0x400e8294,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8050,
+                pad: 0x400e8294,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8050,
+                pad: 0x400e8294,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8050,
+                pad: 0x400e8294,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8050,
+                pad: 0x400e8294,
+            },
+            alt: 10,
+            signal: "GPIO7_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 2,
+            signal: "TMR1_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            alt: 10,
+            signal: "GPIO7_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 1,
+            signal: "FLEXPWM4_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 2,
+            signal: "TMR2_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            alt: 10,
+            signal: "GPIO7_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 2,
+            signal: "TMR3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            alt: 10,
+            signal: "GPIO7_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 1,
+            signal: "FLEXPWM2_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 2,
+            signal: "TMR4_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            alt: 10,
+            signal: "GPIO7_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            alt: 0,
+            signal: "SEMC_BA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            alt: 10,
+            signal: "GPIO7_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            alt: 0,
+            signal: "SEMC_BA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            alt: 10,
+            signal: "GPIO7_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            alt: 0,
+            signal: "SEMC_ADDR10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            alt: 10,
+            signal: "GPIO7_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            alt: 0,
+            signal: "SEMC_CAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            alt: 10,
+            signal: "GPIO7_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            alt: 0,
+            signal: "SEMC_RAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            alt: 10,
+            signal: "GPIO7_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            alt: 0,
+            signal: "SEMC_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            alt: 10,
+            signal: "GPIO7_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            alt: 0,
+            signal: "SEMC_CKE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            alt: 10,
+            signal: "GPIO7_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            alt: 0,
+            signal: "SEMC_WE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            alt: 10,
+            signal: "GPIO7_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            alt: 0,
+            signal: "SEMC_CS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            alt: 10,
+            signal: "GPIO7_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            alt: 0,
+            signal: "SEMC_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            alt: 10,
+            signal: "GPIO7_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            alt: 0,
+            signal: "SEMC_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX1_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            alt: 8,
+            signal: "FLEXIO1_D31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            alt: 10,
+            signal: "GPIO7_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8090,
+                pad: 0x400e82d4,
+            },
+            alt: 0,
+            signal: "SEMC_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8090,
+                pad: 0x400e82d4,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8090,
+                pad: 0x400e82d4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8090,
+                pad: 0x400e82d4,
+            },
+            alt: 10,
+            signal: "GPIO8_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8094,
+                pad: 0x400e82d8,
+            },
+            alt: 0,
+            signal: "SEMC_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8094,
+                pad: 0x400e82d8,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8094,
+                pad: 0x400e82d8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8094,
+                pad: 0x400e82d8,
+            },
+            alt: 10,
+            signal: "GPIO8_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8098,
+                pad: 0x400e82dc,
+            },
+            alt: 0,
+            signal: "SEMC_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8098,
+                pad: 0x400e82dc,
+            },
+            alt: 1,
+            signal: "FLEXPWM3_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8098,
+                pad: 0x400e82dc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8098,
+                pad: 0x400e82dc,
+            },
+            alt: 10,
+            signal: "GPIO8_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e809c,
+                pad: 0x400e82e0,
+            },
+            alt: 0,
+            signal: "SEMC_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e809c,
+                pad: 0x400e82e0,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e809c,
+                pad: 0x400e82e0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e809c,
+                pad: 0x400e82e0,
+            },
+            alt: 10,
+            signal: "GPIO8_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a0,
+                pad: 0x400e82e4,
+            },
+            alt: 0,
+            signal: "SEMC_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a0,
+                pad: 0x400e82e4,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a0,
+                pad: 0x400e82e4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a0,
+                pad: 0x400e82e4,
+            },
+            alt: 10,
+            signal: "GPIO8_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a4,
+                pad: 0x400e82e8,
+            },
+            alt: 0,
+            signal: "SEMC_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a4,
+                pad: 0x400e82e8,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a4,
+                pad: 0x400e82e8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a4,
+                pad: 0x400e82e8,
+            },
+            alt: 10,
+            signal: "GPIO8_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            alt: 0,
+            signal: "SEMC_DM01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            alt: 2,
+            signal: "TMR1_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            alt: 10,
+            signal: "GPIO8_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            alt: 0,
+            signal: "SEMC_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            alt: 1,
+            signal: "FLEXPWM1_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            alt: 2,
+            signal: "TMR2_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            alt: 10,
+            signal: "GPIO8_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 0,
+            signal: "SEMC_RDY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 2,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 3,
+            signal: "LPUART6_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 7,
+            signal: "ENET_1G_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 9,
+            signal: "CCM_CLKO1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b0,
+                pad: 0x400e82f4,
+            },
+            alt: 10,
+            signal: "GPIO8_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 0,
+            signal: "SEMC_CSX00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 2,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 3,
+            signal: "LPUART6_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 7,
+            signal: "ENET_1G_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 9,
+            signal: "CCM_CLKO2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            alt: 10,
+            signal: "GPIO8_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 0,
+            signal: "SEMC_DATA16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 1,
+            signal: "CCM_ENET_REF_CLK_25M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 2,
+            signal: "TMR3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 3,
+            signal: "LPUART6_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 7,
+            signal: "ENET_QOS_1588_EVENT1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 8,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 9,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 10,
+            signal: "GPIO8_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 0,
+            signal: "SEMC_DATA17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 1,
+            signal: "USDHC2_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 2,
+            signal: "TMR4_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 3,
+            signal: "LPUART6_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 7,
+            signal: "ENET_QOS_1588_EVENT1_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 8,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 9,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 10,
+            signal: "GPIO8_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 0,
+            signal: "SEMC_DATA18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 1,
+            signal: "USDHC2_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 7,
+            signal: "ENET_QOS_1588_EVENT1_AUX_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 8,
+            signal: "LPSPI1_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 10,
+            signal: "GPIO8_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 0,
+            signal: "SEMC_DATA19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 1,
+            signal: "USDHC2_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 7,
+            signal: "ENET_1G_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 8,
+            signal: "LPSPI1_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 10,
+            signal: "GPIO8_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 1,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 2,
+            signal: "SAI2_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 7,
+            signal: "ENET_1G_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 8,
+            signal: "LPSPI3_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 10,
+            signal: "GPIO8_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 0,
+            signal: "SEMC_DATA21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 1,
+            signal: "GPT3_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 2,
+            signal: "SAI2_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 7,
+            signal: "ENET_1G_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 8,
+            signal: "LPSPI3_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 9,
+            signal: "PIT1_TRIGGER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 10,
+            signal: "GPIO8_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 0,
+            signal: "SEMC_DATA22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 1,
+            signal: "GPT3_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 2,
+            signal: "SAI2_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 7,
+            signal: "ENET_1G_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 8,
+            signal: "LPSPI3_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 9,
+            signal: "PIT1_TRIGGER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 10,
+            signal: "GPIO8_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM3_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 0,
+            signal: "SEMC_DATA23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 1,
+            signal: "GPT3_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 2,
+            signal: "SAI2_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 7,
+            signal: "ENET_1G_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 8,
+            signal: "LPSPI3_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 9,
+            signal: "PIT1_TRIGGER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 10,
+            signal: "GPIO8_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM3_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 0,
+            signal: "SEMC_DM02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 1,
+            signal: "GPT3_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 2,
+            signal: "SAI2_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 7,
+            signal: "ENET_1G_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 8,
+            signal: "LPSPI3_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 9,
+            signal: "PIT1_TRIGGER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            alt: 10,
+            signal: "GPIO8_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 0,
+            signal: "SEMC_DATA24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 1,
+            signal: "GPT3_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 2,
+            signal: "SAI2_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_DATA16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 7,
+            signal: "ENET_1G_CRS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 8,
+            signal: "LPSPI3_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 9,
+            signal: "TMR1_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            alt: 10,
+            signal: "GPIO8_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 0,
+            signal: "SEMC_DATA25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 1,
+            signal: "GPT3_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 2,
+            signal: "SAI2_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_CSI_FIELD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 7,
+            signal: "ENET_1G_COL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 8,
+            signal: "LPSPI3_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 9,
+            signal: "TMR1_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            alt: 10,
+            signal: "GPIO8_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 0,
+            signal: "SEMC_DATA26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 1,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 3,
+            signal: "SAI3_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 8,
+            signal: "EMVSIM1_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 9,
+            signal: "TMR1_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            alt: 10,
+            signal: "GPIO8_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 1,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 3,
+            signal: "SAI3_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT32",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 8,
+            signal: "EMVSIM1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 9,
+            signal: "TMR1_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            alt: 10,
+            signal: "GPIO8_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 0,
+            signal: "SEMC_DATA28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 3,
+            signal: "SAI3_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT33",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 8,
+            signal: "EMVSIM1_RST",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 9,
+            signal: "TMR2_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            alt: 10,
+            signal: "GPIO8_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 0,
+            signal: "SEMC_DATA29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_CLK_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 3,
+            signal: "SAI3_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT34",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 7,
+            signal: "SFA_ipp_do_atx_clk_under_test",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 8,
+            signal: "EMVSIM1_SVEN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 9,
+            signal: "TMR2_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            alt: 10,
+            signal: "GPIO8_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 0,
+            signal: "SEMC_DATA30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 3,
+            signal: "SAI3_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 6,
+            signal: "XBAR1_INOUT35",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 8,
+            signal: "EMVSIM1_PD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 9,
+            signal: "TMR2_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            alt: 10,
+            signal: "GPIO8_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 0,
+            signal: "SEMC_DATA31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 3,
+            signal: "SAI3_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 8,
+            signal: "EMVSIM1_POWER_FAIL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 9,
+            signal: "TMR2_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            alt: 10,
+            signal: "GPIO8_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 0,
+            signal: "SEMC_DM03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 3,
+            signal: "SAI3_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 8,
+            signal: "WDOG1_ANY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 9,
+            signal: "TMR3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            alt: 10,
+            signal: "GPIO8_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 0,
+            signal: "SEMC_DQS4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 1,
+            signal: "XBAR1_INOUT16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 3,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 6,
+            signal: "FLEXSPI1_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 8,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 9,
+            signal: "TMR3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            alt: 10,
+            signal: "GPIO8_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 0,
+            signal: "SEMC_CLKX00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 1,
+            signal: "ENET_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 2,
+            signal: "ENET_1G_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 3,
+            signal: "ENET_1G_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 8,
+            signal: "ENET_QOS_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 9,
+            signal: "TMR3_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            alt: 10,
+            signal: "GPIO8_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 0,
+            signal: "SEMC_CLKX01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 1,
+            signal: "ENET_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 2,
+            signal: "ENET_1G_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 3,
+            signal: "ENET_QOS_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 4,
+            signal: "FLEXSPI2_A_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 9,
+            signal: "TMR3_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            alt: 10,
+            signal: "GPIO8_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 0,
+            signal: "EMVSIM1_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 1,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT1_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 3,
+            signal: "GPT2_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 5,
+            signal: "GPIO_MUX2_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 6,
+            signal: "LPUART7_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 9,
+            signal: "FLEXSPI2_B_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            alt: 10,
+            signal: "GPIO8_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 0,
+            signal: "EMVSIM1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 1,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 3,
+            signal: "GPT2_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 6,
+            signal: "LPUART7_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 9,
+            signal: "FLEXSPI2_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            alt: 10,
+            signal: "GPIO9_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 0,
+            signal: "EMVSIM1_RST",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 1,
+            signal: "LPUART7_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT2_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 3,
+            signal: "GPT2_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 6,
+            signal: "LPUART8_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 9,
+            signal: "VIDEO_MUX_EXT_DCIC1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            alt: 10,
+            signal: "GPIO9_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 0,
+            signal: "EMVSIM1_SVEN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 1,
+            signal: "LPUART7_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 3,
+            signal: "GPT2_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 6,
+            signal: "LPUART8_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 9,
+            signal: "VIDEO_MUX_EXT_DCIC2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            alt: 10,
+            signal: "GPIO9_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 0,
+            signal: "EMVSIM1_PD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 1,
+            signal: "LPUART8_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT3_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 3,
+            signal: "GPT2_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 6,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 9,
+            signal: "TMR4_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            alt: 10,
+            signal: "GPIO9_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 0,
+            signal: "EMVSIM1_POWER_FAIL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 1,
+            signal: "LPUART8_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 2,
+            signal: "ENET_1G_1588_EVENT3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 3,
+            signal: "GPT2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 4,
+            signal: "FLEXPWM1_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 6,
+            signal: "WDOG2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 9,
+            signal: "TMR4_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            alt: 10,
+            signal: "GPIO9_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 0,
+            signal: "USB_OTG2_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 1,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 2,
+            signal: "EMVSIM2_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 3,
+            signal: "GPT3_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT1_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 9,
+            signal: "TMR4_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 10,
+            signal: "GPIO9_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            alt: 11,
+            signal: "FLEXPWM1_PWM0_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 0,
+            signal: "USB_OTG2_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 1,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 2,
+            signal: "EMVSIM2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 3,
+            signal: "GPT3_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 9,
+            signal: "TMR4_TIMER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 10,
+            signal: "GPIO9_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            alt: 11,
+            signal: "FLEXPWM1_PWM1_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 0,
+            signal: "USBPHY2_OTG_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 1,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 2,
+            signal: "EMVSIM2_RST",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 3,
+            signal: "GPT3_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT2_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 10,
+            signal: "GPIO9_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            alt: 11,
+            signal: "FLEXPWM1_PWM2_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 0,
+            signal: "USBPHY1_OTG_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 1,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 2,
+            signal: "EMVSIM2_SVEN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 3,
+            signal: "GPT3_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 10,
+            signal: "GPIO9_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            alt: 11,
+            signal: "FLEXPWM1_PWM3_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 0,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 1,
+            signal: "LPI2C1_SCLS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 2,
+            signal: "EMVSIM2_PD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 3,
+            signal: "GPT3_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT3_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 10,
+            signal: "GPIO9_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            alt: 11,
+            signal: "FLEXPWM2_PWM0_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 0,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 1,
+            signal: "LPI2C1_SDAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 2,
+            signal: "EMVSIM2_POWER_FAIL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 3,
+            signal: "GPT3_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 6,
+            signal: "ENET_1588_EVENT3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 10,
+            signal: "GPIO9_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            alt: 11,
+            signal: "FLEXPWM2_PWM1_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 0,
+            signal: "SPDIF_LOCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 1,
+            signal: "LPI2C1_HREQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 2,
+            signal: "GPT1_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_PIXCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 6,
+            signal: "ENET_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 9,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 10,
+            signal: "GPIO9_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            alt: 11,
+            signal: "FLEXPWM2_PWM2_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 0,
+            signal: "SPDIF_SR_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 1,
+            signal: "PIT1_TRIGGER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 2,
+            signal: "GPT1_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 6,
+            signal: "ENET_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 9,
+            signal: "REF_CLK_32K",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 10,
+            signal: "GPIO9_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            alt: 11,
+            signal: "FLEXPWM2_PWM3_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 0,
+            signal: "SPDIF_EXT_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 1,
+            signal: "REF_CLK_24M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 6,
+            signal: "ENET_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 9,
+            signal: "CCM_ENET_REF_CLK_25M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 10,
+            signal: "GPIO9_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM0_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 0,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 1,
+            signal: "LPUART10_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 6,
+            signal: "ENET_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 10,
+            signal: "GPIO9_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM1_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 0,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 1,
+            signal: "LPUART10_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 2,
+            signal: "GPT1_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 6,
+            signal: "ENET_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 9,
+            signal: "ENET_1G_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 10,
+            signal: "GPIO9_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM2_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 0,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 1,
+            signal: "ACMP1_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 2,
+            signal: "GPT1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 6,
+            signal: "ENET_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 9,
+            signal: "ENET_1G_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 10,
+            signal: "GPIO9_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            alt: 11,
+            signal: "FLEXPWM3_PWM3_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 0,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 1,
+            signal: "ACMP2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 6,
+            signal: "ENET_CRS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 9,
+            signal: "LPI2C2_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 10,
+            signal: "GPIO9_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            alt: 11,
+            signal: "FLEXPWM4_PWM0_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 0,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 1,
+            signal: "ACMP3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 6,
+            signal: "ENET_COL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 9,
+            signal: "LPI2C2_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 10,
+            signal: "GPIO9_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            alt: 11,
+            signal: "FLEXPWM4_PWM1_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 0,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 1,
+            signal: "ACMP4_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 2,
+            signal: "LPSPI1_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 6,
+            signal: "KPP_ROW07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT2_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 10,
+            signal: "GPIO9_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            alt: 11,
+            signal: "FLEXPWM4_PWM2_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 0,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 2,
+            signal: "LPSPI2_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 6,
+            signal: "KPP_COL07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT2_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 10,
+            signal: "GPIO9_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            alt: 11,
+            signal: "FLEXPWM4_PWM3_X",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 0,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 2,
+            signal: "LPSPI2_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 6,
+            signal: "KPP_ROW06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT3_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            alt: 10,
+            signal: "GPIO9_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 0,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 2,
+            signal: "LPSPI2_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 4,
+            signal: "VIDEO_MUX_CSI_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 6,
+            signal: "KPP_COL06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT3_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            alt: 10,
+            signal: "GPIO9_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 0,
+            signal: "LPUART1_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 1,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 2,
+            signal: "VIDEO_MUX_CSI_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 3,
+            signal: "ENET_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM0_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 6,
+            signal: "KPP_ROW05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 9,
+            signal: "LPI2C4_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            alt: 10,
+            signal: "GPIO9_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 0,
+            signal: "LPUART1_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 1,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 2,
+            signal: "VIDEO_MUX_CSI_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 3,
+            signal: "ENET_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 6,
+            signal: "KPP_COL05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 9,
+            signal: "LPI2C4_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            alt: 10,
+            signal: "GPIO9_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 0,
+            signal: "LPUART1_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 1,
+            signal: "LPSPI2_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 2,
+            signal: "SEMC_CSX01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 3,
+            signal: "ENET_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM1_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 6,
+            signal: "KPP_ROW04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 9,
+            signal: "ENET_QOS_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 10,
+            signal: "GPIO9_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            alt: 11,
+            signal: "USDHC2_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 0,
+            signal: "LPUART1_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 1,
+            signal: "LPSPI2_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 2,
+            signal: "SEMC_CSX02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 3,
+            signal: "ENET_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 6,
+            signal: "KPP_COL04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 9,
+            signal: "ENET_QOS_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 10,
+            signal: "GPIO9_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            alt: 11,
+            signal: "USDHC2_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 0,
+            signal: "LPSPI1_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 1,
+            signal: "LPUART5_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 2,
+            signal: "SEMC_CSX03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 3,
+            signal: "ENET_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM2_A",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 6,
+            signal: "KPP_ROW03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 9,
+            signal: "VIDEO_MUX_EXT_DCIC1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 10,
+            signal: "GPIO9_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            alt: 11,
+            signal: "USDHC2_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 0,
+            signal: "LPSPI1_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 1,
+            signal: "LPUART5_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 2,
+            signal: "ENET_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 3,
+            signal: "ENET_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 4,
+            signal: "FLEXPWM2_PWM2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 6,
+            signal: "KPP_COL03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 9,
+            signal: "VIDEO_MUX_EXT_DCIC2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 10,
+            signal: "GPIO9_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            alt: 11,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 0,
+            signal: "LPSPI1_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 1,
+            signal: "USB_OTG2_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 2,
+            signal: "FLEXCAN2_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 3,
+            signal: "ENET_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 4,
+            signal: "LPUART3_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 6,
+            signal: "KPP_ROW02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 9,
+            signal: "WDOG2_RESET_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            alt: 10,
+            signal: "GPIO9_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 0,
+            signal: "LPSPI1_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 1,
+            signal: "USB_OTG2_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 2,
+            signal: "FLEXCAN2_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 3,
+            signal: "ENET_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 4,
+            signal: "LPUART3_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 6,
+            signal: "KPP_COL02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 8,
+            signal: "FLEXIO2_D31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 9,
+            signal: "WDOG1_RESET_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            alt: 10,
+            signal: "GPIO9_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 0,
+            signal: "LPI2C1_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 1,
+            signal: "USBPHY2_OTG_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 2,
+            signal: "PGMC_PMIC_RDY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 3,
+            signal: "ENET_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 4,
+            signal: "USDHC1_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX3_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 6,
+            signal: "KPP_ROW01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 8,
+            signal: "LPUART10_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 9,
+            signal: "ENET_1G_MDC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            alt: 10,
+            signal: "GPIO9_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 0,
+            signal: "LPI2C1_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 1,
+            signal: "USBPHY1_OTG_ID",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 3,
+            signal: "ENET_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 4,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 6,
+            signal: "KPP_COL01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 8,
+            signal: "LPUART10_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 9,
+            signal: "ENET_1G_MDIO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            alt: 10,
+            signal: "GPIO10_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 0,
+            signal: "ENET_1G_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 1,
+            signal: "USB_OTG1_PWR",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 4,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 6,
+            signal: "KPP_ROW00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 8,
+            signal: "LPUART10_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 9,
+            signal: "WDOG1_ANY",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8194,
+                pad: 0x400e83d8,
+            },
+            alt: 10,
+            signal: "GPIO10_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 0,
+            signal: "ENET_1G_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 1,
+            signal: "USB_OTG1_OC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 3,
+            signal: "ENET_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 4,
+            signal: "USDHC1_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 6,
+            signal: "KPP_COL00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 8,
+            signal: "LPUART10_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 9,
+            signal: "FLEXSPI1_B_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            alt: 10,
+            signal: "GPIO10_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 0,
+            signal: "USDHC1_CMD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 3,
+            signal: "GPT4_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 8,
+            signal: "KPP_ROW07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            alt: 10,
+            signal: "GPIO10_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 0,
+            signal: "USDHC1_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 3,
+            signal: "GPT4_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 8,
+            signal: "KPP_COL07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            alt: 10,
+            signal: "GPIO10_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 3,
+            signal: "GPT4_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 8,
+            signal: "KPP_ROW06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 9,
+            signal: "FLEXSPI1_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            alt: 10,
+            signal: "GPIO10_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 3,
+            signal: "GPT4_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 8,
+            signal: "KPP_COL06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 9,
+            signal: "FLEXSPI1_B_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            alt: 10,
+            signal: "GPIO10_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 3,
+            signal: "GPT4_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 8,
+            signal: "FLEXSPI1_B_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT2_AUX_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            alt: 10,
+            signal: "GPIO10_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 0,
+            signal: "USDHC1_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 2,
+            signal: "XBAR1_INOUT25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 3,
+            signal: "GPT4_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 6,
+            signal: "FLEXSPI2_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 8,
+            signal: "FLEXSPI1_B_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 9,
+            signal: "ENET_QOS_1588_EVENT3_AUX_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            alt: 10,
+            signal: "GPIO10_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_B_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 3,
+            signal: "LPUART9_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 4,
+            signal: "LPSPI4_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            alt: 10,
+            signal: "GPIO10_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_B_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 3,
+            signal: "LPUART9_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 4,
+            signal: "LPSPI4_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            alt: 10,
+            signal: "GPIO10_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_B_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 3,
+            signal: "LPUART9_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 4,
+            signal: "LPSPI4_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            alt: 10,
+            signal: "GPIO10_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_B_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 3,
+            signal: "LPUART9_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 4,
+            signal: "LPSPI4_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            alt: 10,
+            signal: "GPIO10_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 0,
+            signal: "USDHC2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_B_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_A_SS1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 4,
+            signal: "LPSPI4_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            alt: 10,
+            signal: "GPIO10_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 0,
+            signal: "USDHC2_CMD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_DQS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 3,
+            signal: "FLEXSPI1_B_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 4,
+            signal: "LPSPI4_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            alt: 10,
+            signal: "GPIO10_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 0,
+            signal: "USDHC2_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_SS0_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 3,
+            signal: "LPSPI4_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 4,
+            signal: "GPT6_CAPTURE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81cc,
+                pad: 0x400e8410,
+            },
+            alt: 10,
+            signal: "GPIO10_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 0,
+            signal: "USDHC2_STROBE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_SCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 3,
+            signal: "LPUART3_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 4,
+            signal: "GPT6_CAPTURE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 6,
+            signal: "LPSPI2_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 8,
+            signal: "ENET_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 9,
+            signal: "ENET_QOS_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            alt: 10,
+            signal: "GPIO10_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 3,
+            signal: "LPUART3_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 4,
+            signal: "GPT6_COMPARE1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 6,
+            signal: "LPSPI2_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            alt: 10,
+            signal: "GPIO10_IO17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 3,
+            signal: "LPUART5_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 4,
+            signal: "GPT6_COMPARE2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 6,
+            signal: "LPSPI2_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            alt: 10,
+            signal: "GPIO10_IO18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 3,
+            signal: "LPUART5_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 4,
+            signal: "GPT6_COMPARE3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 6,
+            signal: "LPSPI2_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            alt: 10,
+            signal: "GPIO10_IO19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 0,
+            signal: "USDHC2_DATA7",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 1,
+            signal: "FLEXSPI1_A_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 2,
+            signal: "ENET_1G_TX_CLK_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 3,
+            signal: "ENET_1G_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 4,
+            signal: "GPT6_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 6,
+            signal: "LPSPI2_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            alt: 10,
+            signal: "GPIO10_IO20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 3,
+            signal: "TMR1_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            alt: 10,
+            signal: "GPIO10_IO21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_ENABLE",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 2,
+            signal: "ENET_1G_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 3,
+            signal: "TMR1_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 9,
+            signal: "ENET_QOS_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            alt: 10,
+            signal: "GPIO10_IO22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_HSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 2,
+            signal: "LPI2C3_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 3,
+            signal: "TMR1_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 9,
+            signal: "LPUART1_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            alt: 10,
+            signal: "GPIO10_IO23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_VSYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 2,
+            signal: "LPI2C3_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 3,
+            signal: "TMR2_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 9,
+            signal: "LPUART1_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            alt: 10,
+            signal: "GPIO10_IO24",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 2,
+            signal: "LPUART4_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 3,
+            signal: "TMR2_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 9,
+            signal: "LPSPI3_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            alt: 10,
+            signal: "GPIO10_IO25",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 1,
+            signal: "ENET_1G_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 2,
+            signal: "LPUART4_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 3,
+            signal: "TMR2_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 9,
+            signal: "LPSPI3_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            alt: 10,
+            signal: "GPIO10_IO26",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 2,
+            signal: "LPUART4_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 3,
+            signal: "TMR3_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT32",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 9,
+            signal: "LPSPI3_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            alt: 10,
+            signal: "GPIO10_IO27",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 2,
+            signal: "LPUART4_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 3,
+            signal: "TMR3_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT33",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 9,
+            signal: "LPSPI3_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            alt: 10,
+            signal: "GPIO10_IO28",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 2,
+            signal: "USDHC1_CD_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 3,
+            signal: "TMR3_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT34",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 9,
+            signal: "LPSPI3_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            alt: 10,
+            signal: "GPIO10_IO29",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 2,
+            signal: "USDHC1_WP",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 3,
+            signal: "TMR4_TIMER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT35",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 9,
+            signal: "LPSPI3_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            alt: 10,
+            signal: "GPIO10_IO30",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 2,
+            signal: "USDHC1_RESET_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 3,
+            signal: "TMR4_TIMER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT36",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 5,
+            signal: "GPIO_MUX4_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 9,
+            signal: "LPSPI3_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            alt: 10,
+            signal: "GPIO10_IO31",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 1,
+            signal: "ENET_1G_TX_CLK_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 2,
+            signal: "ENET_1G_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 3,
+            signal: "TMR4_TIMER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT37",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 9,
+            signal: "ENET_QOS_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            alt: 10,
+            signal: "GPIO11_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 1,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 2,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 3,
+            signal: "ENET_1G_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 4,
+            signal: "SAI1_TX_DATA03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8214,
+                pad: 0x400e8458,
+            },
+            alt: 10,
+            signal: "GPIO11_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 1,
+            signal: "USDHC1_VSELECT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 2,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 3,
+            signal: "WDOG2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 4,
+            signal: "SAI1_TX_DATA02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 8,
+            signal: "EWM_OUT_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 9,
+            signal: "CCM_ENET_REF_CLK_25M",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8218,
+                pad: 0x400e845c,
+            },
+            alt: 10,
+            signal: "GPIO11_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 1,
+            signal: "ENET_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 2,
+            signal: "PIT1_TRIGGER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 3,
+            signal: "ARM_TRACE00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 4,
+            signal: "SAI1_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e821c,
+                pad: 0x400e8460,
+            },
+            alt: 10,
+            signal: "GPIO11_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 1,
+            signal: "ENET_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 2,
+            signal: "PIT1_TRIGGER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 3,
+            signal: "ARM_TRACE01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 4,
+            signal: "SAI1_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            alt: 10,
+            signal: "GPIO11_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 1,
+            signal: "ENET_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 2,
+            signal: "PIT1_TRIGGER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 3,
+            signal: "ARM_TRACE02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 4,
+            signal: "SAI1_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            alt: 10,
+            signal: "GPIO11_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 1,
+            signal: "ENET_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 2,
+            signal: "ENET_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 3,
+            signal: "ARM_TRACE03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 4,
+            signal: "SAI1_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 6,
+            signal: "SRC_BT_CFG11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_TX_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            alt: 10,
+            signal: "GPIO11_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 1,
+            signal: "ENET_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 2,
+            signal: "LPUART7_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 3,
+            signal: "ARM_TRACE_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 4,
+            signal: "SAI1_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            alt: 10,
+            signal: "GPIO11_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 1,
+            signal: "ENET_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 2,
+            signal: "LPUART7_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 3,
+            signal: "ARM_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 4,
+            signal: "SAI1_TX_DATA00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_DATA01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            alt: 10,
+            signal: "GPIO11_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 1,
+            signal: "ENET_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 2,
+            signal: "LPUART8_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 3,
+            signal: "ARM_CM7_EVENTO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 4,
+            signal: "SAI1_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_EN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 9,
+            signal: "LPUART1_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            alt: 10,
+            signal: "GPIO11_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA17",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 1,
+            signal: "ENET_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 2,
+            signal: "LPUART8_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 3,
+            signal: "ARM_CM7_EVENTI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 4,
+            signal: "SAI1_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 9,
+            signal: "LPUART1_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            alt: 10,
+            signal: "GPIO11_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA18",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 1,
+            signal: "EMVSIM2_IO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 2,
+            signal: "LPUART2_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 3,
+            signal: "WDOG2_RESET_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT38",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 6,
+            signal: "LPI2C3_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 8,
+            signal: "ENET_QOS_RX_ER",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 9,
+            signal: "SPDIF_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            alt: 10,
+            signal: "GPIO11_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA19",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 1,
+            signal: "EMVSIM2_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 2,
+            signal: "LPUART2_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 3,
+            signal: "WDOG1_RESET_B_DEB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT39",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 6,
+            signal: "LPI2C3_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 8,
+            signal: "ENET_QOS_CRS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 9,
+            signal: "SPDIF_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            alt: 10,
+            signal: "GPIO11_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA20",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 1,
+            signal: "EMVSIM2_RST",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 3,
+            signal: "LPUART2_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 4,
+            signal: "XBAR1_INOUT40",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 6,
+            signal: "LPI2C4_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 8,
+            signal: "ENET_QOS_COL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 9,
+            signal: "LPSPI4_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            alt: 10,
+            signal: "GPIO11_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA21",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 1,
+            signal: "EMVSIM2_SVEN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 2,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 3,
+            signal: "LPUART2_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 4,
+            signal: "ENET_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 6,
+            signal: "LPI2C4_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 8,
+            signal: "ENET_QOS_1588_EVENT0_OUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 9,
+            signal: "LPSPI4_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            alt: 10,
+            signal: "GPIO11_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA22",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 1,
+            signal: "EMVSIM2_PD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 2,
+            signal: "WDOG2_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_EXT_DCIC1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 4,
+            signal: "ENET_1G_REF_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 6,
+            signal: "FLEXCAN1_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 8,
+            signal: "ENET_QOS_1588_EVENT0_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 9,
+            signal: "LPSPI4_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            alt: 10,
+            signal: "GPIO11_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 0,
+            signal: "VIDEO_MUX_LCDIF_DATA23",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 1,
+            signal: "EMVSIM2_POWER_FAIL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 2,
+            signal: "WDOG1_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 3,
+            signal: "VIDEO_MUX_EXT_DCIC2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 4,
+            signal: "PIT1_TRIGGER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 5,
+            signal: "GPIO_MUX5_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 6,
+            signal: "FLEXCAN1_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 8,
+            signal: "ENET_QOS_1588_EVENT0_AUX_IN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 9,
+            signal: "LPSPI4_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            alt: 10,
+            signal: "GPIO11_IO16",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 0,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 1,
+            signal: "MIC_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 2,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 3,
+            signal: "ARM_CM4_EVENTO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 6,
+            signal: "LPUART12_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 7,
+            signal: "SAI4_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            alt: 10,
+            signal: "GPIO12_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 0,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 1,
+            signal: "MIC_BITSTREAM0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 2,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 3,
+            signal: "ARM_CM4_EVENTI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 6,
+            signal: "LPUART12_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            alt: 10,
+            signal: "GPIO12_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 0,
+            signal: "SRC_BOOT_MODE00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 1,
+            signal: "LPSPI5_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 2,
+            signal: "SAI4_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 3,
+            signal: "MQS_RIGHT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            alt: 10,
+            signal: "GPIO12_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 0,
+            signal: "SRC_BOOT_MODE01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 1,
+            signal: "LPSPI5_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 2,
+            signal: "SAI4_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 3,
+            signal: "MQS_LEFT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            alt: 10,
+            signal: "GPIO12_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 0,
+            signal: "LPI2C5_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 1,
+            signal: "LPSPI5_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 2,
+            signal: "SAI4_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 3,
+            signal: "LPUART12_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 6,
+            signal: "LPUART11_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            alt: 10,
+            signal: "GPIO12_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 0,
+            signal: "LPI2C5_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 1,
+            signal: "LPSPI5_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 2,
+            signal: "SAI4_MCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 3,
+            signal: "LPUART12_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 6,
+            signal: "LPUART11_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 7,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            alt: 10,
+            signal: "GPIO12_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 0,
+            signal: "LPI2C6_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 2,
+            signal: "SAI4_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 3,
+            signal: "LPUART12_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 4,
+            signal: "LPSPI6_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 6,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 7,
+            signal: "PIT2_TRIGGER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 8,
+            signal: "LPSPI5_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            alt: 10,
+            signal: "GPIO12_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 0,
+            signal: "LPI2C6_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 2,
+            signal: "SAI4_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 3,
+            signal: "LPUART12_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 4,
+            signal: "LPSPI6_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 6,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 7,
+            signal: "PIT2_TRIGGER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 8,
+            signal: "LPSPI5_PCS2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            alt: 10,
+            signal: "GPIO12_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 0,
+            signal: "LPUART11_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 1,
+            signal: "FLEXCAN3_TX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 2,
+            signal: "SAI4_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 3,
+            signal: "MIC_CLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 4,
+            signal: "LPSPI6_PCS1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 6,
+            signal: "LPI2C5_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 7,
+            signal: "PIT2_TRIGGER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 8,
+            signal: "LPSPI5_PCS3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            alt: 10,
+            signal: "GPIO12_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 0,
+            signal: "LPUART11_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 1,
+            signal: "FLEXCAN3_RX",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 2,
+            signal: "PIT2_TRIGGER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 3,
+            signal: "MIC_BITSTREAM0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 4,
+            signal: "LPSPI6_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 6,
+            signal: "LPI2C5_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 7,
+            signal: "SAI4_TX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            alt: 10,
+            signal: "GPIO12_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_TRSTB",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 1,
+            signal: "LPUART11_CTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 2,
+            signal: "LPI2C6_SDA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 3,
+            signal: "MIC_BITSTREAM1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 4,
+            signal: "LPSPI6_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 6,
+            signal: "LPI2C5_SCLS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 7,
+            signal: "SAI4_TX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 8,
+            signal: "LPUART12_TXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            alt: 10,
+            signal: "GPIO12_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_TDO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 1,
+            signal: "LPUART11_RTS_B",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 2,
+            signal: "LPI2C6_SCL",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 3,
+            signal: "MIC_BITSTREAM2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 4,
+            signal: "LPSPI6_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 6,
+            signal: "LPI2C5_SDAS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 7,
+            signal: "ARM_TRACE_SWO",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 8,
+            signal: "LPUART12_RXD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            alt: 10,
+            signal: "GPIO12_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_TDI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 1,
+            signal: "PIT2_TRIGGER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 3,
+            signal: "MIC_BITSTREAM3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 4,
+            signal: "LPSPI6_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 6,
+            signal: "LPI2C5_HREQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 7,
+            signal: "SAI4_TX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 8,
+            signal: "LPSPI5_SCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            alt: 10,
+            signal: "GPIO12_IO12",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_MOD",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 1,
+            signal: "MIC_BITSTREAM1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 2,
+            signal: "PIT2_TRIGGER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 7,
+            signal: "SAI4_RX_DATA",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 8,
+            signal: "LPSPI5_PCS0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            alt: 10,
+            signal: "GPIO12_IO13",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_TCK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 1,
+            signal: "MIC_BITSTREAM2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 2,
+            signal: "PIT2_TRIGGER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 7,
+            signal: "SAI4_RX_BCLK",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 8,
+            signal: "LPSPI5_SOUT",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            alt: 10,
+            signal: "GPIO12_IO14",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 0,
+            signal: "JTAG_MUX_TMS",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 1,
+            signal: "MIC_BITSTREAM3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 2,
+            signal: "PIT2_TRIGGER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 5,
+            signal: "GPIO_MUX6_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 7,
+            signal: "SAI4_RX_SYNC",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 8,
+            signal: "LPSPI5_SIN",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            alt: 10,
+            signal: "GPIO12_IO15",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94000,
+                pad: 0x40c94040,
+            },
+            alt: 5,
+            signal: "GPIO13_IO00",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94000,
+                pad: 0x40c94040,
+            },
+            alt: 7,
+            signal: "NMI_GLUE_NMI",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94004,
+                pad: 0x40c94044,
+            },
+            alt: 0,
+            signal: "SNVS_LP_PMIC_ON_REQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94004,
+                pad: 0x40c94044,
+            },
+            alt: 5,
+            signal: "GPIO13_IO01",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94008,
+                pad: 0x40c94048,
+            },
+            alt: 0,
+            signal: "CCM_PMIC_VSTBY_REQ",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94008,
+                pad: 0x40c94048,
+            },
+            alt: 5,
+            signal: "GPIO13_IO02",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9400c,
+                pad: 0x40c9404c,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER0",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9400c,
+                pad: 0x40c9404c,
+            },
+            alt: 5,
+            signal: "GPIO13_IO03",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94010,
+                pad: 0x40c94050,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER1",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94010,
+                pad: 0x40c94050,
+            },
+            alt: 5,
+            signal: "GPIO13_IO04",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94014,
+                pad: 0x40c94054,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER2",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94014,
+                pad: 0x40c94054,
+            },
+            alt: 5,
+            signal: "GPIO13_IO05",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94018,
+                pad: 0x40c94058,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER3",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94018,
+                pad: 0x40c94058,
+            },
+            alt: 5,
+            signal: "GPIO13_IO06",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9401c,
+                pad: 0x40c9405c,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER4",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9401c,
+                pad: 0x40c9405c,
+            },
+            alt: 5,
+            signal: "GPIO13_IO07",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94020,
+                pad: 0x40c94060,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER5",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94020,
+                pad: 0x40c94060,
+            },
+            alt: 5,
+            signal: "GPIO13_IO08",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94024,
+                pad: 0x40c94064,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER6",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94024,
+                pad: 0x40c94064,
+            },
+            alt: 5,
+            signal: "GPIO13_IO09",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94028,
+                pad: 0x40c94068,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER7",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94028,
+                pad: 0x40c94068,
+            },
+            alt: 5,
+            signal: "GPIO13_IO10",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9402c,
+                pad: 0x40c9406c,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER8",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c9402c,
+                pad: 0x40c9406c,
+            },
+            alt: 5,
+            signal: "GPIO13_IO11",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94030,
+                pad: 0x40c94070,
+            },
+            alt: 0,
+            signal: "SNVS_TAMPER9",
+        },
+        Alternate {
+            pad: crate::PadLocation {
+                mux: 0x40c94030,
+                pad: 0x40c94070,
+            },
+            alt: 5,
+            signal: "GPIO13_IO12",
+        },
+    ],
+    inputs: &[
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x400e8498,
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x400e8498,
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXCAN1_RX",
+            register: 0x400e8498,
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x400e849c,
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXCAN2_RX",
+            register: 0x400e849c,
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CCM_ENET_QOS_REF_CLK",
+            register: 0x400e84a0,
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CCM_ENET_QOS_REF_CLK",
+            register: 0x400e84a0,
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CCM_ENET_QOS_REF_CLK",
+            register: 0x400e84a0,
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "CCM_ENET_QOS_TX_CLK",
+            register: 0x400e84a4,
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CCM_ENET_QOS_TX_CLK",
+            register: 0x400e84a4,
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_IPG_CLK_RMII",
+            register: 0x400e84a8,
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_IPG_CLK_RMII",
+            register: 0x400e84a8,
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_IPG_CLK_RMII",
+            register: 0x400e84a8,
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_MAC0_MDIO",
+            register: 0x400e84ac,
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_MDIO",
+            register: 0x400e84ac,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXDATA_0",
+            register: 0x400e84b0,
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXDATA_0",
+            register: 0x400e84b0,
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXDATA_1",
+            register: 0x400e84b4,
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXDATA_1",
+            register: 0x400e84b4,
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXEN",
+            register: 0x400e84b8,
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXEN",
+            register: 0x400e84b8,
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXERR",
+            register: 0x400e84bc,
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_RXERR",
+            register: 0x400e84bc,
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_MAC0_TXCLK",
+            register: 0x400e84c0,
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_MAC0_TXCLK",
+            register: 0x400e84c0,
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_IPG_CLK_RMII",
+            register: 0x400e84c4,
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_IPG_CLK_RMII",
+            register: 0x400e84c4,
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_IPG_CLK_RMII",
+            register: 0x400e84c4,
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_IPG_CLK_RMII",
+            register: 0x400e84c4,
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_MDIO",
+            register: 0x400e84c8,
+            pad: crate::PadLocation {
+                mux: 0x400e80b4,
+                pad: 0x400e82f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_MDIO",
+            register: 0x400e84c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_MDIO",
+            register: 0x400e84c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_MDIO",
+            register: 0x400e84c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 3,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXCLK",
+            register: 0x400e84cc,
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXCLK",
+            register: 0x400e84cc,
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXCLK",
+            register: 0x400e84cc,
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_0",
+            register: 0x400e84d0,
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_0",
+            register: 0x400e84d0,
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_0",
+            register: 0x400e84d0,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_1",
+            register: 0x400e84d4,
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_1",
+            register: 0x400e84d4,
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_1",
+            register: 0x400e84d4,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_2",
+            register: 0x400e84d8,
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_2",
+            register: 0x400e84d8,
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_2",
+            register: 0x400e84d8,
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_3",
+            register: 0x400e84dc,
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_3",
+            register: 0x400e84dc,
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXDATA_3",
+            register: 0x400e84dc,
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXEN",
+            register: 0x400e84e0,
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXEN",
+            register: 0x400e84e0,
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXEN",
+            register: 0x400e84e0,
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXERR",
+            register: 0x400e84e4,
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_RXERR",
+            register: 0x400e84e4,
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_TXCLK",
+            register: 0x400e84e8,
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_TXCLK",
+            register: 0x400e84e8,
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_1G_MAC0_TXCLK",
+            register: 0x400e84e8,
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "ENET_QOS_GMII_MDI_I",
+            register: 0x400e84ec,
+            pad: crate::PadLocation {
+                mux: 0x400e8108,
+                pad: 0x400e834c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_QOS_GMII_MDI_I",
+            register: 0x400e84ec,
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXD_I_0",
+            register: 0x400e84f0,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXD_I_0",
+            register: 0x400e84f0,
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXD_I_1",
+            register: 0x400e84f4,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXD_I_1",
+            register: 0x400e84f4,
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXDV_I",
+            register: 0x400e84f8,
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXDV_I",
+            register: 0x400e84f8,
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXER_I",
+            register: 0x400e84fc,
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXER_I",
+            register: 0x400e84fc,
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "ENET_QOS_PHY_RXER_I",
+            register: 0x400e84fc,
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_0",
+            register: 0x400e8500,
+            pad: crate::PadLocation {
+                mux: 0x400e806c,
+                pad: 0x400e82b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_0",
+            register: 0x400e8500,
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_1",
+            register: 0x400e8504,
+            pad: crate::PadLocation {
+                mux: 0x400e8074,
+                pad: 0x400e82b8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_1",
+            register: 0x400e8504,
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_2",
+            register: 0x400e8508,
+            pad: crate::PadLocation {
+                mux: 0x400e807c,
+                pad: 0x400e82c0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMA_2",
+            register: 0x400e8508,
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_0",
+            register: 0x400e850c,
+            pad: crate::PadLocation {
+                mux: 0x400e8070,
+                pad: 0x400e82b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_0",
+            register: 0x400e850c,
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_1",
+            register: 0x400e8510,
+            pad: crate::PadLocation {
+                mux: 0x400e8078,
+                pad: 0x400e82bc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_1",
+            register: 0x400e8510,
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_2",
+            register: 0x400e8514,
+            pad: crate::PadLocation {
+                mux: 0x400e8080,
+                pad: 0x400e82c4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM1_PWMB_2",
+            register: 0x400e8514,
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_0",
+            register: 0x400e8518,
+            pad: crate::PadLocation {
+                mux: 0x400e8028,
+                pad: 0x400e826c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_0",
+            register: 0x400e8518,
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_1",
+            register: 0x400e851c,
+            pad: crate::PadLocation {
+                mux: 0x400e8030,
+                pad: 0x400e8274,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_1",
+            register: 0x400e851c,
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_2",
+            register: 0x400e8520,
+            pad: crate::PadLocation {
+                mux: 0x400e8038,
+                pad: 0x400e827c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMA_2",
+            register: 0x400e8520,
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_0",
+            register: 0x400e8524,
+            pad: crate::PadLocation {
+                mux: 0x400e802c,
+                pad: 0x400e8270,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_0",
+            register: 0x400e8524,
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_1",
+            register: 0x400e8528,
+            pad: crate::PadLocation {
+                mux: 0x400e8034,
+                pad: 0x400e8278,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_1",
+            register: 0x400e8528,
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_2",
+            register: 0x400e852c,
+            pad: crate::PadLocation {
+                mux: 0x400e803c,
+                pad: 0x400e8280,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM2_PWMB_2",
+            register: 0x400e852c,
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMA_0",
+            register: 0x400e8530,
+            pad: crate::PadLocation {
+                mux: 0x400e8084,
+                pad: 0x400e82c8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMA_1",
+            register: 0x400e8534,
+            pad: crate::PadLocation {
+                mux: 0x400e808c,
+                pad: 0x400e82d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMA_2",
+            register: 0x400e8538,
+            pad: crate::PadLocation {
+                mux: 0x400e8094,
+                pad: 0x400e82d8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMA_3",
+            register: 0x400e853c,
+            pad: crate::PadLocation {
+                mux: 0x400e8064,
+                pad: 0x400e82a8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMB_0",
+            register: 0x400e8540,
+            pad: crate::PadLocation {
+                mux: 0x400e8088,
+                pad: 0x400e82cc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMB_1",
+            register: 0x400e8544,
+            pad: crate::PadLocation {
+                mux: 0x400e8090,
+                pad: 0x400e82d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMB_2",
+            register: 0x400e8548,
+            pad: crate::PadLocation {
+                mux: 0x400e8098,
+                pad: 0x400e82dc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXPWM3_PWMB_3",
+            register: 0x400e854c,
+            pad: crate::PadLocation {
+                mux: 0x400e8068,
+                pad: 0x400e82ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_DQS_FA",
+            register: 0x400e8550,
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_DQS_FA",
+            register: 0x400e8550,
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_DQS_FA",
+            register: 0x400e8550,
+            pad: crate::PadLocation {
+                mux: 0x400e81c8,
+                pad: 0x400e840c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_0",
+            register: 0x400e8554,
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_0",
+            register: 0x400e8554,
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_1",
+            register: 0x400e8558,
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_1",
+            register: 0x400e8558,
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_2",
+            register: 0x400e855c,
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_2",
+            register: 0x400e855c,
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_3",
+            register: 0x400e8560,
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FA_3",
+            register: 0x400e8560,
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_0",
+            register: 0x400e8564,
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_0",
+            register: 0x400e8564,
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_1",
+            register: 0x400e8568,
+            pad: crate::PadLocation {
+                mux: 0x400e8144,
+                pad: 0x400e8388,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_1",
+            register: 0x400e8568,
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_2",
+            register: 0x400e856c,
+            pad: crate::PadLocation {
+                mux: 0x400e8140,
+                pad: 0x400e8384,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_2",
+            register: 0x400e856c,
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_3",
+            register: 0x400e8570,
+            pad: crate::PadLocation {
+                mux: 0x400e813c,
+                pad: 0x400e8380,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_IO_FB_3",
+            register: 0x400e8570,
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_SCK_FA",
+            register: 0x400e8574,
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_SCK_FA",
+            register: 0x400e8574,
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_SCK_FB",
+            register: 0x400e8578,
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI1_I_SCK_FB",
+            register: 0x400e8578,
+            pad: crate::PadLocation {
+                mux: 0x400e81c4,
+                pad: 0x400e8408,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_0",
+            register: 0x400e857c,
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_0",
+            register: 0x400e857c,
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_1",
+            register: 0x400e8580,
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_1",
+            register: 0x400e8580,
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_2",
+            register: 0x400e8584,
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_2",
+            register: 0x400e8584,
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_3",
+            register: 0x400e8588,
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_IO_FA_3",
+            register: 0x400e8588,
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_SCK_FA",
+            register: 0x400e858c,
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "FLEXSPI2_I_SCK_FA",
+            register: 0x400e858c,
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT3_CAPIN1",
+            register: 0x400e8590,
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT3_CAPIN1",
+            register: 0x400e8590,
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT3_CAPIN2",
+            register: 0x400e8594,
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT3_CAPIN2",
+            register: 0x400e8594,
+            pad: crate::PadLocation {
+                mux: 0x400e8128,
+                pad: 0x400e836c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "GPT3_CLKIN",
+            register: 0x400e8598,
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "GPT3_CLKIN",
+            register: 0x400e8598,
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_6",
+            register: 0x400e859c,
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_6",
+            register: 0x400e859c,
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_COL_7",
+            register: 0x400e85a0,
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_COL_7",
+            register: 0x400e85a0,
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_6",
+            register: 0x400e85a4,
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_6",
+            register: 0x400e85a4,
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "KPP_ROW_7",
+            register: 0x400e85a8,
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "KPP_ROW_7",
+            register: 0x400e85a8,
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_LPI2C_SCL",
+            register: 0x400e85ac,
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_LPI2C_SCL",
+            register: 0x400e85ac,
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C1_LPI2C_SDA",
+            register: 0x400e85b0,
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C1_LPI2C_SDA",
+            register: 0x400e85b0,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_LPI2C_SCL",
+            register: 0x400e85b4,
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_LPI2C_SCL",
+            register: 0x400e85b4,
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C2_LPI2C_SDA",
+            register: 0x400e85b8,
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C2_LPI2C_SDA",
+            register: 0x400e85b8,
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C3_LPI2C_SCL",
+            register: 0x400e85bc,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C3_LPI2C_SCL",
+            register: 0x400e85bc,
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C3_LPI2C_SDA",
+            register: 0x400e85c0,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C3_LPI2C_SDA",
+            register: 0x400e85c0,
+            pad: crate::PadLocation {
+                mux: 0x400e8240,
+                pad: 0x400e8484,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C4_LPI2C_SCL",
+            register: 0x400e85c4,
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C4_LPI2C_SCL",
+            register: 0x400e85c4,
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C4_LPI2C_SDA",
+            register: 0x400e85c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C4_LPI2C_SDA",
+            register: 0x400e85c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_PCS_0",
+            register: 0x400e85cc,
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_PCS_0",
+            register: 0x400e85cc,
+            pad: crate::PadLocation {
+                mux: 0x400e8180,
+                pad: 0x400e83c4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SCK",
+            register: 0x400e85d0,
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SCK",
+            register: 0x400e85d0,
+            pad: crate::PadLocation {
+                mux: 0x400e817c,
+                pad: 0x400e83c0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SDI",
+            register: 0x400e85d4,
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SDI",
+            register: 0x400e85d4,
+            pad: crate::PadLocation {
+                mux: 0x400e8188,
+                pad: 0x400e83cc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SDO",
+            register: 0x400e85d8,
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI1_LPSPI_SDO",
+            register: 0x400e85d8,
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_PCS_0",
+            register: 0x400e85dc,
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_PCS_0",
+            register: 0x400e85dc,
+            pad: crate::PadLocation {
+                mux: 0x400e81d4,
+                pad: 0x400e8418,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_PCS_1",
+            register: 0x400e85e0,
+            pad: crate::PadLocation {
+                mux: 0x400e8160,
+                pad: 0x400e83a4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_PCS_1",
+            register: 0x400e85e0,
+            pad: crate::PadLocation {
+                mux: 0x400e81e0,
+                pad: 0x400e8424,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SCK",
+            register: 0x400e85e4,
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SCK",
+            register: 0x400e85e4,
+            pad: crate::PadLocation {
+                mux: 0x400e81d0,
+                pad: 0x400e8414,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SDI",
+            register: 0x400e85e8,
+            pad: crate::PadLocation {
+                mux: 0x400e8178,
+                pad: 0x400e83bc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SDI",
+            register: 0x400e85e8,
+            pad: crate::PadLocation {
+                mux: 0x400e81dc,
+                pad: 0x400e8420,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SDO",
+            register: 0x400e85ec,
+            pad: crate::PadLocation {
+                mux: 0x400e8174,
+                pad: 0x400e83b8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI2_LPSPI_SDO",
+            register: 0x400e85ec,
+            pad: crate::PadLocation {
+                mux: 0x400e81d8,
+                pad: 0x400e841c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_0",
+            register: 0x400e85f0,
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_0",
+            register: 0x400e85f0,
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_1",
+            register: 0x400e85f4,
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_1",
+            register: 0x400e85f4,
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_2",
+            register: 0x400e85f8,
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_2",
+            register: 0x400e85f8,
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_3",
+            register: 0x400e85fc,
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_PCS_3",
+            register: 0x400e85fc,
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SCK",
+            register: 0x400e8600,
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SCK",
+            register: 0x400e8600,
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SDI",
+            register: 0x400e8604,
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SDI",
+            register: 0x400e8604,
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SDO",
+            register: 0x400e8608,
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI3_LPSPI_SDO",
+            register: 0x400e8608,
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_PCS_0",
+            register: 0x400e860c,
+            pad: crate::PadLocation {
+                mux: 0x400e81b8,
+                pad: 0x400e83fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_PCS_0",
+            register: 0x400e860c,
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SCK",
+            register: 0x400e8610,
+            pad: crate::PadLocation {
+                mux: 0x400e81b4,
+                pad: 0x400e83f8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SCK",
+            register: 0x400e8610,
+            pad: crate::PadLocation {
+                mux: 0x400e8244,
+                pad: 0x400e8488,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SDI",
+            register: 0x400e8614,
+            pad: crate::PadLocation {
+                mux: 0x400e81c0,
+                pad: 0x400e8404,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SDI",
+            register: 0x400e8614,
+            pad: crate::PadLocation {
+                mux: 0x400e8248,
+                pad: 0x400e848c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SDO",
+            register: 0x400e8618,
+            pad: crate::PadLocation {
+                mux: 0x400e81bc,
+                pad: 0x400e8400,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI4_LPSPI_SDO",
+            register: 0x400e8618,
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_RXD",
+            register: 0x400e861c,
+            pad: crate::PadLocation {
+                mux: 0x400e8170,
+                pad: 0x400e83b4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_RXD",
+            register: 0x400e861c,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_RXD",
+            register: 0x400e861c,
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_TXD",
+            register: 0x400e8620,
+            pad: crate::PadLocation {
+                mux: 0x400e816c,
+                pad: 0x400e83b0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_TXD",
+            register: 0x400e8620,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART1_LPUART_TXD",
+            register: 0x400e8620,
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART10_LPUART_RXD",
+            register: 0x400e8624,
+            pad: crate::PadLocation {
+                mux: 0x400e814c,
+                pad: 0x400e8390,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART10_LPUART_RXD",
+            register: 0x400e8624,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART10_LPUART_TXD",
+            register: 0x400e8628,
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART10_LPUART_TXD",
+            register: 0x400e8628,
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART7_LPUART_RXD",
+            register: 0x400e862c,
+            pad: crate::PadLocation {
+                mux: 0x400e8110,
+                pad: 0x400e8354,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART7_LPUART_RXD",
+            register: 0x400e862c,
+            pad: crate::PadLocation {
+                mux: 0x400e8230,
+                pad: 0x400e8474,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART7_LPUART_TXD",
+            register: 0x400e8630,
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART7_LPUART_TXD",
+            register: 0x400e8630,
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART8_LPUART_RXD",
+            register: 0x400e8634,
+            pad: crate::PadLocation {
+                mux: 0x400e8118,
+                pad: 0x400e835c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART8_LPUART_RXD",
+            register: 0x400e8634,
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART8_LPUART_TXD",
+            register: 0x400e8638,
+            pad: crate::PadLocation {
+                mux: 0x400e8114,
+                pad: 0x400e8358,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART8_LPUART_TXD",
+            register: 0x400e8638,
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR0_INPUT",
+            register: 0x400e863c,
+            pad: crate::PadLocation {
+                mux: 0x400e8054,
+                pad: 0x400e8298,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR0_INPUT",
+            register: 0x400e863c,
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR0_INPUT",
+            register: 0x400e863c,
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR1_INPUT",
+            register: 0x400e8640,
+            pad: crate::PadLocation {
+                mux: 0x400e80a8,
+                pad: 0x400e82ec,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR1_INPUT",
+            register: 0x400e8640,
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR1_INPUT",
+            register: 0x400e8640,
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR2_INPUT",
+            register: 0x400e8644,
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER1_TMR2_INPUT",
+            register: 0x400e8644,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR0_INPUT",
+            register: 0x400e8648,
+            pad: crate::PadLocation {
+                mux: 0x400e8058,
+                pad: 0x400e829c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR0_INPUT",
+            register: 0x400e8648,
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR0_INPUT",
+            register: 0x400e8648,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR1_INPUT",
+            register: 0x400e864c,
+            pad: crate::PadLocation {
+                mux: 0x400e80ac,
+                pad: 0x400e82f0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR1_INPUT",
+            register: 0x400e864c,
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR1_INPUT",
+            register: 0x400e864c,
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR2_INPUT",
+            register: 0x400e8650,
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER2_TMR2_INPUT",
+            register: 0x400e8650,
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR0_INPUT",
+            register: 0x400e8654,
+            pad: crate::PadLocation {
+                mux: 0x400e805c,
+                pad: 0x400e82a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR0_INPUT",
+            register: 0x400e8654,
+            pad: crate::PadLocation {
+                mux: 0x400e80fc,
+                pad: 0x400e8340,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR0_INPUT",
+            register: 0x400e8654,
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR1_INPUT",
+            register: 0x400e8658,
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR1_INPUT",
+            register: 0x400e8658,
+            pad: crate::PadLocation {
+                mux: 0x400e8100,
+                pad: 0x400e8344,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR1_INPUT",
+            register: 0x400e8658,
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR2_INPUT",
+            register: 0x400e865c,
+            pad: crate::PadLocation {
+                mux: 0x400e8104,
+                pad: 0x400e8348,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER3_TMR2_INPUT",
+            register: 0x400e865c,
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR0_INPUT",
+            register: 0x400e8660,
+            pad: crate::PadLocation {
+                mux: 0x400e8060,
+                pad: 0x400e82a4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR0_INPUT",
+            register: 0x400e8660,
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR0_INPUT",
+            register: 0x400e8660,
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR1_INPUT",
+            register: 0x400e8664,
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR1_INPUT",
+            register: 0x400e8664,
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR1_INPUT",
+            register: 0x400e8664,
+            pad: crate::PadLocation {
+                mux: 0x400e820c,
+                pad: 0x400e8450,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR2_INPUT",
+            register: 0x400e8668,
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "QTIMER4_TMR2_INPUT",
+            register: 0x400e8668,
+            pad: crate::PadLocation {
+                mux: 0x400e8210,
+                pad: 0x400e8454,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_IPG_CLK_SAI_MCLK",
+            register: 0x400e866c,
+            pad: crate::PadLocation {
+                mux: 0x400e8150,
+                pad: 0x400e8394,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_IPG_CLK_SAI_MCLK",
+            register: 0x400e866c,
+            pad: crate::PadLocation {
+                mux: 0x400e8220,
+                pad: 0x400e8464,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXBCLK",
+            register: 0x400e8670,
+            pad: crate::PadLocation {
+                mux: 0x400e8158,
+                pad: 0x400e839c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXBCLK",
+            register: 0x400e8670,
+            pad: crate::PadLocation {
+                mux: 0x400e8228,
+                pad: 0x400e846c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXDATA_0",
+            register: 0x400e8674,
+            pad: crate::PadLocation {
+                mux: 0x400e815c,
+                pad: 0x400e83a0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXDATA_0",
+            register: 0x400e8674,
+            pad: crate::PadLocation {
+                mux: 0x400e822c,
+                pad: 0x400e8470,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXSYNC",
+            register: 0x400e8678,
+            pad: crate::PadLocation {
+                mux: 0x400e8154,
+                pad: 0x400e8398,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_SAI_RXSYNC",
+            register: 0x400e8678,
+            pad: crate::PadLocation {
+                mux: 0x400e8224,
+                pad: 0x400e8468,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_SAI_TXBCLK",
+            register: 0x400e867c,
+            pad: crate::PadLocation {
+                mux: 0x400e8164,
+                pad: 0x400e83a8,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_SAI_TXBCLK",
+            register: 0x400e867c,
+            pad: crate::PadLocation {
+                mux: 0x400e8234,
+                pad: 0x400e8478,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI1_SAI_TXSYNC",
+            register: 0x400e8680,
+            pad: crate::PadLocation {
+                mux: 0x400e8168,
+                pad: 0x400e83ac,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI1_SAI_TXSYNC",
+            register: 0x400e8680,
+            pad: crate::PadLocation {
+                mux: 0x400e8238,
+                pad: 0x400e847c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM1_SIO",
+            register: 0x400e869c,
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM1_SIO",
+            register: 0x400e869c,
+            pad: crate::PadLocation {
+                mux: 0x400e810c,
+                pad: 0x400e8350,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM1_IPP_SIMPD",
+            register: 0x400e86a0,
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM1_IPP_SIMPD",
+            register: 0x400e86a0,
+            pad: crate::PadLocation {
+                mux: 0x400e811c,
+                pad: 0x400e8360,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM1_POWER_FAIL",
+            register: 0x400e86a4,
+            pad: crate::PadLocation {
+                mux: 0x400e80f8,
+                pad: 0x400e833c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM1_POWER_FAIL",
+            register: 0x400e86a4,
+            pad: crate::PadLocation {
+                mux: 0x400e8120,
+                pad: 0x400e8364,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM2_SIO",
+            register: 0x400e86a8,
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM2_SIO",
+            register: 0x400e86a8,
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM2_IPP_SIMPD",
+            register: 0x400e86ac,
+            pad: crate::PadLocation {
+                mux: 0x400e8134,
+                pad: 0x400e8378,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM2_IPP_SIMPD",
+            register: 0x400e86ac,
+            pad: crate::PadLocation {
+                mux: 0x400e824c,
+                pad: 0x400e8490,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "EMVSIM2_POWER_FAIL",
+            register: 0x400e86b0,
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "EMVSIM2_POWER_FAIL",
+            register: 0x400e86b0,
+            pad: crate::PadLocation {
+                mux: 0x400e8250,
+                pad: 0x400e8494,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SPDIF_SPDIF_IN1",
+            register: 0x400e86b4,
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SPDIF_SPDIF_IN1",
+            register: 0x400e86b4,
+            pad: crate::PadLocation {
+                mux: 0x400e8148,
+                pad: 0x400e838c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SPDIF_SPDIF_IN1",
+            register: 0x400e86b4,
+            pad: crate::PadLocation {
+                mux: 0x400e823c,
+                pad: 0x400e8480,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "USB_OTG2_OC",
+            register: 0x400e86b8,
+            pad: crate::PadLocation {
+                mux: 0x400e8124,
+                pad: 0x400e8368,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG2_OC",
+            register: 0x400e86b8,
+            pad: crate::PadLocation {
+                mux: 0x400e8184,
+                pad: 0x400e83c8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USB_OTG_OC",
+            register: 0x400e86bc,
+            pad: crate::PadLocation {
+                mux: 0x400e8138,
+                pad: 0x400e837c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USB_OTG_OC",
+            register: 0x400e86bc,
+            pad: crate::PadLocation {
+                mux: 0x400e8198,
+                pad: 0x400e83dc,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USBPHY1_USB_ID",
+            register: 0x400e86c0,
+            pad: crate::PadLocation {
+                mux: 0x400e8130,
+                pad: 0x400e8374,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USBPHY1_USB_ID",
+            register: 0x400e86c0,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USBPHY2_USB_ID",
+            register: 0x400e86c4,
+            pad: crate::PadLocation {
+                mux: 0x400e812c,
+                pad: 0x400e8370,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USBPHY2_USB_ID",
+            register: 0x400e86c4,
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC1_IPP_CARD_DET",
+            register: 0x400e86c8,
+            pad: crate::PadLocation {
+                mux: 0x400e818c,
+                pad: 0x400e83d0,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC1_IPP_CARD_DET",
+            register: 0x400e86c8,
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC1_IPP_WP_ON",
+            register: 0x400e86cc,
+            pad: crate::PadLocation {
+                mux: 0x400e8190,
+                pad: 0x400e83d4,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC1_IPP_WP_ON",
+            register: 0x400e86cc,
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "USDHC2_IPP_CARD_DET",
+            register: 0x400e86d0,
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "USDHC2_IPP_WP_ON",
+            register: 0x400e86d4,
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_20",
+            register: 0x400e86d8,
+            pad: crate::PadLocation {
+                mux: 0x400e80b8,
+                pad: 0x400e82fc,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_20",
+            register: 0x400e86d8,
+            pad: crate::PadLocation {
+                mux: 0x400e819c,
+                pad: 0x400e83e0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_21",
+            register: 0x400e86dc,
+            pad: crate::PadLocation {
+                mux: 0x400e80bc,
+                pad: 0x400e8300,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_21",
+            register: 0x400e86dc,
+            pad: crate::PadLocation {
+                mux: 0x400e81a0,
+                pad: 0x400e83e4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_22",
+            register: 0x400e86e0,
+            pad: crate::PadLocation {
+                mux: 0x400e80c0,
+                pad: 0x400e8304,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_22",
+            register: 0x400e86e0,
+            pad: crate::PadLocation {
+                mux: 0x400e81a4,
+                pad: 0x400e83e8,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_23",
+            register: 0x400e86e4,
+            pad: crate::PadLocation {
+                mux: 0x400e80c4,
+                pad: 0x400e8308,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_23",
+            register: 0x400e86e4,
+            pad: crate::PadLocation {
+                mux: 0x400e81a8,
+                pad: 0x400e83ec,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_24",
+            register: 0x400e86e8,
+            pad: crate::PadLocation {
+                mux: 0x400e80c8,
+                pad: 0x400e830c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_24",
+            register: 0x400e86e8,
+            pad: crate::PadLocation {
+                mux: 0x400e81ac,
+                pad: 0x400e83f0,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_25",
+            register: 0x400e86ec,
+            pad: crate::PadLocation {
+                mux: 0x400e80cc,
+                pad: 0x400e8310,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_25",
+            register: 0x400e86ec,
+            pad: crate::PadLocation {
+                mux: 0x400e81b0,
+                pad: 0x400e83f4,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_26",
+            register: 0x400e86f0,
+            pad: crate::PadLocation {
+                mux: 0x400e80d0,
+                pad: 0x400e8314,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_26",
+            register: 0x400e86f0,
+            pad: crate::PadLocation {
+                mux: 0x400e81e4,
+                pad: 0x400e8428,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_27",
+            register: 0x400e86f4,
+            pad: crate::PadLocation {
+                mux: 0x400e80d4,
+                pad: 0x400e8318,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_27",
+            register: 0x400e86f4,
+            pad: crate::PadLocation {
+                mux: 0x400e81e8,
+                pad: 0x400e842c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_28",
+            register: 0x400e86f8,
+            pad: crate::PadLocation {
+                mux: 0x400e80d8,
+                pad: 0x400e831c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_28",
+            register: 0x400e86f8,
+            pad: crate::PadLocation {
+                mux: 0x400e81ec,
+                pad: 0x400e8430,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_29",
+            register: 0x400e86fc,
+            pad: crate::PadLocation {
+                mux: 0x400e80dc,
+                pad: 0x400e8320,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_29",
+            register: 0x400e86fc,
+            pad: crate::PadLocation {
+                mux: 0x400e81f0,
+                pad: 0x400e8434,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_30",
+            register: 0x400e8700,
+            pad: crate::PadLocation {
+                mux: 0x400e80e0,
+                pad: 0x400e8324,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_30",
+            register: 0x400e8700,
+            pad: crate::PadLocation {
+                mux: 0x400e81f4,
+                pad: 0x400e8438,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_31",
+            register: 0x400e8704,
+            pad: crate::PadLocation {
+                mux: 0x400e80e4,
+                pad: 0x400e8328,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_31",
+            register: 0x400e8704,
+            pad: crate::PadLocation {
+                mux: 0x400e81f8,
+                pad: 0x400e843c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_32",
+            register: 0x400e8708,
+            pad: crate::PadLocation {
+                mux: 0x400e80e8,
+                pad: 0x400e832c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_32",
+            register: 0x400e8708,
+            pad: crate::PadLocation {
+                mux: 0x400e81fc,
+                pad: 0x400e8440,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_33",
+            register: 0x400e870c,
+            pad: crate::PadLocation {
+                mux: 0x400e80ec,
+                pad: 0x400e8330,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_33",
+            register: 0x400e870c,
+            pad: crate::PadLocation {
+                mux: 0x400e8200,
+                pad: 0x400e8444,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_34",
+            register: 0x400e8710,
+            pad: crate::PadLocation {
+                mux: 0x400e80f0,
+                pad: 0x400e8334,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_34",
+            register: 0x400e8710,
+            pad: crate::PadLocation {
+                mux: 0x400e8204,
+                pad: 0x400e8448,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "XBAR1_IN_35",
+            register: 0x400e8714,
+            pad: crate::PadLocation {
+                mux: 0x400e80f4,
+                pad: 0x400e8338,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "XBAR1_IN_35",
+            register: 0x400e8714,
+            pad: crate::PadLocation {
+                mux: 0x400e8208,
+                pad: 0x400e844c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CAN3_IPP_IND_CANRX",
+            register: 0x40c08080,
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "CAN3_IPP_IND_CANRX",
+            register: 0x40c08080,
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "CAN3_IPP_IND_CANRX",
+            register: 0x40c08080,
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPI2C5_IPP_IND_LPI2C_SCL",
+            register: 0x40c08084,
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C5_IPP_IND_LPI2C_SCL",
+            register: 0x40c08084,
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C5_IPP_IND_LPI2C_SDA",
+            register: 0x40c08088,
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C5_IPP_IND_LPI2C_SDA",
+            register: 0x40c08088,
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C6_IPP_IND_LPI2C_SCL",
+            register: 0x40c0808c,
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C6_IPP_IND_LPI2C_SCL",
+            register: 0x40c0808c,
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPI2C6_IPP_IND_LPI2C_SDA",
+            register: 0x40c08090,
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPI2C6_IPP_IND_LPI2C_SDA",
+            register: 0x40c08090,
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_PCS_0",
+            register: 0x40c08094,
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_PCS_0",
+            register: 0x40c08094,
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SCK",
+            register: 0x40c08098,
+            pad: crate::PadLocation {
+                mux: 0x40c08008,
+                pad: 0x40c08048,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SCK",
+            register: 0x40c08098,
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SDI",
+            register: 0x40c0809c,
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SDI",
+            register: 0x40c0809c,
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SDO",
+            register: 0x40c080a0,
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPSPI5_IPP_IND_LPSPI_SDO",
+            register: 0x40c080a0,
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART11_IPP_IND_LPUART_RXD",
+            register: 0x40c080a4,
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART11_IPP_IND_LPUART_RXD",
+            register: 0x40c080a4,
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART11_IPP_IND_LPUART_TXD",
+            register: 0x40c080a8,
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART11_IPP_IND_LPUART_TXD",
+            register: 0x40c080a8,
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_RXD",
+            register: 0x40c080ac,
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_RXD",
+            register: 0x40c080ac,
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_RXD",
+            register: 0x40c080ac,
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_TXD",
+            register: 0x40c080b0,
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_TXD",
+            register: 0x40c080b0,
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "LPUART12_IPP_IND_LPUART_TXD",
+            register: 0x40c080b0,
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            value: 2,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_0",
+            register: 0x40c080b4,
+            pad: crate::PadLocation {
+                mux: 0x40c08004,
+                pad: 0x40c08044,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_0",
+            register: 0x40c080b4,
+            pad: crate::PadLocation {
+                mux: 0x40c08024,
+                pad: 0x40c08064,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_1",
+            register: 0x40c080b8,
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_1",
+            register: 0x40c080b8,
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_2",
+            register: 0x40c080bc,
+            pad: crate::PadLocation {
+                mux: 0x40c0802c,
+                pad: 0x40c0806c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_2",
+            register: 0x40c080bc,
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_3",
+            register: 0x40c080c0,
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "MIC_IPP_IND_MIC_PDM_BITSTREAM_3",
+            register: 0x40c080c0,
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "NMI_GLUE_IPP_IND_NMI",
+            register: 0x40c080c4,
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPG_CLK_SAI_MCLK",
+            register: 0x40c080c8,
+            pad: crate::PadLocation {
+                mux: 0x40c08000,
+                pad: 0x40c08040,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPG_CLK_SAI_MCLK",
+            register: 0x40c080c8,
+            pad: crate::PadLocation {
+                mux: 0x40c08014,
+                pad: 0x40c08054,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXBCLK",
+            register: 0x40c080cc,
+            pad: crate::PadLocation {
+                mux: 0x40c0801c,
+                pad: 0x40c0805c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXBCLK",
+            register: 0x40c080cc,
+            pad: crate::PadLocation {
+                mux: 0x40c08038,
+                pad: 0x40c08078,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXDATA_0",
+            register: 0x40c080d0,
+            pad: crate::PadLocation {
+                mux: 0x40c08018,
+                pad: 0x40c08058,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXDATA_0",
+            register: 0x40c080d0,
+            pad: crate::PadLocation {
+                mux: 0x40c08034,
+                pad: 0x40c08074,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXSYNC",
+            register: 0x40c080d4,
+            pad: crate::PadLocation {
+                mux: 0x40c08020,
+                pad: 0x40c08060,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_RXSYNC",
+            register: 0x40c080d4,
+            pad: crate::PadLocation {
+                mux: 0x40c0803c,
+                pad: 0x40c0807c,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_TXBCLK",
+            register: 0x40c080d8,
+            pad: crate::PadLocation {
+                mux: 0x40c08010,
+                pad: 0x40c08050,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_TXBCLK",
+            register: 0x40c080d8,
+            pad: crate::PadLocation {
+                mux: 0x40c08030,
+                pad: 0x40c08070,
+            },
+            value: 1,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_TXSYNC",
+            register: 0x40c080dc,
+            pad: crate::PadLocation {
+                mux: 0x40c0800c,
+                pad: 0x40c0804c,
+            },
+            value: 0,
+        },
+        SelectInput {
+            input: "SAI4_IPP_IND_SAI_TXSYNC",
+            register: 0x40c080dc,
+            pad: crate::PadLocation {
+                mux: 0x40c08028,
+                pad: 0x40c08068,
+            },
+            value: 1,
+        },
+    ],
+};
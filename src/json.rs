@@ -0,0 +1,180 @@
+//! The pad dataset as JSON
+//!
+//! [`dataset()`] writes a chip's pads, with their registers and daisy values,
+//! as a JSON document. Pass a signal [`Database`] to add each pad's alternates
+//! and select inputs. Pin planners and documentation generators can read the
+//! document instead of parsing this crate's source.
+//!
+//! ```
+//! use imxrt_iomuxc::{json, register_map::PadRegisters};
+//!
+//! const MAP: &[PadRegisters] = &[PadRegisters {
+//!     name: "GPIO_AD_B0_12",
+//!     mux: 0x401F_80EC,
+//!     pad: 0x401F_82DC,
+//!     reset: None,
+//!     daisies: &[],
+//! }];
+//!
+//! let document = json::dataset(MAP, None);
+//! assert!(document.contains(r#""name":"GPIO_AD_B0_12""#));
+//! ```
+//!
+//! The document has one object per pad, in `map` order:
+//!
+//! ```text
+//! {"pads":[
+//! {"name":"GPIO_AD_B0_12","mux":1075806444,"pad":1075806940,
+//!  "daisies":[{"register":1075807716,"value":1}],
+//!  "alternates":[{"alt":2,"signal":"LPUART1_TX"}],
+//!  "inputs":[{"input":"LPUART1_TX","register":1075807716,"value":1}]}
+//! ]}
+//! ```
+//!
+//! Addresses are numbers. Without a database, `alternates` and `inputs` are
+//! empty.
+
+use crate::{register_map::PadRegisters, signals::Database, PadLocation};
+use core::fmt::{self, Write};
+use std::string::String;
+
+/// A JSON string
+struct Str<'a>(&'a str);
+
+impl fmt::Display for Str<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Write the pads in `map` as a JSON document
+///
+/// `database` supplies the pads' alternates and select inputs.
+pub fn dataset(map: &[PadRegisters], database: Option<&Database>) -> String {
+    let mut json = String::new();
+    // Writing to a String never fails.
+    let _ = write_dataset(&mut json, map, database);
+    json
+}
+
+fn write_dataset(
+    out: &mut String,
+    map: &[PadRegisters],
+    database: Option<&Database>,
+) -> fmt::Result {
+    writeln!(out, "{{\"pads\":[")?;
+    for (idx, pad) in map.iter().enumerate() {
+        let location = PadLocation {
+            mux: pad.mux,
+            pad: pad.pad,
+        };
+        write!(
+            out,
+            "{{\"name\":{},\"mux\":{},\"pad\":{},\"daisies\":[",
+            Str(pad.name),
+            pad.mux,
+            pad.pad
+        )?;
+        for (idx, daisy) in pad.daisies.iter().enumerate() {
+            let comma = if idx > 0 { "," } else { "" };
+            write!(
+                out,
+                "{comma}{{\"register\":{},\"value\":{}}}",
+                daisy.reg, daisy.value
+            )?;
+        }
+        write!(out, "],\"alternates\":[")?;
+        let alternates = database
+            .into_iter()
+            .flat_map(|db| db.alternates_of(location));
+        for (idx, alt) in alternates.enumerate() {
+            let comma = if idx > 0 { "," } else { "" };
+            write!(
+                out,
+                "{comma}{{\"alt\":{},\"signal\":{}}}",
+                alt.alt,
+                Str(alt.signal)
+            )?;
+        }
+        write!(out, "],\"inputs\":[")?;
+        let inputs = database.into_iter().flat_map(|db| db.inputs_of(location));
+        for (idx, input) in inputs.enumerate() {
+            let comma = if idx > 0 { "," } else { "" };
+            write!(
+                out,
+                "{comma}{{\"input\":{},\"register\":{},\"value\":{}}}",
+                Str(input.input),
+                input.register,
+                input.value
+            )?;
+        }
+        let comma = if idx + 1 < map.len() { "," } else { "" };
+        writeln!(out, "]}}{comma}")?;
+    }
+    write!(out, "]}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dataset, Str};
+    use crate::register_map::{DaisyEntry, PadRegisters};
+    use crate::signals::{Alternate, Database, SelectInput};
+    use crate::PadLocation;
+
+    #[test]
+    fn pads_and_signals() {
+        const PAD: PadLocation = PadLocation { mux: 4, pad: 8 };
+        const MAP: &[PadRegisters] = &[
+            PadRegisters {
+                name: "PAD_A",
+                mux: 4,
+                pad: 8,
+                reset: None,
+                daisies: &[DaisyEntry { reg: 12, value: 1 }],
+            },
+            PadRegisters {
+                name: "PAD_B",
+                mux: 16,
+                pad: 20,
+                reset: None,
+                daisies: &[],
+            },
+        ];
+        const DATABASE: Database = Database {
+            alternates: &[Alternate {
+                pad: PAD,
+                alt: 2,
+                signal: "LPUART1_RX",
+            }],
+            inputs: &[SelectInput {
+                input: "LPUART1_RX",
+                register: 12,
+                pad: PAD,
+                value: 1,
+            }],
+        };
+        assert_eq!(
+            dataset(MAP, Some(&DATABASE)),
+            concat!(
+                "{\"pads\":[\n",
+                "{\"name\":\"PAD_A\",\"mux\":4,\"pad\":8,",
+                "\"daisies\":[{\"register\":12,\"value\":1}],",
+                "\"alternates\":[{\"alt\":2,\"signal\":\"LPUART1_RX\"}],",
+                "\"inputs\":[{\"input\":\"LPUART1_RX\",\"register\":12,\"value\":1}]},\n",
+                "{\"name\":\"PAD_B\",\"mux\":16,\"pad\":20,",
+                "\"daisies\":[],\"alternates\":[],\"inputs\":[]}\n",
+                "]}",
+            )
+        );
+        assert_eq!(std::format!("{}", Str("a\"b\\\n")), "\"a\\\"b\\\\\\u000a\"");
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! As an end user, you're expected to use `imxrt-iomuxc` through a hardware abstraction layer
 //! (HAL) or board support package (BSP). Specifically, you should have access to pad structs and
-//! objects, and you should be able to configure pads with the [`configure`] APIs.
+//! objects, and you should be able to configure pads with the [`configure()`] APIs.
 //!
 //! As a library developer who writes HALs or hardware drivers, you may use the `imxrt-iomuxc`
 //! pin traits in your APIs to statically ensure pad-peripheral compatibility. See the design
@@ -26,6 +26,22 @@
 //! RT 1060 processor pads. Users and integrators are responsible for making sure an enabled
 //! feature makes sense for their system.
 //!
+//! Without any features, the package has the pin traits, [`Config`], [`Daisy`],
+//! and the other chip-independent types, but no pads. Generic drivers should
+//! depend on the package this way. The build script only generates pads with the
+//! `svd` feature.
+//!
+//! You may enable more than one chip feature. Each chip's pads, pin
+//! implementations, and data, like its `REGISTER_MAP`, are in the chip's
+//! module, so host tools may use `imxrt1010`, `imxrt1060`, and `imxrt1170`
+//! together. No two chips define a pad with the same MUX and PAD registers, so
+//! their `Pad` types never overlap.
+//!
+//! The package doesn't assume an i.MX RT target. Point an [`ErasedPad`] at
+//! memory, and the package configures that memory like the IOMUXC registers;
+//! see [`ErasedPad::new()`]. Host tests, and pin planners built for
+//! `wasm32-unknown-unknown`, use the same code that runs on the chip.
+//!
 //! # Design Guidance
 //!
 //! For recommendations on how you can use these traits, see the module-level documentation. The
@@ -111,6 +127,35 @@
 //! lpuart_new(gpio_ad_b1_02, gpio_ad_b0_13);
 //! ```
 //!
+//! ## Borrowing pads
+//!
+//! The pin traits are implemented for mutable references to pins. A driver that only needs
+//! its pads during construction can accept borrowed pads with the same trait bounds, and the
+//! caller retains ownership.
+//!
+//! ```no_run
+//! use imxrt_iomuxc as iomuxc;
+//! use iomuxc::lpuart::{Pin, Tx, Rx};
+//!
+//! # struct Lpuart<const N: u8>;
+//! fn lpuart_new<T, R, const N: u8>(mut tx: T, mut rx: R) -> Lpuart<N>
+//! where
+//!     T: Pin<Direction = Tx, Module = iomuxc::consts::Const<N>>,
+//!     R: Pin<Direction = Rx, Module = <T as Pin>::Module>,
+//! {
+//!     iomuxc::lpuart::prepare(&mut tx);
+//!     iomuxc::lpuart::prepare(&mut rx);
+//!     # Lpuart
+//! }
+//!
+//! # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_13, GPIO_AD_B0_12};
+//! let mut gpio_ad_b0_13 = unsafe { GPIO_AD_B0_13::new() };
+//! let mut gpio_ad_b0_12 = unsafe { GPIO_AD_B0_12::new() };
+//! let uart1 = lpuart_new(&mut gpio_ad_b0_12, &mut gpio_ad_b0_13);
+//! // Still own the pads...
+//! let erased = gpio_ad_b0_12.erase();
+//! ```
+//!
 //! ## Type-Erased Pads
 //!
 //! At the expense of requiring `unsafe`, users may favor type-erased pads over strongly-typed pads.
@@ -162,38 +207,141 @@
 //!     <GPIO_AD_B0_13 as imxrt_iomuxc::lpuart::Pin>::DAISY.map(|daisy| daisy.write());
 //!     <GPIO_AD_B0_12 as imxrt_iomuxc::lpuart::Pin>::DAISY.map(|daisy| daisy.write());
 //! }
-//! imxrt_iomuxc::alternate(&mut tx_pad, 2);
-//! imxrt_iomuxc::alternate(&mut rx_pad, 2);
+//! imxrt_iomuxc::alternate(&mut tx_pad, imxrt_iomuxc::Alternate::<2>);
+//! imxrt_iomuxc::alternate(&mut rx_pad, imxrt_iomuxc::Alternate::<2>);
 //! imxrt_iomuxc::clear_sion(&mut tx_pad);
 //! imxrt_iomuxc::clear_sion(&mut rx_pad);
 //! // Pads are configured for LPUART settings
 //! let uart1 = Lpuart::<1>::with_erased_pads(tx_pad, rx_pad);
 //! ```
+//!
+//! ## Preparation writes
+//!
+//! Each peripheral `prepare()` function performs, in order,
+//!
+//! 1. one read-modify-write of the pad's MUX register, which sets the alternate
+//!    and, if the peripheral needs it, sets or clears the SION bit.
+//! 2. for uSDHC pins, one write of the pad's PAD register.
+//! 3. at most one daisy register write, if the pin has a daisy value.
+//!
+//! The GPIO, FlexIO, FlexPWM, and CCM `prepare()` functions only set the alternate, and
+//! the ADC `prepare()` function only modifies the PAD register. A `Pins` set
+//! prepares each of its pins once. Pins of a set that select different inputs
+//! write different daisy registers.
+//!
+//! To write each register once across many pins, use a [`PreparePlan`].
+//!
+//! ## Using `imxrt-ral`
+//!
+//! Pads name their registers by the chip's addresses, so they don't need an
+//! `imxrt-ral` instance. To keep the RAL as the only owner of the IOMUXC, or to
+//! configure pads in an emulated register block, enable the `imxrt-ral`
+//! feature, and move pads into a [`ral::Block`]. The
+//! block's pads and daisies access the instance's registers.
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 pub mod adc;
+pub mod assignments;
+#[macro_use]
+mod board;
+pub mod bus;
+#[cfg(feature = "self-check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-check")))]
+pub mod check;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod codegen;
 mod config;
+mod custom;
+#[cfg(feature = "digital")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digital")))]
+pub mod digital;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod dts;
+#[cfg(feature = "embassy")]
+mod embassy;
+#[macro_use]
+pub mod enet;
+#[cfg(feature = "export")]
+#[cfg_attr(docsrs, doc(cfg(feature = "export")))]
+pub mod export;
+#[macro_use]
+pub mod flexcan;
+#[macro_use]
+pub mod flexio;
 #[macro_use]
 pub mod flexpwm;
 #[macro_use]
+pub mod flexspi;
+#[cfg(feature = "golden")]
+#[cfg_attr(docsrs, doc(cfg(feature = "golden")))]
+pub mod golden;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod json;
+pub mod lock;
+#[macro_use]
+pub mod lpadc;
+#[macro_use]
 pub mod lpi2c;
 #[macro_use]
 pub mod lpspi;
 #[macro_use]
 pub mod lpuart;
+pub mod motor;
+mod nopin;
+#[cfg(feature = "pin-map")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pin-map")))]
+pub mod pin_map;
+mod plan;
+mod prepared;
+#[cfg(feature = "imxrt-ral")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imxrt-ral")))]
+pub mod ral;
+pub mod register_map;
+#[cfg(feature = "remote")]
+#[cfg_attr(docsrs, doc(cfg(feature = "remote")))]
+pub mod remote;
 #[macro_use]
 pub mod sai;
+mod shared;
+pub mod signals;
+pub mod sleep;
+pub mod snvs;
+mod table;
+#[cfg(feature = "text")]
+#[cfg_attr(docsrs, doc(cfg(feature = "text")))]
+pub mod text;
+#[cfg(any(feature = "log", feature = "defmt"))]
+mod trace;
 #[macro_use]
 pub mod usdhc;
+pub mod unused;
+pub mod wakeup;
+#[cfg(feature = "xip-guard")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xip-guard")))]
+pub mod xip;
 
-use core::ptr;
+use core::ptr::{self, NonNull};
 
+pub use board::{Board, BoardRoles, PadLocation, SdCard, SdCardRoles};
+use config::configure_register;
 pub use config::{
-    configure, Config, DriveStrength, Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
+    configure, configure_all, configure_cs, configure_if_changed, Config, DriveStrength,
+    Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
 };
+pub use nopin::NoPin;
+pub use plan::PreparePlan;
+pub use prepared::Prepared;
+pub use shared::{Lease, Shared};
+pub use table::{PadImage, PadTable, RegisterWrite};
 
 /// Re-export of top-level components, without the chip-specific modules.
 ///
@@ -212,12 +360,17 @@ pub use config::{
 /// ```
 pub mod prelude {
     pub use crate::config::{
-        configure, Config, DriveStrength, Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
+        configure, configure_all, configure_cs, configure_if_changed, Config, DriveStrength,
+        Hysteresis, OpenDrain, PullKeeper, SlewRate, Speed,
     };
 
     pub use crate::{
-        adc, alternate, ccm, clear_sion, consts, flexpwm, gpio, lpi2c, lpspi, lpuart, sai,
-        set_sion, usdhc, Daisy, ErasedPad, Pad, WrongPadError,
+        adc, alternate, alternate_cs, alternate_raw, ccm, clear_sion, consts, enet, flexio,
+        flexpwm, flexspi, gpio, lock, lpadc, lpi2c, lpspi, lpuart, prepare_alternate,
+        prepare_analog, replace_alternate, sai, set_sion, signals, sleep, unused, usdhc, wakeup,
+        Alternate, Analog, AnyAltPin, Daisy, DaisyChoice, ErasedPad, Lease, NoPin, OutputCapable,
+        Pad, PadImage, PadLocation, PadTable, PreparePlan, Prepared, RegisterWrite, Shared,
+        WrongPadError,
     };
 }
 
@@ -227,6 +380,20 @@ pub mod consts {
     ///
     /// You can pattern match these in trait constraints. See the package documentation for
     /// examples.
+    ///
+    /// To use the constant in your code, match it with a const generic. Here, `N` is the
+    /// instance number of the pin's module:
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{consts::Const, lpuart::Pin};
+    ///
+    /// fn module<P, const N: u8>(_: &P) -> u8
+    /// where
+    ///     P: Pin<Module = Const<N>>,
+    /// {
+    ///     N
+    /// }
+    /// ```
     #[derive(Debug)]
     pub enum Const<const N: u8> {}
     #[doc(hidden)]
@@ -241,7 +408,10 @@ pub mod consts {
     }
     macro_rules! ux {
         ($($Ux:ident => $N:literal,)+) => {
-            $(pub type $Ux = Const<$N>;)+
+            $(
+                #[deprecated(since = "0.3.0", note = "use Const<N>; these aliases will be removed")]
+                pub type $Ux = Const<$N>;
+            )+
         };
     }
     ux! {
@@ -257,18 +427,48 @@ pub mod consts {
     }
 }
 
-#[cfg(feature = "imxrt1010")]
+#[cfg(feature = "_imxrt1010")]
 #[cfg_attr(docsrs, doc(cfg(feature = "imxrt1010")))]
 pub mod imxrt1010;
 
-#[cfg(feature = "imxrt1060")]
+#[cfg(feature = "_imxrt1060")]
 #[cfg_attr(docsrs, doc(cfg(feature = "imxrt1060")))]
 pub mod imxrt1060;
 
-#[cfg(feature = "imxrt1170")]
+#[cfg(feature = "_imxrt1170")]
 #[cfg_attr(docsrs, doc(cfg(feature = "imxrt1170")))]
 pub mod imxrt1170;
 
+/// Pads generated from the SVD named by `IMXRT_IOMUXC_SVD`
+///
+/// When you enable the `svd` feature, and set the `IMXRT_IOMUXC_SVD` environment
+/// variable to the path of an i.MX RT SVD file, the build script generates this module from the file's
+/// IOMUXC peripherals. The module has the same pad groups and GPIO pin
+/// implementations as `iomuxc.py` generates, and a `daisy` module with every
+/// select input value, and a signal `DATABASE`. Since the module implements traits on the same `Pad`
+/// types, you can't enable a chip feature with an SVD.
+///
+/// The module also has `RESET_VALUES`, the SVD's reset value of every MUX, PAD,
+/// and select input register, and a `reset()` function that writes them. Its
+/// `REGISTER_MAP` has each pad's reset values, and the daisy values from the
+/// SVD's full select input table.
+#[cfg(imxrt_iomuxc_svd)]
+#[allow(non_camel_case_types)]
+pub mod svd {
+    include!(concat!(env!("OUT_DIR"), "/svd.rs"));
+}
+
+#[cfg(any(
+    feature = "imxrt1010evk",
+    feature = "imxrt1060evk",
+    feature = "imxrt1170evk",
+    feature = "metro-m7",
+    feature = "teensy40",
+    feature = "teensy41",
+    feature = "teensy-micromod"
+))]
+pub mod boards;
+
 /// An IOMUXC-capable pad which can support I/O multiplexing
 ///
 /// # Safety
@@ -288,7 +488,114 @@ mod private {
     pub trait Sealed {}
 }
 
+/// Implementation details of the [`define_pad!`] macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::board::first_duplicate;
+    pub use crate::private::Sealed;
+}
+
+/// A pad that can drive an output
+///
+/// Use this bound in APIs that drive a pad, like GPIO outputs or push-pull
+/// peripheral signals, to reject pads that can only be inputs.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{imxrt1060::snvs::PMIC_ON_REQ, OutputCapable};
+///
+/// fn drive<P: OutputCapable>(_: &mut P) {}
+///
+/// let mut pad = unsafe { PMIC_ON_REQ::new() };
+/// drive(&mut pad);
+/// ```
+///
+/// The generated input-only pads, like the SNVS `WAKEUP` pads, don't
+/// satisfy the bound:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{imxrt1060::snvs::WAKEUP, OutputCapable};
+///
+/// fn drive<P: OutputCapable>(_: &mut P) {}
+///
+/// let mut pad = unsafe { WAKEUP::new() };
+/// drive(&mut pad);
+/// ```
+pub trait OutputCapable: Iomuxc {}
+
+/// A pad that connects to an analog peripheral
+///
+/// Every [`adc::Pin`] in this package implements `Analog`. Use this bound in analog drivers
+/// that accept any analog pad, and [`prepare_analog()`] to configure the pad.
+pub trait Analog: Iomuxc {}
+
+/// A pad that has a signal on alternate `ALT`
+///
+/// Use this bound for peripherals that don't have a pin trait, then select the
+/// alternate with [`prepare_alternate()`]. The bound checks that the pad has a
+/// signal at `ALT`, but not which signal; check that in the reference manual.
+///
+/// The generated pads implement `AnyAltPin` for every `MUX_MODE` alternate in
+/// the SVD, including alternates of peripherals without pin traits.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{prepare_alternate, AnyAltPin};
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_00;
+///
+/// // GPIO_AD_B0_00 is XBAR1_INOUT14 at alternate 1.
+/// fn prepare_xbar<P: AnyAltPin<1>>(pad: &mut P) {
+///     prepare_alternate::<1>(pad);
+/// }
+///
+/// let mut pad = unsafe { GPIO_AD_B0_00::new() };
+/// prepare_xbar(&mut pad);
+/// ```
+///
+/// A pad without a signal at `ALT` doesn't satisfy the bound:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{prepare_alternate, imxrt1060::gpio_ad_b0::GPIO_AD_B0_00};
+///
+/// // GPIO_AD_B0_00 has alternates 0 through 7.
+/// let mut pad = unsafe { GPIO_AD_B0_00::new() };
+/// prepare_alternate::<8>(&mut pad);
+/// ```
+pub trait AnyAltPin<const ALT: u8>: Iomuxc {}
+
+impl<P: OutputCapable + ?Sized> OutputCapable for &mut P {}
+impl<P: Analog + ?Sized> Analog for &mut P {}
+impl<P: AnyAltPin<ALT> + ?Sized, const ALT: u8> AnyAltPin<ALT> for &mut P {}
+
+/// Select an alternate that the pad has
+///
+/// Like [`alternate()`], but the [`AnyAltPin`] bound checks that the pad has a
+/// signal at `ALT`. The SION bit doesn't change.
+#[inline(always)]
+pub fn prepare_alternate<const ALT: u8>(pad: &mut impl AnyAltPin<ALT>) {
+    alternate_raw(pad, ALT as u32);
+}
+
+/// Put a pad in its analog state
+///
+/// Clears the SION bit, and disables the pull/keeper and hysteresis, like
+/// [`adc::prepare()`]. The pad's other fields, and its alternate, don't
+/// change. The reference manuals note that the alternate doesn't affect the
+/// analog input.
+pub fn prepare_analog<P: Analog>(pad: &mut P) {
+    clear_sion(pad);
+    configure(pad, adc::CONFIG);
+}
+
 const SION_BIT: u32 = 1 << 4;
+/// The MUX_MODE field of a pad's MUX register.
+const ALT_MASK: u32 = 0b1111;
+
+/// Returns `true` if the alternate fits in the MUX_MODE field.
+///
+/// Used by the pin implementation macros to reject invalid alternates.
+#[allow(unused)]
+const fn alt_fits(alt: u32) -> bool {
+    alt & !ALT_MASK == 0
+}
 
 /// Set the SION bit in a pad's MUX register
 ///
@@ -313,11 +620,7 @@ pub fn set_sion<I: Iomuxc>(pad: &mut I) {
     // By taking a mutable reference, the caller has to ensure atomicity of this
     // read-modify-write operation (or, violate the requirement with more unsafe
     // code).
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux |= SION_BIT;
-        ptr::write_volatile(pad.mux(), mux);
-    }
+    unsafe { modify_mux(pad.mux(), 0, SION_BIT) };
 }
 
 /// Clear the SION bit in a pad's MUX register
@@ -331,11 +634,32 @@ pub fn set_sion<I: Iomuxc>(pad: &mut I) {
 #[inline(always)]
 pub fn clear_sion<I: Iomuxc>(pad: &mut I) {
     // Safety: same justification as set_sion
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux &= !SION_BIT;
-        ptr::write_volatile(pad.mux(), mux);
-    }
+    unsafe { modify_mux(pad.mux(), SION_BIT, 0) };
+}
+
+/// An alternate value for a pad's MUX_MODE field
+///
+/// The alternate is part of the type, so functions like [`alternate()`] check,
+/// at compile time, that `N` fits the MUX_MODE field. An alternate with the
+/// SION bit, like `0x15`, fails to compile:
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::{alternate, Alternate};
+/// # type GPIO_AD_B0_03 = imxrt_iomuxc::Pad<0xDEAD, 0xBEEF>;
+/// let mut pad = unsafe { GPIO_AD_B0_03::new() };
+/// alternate(&mut pad, Alternate::<0x15>);
+/// ```
+///
+/// Use [`alternate_raw()`] for alternates that are only known at run time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Alternate<const N: u32>;
+
+impl<const N: u32> Alternate<N> {
+    /// The alternate value
+    pub const VALUE: u32 = {
+        assert!(alt_fits(N), "Alternate exceeds MUX_MODE");
+        N
+    };
 }
 
 /// Set an alternate value for the pad
@@ -347,15 +671,120 @@ pub fn clear_sion<I: Iomuxc>(pad: &mut I) {
 /// However, you should use `alternate()` if you're using any type-erased pads, since those
 /// pads cannot be used with a peripheral's `prepare()` function.
 #[inline(always)]
-pub fn alternate<I: Iomuxc>(pad: &mut I, alt: u32) {
-    const ALT_MASK: u32 = 0b1111;
+pub fn alternate<I: Iomuxc, const N: u32>(pad: &mut I, _: Alternate<N>) {
+    alternate_raw(pad, Alternate::<N>::VALUE);
+}
+
+/// Set an alternate value for the pad, without checking the value
+///
+/// Like [`alternate()`], but the alternate is a value that's only known at run
+/// time. Bits of `alt` that don't fit the MUX_MODE field are ignored.
+#[inline(always)]
+pub fn alternate_raw<I: Iomuxc>(pad: &mut I, alt: u32) {
     // Safety: same justification as set_sion. Argument extends to
     // pad values and alternate values.
-    unsafe {
-        let mut mux = ptr::read_volatile(pad.mux());
-        mux = (mux & !ALT_MASK) | (alt & ALT_MASK);
-        ptr::write_volatile(pad.mux(), mux);
+    unsafe { modify_mux(pad.mux(), ALT_MASK, alt & ALT_MASK) };
+}
+
+/// Set an alternate value for the pad, and return the previous alternate
+///
+/// Like [`alternate()`]. Pass the returned value to [`alternate_raw()`] to
+/// restore the pad's previous alternate:
+///
+/// ```
+/// use imxrt_iomuxc::{alternate_raw, replace_alternate, Alternate};
+/// # fn borrow_pad(pad: &mut imxrt_iomuxc::ErasedPad) {
+/// let previous = replace_alternate(pad, Alternate::<5>);
+/// // Use the pad as a GPIO...
+/// alternate_raw(pad, previous);
+/// # }
+/// ```
+#[inline(always)]
+pub fn replace_alternate<I: Iomuxc, const N: u32>(pad: &mut I, _: Alternate<N>) -> u32 {
+    // Safety: same justification as alternate.
+    unsafe { modify_mux(pad.mux(), ALT_MASK, Alternate::<N>::VALUE) & ALT_MASK }
+}
+
+/// Set an alternate value for the pad inside a critical section
+///
+/// Like [`alternate()`], but `critical_section` runs the register
+/// read-modify-write. See [`configure_cs()`] for the requirements of
+/// `critical_section`.
+pub fn alternate_cs<I: Iomuxc, const N: u32>(
+    pad: &mut I,
+    _: Alternate<N>,
+    critical_section: impl FnOnce(&mut dyn FnMut()),
+) {
+    let mux = pad.mux();
+    // Safety: same justification as alternate. The critical section makes
+    // the read-modify-write atomic.
+    critical_section(&mut || unsafe {
+        modify_mux(mux, ALT_MASK, Alternate::<N>::VALUE);
+    });
+}
+
+/// Clear the `clear` bits, then set the `set` bits, of the MUX register `mux`
+///
+/// `alternate()`, `set_sion()`, and `clear_sion()` call this function. It's not
+/// generic, so all pads share one copy of the read-modify-write code. Returns
+/// the register's previous value.
+///
+/// # Safety
+///
+/// `mux` must be a valid MUX register. The caller must ensure that the
+/// read-modify-write operation does not race.
+unsafe fn modify_mux(mux: *mut u32, clear: u32, set: u32) -> u32 {
+    lock::check_mux(mux);
+    #[cfg(feature = "xip-guard")]
+    xip::check_mux(mux);
+    let value = ptr::read_volatile(mux);
+    ptr::write_volatile(mux, (value & !clear) | set);
+    #[cfg(any(feature = "log", feature = "defmt"))]
+    trace::write(mux, (value & !clear) | set);
+    value
+}
+
+/// Set the alternate and SION bit of the MUX register `mux`, then write the
+/// `daisy` value
+///
+/// Peripheral `prepare()` functions call this function. It's not generic, so
+/// all pads share one copy of the preparation code.
+///
+/// # Safety
+///
+/// `mux` must be a valid MUX register. The caller must ensure that the
+/// read-modify-write operation does not race.
+#[allow(unused)] // Used in peripheral modules...
+unsafe fn prepare_mux(mux: *mut u32, alt: u32, sion: bool, daisy: Option<Daisy>) {
+    let sion = if sion { SION_BIT } else { 0 };
+    modify_mux(mux, ALT_MASK | SION_BIT, (alt & ALT_MASK) | sion);
+    if let Some(daisy) = daisy {
+        daisy.write();
+    }
+}
+
+/// Write `value` to the IOMUXC register `reg`
+///
+/// `reg` may be any IOMUXC register. If it's a locked or boot flash pad's MUX
+/// or PAD register, the function panics like [`modify_mux()`] and
+/// [`configure_register()`](config::configure_register). The snapshot and
+/// reset functions of [`register_map`] call this function.
+///
+/// # Safety
+///
+/// `reg` must be a valid register. The caller must ensure that the write does
+/// not race.
+unsafe fn write_register(reg: *mut u32, value: u32) {
+    lock::check_mux(reg);
+    lock::check_pad(reg);
+    #[cfg(feature = "xip-guard")]
+    {
+        xip::check_mux(reg);
+        xip::check_pad(reg);
     }
+    ptr::write_volatile(reg, value);
+    #[cfg(any(feature = "log", feature = "defmt"))]
+    trace::write(reg, value);
 }
 
 /// An i.MXT RT pad
@@ -379,12 +808,16 @@ impl<const MUX: u32, const PAD: u32> Pad<MUX, PAD> {
     /// `new()` may be called anywhere, by anyone. This could lead to multiple objects that
     /// mutate the same memory. Consider calling `new()` once, near startup, then passing objects
     /// and references throughout your program.
+    ///
+    /// `new()` is a `const fn`, so you may use it to initialize a `static`.
     #[inline(always)]
     pub const unsafe fn new() -> Self {
         Self {
             _not_send_sync: ::core::marker::PhantomData,
         }
     }
+    /// The pad's register addresses
+    pub const LOCATION: PadLocation = PadLocation { mux: MUX, pad: PAD };
     /// Cast the MUX address.
     const fn mux() -> *mut u32 {
         MUX as *mut u32
@@ -401,26 +834,33 @@ impl<const MUX: u32, const PAD: u32> Pad<MUX, PAD> {
     /// Erase the pad's type, returning an `ErasedPad`
     #[inline(always)]
     pub const fn erase(self) -> ErasedPad {
-        ErasedPad {
-            mux: Self::mux(),
-            pad: Self::pad(),
-        }
+        const {
+            assert!(
+                MUX != 0 && PAD != 0,
+                "pad registers are never at address zero"
+            )
+        };
+        // Safety: the assertion ensures that the pointers are non-null. See
+        // ErasedPad::new for the remaining requirements.
+        unsafe { ErasedPad::new(Self::mux(), Self::pad()) }
     }
 
     /// Set the alternate value for this pad.
     ///
     /// Performs a read-modify-write on the pad's mux register to set the
-    /// alternate value to `alt`.
+    /// alternate value to `N`. Returns the previous alternate value. Like
+    /// [`alternate()`], an alternate that doesn't fit the MUX_MODE field fails
+    /// to compile.
     ///
     /// # Safety
     ///
     /// This function performs a read-modify-write operation on peripheral
     /// memory. It could race with other calls that modify this pad's mux register.
-    /// For a safer interface, see [`alternate()`](crate::alternate()).
+    /// For a safer interface, see [`replace_alternate()`](crate::replace_alternate()).
     #[inline(always)]
-    pub unsafe fn set_alternate(alt: u32) {
-        let mut pad = Self::new();
-        alternate(&mut pad, alt);
+    pub unsafe fn set_alternate<const N: u32>(_: Alternate<N>) -> u32 {
+        // Safety: the caller ensures that the read-modify-write doesn't race.
+        unsafe { modify_mux(Self::mux(), ALT_MASK, Alternate::<N>::VALUE) & ALT_MASK }
     }
 
     /// Set the pad's SION bit.
@@ -467,6 +907,23 @@ impl<const MUX: u32, const PAD: u32> Pad<MUX, PAD> {
         let mut pad = Self::new();
         configure(&mut pad, config);
     }
+
+    /// Configure the pad, then return it
+    ///
+    /// Use this to configure pads as you move them into drivers.
+    ///
+    /// ```no_run
+    /// use imxrt_iomuxc::{Config, SlewRate};
+    /// use imxrt_iomuxc::imxrt1060::gpio_b0::GPIO_B0_03;
+    ///
+    /// let led = unsafe { GPIO_B0_03::new() }
+    ///     .with_config(Config::modify().set_slew_rate(SlewRate::Fast));
+    /// ```
+    #[inline(always)]
+    pub fn with_config(mut self, config: Config) -> Self {
+        configure(&mut self, config);
+        self
+    }
 }
 
 impl<const MUX: u32, const PAD: u32> private::Sealed for Pad<MUX, PAD> {}
@@ -499,17 +956,93 @@ unsafe impl<const MUX: u32, const PAD: u32> crate::Iomuxc for Pad<MUX, PAD> {
 /// let mut erased = gpio_ad_b0_03.erase();
 ///
 /// // Erased pads may be manually manipulated
-/// iomuxc::alternate(&mut erased, 7);
+/// iomuxc::alternate(&mut erased, iomuxc::Alternate::<7>);
 /// iomuxc::set_sion(&mut erased);
 ///
 /// // Try to convert the erased pad back to its strongly-typed counterpart
 /// use core::convert::TryFrom;
 /// let gpio_ad_b0_03 = GPIO_AD_B0_03::try_from(erased).unwrap();
 /// ```
+///
+/// `ErasedPad` is non-nullable, so an `Option<ErasedPad>` is the same size as an
+/// `ErasedPad`.
+///
+/// Erased pads compare, order, and hash by their MUX address, then their PAD
+/// address, like their [`location()`](Self::location). Use them as map keys.
 #[derive(Debug)]
 pub struct ErasedPad {
-    mux: *mut u32,
-    pad: *mut u32,
+    mux: NonNull<u32>,
+    pad: NonNull<u32>,
+}
+
+impl ErasedPad {
+    /// Creates an erased pad from its MUX and PAD register addresses
+    ///
+    /// Like [`Pad::new()`], this is a `const fn`, so you may use it to
+    /// initialize a `static`.
+    ///
+    /// # Safety
+    ///
+    /// `mux` and `pad` must be the pad's MUX and PAD registers, so they must not be null.
+    /// `new()` may be called anywhere, by anyone. This could lead to multiple objects that
+    /// mutate the same memory.
+    #[inline(always)]
+    pub const unsafe fn new(mux: *mut u32, pad: *mut u32) -> Self {
+        Self {
+            mux: NonNull::new_unchecked(mux),
+            pad: NonNull::new_unchecked(pad),
+        }
+    }
+
+    /// Returns the pad's MUX and PAD register addresses
+    ///
+    /// Print a location's addresses to describe a pad without `core::fmt`
+    /// formatting.
+    ///
+    /// On a host with 64-bit pointers, the addresses of a pad in host memory
+    /// are truncated to 32 bits.
+    #[inline(always)]
+    pub fn location(&self) -> PadLocation {
+        PadLocation {
+            mux: self.mux.as_ptr() as u32,
+            pad: self.pad.as_ptr() as u32,
+        }
+    }
+
+    /// Configure the pad, then return it
+    ///
+    /// See [`Pad::with_config()`].
+    #[inline(always)]
+    pub fn with_config(mut self, config: Config) -> Self {
+        configure(&mut self, config);
+        self
+    }
+}
+
+impl PartialEq for ErasedPad {
+    fn eq(&self, other: &Self) -> bool {
+        (self.mux, self.pad) == (other.mux, other.pad)
+    }
+}
+
+impl Eq for ErasedPad {}
+
+impl PartialOrd for ErasedPad {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ErasedPad {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.mux, self.pad).cmp(&(other.mux, other.pad))
+    }
+}
+
+impl core::hash::Hash for ErasedPad {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (self.mux, self.pad).hash(state);
+    }
 }
 
 impl private::Sealed for ErasedPad {}
@@ -517,17 +1050,32 @@ impl private::Sealed for ErasedPad {}
 unsafe impl crate::Iomuxc for ErasedPad {
     #[inline(always)]
     fn mux(&mut self) -> *mut u32 {
-        self.mux
+        self.mux.as_ptr()
     }
 
     #[inline(always)]
     fn pad(&mut self) -> *mut u32 {
-        self.pad
+        self.pad.as_ptr()
     }
 }
 
 unsafe impl Send for ErasedPad {}
 
+impl<I: Iomuxc + ?Sized> private::Sealed for &mut I {}
+
+// Safety: a mutable borrow of a pad uses the same registers as the pad.
+unsafe impl<I: Iomuxc + ?Sized> crate::Iomuxc for &mut I {
+    #[inline(always)]
+    fn mux(&mut self) -> *mut u32 {
+        (**self).mux()
+    }
+
+    #[inline(always)]
+    fn pad(&mut self) -> *mut u32 {
+        (**self).pad()
+    }
+}
+
 /// An error that indicates the conversion from an `ErasedPad` to a
 /// strongly-typed pad failed.
 ///
@@ -539,7 +1087,7 @@ pub struct WrongPadError(pub ErasedPad);
 impl<const MUX: u32, const PAD: u32> ::core::convert::TryFrom<ErasedPad> for Pad<MUX, PAD> {
     type Error = WrongPadError;
     fn try_from(erased_pad: ErasedPad) -> Result<Self, Self::Error> {
-        if erased_pad.mux == Self::mux() && erased_pad.pad == Self::pad() {
+        if erased_pad.mux.as_ptr() == Self::mux() && erased_pad.pad.as_ptr() == Self::pad() {
             Ok(unsafe { Self::new() })
         } else {
             Err(WrongPadError(erased_pad))
@@ -547,12 +1095,43 @@ impl<const MUX: u32, const PAD: u32> ::core::convert::TryFrom<ErasedPad> for Pad
     }
 }
 
+/// Returns `true` if the erased pads are the same pads, in the same order.
+#[allow(unused)] // Used in chip-specific modules...
+fn same_pads(left: &[ErasedPad], right: &[ErasedPad]) -> bool {
+    left == right
+}
+
+/// Returns the erased pad at `location`, searching each group in order.
+#[allow(unused)] // Used in chip-specific modules...
+fn find_pad<const N: usize>(
+    groups: [&[ErasedPad]; N],
+    location: PadLocation,
+) -> Option<&ErasedPad> {
+    groups
+        .into_iter()
+        .flatten()
+        .find(|pad| pad.location() == location)
+}
+
+/// Returns the erased pad at `location`, searching each group in order.
+#[allow(unused)] // Used in chip-specific modules...
+fn find_pad_mut<const N: usize>(
+    groups: [&mut [ErasedPad]; N],
+    location: PadLocation,
+) -> Option<&mut ErasedPad> {
+    groups
+        .into_iter()
+        .flatten()
+        .find(|pad| pad.location() == location)
+}
+
 /// A daisy selection
 ///
 /// A daisy chain specifies which pad will be used for a peripheral's
 /// input. Call `write()` to commit the settings described by a `Daisy`
 /// value.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub struct Daisy {
     reg: *mut u32,
     value: u32,
@@ -566,6 +1145,39 @@ impl Daisy {
         Daisy { reg, value }
     }
 
+    /// Create a select input for a pad that this package doesn't define
+    ///
+    /// Use this to implement pin traits for pads from [`define_pad!`].
+    ///
+    /// # Safety
+    ///
+    /// `reg` must be the address of the peripheral's select input register, and
+    /// `value` must select the pad.
+    pub const unsafe fn new_unchecked(reg: *mut u32, value: u32) -> Self {
+        Daisy { reg, value }
+    }
+
+    /// Returns the address of the select input register
+    pub const fn reg(self) -> *mut u32 {
+        self.reg
+    }
+
+    /// Returns the value that selects the pad
+    pub const fn value(self) -> u32 {
+        self.value
+    }
+
+    /// Returns `true` if the register holds this daisy's value
+    ///
+    /// # Safety
+    ///
+    /// This reads a global, processor register. The register address must be
+    /// valid for reads.
+    #[inline(always)]
+    pub unsafe fn is_selected(self) -> bool {
+        ptr::read_volatile(self.reg) == self.value
+    }
+
     /// Commit the settings defined by this `Daisy` value to the hardware
     ///
     /// # Safety
@@ -575,25 +1187,206 @@ impl Daisy {
     #[inline(always)]
     pub unsafe fn write(self) {
         ptr::write_volatile(self.reg, self.value);
+        #[cfg(any(feature = "log", feature = "defmt"))]
+        trace::write(self.reg, self.value);
     }
 }
 
+/// The USB_ANALOG DIGPROG register of the 1010 and 1060, which identifies the
+/// chip
+#[allow(unused)] // Used in chip modules...
+const DIGPROG: *const u32 = 0x400D_8260 as *const u32;
+
+/// In debug builds, panic unless the `digprog` register's upper half is
+/// `family`
+///
+/// The chip modules' `debug_check_chip()` functions call this function. The
+/// lower half is the silicon revision, which any build accepts.
+///
+/// # Safety
+///
+/// `digprog` must be readable.
+#[allow(unused)] // Used in chip modules...
+unsafe fn debug_check_chip(digprog: *const u32, chip: &str, family: u32) {
+    #[cfg(debug_assertions)]
+    {
+        let value = ptr::read_volatile(digprog);
+        assert!(
+            value >> 16 == family,
+            "this build is for the i.MX RT {chip}, but the chip's DIGPROG is {value:#010x}"
+        );
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = (digprog, chip, family);
+}
+
+/// In debug builds, check that a pin's daisy took effect, and that the daisy
+/// selects the pin.
+///
+/// The readback catches daisies with the wrong register. When the pad is one
+/// of an enabled chip's pads, the chip's register map catches daisies with the
+/// wrong value or the wrong pad.
+#[allow(unused)] // Used in peripheral modules...
+#[inline(always)]
+fn debug_check_daisy<P: Iomuxc>(pin: &mut P, daisy: Option<Daisy>) {
+    #[cfg(debug_assertions)]
+    if daisy.is_some() {
+        let location = PadLocation {
+            mux: pin.mux() as u32,
+            pad: pin.pad() as u32,
+        };
+        debug_check_daisy_at(location, daisy);
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = (pin, daisy);
+}
+
+/// In debug builds, check the daisy that a `prepare_with_daisy()` function
+/// wrote for `choice`
+///
+/// A [`DaisyChoice::Pin`] daisy has the same checks as [`debug_check_daisy()`].
+/// A [`DaisyChoice::Other`] daisy may select another pad, so the check only
+/// reads it back.
+#[allow(unused)] // Used in peripheral modules...
+#[inline(always)]
+fn debug_check_daisy_choice<P: Iomuxc>(pin: &mut P, daisy: Option<Daisy>, choice: DaisyChoice) {
+    match choice {
+        DaisyChoice::Pin => debug_check_daisy(pin, daisy),
+        DaisyChoice::Skip => {}
+        DaisyChoice::Other(daisy) => {
+            // Safety: see debug_check_daisy_at.
+            debug_assert!(
+                unsafe { daisy.is_selected() },
+                "the daisy register at {:p} did not take the value {}",
+                daisy.reg,
+                daisy.value
+            );
+        }
+    }
+}
+
+/// The non-generic implementation of [`debug_check_daisy()`], for the pad at
+/// `location`
+#[allow(unused)]
+fn debug_check_daisy_at(location: PadLocation, daisy: Option<Daisy>) {
+    #[cfg(debug_assertions)]
+    if let Some(daisy) = daisy {
+        // Safety: the pin's daisy addresses the chip's IOMUXC, and the read
+        // has no side effects.
+        assert!(
+            unsafe { daisy.is_selected() },
+            "the daisy register at {:p} did not take the value {}",
+            daisy.reg,
+            daisy.value
+        );
+        assert!(
+            daisy_matches(location, daisy),
+            "{daisy:?} does not select the pad at {location:?}"
+        );
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = (location, daisy);
+}
+
+/// Returns `false` if an enabled chip's register map has the pad, and the map
+/// doesn't list the daisy for the pad.
+#[allow(unused)]
+fn daisy_matches(location: PadLocation, daisy: Daisy) -> bool {
+    let maps: &[&[register_map::PadRegisters]] = &[
+        #[cfg(feature = "_imxrt1010")]
+        imxrt1010::REGISTER_MAP,
+        #[cfg(feature = "_imxrt1060")]
+        imxrt1060::REGISTER_MAP,
+        #[cfg(feature = "_imxrt1170")]
+        imxrt1170::REGISTER_MAP,
+    ];
+    let entry = register_map::DaisyEntry {
+        reg: daisy.reg as u32,
+        value: daisy.value,
+    };
+    maps.iter()
+        .flat_map(|map| map.iter())
+        .find(|pad| (pad.mux, pad.pad) == (location.mux, location.pad))
+        .is_none_or(|pad| pad.daisies.contains(&entry))
+}
+
+/// The daisy value that a `prepare_with_daisy()` function writes
+///
+/// The peripheral `prepare()` functions write the pin's daisy value, which
+/// routes the peripheral's input to the pin. Use a `prepare_with_daisy()`
+/// function when your board needs another routing, like
+///
+/// ```no_run
+/// use imxrt_iomuxc::{lpuart, DaisyChoice};
+/// use imxrt_iomuxc::imxrt1060::{daisy, gpio_ad_b1::GPIO_AD_B1_03};
+///
+/// let mut rx = unsafe { GPIO_AD_B1_03::new() };
+/// // Mux the pad, but route the LPUART2 RX input from GPIO_SD_B1_10.
+/// lpuart::prepare_with_daisy(
+///     &mut rx,
+///     DaisyChoice::Other(daisy::lpuart::DAISY_LPUART2_RX_GPIO_SD_B1_10),
+/// );
+/// // Or, leave the daisy register as it is.
+/// lpuart::prepare_with_daisy(&mut rx, DaisyChoice::Skip);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum DaisyChoice {
+    /// Write the pin's daisy value, like `prepare()`
+    Pin,
+    /// Don't write any daisy register
+    Skip,
+    /// Write this daisy value, instead of the pin's
+    Other(Daisy),
+}
+
+impl DaisyChoice {
+    /// Returns the daisy to write, given the pin's daisy.
+    #[allow(unused)] // Used in peripheral modules...
+    const fn resolve(self, pin: Option<Daisy>) -> Option<Daisy> {
+        match self {
+            DaisyChoice::Pin => pin,
+            DaisyChoice::Skip => None,
+            DaisyChoice::Other(daisy) => Some(daisy),
+        }
+    }
+}
+
+/// Returns `true` if a pin's daisy, if any, is selected.
+#[allow(unused)] // Used in peripheral modules...
+fn daisy_selected(daisy: Option<Daisy>) -> bool {
+    // Safety: pin daisies address the chip's IOMUXC, and the read has no side
+    // effects.
+    daisy.is_none_or(|daisy| unsafe { daisy.is_selected() })
+}
+
 /// GPIO pad configuration
 pub mod gpio {
     /// A GPIO pin
     ///
     /// The constant `N` is the associated GPIO module
     /// (a `3` for `GPIO3`).
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a GPIO{N} pin",
+        label = "this pad does not have a GPIO{N} alternate",
+        note = "the `gpio::Pin` implementors list the compatible pads",
+        note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+    )]
     pub trait Pin<const N: u8>: super::Iomuxc {
         /// The alternate value for this pad
         const ALT: u32;
-        /// The offset; `U13` for `GPIO5_IO13`
+        /// The offset; `13` for `GPIO5_IO13`
         const OFFSET: u32;
     }
 
+    impl<P: Pin<N>, const N: u8> Pin<N> for &mut P {
+        const ALT: u32 = P::ALT;
+        const OFFSET: u32 = P::OFFSET;
+    }
+
     /// Prepare a pad to be used as a GPIO pin
     pub fn prepare<P: Pin<N>, const N: u8>(pin: &mut P) {
-        super::alternate(pin, P::ALT);
+        super::alternate_raw(pin, P::ALT);
     }
 }
 
@@ -601,8 +1394,14 @@ pub mod gpio {
 pub mod ccm {
     /// A CCM pin.
     ///
-    /// These can be used for observing clock outputs, or for generating
-    /// outputs for your PMIC.
+    /// These can be used for observing clock outputs, generating outputs
+    /// for your PMIC, or routing reference clocks to external devices.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a CCM pin",
+        label = "this pad does not support any CCM function",
+        note = "the `ccm::Pin` implementors list the compatible pads",
+        note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+    )]
     pub trait Pin: super::Iomuxc {
         /// The alternate value for this pad.
         const ALT: u32;
@@ -610,9 +1409,14 @@ pub mod ccm {
         type Function: Function;
     }
 
+    impl<P: Pin> Pin for &mut P {
+        const ALT: u32 = P::ALT;
+        type Function = P::Function;
+    }
+
     /// Prepare a pad to be used as a CCM pin.
     pub fn prepare<P: Pin>(pin: &mut P) {
-        super::alternate(pin, P::ALT);
+        super::alternate_raw(pin, P::ALT);
     }
 
     mod private {
@@ -627,16 +1431,42 @@ pub mod ccm {
     impl private::Sealed for Observable<2> {}
     impl Function for Observable<1> {}
     impl Function for Observable<2> {}
+
+    /// 24MHz reference clock output, `REF_CLK_24M`.
+    ///
+    /// The ANATOP crystal oscillator drives the output. Use it to clock an
+    /// external PHY or radio.
+    pub enum RefClk24M {}
+    impl private::Sealed for RefClk24M {}
+    impl Function for RefClk24M {}
+
+    /// 32KHz reference clock output, `REF_CLK_32K`.
+    pub enum RefClk32K {}
+    impl private::Sealed for RefClk32K {}
+    impl Function for RefClk32K {}
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[derive(Debug)]
-    struct TestBase;
+    #[cfg(debug_assertions)]
+    #[test]
+    fn check_chip() {
+        let digprog = 0x006C_0001;
+        unsafe { debug_check_chip(&digprog, "1060", 0x006C) };
+    }
 
-    type TestPad = Pad<0, 0>;
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "this build is for the i.MX RT 1060")]
+    fn check_wrong_chip() {
+        // An i.MX RT 1050
+        let digprog = 0x006A_0001;
+        unsafe { debug_check_chip(&digprog, "1060", 0x006C) };
+    }
+
+    type TestPad = Pad<0xDEAD, 0xBEEF>;
 
     #[test]
     fn erased_pad_convert_success() {
@@ -656,6 +1486,97 @@ mod tests {
         type OtherPad = Pad<1, 1>;
         OtherPad::try_from(erased).expect_err("This is a different pad");
     }
+
+    #[test]
+    fn erased_pad_with_config() {
+        let mut registers = [0u32; 2];
+        let [mux, pad] = &mut registers;
+        let _pad = unsafe { ErasedPad::new(mux, pad) }
+            .with_config(Config::zero().set_speed(Speed::Max))
+            .with_config(Config::modify().set_slew_rate(SlewRate::Fast));
+        assert_eq!(registers, [0, Speed::Max as u32 | SlewRate::Fast as u32]);
+    }
+
+    #[test]
+    fn option_erased_pad_size() {
+        use core::mem::size_of;
+        assert_eq!(size_of::<Option<ErasedPad>>(), size_of::<ErasedPad>());
+    }
+
+    #[test]
+    fn prepare_mux_alt_and_sion() {
+        let mut mux: u32 = 0xFF00_0000 | SION_BIT | 0b101;
+        unsafe { prepare_mux(&mut mux, 0b010, false, None) };
+        assert_eq!(mux, 0xFF00_0000 | 0b010);
+        unsafe { prepare_mux(&mut mux, 0b011, true, None) };
+        assert_eq!(mux, 0xFF00_0000 | SION_BIT | 0b011);
+    }
+
+    #[test]
+    fn alternate_and_sion_keep_other_bits() {
+        let mut pad = crate::NoPin::<1>::new();
+        unsafe { pad.mux().write(0xFF00_0000) };
+        alternate_raw(&mut pad, 0b1_0111);
+        assert_eq!(unsafe { pad.mux().read() }, 0xFF00_0000 | 0b0111);
+        set_sion(&mut pad);
+        assert_eq!(unsafe { pad.mux().read() }, 0xFF00_0000 | SION_BIT | 0b0111);
+        clear_sion(&mut pad);
+        assert_eq!(unsafe { pad.mux().read() }, 0xFF00_0000 | 0b0111);
+    }
+
+    #[test]
+    fn replace_alternate_returns_previous() {
+        let mut pad = crate::NoPin::<1>::new();
+        unsafe { pad.mux().write(0xFF00_0000 | SION_BIT | 0b0101) };
+        assert_eq!(replace_alternate(&mut pad, Alternate::<0b0010>), 0b0101);
+        assert_eq!(replace_alternate(&mut pad, Alternate::<0b0101>), 0b0010);
+        assert_eq!(unsafe { pad.mux().read() }, 0xFF00_0000 | SION_BIT | 0b0101);
+    }
+
+    #[test]
+    fn prepare_with_daisy() {
+        let mut reg = 0u32;
+        let mut pin = crate::NoPin::<1>::new();
+        crate::lpspi::prepare_with_daisy(&mut pin, DaisyChoice::Pin);
+        assert_eq!(reg, 0);
+        let other = Daisy::new(&mut reg, 3);
+        crate::lpspi::prepare_with_daisy(&mut pin, DaisyChoice::Other(other));
+        assert_eq!(reg, 3);
+        assert_eq!(DaisyChoice::Skip.resolve(Some(other)), None);
+        assert_eq!(DaisyChoice::Pin.resolve(Some(other)), Some(other));
+    }
+
+    #[test]
+    fn erased_pad_ordering() {
+        let low = unsafe { Pad::<0x100, 0x200>::new() }.erase();
+        let high = unsafe { Pad::<0x104, 0x1FC>::new() }.erase();
+        assert!(low < high);
+        assert_eq!(low, unsafe { Pad::<0x100, 0x200>::new() }.erase());
+        assert_ne!(low, high);
+        assert!(low.location() < high.location());
+        assert_eq!(high.location().id(), 0x104);
+    }
+
+    #[test]
+    fn daisy_selection() {
+        let mut reg = 0u32;
+        let daisy = Daisy::new(&mut reg, 2);
+        assert!(!daisy_selected(Some(daisy)));
+        unsafe { daisy.write() };
+        assert!(daisy_selected(Some(daisy)));
+        assert!(daisy_selected(None));
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn daisy_matches_pad() {
+        use crate::imxrt1060::{daisy::lpuart::*, gpio_ad_b1::GPIO_AD_B1_03};
+        let location = GPIO_AD_B1_03::LOCATION;
+        assert!(daisy_matches(location, DAISY_LPUART2_RX_GPIO_AD_B1_03));
+        assert!(!daisy_matches(location, DAISY_LPUART2_RX_GPIO_SD_B1_10));
+        let unknown = PadLocation { mux: 4, pad: 8 };
+        assert!(daisy_matches(unknown, DAISY_LPUART2_RX_GPIO_SD_B1_10));
+    }
 }
 
 /// ```
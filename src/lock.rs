@@ -0,0 +1,222 @@
+//! Locked pads.
+//!
+//! Lock the pads that must never change, like the FlexSPI pads that the
+//! firmware executes from, or the debug pins. In debug builds, the functions
+//! below panic if they would write a locked pad's register:
+//!
+//! - [`configure()`], [`configure_if_changed()`], [`alternate()`],
+//!   [`set_sion()`], [`clear_sion()`], and every peripheral `prepare()` function
+//! - [`PreparePlan::apply()`]
+//! - dropping a [`Lease`], and dropping or exiting a [`Sleep`] guard
+//! - [`register_map::restore()`] and [`register_map::reset()`]
+//! - the `remote` module's `Message::apply()` and `dispatch()`
+//!
+//! Release builds skip the check. [`PadTable::apply()`] is never checked; it
+//! may run before static initialization, when the registry is not ready.
+//!
+//! A lock lasts until reset. The package holds up to [`CAPACITY`] locks.
+//!
+//! The check covers a locked pad's MUX and PAD registers, but not the daisy
+//! registers. A daisy register selects one of several pads for an input, so
+//! preparing another pad for the same input still writes the daisy, and
+//! reroutes the input away from the locked pad. To keep a locked pad's input,
+//! don't prepare the other pads that can feed it.
+//!
+//! ```
+//! use imxrt_iomuxc::lock;
+//! # type GPIO_SD_B1_06 = imxrt_iomuxc::Pad<0x1000, 0x2000>;
+//!
+//! lock::lock(GPIO_SD_B1_06::LOCATION).unwrap();
+//! assert!(lock::is_locked(GPIO_SD_B1_06::LOCATION));
+//!
+//! // In a debug build, preparing or configuring GPIO_SD_B1_06 now panics,
+//! // before touching the pad.
+//! ```
+//!
+//! [`configure()`]: crate::configure()
+//! [`alternate()`]: crate::alternate()
+//! [`set_sion()`]: crate::set_sion()
+//! [`clear_sion()`]: crate::clear_sion()
+//! [`configure_if_changed()`]: crate::configure_if_changed()
+//! [`PreparePlan::apply()`]: crate::PreparePlan::apply()
+//! [`Lease`]: crate::Lease
+//! [`Sleep`]: crate::sleep::Sleep
+//! [`register_map::restore()`]: crate::register_map::restore()
+//! [`register_map::reset()`]: crate::register_map::reset()
+//! [`PadTable::apply()`]: crate::PadTable::apply()
+
+use crate::PadLocation;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// The number of pads that may be locked
+pub const CAPACITY: usize = 16;
+
+/// An unused lock slot
+const FREE: u32 = 0;
+
+/// Marks a MUX entry whose PAD address is not yet published
+///
+/// MUX registers are word aligned, so a MUX address never has this bit.
+const CLAIMING: u32 = 1;
+
+/// MUX and PAD addresses of the locked pads
+///
+/// A single compare-exchange of a slot's MUX entry, from `FREE` to the MUX
+/// address with `CLAIMING`, claims the slot. The claimer then stores the PAD
+/// address, and finally the plain MUX address, which publishes the lock. Slots
+/// are never released.
+static MUXES: [AtomicU32; CAPACITY] = [const { AtomicU32::new(FREE) }; CAPACITY];
+static PADS: [AtomicU32; CAPACITY] = [const { AtomicU32::new(FREE) }; CAPACITY];
+
+/// An error from [`lock()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The package already holds [`CAPACITY`] locks
+    Full,
+    /// The location's MUX address is zero, or isn't word aligned
+    InvalidLocation,
+}
+
+/// Lock a pad
+///
+/// Locking a pad that's already locked has no effect. Fails if the registry
+/// is full, or if the location can't be a pad's.
+pub fn lock(location: PadLocation) -> Result<(), Error> {
+    if location.mux == FREE || location.mux % 4 != 0 {
+        return Err(Error::InvalidLocation);
+    }
+    // Every caller claims the first free slot, in order, so two callers that
+    // lock the same pad meet at the same slot.
+    for (mux, pad) in MUXES.iter().zip(&PADS) {
+        match mux.compare_exchange(
+            FREE,
+            location.mux | CLAIMING,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                pad.store(location.pad, Ordering::Release);
+                mux.store(location.mux, Ordering::Release);
+                return Ok(());
+            }
+            Err(held) if held & !CLAIMING == location.mux => return Ok(()),
+            Err(_) => {}
+        }
+    }
+    Err(Error::Full)
+}
+
+/// Returns `true` if the pad is locked
+pub fn is_locked(location: PadLocation) -> bool {
+    location.mux != FREE
+        && MUXES
+            .iter()
+            .any(|mux| mux.load(Ordering::Acquire) == location.mux)
+}
+
+/// Returns `true` if `registers` has the address of a locked pad register
+#[cfg(debug_assertions)]
+fn holds(registers: &[AtomicU32; CAPACITY], address: u32) -> bool {
+    address != FREE
+        && registers
+            .iter()
+            .any(|reg| reg.load(Ordering::Acquire) == address)
+}
+
+/// In debug builds, panic if `mux` is a locked pad's MUX register
+#[inline(always)]
+pub(crate) fn check_mux(mux: *mut u32) {
+    #[cfg(debug_assertions)]
+    assert!(
+        !holds(&MUXES, mux as u32),
+        "the pad with MUX register {mux:p} is locked"
+    );
+    #[cfg(not(debug_assertions))]
+    let _ = mux;
+}
+
+/// In debug builds, panic if `pad` is a locked pad's PAD register
+#[inline(always)]
+pub(crate) fn check_pad(pad: *mut u32) {
+    #[cfg(debug_assertions)]
+    assert!(
+        !holds(&PADS, pad as u32),
+        "the pad with PAD register {pad:p} is locked"
+    );
+    #[cfg(not(debug_assertions))]
+    let _ = pad;
+}
+
+/// Release a pad's lock, so that tests may reuse its addresses
+#[cfg(test)]
+pub(crate) fn unlock(location: PadLocation) {
+    for (mux, pad) in MUXES.iter().zip(&PADS) {
+        if mux.load(Ordering::Acquire) == location.mux {
+            mux.store(FREE, Ordering::Release);
+            pad.store(FREE, Ordering::Release);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_locked, lock, unlock, Error};
+    use crate::PadLocation;
+
+    /// Unlocks the pad when dropped, even when the test panics
+    struct Unlock(PadLocation);
+
+    impl Drop for Unlock {
+        fn drop(&mut self) {
+            unlock(self.0);
+        }
+    }
+
+    #[test]
+    fn lock_is_idempotent() {
+        let location = PadLocation {
+            mux: 0x4000,
+            pad: 0x5000,
+        };
+        assert!(!is_locked(location));
+        let _unlock = Unlock(location);
+        lock(location).unwrap();
+        lock(location).unwrap();
+        assert!(is_locked(location));
+    }
+
+    #[test]
+    fn unlock_frees_slot() {
+        let location = PadLocation {
+            mux: 0x4100,
+            pad: 0x5100,
+        };
+        lock(location).unwrap();
+        unlock(location);
+        assert!(!is_locked(location));
+    }
+
+    #[test]
+    fn reject_invalid_locations() {
+        for mux in [0, 0x4202] {
+            let location = PadLocation { mux, pad: 0x5200 };
+            assert_eq!(lock(location), Err(Error::InvalidLocation));
+            assert!(!is_locked(location));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "is locked")]
+    fn configure_locked_pad() {
+        use crate::{configure, Config, ErasedPad, SlewRate};
+
+        let mut registers = [0u32; 2];
+        let [mux, pad] = &mut registers;
+        let mut pad = unsafe { ErasedPad::new(mux, pad) };
+        // The stack addresses may belong to another test's pad later.
+        let _unlock = Unlock(pad.location());
+        lock(pad.location()).unwrap();
+        configure(&mut pad, Config::modify().set_slew_rate(SlewRate::Fast));
+    }
+}
@@ -0,0 +1,91 @@
+//! LPADC pad configuration
+//!
+//! The i.MX RT 11xx parts convert analog inputs with LPADC, not the 10xx
+//! [`adc`](crate::adc). An LPADC channel has up to two inputs, side A and
+//! side B, like `ADC1_CH3A` and `ADC1_CH3B`. A conversion command selects the
+//! channel number and the side.
+//!
+//! ```
+//! use imxrt_iomuxc::{define_pad, lpadc};
+//!
+//! define_pad! {
+//!     /// A pad that connects to ADC1_CH3B.
+//!     pub struct ANALOG { mux: 0x400E_8010, pad: 0x400E_8254 }
+//! }
+//!
+//! impl lpadc::Pin<1> for ANALOG {
+//!     const CHANNEL: u8 = 3;
+//!     type Side = lpadc::B;
+//! }
+//!
+//! fn command<P: lpadc::Pin<1>>(_: &P) -> (u8, bool) {
+//!     (P::CHANNEL, P::SIDE_B)
+//! }
+//!
+//! let pad = unsafe { ANALOG::new() };
+//! assert_eq!(command(&pad), (3, true));
+//! ```
+
+/// The `ADC1` instance number
+pub const LPADC1: u8 = 1;
+/// The `ADC2` instance number
+pub const LPADC2: u8 = 2;
+
+/// An LPADC channel side; one of `A` or `B`
+pub trait Side: private::Sealed {
+    /// `true` for side B
+    const B: bool;
+}
+/// Side A of a channel, like `ADC1_CH3A`
+pub enum A {}
+/// Side B of a channel, like `ADC1_CH3B`
+pub enum B {}
+
+impl Side for A {
+    const B: bool = false;
+}
+impl Side for B {
+    const B: bool = true;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::A {}
+    impl Sealed for super::B {}
+}
+
+/// An LPADC input pin
+///
+/// Some pads connect to both LPADCs, so implementations indicate their
+/// compatibility by supplying a constant `N`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an input for LPADC{N}",
+    label = "this pad is not connected to LPADC{N}",
+    note = "the `lpadc::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin<const N: u8>: super::Iomuxc {
+    /// The channel number; `3` for `ADC1_CH3A`
+    const CHANNEL: u8;
+    /// The channel side; `A` for `ADC1_CH3A`
+    type Side: Side;
+    /// `true` if the pad is side B of its channel
+    ///
+    /// Use this to set a conversion command's channel side.
+    const SIDE_B: bool = <Self::Side as Side>::B;
+}
+
+impl<P: Pin<N>, const N: u8> Pin<N> for &mut P {
+    const CHANNEL: u8 = P::CHANNEL;
+    type Side = P::Side;
+}
+
+#[allow(unused)] // Used in chip-specific modules...
+macro_rules! lpadc {
+    (module: $module:expr, pad: $pad:ty, channel: $channel:expr, side: $side:ty) => {
+        impl Pin<$module> for $pad {
+            const CHANNEL: u8 = $channel;
+            type Side = $side;
+        }
+    };
+}
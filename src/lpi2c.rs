@@ -18,6 +18,12 @@ mod private {
 }
 
 /// An I2C pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an LPI2C pin",
+    label = "this pad does not support any LPI2C signal",
+    note = "the `lpi2c::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin: super::Iomuxc {
     /// Alternate value for this pin
     const ALT: u32;
@@ -25,8 +31,19 @@ pub trait Pin: super::Iomuxc {
     const DAISY: super::Daisy;
     /// I2C Signal
     type Signal: Signal;
-    /// I2C module; `U2` for `I2C2`
+    /// I2C module; `Const<2>` for `I2C2`
     type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: super::Daisy = P::DAISY;
+    type Signal = P::Signal;
+    type Module = P::Module;
 }
 
 /// Prepare an I2C pin
@@ -34,19 +51,96 @@ pub trait Pin: super::Iomuxc {
 /// If you do not call `prepare()` on your I2C pin, it might not work as a I2C
 /// pin.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    unsafe { P::DAISY.write() };
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, Some(P::DAISY)) };
+    super::debug_check_daisy(pin, Some(P::DAISY));
+}
+
+/// Prepare a I2C pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, daisy.resolve(Some(P::DAISY))) };
+    super::debug_check_daisy_choice(pin, Some(P::DAISY), daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the I2C input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(Some(P::DAISY))
+}
+
+/// Prepare a I2C pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// A pair of I2C pins from the same module
+///
+/// ```no_run
+/// use imxrt_iomuxc::lpi2c::Pins;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b1::{GPIO_AD_B1_00, GPIO_AD_B1_01};
+///
+/// let mut pins = Pins::new(
+///     unsafe { GPIO_AD_B1_00::new() },
+///     unsafe { GPIO_AD_B1_01::new() },
+/// );
+/// pins.prepare();
+/// ```
+#[derive(Debug)]
+pub struct Pins<C, D> {
+    /// Clock pin
+    pub scl: C,
+    /// Data pin
+    pub sda: D,
+}
+
+impl<C, D> Pins<C, D>
+where
+    C: Pin<Signal = Scl>,
+    D: Pin<Signal = Sda, Module = C::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = C::MODULE;
+
+    /// Group the I2C pins
+    pub const fn new(scl: C, sda: D) -> Self {
+        Self { scl, sda }
+    }
+
+    /// Prepare all of the I2C pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.scl);
+        prepare(&mut self.sda);
+    }
+}
+
+impl<C, D> From<(C, D)> for Pins<C, D>
+where
+    C: Pin<Signal = Scl>,
+    D: Pin<Signal = Sda, Module = C::Module>,
+{
+    fn from((scl, sda): (C, D)) -> Self {
+        Self::new(scl, sda)
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! i2c {
-    (module: $module:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
         impl Pin for $pad {
             const ALT: u32 = $alt;
             const DAISY: Daisy = $daisy;
             type Signal = $signal;
-            type Module = $module;
+            type Module = crate::consts::Const<$module>;
         }
     };
 }
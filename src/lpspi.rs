@@ -26,15 +26,32 @@ mod private {
 }
 
 /// A SPI pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an LPSPI pin",
+    label = "this pad does not support any LPSPI signal",
+    note = "the `lpspi::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin: super::Iomuxc {
     /// Alternate value for this pin
     const ALT: u32;
     /// Daisy register
-    const DAISY: super::Daisy;
+    const DAISY: Option<super::Daisy>;
     /// SPI signal
     type Signal: Signal;
-    /// SPI module; `U3` for `SPI3`
+    /// SPI module; `Const<3>` for `SPI3`
     type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Signal = P::Signal;
+    type Module = P::Module;
 }
 
 /// Prepare a SPI pin
@@ -46,19 +63,121 @@ pub trait Pin: super::Iomuxc {
 ///
 /// `prepare()` inherits all the unsafety that comes from the `IOMUX` supertrait.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    unsafe { P::DAISY.write() };
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a SPI pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, daisy.resolve(P::DAISY)) };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the SPI input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a SPI pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// A set of SPI pins from the same module
+///
+/// If a driver controls its own chip select, use [`NoPin`](crate::NoPin) for `pcs0`.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{lpspi::Pins, NoPin};
+/// # use imxrt_iomuxc::imxrt1060::gpio_b0::{GPIO_B0_01, GPIO_B0_02, GPIO_B0_03};
+///
+/// let mut pins = Pins::new(
+///     unsafe { GPIO_B0_02::new() },
+///     unsafe { GPIO_B0_01::new() },
+///     unsafe { GPIO_B0_03::new() },
+///     NoPin::new(),
+/// );
+/// pins.prepare();
+/// ```
+#[derive(Debug)]
+pub struct Pins<O, I, C, P> {
+    /// Data out pin
+    pub sdo: O,
+    /// Data in pin
+    pub sdi: I,
+    /// Clock pin
+    pub sck: C,
+    /// Chip select pin
+    pub pcs0: P,
+}
+
+impl<O, I, C, P> Pins<O, I, C, P>
+where
+    O: Pin<Signal = Sdo>,
+    I: Pin<Signal = Sdi, Module = O::Module>,
+    C: Pin<Signal = Sck, Module = O::Module>,
+    P: Pin<Signal = Pcs0, Module = O::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = O::MODULE;
+
+    /// Group the SPI pins
+    pub const fn new(sdo: O, sdi: I, sck: C, pcs0: P) -> Self {
+        Self {
+            sdo,
+            sdi,
+            sck,
+            pcs0,
+        }
+    }
+
+    /// Prepare all of the SPI pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.sdo);
+        prepare(&mut self.sdi);
+        prepare(&mut self.sck);
+        prepare(&mut self.pcs0);
+    }
+}
+
+impl<O, I, C, P> From<(O, I, C, P)> for Pins<O, I, C, P>
+where
+    O: Pin<Signal = Sdo>,
+    I: Pin<Signal = Sdi, Module = O::Module>,
+    C: Pin<Signal = Sck, Module = O::Module>,
+    P: Pin<Signal = Pcs0, Module = O::Module>,
+{
+    fn from((sdo, sdi, sck, pcs0): (O, I, C, P)) -> Self {
+        Self::new(sdo, sdi, sck, pcs0)
+    }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! spi {
-    (module: $module:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: None) => {
+        spi!(@impl $module, $alt, $pad, $signal, None);
+    };
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        spi!(@impl $module, $alt, $pad, $signal, Some($daisy));
+    };
+    (@impl $module:literal, $alt:expr, $pad:ty, $signal:ty, $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
         impl Pin for $pad {
             const ALT: u32 = $alt;
-            const DAISY: Daisy = $daisy;
+            const DAISY: Option<Daisy> = $daisy;
             type Signal = $signal;
-            type Module = $module;
+            type Module = crate::consts::Const<$module>;
         }
     };
 }
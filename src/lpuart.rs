@@ -4,20 +4,33 @@
 pub enum Tx {}
 /// Type tag for the receive pin
 pub enum Rx {}
+/// Type tag for the request-to-send pin
+///
+/// An RS-485 transceiver uses the RTS pin as its driver enable. See
+/// [`prepare_de()`].
+pub enum Rts {}
 
-/// A pin direction, either transfer or receive
+/// A pin direction: transfer, receive, or request-to-send
 pub trait Direction: private::Sealed {}
 
 impl Direction for Tx {}
 impl Direction for Rx {}
+impl Direction for Rts {}
 
 mod private {
     pub trait Sealed {}
     impl Sealed for super::Tx {}
     impl Sealed for super::Rx {}
+    impl Sealed for super::Rts {}
 }
 
 /// A UART pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an LPUART pin",
+    label = "this pad does not support any LPUART signal",
+    note = "the `lpuart::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin: super::Iomuxc {
     /// The alternate value for the UART pin
     const ALT: u32;
@@ -25,8 +38,19 @@ pub trait Pin: super::Iomuxc {
     const DAISY: Option<super::Daisy>;
     /// Pin direction
     type Direction: Direction;
-    /// UART module; `U3` for `UART3`
+    /// UART module; `Const<3>` for `UART3`
     type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Direction = P::Direction;
+    type Module = P::Module;
 }
 
 /// Prepare a UART pin
@@ -40,21 +64,188 @@ pub trait Pin: super::Iomuxc {
 /// In particular, we cannot be sure that the implementation's pointers are correct.
 /// It may also write a daisy configuration that's incorrect.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::clear_sion(pin);
-    if let Some(daisy) = P::DAISY {
-        unsafe { daisy.write() };
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, false, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a UART pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, false, daisy.resolve(P::DAISY)) };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the UART input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a UART RTS pin as an RS-485 driver enable
+///
+/// Like [`prepare()`], but only accepts RTS pins. Configure the LPUART to
+/// assert RTS while it transmits (`MODIR[TXRTSE]`), and the pin enables the
+/// transceiver's driver.
+pub fn prepare_de<P: Pin<Direction = Rts>>(pin: &mut P) {
+    prepare(pin);
+}
+
+/// Prepare a UART TX pin for single-wire, half-duplex transfers
+///
+/// Like [`prepare()`], but only accepts TX pins, and also sets the pad's SION
+/// bit. SION feeds the pad's level back to the LPUART, so the receiver listens
+/// on the TX pin. Configure the LPUART for single-wire operation
+/// (`CTRL[LOOPS]` and `CTRL[RSRC]`), and switch `CTRL[TXDIR]` to change
+/// direction.
+///
+/// ```no_run
+/// use imxrt_iomuxc::lpuart;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_12;
+///
+/// let mut tx = unsafe { GPIO_AD_B0_12::new() };
+/// lpuart::prepare_half_duplex(&mut tx);
+/// ```
+pub fn prepare_half_duplex<P: Pin<Direction = Tx>>(pin: &mut P) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a UART pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Direction, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// A pair of UART pins from the same module
+///
+/// ```no_run
+/// use imxrt_iomuxc::lpuart::Pins;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// let mut pins = Pins::new(
+///     unsafe { GPIO_AD_B0_12::new() },
+///     unsafe { GPIO_AD_B0_13::new() },
+/// );
+/// pins.prepare();
+/// assert_eq!(Pins::<GPIO_AD_B0_12, GPIO_AD_B0_13>::MODULE, 1);
+/// ```
+///
+/// The pins must be from the same module.
+///
+/// ```compile_fail
+/// use imxrt_iomuxc::lpuart::Pins;
+/// # use imxrt_iomuxc::imxrt1060::{gpio_ad_b0::GPIO_AD_B0_13, gpio_ad_b1::GPIO_AD_B1_02};
+///
+/// // GPIO_AD_B1_02 is a UART2 TX pin, but GPIO_AD_B0_13 is a UART1 RX pin
+/// let pins = Pins::new(
+///     unsafe { GPIO_AD_B1_02::new() },
+///     unsafe { GPIO_AD_B0_13::new() },
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Pins<T, R> {
+    /// Transfer pin
+    pub tx: T,
+    /// Receive pin
+    pub rx: R,
+}
+
+impl<T, R> Pins<T, R>
+where
+    T: Pin<Direction = Tx>,
+    R: Pin<Direction = Rx, Module = T::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = T::MODULE;
+
+    /// Group the UART pins
+    pub const fn new(tx: T, rx: R) -> Self {
+        Self { tx, rx }
+    }
+
+    /// Prepare all of the UART pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.tx);
+        prepare(&mut self.rx);
+    }
+}
+
+impl<T, R> From<(T, R)> for Pins<T, R>
+where
+    T: Pin<Direction = Tx>,
+    R: Pin<Direction = Rx, Module = T::Module>,
+{
+    fn from((tx, rx): (T, R)) -> Self {
+        Self::new(tx, rx)
+    }
+}
+
+/// UART pins for an RS-485 transceiver
+///
+/// The `de` pin is the module's RTS pin, and it drives the transceiver's
+/// driver enable.
+///
+/// ```no_run
+/// use imxrt_iomuxc::lpuart::Rs485Pins;
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13, GPIO_AD_B0_15};
+///
+/// let mut pins = Rs485Pins::new(
+///     unsafe { GPIO_AD_B0_12::new() },
+///     unsafe { GPIO_AD_B0_13::new() },
+///     unsafe { GPIO_AD_B0_15::new() },
+/// );
+/// pins.prepare();
+/// ```
+#[derive(Debug)]
+pub struct Rs485Pins<T, R, D> {
+    /// Transfer pin
+    pub tx: T,
+    /// Receive pin
+    pub rx: R,
+    /// Driver enable pin
+    pub de: D,
+}
+
+impl<T, R, D> Rs485Pins<T, R, D>
+where
+    T: Pin<Direction = Tx>,
+    R: Pin<Direction = Rx, Module = T::Module>,
+    D: Pin<Direction = Rts, Module = T::Module>,
+{
+    /// The pins' module number
+    pub const MODULE: u8 = T::MODULE;
+
+    /// Group the RS-485 pins
+    pub const fn new(tx: T, rx: R, de: D) -> Self {
+        Self { tx, rx, de }
+    }
+
+    /// Prepare all of the RS-485 pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.tx);
+        prepare(&mut self.rx);
+        prepare_de(&mut self.de);
     }
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! uart {
-    (module: $module:ty, alt: $alt:expr, pad: $pad:ty, direction: $direction:ty, daisy: $daisy:expr) => {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, direction: $direction:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
         impl Pin for $pad {
             const ALT: u32 = $alt;
             const DAISY: Option<Daisy> = $daisy;
             type Direction = $direction;
-            type Module = $module;
+            type Module = crate::consts::Const<$module>;
         }
     };
 }
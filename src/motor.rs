@@ -0,0 +1,259 @@
+//! Motor-control pin bundles
+//!
+//! A three-phase motor drive uses three complementary FlexPWM pairs, the
+//! encoder's phase inputs, a fault input, and a pad that triggers the ADC.
+//! [`Pins`] groups those pads, checks at compile time that the pairs use
+//! three submodules of one FlexPWM, and prepares all the pads together.
+//!
+//! The package doesn't have pin traits for the encoder, fault, and ADC trigger
+//! signals. On most parts, those signals reach the pads through the XBAR, so
+//! they don't belong to one peripheral. Wrap their pads in an [`AltPad`],
+//! which checks the pad's alternate with [`AnyAltPin`].
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{consts::Const, define_pad, flexpwm, AnyAltPin};
+//! use imxrt_iomuxc::motor::{AltPad, Pair, Pins};
+//!
+//! macro_rules! pwm_pad {
+//!     ($name:ident, $mux:literal, $submodule:literal, $output:ty) => {
+//!         define_pad! { pub struct $name { mux: $mux, pad: $mux + 0x1F0 } }
+//!         impl flexpwm::Pin for $name {
+//!             const ALT: u32 = 1;
+//!             type Output = $output;
+//!             type Module = Const<1>;
+//!             type Submodule = Const<$submodule>;
+//!         }
+//!     };
+//! }
+//! pwm_pad!(UA, 0x1000, 0, flexpwm::A);
+//! pwm_pad!(UB, 0x1004, 0, flexpwm::B);
+//! pwm_pad!(VA, 0x1008, 1, flexpwm::A);
+//! pwm_pad!(VB, 0x100C, 1, flexpwm::B);
+//! pwm_pad!(WA, 0x1010, 2, flexpwm::A);
+//! pwm_pad!(WB, 0x1014, 2, flexpwm::B);
+//!
+//! macro_rules! xbar_pad {
+//!     ($name:ident, $mux:literal) => {
+//!         define_pad! { pub struct $name { mux: $mux, pad: $mux + 0x1F0 } }
+//!         impl AnyAltPin<3> for $name {}
+//!     };
+//! }
+//! xbar_pad!(PHASE_A, 0x1018);
+//! xbar_pad!(PHASE_B, 0x101C);
+//! xbar_pad!(FAULT, 0x1020);
+//! xbar_pad!(TRIGGER, 0x1024);
+//!
+//! let mut pins = unsafe {
+//!     Pins::new(
+//!         Pair::new(UA::new(), UB::new()),
+//!         Pair::new(VA::new(), VB::new()),
+//!         Pair::new(WA::new(), WB::new()),
+//!         AltPad::<_, 3>::new(PHASE_A::new()),
+//!         AltPad::<_, 3>::new(PHASE_B::new()),
+//!         AltPad::<_, 3>::new(FAULT::new()),
+//!         AltPad::<_, 3>::new(TRIGGER::new()),
+//!     )
+//! };
+//! pins.prepare();
+//! ```
+//!
+//! The phases can't share a submodule:
+//!
+//! ```compile_fail
+//! # use imxrt_iomuxc::{consts::Const, define_pad, flexpwm};
+//! # use imxrt_iomuxc::motor::{Pair, Pins};
+//! # macro_rules! pwm_pad {
+//! #     ($name:ident, $mux:literal, $submodule:literal, $output:ty) => {
+//! #         define_pad! { pub struct $name { mux: $mux, pad: $mux + 0x1F0 } }
+//! #         impl flexpwm::Pin for $name {
+//! #             const ALT: u32 = 1;
+//! #             type Output = $output;
+//! #             type Module = Const<1>;
+//! #             type Submodule = Const<$submodule>;
+//! #         }
+//! #     };
+//! # }
+//! # pwm_pad!(UA, 0x1000, 0, flexpwm::A);
+//! # pwm_pad!(UB, 0x1004, 0, flexpwm::B);
+//! # pwm_pad!(VA, 0x1008, 0, flexpwm::A);
+//! # pwm_pad!(VB, 0x100C, 0, flexpwm::B);
+//! # pwm_pad!(WA, 0x1010, 2, flexpwm::A);
+//! # pwm_pad!(WB, 0x1014, 2, flexpwm::B);
+//! // U and V both use PWM1_SM0.
+//! let pins = unsafe {
+//!     Pins::new(
+//!         Pair::new(UA::new(), UB::new()),
+//!         Pair::new(VA::new(), VB::new()),
+//!         Pair::new(WA::new(), WB::new()),
+//!         (), (), (), (),
+//!     )
+//! };
+//! ```
+
+use crate::{
+    flexpwm::{self, A, B},
+    prepare_alternate, AnyAltPin,
+};
+
+/// A complementary FlexPWM pair
+///
+/// The A and B outputs must be from the same submodule.
+#[derive(Debug)]
+pub struct Pair<PA, PB> {
+    /// The high-side output
+    pub a: PA,
+    /// The low-side output
+    pub b: PB,
+}
+
+impl<PA, PB> Pair<PA, PB>
+where
+    PA: flexpwm::Pin<Output = A>,
+    PB: flexpwm::Pin<Output = B, Module = PA::Module, Submodule = PA::Submodule>,
+{
+    /// The pair's module number
+    pub const MODULE: u8 = PA::MODULE;
+    /// The pair's submodule number
+    pub const SUBMODULE: u8 = PA::SUBMODULE;
+
+    /// Group the PWM outputs
+    pub const fn new(a: PA, b: PB) -> Self {
+        Self { a, b }
+    }
+
+    /// Prepare both outputs
+    pub fn prepare(&mut self) {
+        flexpwm::prepare(&mut self.a);
+        flexpwm::prepare(&mut self.b);
+    }
+}
+
+/// A pad for a signal without a pin trait, and its alternate
+#[derive(Debug)]
+pub struct AltPad<P, const ALT: u8> {
+    /// The pad
+    pub pad: P,
+}
+
+impl<P: AnyAltPin<ALT>, const ALT: u8> AltPad<P, ALT> {
+    /// Select the pad's signal at alternate `ALT`
+    pub const fn new(pad: P) -> Self {
+        Self { pad }
+    }
+}
+
+/// A motor-control pad that [`Pins`] prepares
+///
+/// Implemented by [`AltPad`], and by `()` for a signal that the design
+/// doesn't route.
+pub trait Aux: private::Sealed {
+    /// Prepare the pad
+    fn prepare(&mut self);
+}
+
+impl<P: AnyAltPin<ALT>, const ALT: u8> Aux for AltPad<P, ALT> {
+    fn prepare(&mut self) {
+        prepare_alternate::<ALT>(&mut self.pad);
+    }
+}
+
+impl Aux for () {
+    fn prepare(&mut self) {}
+}
+
+mod private {
+    pub trait Sealed {}
+    impl<P, const ALT: u8> Sealed for super::AltPad<P, ALT> {}
+    impl Sealed for () {}
+}
+
+/// The pins of a three-phase motor drive
+///
+/// The U, V, and W pairs must use three different submodules of the same
+/// FlexPWM.
+#[derive(Debug)]
+pub struct Pins<UA, UB, VA, VB, WA, WB, ENCA, ENCB, FAULT, TRIG> {
+    /// Phase U outputs
+    pub u: Pair<UA, UB>,
+    /// Phase V outputs
+    pub v: Pair<VA, VB>,
+    /// Phase W outputs
+    pub w: Pair<WA, WB>,
+    /// Encoder phase A input
+    pub phase_a: ENCA,
+    /// Encoder phase B input
+    pub phase_b: ENCB,
+    /// Fault input
+    pub fault: FAULT,
+    /// ADC trigger
+    pub trigger: TRIG,
+}
+
+impl<UA, UB, VA, VB, WA, WB, ENCA, ENCB, FAULT, TRIG>
+    Pins<UA, UB, VA, VB, WA, WB, ENCA, ENCB, FAULT, TRIG>
+where
+    UA: flexpwm::Pin<Output = A>,
+    UB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = UA::Submodule>,
+    VA: flexpwm::Pin<Output = A, Module = UA::Module>,
+    VB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = VA::Submodule>,
+    WA: flexpwm::Pin<Output = A, Module = UA::Module>,
+    WB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = WA::Submodule>,
+{
+    /// The pins' FlexPWM module number
+    pub const MODULE: u8 = UA::MODULE;
+
+    /// Group the motor-control pins
+    pub const fn new(
+        u: Pair<UA, UB>,
+        v: Pair<VA, VB>,
+        w: Pair<WA, WB>,
+        phase_a: ENCA,
+        phase_b: ENCB,
+        fault: FAULT,
+        trigger: TRIG,
+    ) -> Self {
+        const {
+            assert!(
+                UA::SUBMODULE != VA::SUBMODULE
+                    && UA::SUBMODULE != WA::SUBMODULE
+                    && VA::SUBMODULE != WA::SUBMODULE,
+                "each phase needs its own PWM submodule"
+            )
+        };
+        Self {
+            u,
+            v,
+            w,
+            phase_a,
+            phase_b,
+            fault,
+            trigger,
+        }
+    }
+}
+
+impl<UA, UB, VA, VB, WA, WB, ENCA, ENCB, FAULT, TRIG>
+    Pins<UA, UB, VA, VB, WA, WB, ENCA, ENCB, FAULT, TRIG>
+where
+    UA: flexpwm::Pin<Output = A>,
+    UB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = UA::Submodule>,
+    VA: flexpwm::Pin<Output = A, Module = UA::Module>,
+    VB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = VA::Submodule>,
+    WA: flexpwm::Pin<Output = A, Module = UA::Module>,
+    WB: flexpwm::Pin<Output = B, Module = UA::Module, Submodule = WA::Submodule>,
+    ENCA: Aux,
+    ENCB: Aux,
+    FAULT: Aux,
+    TRIG: Aux,
+{
+    /// Prepare all of the motor-control pins
+    pub fn prepare(&mut self) {
+        self.u.prepare();
+        self.v.prepare();
+        self.w.prepare();
+        self.phase_a.prepare();
+        self.phase_b.prepare();
+        self.fault.prepare();
+        self.trigger.prepare();
+    }
+}
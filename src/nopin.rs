@@ -0,0 +1,113 @@
+//! A placeholder for optional peripheral pins.
+
+use crate::{consts::Const, lpspi, lpuart, private, sai, Iomuxc};
+
+/// A placeholder for an optional peripheral pin
+///
+/// Use `NoPin` when a peripheral signal isn't connected to a pad. `NoPin`
+/// implements the pin traits for optional signals: an SPI chip select, a UART
+/// RTS, and a SAI master clock. Preparing a `NoPin` has no effect on the IOMUXC.
+///
+/// A pin trait's signal is an associated type, so `NoPin` stands in for one
+/// signal of each peripheral: `lpspi::Pcs0`, `lpuart::Rts`, and `sai::Mclk`.
+/// The `lpspi` and `lpuart` modules don't have PCS1 through PCS3, or CTS, pins.
+///
+/// `N` is the peripheral instance; it's typically inferred from the other
+/// pins' modules.
+///
+/// # Example
+///
+/// ```
+/// use imxrt_iomuxc::{consts::Const, lpspi, NoPin};
+///
+/// fn spi_new<C, const N: u8>(mut pcs0: C)
+/// where
+///     C: lpspi::Pin<Signal = lpspi::Pcs0, Module = Const<N>>,
+/// {
+///     lpspi::prepare(&mut pcs0);
+///     // ...
+/// }
+///
+/// // The driver manages its own chip select with a GPIO...
+/// spi_new(NoPin::<4>::new());
+/// ```
+#[derive(Debug)]
+pub struct NoPin<const N: u8> {
+    // Scratch registers. The pin functions write here instead of
+    // the IOMUXC.
+    mux: u32,
+    pad: u32,
+}
+
+impl<const N: u8> NoPin<N> {
+    /// Create a placeholder pin
+    pub const fn new() -> Self {
+        Self { mux: 0, pad: 0 }
+    }
+}
+
+impl<const N: u8> Default for NoPin<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: u8> private::Sealed for NoPin<N> {}
+
+// Safety: the pointers refer to memory owned by this object, so they never
+// alias the IOMUXC. They're only used while the caller holds a mutable borrow.
+unsafe impl<const N: u8> Iomuxc for NoPin<N> {
+    #[inline(always)]
+    fn mux(&mut self) -> *mut u32 {
+        &mut self.mux
+    }
+
+    #[inline(always)]
+    fn pad(&mut self) -> *mut u32 {
+        &mut self.pad
+    }
+}
+
+impl<const N: u8> lpspi::Pin for NoPin<N> {
+    const ALT: u32 = 0;
+    const DAISY: Option<crate::Daisy> = None;
+    type Signal = lpspi::Pcs0;
+    type Module = Const<N>;
+}
+
+impl<const N: u8> lpuart::Pin for NoPin<N> {
+    const ALT: u32 = 0;
+    const DAISY: Option<crate::Daisy> = None;
+    type Direction = lpuart::Rts;
+    type Module = Const<N>;
+}
+
+impl<const N: u8> sai::Pin<Const<N>> for NoPin<N> {
+    const ALT: u32 = 0;
+    const DAISY: Option<crate::Daisy> = None;
+    type Signal = sai::Mclk;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NoPin;
+    use crate::{consts::Const, lpspi, lpuart, sai, Config, PullKeeper};
+
+    #[test]
+    fn prepare() {
+        let mut pin = NoPin::<1>::new();
+        lpspi::prepare(&mut pin);
+        assert_eq!(pin.mux, crate::SION_BIT);
+        sai::prepare::<Const<1>, _>(&mut pin);
+        assert_eq!(pin.mux, crate::SION_BIT);
+        lpuart::prepare_de(&mut pin);
+        assert_eq!(pin.mux, 0);
+
+        let owned = lpspi::prepare_owned(NoPin::<1>::new()).release();
+        assert_eq!(owned.mux, crate::SION_BIT);
+
+        let config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k));
+        crate::configure(&mut pin, config);
+        assert_ne!(pin.pad, 0);
+    }
+}
@@ -0,0 +1,115 @@
+//! Pin map metadata in the firmware binary
+//!
+//! With the `pin-map` feature, [`record_pin!`](crate::record_pin) and
+//! [`define_board_pins!`](crate::define_board_pins) place a [`Descriptor`] for
+//! each pad in the `.iomuxc_pins` link section. Host tools read the section
+//! from the ELF file to list the firmware's pins, without running the
+//! firmware:
+//!
+//! ```text
+//! objcopy -O binary --only-section=.iomuxc_pins firmware.elf pins.bin
+//! ```
+//!
+//! `pins.bin` is an array of descriptors. The section is allocated like other
+//! statics, so it takes flash space. To keep it out of the image, place it in an
+//! `(INFO)` output section of your linker script.
+//!
+//! ```
+//! use imxrt_iomuxc::record_pin;
+//! # type GPIO_AD_B0_12 = imxrt_iomuxc::Pad<0x401F_80EC, 0x401F_82DC>;
+//!
+//! record_pin!(GPIO_AD_B0_12, "LPUART1_TX");
+//! ```
+//!
+//! `prepare()` functions are generic, so they can't place statics for each pad.
+//! Record the pins where you prepare them.
+
+use crate::PadLocation;
+
+/// The length of a descriptor's signal field
+pub const SIGNAL_LEN: usize = 32;
+/// The length of a descriptor's file field
+pub const FILE_LEN: usize = 64;
+
+/// A pad and the signal that the firmware assigns it
+///
+/// The layout is fixed, and has no pointers, so host tools can decode the
+/// section without relocations. Fields are little endian on the target.
+/// Strings are UTF-8, padded with zeros, and truncated to their field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Descriptor {
+    /// The pad's MUX register address; its [`id()`](PadLocation::id)
+    pub mux: u32,
+    /// The pad's PAD register address
+    pub pad: u32,
+    /// The source line that recorded the pad
+    pub line: u32,
+    /// The signal, like `LPUART1_TX`, or the board pin name
+    pub signal: [u8; SIGNAL_LEN],
+    /// The source file that recorded the pad
+    pub file: [u8; FILE_LEN],
+}
+
+impl Descriptor {
+    /// Describe a pad
+    pub const fn new(location: PadLocation, signal: &str, file: &str, line: u32) -> Self {
+        Self {
+            mux: location.mux,
+            pad: location.pad,
+            line,
+            signal: pad_str(signal),
+            file: pad_str(file),
+        }
+    }
+}
+
+/// Copy `text` into a zero-padded array, truncating it to `N` bytes
+const fn pad_str<const N: usize>(text: &str) -> [u8; N] {
+    let bytes = text.as_bytes();
+    let mut out = [0; N];
+    let mut idx = 0;
+    while idx < N && idx < bytes.len() {
+        out[idx] = bytes[idx];
+        idx += 1;
+    }
+    out
+}
+
+/// Record a pad and its signal in the firmware's pin map
+///
+/// The first argument is the pad type. See the [`pin_map`](crate::pin_map)
+/// module for more information.
+#[macro_export]
+macro_rules! record_pin {
+    ($pad:ty, $signal:expr) => {
+        const _: () = {
+            #[used]
+            #[cfg_attr(target_vendor = "apple", link_section = "__DATA,__iomuxc_pins")]
+            #[cfg_attr(not(target_vendor = "apple"), link_section = ".iomuxc_pins")]
+            static DESCRIPTOR: $crate::pin_map::Descriptor =
+                $crate::pin_map::Descriptor::new(<$pad>::LOCATION, $signal, file!(), line!());
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Descriptor;
+    use crate::PadLocation;
+
+    crate::record_pin!(crate::Pad<0x1000, 0x2000>, "LPUART1_TX");
+
+    #[test]
+    fn descriptor_strings() {
+        let location = PadLocation {
+            mux: 0x1000,
+            pad: 0x2000,
+        };
+        let long = "src/a/very/long/path/to/a/module/that/does/not/fit/in/the/file/field.rs";
+        let descriptor = Descriptor::new(location, "LPUART1_TX", long, 7);
+        assert_eq!(&descriptor.signal[..11], b"LPUART1_TX\0");
+        assert_eq!(descriptor.file, long.as_bytes()[..64]);
+        assert_eq!((descriptor.mux, descriptor.line), (0x1000, 7));
+    }
+}
@@ -0,0 +1,382 @@
+//! Batched pad preparation.
+
+use crate::{
+    adc, config::configure_register, flexpwm, flexspi, gpio, lpi2c, lpspi, lpuart, sai, usdhc,
+    Config, Daisy, Iomuxc, PadLocation, ALT_MASK, SION_BIT,
+};
+use core::{marker::PhantomData, ptr};
+
+/// A set of pad preparations, applied in one pass
+///
+/// Add pins with the peripheral methods, like [`lpuart()`](Self::lpuart). Each
+/// method records the same mux, configuration, and daisy settings as the
+/// peripheral's `prepare()` function. Then, [`apply()`](Self::apply) the plan.
+///
+/// The plan coalesces its writes. Each MUX, PAD, and daisy register is written
+/// once, with the last value planned for that register. The writes take the
+/// same path as the peripheral `prepare()` functions, so the [`lock`](crate::lock)
+/// registry and the [`xip`](crate::xip) guard check them, and debug builds check
+/// each daisy after writing it. Daisy registers are written after all MUX and
+/// PAD registers.
+///
+/// `N` is the capacity of each of the plan's MUX, PAD, and daisy tables. The
+/// plan borrows its pins until you apply it.
+///
+/// # Panics
+///
+/// Adding a pin panics if it needs a new register, and the register's table is
+/// full.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::PreparePlan;
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b1::{GPIO_AD_B1_00, GPIO_AD_B1_01};
+///
+/// let mut tx = unsafe { GPIO_AD_B0_12::new() };
+/// let mut rx = unsafe { GPIO_AD_B0_13::new() };
+/// let mut scl = unsafe { GPIO_AD_B1_00::new() };
+/// let mut sda = unsafe { GPIO_AD_B1_01::new() };
+///
+/// let mut plan = PreparePlan::<4>::new();
+/// plan.lpuart(&mut tx)
+///     .lpuart(&mut rx)
+///     .lpi2c(&mut scl)
+///     .lpi2c(&mut sda);
+/// plan.apply();
+/// ```
+pub struct PreparePlan<'a, const N: usize> {
+    muxes: [MuxWrite; N],
+    mux_len: usize,
+    pads: [PadWrite; N],
+    pad_len: usize,
+    daisies: [DaisyWrite; N],
+    daisy_len: usize,
+    _pins: PhantomData<&'a mut ()>,
+}
+
+#[derive(Clone, Copy)]
+struct MuxWrite {
+    reg: *mut u32,
+    alt: u32,
+    sion: Option<bool>,
+}
+
+#[derive(Clone, Copy)]
+struct PadWrite {
+    reg: *mut u32,
+    config: Config,
+}
+
+#[derive(Clone, Copy)]
+struct DaisyWrite {
+    daisy: Daisy,
+    /// The pad that the daisy selects
+    pad: PadLocation,
+}
+
+impl<const N: usize> Default for PreparePlan<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize> PreparePlan<'a, N> {
+    /// Create an empty plan
+    pub const fn new() -> Self {
+        Self {
+            muxes: [MuxWrite {
+                reg: ptr::null_mut(),
+                alt: 0,
+                sion: None,
+            }; N],
+            mux_len: 0,
+            pads: [PadWrite {
+                reg: ptr::null_mut(),
+                config: Config::modify(),
+            }; N],
+            pad_len: 0,
+            daisies: [DaisyWrite {
+                daisy: Daisy::new(ptr::null_mut(), 0),
+                pad: PadLocation { mux: 0, pad: 0 },
+            }; N],
+            daisy_len: 0,
+            _pins: PhantomData,
+        }
+    }
+
+    /// Plan a LPUART pin
+    pub fn lpuart<P: lpuart::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, Some(false))
+            .daisy(pin, P::DAISY)
+    }
+
+    /// Plan a LPI2C pin
+    pub fn lpi2c<P: lpi2c::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, Some(true))
+            .daisy(pin, Some(P::DAISY))
+    }
+
+    /// Plan a LPSPI pin
+    pub fn lpspi<P: lpspi::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, Some(true)).daisy(pin, P::DAISY)
+    }
+
+    /// Plan a FlexSPI pin
+    pub fn flexspi<P: flexspi::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        let sion = <P::Signal as flexspi::Signal>::SION;
+        self.mux(pin.mux(), P::ALT, Some(sion)).daisy(pin, P::DAISY)
+    }
+
+    /// Plan a SAI pin
+    pub fn sai<SAIx: crate::consts::Unsigned, P: sai::Pin<SAIx>>(
+        &mut self,
+        pin: &'a mut P,
+    ) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, Some(true)).daisy(pin, P::DAISY)
+    }
+
+    /// Plan a uSDHC pin
+    pub fn usdhc<P: usdhc::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, Some(true))
+            .config(pin.pad(), P::CONFIG)
+            .daisy(pin, P::DAISY)
+    }
+
+    /// Plan a FlexPWM pin
+    pub fn flexpwm<P: flexpwm::Pin>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, None)
+    }
+
+    /// Plan a GPIO pin
+    pub fn gpio<P: gpio::Pin<M>, const M: u8>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.mux(pin.mux(), P::ALT, None)
+    }
+
+    /// Plan an ADC pin
+    pub fn adc<P: adc::Pin<M>, const M: u8>(&mut self, pin: &'a mut P) -> &mut Self {
+        self.config(pin.pad(), adc::CONFIG)
+    }
+
+    /// Plan a pad configuration
+    ///
+    /// Like [`configure()`](fn@crate::configure), fields that a `modify()` configuration
+    /// doesn't set keep their planned, or current, values.
+    pub fn configure<P: Iomuxc>(&mut self, pin: &'a mut P, config: Config) -> &mut Self {
+        self.config(pin.pad(), config)
+    }
+
+    /// Apply the plan
+    pub fn apply(self) {
+        // Safety: the plan borrows every pin that it writes, so the plan has
+        // exclusive access to the pins' registers. The daisy registers are
+        // shared, just like they are in the peripheral prepare() functions.
+        unsafe {
+            for mux in &self.muxes[..self.mux_len] {
+                let alt = mux.alt & ALT_MASK;
+                match mux.sion {
+                    Some(sion) => {
+                        let sion = if sion { SION_BIT } else { 0 };
+                        crate::modify_mux(mux.reg, ALT_MASK | SION_BIT, alt | sion)
+                    }
+                    None => crate::modify_mux(mux.reg, ALT_MASK, alt),
+                };
+            }
+            for pad in &self.pads[..self.pad_len] {
+                configure_register(pad.reg, pad.config);
+            }
+            for write in &self.daisies[..self.daisy_len] {
+                write.daisy.write();
+                crate::debug_check_daisy_at(write.pad, Some(write.daisy));
+            }
+        }
+    }
+
+    /// Returns the alternate planned for the MUX register at `reg`
+    #[cfg(feature = "golden")]
+    pub(crate) fn planned_alt(&self, reg: u32) -> Option<u32> {
+        self.muxes[..self.mux_len]
+            .iter()
+            .find(|mux| mux.reg as usize as u32 == reg)
+            .map(|mux| mux.alt)
+    }
+
+    /// Returns the value planned for the daisy register at `reg`
+    #[cfg(feature = "golden")]
+    pub(crate) fn planned_daisy(&self, reg: u32) -> Option<u32> {
+        self.daisies[..self.daisy_len]
+            .iter()
+            .find(|write| write.daisy.reg as usize as u32 == reg)
+            .map(|write| write.daisy.value)
+    }
+
+    fn mux(&mut self, reg: *mut u32, alt: u32, sion: Option<bool>) -> &mut Self {
+        let planned = &mut self.muxes[..self.mux_len];
+        if let Some(mux) = planned.iter_mut().find(|mux| mux.reg == reg) {
+            mux.alt = alt;
+            mux.sion = sion.or(mux.sion);
+        } else {
+            self.muxes[self.mux_len] = MuxWrite { reg, alt, sion };
+            self.mux_len += 1;
+        }
+        self
+    }
+
+    fn config(&mut self, reg: *mut u32, config: Config) -> &mut Self {
+        let planned = &mut self.pads[..self.pad_len];
+        if let Some(pad) = planned.iter_mut().find(|pad| pad.reg == reg) {
+            pad.config = Config {
+                value: (pad.config.value & !config.mask) | config.value,
+                mask: pad.config.mask | config.mask,
+            };
+        } else {
+            self.pads[self.pad_len] = PadWrite { reg, config };
+            self.pad_len += 1;
+        }
+        self
+    }
+
+    fn daisy<P: Iomuxc + ?Sized>(&mut self, pin: &mut P, daisy: Option<Daisy>) -> &mut Self {
+        let Some(daisy) = daisy else {
+            return self;
+        };
+        let write = DaisyWrite {
+            daisy,
+            pad: PadLocation {
+                mux: pin.mux() as u32,
+                pad: pin.pad() as u32,
+            },
+        };
+        let planned = &mut self.daisies[..self.daisy_len];
+        if let Some(planned) = planned
+            .iter_mut()
+            .find(|planned| planned.daisy.reg == daisy.reg)
+        {
+            *planned = write;
+        } else {
+            self.daisies[self.daisy_len] = write;
+            self.daisy_len += 1;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PreparePlan;
+    use crate::{consts::Const, Config, Daisy, Iomuxc, Speed};
+
+    /// A fake pad. Multiple handles may share the same registers.
+    struct Pad {
+        mux: *mut u32,
+        pad: *mut u32,
+    }
+
+    impl crate::private::Sealed for Pad {}
+
+    unsafe impl Iomuxc for Pad {
+        fn mux(&mut self) -> *mut u32 {
+            self.mux
+        }
+        fn pad(&mut self) -> *mut u32 {
+            self.pad
+        }
+    }
+
+    impl crate::gpio::Pin<1> for Pad {
+        const ALT: u32 = 5;
+        const OFFSET: u32 = 0;
+    }
+
+    impl crate::lpuart::Pin for Pad {
+        const ALT: u32 = 2;
+        const DAISY: Option<Daisy> = None;
+        type Direction = crate::lpuart::Tx;
+        type Module = Const<1>;
+    }
+
+    #[test]
+    fn coalesce_writes() {
+        let mut mux = 0xFF00_0010u32;
+        let mut pad = 0xFFFF_FFFFu32;
+        let mut first = Pad {
+            mux: &mut mux,
+            pad: &mut pad,
+        };
+        let mut second = Pad {
+            mux: &mut mux,
+            pad: &mut pad,
+        };
+        let mut third = Pad {
+            mux: &mut mux,
+            pad: &mut pad,
+        };
+
+        let mut plan = PreparePlan::<1>::new();
+        plan.lpuart(&mut first)
+            .gpio(&mut second)
+            .configure(&mut third, Config::zero().set_speed(Speed::Max));
+        assert_eq!((plan.mux_len, plan.pad_len, plan.daisy_len), (1, 1, 0));
+        plan.apply();
+
+        // The GPIO alternate wins, and the LPUART clears SION.
+        assert_eq!(mux, 0xFF00_0005);
+        assert_eq!(pad, Speed::Max as u32);
+    }
+
+    #[test]
+    fn read_modify_write_without_sion() {
+        let mut mux = 0xFF00_0010u32;
+        let mut pad = 0;
+        let mut gpio = Pad {
+            mux: &mut mux,
+            pad: &mut pad,
+        };
+        let mut plan = PreparePlan::<1>::new();
+        plan.gpio(&mut gpio);
+        plan.apply();
+        assert_eq!(mux, 0xFF00_0015);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "is locked")]
+    fn apply_checks_locks() {
+        use crate::{lock, PadLocation};
+
+        /// Unlocks the pad when dropped, even when the test panics
+        struct Unlock(PadLocation);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                lock::unlock(self.0);
+            }
+        }
+
+        let mut registers = [0u32; 2];
+        let [mux, pad] = &mut registers;
+        let mut gpio = Pad { mux, pad };
+        let location = PadLocation {
+            mux: gpio.mux as u32,
+            pad: gpio.pad as u32,
+        };
+        // The stack addresses may belong to another test's pad later.
+        let _unlock = Unlock(location);
+        lock::lock(location).unwrap();
+        let mut plan = PreparePlan::<1>::new();
+        plan.gpio(&mut gpio);
+        plan.apply();
+    }
+
+    #[test]
+    fn coalesce_daisies() {
+        let mut daisy = 0u32;
+        let mut pin = crate::NoPin::<1>::new();
+        let mut plan = PreparePlan::<1>::new();
+        plan.daisy(&mut pin, Some(Daisy::new(&mut daisy, 1)))
+            .daisy(&mut pin, Some(Daisy::new(&mut daisy, 2)));
+        assert_eq!(plan.daisy_len, 1);
+        plan.apply();
+        assert_eq!(daisy, 2);
+    }
+}
@@ -0,0 +1,54 @@
+//! Pins that are known to be prepared.
+
+use core::marker::PhantomData;
+
+/// A pin that's prepared for a peripheral signal
+///
+/// `S` is the signal, like `lpuart::Tx`, and `P` is the pin. Each peripheral
+/// module has a `prepare_owned()` function that consumes a pin, prepares it,
+/// and returns a `Prepared` pin. A driver that accepts a `Prepared` pin knows
+/// that the pin is ready to use, and does not need to prepare it.
+///
+/// Use [`release()`](Prepared::release) to take back the pin.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::{lpuart::{self, Pin, Tx}, Prepared};
+/// # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_12;
+///
+/// fn uart_tx<P: Pin<Direction = Tx>>(pin: Prepared<Tx, P>) {
+///     // The pin is already prepared...
+///     # let _ = pin;
+/// }
+///
+/// let tx = lpuart::prepare_owned(unsafe { GPIO_AD_B0_12::new() });
+/// uart_tx(tx);
+/// ```
+#[derive(Debug)]
+pub struct Prepared<S, P> {
+    pin: P,
+    _signal: PhantomData<S>,
+}
+
+impl<S, P> Prepared<S, P> {
+    /// Wrap a pin that was prepared for signal `S`
+    pub(crate) const fn new(pin: P) -> Self {
+        Self {
+            pin,
+            _signal: PhantomData,
+        }
+    }
+
+    /// Access the prepared pin
+    pub const fn pin(&self) -> &P {
+        &self.pin
+    }
+
+    /// Release the pin
+    ///
+    /// The pin's configuration is unchanged.
+    pub fn release(self) -> P {
+        self.pin
+    }
+}
@@ -0,0 +1,145 @@
+//! Pads in `imxrt-ral` IOMUXC instances
+//!
+//! A [`Block`] owns an IOMUXC register block through its `imxrt-ral`
+//! instance. It turns the package's pads and daisies, which name the chip's
+//! register addresses, into pads and daisies that access the instance's
+//! registers. [`configure()`](crate::configure()),
+//! [`alternate()`](crate::alternate()), and [`Daisy::write()`] then go through
+//! the instance, and the RAL stays the IOMUXC's only owner.
+//!
+//! The instance may point at memory other than the chip's IOMUXC, like an
+//! emulated register block. The block moves each register by the distance from
+//! the chip's block address to the instance.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{configure, ral::Block, Config, SlewRate};
+//! use imxrt_iomuxc::imxrt1060::{daisy, gpio_ad_b1::GPIO_AD_B1_03};
+//! # struct RegisterBlock([u32; 0x400]);
+//! # let iomuxc = unsafe { imxrt_ral::Instance::<_, 0>::new(0x401F_8000 as *const RegisterBlock) };
+//!
+//! // let iomuxc = unsafe { imxrt_ral::iomuxc::IOMUXC::instance() };
+//! let block = Block::new(iomuxc, 0x401F_8000);
+//! let mut rx = block.pad(unsafe { GPIO_AD_B1_03::new() }).unwrap();
+//! configure(&mut rx, Config::modify().set_slew_rate(SlewRate::Fast));
+//! let daisy = block.daisy(daisy::lpuart::DAISY_LPUART2_RX_GPIO_AD_B1_03).unwrap();
+//! unsafe { daisy.write() };
+//! let iomuxc = block.release();
+//! ```
+//!
+//! Pads from a block are [`ErasedPad`]s, so they don't implement the pin
+//! traits. Set their alternates with [`alternate()`](crate::alternate()), and
+//! write their daisies through the block.
+
+use crate::{Daisy, ErasedPad, Iomuxc, PadLocation};
+use imxrt_ral::Instance;
+
+/// An IOMUXC register block, owned through its `imxrt-ral` instance
+///
+/// `T` is the instance's register block type. The block covers
+/// `size_of::<T>()` bytes, starting at the chip's block address.
+pub struct Block<T, const N: u8> {
+    instance: Instance<T, N>,
+    base: u32,
+}
+
+impl<T, const N: u8> Block<T, N> {
+    /// Own the register block that the chip has at `base`
+    ///
+    /// `base` is the chip's address of the block, like `0x401F_8000` for the
+    /// 1060 IOMUXC. For the chip's own instance, it's the instance's address.
+    pub fn new(instance: Instance<T, N>, base: u32) -> Self {
+        Self { instance, base }
+    }
+
+    /// Release the instance
+    ///
+    /// Pads and daisies from the block still access the instance's registers.
+    pub fn release(self) -> Instance<T, N> {
+        self.instance
+    }
+
+    /// Returns the instance's register at the chip's `address`, if the block
+    /// has the register
+    fn register(&self, address: u32) -> Option<*mut u32> {
+        let offset = address.wrapping_sub(self.base) as usize;
+        let start = &*self.instance as *const T as *mut u8;
+        (offset % 4 == 0 && offset < core::mem::size_of::<T>())
+            // Safety: the offset is inside the register block.
+            .then(|| unsafe { start.add(offset) } as *mut u32)
+    }
+
+    /// Returns `true` if the block has the pad's MUX and PAD registers
+    pub fn contains(&self, location: PadLocation) -> bool {
+        self.register(location.mux).is_some() && self.register(location.pad).is_some()
+    }
+
+    /// Move the pad into the block
+    ///
+    /// Returns a pad that accesses the pad's registers in the instance. If the
+    /// block doesn't have the pad's registers, returns the pad.
+    pub fn pad<P: Iomuxc>(&self, mut pad: P) -> Result<ErasedPad, P> {
+        match (
+            self.register(pad.mux() as u32),
+            self.register(pad.pad() as u32),
+        ) {
+            // Safety: the registers are in the instance's register block, and
+            // consuming the pad leaves the new pad as their only owner.
+            (Some(mux), Some(pad)) => Ok(unsafe { ErasedPad::new(mux, pad) }),
+            _ => Err(pad),
+        }
+    }
+
+    /// Returns the daisy, writing the instance's daisy register
+    ///
+    /// Returns `None` if the block doesn't have the daisy register.
+    pub fn daisy(&self, daisy: Daisy) -> Option<Daisy> {
+        let reg = self.register(daisy.reg() as u32)?;
+        // Safety: the register is in the instance's register block. Writing the
+        // daisy has the same rules as writing the chip's daisy.
+        Some(unsafe { Daisy::new_unchecked(reg, daisy.value()) })
+    }
+}
+
+impl<T, const N: u8> core::fmt::Debug for Block<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Block")
+            .field("instance", &(&*self.instance as *const T))
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Block;
+    use crate::{alternate, configure, Alternate, Config, Daisy, Pad, SlewRate};
+    use core::cell::Cell;
+    use imxrt_ral::Instance;
+
+    /// Registers that allow writes through shared references, like the RAL's
+    type Registers = [Cell<u32>; 0x200];
+
+    #[test]
+    fn relocate() {
+        let memory: Registers = [const { Cell::new(0) }; 0x200];
+        let instance = unsafe { Instance::<Registers, 0>::new(&memory) };
+        let block = Block::new(instance, 0x401F_8000);
+
+        let pad = unsafe { Pad::<0x401F_80EC, 0x401F_82DC>::new() };
+        let mut pad = block.pad(pad).unwrap();
+        alternate(&mut pad, Alternate::<2>);
+        configure(&mut pad, Config::zero().set_slew_rate(SlewRate::Fast));
+        let daisy = unsafe { Daisy::new_unchecked(0x401F_852C as *mut u32, 1) };
+        unsafe { block.daisy(daisy).unwrap().write() };
+
+        let outside = unsafe { Pad::<0x401F_8900, 0x401F_8904>::new() };
+        assert!(block.pad(outside).is_err());
+        let outside = unsafe { Daisy::new_unchecked(0x401F_0000 as *mut u32, 1) };
+        assert_eq!(block.daisy(outside), None);
+
+        let _ = block.release();
+        assert_eq!(memory[0xEC / 4].get(), 2);
+        assert_eq!(memory[0x2DC / 4].get(), SlewRate::Fast as u32);
+        assert_eq!(memory[0x52C / 4].get(), 1);
+    }
+}
@@ -0,0 +1,382 @@
+//! A data description of pad registers
+//!
+//! Each chip module has a `REGISTER_MAP`, which describes all of the chip's
+//! pads as plain data. Use it to generate code, or to drive other register
+//! access crates, without the pad types. The table includes pads regardless of
+//! the enabled pad block features.
+//!
+//! The chip tables don't know the pads' reset values, so each entry's `reset` is
+//! `None`, and the daisy values are the ones that the chip's peripheral modules
+//! define. The `svd` module, generated from an SVD, has its own `REGISTER_MAP`
+//! with every pad's reset values and the daisy values from the SVD's full select
+//! input table. It also has `RESET_VALUES` for [`reset()`].
+//!
+//! Use [`save()`] and [`restore()`] to snapshot all of a chip's pads, for instance
+//! when the pads lose their state in a low-power mode. Each chip's `SNAPSHOT_LEN`
+//! sizes the snapshot buffer, so you may allocate it statically. The snapshot
+//! covers the MUX, PAD, and daisy registers described by the table, and the
+//! IOMUXC_GPR registers that select pad functions. Each chip's `SNAPSHOT_GPRS`
+//! lists those GPRs.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{imxrt1060, register_map};
+//!
+//! static mut SNAPSHOT: [u32; imxrt1060::SNAPSHOT_LEN] = [0; imxrt1060::SNAPSHOT_LEN];
+//!
+//! unsafe {
+//!     let snapshot = &mut *core::ptr::addr_of_mut!(SNAPSHOT);
+//!     register_map::save(imxrt1060::REGISTER_MAP, imxrt1060::SNAPSHOT_GPRS, snapshot);
+//!     // Suspend, then resume...
+//!     register_map::restore(imxrt1060::REGISTER_MAP, imxrt1060::SNAPSHOT_GPRS, snapshot);
+//! }
+//! ```
+
+use core::ptr;
+
+/// A daisy register and the value that selects a pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaisyEntry {
+    /// The daisy register address
+    pub reg: u32,
+    /// The value that selects the pad
+    pub value: u32,
+}
+
+/// The reset values of a pad's MUX and PAD registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PadReset {
+    /// The MUX register's value after reset
+    pub mux: u32,
+    /// The PAD register's value after reset
+    pub pad: u32,
+}
+
+/// A pad's registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PadRegisters {
+    /// The pad name, like `"GPIO_AD_B0_03"`
+    pub name: &'static str,
+    /// The MUX register address
+    pub mux: u32,
+    /// The PAD register address
+    pub pad: u32,
+    /// The registers' reset values, if the table knows them
+    pub reset: Option<PadReset>,
+    /// The daisy values that select this pad
+    pub daisies: &'static [DaisyEntry],
+}
+
+/// Returns the number of registers in a snapshot of `map` and `gprs`
+///
+/// The count includes each pad's MUX and PAD registers, each daisy register, and
+/// each GPR. A daisy register that selects more than one pad is counted once.
+pub const fn snapshot_len(map: &[PadRegisters], gprs: &[u32]) -> usize {
+    let mut len = gprs.len();
+    let mut idx = 0;
+    while idx < map.len() {
+        len += 2;
+        let mut daisy = 0;
+        while daisy < map[idx].daisies.len() {
+            if first_daisy(map, idx, daisy) {
+                len += 1;
+            }
+            daisy += 1;
+        }
+        idx += 1;
+    }
+    len
+}
+
+/// Returns `true` if no earlier entry of `map` names the same daisy register
+/// as `map[idx].daisies[daisy]`.
+const fn first_daisy(map: &[PadRegisters], idx: usize, daisy: usize) -> bool {
+    let reg = map[idx].daisies[daisy].reg;
+    let mut pad = 0;
+    while pad <= idx {
+        let daisies = map[pad].daisies;
+        let end = if pad == idx { daisy } else { daisies.len() };
+        let mut other = 0;
+        while other < end {
+            if daisies[other].reg == reg {
+                return false;
+            }
+            other += 1;
+        }
+        pad += 1;
+    }
+    true
+}
+
+/// The snapshot's registers, in order: every MUX and PAD register, then every
+/// daisy register, once, then every GPR.
+fn registers<'a>(map: &'a [PadRegisters], gprs: &'a [u32]) -> impl Iterator<Item = u32> + 'a {
+    let pads = map.iter().flat_map(|pad| [pad.mux, pad.pad]);
+    let daisies = map.iter().enumerate().flat_map(move |(idx, pad)| {
+        pad.daisies
+            .iter()
+            .enumerate()
+            .filter(move |(daisy, _)| first_daisy(map, idx, *daisy))
+            .map(|(_, daisy)| daisy.reg)
+    });
+    pads.chain(daisies).chain(gprs.iter().copied())
+}
+
+/// Save the registers described by `map`, and the `gprs`, into `snapshot`
+///
+/// # Panics
+///
+/// Panics if `snapshot` is shorter than [`snapshot_len()`] of `map` and `gprs`.
+///
+/// # Safety
+///
+/// Reads every register described by `map`, and every GPR. The addresses must be
+/// valid for reads. When `map` is a chip's `REGISTER_MAP`, the caller must be
+/// running on that chip.
+pub unsafe fn save(map: &[PadRegisters], gprs: &[u32], snapshot: &mut [u32]) {
+    assert!(snapshot.len() >= snapshot_len(map, gprs));
+    for (reg, value) in registers(map, gprs).zip(snapshot) {
+        *value = ptr::read_volatile(reg as *const u32);
+    }
+}
+
+/// Restore the registers described by `map`, and the `gprs`, from `snapshot`
+///
+/// Writes the MUX and PAD registers, then the daisy registers, then the GPRs.
+///
+/// # Panics
+///
+/// Panics if `snapshot` is shorter than [`snapshot_len()`] of `map` and `gprs`.
+/// In debug builds, panics before writing a [locked](crate::lock) pad's
+/// register. With the `xip-guard` feature, also panics before writing a boot
+/// flash pad's register while the code executes in place.
+///
+/// # Safety
+///
+/// Writes every register described by `map`, and every GPR. The caller must
+/// ensure that the writes don't race with any other user of the pads or GPRs.
+/// `snapshot` should come from [`save()`] with the same `map` and `gprs`.
+pub unsafe fn restore(map: &[PadRegisters], gprs: &[u32], snapshot: &[u32]) {
+    assert!(snapshot.len() >= snapshot_len(map, gprs));
+    for (reg, value) in registers(map, gprs).zip(snapshot) {
+        crate::write_register(reg as *mut u32, *value);
+    }
+}
+
+/// A register and its reset value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResetValue {
+    /// The register address
+    pub reg: u32,
+    /// The register's value after reset
+    pub value: u32,
+}
+
+/// Write each register's reset value, in order
+///
+/// Bootloaders can return the pads to their reset state before starting the
+/// next program.
+///
+/// # Panics
+///
+/// In debug builds, panics before writing a [locked](crate::lock) pad's
+/// register. With the `xip-guard` feature, also panics before writing a boot
+/// flash pad's register while the code executes in place.
+///
+/// # Safety
+///
+/// Writes every register in `values`. The caller must ensure that the writes
+/// don't race with any other user of the pads, and that nothing depends on the
+/// pads' current configuration.
+pub unsafe fn reset(values: &[ResetValue]) {
+    for value in values {
+        crate::write_register(value.reg as *mut u32, value.value);
+    }
+}
+
+/// Returns the pad that the daisy register `reg` currently selects
+///
+/// Reads `reg`, then finds the pad whose daisy entry matches the value. Returns
+/// `None` if no pad of `map` matches, for instance when the register selects a
+/// pad that's not in the map.
+///
+/// # Safety
+///
+/// Reads `reg`. The address must be valid for reads. When `map` is a chip's
+/// `REGISTER_MAP`, the caller must be running on that chip.
+pub unsafe fn selected(map: &[PadRegisters], reg: u32) -> Option<&PadRegisters> {
+    selecting(map, reg, ptr::read_volatile(reg as *const u32))
+}
+
+fn selecting(map: &[PadRegisters], reg: u32, value: u32) -> Option<&PadRegisters> {
+    map.iter()
+        .find(|pad| pad.daisies.contains(&DaisyEntry { reg, value }))
+}
+
+/// A peripheral input, named by its select input register
+///
+/// Get an input from a daisy value with `From`, or from a select input
+/// register address with [`new()`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "remote", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputSignal {
+    reg: u32,
+}
+
+impl InputSignal {
+    /// Name the input by its select input register address
+    pub const fn new(reg: u32) -> Self {
+        Self { reg }
+    }
+
+    /// Returns the select input register address
+    pub const fn reg(self) -> u32 {
+        self.reg
+    }
+}
+
+impl From<crate::Daisy> for InputSignal {
+    fn from(daisy: crate::Daisy) -> Self {
+        Self::new(daisy.reg() as u32)
+    }
+}
+
+/// An error from [`select_input()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutingError {
+    /// The map does not describe the pad
+    UnknownPad,
+    /// The pad can't feed the input
+    NoRoute,
+}
+
+/// Route `pad` to the peripheral `input`
+///
+/// Finds the pad in `map`, then writes the daisy value that selects the pad
+/// for the input. Nothing is written if the pad can't feed the input.
+///
+/// Each chip's `select_input()` uses the chip's `REGISTER_MAP`.
+///
+/// # Safety
+///
+/// Writes the input's select input register. When `map` is a chip's
+/// `REGISTER_MAP`, the caller must be running on that chip. The write has the
+/// same rules as [`Daisy::write()`](crate::Daisy::write).
+pub unsafe fn select_input(
+    map: &[PadRegisters],
+    input: InputSignal,
+    pad: &crate::ErasedPad,
+) -> Result<(), RoutingError> {
+    let daisy = route(map, input, pad.location())?;
+    ptr::write_volatile(daisy.reg as *mut u32, daisy.value);
+    #[cfg(any(feature = "log", feature = "defmt"))]
+    crate::trace::write(daisy.reg as *mut u32, daisy.value);
+    Ok(())
+}
+
+pub(crate) fn route(
+    map: &[PadRegisters],
+    input: InputSignal,
+    location: crate::PadLocation,
+) -> Result<DaisyEntry, RoutingError> {
+    let pad = map
+        .iter()
+        .find(|pad| (pad.mux, pad.pad) == (location.mux, location.pad))
+        .ok_or(RoutingError::UnknownPad)?;
+    pad.daisies
+        .iter()
+        .find(|daisy| daisy.reg == input.reg)
+        .copied()
+        .ok_or(RoutingError::NoRoute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        registers, route, selecting, snapshot_len, DaisyEntry, InputSignal, PadRegisters,
+        RoutingError,
+    };
+    use crate::PadLocation;
+
+    const MAP: &[PadRegisters] = &[
+        PadRegisters {
+            name: "A_00",
+            mux: 0x10,
+            pad: 0x20,
+            reset: None,
+            daisies: &[DaisyEntry {
+                reg: 0x30,
+                value: 1,
+            }],
+        },
+        PadRegisters {
+            name: "A_01",
+            mux: 0x14,
+            pad: 0x24,
+            reset: None,
+            daisies: &[],
+        },
+    ];
+
+    #[test]
+    fn snapshot_registers() {
+        const GPRS: &[u32] = &[0x40];
+        const LEN: usize = snapshot_len(MAP, GPRS);
+        assert_eq!(LEN, 6);
+        let mut regs = [0; LEN];
+        for (slot, reg) in regs.iter_mut().zip(registers(MAP, GPRS)) {
+            *slot = reg;
+        }
+        assert_eq!(regs, [0x10, 0x20, 0x14, 0x24, 0x30, 0x40]);
+        assert_eq!(registers(MAP, GPRS).count(), LEN);
+    }
+
+    #[test]
+    fn snapshot_shared_daisy() {
+        // Both pads' daisies share one register.
+        const SHARED: &[PadRegisters] = &[
+            MAP[0],
+            PadRegisters {
+                daisies: &[DaisyEntry {
+                    reg: 0x30,
+                    value: 0,
+                }],
+                ..MAP[1]
+            },
+        ];
+        const LEN: usize = snapshot_len(SHARED, &[]);
+        assert_eq!(LEN, 5);
+        let regs: [u32; LEN] = core::array::from_fn({
+            let mut regs = registers(SHARED, &[]);
+            move |_| regs.next().unwrap()
+        });
+        assert_eq!(regs, [0x10, 0x20, 0x14, 0x24, 0x30]);
+        assert_eq!(registers(SHARED, &[]).count(), LEN);
+    }
+
+    #[test]
+    fn selected_pad() {
+        assert_eq!(selecting(MAP, 0x30, 1).map(|pad| pad.name), Some("A_00"));
+        assert!(selecting(MAP, 0x30, 0).is_none());
+        assert!(selecting(MAP, 0x34, 1).is_none());
+    }
+
+    #[test]
+    fn input_routes() {
+        let location = |mux, pad| PadLocation { mux, pad };
+        assert_eq!(
+            route(MAP, InputSignal::new(0x30), location(0x10, 0x20)),
+            Ok(DaisyEntry {
+                reg: 0x30,
+                value: 1
+            })
+        );
+        assert_eq!(
+            route(MAP, InputSignal::new(0x30), location(0x14, 0x24)),
+            Err(RoutingError::NoRoute)
+        );
+        assert_eq!(
+            route(MAP, InputSignal::new(0x30), location(0x18, 0x28)),
+            Err(RoutingError::UnknownPad)
+        );
+    }
+}
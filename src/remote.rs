@@ -0,0 +1,304 @@
+//! A compact message format for remote pin control
+//!
+//! A host-side tool encodes [`Message`]s, and sends them to the target over
+//! RTT, a serial port, or any other byte stream. The target decodes and applies
+//! each message with [`dispatch()`]. Use this during hardware validation to
+//! change a pad's muxing without rebuilding the firmware.
+//!
+//! # Format
+//!
+//! A message names a pad by its [`PadLocation`], then carries a [`Command`]: a
+//! [`Signal`] that muxes the pad, or a [`Config`] for the pad. Messages use
+//! [postcard]'s encoding of the `serde` types, so a host-side tool can encode
+//! them by enabling this package's `remote` feature, or by declaring the same
+//! types. An encoded message is at most [`Message::MAX_LEN`] bytes long.
+//!
+//! A `Configure` command carries the [`Config::value()`] and [`Config::mask()`]
+//! of the configuration, so `zero()` and `modify()` configurations behave the
+//! same on the target.
+//!
+//! # Validation
+//!
+//! The target checks each decoded message against a register map, like a
+//! chip's `REGISTER_MAP`, before it writes a register. [`dispatch()`] rejects a
+//! message if the map does not describe the pad, or if the pad can't feed the
+//! signal's input.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use imxrt_iomuxc::remote::{self, Command, Message};
+//! use imxrt_iomuxc::{imxrt1060, Config, SlewRate};
+//! use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_12;
+//!
+//! // On the host:
+//! let message = Message {
+//!     pad: GPIO_AD_B0_12::LOCATION,
+//!     command: Command::Configure(Config::modify().set_slew_rate(SlewRate::Fast)),
+//! };
+//! let mut buffer = [0; Message::MAX_LEN];
+//! let len = message.encode(&mut buffer);
+//!
+//! // On the target:
+//! let applied = unsafe { remote::dispatch(imxrt1060::REGISTER_MAP, &buffer[..len]) };
+//! assert_eq!(applied, Ok(len));
+//! ```
+//!
+//! [postcard]: https://docs.rs/postcard
+
+use crate::{
+    config::configure_register,
+    register_map::{self, InputSignal, PadRegisters, RoutingError},
+    Config, Daisy, PadLocation,
+};
+use serde::{Deserialize, Serialize};
+
+/// A remote pin control command for one pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Message {
+    /// The pad
+    pub pad: PadLocation,
+    /// The command for the pad
+    pub command: Command,
+}
+
+/// What a [`Message`] does to its pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Command {
+    /// Mux the pad to a peripheral signal
+    Signal(Signal),
+    /// Apply a configuration to the pad
+    Configure(Config),
+}
+
+/// A pad's peripheral signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Signal {
+    /// The alternate value
+    pub alt: u8,
+    /// Set the SION bit if `true`, or clear it if `false`
+    pub sion: bool,
+    /// The peripheral input that the pad feeds, if the signal is an input
+    ///
+    /// The target writes the daisy value that selects the pad for the input.
+    pub input: Option<InputSignal>,
+}
+
+/// An error decoding a [`Message`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ends before the end of the message
+    Truncated,
+    /// The input doesn't describe a message
+    Invalid,
+}
+
+/// An error from [`dispatch()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchError {
+    /// The bytes don't start with a message
+    Decode(DecodeError),
+    /// The register map rejects the message
+    Routing(RoutingError),
+}
+
+impl From<DecodeError> for DispatchError {
+    fn from(error: DecodeError) -> Self {
+        DispatchError::Decode(error)
+    }
+}
+
+impl From<RoutingError> for DispatchError {
+    fn from(error: RoutingError) -> Self {
+        DispatchError::Routing(error)
+    }
+}
+
+impl Message {
+    /// The length of the longest encoded message
+    ///
+    /// postcard encodes each `u32` in at most five bytes. The longest message is
+    /// a [`Signal`] with an input: two words of location, the command tag, the
+    /// alternate, the SION flag, the input's option tag, and the input's word.
+    pub const MAX_LEN: usize = 10 + 1 + 1 + 1 + 1 + 5;
+
+    /// Encode the message into `buffer`, returning the encoded length
+    pub fn encode(&self, buffer: &mut [u8; Self::MAX_LEN]) -> usize {
+        postcard::to_slice(self, buffer)
+            .expect("MAX_LEN holds every message")
+            .len()
+    }
+
+    /// Decode a message from the start of `bytes`
+    ///
+    /// On success, returns the message and its encoded length. Bytes after the
+    /// message are not read.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        match postcard::take_from_bytes(bytes) {
+            Ok((message, rest)) => Ok((message, bytes.len() - rest.len())),
+            Err(postcard::Error::DeserializeUnexpectedEnd) => Err(DecodeError::Truncated),
+            Err(_) => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Apply the message to the hardware
+    ///
+    /// Fails, without writing, if `map` does not describe the pad, or if the
+    /// pad can't feed the signal's input. Otherwise, writes the pad's MUX
+    /// register and the input's daisy register, or the pad's PAD register.
+    ///
+    /// # Safety
+    ///
+    /// When `map` is a chip's `REGISTER_MAP`, the caller must be running on that
+    /// chip. The caller must ensure that the writes do not race with any other
+    /// user of the pad, or of the input's daisy register.
+    pub unsafe fn apply(&self, map: &[PadRegisters]) -> Result<(), RoutingError> {
+        let pad = self.pad;
+        if !map
+            .iter()
+            .any(|entry| (entry.mux, entry.pad) == (pad.mux, pad.pad))
+        {
+            return Err(RoutingError::UnknownPad);
+        }
+        match self.command {
+            Command::Signal(signal) => {
+                let daisy = match signal.input {
+                    Some(input) => {
+                        let daisy = register_map::route(map, input, pad)?;
+                        Some(Daisy::new(daisy.reg as *mut u32, daisy.value))
+                    }
+                    None => None,
+                };
+                crate::prepare_mux(pad.mux as *mut u32, signal.alt as u32, signal.sion, daisy);
+            }
+            Command::Configure(config) => configure_register(pad.pad as *mut u32, config),
+        }
+        Ok(())
+    }
+}
+
+/// Decode the message at the start of `bytes`, then apply it
+///
+/// Returns the length of the applied message. Use the length to find the next
+/// message in a stream. Nothing is written if the message does not decode, or
+/// if `map` rejects it; see [`Message::apply()`].
+///
+/// # Safety
+///
+/// See [`Message::apply()`].
+pub unsafe fn dispatch(map: &[PadRegisters], bytes: &[u8]) -> Result<usize, DispatchError> {
+    let (message, len) = Message::decode(bytes)?;
+    message.apply(map)?;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, DecodeError, DispatchError, Message, Signal};
+    use crate::register_map::{DaisyEntry, InputSignal, PadRegisters, RoutingError};
+    use crate::{Config, PadLocation, Speed};
+
+    const PAD: PadLocation = PadLocation {
+        mux: 0x401F_80EC,
+        pad: 0x401F_82DC,
+    };
+
+    const MAP: &[PadRegisters] = &[PadRegisters {
+        name: "GPIO_AD_B0_12",
+        mux: PAD.mux,
+        pad: PAD.pad,
+        reset: None,
+        daisies: &[DaisyEntry {
+            reg: 0x401F_852C,
+            value: 1,
+        }],
+    }];
+
+    #[test]
+    fn round_trip() {
+        let messages = [
+            Message {
+                pad: PAD,
+                command: Command::Signal(Signal {
+                    alt: 2,
+                    sion: true,
+                    input: Some(InputSignal::new(0x401F_852C)),
+                }),
+            },
+            Message {
+                pad: PAD,
+                command: Command::Configure(Config::zero().set_speed(Speed::Max)),
+            },
+        ];
+        for message in &messages {
+            let mut buffer = [0; Message::MAX_LEN];
+            let len = message.encode(&mut buffer);
+            assert_eq!(Message::decode(&buffer[..len]), Ok((*message, len)));
+            assert_eq!(
+                Message::decode(&buffer[..len - 1]),
+                Err(DecodeError::Truncated)
+            );
+        }
+    }
+
+    #[test]
+    fn longest_message() {
+        let message = Message {
+            pad: PadLocation {
+                mux: u32::MAX,
+                pad: u32::MAX,
+            },
+            command: Command::Signal(Signal {
+                alt: u8::MAX,
+                sion: true,
+                input: Some(InputSignal::new(u32::MAX)),
+            }),
+        };
+        let mut buffer = [0; Message::MAX_LEN];
+        assert_eq!(message.encode(&mut buffer), Message::MAX_LEN);
+    }
+
+    #[test]
+    fn invalid_command() {
+        let mut buffer = [0; Message::MAX_LEN];
+        let len = Message {
+            pad: PAD,
+            command: Command::Configure(Config::zero()),
+        }
+        .encode(&mut buffer);
+        // The command tag follows the two five-byte location words.
+        buffer[10] = 7;
+        assert_eq!(Message::decode(&buffer[..len]), Err(DecodeError::Invalid));
+        assert_eq!(Message::decode(&[]), Err(DecodeError::Truncated));
+    }
+
+    #[test]
+    fn rejects_unknown_pads() {
+        let message = Message {
+            pad: PadLocation {
+                mux: PAD.mux,
+                pad: PAD.pad + 4,
+            },
+            command: Command::Configure(Config::zero()),
+        };
+        let mut buffer = [0; Message::MAX_LEN];
+        let len = message.encode(&mut buffer);
+        assert_eq!(
+            unsafe { super::dispatch(MAP, &buffer[..len]) },
+            Err(DispatchError::Routing(RoutingError::UnknownPad))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_inputs() {
+        let message = Message {
+            pad: PAD,
+            command: Command::Signal(Signal {
+                alt: 3,
+                sion: false,
+                input: Some(InputSignal::new(0x401F_8530)),
+            }),
+        };
+        assert_eq!(unsafe { message.apply(MAP) }, Err(RoutingError::NoRoute));
+    }
+}
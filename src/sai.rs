@@ -7,14 +7,14 @@
 //!
 //! ```
 //! use imxrt_iomuxc::sai::{Pin, TxDataSignal};
-//! use imxrt_iomuxc::consts::{U1, Unsigned};
+//! use imxrt_iomuxc::consts::{Const, Unsigned};
 //!
 //! struct SAI<U> {
 //!     /* Driver details... */
 //!     # _u: core::marker::PhantomData<U>,
 //! }
 //!
-//! type SAI1 = SAI<U1>;
+//! type SAI1 = SAI<Const<1>>;
 //!
 //! impl<U: Unsigned> SAI<U> {
 //!     fn add_tx_pin<P>(&mut self, pin: P)
@@ -79,11 +79,11 @@ impl Signal for Mclk {}
 
 impl Signal for TxData {}
 impl TxDataSignal for TxData {
-    type Index = super::consts::U0;
+    type Index = super::consts::Const<0>;
 }
 impl Signal for RxData {}
 impl RxDataSignal for RxData {
-    type Index = super::consts::U0;
+    type Index = super::consts::Const<0>;
 }
 
 impl Sealed for TxBclk {}
@@ -96,7 +96,13 @@ impl Sealed for RxData {}
 
 /// A pin that can be used for a SAI peripheral
 ///
-/// `SAIx` is a type number, like `U2`, which indicates 'SAI2'.
+/// `SAIx` is a type number, like `Const<2>`, which indicates 'SAI2'.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a pin for SAI `{SAIx}`",
+    label = "this pad does not support a signal for this SAI instance",
+    note = "the `sai::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin<SAIx: crate::consts::Unsigned>: super::Iomuxc {
     /// The alternate value for the UART pin
     const ALT: u32;
@@ -106,20 +112,104 @@ pub trait Pin<SAIx: crate::consts::Unsigned>: super::Iomuxc {
     type Signal: Signal;
 }
 
+impl<SAIx: crate::consts::Unsigned, P: Pin<SAIx>> Pin<SAIx> for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    type Signal = P::Signal;
+}
+
 /// Prepare a pad to be used as a SAI pin
 pub fn prepare<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    if let Some(daisy) = P::DAISY {
-        unsafe { daisy.write() };
-    }
+    // Safety: same justification as set_sion.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, P::DAISY) };
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a SAI pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(
+    pin: &mut P,
+    daisy: crate::DaisyChoice,
+) {
+    // Safety: same justification as prepare.
+    unsafe { super::prepare_mux(pin.mux(), P::ALT, true, daisy.resolve(P::DAISY)) };
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the SAI input to another pad after you prepared the pin.
+pub fn verify_daisy<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a SAI pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<SAIx: crate::consts::Unsigned, P: Pin<SAIx>>(
+    mut pin: P,
+) -> crate::Prepared<P::Signal, P> {
+    prepare::<SAIx, P>(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// Prepare a SAI transmitter's pins
+///
+/// Prepares the bit clock, frame sync, data, and master clock pins of one
+/// SAI. The bounds check that every pin belongs to the `SAIx` transmitter. If
+/// the MCLK isn't routed to a pad, pass a [`NoPin`](crate::NoPin).
+///
+/// ```no_run
+/// use imxrt_iomuxc::{sai, NoPin};
+/// # use imxrt_iomuxc::imxrt1060::gpio_emc::{GPIO_EMC_04, GPIO_EMC_05, GPIO_EMC_06};
+///
+/// let mut bclk = unsafe { GPIO_EMC_06::new() };
+/// let mut sync = unsafe { GPIO_EMC_05::new() };
+/// let mut data = unsafe { GPIO_EMC_04::new() };
+/// sai::prepare_tx(&mut bclk, &mut sync, &mut data, &mut NoPin::<2>::new());
+/// ```
+pub fn prepare_tx<SAIx, B, S, D, M>(bclk: &mut B, sync: &mut S, data: &mut D, mclk: &mut M)
+where
+    SAIx: crate::consts::Unsigned,
+    B: Pin<SAIx, Signal = TxBclk>,
+    S: Pin<SAIx, Signal = TxSync>,
+    D: Pin<SAIx>,
+    D::Signal: TxDataSignal,
+    M: Pin<SAIx, Signal = Mclk>,
+{
+    prepare::<SAIx, _>(bclk);
+    prepare::<SAIx, _>(sync);
+    prepare::<SAIx, _>(data);
+    prepare::<SAIx, _>(mclk);
+}
+
+/// Prepare a SAI receiver's pins
+///
+/// Like [`prepare_tx()`], for the `SAIx` receiver.
+pub fn prepare_rx<SAIx, B, S, D, M>(bclk: &mut B, sync: &mut S, data: &mut D, mclk: &mut M)
+where
+    SAIx: crate::consts::Unsigned,
+    B: Pin<SAIx, Signal = RxBclk>,
+    S: Pin<SAIx, Signal = RxSync>,
+    D: Pin<SAIx>,
+    D::Signal: RxDataSignal,
+    M: Pin<SAIx, Signal = Mclk>,
+{
+    prepare::<SAIx, _>(bclk);
+    prepare::<SAIx, _>(sync);
+    prepare::<SAIx, _>(data);
+    prepare::<SAIx, _>(mclk);
 }
 
 /// Defines an SAI pin
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! sai {
-    (module: $m:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
-        impl Pin<$m> for $pad {
+    (module: $m:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
+        impl Pin<crate::consts::Const<$m>> for $pad {
             const ALT: u32 = $alt;
             type Signal = $signal;
             const DAISY: Option<Daisy> = $daisy;
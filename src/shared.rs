@@ -0,0 +1,199 @@
+//! Time-multiplexed pads.
+
+use crate::{private, Config, Iomuxc, OutputCapable};
+use core::{
+    cell::{Cell, UnsafeCell},
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+/// A pad that's shared by more than one driver
+///
+/// `Shared` owns a pad, and lends it out through [`lease()`](Shared::lease).
+/// Only one lease may exist at a time. When a lease starts, `Shared` saves
+/// the pad's mux and pad configuration registers. When the lease ends, `Shared`
+/// restores those registers.
+///
+/// `Shared` does not save or restore daisy registers. If the pad is selected as a
+/// peripheral input during the lease, that selection remains after the lease ends.
+///
+/// # Example
+///
+/// Use a pad as a SPI chip select, then briefly lend it out as a GPIO.
+///
+/// ```no_run
+/// use imxrt_iomuxc::{gpio, lpspi, Shared};
+/// # use imxrt_iomuxc::imxrt1060::gpio_b0::GPIO_B0_00;
+///
+/// let pcs0 = Shared::new(unsafe { GPIO_B0_00::new() });
+///
+/// let mut spi_lease = pcs0.lease().unwrap();
+/// lpspi::prepare(&mut *spi_lease);
+/// // Only one lease at a time...
+/// assert!(pcs0.lease().is_none());
+/// drop(spi_lease);
+///
+/// let mut led_lease = pcs0.lease().unwrap();
+/// gpio::prepare(&mut *led_lease);
+/// // Dropping the lease restores the previous configuration...
+/// ```
+#[derive(Debug)]
+pub struct Shared<P> {
+    pad: UnsafeCell<P>,
+    leased: Cell<bool>,
+}
+
+impl<P: Iomuxc> Shared<P> {
+    /// Share a pad
+    pub const fn new(pad: P) -> Self {
+        Self {
+            pad: UnsafeCell::new(pad),
+            leased: Cell::new(false),
+        }
+    }
+
+    /// Lease the pad
+    ///
+    /// Returns `None` if the pad is already leased.
+    pub fn lease(&self) -> Option<Lease<'_, P>> {
+        if self.leased.replace(true) {
+            return None;
+        }
+        // Safety: the leased flag guarantees that this is the only
+        // reference to the pad.
+        let pad = unsafe { &mut *self.pad.get() };
+        let (mux_reg, pad_reg) = (pad.mux(), pad.pad());
+        // Safety: see the justification in set_sion.
+        let (mux, cfg) = unsafe { (ptr::read_volatile(mux_reg), ptr::read_volatile(pad_reg)) };
+        Some(Lease {
+            shared: self,
+            mux_reg,
+            pad_reg,
+            mux,
+            cfg,
+        })
+    }
+
+    /// Returns `true` if the pad is currently leased
+    pub fn is_leased(&self) -> bool {
+        self.leased.get()
+    }
+
+    /// Release the pad
+    pub fn into_inner(self) -> P {
+        self.pad.into_inner()
+    }
+}
+
+/// An exclusive lease of a [`Shared`] pad
+///
+/// Dereference the lease to use the pad's pin traits. Dropping
+/// the lease restores the pad's configuration.
+///
+/// The lease records the pad's registers when it starts, and restores those
+/// registers, even if the leased pad is replaced through the lease.
+#[derive(Debug)]
+pub struct Lease<'a, P: Iomuxc> {
+    shared: &'a Shared<P>,
+    mux_reg: *mut u32,
+    pad_reg: *mut u32,
+    mux: u32,
+    cfg: u32,
+}
+
+impl<P: Iomuxc> Deref for Lease<'_, P> {
+    type Target = P;
+    fn deref(&self) -> &P {
+        // Safety: the lease is the only reference to the pad.
+        unsafe { &*self.shared.pad.get() }
+    }
+}
+
+impl<P: Iomuxc> DerefMut for Lease<'_, P> {
+    fn deref_mut(&mut self) -> &mut P {
+        // Safety: the lease is the only reference to the pad.
+        unsafe { &mut *self.shared.pad.get() }
+    }
+}
+
+impl<P: Iomuxc> private::Sealed for Lease<'_, P> {}
+
+// Safety: the lease uses the registers of the pad that it leased.
+unsafe impl<P: Iomuxc> Iomuxc for Lease<'_, P> {
+    #[inline(always)]
+    fn mux(&mut self) -> *mut u32 {
+        self.mux_reg
+    }
+
+    #[inline(always)]
+    fn pad(&mut self) -> *mut u32 {
+        self.pad_reg
+    }
+}
+
+impl<P: OutputCapable> OutputCapable for Lease<'_, P> {}
+
+impl<P: Iomuxc> Drop for Lease<'_, P> {
+    fn drop(&mut self) {
+        // Safety: see the justification in set_sion.
+        unsafe {
+            crate::modify_mux(self.mux_reg, !0, self.mux);
+            crate::config::configure_register(
+                self.pad_reg,
+                Config {
+                    value: self.cfg,
+                    mask: !0,
+                },
+            );
+        }
+        self.shared.leased.set(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shared;
+    use crate::{alternate, configure, set_sion, Alternate, Config, ErasedPad, NoPin, PullKeeper};
+
+    #[test]
+    fn restore_on_release() {
+        let mut pin = NoPin::<1>::new();
+        alternate(&mut pin, Alternate::<5>);
+        let shared = Shared::new(pin);
+
+        let mut lease = shared.lease().unwrap();
+        assert!(shared.is_leased());
+        assert!(shared.lease().is_none());
+        let saved = (lease.mux, lease.cfg);
+        assert_eq!(saved, (5, 0));
+
+        set_sion(&mut lease);
+        configure(
+            &mut lease,
+            Config::zero().set_pull_keeper(Some(PullKeeper::Pullup22k)),
+        );
+        drop(lease);
+        assert!(!shared.is_leased());
+
+        let lease = shared.lease().unwrap();
+        assert_eq!((lease.mux, lease.cfg), saved);
+    }
+
+    #[test]
+    fn restore_swapped_pad() {
+        let (mut mux, mut pad) = (5u32, 0u32);
+        let (mut other_mux, mut other_pad) = (1u32, 0u32);
+        let shared = Shared::new(unsafe { ErasedPad::new(&mut mux, &mut pad) });
+        let mut other = unsafe { ErasedPad::new(&mut other_mux, &mut other_pad) };
+
+        let mut lease = shared.lease().unwrap();
+        core::mem::swap(&mut *lease, &mut other);
+        alternate(&mut lease, Alternate::<3>);
+        alternate(&mut *lease, Alternate::<2>);
+        drop(lease);
+        let _ = (shared, other);
+
+        assert_eq!((mux, pad), (5, 0));
+        assert_eq!((other_mux, other_pad), (2, 0));
+    }
+}
@@ -0,0 +1,129 @@
+//! Every alternate and select input of a chip, as data.
+//!
+//! The pin traits only describe the peripherals that have trait modules. A
+//! signal [`Database`] describes every alternate function of every pad, and
+//! every select input value, whether or not the package has a trait for it.
+//! Use it for tools, or to mux a pad by signal name at run time.
+//!
+//! The `signals.py` script generates a database from an SVD, or from a pad
+//! description. Each chip module exports its generated `DATABASE`, like
+//! `imxrt1060::DATABASE`. When you generate pads from an SVD with
+//! `IMXRT_IOMUXC_SVD`, the generated `svd` module includes its `DATABASE`.
+//!
+//! ```
+//! use imxrt_iomuxc::{signals::{Alternate, Database}, PadLocation};
+//!
+//! const PAD: PadLocation = PadLocation { mux: 0x401F_80EC, pad: 0x401F_82DC };
+//! const DATABASE: Database = Database {
+//!     alternates: &[Alternate { pad: PAD, alt: 2, signal: "LPUART2_TX" }],
+//!     inputs: &[],
+//! };
+//!
+//! assert_eq!(DATABASE.find_alternate(PAD, "LPUART2_TX"), Some(2));
+//! ```
+
+use crate::{Daisy, PadLocation};
+
+/// An alternate function of a pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alternate {
+    /// The pad
+    pub pad: PadLocation,
+    /// The MUX_MODE value that selects the signal
+    pub alt: u32,
+    /// The signal name from the reference manual, like `"LPUART2_TX"`
+    pub signal: &'static str,
+}
+
+/// A select input value that routes a pad to a peripheral input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectInput {
+    /// The select input register name, without `_SELECT_INPUT`
+    pub input: &'static str,
+    /// The select input register address
+    pub register: u32,
+    /// The pad that the value selects
+    pub pad: PadLocation,
+    /// The value that selects the pad
+    pub value: u32,
+}
+
+impl SelectInput {
+    /// Returns the daisy that writes this select input
+    pub const fn daisy(&self) -> Daisy {
+        Daisy::new(self.register as *mut u32, self.value)
+    }
+}
+
+/// All alternates and select inputs of a chip
+#[derive(Debug, Clone, Copy)]
+pub struct Database {
+    /// Every alternate of every pad
+    pub alternates: &'static [Alternate],
+    /// Every select input value
+    pub inputs: &'static [SelectInput],
+}
+
+impl Database {
+    /// Returns the alternates of a pad
+    pub fn alternates_of(&self, pad: PadLocation) -> impl Iterator<Item = &'static Alternate> {
+        self.alternates.iter().filter(move |alt| alt.pad == pad)
+    }
+
+    /// Returns the alternate that selects `signal` on a pad
+    pub fn find_alternate(&self, pad: PadLocation, signal: &str) -> Option<u32> {
+        self.alternates_of(pad)
+            .find(|alt| alt.signal == signal)
+            .map(|alt| alt.alt)
+    }
+
+    /// Returns the select inputs that a pad can feed
+    pub fn inputs_of(&self, pad: PadLocation) -> impl Iterator<Item = &'static SelectInput> {
+        self.inputs.iter().filter(move |input| input.pad == pad)
+    }
+
+    /// Returns the select input value that routes a pad to `input`
+    pub fn find_input(&self, pad: PadLocation, input: &str) -> Option<SelectInput> {
+        self.inputs_of(pad).find(|sel| sel.input == input).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alternate, Database, SelectInput};
+    use crate::PadLocation;
+
+    const PAD: PadLocation = PadLocation { mux: 4, pad: 8 };
+    const OTHER: PadLocation = PadLocation { mux: 12, pad: 16 };
+
+    const DATABASE: Database = Database {
+        alternates: &[
+            Alternate {
+                pad: PAD,
+                alt: 2,
+                signal: "LPUART1_RX",
+            },
+            Alternate {
+                pad: OTHER,
+                alt: 3,
+                signal: "LPUART1_RX",
+            },
+        ],
+        inputs: &[SelectInput {
+            input: "LPUART1_RX",
+            register: 0x100,
+            pad: OTHER,
+            value: 1,
+        }],
+    };
+
+    #[test]
+    fn lookup() {
+        assert_eq!(DATABASE.alternates_of(PAD).count(), 1);
+        assert_eq!(DATABASE.find_alternate(OTHER, "LPUART1_RX"), Some(3));
+        assert_eq!(DATABASE.find_alternate(OTHER, "LPUART1_TX"), None);
+        assert!(DATABASE.find_input(PAD, "LPUART1_RX").is_none());
+        let input = DATABASE.find_input(OTHER, "LPUART1_RX").unwrap();
+        assert_eq!(input.daisy(), crate::Daisy::new(0x100 as *mut u32, 1));
+    }
+}
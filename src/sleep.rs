@@ -0,0 +1,135 @@
+//! Pull to keeper transitions around low-power modes
+//!
+//! [`enter()`] saves the PAD registers of a group of pads, then switches every pad
+//! to its keeper. The returned [`Sleep`] guard holds the pads. When the system
+//! wakes, [`Sleep::exit()`] restores each pad's saved configuration. Dropping the
+//! guard also restores the pads.
+//!
+//! The guard borrows the pads, so no other code can change them while they're
+//! switched. The guard does not mask interrupts; if an interrupt handler
+//! configures these pads through `unsafe` code, enter and exit in a critical
+//! section.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{sleep, imxrt1060::gpio_ad_b0::{GPIO_AD_B0_02, GPIO_AD_B0_03}};
+//!
+//! let mut pads = unsafe { [GPIO_AD_B0_02::new().erase(), GPIO_AD_B0_03::new().erase()] };
+//!
+//! let asleep = sleep::enter(&mut pads);
+//! // Enter the low-power mode, and wait to wake...
+//! asleep.exit();
+//! ```
+
+use crate::{config::configure_register, Config, Iomuxc, PullKeeper};
+use core::ptr;
+
+/// The configuration applied to each pad on [`enter()`]
+const KEEPER: Config = Config::modify().set_pull_keeper(Some(PullKeeper::Keeper));
+
+/// Save the pads' configurations, then switch them to their keepers
+///
+/// Only the pull / keeper fields change. The other fields keep their values.
+pub fn enter<P: Iomuxc, const N: usize>(pads: &mut [P; N]) -> Sleep<'_, P, N> {
+    let mut saved = [0; N];
+    for (pad, saved) in pads.iter_mut().zip(&mut saved) {
+        // Safety: see the justification in set_sion.
+        unsafe {
+            *saved = ptr::read_volatile(pad.pad());
+            configure_register(pad.pad(), KEEPER);
+        }
+    }
+    Sleep { pads, saved }
+}
+
+/// Pads switched to their keepers
+///
+/// Call [`exit()`](Self::exit), or drop the guard, to restore the pads.
+#[derive(Debug)]
+pub struct Sleep<'a, P: Iomuxc, const N: usize> {
+    pads: &'a mut [P; N],
+    saved: [u32; N],
+}
+
+impl<P: Iomuxc, const N: usize> Sleep<'_, P, N> {
+    /// Returns the PAD register values saved by [`enter()`]
+    pub fn saved(&self) -> &[u32; N] {
+        &self.saved
+    }
+
+    /// Restore the pads' saved configurations
+    pub fn exit(self) {}
+}
+
+impl<P: Iomuxc, const N: usize> Drop for Sleep<'_, P, N> {
+    fn drop(&mut self) {
+        for (pad, saved) in self.pads.iter_mut().zip(&self.saved) {
+            // Safety: see the justification in set_sion.
+            unsafe {
+                configure_register(
+                    pad.pad(),
+                    Config {
+                        value: *saved,
+                        mask: !0,
+                    },
+                )
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::enter;
+    use crate::{configure, Config, Iomuxc, NoPin, PullKeeper, SlewRate};
+
+    #[test]
+    fn keeper_then_restore() {
+        const AWAKE: Config = Config::zero()
+            .set_pull_keeper(Some(PullKeeper::Pullup22k))
+            .set_slew_rate(SlewRate::Fast);
+        let mut pads = [NoPin::<1>::new(), NoPin::<1>::new()];
+        for pad in &mut pads {
+            configure(pad, AWAKE);
+        }
+
+        let asleep = enter(&mut pads);
+        assert_eq!(asleep.saved(), &[AWAKE.value(); 2]);
+        let keeper = Config::zero()
+            .set_pull_keeper(Some(PullKeeper::Keeper))
+            .set_slew_rate(SlewRate::Fast);
+        for pad in asleep.pads.iter_mut() {
+            assert_eq!(unsafe { pad.pad().read() }, keeper.value());
+        }
+        asleep.exit();
+
+        for pad in &mut pads {
+            assert_eq!(unsafe { pad.pad().read() }, AWAKE.value());
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "is locked")]
+    fn exit_checks_locks() {
+        use crate::{lock, PadLocation};
+
+        /// Unlocks the pad when dropped, even when the test panics
+        struct Unlock(PadLocation);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                lock::unlock(self.0);
+            }
+        }
+
+        let mut pads = [NoPin::<1>::new()];
+        let asleep = enter(&mut pads);
+        let location = PadLocation {
+            mux: asleep.pads[0].mux() as u32,
+            pad: asleep.pads[0].pad() as u32,
+        };
+        // The stack addresses may belong to another test's pad later.
+        let _unlock = Unlock(location);
+        lock::lock(location).unwrap();
+        asleep.exit();
+    }
+}
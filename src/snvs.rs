@@ -0,0 +1,113 @@
+//! SNVS tamper pad configuration
+//!
+//! The SNVS_LP tamper detectors watch a set of tamper pads. A passive tamper
+//! input detects a level change, like a case switch opening. An active tamper
+//! output drives a pattern through a mesh, and an active tamper input checks
+//! that the pattern comes back.
+//!
+//! A tamper pad implements [`Pin`]. Its `Direction` is [`TamperIn`] or
+//! [`TamperOut`], and `TAMPER` is the tamper channel. The SNVS selects the
+//! mesh's pattern and channels; group an active mesh's pads in an [`ActivePair`]
+//! to prepare them together.
+//!
+//! No chip module implements `Pin` yet. The 1170's SNVS pads aren't in its pad
+//! modules, so define a tamper pad with [`define_pad!`](crate::define_pad):
+//!
+//! ```
+//! use imxrt_iomuxc::{define_pad, snvs};
+//!
+//! define_pad! {
+//!     /// A pad with a tamper input.
+//!     pub struct TAMPER_PAD { mux: 0x4000_0000, pad: 0x4000_0040 }
+//! }
+//!
+//! impl snvs::Pin for TAMPER_PAD {
+//!     const ALT: u32 = 0;
+//!     const TAMPER: u8 = 2;
+//!     type Direction = snvs::TamperIn;
+//! }
+//!
+//! fn tamper_channel<P: snvs::Pin<Direction = snvs::TamperIn>>(_: &P) -> u8 {
+//!     P::TAMPER
+//! }
+//!
+//! let pad = unsafe { TAMPER_PAD::new() };
+//! assert_eq!(tamper_channel(&pad), 2);
+//! ```
+
+/// Type tag for a tamper input
+pub enum TamperIn {}
+/// Type tag for an active tamper output
+pub enum TamperOut {}
+
+/// A tamper pin direction: input or output
+pub trait Direction: private::Sealed {}
+
+impl Direction for TamperIn {}
+impl Direction for TamperOut {}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::TamperIn {}
+    impl Sealed for super::TamperOut {}
+}
+
+/// An SNVS tamper pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an SNVS tamper pin",
+    label = "this pad does not support any tamper signal",
+    note = "the `snvs::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
+pub trait Pin: super::Iomuxc {
+    /// The alternate value for the tamper pin
+    const ALT: u32;
+    /// The tamper channel; `2` for tamper input 2
+    const TAMPER: u8;
+    /// Pin direction
+    type Direction: Direction;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const TAMPER: u8 = P::TAMPER;
+    type Direction = P::Direction;
+}
+
+/// Prepare a tamper pin
+///
+/// # Safety
+///
+/// `prepare()` inherits all the unsafety that comes from the `IOMUX` supertrait.
+/// In particular, we cannot be sure that the implementation's pointers are correct.
+pub fn prepare<P: Pin>(pin: &mut P) {
+    super::alternate_raw(pin, P::ALT);
+}
+
+/// The output and input of an active tamper mesh
+///
+/// The output drives the mesh, and the input receives it.
+#[derive(Debug)]
+pub struct ActivePair<O, I> {
+    /// Active tamper output
+    pub output: O,
+    /// Active tamper input
+    pub input: I,
+}
+
+impl<O, I> ActivePair<O, I>
+where
+    O: Pin<Direction = TamperOut>,
+    I: Pin<Direction = TamperIn>,
+{
+    /// Group the mesh's pins
+    pub const fn new(output: O, input: I) -> Self {
+        Self { output, input }
+    }
+
+    /// Prepare both pins
+    pub fn prepare(&mut self) {
+        prepare(&mut self.output);
+        prepare(&mut self.input);
+    }
+}
@@ -0,0 +1,259 @@
+//! Precomputed pad register images.
+
+use crate::{Config, Daisy, PadLocation, ALT_MASK, SION_BIT};
+use core::ptr;
+
+/// A pad's MUX and PAD register values
+///
+/// Create images in a `const` context, and collect them in a [`PadTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PadImage {
+    /// The pad's registers
+    pub location: PadLocation,
+    /// The MUX register value
+    pub mux: u32,
+    /// The PAD register value
+    pub pad: u32,
+}
+
+impl PadImage {
+    /// Compute the image for a pad's alternate, SION, and configuration
+    ///
+    /// The image replaces the whole PAD register. Fields that `config` does
+    /// not set are written as zero, like a [`Config::zero()`] configuration.
+    pub const fn new(location: PadLocation, alt: u32, sion: bool, config: Config) -> Self {
+        Self {
+            location,
+            mux: (alt & ALT_MASK) | if sion { SION_BIT } else { 0 },
+            pad: config.value,
+        }
+    }
+}
+
+/// A named group of pad images, and the daisy values they need
+///
+/// A `PadTable` describes a boot-time pin configuration as data. Each
+/// [`apply()`](Self::apply) writes every image, then every daisy value, without
+/// reading any register.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::{lpuart, Config, PadImage, PadTable, PullKeeper};
+/// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::{GPIO_AD_B0_12, GPIO_AD_B0_13};
+///
+/// const UART: Config = Config::zero().set_pull_keeper(Some(PullKeeper::Pullup100k));
+///
+/// const LPUART1: PadTable = PadTable {
+///     name: "lpuart1",
+///     pads: &[
+///         PadImage::new(
+///             GPIO_AD_B0_12::LOCATION,
+///             <GPIO_AD_B0_12 as lpuart::Pin>::ALT,
+///             false,
+///             UART,
+///         ),
+///         PadImage::new(
+///             GPIO_AD_B0_13::LOCATION,
+///             <GPIO_AD_B0_13 as lpuart::Pin>::ALT,
+///             false,
+///             UART,
+///         ),
+///     ],
+///     daisies: &[],
+/// };
+///
+/// // Safety: the LPUART1 pads aren't used anywhere else.
+/// unsafe { LPUART1.apply() };
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PadTable<'a> {
+    /// The table's name, for diagnostics
+    pub name: &'a str,
+    /// The pad images
+    pub pads: &'a [PadImage],
+    /// The daisy values
+    pub daisies: &'a [Daisy],
+}
+
+/// A register address and the value to write
+///
+/// The C layout matches a table of `(address, value)` word pairs, like a DCD
+/// write command or a DMA scatter list.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterWrite {
+    /// The register address
+    pub address: u32,
+    /// The register value
+    pub value: u32,
+}
+
+impl<'a> PadTable<'a> {
+    /// The number of register writes that describe this table
+    pub const fn write_count(&self) -> usize {
+        2 * self.pads.len() + self.daisies.len()
+    }
+
+    /// Returns the table's register writes, in [`apply()`](Self::apply) order
+    ///
+    /// Each image produces its MUX write, then its PAD write. The daisy writes
+    /// follow the images. Collect the writes to hand them to another
+    /// register writer, like a DMA engine, a boot ROM, or a debugger script.
+    ///
+    /// ```
+    /// use imxrt_iomuxc::{Config, PadImage, PadTable, RegisterWrite};
+    /// use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_12;
+    ///
+    /// const TABLE: PadTable = PadTable {
+    ///     name: "gpio",
+    ///     pads: &[PadImage::new(GPIO_AD_B0_12::LOCATION, 5, false, Config::zero())],
+    ///     daisies: &[],
+    /// };
+    ///
+    /// let mut writes = [RegisterWrite { address: 0, value: 0 }; TABLE.write_count()];
+    /// for (slot, write) in writes.iter_mut().zip(TABLE.writes()) {
+    ///     *slot = write;
+    /// }
+    /// assert_eq!(writes[0], RegisterWrite { address: 0x401F_80EC, value: 5 });
+    /// ```
+    pub fn writes(&self) -> impl Iterator<Item = RegisterWrite> + 'a {
+        let pads = self.pads.iter().flat_map(|image| {
+            [
+                RegisterWrite {
+                    address: image.location.mux,
+                    value: image.mux,
+                },
+                RegisterWrite {
+                    address: image.location.pad,
+                    value: image.pad,
+                },
+            ]
+        });
+        let daisies = self.daisies.iter().map(|daisy| RegisterWrite {
+            address: daisy.reg as usize as u32,
+            value: daisy.value,
+        });
+        pads.chain(daisies)
+    }
+
+    /// Write the table's pad images and daisy values
+    ///
+    /// `apply()` only reads the table, and only writes IOMUXC registers. It may
+    /// run before static initialization, as long as the table is in memory
+    /// that's ready, like flash. See [`pre_init_pads!`](crate::pre_init_pads).
+    /// For the same reason, the `log` and `defmt` features don't record its
+    /// writes.
+    ///
+    /// # Safety
+    ///
+    /// The images' locations must be valid MUX and PAD registers. The table writes
+    /// registers without owning their pads, so the caller must ensure that no other
+    /// code uses those pads, or their daisy registers, during or after the write.
+    pub unsafe fn apply(&self) {
+        for image in self.pads {
+            ptr::write_volatile(image.location.mux as *mut u32, image.mux);
+            ptr::write_volatile(image.location.pad as *mut u32, image.pad);
+        }
+        for daisy in self.daisies {
+            ptr::write_volatile(daisy.reg, daisy.value);
+        }
+    }
+}
+
+/// Apply pad tables before static initialization
+///
+/// The macro defines the `__pre_init` function, which `cortex-m-rt` calls
+/// before it initializes `.data` and `.bss`. The function applies each
+/// [`PadTable`] in order. Use this for boards whose `.data` and `.bss` are in
+/// external memory, like SEMC SDRAM, to configure the memory bus pads before
+/// the runtime touches that memory.
+///
+/// The tables should be `const`s so that their data stays in flash. The
+/// function must not use static variables, so the macro only accepts tables.
+/// A program may only define one `__pre_init` function.
+///
+/// Pads are only part of the memory bring-up. Your program still needs to
+/// configure the clocks and memory controller before it uses external memory.
+///
+/// # Example
+///
+/// ```no_run
+/// use imxrt_iomuxc::{Config, PadImage, PadTable};
+/// use imxrt_iomuxc::imxrt1060::gpio_emc::{GPIO_EMC_00, GPIO_EMC_01};
+///
+/// const SEMC: Config = Config::zero();
+/// const SEMC_PADS: PadTable = PadTable {
+///     name: "semc",
+///     pads: &[
+///         PadImage::new(GPIO_EMC_00::LOCATION, 0, false, SEMC),
+///         PadImage::new(GPIO_EMC_01::LOCATION, 0, false, SEMC),
+///         // ...
+///     ],
+///     daisies: &[],
+/// };
+///
+/// imxrt_iomuxc::pre_init_pads!(SEMC_PADS);
+/// ```
+#[macro_export]
+macro_rules! pre_init_pads {
+    ($($table:expr),+ $(,)?) => {
+        #[no_mangle]
+        pub unsafe extern "C" fn __pre_init() {
+            $($crate::PadTable::apply(&$table);)+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PadImage, PadTable, RegisterWrite};
+    use crate::{Config, Daisy, PadLocation, SlewRate, SION_BIT};
+
+    #[test]
+    fn image_values_and_writes() {
+        const LOCATION: PadLocation = PadLocation {
+            mux: 0x401F_80EC,
+            pad: 0x401F_82DC,
+        };
+        const IMAGE: PadImage = PadImage::new(
+            LOCATION,
+            3,
+            true,
+            Config::modify().set_slew_rate(SlewRate::Fast),
+        );
+        assert_eq!(IMAGE.location, LOCATION);
+        assert_eq!(IMAGE.mux, SION_BIT | 3);
+        assert_eq!(IMAGE.pad, SlewRate::Fast as u32);
+
+        const TABLE: PadTable = PadTable {
+            name: "test",
+            pads: &[IMAGE],
+            daisies: &[Daisy::new(0x401F_852C as *mut u32, 1)],
+        };
+        let mut writes = TABLE.writes();
+        assert_eq!(TABLE.write_count(), 3);
+        assert_eq!(
+            writes.next(),
+            Some(RegisterWrite {
+                address: LOCATION.mux,
+                value: SION_BIT | 3
+            })
+        );
+        assert_eq!(
+            writes.next(),
+            Some(RegisterWrite {
+                address: LOCATION.pad,
+                value: SlewRate::Fast as u32
+            })
+        );
+        assert_eq!(
+            writes.next(),
+            Some(RegisterWrite {
+                address: 0x401F_852C,
+                value: 1
+            })
+        );
+        assert_eq!(writes.next(), None);
+    }
+}
@@ -0,0 +1,456 @@
+//! Pad configurations as text
+//!
+//! [`parse()`] turns a comma-separated list of settings into a [`Config`].
+//! [`ConfigText`] writes a configuration in the same format. Use them in a
+//! debug console to inspect and change pad settings without reflashing.
+//!
+//! ```
+//! use imxrt_iomuxc::{text, Config, DriveStrength, Hysteresis, PullKeeper, SlewRate};
+//!
+//! let config = text::parse("pullup-47k,hys,drive-r0-6,fast").unwrap();
+//! assert_eq!(
+//!     config,
+//!     Config::modify()
+//!         .set_pull_keeper(Some(PullKeeper::Pullup47k))
+//!         .set_hysteresis(Hysteresis::Enabled)
+//!         .set_drive_strength(DriveStrength::R0_6)
+//!         .set_slew_rate(SlewRate::Fast)
+//! );
+//!
+//! let text = format!("{}", text::ConfigText(config));
+//! assert_eq!(text, "hys,pullup-47k,drive-r0-6,fast");
+//! assert_eq!(text::parse(&text), Ok(config));
+//! ```
+//!
+//! Each setting changes one field:
+//!
+//! | Field          | Settings                                                        |
+//! | -------------- | --------------------------------------------------------------- |
+//! | Hysteresis     | `hys`, `no-hys`                                                 |
+//! | Pull / keeper  | `pulldown-100k`, `pullup-22k`, `pullup-47k`, `pullup-100k`, `keeper`, `no-pull` |
+//! | Open drain     | `open-drain`, `push-pull`                                       |
+//! | Speed          | `speed-low`, `speed-medium`, `speed-fast`, `speed-max`          |
+//! | Drive strength | `drive-off`, `drive-r0`, `drive-r0-2`, ..., `drive-r0-7`        |
+//! | Slew rate      | `fast`, `slow`                                                  |
+//!
+//! A configuration only changes the fields it names, like
+//! [`Config::modify()`]. Add `zero` to zero every other field, like
+//! [`Config::zero()`]. When a list names a field more than once, the last
+//! setting wins.
+//!
+//! [`write_pinout()`] writes a table of a chip's pads, with each pad's
+//! alternate and configuration in this format.
+//!
+//! With the `ufmt` feature, [`Config`] and its field enums implement
+//! `uDisplay`, and write the same settings without `core::fmt`.
+
+use crate::config::{fields, from_register, Fields};
+use crate::register_map::PadRegisters;
+use crate::signals::Database;
+use crate::{
+    Config, DriveStrength, Hysteresis, OpenDrain, PadLocation, PullKeeper, SlewRate, Speed,
+    ALT_MASK, SION_BIT,
+};
+use core::fmt;
+
+/// A setting that [`parse()`] doesn't recognize
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError<'a> {
+    /// The unrecognized setting
+    pub token: &'a str,
+}
+
+impl fmt::Display for ParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown pad setting '{}'", self.token)
+    }
+}
+
+const PULL_KEEPERS: &[(&str, Option<PullKeeper>)] = &[
+    ("pulldown-100k", Some(PullKeeper::Pulldown100k)),
+    ("pullup-22k", Some(PullKeeper::Pullup22k)),
+    ("pullup-47k", Some(PullKeeper::Pullup47k)),
+    ("pullup-100k", Some(PullKeeper::Pullup100k)),
+    ("keeper", Some(PullKeeper::Keeper)),
+    ("no-pull", None),
+];
+
+const SPEEDS: &[(&str, Speed)] = &[
+    ("speed-low", Speed::Low),
+    ("speed-medium", Speed::Medium),
+    ("speed-fast", Speed::Fast),
+    ("speed-max", Speed::Max),
+];
+
+const DRIVE_STRENGTHS: &[(&str, DriveStrength)] = &[
+    ("drive-off", DriveStrength::Disabled),
+    ("drive-r0", DriveStrength::R0),
+    ("drive-r0-2", DriveStrength::R0_2),
+    ("drive-r0-3", DriveStrength::R0_3),
+    ("drive-r0-4", DriveStrength::R0_4),
+    ("drive-r0-5", DriveStrength::R0_5),
+    ("drive-r0-6", DriveStrength::R0_6),
+    ("drive-r0-7", DriveStrength::R0_7),
+];
+
+/// Returns the name of `value` in `names`
+fn name_of<T: PartialEq>(names: &[(&'static str, T)], value: T) -> &'static str {
+    names
+        .iter()
+        .find(|(_, candidate)| *candidate == value)
+        .map(|(name, _)| *name)
+        .unwrap_or_default()
+}
+
+/// Returns the value named `token` in `names`
+fn value_of<T: Copy>(names: &[(&str, T)], token: &str) -> Option<T> {
+    names
+        .iter()
+        .find(|(name, _)| *name == token)
+        .map(|(_, value)| *value)
+}
+
+/// Parse a comma-separated list of pad settings
+///
+/// Whitespace around each setting is ignored, and so are empty settings. See
+/// the [module documentation](self) for the settings.
+pub fn parse(text: &str) -> Result<Config, ParseError<'_>> {
+    let mut config = Config::modify();
+    let mut zero = false;
+    for token in text.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        config = match token {
+            "zero" => {
+                zero = true;
+                config
+            }
+            "hys" => config.set_hysteresis(Hysteresis::Enabled),
+            "no-hys" => config.set_hysteresis(Hysteresis::Disabled),
+            "open-drain" => config.set_open_drain(OpenDrain::Enabled),
+            "push-pull" => config.set_open_drain(OpenDrain::Disabled),
+            "fast" => config.set_slew_rate(SlewRate::Fast),
+            "slow" => config.set_slew_rate(SlewRate::Slow),
+            token => {
+                if let Some(pk) = value_of(PULL_KEEPERS, token) {
+                    config.set_pull_keeper(pk)
+                } else if let Some(speed) = value_of(SPEEDS, token) {
+                    config.set_speed(speed)
+                } else if let Some(dse) = value_of(DRIVE_STRENGTHS, token) {
+                    config.set_drive_strength(dse)
+                } else {
+                    return Err(ParseError { token });
+                }
+            }
+        };
+    }
+    Ok(if zero {
+        Config::zero().merge(config)
+    } else {
+        config
+    })
+}
+
+/// Writes a configuration as a list of pad settings
+///
+/// The text names each field that the configuration sets, and [`parse()`]
+/// turns it back into the same configuration. A [`Config::zero()`]
+/// configuration starts with `zero`, and names every field.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigText(pub Config);
+
+impl fmt::Display for ConfigText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for setting in settings(self.0).into_iter().flatten() {
+            write!(f, "{separator}{setting}")?;
+            separator = ",";
+        }
+        Ok(())
+    }
+}
+
+/// Returns the names of the configuration's settings, in text order
+fn settings(config: Config) -> [Option<&'static str>; 7] {
+    let Fields {
+        hysteresis,
+        pull_keeper,
+        open_drain,
+        speed,
+        drive_strength,
+        slew_rate,
+    } = fields(config);
+    [
+        config.is_zero().then_some("zero"),
+        hysteresis.map(hysteresis_name),
+        pull_keeper.map(|pk| name_of(PULL_KEEPERS, pk)),
+        open_drain.map(open_drain_name),
+        speed.map(|speed| name_of(SPEEDS, speed)),
+        drive_strength.map(|dse| name_of(DRIVE_STRENGTHS, dse)),
+        slew_rate.map(slew_rate_name),
+    ]
+}
+
+fn hysteresis_name(hys: Hysteresis) -> &'static str {
+    match hys {
+        Hysteresis::Enabled => "hys",
+        Hysteresis::Disabled => "no-hys",
+    }
+}
+
+fn open_drain_name(od: OpenDrain) -> &'static str {
+    match od {
+        OpenDrain::Enabled => "open-drain",
+        OpenDrain::Disabled => "push-pull",
+    }
+}
+
+fn slew_rate_name(sre: SlewRate) -> &'static str {
+    match sre {
+        SlewRate::Fast => "fast",
+        SlewRate::Slow => "slow",
+    }
+}
+
+/// Write a table of the pads in `map`
+///
+/// Each row has the pad's name, alternate, SION bit, signal, and configuration.
+/// The signal comes from `database`, if you have one for the chip; otherwise, or
+/// if the database doesn't name the alternate, the signal is `-`.
+///
+/// ```text
+/// PAD              ALT SION SIGNAL           CONFIG
+/// GPIO_AD_B0_12      2 no   LPUART1_TX       no-hys,pullup-100k,push-pull,speed-medium,drive-r0-6,fast
+/// ```
+///
+/// # Safety
+///
+/// Reads every MUX and PAD register described by `map`. The addresses must be
+/// valid for reads. When `map` is a chip's `REGISTER_MAP`, the caller must be
+/// running on that chip.
+pub unsafe fn write_pinout<W: fmt::Write>(
+    out: &mut W,
+    map: &[PadRegisters],
+    database: Option<&Database>,
+) -> fmt::Result {
+    writeln!(out, "{:<16} ALT SION {:<16} CONFIG", "PAD", "SIGNAL")?;
+    for pad in map {
+        let mux = core::ptr::read_volatile(pad.mux as *const u32);
+        let config = core::ptr::read_volatile(pad.pad as *const u32);
+        let location = PadLocation {
+            mux: pad.mux,
+            pad: pad.pad,
+        };
+        write_row(out, pad.name, location, mux, config, database)?;
+    }
+    Ok(())
+}
+
+/// Write one pinout row for the register values `mux` and `pad`
+fn write_row(
+    out: &mut dyn fmt::Write,
+    name: &str,
+    location: PadLocation,
+    mux: u32,
+    pad: u32,
+    database: Option<&Database>,
+) -> fmt::Result {
+    let alt = mux & ALT_MASK;
+    let sion = if mux & SION_BIT != 0 { "yes" } else { "no" };
+    let signal = database
+        .and_then(|database| database.alternates_of(location).find(|a| a.alt == alt))
+        .map_or("-", |a| a.signal);
+    writeln!(
+        out,
+        "{name:<16} {alt:>3} {sion:<4} {signal:<16} {}",
+        ConfigText(from_register(pad))
+    )
+}
+
+/// `uDisplay` writes the same settings as [`ConfigText`]
+#[cfg(feature = "ufmt")]
+mod ufmt_impls {
+    use super::{
+        hysteresis_name, name_of, open_drain_name, settings, slew_rate_name, DRIVE_STRENGTHS,
+        PULL_KEEPERS, SPEEDS,
+    };
+    use crate::{
+        Config, DriveStrength, ErasedPad, Hysteresis, OpenDrain, PadLocation, PullKeeper, SlewRate,
+        Speed,
+    };
+    use ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+    macro_rules! display_name {
+        ($($ty:ty => $name:expr;)*) => {$(
+            impl uDisplay for $ty {
+                fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                    let name: fn($ty) -> &'static str = $name;
+                    f.write_str(name(*self))
+                }
+            }
+        )*};
+    }
+
+    display_name! {
+        Hysteresis => hysteresis_name;
+        PullKeeper => |pk| name_of(PULL_KEEPERS, Some(pk));
+        OpenDrain => open_drain_name;
+        Speed => |speed| name_of(SPEEDS, speed);
+        DriveStrength => |dse| name_of(DRIVE_STRENGTHS, dse);
+        SlewRate => slew_rate_name;
+    }
+
+    impl uDisplay for Config {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            let mut separator = "";
+            for setting in settings(*self).into_iter().flatten() {
+                uwrite!(f, "{}{}", separator, setting)?;
+                separator = ",";
+            }
+            Ok(())
+        }
+    }
+
+    /// Writes the MUX and PAD register addresses
+    impl uDisplay for PadLocation {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            uwrite!(f, "mux {:#x}, pad {:#x}", self.mux, self.pad)
+        }
+    }
+
+    impl uDebug for ErasedPad {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("ErasedPad")?
+                .field("mux", &self.mux.as_ptr())?
+                .field("pad", &self.pad.as_ptr())?
+                .finish()
+        }
+    }
+
+    /// Writes the pad's [location](ErasedPad::location)
+    impl uDisplay for ErasedPad {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            uDisplay::fmt(&self.location(), f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, write_row, ConfigText, ParseError};
+    use crate::signals::{Alternate, Database};
+    use crate::{Config, PadLocation, Speed};
+
+    #[test]
+    fn zero_round_trip() {
+        let config = Config::zero().set_speed(Speed::Max);
+        let mut text = [0u8; 96];
+        let mut cursor = Cursor(&mut text, 0);
+        core::fmt::write(&mut cursor, format_args!("{}", ConfigText(config))).unwrap();
+        let len = cursor.1;
+        let text = core::str::from_utf8(&text[..len]).unwrap();
+        assert_eq!(
+            text,
+            "zero,no-hys,no-pull,push-pull,speed-max,drive-off,slow"
+        );
+        assert_eq!(parse(text), Ok(config));
+        assert_eq!(parse(" speed-max , zero, "), Ok(config));
+    }
+
+    #[test]
+    fn unknown_setting() {
+        assert_eq!(
+            parse("hys,pullup-33k"),
+            Err(ParseError {
+                token: "pullup-33k"
+            })
+        );
+        assert_eq!(parse(""), Ok(Config::modify()));
+    }
+
+    #[test]
+    fn pinout_row() {
+        const PAD: PadLocation = PadLocation { mux: 4, pad: 8 };
+        const DATABASE: Database = Database {
+            alternates: &[Alternate {
+                pad: PAD,
+                alt: 2,
+                signal: "LPUART1_TX",
+            }],
+            inputs: &[],
+        };
+        let mut text = [0u8; 256];
+        let mut cursor = Cursor(&mut text, 0);
+        write_row(&mut cursor, "PAD_A", PAD, 0x12, 0x10B0, Some(&DATABASE)).unwrap();
+        write_row(&mut cursor, "PAD_A", PAD, 0x05, 0, None).unwrap();
+        let len = cursor.1;
+        let mut rows = core::str::from_utf8(&text[..len]).unwrap().lines();
+        assert_eq!(
+            rows.next(),
+            Some("PAD_A              2 yes  LPUART1_TX       no-hys,keeper,push-pull,speed-fast,drive-r0-6,slow")
+        );
+        assert_eq!(
+            rows.next(),
+            Some("PAD_A              5 no   -                no-hys,no-pull,push-pull,speed-low,drive-off,slow")
+        );
+    }
+
+    struct Cursor<'a>(&'a mut [u8], usize);
+
+    impl core::fmt::Write for Cursor<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl ufmt::uWrite for Cursor<'_> {
+        type Error = core::fmt::Error;
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            core::fmt::Write::write_str(self, s)
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn ufmt() {
+        use crate::{ErasedPad, PullKeeper};
+
+        fn assert_display(value: &impl ufmt::uDisplay, expected: &str) {
+            let mut text = [0u8; 96];
+            let mut cursor = Cursor(&mut text, 0);
+            ufmt::uwrite!(&mut cursor, "{}", value).unwrap();
+            let len = cursor.1;
+            assert_eq!(core::str::from_utf8(&text[..len]), Ok(expected));
+        }
+
+        assert_display(
+            &Config::zero().set_speed(Speed::Max),
+            "zero,no-hys,no-pull,push-pull,speed-max,drive-off,slow",
+        );
+        assert_display(&PullKeeper::Pullup47k, "pullup-47k");
+        let location = PadLocation {
+            mux: 0x401F_80EC,
+            pad: 0x401F_82DC,
+        };
+        assert_display(&location, "mux 0x401f80ec, pad 0x401f82dc");
+
+        let mut regs = [0u32; 2];
+        let [mux, pad] = &mut regs;
+        let erased = unsafe { ErasedPad::new(mux, pad) };
+        let location = erased.location();
+        let mut expected = [0u8; 96];
+        let mut cursor = Cursor(&mut expected, 0);
+        core::fmt::write(
+            &mut cursor,
+            format_args!("mux {:#x}, pad {:#x}", location.mux, location.pad),
+        )
+        .unwrap();
+        let len = cursor.1;
+        assert_display(&erased, core::str::from_utf8(&expected[..len]).unwrap());
+    }
+}
@@ -0,0 +1,110 @@
+//! `log` and `defmt` records of register writes
+//!
+//! With the `log` or the `defmt` feature, every MUX, PAD, and daisy register
+//! write has a trace-level record. The record names the register's pad, and the
+//! signal that the write selects, when an enabled chip's register map and
+//! signal database know them:
+//!
+//! ```text
+//! mux GPIO_AD_B1_03 LPUART2_RX 0x401f8108 = 0x2
+//! daisy GPIO_AD_B1_03 LPUART2_RX 0x401f852c = 0x1
+//! pad GPIO_AD_B1_03 ? 0x401f82f8 = 0x10b0
+//! ```
+//!
+//! Each write searches the chips' tables, so only enable the features during
+//! bring-up.
+
+use crate::{register_map::PadRegisters, signals::Database};
+
+/// A chip's register map, and its signal database
+type Chip = (&'static [PadRegisters], &'static Database);
+
+/// The enabled chips
+///
+/// Only one chip is enabled in firmware. With more than one chip, the first
+/// chip that has the register names it.
+const CHIPS: &[Chip] = &[
+    #[cfg(feature = "_imxrt1010")]
+    (crate::imxrt1010::REGISTER_MAP, &crate::imxrt1010::DATABASE),
+    #[cfg(feature = "_imxrt1060")]
+    (crate::imxrt1060::REGISTER_MAP, &crate::imxrt1060::DATABASE),
+    #[cfg(feature = "_imxrt1170")]
+    (crate::imxrt1170::REGISTER_MAP, &crate::imxrt1170::DATABASE),
+];
+
+/// Record that `value` was written to the IOMUXC register `reg`
+pub(crate) fn write(reg: *mut u32, value: u32) {
+    let reg = reg as u32;
+    let (kind, pad, signal) = describe(CHIPS, reg, value);
+    #[cfg(feature = "log")]
+    log::trace!("{kind} {pad} {signal} {reg:#x} = {value:#x}");
+    #[cfg(feature = "defmt")]
+    defmt::trace!(
+        "{=str} {=str} {=str} {=u32:#x} = {=u32:#x}",
+        kind,
+        pad,
+        signal,
+        reg,
+        value
+    );
+}
+
+/// Returns the register's kind, its pad's name, and the signal that `value`
+/// selects
+///
+/// Unknown names are `"?"`.
+fn describe(chips: &[Chip], reg: u32, value: u32) -> (&'static str, &'static str, &'static str) {
+    for (map, database) in chips {
+        if let Some(entry) = map.iter().find(|entry| entry.mux == reg) {
+            let signal = database
+                .alternates
+                .iter()
+                .find(|alt| alt.pad.mux == reg && alt.alt == value & crate::ALT_MASK)
+                .map_or("?", |alt| alt.signal);
+            return ("mux", entry.name, signal);
+        }
+        if let Some(entry) = map.iter().find(|entry| entry.pad == reg) {
+            return ("pad", entry.name, "?");
+        }
+        if let Some(input) = database
+            .inputs
+            .iter()
+            .find(|input| input.register == reg && input.value == value)
+        {
+            let pad = map
+                .iter()
+                .find(|entry| (entry.mux, entry.pad) == (input.pad.mux, input.pad.pad))
+                .map_or("?", |entry| entry.name);
+            return ("daisy", pad, input.input);
+        }
+    }
+    ("register", "?", "?")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, CHIPS};
+
+    #[test]
+    fn unknown_register() {
+        assert_eq!(describe(CHIPS, 0x1000, 0), ("register", "?", "?"));
+    }
+
+    #[cfg(feature = "imxrt1060")]
+    #[test]
+    fn names() {
+        let chip = &[(crate::imxrt1060::REGISTER_MAP, &crate::imxrt1060::DATABASE)];
+        assert_eq!(
+            describe(chip, 0x401F_8108, 0x12),
+            ("mux", "GPIO_AD_B1_03", "LPUART2_RX")
+        );
+        assert_eq!(
+            describe(chip, 0x401F_82F8, 0x10B0),
+            ("pad", "GPIO_AD_B1_03", "?")
+        );
+        assert_eq!(
+            describe(chip, 0x401F_852C, 1),
+            ("daisy", "GPIO_AD_B1_03", "LPUART2_RX")
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Low-power configuration for unused pads
+//!
+//! An unused pad with a floating input can draw current in a low-power mode.
+//! [`park()`] applies [`CONFIG`] to every pad that isn't in a set of used pads.
+//! The configuration disables the pad's output driver, and enables the keeper,
+//! which holds the input at its last level.
+//!
+//! `park()` only changes the PAD registers. Each pad stays in its current
+//! alternate, which, after reset, is typically its GPIO input. Some pads don't
+//! have a GPIO alternate, so `park()` does not select one.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{unused, imxrt1060::{gpio_ad_b0, Pads}};
+//!
+//! let mut pads = unsafe { Pads::new() }.erase();
+//! let used = [
+//!     gpio_ad_b0::GPIO_AD_B0_12::LOCATION,
+//!     gpio_ad_b0::GPIO_AD_B0_13::LOCATION,
+//! ];
+//! unused::park(
+//!     pads.gpio_emc
+//!         .iter_mut()
+//!         .chain(pads.gpio_ad_b0.iter_mut())
+//!         .chain(pads.gpio_ad_b1.iter_mut()),
+//!     &used,
+//! );
+//! ```
+
+use crate::{
+    Config, DriveStrength, ErasedPad, Hysteresis, OpenDrain, PadLocation, PullKeeper, SlewRate,
+    Speed,
+};
+
+/// The configuration for an unused pad
+///
+/// The output driver is disabled, and the keeper is enabled. All other fields
+/// are at their slowest, lowest-power setting.
+pub const CONFIG: Config = Config::zero()
+    .set_hysteresis(Hysteresis::Disabled)
+    .set_pull_keeper(Some(PullKeeper::Keeper))
+    .set_open_drain(OpenDrain::Disabled)
+    .set_speed(Speed::Low)
+    .set_drive_strength(DriveStrength::Disabled)
+    .set_slew_rate(SlewRate::Slow);
+
+/// Apply [`CONFIG`] to every pad that's not in `used`
+///
+/// Returns the number of configured pads.
+pub fn park<'a>(pads: impl IntoIterator<Item = &'a mut ErasedPad>, used: &[PadLocation]) -> usize {
+    let mut parked = 0;
+    for pad in pads {
+        if !used.contains(&pad.location()) {
+            crate::configure(pad, CONFIG);
+            parked += 1;
+        }
+    }
+    parked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CONFIG;
+
+    #[test]
+    fn keeper_without_driver() {
+        // PKE set, PUE clear, and every other field zero.
+        assert_eq!(CONFIG.value(), 1 << 12);
+        assert!(CONFIG.is_zero());
+    }
+}
@@ -1,4 +1,9 @@
 //! USDHC pad configuration
+//!
+//! The chip modules implement [`Pin`] for the command, clock, and data pads.
+//! No chip pad implements the [`CdB`], [`Wp`], [`ResetB`], or [`VSelect`]
+//! signals yet. Implement `Pin` for your board's socket pads, using the
+//! alternates and select inputs from the reference manual's muxing tables.
 
 /// Type tag for the command pin
 pub enum Cmd {}
@@ -8,6 +13,13 @@ pub enum Clk {}
 pub enum Wp {}
 /// Type tag for the card detection pin
 pub enum CdB {}
+/// Type tag for the card reset pin; `RESET_B`
+pub enum ResetB {}
+/// Type tag for the signal voltage select pin
+///
+/// The uSDHC drives `VSELECT` to switch the card's I/O supply between 3.3V
+/// and 1.8V.
+pub enum VSelect {}
 /// Type tag for the data0 pin
 pub enum Data0 {}
 /// Type tag for the data1 pin
@@ -24,6 +36,8 @@ mod private {
     impl Signal for super::Clk {}
     impl Signal for super::Wp {}
     impl Signal for super::CdB {}
+    impl Signal for super::ResetB {}
+    impl Signal for super::VSelect {}
     impl Signal for super::Data0 {}
     impl Signal for super::Data1 {}
     impl Signal for super::Data2 {}
@@ -34,6 +48,12 @@ use private::Signal;
 use crate::Config;
 
 /// A uSDHC pin
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an uSDHC pin",
+    label = "this pad does not support any uSDHC signal",
+    note = "the `usdhc::Pin` implementors list the compatible pads",
+    note = "a `Pad<MUX, PAD>` is named by its MUX and PAD register addresses"
+)]
 pub trait Pin: super::Iomuxc {
     /// The alternate value for the uSDHC pin
     const ALT: u32;
@@ -46,8 +66,20 @@ pub trait Pin: super::Iomuxc {
     const CONFIG: Config;
     /// Pin direction
     type Signal: Signal;
-    /// uSDHC module; `U1` for `uSDHC1`
+    /// uSDHC module; `Const<1>` for `uSDHC1`
     type Module: super::consts::Unsigned;
+    /// The module instance number; `3` for `Module = Const<3>`
+    ///
+    /// Use this to index tables of per-instance registers or clock gates.
+    const MODULE: u8 = <Self::Module as super::consts::Unsigned>::USIZE as u8;
+}
+
+impl<P: Pin> Pin for &mut P {
+    const ALT: u32 = P::ALT;
+    const DAISY: Option<super::Daisy> = P::DAISY;
+    const CONFIG: Config = P::CONFIG;
+    type Signal = P::Signal;
+    type Module = P::Module;
 }
 
 /// Prepare a uSDHC pin
@@ -61,17 +93,126 @@ pub trait Pin: super::Iomuxc {
 /// In particular, we cannot be sure that the implementation's pointers are correct.
 /// It may also write a daisy configuration that's incorrect.
 pub fn prepare<P: Pin>(pin: &mut P) {
-    super::alternate(pin, P::ALT);
-    super::set_sion(pin);
-    super::configure(pin, P::CONFIG);
-    if let Some(daisy) = P::DAISY {
-        unsafe { daisy.write() }
+    // Safety: same justification as set_sion.
+    unsafe {
+        super::prepare_mux(pin.mux(), P::ALT, true, None);
+        super::configure_register(pin.pad(), P::CONFIG);
+        if let Some(daisy) = P::DAISY {
+            daisy.write();
+        }
+    }
+    super::debug_check_daisy(pin, P::DAISY);
+}
+
+/// Prepare a uSDHC pin, choosing its daisy value
+///
+/// Like [`prepare()`], but writes the daisy value that `daisy` selects.
+pub fn prepare_with_daisy<P: Pin>(pin: &mut P, daisy: crate::DaisyChoice) {
+    // Safety: same justification as prepare.
+    unsafe {
+        super::prepare_mux(pin.mux(), P::ALT, true, None);
+        super::configure_register(pin.pad(), P::CONFIG);
+        if let Some(daisy) = daisy.resolve(P::DAISY) {
+            daisy.write();
+        }
+    }
+    super::debug_check_daisy_choice(pin, P::DAISY, daisy);
+}
+
+/// Returns `true` if the pin's daisy register selects the pin
+///
+/// A pin without a daisy register is always selected. Use this to check that
+/// nothing routed the uSDHC input to another pad after you prepared the pin.
+pub fn verify_daisy<P: Pin>(_: &P) -> bool {
+    super::daisy_selected(P::DAISY)
+}
+
+/// Prepare a uSDHC pin, and take ownership of it
+///
+/// The returned pin proves that it was prepared. Use
+/// [`Prepared::release()`](crate::Prepared::release) to take back the pin.
+pub fn prepare_owned<P: Pin>(mut pin: P) -> crate::Prepared<P::Signal, P> {
+    prepare(&mut pin);
+    crate::Prepared::new(pin)
+}
+
+/// The signal voltage of a uSDHC bus
+///
+/// Cards start at 3.3V. UHS-I SD cards, and HS200 eMMC devices, switch to 1.8V
+/// for the SDR50, SDR104, and HS200 modes. A lower voltage raises the pads'
+/// output impedance, and the faster modes need faster edges, so
+/// each voltage has its own pad configuration.
+///
+/// To switch a bus to 1.8V, ask the card to switch, and set the uSDHC's
+/// `VSELECT` bit, which drives the [`VSelect`] pin to the card's supply
+/// regulator. Then, call [`configure_signaling()`] on the command, clock, and
+/// data pins.
+///
+/// ```no_run
+/// use imxrt_iomuxc::usdhc::{self, Signaling};
+/// use imxrt_iomuxc::imxrt1060::gpio_sd_b0::{GPIO_SD_B0_00, GPIO_SD_B0_01};
+///
+/// let mut cmd = unsafe { GPIO_SD_B0_00::new() };
+/// let mut clk = unsafe { GPIO_SD_B0_01::new() };
+/// usdhc::prepare(&mut cmd);
+/// usdhc::prepare(&mut clk);
+///
+/// // After the card switches to 1.8V, and VSELECT is high...
+/// assert!(Signaling::V1_8.vselect());
+/// usdhc::configure_signaling(&mut cmd, Signaling::V1_8);
+/// usdhc::configure_signaling(&mut clk, Signaling::V1_8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signaling {
+    /// 3.3V signaling, up to 50MHz; default and high speed modes
+    V3_3,
+    /// 1.8V signaling, up to 208MHz; SDR50, SDR104, and HS200 modes
+    V1_8,
+}
+
+impl Signaling {
+    /// Returns the pad settings for this voltage
+    ///
+    /// At 3.3V, this is the speed and drive strength that [`prepare()`] uses.
+    /// At 1.8V, the speed is the maximum, and the slew rate is fast. Both use
+    /// the strongest drive, `R0_7`, since `R0` is 260 Ohm at 1.8V. Boards with
+    /// short traces may need weaker drive strengths; start with these, and
+    /// tune with a scope.
+    ///
+    /// The configuration doesn't change the pull/keeper.
+    pub const fn config(self) -> Config {
+        match self {
+            Signaling::V3_3 => Config::modify()
+                .set_speed(crate::Speed::Fast)
+                .set_drive_strength(crate::DriveStrength::R0_7)
+                .set_slew_rate(crate::SlewRate::Slow),
+            Signaling::V1_8 => Config::modify()
+                .set_speed(crate::Speed::Max)
+                .set_drive_strength(crate::DriveStrength::R0_7)
+                .set_slew_rate(crate::SlewRate::Fast),
+        }
     }
+
+    /// Returns the level of the VSELECT pin for this voltage
+    ///
+    /// `true`, or high, selects 1.8V.
+    pub const fn vselect(self) -> bool {
+        matches!(self, Signaling::V1_8)
+    }
+}
+
+/// Configure a prepared uSDHC pin for a signal voltage
+///
+/// Applies [`Signaling::config()`] over the pin's [`CONFIG`](Pin::CONFIG).
+/// The pin's pull/keeper, alternate, and daisy don't change.
+pub fn configure_signaling<P: Pin>(pin: &mut P, signaling: Signaling) {
+    crate::configure(pin, P::CONFIG.merge(signaling.config()));
 }
 
 #[allow(unused)] // Used in chip-specific modules...
 macro_rules! usdhc {
-    (module: $module:ty, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, keeper: $keeper:expr, daisy: $daisy:expr) => {
+    (module: $module:literal, alt: $alt:expr, pad: $pad:ty, signal: $signal:ty, keeper: $keeper:expr, daisy: $daisy:expr) => {
+        const _: () = assert!(crate::alt_fits($alt), "Alternate exceeds MUX_MODE");
         impl Pin for $pad {
             const ALT: u32 = $alt;
             const DAISY: Option<Daisy> = $daisy;
@@ -80,7 +221,7 @@ macro_rules! usdhc {
                 .set_drive_strength(crate::DriveStrength::R0_7)
                 .set_pull_keeper($keeper);
             type Signal = $signal;
-            type Module = $module;
+            type Module = crate::consts::Const<$module>;
         }
     };
 }
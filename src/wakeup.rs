@@ -0,0 +1,74 @@
+//! Wakeup pad configuration
+//!
+//! A wakeup pad is a GPIO input that holds a known level while the system sleeps.
+//! [`prepare()`] puts a pad into its GPIO alternate, enables the input
+//! hysteresis, and pulls the pad to its idle level, so that a floating input
+//! can't wake the system. The pull depends on the wakeup [`Polarity`].
+//!
+//! Preparing the pad is only one step. You still need to configure the GPIO
+//! interrupt, and enable the interrupt as a wakeup source in the GPC. This
+//! module does not describe the SNVS `WAKEUP` pad.
+//!
+//! ```no_run
+//! use imxrt_iomuxc::wakeup::{self, Polarity};
+//! # use imxrt_iomuxc::imxrt1060::gpio_ad_b0::GPIO_AD_B0_03;
+//!
+//! let mut button = unsafe { GPIO_AD_B0_03::new() };
+//! // The button connects the pad to ground.
+//! wakeup::prepare(&mut button, Polarity::ActiveLow);
+//! ```
+
+use crate::{gpio, Config, Hysteresis, PullKeeper};
+
+/// The pad level that wakes the system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    /// A high level, or rising edge, wakes the system; the pad idles low
+    ActiveHigh,
+    /// A low level, or falling edge, wakes the system; the pad idles high
+    ActiveLow,
+}
+
+/// Returns the wakeup configuration for `polarity`
+///
+/// The configuration enables hysteresis, and pulls the pad to its idle level. It
+/// doesn't change any other field.
+pub const fn config(polarity: Polarity) -> Config {
+    let pull = match polarity {
+        Polarity::ActiveHigh => PullKeeper::Pulldown100k,
+        Polarity::ActiveLow => PullKeeper::Pullup100k,
+    };
+    Config::modify()
+        .set_hysteresis(Hysteresis::Enabled)
+        .set_pull_keeper(Some(pull))
+}
+
+/// Prepare a GPIO pad as a wakeup source
+pub fn prepare<P: gpio::Pin<N>, const N: u8>(pin: &mut P, polarity: Polarity) {
+    gpio::prepare(pin);
+    crate::configure(pin, config(polarity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{config, Polarity};
+
+    // Pad register fields, from the reference manual.
+    const HYS: u32 = 1 << 16;
+    const PUS_100K_PULLUP: u32 = 0b10 << 14;
+    const PUS_MASK: u32 = 0b11 << 14;
+    const PUE: u32 = 1 << 13;
+    const PKE: u32 = 1 << 12;
+
+    #[test]
+    fn idle_pulls() {
+        let low = config(Polarity::ActiveLow);
+        assert_eq!(low.value(), HYS | PUS_100K_PULLUP | PUE | PKE);
+        assert_eq!(low.mask(), HYS | PUS_MASK | PUE | PKE);
+
+        // The 100K pulldown is PUS = 0.
+        let high = config(Polarity::ActiveHigh);
+        assert_eq!(high.value(), HYS | PUE | PKE);
+        assert_eq!(high.mask(), HYS | PUS_MASK | PUE | PKE);
+    }
+}
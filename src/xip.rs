@@ -0,0 +1,160 @@
+//! Boot flash pad guard.
+//!
+//! A chip that executes in place (XIP) fetches its code through the FlexSPI
+//! pads that connect the boot flash. Reconfiguring one of those pads stops
+//! the CPU, and a firmware that does it on every boot can't be reflashed
+//! without the serial downloader.
+//!
+//! With the `xip-guard` feature, the functions below panic, before writing, if
+//! they would change a boot flash pad while the code executes from FlexSPI
+//! memory:
+//!
+//! - [`configure()`], [`configure_if_changed()`], [`alternate()`],
+//!   [`set_sion()`], [`clear_sion()`], and every peripheral `prepare()` function
+//! - [`PreparePlan::apply()`]
+//! - dropping a [`Lease`], and dropping or exiting a [`Sleep`] guard
+//! - [`register_map::restore()`] and [`register_map::reset()`]
+//! - the `remote` module's `Message::apply()` and `dispatch()`
+//!
+//! [`PadTable::apply()`] is not checked; it may run before static
+//! initialization, when the guard's state is not ready.
+//!
+//! Unlike the [`lock`](crate::lock) registry, the guard is active in release builds, and it knows each enabled
+//! chip's boot flash pads; see `BOOT_FLASH` in the chip modules.
+//!
+//! To change the boot flash pads on purpose, like when the firmware tunes
+//! the flash pads' drive strength, use [`unguarded()`].
+//!
+//! ```no_run
+//! use imxrt_iomuxc::{configure, xip, Config, DriveStrength};
+//! use imxrt_iomuxc::imxrt1060::gpio_sd_b1::GPIO_SD_B1_07;
+//!
+//! let mut sclk = unsafe { GPIO_SD_B1_07::new() };
+//! // Safety: the new drive strength keeps the flash running.
+//! unsafe {
+//!     xip::unguarded(|| {
+//!         configure(&mut sclk, Config::modify().set_drive_strength(DriveStrength::R0_6));
+//!     });
+//! }
+//! ```
+//!
+//! [`configure()`]: crate::configure()
+//! [`alternate()`]: crate::alternate()
+//! [`set_sion()`]: crate::set_sion()
+//! [`clear_sion()`]: crate::clear_sion()
+//! [`configure_if_changed()`]: crate::configure_if_changed()
+//! [`PreparePlan::apply()`]: crate::PreparePlan::apply()
+//! [`Lease`]: crate::Lease
+//! [`Sleep`]: crate::sleep::Sleep
+//! [`register_map::restore()`]: crate::register_map::restore()
+//! [`register_map::reset()`]: crate::register_map::reset()
+//! [`PadTable::apply()`]: crate::PadTable::apply()
+
+use crate::PadLocation;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Set while [`unguarded()`] runs
+static UNGUARDED: AtomicBool = AtomicBool::new(false);
+
+/// The boot flash pads of all enabled chips
+const BOOT_FLASH: &[&[PadLocation]] = &[
+    #[cfg(feature = "_imxrt1010")]
+    crate::imxrt1010::BOOT_FLASH,
+    #[cfg(feature = "_imxrt1060")]
+    crate::imxrt1060::BOOT_FLASH,
+    #[cfg(feature = "_imxrt1170")]
+    crate::imxrt1170::BOOT_FLASH,
+];
+
+/// The FlexSPI memory of all enabled chips
+const FLEXSPI_MEMORY: &[core::ops::Range<u32>] = &[
+    #[cfg(feature = "_imxrt1010")]
+    crate::imxrt1010::FLEXSPI_MEMORY,
+    #[cfg(feature = "_imxrt1060")]
+    crate::imxrt1060::FLEXSPI_MEMORY,
+    #[cfg(feature = "_imxrt1170")]
+    crate::imxrt1170::FLEXSPI_MEMORY,
+];
+
+/// Returns `true` if `location` is a boot flash pad of an enabled chip
+pub fn is_boot_flash(location: PadLocation) -> bool {
+    BOOT_FLASH.iter().any(|pads| pads.contains(&location))
+}
+
+/// Returns `true` if the code executes from FlexSPI memory
+///
+/// Always `false` when not compiled for an Arm target.
+pub fn executing_in_place() -> bool {
+    if cfg!(target_arch = "arm") {
+        let pc = executing_in_place as *const () as usize as u32;
+        FLEXSPI_MEMORY.iter().any(|memory| memory.contains(&pc))
+    } else {
+        false
+    }
+}
+
+/// Run `f` with the guard disabled
+///
+/// # Safety
+///
+/// `f` may change the boot flash pads. If the chip executes in place, the
+/// changes must keep the flash running.
+pub unsafe fn unguarded<R>(f: impl FnOnce() -> R) -> R {
+    let previous = UNGUARDED.swap(true, Ordering::AcqRel);
+    let result = f();
+    UNGUARDED.store(previous, Ordering::Release);
+    result
+}
+
+/// Returns `true` if the guard rejects writes to `address`
+///
+/// `select` picks the MUX or PAD address from each boot flash pad.
+fn guards(address: u32, select: fn(&PadLocation) -> u32, xip: bool) -> bool {
+    xip && !UNGUARDED.load(Ordering::Acquire)
+        && BOOT_FLASH
+            .iter()
+            .flat_map(|pads| pads.iter())
+            .any(|location| select(location) == address)
+}
+
+/// Panic if `mux` is a boot flash pad's MUX register, and the code executes
+/// in place
+#[inline(always)]
+pub(crate) fn check_mux(mux: *mut u32) {
+    assert!(
+        !guards(mux as u32, |location| location.mux, executing_in_place()),
+        "the pad with MUX register {mux:p} connects the boot flash"
+    );
+}
+
+/// Panic if `pad` is a boot flash pad's PAD register, and the code executes
+/// in place
+#[inline(always)]
+pub(crate) fn check_pad(pad: *mut u32) {
+    assert!(
+        !guards(pad as u32, |location| location.pad, executing_in_place()),
+        "the pad with PAD register {pad:p} connects the boot flash"
+    );
+}
+
+#[cfg(all(test, feature = "imxrt1060"))]
+mod tests {
+    use super::{guards, is_boot_flash, unguarded};
+    use crate::imxrt1060::gpio_sd_b1::{GPIO_SD_B1_04, GPIO_SD_B1_07};
+
+    #[test]
+    fn guard_boot_flash_pads() {
+        let sclk = GPIO_SD_B1_07::LOCATION;
+        assert!(is_boot_flash(sclk));
+        assert!(!is_boot_flash(GPIO_SD_B1_04::LOCATION));
+
+        assert!(guards(sclk.mux, |location| location.mux, true));
+        assert!(guards(sclk.pad, |location| location.pad, true));
+        assert!(!guards(sclk.mux, |location| location.mux, false));
+        assert!(!guards(sclk.mux, |location| location.pad, true));
+
+        let unguarded = unsafe { unguarded(|| guards(sclk.mux, |location| location.mux, true)) };
+        assert!(!unguarded);
+        assert!(guards(sclk.mux, |location| location.mux, true));
+    }
+}
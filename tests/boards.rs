@@ -0,0 +1,154 @@
+//! Tests for board pin aliases.
+
+#[cfg(feature = "teensy40")]
+#[test]
+fn teensy40() {
+    use imxrt_iomuxc::{boards::teensy40, imxrt1060 as pads};
+
+    let pins = teensy40::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio_ad_b0::GPIO_AD_B0_03 = pins.p0;
+    let _: teensy40::Led = pins.p13;
+    let _: pads::gpio_b0::GPIO_B0_03 = unsafe { teensy40::Led::new() };
+    let _: pads::gpio_ad_b1::GPIO_AD_B1_09 = pins.p23;
+}
+
+#[cfg(feature = "teensy41")]
+#[test]
+fn teensy41() {
+    use imxrt_iomuxc::{boards::teensy41, imxrt1060 as pads};
+
+    let pins = teensy41::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio_ad_b0::GPIO_AD_B0_03 = pins.p0;
+    let _: teensy41::Led = pins.p13;
+    let _: pads::gpio_b1::GPIO_B1_13 = pins.p34;
+    let _: pads::gpio_sd_b0::GPIO_SD_B0_03 = pins.p42;
+    let _: pads::gpio_emc::GPIO_EMC_29 = pins.p54;
+}
+
+#[cfg(feature = "imxrt1010evk")]
+#[test]
+fn imxrt1010evk() {
+    use imxrt_iomuxc::{boards::imxrt1010evk as evk, imxrt1010 as pads};
+
+    let pins = evk::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio::GPIO_11 = pins.user_led;
+    let _: pads::gpio_sd::GPIO_SD_05 = pins.user_button;
+    let _: evk::ConsoleTx = pins.console_tx;
+}
+
+#[cfg(feature = "imxrt1060evk")]
+#[test]
+fn imxrt1060evk() {
+    use imxrt_iomuxc::{boards::imxrt1060evk as evk, imxrt1060 as pads};
+
+    let pins = evk::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio_ad_b1::GPIO_AD_B1_07 = pins.d0;
+    let _: evk::UserLed = pins.d4;
+    let _: evk::A4 = pins.d14;
+    let _: pads::gpio_sd_b0::GPIO_SD_B0_00 = pins.d13;
+    let _: pads::gpio_ad_b1::GPIO_AD_B1_10 = pins.a0;
+}
+
+#[cfg(feature = "imxrt1170evk")]
+#[test]
+fn imxrt1170evk() {
+    use imxrt_iomuxc::{boards::imxrt1170evk as evk, imxrt1170 as pads};
+
+    let pins = evk::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio_lpsr::GPIO_LPSR_05 = pins.d15;
+    let _: pads::gpio_ad::GPIO_AD_04 = pins.user_led;
+    let _: evk::DisplayBacklight = pins.d11;
+    let _: pads::gpio_lpsr::GPIO_LPSR_04 = pins.d14;
+}
+
+#[cfg(feature = "metro-m7")]
+#[test]
+fn metro_m7() {
+    use imxrt_iomuxc::{boards::metro_m7, imxrt1010 as pads};
+
+    let pins = metro_m7::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio::GPIO_09 = pins.d0;
+    let _: metro_m7::Led = pins.d13;
+    let _: pads::gpio_sd::GPIO_SD_00 = pins.d4;
+    let _: pads::gpio_ad::GPIO_AD_08 = pins.a5;
+    let _: pads::gpio::GPIO_00 = pins.neopixel;
+}
+
+#[cfg(feature = "imxrt1060")]
+#[test]
+fn define_board_pins() {
+    use imxrt_iomuxc::imxrt1060 as pads;
+
+    imxrt_iomuxc::define_board_pins! {
+        struct Pins(pads::Pads) {
+            led: pads::gpio_b0::GPIO_B0_03 = gpio_b0.p03,
+            rx: pads::gpio_ad_b0::GPIO_AD_B0_03 = gpio_ad_b0.p03,
+        }
+    }
+
+    let pins = Pins::from_pads(unsafe { pads::Pads::new() });
+    let [led, rx] = pins.into_pads();
+    assert_eq!(Pins::LEN, 2);
+    let _: pads::gpio_b0::GPIO_B0_03 = core::convert::TryFrom::try_from(led).unwrap();
+    let _: pads::gpio_ad_b0::GPIO_AD_B0_03 = core::convert::TryFrom::try_from(rx).unwrap();
+}
+
+#[cfg(feature = "teensy40")]
+#[test]
+fn teensy40_pin_numbers() {
+    use imxrt_iomuxc::{boards::teensy40, imxrt1060 as pads};
+
+    assert_eq!(teensy40::PINS[13], teensy40::Led::LOCATION);
+    let led = unsafe { teensy40::erased_pin(13) }.unwrap();
+    let _: pads::gpio_b0::GPIO_B0_03 = core::convert::TryFrom::try_from(led).unwrap();
+    assert!(unsafe { teensy40::erased_pin(teensy40::PINS.len()) }.is_none());
+}
+
+#[cfg(feature = "teensy-micromod")]
+#[test]
+fn teensy_micromod() {
+    use imxrt_iomuxc::{boards::teensy_micromod as micromod, imxrt1060 as pads};
+
+    let pins = micromod::Pins::from_pads(unsafe { pads::Pads::new() });
+    let _: pads::gpio_ad_b0::GPIO_AD_B0_02 = pins.tx1;
+    let _: micromod::Led = pins.spi_sck;
+    let _: pads::gpio_ad_b1::GPIO_AD_B1_00 = pins.scl;
+    assert_eq!(micromod::Pins::LEN, 14);
+}
+
+#[cfg(feature = "metro-m7")]
+#[test]
+fn board_pads_take() {
+    use imxrt_iomuxc::{boards::metro_m7, imxrt1010 as pads};
+
+    let board = unsafe { metro_m7::BoardPads::take() };
+    let _: pads::gpio::GPIO_03 = board.d13;
+    assert_eq!(board.into_pads().len(), metro_m7::BoardPads::LEN);
+}
+
+#[cfg(all(feature = "teensy41", feature = "imxrt1170evk"))]
+#[test]
+fn board_roles() {
+    use imxrt_iomuxc::{
+        boards::{imxrt1170evk, teensy41},
+        lpi2c, lpuart, Board, SdCard,
+    };
+
+    fn console_and_led<B: Board>() -> (u8, u8) {
+        let roles = unsafe { B::take() };
+        let console = lpuart::Pins::new(roles.console_tx, roles.console_rx);
+        let i2c = lpi2c::Pins::new(roles.i2c_scl, roles.i2c_sda);
+        let _ = (console, i2c);
+        (
+            lpuart::Pins::<B::ConsoleTx, B::ConsoleRx>::MODULE,
+            B::LED_GPIO,
+        )
+    }
+
+    assert_eq!(console_and_led::<teensy41::Teensy41>(), (6, 2));
+    assert_eq!(teensy41::Teensy41::LED_OFFSET, 3);
+    assert_eq!(console_and_led::<imxrt1170evk::Imxrt1170Evk>(), (1, 3));
+
+    let sd = unsafe { teensy41::Teensy41::take_sd_card() };
+    let _: teensy41::P45 = sd.cmd;
+}
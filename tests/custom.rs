@@ -0,0 +1,30 @@
+//! Pads defined outside of the package.
+
+use imxrt_iomuxc::{consts::Const, define_pad, lpuart, Daisy, Iomuxc};
+
+define_pad! {
+    /// A pad with made-up registers.
+    struct Custom { mux: 0x1000_0000, pad: 0x1000_0004 }
+}
+
+impl lpuart::Pin for Custom {
+    const ALT: u32 = 3;
+    const DAISY: Option<Daisy> = None;
+    type Direction = lpuart::Rx;
+    type Module = Const<1>;
+}
+
+fn module<P: lpuart::Pin>(_: &P) -> u8 {
+    P::MODULE
+}
+
+#[test]
+fn custom_pad() {
+    let mut pad = unsafe { Custom::new() };
+    assert_eq!(pad.mux() as u32, 0x1000_0000);
+    assert_eq!(pad.pad() as u32, 0x1000_0004);
+    assert_eq!(module(&pad), 1);
+
+    let location = pad.erase().location();
+    assert_eq!((location.mux, location.pad), (0x1000_0000, 0x1000_0004));
+}
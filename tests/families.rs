@@ -0,0 +1,35 @@
+//! Tests for enabling all chip families together.
+
+#![cfg(all(feature = "imxrt1010", feature = "imxrt1060", feature = "imxrt1170"))]
+
+use imxrt_iomuxc::{imxrt1010, imxrt1060, imxrt1170, register_map::PadRegisters};
+
+const FAMILIES: &[&[PadRegisters]] = &[
+    imxrt1010::REGISTER_MAP,
+    imxrt1060::REGISTER_MAP,
+    imxrt1170::REGISTER_MAP,
+];
+
+/// Two chips with the same pad registers would share a `Pad` type, and
+/// their pin implementations would conflict.
+#[test]
+fn pad_types_are_distinct() {
+    for (idx, family) in FAMILIES.iter().enumerate() {
+        for other in &FAMILIES[idx + 1..] {
+            for pad in family.iter() {
+                assert!(
+                    !other.iter().any(|o| (o.mux, o.pad) == (pad.mux, pad.pad)),
+                    "{} shares its registers with another family",
+                    pad.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn namespaced_pads() {
+    let _ = unsafe { imxrt1010::Pads::new() }.erase();
+    let _ = unsafe { imxrt1060::Pads::new() }.erase();
+    let _ = unsafe { imxrt1170::Pads::new() }.erase();
+}
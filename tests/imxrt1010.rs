@@ -19,3 +19,35 @@ group!(gpio, 14,
          [GPIO_13, GPIO_00],
     mux: [0x401F_8088, 0x401F_80BC],
     pad: [0x401F_8138, 0x401F_816C]);
+group!(snvs, 1,
+         [PMIC_ON_REQ, PMIC_ON_REQ],
+    mux: [0x400A_8000, 0x400A_8000],
+    pad: [0x400A_8010, 0x400A_8010]);
+
+#[cfg(feature = "self-check")]
+#[test]
+fn self_check() {
+    pads::self_check().unwrap();
+}
+
+#[test]
+fn signal_database() {
+    use imxrt_iomuxc::PadLocation;
+
+    // Every daisy that a pin trait writes is a select input of the pad.
+    for entry in pads::REGISTER_MAP {
+        let location = PadLocation {
+            mux: entry.mux,
+            pad: entry.pad,
+        };
+        for daisy in entry.daisies {
+            assert!(
+                pads::DATABASE
+                    .inputs_of(location)
+                    .any(|input| input.register == daisy.reg && input.value == daisy.value),
+                "{}",
+                entry.name
+            );
+        }
+    }
+}
@@ -35,3 +35,440 @@ group!(gpio_sd_b1, 12,
          [GPIO_SD_B1_00, GPIO_SD_B1_11],
     mux: [0x401F_81D4, 0x401F_8200],
     pad: [0x401F_83C4, 0x401F_83F0]);
+group!(snvs, 3,
+         [WAKEUP, PMIC_STBY_REQ],
+    mux: [0x400A_8000, 0x400A_8008],
+    pad: [0x400A_8018, 0x400A_8020]);
+
+#[cfg(feature = "self-check")]
+#[test]
+fn self_check() {
+    pads::self_check().unwrap();
+}
+
+#[test]
+fn module_numbers() {
+    use imxrt_iomuxc::{adc, flexpwm, lpi2c, lpspi, lpuart};
+    use pads::{gpio_ad_b1::GPIO_AD_B1_02, gpio_b0::GPIO_B0_00, gpio_b0::GPIO_B0_10};
+
+    assert_eq!(<GPIO_AD_B1_02 as lpuart::Pin>::MODULE, 2);
+    assert_eq!(<GPIO_B0_00 as lpspi::Pin>::MODULE, 4);
+    assert_eq!(<&mut GPIO_B0_00 as lpspi::Pin>::MODULE, 4);
+    assert_eq!(<GPIO_B0_10 as flexpwm::Pin>::MODULE, 2);
+    assert_eq!(<GPIO_B0_10 as flexpwm::Pin>::SUBMODULE, 2);
+
+    assert_eq!(<pads::gpio_b0::GPIO_B0_04 as lpi2c::Pin>::MODULE, 2);
+    assert_eq!(<pads::gpio_emc::GPIO_EMC_11 as lpi2c::Pin>::MODULE, 4);
+
+    assert_eq!(<pads::gpio_ad_b1::GPIO_AD_B1_03 as adc::Pin<2>>::CHANNEL, 8);
+    assert_eq!(<pads::gpio_ad_b1::GPIO_AD_B1_03 as adc::Pin<2>>::MODULE, 2);
+
+    fn x_output<P: flexpwm::Pin<Output = flexpwm::X>>() -> u8 {
+        P::SUBMODULE
+    }
+    assert_eq!(x_output::<pads::gpio_ad_b0::GPIO_AD_B0_12>(), 2);
+}
+
+#[test]
+fn reference_clocks() {
+    use imxrt_iomuxc::ccm::{Pin, RefClk24M, RefClk32K};
+    use pads::gpio_ad_b0::{GPIO_AD_B0_00, GPIO_AD_B0_01, GPIO_AD_B0_03};
+
+    fn clock<P: Pin<Function = F>, F>() -> u32 {
+        P::ALT
+    }
+    assert_eq!(clock::<GPIO_AD_B0_00, RefClk32K>(), 2);
+    assert_eq!(clock::<GPIO_AD_B0_01, RefClk24M>(), 2);
+    assert_eq!(clock::<GPIO_AD_B0_03, RefClk24M>(), 6);
+}
+
+#[test]
+fn lpspi_on_emc() {
+    use imxrt_iomuxc::lpspi::Pins;
+    use pads::gpio_emc::*;
+
+    type Lpspi1 = Pins<GPIO_EMC_28, GPIO_EMC_29, GPIO_EMC_27, GPIO_EMC_30>;
+    type Lpspi2 = Pins<GPIO_EMC_02, GPIO_EMC_03, GPIO_EMC_00, GPIO_EMC_01>;
+    assert_eq!(Lpspi1::MODULE, 1);
+    assert_eq!(Lpspi2::MODULE, 2);
+}
+
+#[test]
+fn lpuart_pairs() {
+    use imxrt_iomuxc::lpuart::Pins;
+    use pads::{gpio_b0::*, gpio_b1::*, gpio_emc::*, gpio_sd_b0::*, gpio_sd_b1::*};
+
+    assert_eq!(Pins::<GPIO_SD_B1_11, GPIO_SD_B1_10>::MODULE, 2);
+    assert_eq!(Pins::<GPIO_B0_08, GPIO_B0_09>::MODULE, 3);
+    assert_eq!(Pins::<GPIO_EMC_19, GPIO_EMC_20>::MODULE, 4);
+    assert_eq!(Pins::<GPIO_B1_12, GPIO_B1_13>::MODULE, 5);
+    assert_eq!(Pins::<GPIO_EMC_25, GPIO_EMC_26>::MODULE, 6);
+    assert_eq!(Pins::<GPIO_SD_B1_08, GPIO_SD_B1_09>::MODULE, 7);
+    assert_eq!(Pins::<GPIO_SD_B0_04, GPIO_EMC_39>::MODULE, 8);
+}
+
+#[test]
+fn enet_rmii_pins() {
+    use imxrt_iomuxc::enet::{Pin, Rmii};
+    use pads::{daisy::enet::*, gpio_b1::*};
+
+    type Enet = Rmii<
+        GPIO_B1_14,
+        GPIO_B1_15,
+        GPIO_B1_10,
+        GPIO_B1_09,
+        GPIO_B1_07,
+        GPIO_B1_08,
+        GPIO_B1_06,
+        GPIO_B1_04,
+        GPIO_B1_05,
+        GPIO_B1_11,
+    >;
+    assert_eq!(Enet::MODULE, 1);
+    assert_eq!(<GPIO_B1_14 as Pin>::ALT, 0);
+    assert_eq!(<GPIO_B1_14 as Pin>::DAISY, None);
+    assert_eq!(<GPIO_B1_10 as Pin>::ALT, 6);
+    assert_eq!(
+        <GPIO_B1_10 as Pin>::DAISY,
+        Some(DAISY_ENET_IPG_CLK_RMII_GPIO_B1_10)
+    );
+    assert_eq!(<GPIO_B1_15 as Pin>::DAISY, Some(DAISY_ENET_MDIO_GPIO_B1_15));
+    assert_eq!(
+        <GPIO_B1_04 as Pin>::DAISY,
+        Some(DAISY_ENET0_RXDATA_GPIO_B1_04)
+    );
+    assert_eq!(DAISY_ENET_MDIO_GPIO_B1_15.reg() as u32, 0x401F_8430);
+    assert_eq!(DAISY_ENET_MDIO_GPIO_B1_15.value(), 2);
+
+    // Naming the function checks the bounds, without writing the IOMUXC.
+    let _ = imxrt_iomuxc::enet::prepare_rmii::<
+        GPIO_B1_14,
+        GPIO_B1_15,
+        GPIO_B1_10,
+        GPIO_B1_09,
+        GPIO_B1_07,
+        GPIO_B1_08,
+        GPIO_B1_06,
+        GPIO_B1_04,
+        GPIO_B1_05,
+        GPIO_B1_11,
+    >;
+}
+
+#[test]
+fn sai2_sai3_pins() {
+    use imxrt_iomuxc::{
+        consts::Const,
+        sai::{Mclk, Pin, TxBclk, TxDataSignal},
+    };
+    use pads::{gpio_emc::*, gpio_sd_b1::*};
+
+    fn clock<N, P: Pin<N, Signal = S>, S>() -> u32
+    where
+        N: imxrt_iomuxc::consts::Unsigned,
+    {
+        P::ALT
+    }
+    fn tx_data<N, P: Pin<N>>() -> usize
+    where
+        N: imxrt_iomuxc::consts::Unsigned,
+        P::Signal: TxDataSignal,
+    {
+        <<P::Signal as TxDataSignal>::Index as imxrt_iomuxc::consts::Unsigned>::USIZE
+    }
+
+    assert_eq!(clock::<Const<2>, GPIO_EMC_06, TxBclk>(), 2);
+    assert_eq!(clock::<Const<3>, GPIO_EMC_37, Mclk>(), 3);
+    assert_eq!(clock::<Const<3>, GPIO_SD_B1_04, Mclk>(), 8);
+    assert_eq!(tx_data::<Const<2>, GPIO_EMC_04>(), 0);
+    assert_eq!(tx_data::<Const<3>, GPIO_SD_B1_01>(), 0);
+}
+
+#[test]
+fn sai_direction_pins() {
+    use imxrt_iomuxc::{consts::Const, sai, NoPin};
+    use pads::gpio_emc::*;
+
+    // Naming the functions checks the bounds, without writing the IOMUXC.
+    let _ = sai::prepare_tx::<Const<2>, GPIO_EMC_06, GPIO_EMC_05, GPIO_EMC_04, NoPin<2>>;
+    let _ = sai::prepare_rx::<Const<2>, GPIO_EMC_10, GPIO_EMC_09, GPIO_EMC_08, GPIO_EMC_07>;
+}
+
+#[test]
+fn shared_adc_pins() {
+    fn shared<P: imxrt_iomuxc::adc::SharedPin>() -> (u8, u8) {
+        (
+            <P as imxrt_iomuxc::adc::Pin<1>>::CHANNEL,
+            <P as imxrt_iomuxc::adc::Pin<2>>::CHANNEL,
+        )
+    }
+    assert_eq!(shared::<pads::gpio_ad_b1::GPIO_AD_B1_11>(), (0, 0));
+    assert_eq!(shared::<pads::gpio_ad_b1::GPIO_AD_B1_03>(), (8, 8));
+}
+
+#[test]
+fn analog_pins() {
+    fn analog<P: imxrt_iomuxc::Analog>() {}
+    analog::<pads::gpio_ad_b0::GPIO_AD_B0_12>();
+    // Only an ADC2 input.
+    analog::<pads::gpio_ad_b1::GPIO_AD_B1_15>();
+}
+
+#[test]
+fn any_alt_pins() {
+    fn any_alt<P: imxrt_iomuxc::AnyAltPin<ALT>, const ALT: u8>() {}
+    // Every alternate, including those without pin traits, like XBAR1_INOUT14
+    // and REF_CLK_32K.
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 0>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 1>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 2>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 3>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 4>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 5>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 6>();
+    any_alt::<pads::gpio_ad_b0::GPIO_AD_B0_00, 7>();
+    // ENET2_TDATA00, after a gap at alternate 7.
+    any_alt::<pads::gpio_b0::GPIO_B0_12, 8>();
+}
+
+#[test]
+fn default_pins() {
+    use imxrt_iomuxc::{consts::Const, lpi2c, lpspi, lpuart};
+
+    fn uart<T, R, const N: u8>(_: (T, R)) -> u8
+    where
+        T: lpuart::Pin<Direction = lpuart::Tx, Module = Const<N>>,
+        R: lpuart::Pin<Direction = lpuart::Rx, Module = Const<N>>,
+    {
+        N
+    }
+    fn i2c<C, D, const N: u8>(_: (C, D)) -> u8
+    where
+        C: lpi2c::Pin<Signal = lpi2c::Scl, Module = Const<N>>,
+        D: lpi2c::Pin<Signal = lpi2c::Sda, Module = Const<N>>,
+    {
+        N
+    }
+    fn spi<O, I, C, P, const N: u8>(_: (O, I, C, P)) -> u8
+    where
+        O: lpspi::Pin<Signal = lpspi::Sdo, Module = Const<N>>,
+        I: lpspi::Pin<Signal = lpspi::Sdi, Module = Const<N>>,
+        C: lpspi::Pin<Signal = lpspi::Sck, Module = Const<N>>,
+        P: lpspi::Pin<Signal = lpspi::Pcs0, Module = Const<N>>,
+    {
+        N
+    }
+
+    let pads = unsafe { pads::Pads::new() };
+    assert_eq!(uart(pads::defaults::lpuart!(pads, 1)), 1);
+    assert_eq!(uart(pads::defaults::lpuart!(pads, 3)), 3);
+    assert_eq!(i2c(pads::defaults::lpi2c!(pads, 1)), 1);
+    assert_eq!(spi(pads::defaults::lpspi!(pads, 1)), 1);
+}
+
+#[test]
+fn output_capable() {
+    fn output<P: imxrt_iomuxc::OutputCapable>(_: P) {}
+    let mut pads = unsafe { pads::Pads::new() };
+    output(&mut pads.gpio_b0.p03);
+    output(pads.gpio_b0.p03);
+}
+
+#[test]
+fn default_bundles() {
+    use pads::defaults::{self, Lpi2c1, Lpspi1, Lpuart1};
+
+    let pads = unsafe { pads::Pads::new() };
+    let _: Lpuart1 = defaults::lpuart!(pads, 1).into();
+    let _: Lpi2c1 = defaults::lpi2c!(pads, 1).into();
+    let _: Lpspi1 = defaults::lpspi!(pads, 1).into();
+    assert_eq!(Lpuart1::MODULE, 1);
+}
+
+#[test]
+fn regroup_erased_pads() {
+    use core::convert::TryFrom;
+
+    let erased = unsafe { pads::Pads::new() }.erase();
+    let Ok(pads) = pads::Pads::try_from(erased) else {
+        panic!("All pads should regroup");
+    };
+
+    let mut erased = pads.gpio_b0.erase();
+    erased.swap(0, 1);
+    let Err(erased) = pads::gpio_b0::Pads::try_from(erased) else {
+        panic!("Swapped pads should not regroup");
+    };
+    assert_eq!(erased.len(), 16);
+}
+
+#[test]
+fn motor_pins() {
+    use imxrt_iomuxc::motor::{AltPad, Pair, Pins};
+    use pads::{gpio_ad_b0::*, gpio_b0::GPIO_B0_12, gpio_sd_b0::*};
+
+    // PWM1_SM0 through PWM1_SM2. The encoder phases are XBAR1_INOUT14 and
+    // XBAR1_INOUT15, the fault is XBAR1_IN24, and the ADC trigger is
+    // XBAR1_INOUT10; all are at alternate 1.
+    type Motor = Pins<
+        GPIO_SD_B0_00,
+        GPIO_SD_B0_01,
+        GPIO_SD_B0_02,
+        GPIO_SD_B0_03,
+        GPIO_SD_B0_04,
+        GPIO_SD_B0_05,
+        AltPad<GPIO_AD_B0_00, 1>,
+        AltPad<GPIO_AD_B0_01, 1>,
+        AltPad<GPIO_AD_B0_14, 1>,
+        AltPad<GPIO_B0_12, 1>,
+    >;
+    let _: Motor = unsafe {
+        Pins::new(
+            Pair::new(GPIO_SD_B0_00::new(), GPIO_SD_B0_01::new()),
+            Pair::new(GPIO_SD_B0_02::new(), GPIO_SD_B0_03::new()),
+            Pair::new(GPIO_SD_B0_04::new(), GPIO_SD_B0_05::new()),
+            AltPad::new(GPIO_AD_B0_00::new()),
+            AltPad::new(GPIO_AD_B0_01::new()),
+            AltPad::new(GPIO_AD_B0_14::new()),
+            AltPad::new(GPIO_B0_12::new()),
+        )
+    };
+    assert_eq!(Motor::MODULE, 1);
+    assert_eq!(Pair::<GPIO_SD_B0_02, GPIO_SD_B0_03>::SUBMODULE, 1);
+    assert_eq!(Pair::<GPIO_SD_B0_04, GPIO_SD_B0_05>::SUBMODULE, 2);
+}
+
+#[test]
+fn erased_pads_by_location() {
+    use pads::{gpio_b0::GPIO_B0_03, gpio_sd_b1::GPIO_SD_B1_11};
+
+    let mut erased = unsafe { pads::Pads::new() }.erase();
+    let led = erased.get_mut(GPIO_B0_03::LOCATION).unwrap();
+    assert_eq!(led.location(), GPIO_B0_03::LOCATION);
+    assert_eq!(
+        erased.get(GPIO_SD_B1_11::LOCATION).unwrap().location(),
+        GPIO_SD_B1_11::LOCATION
+    );
+    assert!(erased
+        .get(imxrt_iomuxc::PadLocation { mux: 0, pad: 0 })
+        .is_none());
+}
+
+#[test]
+fn pads_in_statics() {
+    use imxrt_iomuxc::ErasedPad;
+    use pads::gpio_ad_b0::GPIO_AD_B0_12;
+    use std::sync::Mutex;
+
+    static PADS: Mutex<Option<pads::Pads>> = Mutex::new(Some(unsafe { pads::Pads::new() }));
+    static ERASED: Mutex<Option<ErasedPad>> = Mutex::new(Some(unsafe {
+        ErasedPad::new(0x401F_80EC as *mut u32, 0x401F_82DC as *mut u32)
+    }));
+
+    let pads = PADS.lock().unwrap().take().unwrap();
+    let erased = ERASED.lock().unwrap().take().unwrap();
+    assert_eq!(erased.location(), GPIO_AD_B0_12::LOCATION);
+    let erased: GPIO_AD_B0_12 = core::convert::TryFrom::try_from(erased).unwrap();
+    let _ = (pads.gpio_ad_b0.p12, erased);
+}
+
+#[test]
+fn register_map() {
+    use imxrt_iomuxc::Iomuxc;
+
+    let erased = unsafe { pads::Pads::new() }.erase();
+    let mut erased: Vec<_> = erased
+        .gpio_emc
+        .into_iter()
+        .chain(erased.gpio_ad_b0)
+        .chain(erased.gpio_ad_b1)
+        .chain(erased.gpio_b0)
+        .chain(erased.gpio_b1)
+        .chain(erased.gpio_sd_b0)
+        .chain(erased.gpio_sd_b1)
+        .chain(erased.gpio_spi_b0)
+        .chain(erased.gpio_spi_b1)
+        .chain(erased.snvs)
+        .collect();
+    // The SNVS pads erase by name, not by register address.
+    erased.sort_by_key(|pad| pad.location().mux);
+    let mut entries: Vec<_> = pads::REGISTER_MAP.iter().collect();
+    entries.sort_by_key(|entry| entry.mux);
+    assert_eq!(erased.len(), entries.len());
+    for (pad, entry) in erased.iter_mut().zip(entries) {
+        assert_eq!(pad.mux() as u32, entry.mux, "{}", entry.name);
+        assert_eq!(pad.pad() as u32, entry.pad, "{}", entry.name);
+    }
+
+    let lpuart2_rx = pads::REGISTER_MAP
+        .iter()
+        .find(|entry| entry.name == "GPIO_AD_B1_03")
+        .unwrap();
+    assert!(lpuart2_rx
+        .daisies
+        .contains(&imxrt_iomuxc::register_map::DaisyEntry {
+            reg: 0x401F_852C,
+            value: 1
+        }));
+}
+
+#[test]
+fn public_daisies() {
+    use imxrt_iomuxc::lpuart::Pin;
+    use pads::daisy::lpuart::DAISY_LPUART2_RX_GPIO_AD_B1_03;
+
+    assert_eq!(DAISY_LPUART2_RX_GPIO_AD_B1_03.reg() as u32, 0x401F_852C);
+    assert_eq!(DAISY_LPUART2_RX_GPIO_AD_B1_03.value(), 1);
+    assert_eq!(
+        <pads::gpio_ad_b1::GPIO_AD_B1_03 as Pin>::DAISY,
+        Some(DAISY_LPUART2_RX_GPIO_AD_B1_03)
+    );
+}
+
+#[test]
+fn select_input_routes() {
+    use imxrt_iomuxc::register_map::{InputSignal, RoutingError};
+
+    let input = InputSignal::from(pads::daisy::lpuart::DAISY_LPUART2_RX_GPIO_AD_B1_03);
+    assert_eq!(input.reg(), 0x401F_852C);
+    // GPIO_AD_B0_12 can't feed LPUART2_RX, so nothing is written.
+    let pad = unsafe { pads::gpio_ad_b0::GPIO_AD_B0_12::new() }.erase();
+    assert_eq!(pads::select_input(input, &pad), Err(RoutingError::NoRoute));
+}
+
+#[test]
+fn signal_database() {
+    use imxrt_iomuxc::PadLocation;
+
+    // Every daisy that a pin trait writes is a select input of the pad.
+    for entry in pads::REGISTER_MAP {
+        let location = PadLocation {
+            mux: entry.mux,
+            pad: entry.pad,
+        };
+        for daisy in entry.daisies {
+            assert!(
+                pads::DATABASE
+                    .inputs_of(location)
+                    .any(|input| input.register == daisy.reg && input.value == daisy.value),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    let tx = pads::gpio_ad_b1::GPIO_AD_B1_02::LOCATION;
+    assert_eq!(pads::DATABASE.find_alternate(tx, "LPUART2_TX"), Some(2));
+    // XBAR1 doesn't have a pin trait.
+    let xbar = pads::gpio_ad_b0::GPIO_AD_B0_00::LOCATION;
+    assert_eq!(
+        pads::DATABASE.find_alternate(xbar, "XBAR1_INOUT14"),
+        Some(1)
+    );
+    let rx = pads::gpio_ad_b1::GPIO_AD_B1_03::LOCATION;
+    let input = pads::DATABASE.find_input(rx, "LPUART2_RX").unwrap();
+    assert_eq!(
+        input.daisy(),
+        pads::daisy::lpuart::DAISY_LPUART2_RX_GPIO_AD_B1_03
+    );
+}
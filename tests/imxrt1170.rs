@@ -0,0 +1,181 @@
+//! Smoke tests for the 1170 pads.
+
+#![cfg(feature = "imxrt1170")]
+
+#[macro_use]
+mod macros;
+
+use imxrt_iomuxc::imxrt1170 as pads;
+
+group!(snvs, 3,
+         [WAKEUP, PMIC_STBY_REQ],
+    mux: [0x40C9_4000, 0x40C9_4008],
+    pad: [0x40C9_4040, 0x40C9_4048]);
+group!(gpio_snvs, 10,
+         [GPIO_SNVS_00, GPIO_SNVS_09],
+    mux: [0x40C9_400C, 0x40C9_4030],
+    pad: [0x40C9_404C, 0x40C9_4070]);
+
+#[cfg(feature = "self-check")]
+#[test]
+fn self_check() {
+    pads::self_check().unwrap();
+}
+
+#[test]
+fn domain_check() {
+    use imxrt_iomuxc::{
+        imxrt1170::domain::{self, Domain, DomainViolation, Instance},
+        NoPin,
+    };
+
+    const CM4: Domain = Domain::new().allow(Instance::IomuxcLpsr);
+
+    let mut lpsr = unsafe { pads::gpio_lpsr::GPIO_LPSR_04::new() };
+    let mut ad = unsafe { pads::gpio_ad::GPIO_AD_04::new() };
+    assert_eq!(domain::check(&CM4, &mut lpsr), Ok(()));
+    assert_eq!(
+        domain::check(&CM4, &mut ad),
+        Err(DomainViolation(Some(Instance::Iomuxc)))
+    );
+    assert_eq!(
+        domain::try_configure(&CM4, &mut ad, imxrt_iomuxc::Config::zero()),
+        Err(DomainViolation(Some(Instance::Iomuxc)))
+    );
+    assert_eq!(
+        domain::check(&CM4, &mut NoPin::<1>::new()),
+        Err(DomainViolation(None))
+    );
+}
+
+#[test]
+fn lpsr_pads() {
+    use imxrt_iomuxc::imxrt1170::domain::Instance;
+
+    let pads = unsafe { pads::Pads::new() };
+    let mut lpsr: pads::LpsrPads = pads.gpio_lpsr;
+    assert_eq!(Instance::of(&mut lpsr.p00), Some(Instance::IomuxcLpsr));
+    let mut ad = pads.gpio_ad;
+    assert_eq!(Instance::of(&mut ad.p00), Some(Instance::Iomuxc));
+    let mut snvs = pads.snvs;
+    assert_eq!(Instance::of(&mut snvs.wakeup), Some(Instance::IomuxcSnvs));
+    let mut gpio_snvs = pads.gpio_snvs;
+    assert_eq!(Instance::of(&mut gpio_snvs.p09), Some(Instance::IomuxcSnvs));
+}
+
+#[test]
+fn split_for_cores() {
+    use imxrt_iomuxc::imxrt1170::cores::{Assignment, CoreId, Group};
+
+    const ASSIGNMENT: Assignment = Assignment::new()
+        .assign_cm4(Group::GpioLpsr)
+        .assign_cm4(Group::GpioAd)
+        .assign_cm7(Group::GpioAd);
+    assert_eq!(ASSIGNMENT.owner(Group::GpioLpsr), CoreId::Cm4);
+    assert_eq!(ASSIGNMENT.owner(Group::GpioAd), CoreId::Cm7);
+
+    let (cm7, cm4) = unsafe { pads::Pads::new() }.split_for_cores(&ASSIGNMENT);
+    assert!(cm7.gpio_lpsr.is_none() && cm4.gpio_lpsr.is_some());
+    assert!(cm7.gpio_ad.is_some() && cm4.gpio_ad.is_none());
+    assert!(cm7.gpio_emc_b1.is_some() && cm4.gpio_emc_b1.is_none());
+    assert!(cm7.snvs.is_some() && cm4.snvs.is_none());
+    assert!(cm7.gpio_snvs.is_some() && cm4.gpio_snvs.is_none());
+}
+
+#[test]
+fn power_check() {
+    use imxrt_iomuxc::{
+        imxrt1170::power::{self, PowerDomain, Powered, Unpowered},
+        NoPin,
+    };
+
+    const SUSPENDED: Powered = Powered::new()
+        .on(PowerDomain::Lpsr)
+        .on(PowerDomain::Wakeup)
+        .off(PowerDomain::Wakeup);
+
+    let mut lpsr = unsafe { pads::gpio_lpsr::GPIO_LPSR_04::new() };
+    let mut ad = unsafe { pads::gpio_ad::GPIO_AD_04::new() };
+    assert_eq!(power::check(&SUSPENDED, &mut lpsr), Ok(()));
+    assert_eq!(
+        power::try_configure(&SUSPENDED, &mut ad, imxrt_iomuxc::Config::zero()),
+        Err(Unpowered(Some(PowerDomain::Wakeup)))
+    );
+    assert_eq!(
+        power::check(&SUSPENDED, &mut NoPin::<1>::new()),
+        Err(Unpowered(None))
+    );
+    let mut wakeup = unsafe { pads::snvs::WAKEUP::new() };
+    assert_eq!(
+        power::check(&SUSPENDED, &mut wakeup),
+        Err(Unpowered(Some(PowerDomain::Snvs)))
+    );
+    assert_eq!(
+        power::check(&SUSPENDED.on(PowerDomain::Snvs), &mut wakeup),
+        Ok(())
+    );
+}
+
+#[test]
+fn signal_database() {
+    use imxrt_iomuxc::PadLocation;
+
+    // Every daisy that a pin trait writes is a select input of the pad.
+    for entry in pads::REGISTER_MAP {
+        let location = PadLocation {
+            mux: entry.mux,
+            pad: entry.pad,
+        };
+        for daisy in entry.daisies {
+            assert!(
+                pads::DATABASE
+                    .inputs_of(location)
+                    .any(|input| input.register == daisy.reg && input.value == daisy.value),
+                "{}",
+                entry.name
+            );
+        }
+    }
+}
+
+#[test]
+fn lpsr_peripherals() {
+    use imxrt_iomuxc::{flexcan, lpi2c, lpspi, lpuart, Iomuxc, PadLocation};
+    use pads::gpio_lpsr::*;
+
+    fn location<P: Iomuxc>(mut pad: P) -> PadLocation {
+        PadLocation {
+            mux: pad.mux() as u32,
+            pad: pad.pad() as u32,
+        }
+    }
+    // The pins' alternates are the database's alternates for the signals.
+    macro_rules! check {
+        ($trait:path, $pad:ident, $signal:literal) => {
+            assert_eq!(
+                pads::DATABASE.find_alternate(location(unsafe { $pad::new() }), $signal),
+                Some(<$pad as $trait>::ALT),
+                "{} {}",
+                stringify!($pad),
+                $signal
+            );
+        };
+    }
+
+    check!(lpuart::Pin, GPIO_LPSR_04, "LPUART11_TXD");
+    check!(lpuart::Pin, GPIO_LPSR_09, "LPUART11_RXD");
+    check!(lpuart::Pin, GPIO_LPSR_00, "LPUART12_TXD");
+    check!(lpuart::Pin, GPIO_LPSR_11, "LPUART12_RXD");
+    check!(lpspi::Pin, GPIO_LPSR_02, "LPSPI5_SCK");
+    check!(lpspi::Pin, GPIO_LPSR_15, "LPSPI5_SIN");
+    check!(lpspi::Pin, GPIO_LPSR_10, "LPSPI6_SCK");
+    check!(lpspi::Pin, GPIO_LPSR_12, "LPSPI6_SIN");
+    check!(lpi2c::Pin, GPIO_LPSR_09, "LPI2C5_SCL");
+    check!(lpi2c::Pin, GPIO_LPSR_10, "LPI2C6_SDA");
+    check!(flexcan::Pin, GPIO_LPSR_06, "FLEXCAN3_TX");
+    check!(flexcan::Pin, GPIO_LPSR_01, "FLEXCAN3_RX");
+
+    assert_eq!(lpuart::Pins::<GPIO_LPSR_08, GPIO_LPSR_09>::MODULE, 11);
+    assert_eq!(flexcan::Pins::<GPIO_LPSR_08, GPIO_LPSR_09>::MODULE, 3);
+    assert!(<GPIO_LPSR_11 as lpspi::Pin>::DAISY.is_none());
+}
@@ -6,6 +6,8 @@ macro_rules! group {
         [ $id_left: ident, $id_right: ident ],
         mux: [ $mux_left: expr, $mux_right: expr ],
         pad: [ $pad_left: expr, $pad_right: expr ]) => {
+        // Counts are written with two digits, like `06`.
+        #[allow(clippy::zero_prefixed_literal)]
         mod $group {
             use super::pads;
             use imxrt_iomuxc::Iomuxc;
@@ -14,9 +14,13 @@ mod iomuxc {
 #[test]
 fn use_prelude() {
     use iomuxc::{
-        adc, alternate, ccm, clear_sion, configure, consts, flexpwm, gpio, lpi2c, lpspi, lpuart,
-        sai, set_sion, usdhc, Config, Daisy, DriveStrength, ErasedPad, Hysteresis, OpenDrain, Pad,
-        PullKeeper, SlewRate, Speed, WrongPadError,
+        adc, alternate, alternate_cs, alternate_raw, ccm, clear_sion, configure, configure_all,
+        configure_cs, configure_if_changed, consts, enet, flexio, flexpwm, flexspi, gpio, lock,
+        lpadc, lpi2c, lpspi, lpuart, prepare_alternate, prepare_analog, replace_alternate, sai,
+        set_sion, signals, sleep, unused, usdhc, wakeup, Alternate, Analog, AnyAltPin, Config,
+        Daisy, DaisyChoice, DriveStrength, ErasedPad, Hysteresis, Lease, NoPin, OpenDrain,
+        OutputCapable, Pad, PadImage, PadLocation, PadTable, PreparePlan, Prepared, PullKeeper,
+        RegisterWrite, Shared, SlewRate, Speed, WrongPadError,
     };
 }
 
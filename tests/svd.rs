@@ -0,0 +1,9 @@
+//! Runs the `svd` module generator's tests on the host.
+
+#![cfg(feature = "svd")]
+
+#[allow(dead_code)]
+#[path = "../build/svd.rs"]
+mod svd;
+
+const SVD_VAR: &str = "IMXRT_IOMUXC_SVD";